@@ -0,0 +1,211 @@
+//! Generates the `character_sets!` invocation for `CharacterSetEnum` from the vendored copy of
+//! IANA's `character-sets-1.csv`.
+//!
+//! To update to a new revision of the registry, replace `character-sets-1.csv` with the file
+//! published at <https://www.iana.org/assignments/character-sets/character-sets-1.csv>.
+use std::{
+	env,
+	fmt::Write as _,
+	fs,
+	path::Path,
+};
+
+const REGISTRY: &str = "character-sets-1.csv";
+
+struct Entry
+{
+	ident: String,
+	preferred_mime_name: Option<String>,
+	name: String,
+	mib_enum: u16,
+	aliases: Vec<String>,
+	upper: Vec<String>,
+}
+
+fn main()
+{
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rerun-if-changed={}", REGISTRY);
+
+	let csv = fs::read_to_string(REGISTRY).expect("failed to read character-sets-1.csv");
+	let entries = parse_registry(&csv);
+
+	let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+	fs::write(
+		Path::new(&out_dir).join("character_sets.rs"),
+		generate(&entries),
+	)
+	.expect("failed to write character_sets.rs");
+}
+
+fn parse_registry(csv: &str) -> Vec<Entry>
+{
+	let mut records = parse_csv(csv).into_iter();
+	let header = records.next().expect("character-sets-1.csv is empty");
+	let column = |title: &str| {
+		header
+			.iter()
+			.position(|h| h.trim() == title)
+			.unwrap_or_else(|| panic!("character-sets-1.csv has no `{}` column", title))
+	};
+	let preferred_mime_name = column("Preferred MIME Name");
+	let name = column("Name");
+	let mib_enum = column("MIBenum");
+	let aliases = column("Aliases");
+
+	records
+		.filter(|record| record.iter().any(|field| !field.trim().is_empty()))
+		.map(|record| {
+			let field = |i: usize| record.get(i).map(|s| s.trim()).unwrap_or("");
+			let name = field(name).to_owned();
+			let mib_enum = field(mib_enum)
+				.parse()
+				.unwrap_or_else(|_| panic!("invalid MIBenum for {}", name));
+			let preferred_mime_name = Some(field(preferred_mime_name))
+				.filter(|s| !s.is_empty())
+				.map(str::to_owned);
+			let aliases = field(aliases)
+				.lines()
+				.map(str::trim)
+				.filter(|s| !s.is_empty())
+				.map(str::to_owned)
+				.collect::<Vec<_>>();
+			let mut upper = Vec::<String>::new();
+			for s in std::iter::once(&name).chain(aliases.iter())
+			{
+				let s = s.to_ascii_uppercase();
+				if !upper.contains(&s)
+				{
+					upper.push(s);
+				}
+			}
+			Entry {
+				ident: ident(&name),
+				preferred_mime_name,
+				name,
+				mib_enum,
+				aliases,
+				upper,
+			}
+		})
+		.collect()
+}
+
+/// Parses RFC 4180 CSV, where quoted fields may contain commas, newlines and doubled quotes.
+fn parse_csv(csv: &str) -> Vec<Vec<String>>
+{
+	let mut records = vec![];
+	let mut record = vec![];
+	let mut field = String::new();
+	let mut quoted = false;
+	let mut chars = csv.chars().peekable();
+	while let Some(c) = chars.next()
+	{
+		match c
+		{
+			'"' if quoted =>
+			{
+				if chars.peek() == Some(&'"')
+				{
+					field.push('"');
+					chars.next();
+				}
+				else
+				{
+					quoted = false;
+				}
+			}
+			'"' if field.is_empty() => quoted = true,
+			',' if !quoted => record.push(std::mem::take(&mut field)),
+			'\r' if !quoted =>
+			{}
+			'\n' if !quoted =>
+			{
+				record.push(std::mem::take(&mut field));
+				records.push(std::mem::take(&mut record));
+			}
+			c => field.push(c),
+		}
+	}
+	if !field.is_empty() || !record.is_empty()
+	{
+		record.push(field);
+		records.push(record);
+	}
+	records
+}
+
+/// Derives a type name from a registered name, e.g. `ISO_8859-1:1987` to `ISO_8859_1_1987`.
+fn ident(name: &str) -> String
+{
+	let mut ident = String::new();
+	for c in name.chars()
+	{
+		if c.is_ascii_alphanumeric()
+		{
+			ident.push(c.to_ascii_uppercase());
+		}
+		else if !ident.is_empty() && !ident.ends_with('_')
+		{
+			ident.push('_');
+		}
+	}
+	ident.trim_end_matches('_').to_owned()
+}
+
+fn generate(entries: &[Entry]) -> String
+{
+	let mut out = String::new();
+	out.push_str("character_sets!(\n\tCharacterSetEnum,\n");
+	write_lookup(&mut out, entries);
+	for entry in entries
+	{
+		writeln!(out, "\t{},", entry.ident).unwrap();
+		match &entry.preferred_mime_name
+		{
+			Some(name) => writeln!(out, "\tcore::option::Option::Some({:?}),", name).unwrap(),
+			None => out.push_str("\tcore::option::Option::None,\n"),
+		}
+		writeln!(out, "\t{:?},", entry.name).unwrap();
+		writeln!(out, "\t{},", entry.mib_enum).unwrap();
+		write_list(&mut out, &entry.aliases);
+		write_list(&mut out, &entry.upper);
+	}
+	out.push_str(");\n");
+	out
+}
+
+/// Writes the `@lookup` table: every uppercase name sorted by bytes, so that
+/// `CharacterSetEnum::lookup` can binary search it. The first charset claiming a name wins.
+fn write_lookup(out: &mut String, entries: &[Entry])
+{
+	let mut lookup = Vec::<(&str, &str)>::new();
+	for entry in entries
+	{
+		for upper in &entry.upper
+		{
+			if !lookup.iter().any(|(name, _)| name == upper)
+			{
+				lookup.push((upper, &entry.ident));
+			}
+		}
+	}
+	lookup.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+
+	out.push_str("\t@lookup [\n");
+	for (upper, ident) in lookup
+	{
+		writeln!(out, "\t\t({:?}, {}),", upper, ident).unwrap();
+	}
+	out.push_str("\t],\n");
+}
+
+fn write_list(out: &mut String, list: &[String])
+{
+	out.push_str("\t[\n");
+	for s in list
+	{
+		writeln!(out, "\t\t{:?},", s).unwrap();
+	}
+	out.push_str("\t],\n");
+}
@@ -0,0 +1,187 @@
+//! Big5 and Big5-HKSCS, following HKSCS-2008.
+//!
+//! `Big5` decodes the lead bytes 0xA1 to 0xF9 of the same table, which include the ETEN
+//! extensions. A few codes are duplicates of others and decode to a character that encodes
+//! elsewhere, and four Big5-HKSCS codes decode to a letter followed by a combining mark.
+use super::{
+	encode_chars,
+	tables::chinese::BIG5_HKSCS,
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// The lead byte of the first row of the table.
+const FIRST_LEAD: u8 = 0x87;
+
+/// The Big5-HKSCS codes `0x88XX` decoding to two code points, by trail byte.
+const COMPOSED: [(u8, char, char); 4] = [
+	(0x62, '\u{CA}', '\u{304}'),
+	(0x64, '\u{CA}', '\u{30C}'),
+	(0xA3, '\u{EA}', '\u{304}'),
+	(0xA5, '\u{EA}', '\u{30C}'),
+];
+
+/// `Big5` or, if `hkscs` is set, `Big5-HKSCS`.
+pub(crate) struct Big5
+{
+	hkscs: bool,
+	/// A letter held back by the encoder in case a combining mark follows.
+	base: Option<char>,
+}
+
+impl Big5
+{
+	pub(crate) fn new(hkscs: bool) -> Self
+	{
+		Self { hkscs, base: None }
+	}
+
+	fn leads(&self) -> (u8, u8)
+	{
+		if self.hkscs
+		{
+			(FIRST_LEAD, 0xFE)
+		}
+		else
+		{
+			(0xA1, 0xF9)
+		}
+	}
+
+	fn encode_pointer(&self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		let (first, last) = self.leads();
+		let range = usize::from(first - FIRST_LEAD) * 157..usize::from(last - FIRST_LEAD + 1) * 157;
+		match BIG5_HKSCS.pointer(c)
+		{
+			Some(pointer) if range.contains(&pointer) =>
+			{
+				let (lead, trail) = (pointer / 157, pointer % 157);
+				output.push(FIRST_LEAD + lead as u8);
+				output.push(trail as u8 + if trail < 0x3F { 0x40 } else { 0x62 });
+				true
+			}
+			_ => false,
+		}
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if let Some(base) = self.base.take()
+		{
+			if let Some(&(trail, ..)) = COMPOSED.iter().find(|e| (e.1, e.2) == (base, c))
+			{
+				output.extend_from_slice(&[0x88, trail]);
+				return true;
+			}
+			self.encode_pointer(base, output);
+		}
+		if self.hkscs && COMPOSED.iter().any(|e| e.1 == c)
+		{
+			self.base = Some(c);
+			return true;
+		}
+		if c.is_ascii()
+		{
+			output.push(c as u8);
+			return true;
+		}
+		self.encode_pointer(c, output)
+	}
+}
+
+impl Stepper for Big5
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		if b < 0x80
+		{
+			output.push(char::from(b));
+			return Step::Decoded(1);
+		}
+		let (first, last) = self.leads();
+		if !(first..=last).contains(&b)
+		{
+			return Step::Malformed(1);
+		}
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		let trail = bytes[1];
+		let trail_offset = match trail
+		{
+			0x40..=0x7E => 0x40,
+			0xA1..=0xFE => 0x62,
+			_ => return Step::Malformed(1),
+		};
+		if self.hkscs && b == 0x88
+		{
+			if let Some(&(_, base, mark)) = COMPOSED.iter().find(|e| e.0 == trail)
+			{
+				output.push(base);
+				output.push(mark);
+				return Step::Decoded(2);
+			}
+		}
+		let pointer = usize::from(b - FIRST_LEAD) * 157 + usize::from(trail - trail_offset);
+		match BIG5_HKSCS.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			// An ASCII trail byte is decoded again on its own.
+			None if trail < 0x80 => Step::Malformed(1),
+			None => Step::Malformed(2),
+		}
+	}
+}
+
+impl RawEncoder for Big5
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, output: &mut Vec<u8>)
+	{
+		if let Some(base) = self.base.take()
+		{
+			self.encode_pointer(base, output);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_big5()
+	{
+		let codec = CharacterSetEnum::BIG5.codec().unwrap();
+		round_trip(codec, "中文a", b"\xA4\xA4\xA4\xE5a");
+		malformed(codec, b"a\xA4\x20", 1, b"\xA4");
+
+		let codec = CharacterSetEnum::BIG5_HKSCS.codec().unwrap();
+		round_trip(codec, "中文㐵Ê\u{304}", b"\xA4\xA4\xA4\xE5\x92\x77\x88\x62");
+		malformed(codec, b"a\x80", 1, b"\x80");
+	}
+}
@@ -0,0 +1,264 @@
+//! BOCU-1 (Unicode Technical Note #6): each code point is written as its difference from the
+//! previous one, in one to four bytes. Code points up to U+0020 are written as themselves, and
+//! the controls among them reset the previous code point; byte 0xFF resets it as well.
+use super::{
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use core::convert::TryFrom;
+
+/// The previous code point at the start and after a reset.
+const ASCII_PREV: i32 = 0x40;
+const MIDDLE: i32 = 0x90;
+const RESET: u8 = 0xFF;
+
+/// Number of values a trail byte carries.
+const TRAIL_COUNT: i32 = 243;
+/// Control bytes usable as trail bytes, carrying the values 0 to 19; bytes from 0x21 on carry
+/// the values 20 to 242.
+const TRAIL_CONTROLS: [u8; 20] = [
+	0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+	0x1C, 0x1D, 0x1E, 0x1F,
+];
+
+const REACH_POS_1: i32 = 63;
+const REACH_NEG_1: i32 = -64;
+const REACH_POS_2: i32 = REACH_POS_1 + 43 * TRAIL_COUNT;
+const REACH_NEG_2: i32 = REACH_NEG_1 - 43 * TRAIL_COUNT;
+const REACH_POS_3: i32 = REACH_POS_2 + 3 * TRAIL_COUNT * TRAIL_COUNT;
+const REACH_NEG_3: i32 = REACH_NEG_2 - 3 * TRAIL_COUNT * TRAIL_COUNT;
+
+const START_POS_2: i32 = MIDDLE + REACH_POS_1 + 1;
+const START_POS_3: i32 = START_POS_2 + 43;
+const START_POS_4: i32 = START_POS_3 + 3;
+const START_NEG_2: i32 = MIDDLE + REACH_NEG_1;
+const START_NEG_3: i32 = START_NEG_2 - 43;
+const START_NEG_4: i32 = START_NEG_3 - 3;
+
+fn trail_byte(value: i32) -> u8
+{
+	match usize::try_from(value)
+	{
+		Ok(i) if i < TRAIL_CONTROLS.len() => TRAIL_CONTROLS[i],
+		_ => (value + 13) as u8,
+	}
+}
+
+fn trail_value(b: u8) -> Option<i32>
+{
+	match b
+	{
+		0x21..=0xFF => Some(i32::from(b) - 13),
+		_ =>
+		{
+			TRAIL_CONTROLS
+				.iter()
+				.position(|&t| t == b)
+				.map(|i| i as i32)
+		}
+	}
+}
+
+/// The previous code point after `c`, chosen near the middle of its script.
+fn next_prev(c: i32) -> i32
+{
+	match c
+	{
+		0x3040..=0x309F => 0x3070,
+		0x4E00..=0x9FA5 => 0x4E00 - REACH_NEG_2,
+		0xAC00..=0xD7A3 => (0xD7A3 + 0xAC00) / 2,
+		_ => (c & !0x7F) + ASCII_PREV,
+	}
+}
+
+pub(crate) struct Bocu1
+{
+	prev: i32,
+}
+
+impl Bocu1
+{
+	pub(crate) fn new() -> Self
+	{
+		Self { prev: ASCII_PREV }
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>)
+	{
+		let c = u32::from(c) as i32;
+		if c <= 0x20
+		{
+			if c != 0x20
+			{
+				self.prev = ASCII_PREV;
+			}
+			output.push(c as u8);
+			return;
+		}
+		let diff = c - self.prev;
+		self.prev = next_prev(c);
+		if (REACH_NEG_1..=REACH_POS_1).contains(&diff)
+		{
+			output.push((MIDDLE + diff) as u8);
+			return;
+		}
+		let (lead, mut rest, len) = if diff > 0
+		{
+			let (base, start, len) = if diff <= REACH_POS_2
+			{
+				(REACH_POS_1 + 1, START_POS_2, 2)
+			}
+			else if diff <= REACH_POS_3
+			{
+				(REACH_POS_2 + 1, START_POS_3, 3)
+			}
+			else
+			{
+				(REACH_POS_3 + 1, START_POS_4, 4)
+			};
+			(start, diff - base, len)
+		}
+		else
+		{
+			let (base, start, len) = if diff >= REACH_NEG_2
+			{
+				(REACH_NEG_1, START_NEG_2, 2)
+			}
+			else if diff >= REACH_NEG_3
+			{
+				(REACH_NEG_2, START_NEG_3, 3)
+			}
+			else
+			{
+				(REACH_NEG_3, START_NEG_4, 4)
+			};
+			(start, diff - base, len)
+		};
+		let mut bytes = [0; 4];
+		for b in bytes[1..len].iter_mut().rev()
+		{
+			*b = trail_byte(rest.rem_euclid(TRAIL_COUNT));
+			rest = rest.div_euclid(TRAIL_COUNT);
+		}
+		bytes[0] = (lead + rest) as u8;
+		output.extend_from_slice(&bytes[..len]);
+	}
+}
+
+impl Stepper for Bocu1
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		if b <= 0x20
+		{
+			if b != 0x20
+			{
+				self.prev = ASCII_PREV;
+			}
+			output.push(char::from(b));
+			return Step::Decoded(1);
+		}
+		if b == RESET
+		{
+			self.prev = ASCII_PREV;
+			return Step::Decoded(1);
+		}
+		let lead = i32::from(b);
+		let (diff, len) = match lead
+		{
+			START_NEG_2..=0xCF => (lead - MIDDLE, 1),
+			START_POS_2..=0xFA => ((lead - START_POS_2) * TRAIL_COUNT + REACH_POS_1 + 1, 2),
+			START_POS_3..=0xFD =>
+			{
+				let diff = (lead - START_POS_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_POS_2 + 1;
+				(diff, 3)
+			}
+			START_POS_4 => (REACH_POS_3 + 1, 4),
+			START_NEG_3..=0x4F => ((lead - START_NEG_2) * TRAIL_COUNT + REACH_NEG_1, 2),
+			START_NEG_4..=0x24 =>
+			{
+				let diff = (lead - START_NEG_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_2;
+				(diff, 3)
+			}
+			_ => (-TRAIL_COUNT * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_3, 4),
+		};
+		let mut rest = 0;
+		for i in 1..len
+		{
+			let b = match bytes.get(i)
+			{
+				Some(&b) => b,
+				None => return Step::Incomplete,
+			};
+			match trail_value(b)
+			{
+				Some(value) => rest = rest * TRAIL_COUNT + value,
+				// Bytes from the invalid one on are decoded again.
+				None => return Step::Malformed(i),
+			}
+		}
+		let c = self.prev + diff + rest;
+		match u32::try_from(c).ok().and_then(char::from_u32)
+		{
+			Some(decoded) if c > 0x20 =>
+			{
+				self.prev = next_prev(c);
+				output.push(decoded);
+				Step::Decoded(len)
+			}
+			_ => Step::Malformed(len),
+		}
+	}
+}
+
+impl RawEncoder for Bocu1
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for c in input.chars()
+		{
+			self.encode_char(c, output);
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_bocu_1()
+	{
+		let codec = CharacterSetEnum::BOCU_1.codec().unwrap();
+		round_trip(
+			codec,
+			"aЖЖ 日本",
+			b"\xB1\xD3\xCA\x66\x20\xFB\x48\xA0\x3F\x8B",
+		);
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::BOCU_1.codec().unwrap();
+		malformed(codec, b"\xB1\xFB", 1, b"\xFB");
+		malformed(codec, b"\xB1\x21\x00", 1, b"\x21");
+	}
+}
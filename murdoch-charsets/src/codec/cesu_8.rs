@@ -0,0 +1,154 @@
+//! CESU-8 (Unicode Technical Report #26): UTF-8 with supplementary characters written as a
+//! surrogate pair of three-byte sequences. Four-byte sequences and lone surrogates are malformed.
+use super::{
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// What starts `bytes`: a code unit of up to three bytes, or a `Step` if there is none.
+fn unit(bytes: &[u8]) -> Result<(u32, usize), Step>
+{
+	let b = bytes[0];
+	let (len, first) = match b
+	{
+		0x00..=0x7F => return Ok((u32::from(b), 1)),
+		0xC2..=0xDF => (2, 0x80..=0xBF),
+		0xE0 => (3, 0xA0..=0xBF),
+		0xE1..=0xEF => (3, 0x80..=0xBF),
+		_ => return Err(Step::Malformed(1)),
+	};
+	let mut unit = u32::from(b) & if len == 2 { 0x1F } else { 0x0F };
+	for i in 1..len
+	{
+		let b = match bytes.get(i)
+		{
+			Some(&b) => b,
+			None => return Err(Step::Incomplete),
+		};
+		let valid = if i == 1
+		{
+			first.contains(&b)
+		}
+		else
+		{
+			(0x80..=0xBF).contains(&b)
+		};
+		// Bytes from the first invalid one on are decoded again.
+		if !valid
+		{
+			return Err(Step::Malformed(i));
+		}
+		unit = unit << 6 | u32::from(b) & 0x3F;
+	}
+	Ok((unit, len))
+}
+
+fn push_unit(unit: u16, output: &mut Vec<u8>)
+{
+	output.extend_from_slice(&[
+		0xE0 | (unit >> 12) as u8,
+		0x80 | (unit >> 6 & 0x3F) as u8,
+		0x80 | (unit & 0x3F) as u8,
+	]);
+}
+
+pub(crate) struct Cesu8;
+
+impl Stepper for Cesu8
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let (high, len) = match unit(bytes)
+		{
+			Ok(unit) => unit,
+			Err(step) => return step,
+		};
+		match high
+		{
+			0xD800..=0xDBFF =>
+			{}
+			0xDC00..=0xDFFF => return Step::Malformed(len),
+			c =>
+			{
+				output.extend(char::from_u32(c));
+				return Step::Decoded(len);
+			}
+		}
+		if bytes.len() == len
+		{
+			return Step::Incomplete;
+		}
+		match unit(&bytes[len..])
+		{
+			Ok((low @ 0xDC00..=0xDFFF, 3)) =>
+			{
+				output.extend(char::from_u32(
+					0x10000 + ((high - 0xD800) << 10) + low - 0xDC00,
+				));
+				Step::Decoded(6)
+			}
+			Err(Step::Incomplete) => Step::Incomplete,
+			// The high surrogate alone is malformed; what follows is decoded again.
+			_ => Step::Malformed(len),
+		}
+	}
+}
+
+impl RawEncoder for Cesu8
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for c in input.chars()
+		{
+			if u32::from(c) < 0x10000
+			{
+				let mut buffer = [0; 4];
+				output.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+			}
+			else
+			{
+				let mut units = [0; 2];
+				for &mut unit in c.encode_utf16(&mut units)
+				{
+					push_unit(unit, output);
+				}
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_cesu_8()
+	{
+		let codec = CharacterSetEnum::CESU_8.codec().unwrap();
+		round_trip(codec, "aé😀", b"a\xC3\xA9\xED\xA0\xBD\xED\xB8\x80");
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::CESU_8.codec().unwrap();
+		malformed(codec, b"a\xF0\x9F\x98\x80", 1, b"\xF0");
+		malformed(codec, b"a\xED\xA0\xBDb", 1, b"\xED\xA0\xBD");
+	}
+}
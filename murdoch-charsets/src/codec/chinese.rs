@@ -0,0 +1,415 @@
+//! GB2312 (EUC-CN), GBK, GB18030 and HZ-GB-2312 (RFC 1843).
+//!
+//! GBK follows Windows code page 936, which decodes the GB18030 two-byte codes and maps `0x80` to
+//! U+20AC. GB18030 adds four-byte codes covering the rest of Unicode, following GB18030-2005.
+use super::{
+	encode_chars,
+	tables::chinese::{
+		GB18030,
+		GB18030_RANGES,
+		GB_2312,
+	},
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Variant
+{
+	Gb2312,
+	Gbk,
+	Gb18030,
+}
+
+/// The four-byte pointer of U+E7C7, which GB18030-2005 gave up the two-byte code `0xA8BC` for.
+const E7C7_POINTER: u32 = 7457;
+
+/// The last four-byte pointer below U+10000.
+const BMP_POINTERS_END: u32 = 39419;
+
+/// The four-byte pointer of U+10000.
+const SUPPLEMENTARY_POINTERS_START: u32 = 189_000;
+
+fn four_byte_code_point(pointer: u32) -> Option<char>
+{
+	if pointer == E7C7_POINTER
+	{
+		return Some('\u{E7C7}');
+	}
+	if pointer > BMP_POINTERS_END
+	{
+		return pointer
+			.checked_sub(SUPPLEMENTARY_POINTERS_START)
+			.and_then(|offset| char::from_u32(0x10000 + offset));
+	}
+	let i = GB18030_RANGES.partition_point(|&(p, _)| u32::from(p) <= pointer) - 1;
+	let (start, c) = GB18030_RANGES[i];
+	char::from_u32(u32::from(c) + pointer - u32::from(start))
+}
+
+fn four_byte_pointer(c: char) -> u32
+{
+	let c = u32::from(c);
+	if c == 0xE7C7
+	{
+		return E7C7_POINTER;
+	}
+	if c >= 0x10000
+	{
+		return SUPPLEMENTARY_POINTERS_START + c - 0x10000;
+	}
+	let i = GB18030_RANGES.partition_point(|&(_, start)| u32::from(start) <= c) - 1;
+	let (pointer, start) = GB18030_RANGES[i];
+	u32::from(pointer) + c - u32::from(start)
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct Gb
+{
+	variant: Variant,
+}
+
+impl Gb
+{
+	pub(crate) fn new(variant: Variant) -> Self
+	{
+		Self { variant }
+	}
+
+	fn encode_char(&self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if c.is_ascii()
+		{
+			output.push(c as u8);
+			return true;
+		}
+		if self.variant == Variant::Gb2312
+		{
+			return match GB_2312.pointer(c)
+			{
+				Some(pointer) =>
+				{
+					output.extend_from_slice(&[
+						0xA1 + (pointer / 94) as u8,
+						0xA1 + (pointer % 94) as u8,
+					]);
+					true
+				}
+				None => false,
+			};
+		}
+		if self.variant == Variant::Gbk && c == '\u{20AC}'
+		{
+			output.push(0x80);
+			return true;
+		}
+		if let Some(pointer) = GB18030.pointer(c)
+		{
+			let (lead, trail) = (pointer / 190, pointer % 190);
+			output.push(0x81 + lead as u8);
+			output.push(trail as u8 + if trail < 0x3F { 0x40 } else { 0x41 });
+			return true;
+		}
+		if self.variant == Variant::Gbk
+		{
+			return false;
+		}
+		let pointer = four_byte_pointer(c);
+		output.extend_from_slice(&[
+			0x81 + (pointer / 12600) as u8,
+			0x30 + (pointer / 1260 % 10) as u8,
+			0x81 + (pointer / 10 % 126) as u8,
+			0x30 + (pointer % 10) as u8,
+		]);
+		true
+	}
+
+	fn step_gb2312(&self, bytes: &[u8], output: &mut String) -> Step
+	{
+		if !(0xA1..=0xFE).contains(&bytes[0])
+		{
+			return Step::Malformed(1);
+		}
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		if !(0xA1..=0xFE).contains(&bytes[1])
+		{
+			return Step::Malformed(1);
+		}
+		let pointer = usize::from(bytes[0] - 0xA1) * 94 + usize::from(bytes[1] - 0xA1);
+		match GB_2312.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			None => Step::Malformed(2),
+		}
+	}
+
+	fn step_four_bytes(&self, bytes: &[u8], output: &mut String) -> Step
+	{
+		if bytes.len() < 4
+		{
+			let valid = bytes[2..]
+				.iter()
+				.zip(&[0x81..=0xFE, 0x30..=0x39])
+				.all(|(b, r)| r.contains(b));
+			return if valid
+			{
+				Step::Incomplete
+			}
+			else
+			{
+				Step::Malformed(1)
+			};
+		}
+		if !(0x81..=0xFE).contains(&bytes[2]) || !(0x30..=0x39).contains(&bytes[3])
+		{
+			return Step::Malformed(1);
+		}
+		let pointer = ((u32::from(bytes[0]) - 0x81) * 10 + u32::from(bytes[1]) - 0x30) * 1260
+			+ (u32::from(bytes[2]) - 0x81) * 10
+			+ u32::from(bytes[3])
+			- 0x30;
+		match four_byte_code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(4)
+			}
+			None => Step::Malformed(4),
+		}
+	}
+}
+
+impl Stepper for Gb
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		if b < 0x80
+		{
+			output.push(char::from(b));
+			return Step::Decoded(1);
+		}
+		if self.variant == Variant::Gb2312
+		{
+			return self.step_gb2312(bytes, output);
+		}
+		if b == 0x80
+		{
+			output.push('\u{20AC}');
+			return Step::Decoded(1);
+		}
+		if b == 0xFF
+		{
+			return Step::Malformed(1);
+		}
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		let trail = bytes[1];
+		let trail_offset = match trail
+		{
+			0x30..=0x39 if self.variant == Variant::Gb18030 =>
+			{
+				return self.step_four_bytes(bytes, output);
+			}
+			0x40..=0x7E => 0x40,
+			0x80..=0xFE => 0x41,
+			_ => return Step::Malformed(1),
+		};
+		let pointer = usize::from(b - 0x81) * 190 + usize::from(trail - trail_offset);
+		match GB18030.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			// An ASCII trail byte is decoded again on its own.
+			None if trail < 0x80 => Step::Malformed(1),
+			None => Step::Malformed(2),
+		}
+	}
+}
+
+impl RawEncoder for Gb
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+/// HZ-GB-2312: GB 2312 in 7 bits, entered with `~{` and left with `~}`.
+pub(crate) struct Hz
+{
+	gb: bool,
+}
+
+impl Hz
+{
+	pub(crate) fn new() -> Self
+	{
+		Self { gb: false }
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if c.is_ascii()
+		{
+			if self.gb
+			{
+				output.extend_from_slice(b"~}");
+				self.gb = false;
+			}
+			if c == '~'
+			{
+				output.push(b'~');
+			}
+			output.push(c as u8);
+			return true;
+		}
+		match GB_2312.pointer(c)
+		{
+			Some(pointer) =>
+			{
+				if !self.gb
+				{
+					output.extend_from_slice(b"~{");
+					self.gb = true;
+				}
+				output
+					.extend_from_slice(&[0x21 + (pointer / 94) as u8, 0x21 + (pointer % 94) as u8]);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+impl Stepper for Hz
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		if b == b'~'
+		{
+			if bytes.len() < 2
+			{
+				return Step::Incomplete;
+			}
+			match bytes[1]
+			{
+				b'{' => self.gb = true,
+				b'}' => self.gb = false,
+				b'~' => output.push('~'),
+				// A line continuation.
+				b'\n' =>
+				{}
+				_ => return Step::Malformed(1),
+			}
+			return Step::Decoded(2);
+		}
+		if b >= 0x80
+		{
+			return Step::Malformed(1);
+		}
+		if !self.gb || b < 0x21 || b == 0x7F
+		{
+			output.push(char::from(b));
+			return Step::Decoded(1);
+		}
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		if !(0x21..=0x7E).contains(&bytes[1])
+		{
+			return Step::Malformed(1);
+		}
+		let pointer = usize::from(b - 0x21) * 94 + usize::from(bytes[1] - 0x21);
+		match GB_2312.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			None => Step::Malformed(2),
+		}
+	}
+}
+
+impl RawEncoder for Hz
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, output: &mut Vec<u8>)
+	{
+		if self.gb
+		{
+			output.extend_from_slice(b"~}");
+			self.gb = false;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_gb()
+	{
+		let codec = CharacterSetEnum::GB2312.codec().unwrap();
+		round_trip(codec, "中文a", b"\xD6\xD0\xCE\xC4a");
+		malformed(codec, b"a\xD6\x41", 1, b"\xD6");
+
+		let codec = CharacterSetEnum::GBK.codec().unwrap();
+		round_trip(codec, "中文€", b"\xD6\xD0\xCE\xC4\x80");
+		malformed(codec, b"a\xFF", 1, b"\xFF");
+
+		let codec = CharacterSetEnum::GB18030.codec().unwrap();
+		round_trip(
+			codec,
+			"中文€😀ǹ",
+			b"\xD6\xD0\xCE\xC4\xA2\xE3\x94\x39\xFC\x36\xA8\xBF",
+		);
+		malformed(codec, b"a\x81\x30\x81", 1, b"\x81\x30\x81");
+		malformed(codec, b"a\x84\x31\xA5\x30", 1, b"\x84\x31\xA5\x30");
+	}
+
+	#[test]
+	fn converts_hz()
+	{
+		let codec = CharacterSetEnum::HZ_GB_2312.codec().unwrap();
+		round_trip(codec, "中文~a", b"~{VPND~}~~a");
+		malformed(codec, b"~{VP~x", 4, b"~");
+	}
+}
@@ -0,0 +1,151 @@
+//! EBCDIC code pages.
+//!
+//! The tables follow IBM's mapping of byte 0x25 to U+000A and byte 0x15 to U+0085; swapping the
+//! two bytes gives the mapping of z/OS UNIX System Services, where 0x15 ends a line.
+//!
+//! OSD_EBCDIC_DF03_IRV, OSD_EBCDIC_DF04_1 and OSD_EBCDIC_DF04_15 are left out, as no reference
+//! mapping of them is available.
+use super::{
+	single_byte::SingleByte,
+	tables::Table,
+	EbcdicLineFeed,
+	RawDecodeError,
+	RawDecoder,
+	RawEncodeError,
+	RawEncoder,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+#[derive(Copy, Clone)]
+pub(crate) struct Ebcdic
+{
+	table: SingleByte,
+	line_feed: EbcdicLineFeed,
+}
+
+impl Ebcdic
+{
+	pub(crate) fn new(table: &'static Table<256>) -> Self
+	{
+		Self {
+			table: SingleByte::full(table),
+			line_feed: EbcdicLineFeed::Lf,
+		}
+	}
+
+	pub(crate) fn with_line_feed(self, line_feed: EbcdicLineFeed) -> Self
+	{
+		Self { line_feed, ..self }
+	}
+
+	/// Exchanges NL and LF if the line feed is NL; the mapping is its own inverse.
+	fn swap(&self, b: u8) -> u8
+	{
+		match (self.line_feed, b)
+		{
+			(EbcdicLineFeed::Nl, 0x15) => 0x25,
+			(EbcdicLineFeed::Nl, 0x25) => 0x15,
+			(_, b) => b,
+		}
+	}
+}
+
+impl RawDecoder for Ebcdic
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>)
+	{
+		for (i, &b) in input.iter().enumerate()
+		{
+			match self.table.decode_byte(self.swap(b))
+			{
+				Some(c) => output.push(c),
+				None => return (i, Some(RawDecodeError::new(i + 1))),
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut String) -> Option<RawDecodeError>
+	{
+		None
+	}
+}
+
+impl RawEncoder for Ebcdic
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for (i, c) in input.char_indices()
+		{
+			match self.table.encode_char(c)
+			{
+				Some(b) => output.push(self.swap(b)),
+				None =>
+				{
+					return (
+						i,
+						Some(RawEncodeError {
+							upto: i + c.len_utf8(),
+						}),
+					)
+				}
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::{
+			tests::{
+				malformed,
+				round_trip,
+			},
+			EbcdicLineFeed,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_ebcdic()
+	{
+		let codec = CharacterSetEnum::IBM037.codec().unwrap();
+		round_trip(codec, "Hi é\n\u{85}", b"\xC8\x89\x40\x51\x25\x15");
+		let codec = codec.with_ebcdic_line_feed(EbcdicLineFeed::Nl);
+		round_trip(codec, "Hi é\n\u{85}", b"\xC8\x89\x40\x51\x15\x25");
+
+		let codec = CharacterSetEnum::IBM290.codec().unwrap();
+		malformed(codec, b"\x41\x42\x80", 2, b"\x80");
+	}
+
+	#[test]
+	fn follows_ibm_where_glibc_differs()
+	{
+		let codec = CharacterSetEnum::IBM1026.codec().unwrap();
+		assert_eq!(codec.decode(b"\x9D\xBC"), "\u{B8}\u{AF}");
+		assert_eq!(codec.encode("\u{B8}\u{AF}").unwrap(), &b"\x9D\xBC"[..]);
+
+		let codec = CharacterSetEnum::IBM424.codec().unwrap();
+		assert_eq!(codec.decode(b"\x78\x8F"), "\u{2017}\u{B1}");
+		assert_eq!(codec.encode("\u{2017}\u{B1}").unwrap(), &b"\x78\x8F"[..]);
+	}
+
+	#[test]
+	fn encodes_ibm_thai_tone_marks_to_their_main_bytes()
+	{
+		let codec = CharacterSetEnum::IBM_THAI.codec().unwrap();
+		assert_eq!(codec.decode(b"\x42\x51\xED"), "\u{E01}\u{E48}\u{E48}");
+		assert_eq!(
+			codec.encode("\u{E48}\u{E49}\u{E4A}\u{E4B}\u{E4C}").unwrap(),
+			&b"\xED\xEE\xEF\xFA\xFB"[..]
+		);
+	}
+}
@@ -0,0 +1,271 @@
+//! `futures-io` adapters over `Decoder` and `Encoder`, enabled by the `futures-io` feature.
+use super::{
+	io::{
+		ReadState,
+		WriteState,
+		BUFFER_SIZE,
+	},
+	Decoder,
+	Encoder,
+};
+use ::futures_io::{
+	AsyncBufRead,
+	AsyncRead,
+	AsyncWrite,
+};
+use alloc::vec::Vec;
+use core::{
+	pin::Pin,
+	task::{
+		Context,
+		Poll,
+	},
+};
+use std::io;
+
+/// A reader that decodes the bytes of another reader and yields them as UTF-8.
+///
+/// Malformed or unmapped input is handled as the decoder's error mode says. In strict mode the
+/// error is returned, with `io::ErrorKind::InvalidData`, once the text before it is read, and
+/// again by every read after it.
+pub struct DecodingReader<R>
+{
+	inner: R,
+	buffer: Vec<u8>,
+	state: ReadState,
+}
+
+impl<R: AsyncRead + Unpin> DecodingReader<R>
+{
+	pub fn new(inner: R, decoder: Decoder) -> Self
+	{
+		Self {
+			inner,
+			buffer: vec![0; BUFFER_SIZE],
+			state: ReadState::new(decoder),
+		}
+	}
+
+	pub fn get_ref(&self) -> &R
+	{
+		&self.inner
+	}
+
+	pub fn get_mut(&mut self) -> &mut R
+	{
+		&mut self.inner
+	}
+
+	/// Returns the underlying reader; input it has produced but not yet decoded is lost.
+	pub fn into_inner(self) -> R
+	{
+		self.inner
+	}
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for DecodingReader<R>
+{
+	fn poll_read(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut [u8],
+	) -> Poll<io::Result<usize>>
+	{
+		let available = match self.as_mut().poll_fill_buf(cx)
+		{
+			Poll::Ready(Ok(available)) => available,
+			Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+			Poll::Pending => return Poll::Pending,
+		};
+		let n = available.len().min(buf.len());
+		buf[..n].copy_from_slice(&available[..n]);
+		self.consume(n);
+		Poll::Ready(Ok(n))
+	}
+}
+
+impl<R: AsyncRead + Unpin> AsyncBufRead for DecodingReader<R>
+{
+	fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>>
+	{
+		let this = self.get_mut();
+		let inner = &mut this.inner;
+		this.state.poll_fill_buf(&mut this.buffer, |buffer| {
+			Pin::new(&mut *inner).poll_read(cx, buffer)
+		})
+	}
+
+	fn consume(self: Pin<&mut Self>, amt: usize)
+	{
+		self.get_mut().state.consume(amt);
+	}
+}
+
+/// A writer that takes UTF-8 and writes it to another writer encoded in a character set.
+///
+/// UTF-8 sequences may be split between writes. Invalid UTF-8 fails with
+/// `io::ErrorKind::InvalidData`, as do characters the character set cannot represent in the
+/// encoder's strict mode. Encoded output is passed on before
+/// more input is accepted, and closing ends the output, which some character sets need to
+/// return to their initial shift state.
+pub struct EncodingWriter<W>
+{
+	inner: W,
+	state: WriteState,
+}
+
+impl<W: AsyncWrite + Unpin> EncodingWriter<W>
+{
+	pub fn new(inner: W, encoder: Encoder) -> Self
+	{
+		Self {
+			inner,
+			state: WriteState::new(encoder),
+		}
+	}
+
+	pub fn get_ref(&self) -> &W
+	{
+		&self.inner
+	}
+
+	pub fn get_mut(&mut self) -> &mut W
+	{
+		&mut self.inner
+	}
+
+	/// Returns the underlying writer; encoded output not yet written to it is lost.
+	pub fn into_inner(self) -> W
+	{
+		self.inner
+	}
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for EncodingWriter<W>
+{
+	fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8])
+		-> Poll<io::Result<usize>>
+	{
+		let this = self.get_mut();
+		let inner = &mut this.inner;
+		this.state
+			.poll_write(buf, |encoded| Pin::new(&mut *inner).poll_write(cx, encoded))
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>
+	{
+		let this = self.get_mut();
+		let inner = &mut this.inner;
+		match this
+			.state
+			.poll_write_encoded(|encoded| Pin::new(&mut *inner).poll_write(cx, encoded))
+		{
+			Poll::Ready(Ok(())) => Pin::new(inner).poll_flush(cx),
+			poll => poll,
+		}
+	}
+
+	fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>
+	{
+		let this = self.get_mut();
+		let inner = &mut this.inner;
+		match this
+			.state
+			.poll_finish(|encoded| Pin::new(&mut *inner).poll_write(cx, encoded))
+		{
+			Poll::Ready(Ok(())) => Pin::new(inner).poll_close(cx),
+			poll => poll,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::{
+		super::io::tests::{
+			block_on,
+			Trickle,
+		},
+		*,
+	};
+	use crate::CharacterSetEnum;
+
+	impl AsyncRead for Trickle
+	{
+		fn poll_read(
+			self: Pin<&mut Self>,
+			cx: &mut Context<'_>,
+			buf: &mut [u8],
+		) -> Poll<io::Result<usize>>
+		{
+			let this = self.get_mut();
+			if this.pending(cx)
+			{
+				return Poll::Pending;
+			}
+			Poll::Ready(Ok(this.read_into(buf)))
+		}
+	}
+
+	impl AsyncWrite for Trickle
+	{
+		fn poll_write(
+			self: Pin<&mut Self>,
+			cx: &mut Context<'_>,
+			buf: &[u8],
+		) -> Poll<io::Result<usize>>
+		{
+			let this = self.get_mut();
+			if this.pending(cx)
+			{
+				return Poll::Pending;
+			}
+			Poll::Ready(Ok(this.write_from(buf)))
+		}
+
+		fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>>
+		{
+			Poll::Ready(Ok(()))
+		}
+
+		fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>>
+		{
+			Poll::Ready(Ok(()))
+		}
+	}
+
+	#[test]
+	fn reads_through_pending_and_short_reads()
+	{
+		let decoder = CharacterSetEnum::SHIFT_JIS.new_decoder().unwrap();
+		let mut reader = DecodingReader::new(Trickle::new(b"\x93\xFA\x96\x7Ba"), decoder);
+		let mut text = Vec::new();
+		loop
+		{
+			let mut buf = [0; 2];
+			let n = block_on(|cx| Pin::new(&mut reader).poll_read(cx, &mut buf)).unwrap();
+			if n == 0
+			{
+				break;
+			}
+			text.extend_from_slice(&buf[..n]);
+		}
+		assert_eq!(text, "日本a".as_bytes());
+	}
+
+	#[test]
+	fn writes_through_pending_and_short_writes()
+	{
+		let encoder = CharacterSetEnum::ISO_2022_JP.new_encoder().unwrap();
+		let mut writer = EncodingWriter::new(Trickle::default(), encoder);
+		let mut input = "日本".as_bytes();
+		while !input.is_empty()
+		{
+			let n = block_on(|cx| Pin::new(&mut writer).poll_write(cx, &input[..2])).unwrap();
+			input = &input[n..];
+		}
+		block_on(|cx| Pin::new(&mut writer).poll_close(cx)).unwrap();
+		assert_eq!(writer.get_ref().bytes, b"\x1B$BF|K\\\x1B(B");
+	}
+}
@@ -0,0 +1,677 @@
+//! `std::io` adapters over `Decoder` and `Encoder`, and the buffering they share with the
+//! asynchronous ones.
+use super::{
+	DecodeError,
+	Decoder,
+	EncodeError,
+	Encoder,
+};
+use alloc::{
+	borrow::Cow,
+	string::String,
+	vec::Vec,
+};
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+use core::task::Poll;
+use std::io::{
+	self,
+	BufRead,
+	Read,
+	Write,
+};
+
+/// Size of the buffer the reading adapters read the underlying reader into.
+pub(crate) const BUFFER_SIZE: usize = 8 * 1024;
+
+/// Decoded text waiting to be read.
+pub(crate) struct ReadState
+{
+	decoder: Decoder,
+	/// Decoded text not yet read, from `position` on.
+	decoded: String,
+	position: usize,
+	/// Whether the underlying reader has reached its end and the decoder was flushed.
+	done: bool,
+	/// An error in strict mode, returned once the text decoded before it is read and on every
+	/// read after; the input after it is never decoded.
+	error: Option<DecodeError>,
+}
+
+impl ReadState
+{
+	pub(crate) fn new(decoder: Decoder) -> Self
+	{
+		Self {
+			decoder,
+			decoded: String::new(),
+			position: 0,
+			done: false,
+			error: None,
+		}
+	}
+
+	/// Whether the underlying reader has to be read before there is anything to return. A read
+	/// may decode to nothing, such as an escape sequence, so this can hold after a `feed`.
+	pub(crate) fn needs_input(&self) -> bool
+	{
+		self.position == self.decoded.len() && !self.done && self.error.is_none()
+	}
+
+	/// Decodes bytes read from the underlying reader, where no bytes mean its end.
+	pub(crate) fn feed(&mut self, input: &[u8])
+	{
+		self.decoded.clear();
+		self.position = 0;
+		if let Err(error) =
+			self.decoder
+				.decode_to_string(input, &mut self.decoded, input.is_empty())
+		{
+			self.error = Some(error);
+		}
+		self.done = input.is_empty();
+	}
+
+	/// Returns the error of strict mode once nothing decoded before it is left.
+	pub(crate) fn check(&self) -> io::Result<()>
+	{
+		match self.error
+		{
+			Some(error) if self.position == self.decoded.len() =>
+			{
+				Err(io::Error::new(io::ErrorKind::InvalidData, error))
+			}
+			_ => Ok(()),
+		}
+	}
+
+	pub(crate) fn available(&self) -> &[u8]
+	{
+		&self.decoded.as_bytes()[self.position..]
+	}
+
+	pub(crate) fn consume(&mut self, amt: usize)
+	{
+		self.position = (self.position + amt).min(self.decoded.len());
+	}
+
+	/// Copies as much of the available text as fits into `buf`.
+	pub(crate) fn read_into(&mut self, buf: &mut [u8]) -> usize
+	{
+		let available = self.available();
+		let n = available.len().min(buf.len());
+		buf[..n].copy_from_slice(&available[..n]);
+		self.consume(n);
+		n
+	}
+
+	/// `poll_fill_buf` of the asynchronous readers, where `read` polls the underlying reader to
+	/// read into `buffer` and returns how many bytes it read.
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	pub(crate) fn poll_fill_buf(
+		&mut self,
+		buffer: &mut [u8],
+		mut read: impl FnMut(&mut [u8]) -> Poll<io::Result<usize>>,
+	) -> Poll<io::Result<&[u8]>>
+	{
+		while self.needs_input()
+		{
+			match read(buffer)
+			{
+				Poll::Ready(Ok(n)) => self.feed(&buffer[..n]),
+				Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+		self.check()?;
+		Poll::Ready(Ok(self.available()))
+	}
+}
+
+/// Encoded output waiting to be written.
+pub(crate) struct WriteState
+{
+	encoder: Encoder,
+	/// The start of a UTF-8 sequence split by the previous write.
+	partial: Vec<u8>,
+	/// Encoded bytes not yet written to the underlying writer.
+	encoded: Vec<u8>,
+	finished: bool,
+	/// An error in strict mode, returned by every write after the one that consumed the input
+	/// before it; the input after it is never encoded.
+	error: Option<EncodeError>,
+}
+
+impl WriteState
+{
+	pub(crate) fn new(encoder: Encoder) -> Self
+	{
+		Self {
+			encoder,
+			partial: Vec::new(),
+			encoded: Vec::new(),
+			finished: false,
+			error: None,
+		}
+	}
+
+	pub(crate) fn encoded(&self) -> &[u8]
+	{
+		&self.encoded
+	}
+
+	pub(crate) fn advance(&mut self, n: usize)
+	{
+		self.encoded.drain(..n);
+	}
+
+	/// Returns the error of strict mode once the input before it has been consumed.
+	fn check(&self) -> io::Result<()>
+	{
+		match self.error
+		{
+			Some(error) => Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+			None => Ok(()),
+		}
+	}
+
+	/// Encodes as much of `buf` as possible and returns how many bytes of it were consumed,
+	/// holding back a UTF-8 sequence split at its end. In strict mode, the input before a
+	/// character the character set cannot represent is consumed and the error is returned by the
+	/// next call.
+	pub(crate) fn encode(&mut self, buf: &[u8]) -> io::Result<usize>
+	{
+		self.check()?;
+		let held = self.partial.len();
+		let input = if held == 0
+		{
+			Cow::Borrowed(buf)
+		}
+		else
+		{
+			let mut input = self.partial.clone();
+			input.extend_from_slice(buf);
+			Cow::Owned(input)
+		};
+		let (valid, incomplete) = match core::str::from_utf8(&input)
+		{
+			Ok(valid) => (valid, false),
+			Err(error) =>
+			{
+				if error.valid_up_to() == 0 && error.error_len().is_some()
+				{
+					return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+				}
+				// Only checked up to `valid_up_to`.
+				let valid = core::str::from_utf8(&input[..error.valid_up_to()]).unwrap_or_default();
+				(valid, error.error_len().is_none())
+			}
+		};
+		self.partial.clear();
+		let start = self.encoder.position;
+		if let Err(error) = self.encoder.encode_to_vec(valid, &mut self.encoded, false)
+		{
+			self.error = Some(error);
+			// Nothing of `buf` is consumed if the character was completed from `partial`.
+			let consumed = (error.offset() - start).saturating_sub(held);
+			if consumed == 0
+			{
+				return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+			}
+			return Ok(consumed);
+		}
+		if incomplete
+		{
+			self.partial = input[valid.len()..].to_vec();
+			Ok(buf.len())
+		}
+		else
+		{
+			Ok(valid.len() - held)
+		}
+	}
+
+	/// Encodes the end of the output, once. Fails if the input ends inside a UTF-8 sequence.
+	pub(crate) fn finish(&mut self) -> io::Result<()>
+	{
+		if self.finished
+		{
+			return Ok(());
+		}
+		self.check()?;
+		if !self.partial.is_empty()
+		{
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				"the input ends inside a UTF-8 sequence",
+			));
+		}
+		self.encoder
+			.encode_to_vec("", &mut self.encoded, true)
+			.map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+		self.finished = true;
+		Ok(())
+	}
+
+	/// Passes the encoded output on with `write`, which polls the underlying writer and returns
+	/// how many bytes it took.
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	pub(crate) fn poll_write_encoded(
+		&mut self,
+		mut write: impl FnMut(&[u8]) -> Poll<io::Result<usize>>,
+	) -> Poll<io::Result<()>>
+	{
+		while !self.encoded.is_empty()
+		{
+			match write(&self.encoded)
+			{
+				Poll::Ready(Ok(0)) => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+				Poll::Ready(Ok(n)) => self.advance(n),
+				Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+		Poll::Ready(Ok(()))
+	}
+
+	/// `poll_write` of the asynchronous writers: passes the output encoded before on, then
+	/// encodes `buf`.
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	pub(crate) fn poll_write(
+		&mut self,
+		buf: &[u8],
+		mut write: impl FnMut(&[u8]) -> Poll<io::Result<usize>>,
+	) -> Poll<io::Result<usize>>
+	{
+		match self.poll_write_encoded(&mut write)
+		{
+			Poll::Ready(Ok(())) =>
+			{}
+			Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+			Poll::Pending => return Poll::Pending,
+		}
+		let consumed = self.encode(buf)?;
+		// The input is consumed now; a failure to pass it on is reported by the next call.
+		let _ = self.poll_write_encoded(write);
+		Poll::Ready(Ok(consumed))
+	}
+
+	/// Ends the output and passes it on, before the underlying writer is shut down.
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	pub(crate) fn poll_finish(
+		&mut self,
+		write: impl FnMut(&[u8]) -> Poll<io::Result<usize>>,
+	) -> Poll<io::Result<()>>
+	{
+		self.finish()?;
+		self.poll_write_encoded(write)
+	}
+}
+
+/// A reader that decodes the bytes of another reader and yields them as UTF-8.
+///
+/// Malformed or unmapped input is handled as the decoder's error mode says. In strict mode the
+/// error is returned, with `io::ErrorKind::InvalidData`, once the text before it is read, and
+/// again by every read after it.
+pub struct DecodingReader<R>
+{
+	inner: R,
+	buffer: Vec<u8>,
+	state: ReadState,
+}
+
+impl<R: Read> DecodingReader<R>
+{
+	pub fn new(inner: R, decoder: Decoder) -> Self
+	{
+		Self {
+			inner,
+			buffer: vec![0; BUFFER_SIZE],
+			state: ReadState::new(decoder),
+		}
+	}
+
+	pub fn get_ref(&self) -> &R
+	{
+		&self.inner
+	}
+
+	pub fn get_mut(&mut self) -> &mut R
+	{
+		&mut self.inner
+	}
+
+	/// Returns the underlying reader; input it has produced but not yet decoded is lost.
+	pub fn into_inner(self) -> R
+	{
+		self.inner
+	}
+}
+
+impl<R: Read> Read for DecodingReader<R>
+{
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+	{
+		self.fill_buf()?;
+		Ok(self.state.read_into(buf))
+	}
+}
+
+impl<R: Read> BufRead for DecodingReader<R>
+{
+	fn fill_buf(&mut self) -> io::Result<&[u8]>
+	{
+		while self.state.needs_input()
+		{
+			let n = self.inner.read(&mut self.buffer)?;
+			self.state.feed(&self.buffer[..n]);
+		}
+		self.state.check()?;
+		Ok(self.state.available())
+	}
+
+	fn consume(&mut self, amt: usize)
+	{
+		self.state.consume(amt);
+	}
+}
+
+/// A writer that takes UTF-8 and writes it to another writer encoded in a character set.
+///
+/// UTF-8 sequences may be split between writes. Invalid UTF-8 fails with
+/// `io::ErrorKind::InvalidData`, as do characters the character set cannot represent in the
+/// encoder's strict mode, where the write that reaches one takes the input before it and the
+/// error is returned by every write after. `finish` must be called to end the
+/// output, which some character sets need to return to their initial shift state.
+pub struct EncodingWriter<W>
+{
+	inner: W,
+	state: WriteState,
+}
+
+impl<W: Write> EncodingWriter<W>
+{
+	pub fn new(inner: W, encoder: Encoder) -> Self
+	{
+		Self {
+			inner,
+			state: WriteState::new(encoder),
+		}
+	}
+
+	pub fn get_ref(&self) -> &W
+	{
+		&self.inner
+	}
+
+	pub fn get_mut(&mut self) -> &mut W
+	{
+		&mut self.inner
+	}
+
+	/// Ends the output, flushes it and returns the underlying writer. Fails if the input ends
+	/// inside a UTF-8 sequence.
+	pub fn finish(mut self) -> io::Result<W>
+	{
+		self.state.finish()?;
+		self.write_encoded()?;
+		self.inner.flush()?;
+		Ok(self.inner)
+	}
+
+	fn write_encoded(&mut self) -> io::Result<()>
+	{
+		while !self.state.encoded().is_empty()
+		{
+			match self.inner.write(self.state.encoded())
+			{
+				Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+				Ok(n) => self.state.advance(n),
+				Err(error) if error.kind() == io::ErrorKind::Interrupted =>
+				{}
+				Err(error) => return Err(error),
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<W: Write> Write for EncodingWriter<W>
+{
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+	{
+		self.write_encoded()?;
+		let consumed = self.state.encode(buf)?;
+		// The input is consumed now; a failure to pass it on is reported by the next call.
+		let _ = self.write_encoded();
+		Ok(consumed)
+	}
+
+	fn flush(&mut self) -> io::Result<()>
+	{
+		self.write_encoded()?;
+		self.inner.flush()
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod tests
+{
+	use super::*;
+	use crate::{
+		codec::ErrorMode,
+		CharacterSetEnum,
+	};
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	use alloc::{
+		sync::Arc,
+		task::Wake,
+	};
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	use core::task::{
+		Context,
+		Waker,
+	};
+
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	struct NoopWaker;
+
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	impl Wake for NoopWaker
+	{
+		fn wake(self: Arc<Self>) {}
+	}
+
+	/// Polls `poll` until it is ready.
+	#[cfg(any(feature = "tokio", feature = "futures-io"))]
+	pub(crate) fn block_on<T>(mut poll: impl FnMut(&mut Context<'_>) -> Poll<T>) -> T
+	{
+		let waker = Waker::from(Arc::new(NoopWaker));
+		let mut cx = Context::from_waker(&waker);
+		loop
+		{
+			if let Poll::Ready(value) = poll(&mut cx)
+			{
+				return value;
+			}
+		}
+	}
+
+	/// A reader and writer that is interrupted, or pending, on every other call and otherwise
+	/// reads or writes a single byte.
+	#[derive(Default)]
+	pub(crate) struct Trickle
+	{
+		pub(crate) bytes: Vec<u8>,
+		ready: bool,
+	}
+
+	impl Trickle
+	{
+		pub(crate) fn new(bytes: &[u8]) -> Self
+		{
+			Self {
+				bytes: bytes.to_vec(),
+				ready: false,
+			}
+		}
+
+		/// Returns whether the call is interrupted.
+		fn interrupted(&mut self) -> bool
+		{
+			self.ready = !self.ready;
+			!self.ready
+		}
+
+		/// Returns whether the call is pending, waking the task if so.
+		#[cfg(any(feature = "tokio", feature = "futures-io"))]
+		pub(crate) fn pending(&mut self, cx: &mut Context<'_>) -> bool
+		{
+			let pending = self.interrupted();
+			if pending
+			{
+				cx.waker().wake_by_ref();
+			}
+			pending
+		}
+
+		/// Moves at most one byte from the front of `bytes` to `buf`.
+		pub(crate) fn read_into(&mut self, buf: &mut [u8]) -> usize
+		{
+			let n = self.bytes.len().min(buf.len()).min(1);
+			buf[..n].copy_from_slice(&self.bytes[..n]);
+			self.bytes.drain(..n);
+			n
+		}
+
+		/// Appends at most one byte of `buf` to `bytes`.
+		pub(crate) fn write_from(&mut self, buf: &[u8]) -> usize
+		{
+			let n = buf.len().min(1);
+			self.bytes.extend_from_slice(&buf[..n]);
+			n
+		}
+	}
+
+	impl Read for Trickle
+	{
+		fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+		{
+			if self.interrupted()
+			{
+				return Err(io::ErrorKind::Interrupted.into());
+			}
+			Ok(self.read_into(buf))
+		}
+	}
+
+	impl Write for Trickle
+	{
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize>
+		{
+			if self.interrupted()
+			{
+				return Err(io::ErrorKind::Interrupted.into());
+			}
+			Ok(self.write_from(buf))
+		}
+
+		fn flush(&mut self) -> io::Result<()>
+		{
+			Ok(())
+		}
+	}
+
+	/// Returns one chunk per read.
+	struct Chunks(Vec<&'static [u8]>);
+
+	impl Read for Chunks
+	{
+		fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>
+		{
+			if self.0.is_empty()
+			{
+				return Ok(0);
+			}
+			let chunk = self.0.remove(0);
+			buf[..chunk.len()].copy_from_slice(chunk);
+			Ok(chunk.len())
+		}
+	}
+
+	#[test]
+	fn stops_at_a_strict_error()
+	{
+		let decoder = CharacterSetEnum::UTF_8
+			.new_decoder()
+			.unwrap()
+			.with_error_mode(ErrorMode::Strict);
+		let mut reader = DecodingReader::new(Chunks(vec![b"a\xFF", b"b"]), decoder);
+		let mut buf = [0; 8];
+		assert_eq!(reader.read(&mut buf).unwrap(), 1);
+		assert_eq!(buf[0], b'a');
+		for _ in 0..2
+		{
+			let error = reader.read(&mut buf).unwrap_err();
+			assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+		}
+		assert_eq!(reader.get_ref().0.len(), 1);
+	}
+
+	#[test]
+	fn reads_through_interrupted_and_short_reads()
+	{
+		let decoder = CharacterSetEnum::SHIFT_JIS.new_decoder().unwrap();
+		let mut reader = DecodingReader::new(Trickle::new(b"\x93\xFA\x96\x7Ba"), decoder);
+		let mut text = String::new();
+		reader.read_to_string(&mut text).unwrap();
+		assert_eq!(text, "日本a");
+	}
+
+	fn writer(charset: CharacterSetEnum) -> EncodingWriter<Trickle>
+	{
+		EncodingWriter::new(Trickle::default(), charset.new_encoder().unwrap())
+	}
+
+	#[test]
+	fn writes_utf_8_split_between_writes()
+	{
+		let mut writer = writer(CharacterSetEnum::SHIFT_JIS);
+		for byte in "日本a".as_bytes().chunks(1)
+		{
+			writer.write_all(byte).unwrap();
+		}
+		assert_eq!(writer.finish().unwrap().bytes, b"\x93\xFA\x96\x7Ba");
+	}
+
+	#[test]
+	fn rejects_invalid_utf_8()
+	{
+		let mut writer = writer(CharacterSetEnum::SHIFT_JIS);
+		assert_eq!(writer.write(b"a\xFFb").unwrap(), 1);
+		let error = writer.write(b"\xFFb").unwrap_err();
+		assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+		assert_eq!(writer.get_ref().bytes, b"a");
+	}
+
+	#[test]
+	fn finish_returns_to_the_initial_shift_state()
+	{
+		let mut writer = writer(CharacterSetEnum::ISO_2022_JP);
+		writer.write_all("日本".as_bytes()).unwrap();
+		assert_eq!(writer.get_ref().bytes, b"\x1B$BF|K\\");
+		assert_eq!(writer.finish().unwrap().bytes, b"\x1B$BF|K\\\x1B(B");
+	}
+
+	#[test]
+	fn writes_the_input_before_a_strict_error()
+	{
+		let mut writer = writer(CharacterSetEnum::ISO_2022_JP);
+		assert_eq!(writer.write("a日€b".as_bytes()).unwrap(), 4);
+		for _ in 0..2
+		{
+			let error = writer.write("€b".as_bytes()).unwrap_err();
+			assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+		}
+		assert_eq!(writer.get_ref().bytes, b"a\x1B$BF|\x1B(B");
+	}
+}
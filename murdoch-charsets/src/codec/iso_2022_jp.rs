@@ -0,0 +1,471 @@
+//! ISO-2022-JP (RFC 1468), ISO-2022-JP-2 (RFC 1554) and CP50220.
+//!
+//! The decoder follows the escape sequences designating G0 and, for ISO-2022-JP-2, G2. The
+//! encoder switches back to ASCII before every line end and at the end of the text.
+use super::{
+	encode_chars,
+	japanese::windows_31j_jis_pointer,
+	single_byte::SingleByte,
+	tables::{
+		chinese::GB_2312,
+		japanese::{
+			JIS_X_0208,
+			JIS_X_0212,
+			WINDOWS_31J,
+		},
+		korean::KS_X_1001,
+		single_byte::ISO_8859_7,
+		Index,
+	},
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Variant
+{
+	Jp,
+	Jp2,
+	/// Microsoft's variant: Windows-31J's JIS X 0208 extensions, halfwidth katakana encoded as
+	/// fullwidth, and SO/SI or 8-bit halfwidth katakana accepted when decoding.
+	Cp50220,
+}
+
+/// Character sets that can be designated to G0.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum G0
+{
+	Ascii,
+	Roman,
+	Katakana,
+	JisX0208,
+	JisX0212,
+	Gb2312,
+	KsC5601,
+}
+
+/// Character sets that can be designated to G2 and invoked with a single shift.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum G2
+{
+	Latin1,
+	Greek,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Designation
+{
+	G0(G0),
+	G2(G2),
+}
+
+/// Escape sequences; the first one listed for a designation is the one the encoder writes.
+const ESCAPES: &[(&[u8], Designation)] = &[
+	(b"\x1B(B", Designation::G0(G0::Ascii)),
+	(b"\x1B(J", Designation::G0(G0::Roman)),
+	(b"\x1B(I", Designation::G0(G0::Katakana)),
+	(b"\x1B$B", Designation::G0(G0::JisX0208)),
+	(b"\x1B$@", Designation::G0(G0::JisX0208)),
+	(b"\x1B$(D", Designation::G0(G0::JisX0212)),
+	(b"\x1B$A", Designation::G0(G0::Gb2312)),
+	(b"\x1B$(C", Designation::G0(G0::KsC5601)),
+	(b"\x1B.A", Designation::G2(G2::Latin1)),
+	(b"\x1B.F", Designation::G2(G2::Greek)),
+];
+
+/// Single shift 2, invoking G2 for the next byte.
+const SS2: &[u8] = b"\x1BN";
+
+/// Fullwidth forms of the halfwidth katakana U+FF61 to U+FF9F, which are encoded in their place.
+const FULLWIDTH_KATAKANA: [u16; 63] = [
+	0x3002, 0x300C, 0x300D, 0x3001, 0x30FB, 0x30F2, 0x30A1, 0x30A3, 0x30A5, 0x30A7, 0x30A9, 0x30E3,
+	0x30E5, 0x30E7, 0x30C3, 0x30FC, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF,
+	0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8,
+	0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF,
+	0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF,
+	0x30F3, 0x309B, 0x309C,
+];
+
+pub(crate) struct Iso2022Jp
+{
+	variant: Variant,
+	g0: G0,
+	g2: Option<G2>,
+	/// Whether SO has invoked halfwidth katakana.
+	shifted: bool,
+}
+
+impl Iso2022Jp
+{
+	pub(crate) fn new(variant: Variant) -> Self
+	{
+		Self {
+			variant,
+			g0: G0::Ascii,
+			g2: None,
+			shifted: false,
+		}
+	}
+
+	fn supports(&self, designation: Designation) -> bool
+	{
+		self.variant == Variant::Jp2
+			|| matches!(
+				designation,
+				Designation::G0(G0::Ascii)
+					| Designation::G0(G0::Roman)
+					| Designation::G0(G0::Katakana)
+					| Designation::G0(G0::JisX0208)
+			)
+	}
+
+	fn index(&self, g0: G0) -> Option<&'static Index>
+	{
+		match g0
+		{
+			G0::JisX0208 if self.variant == Variant::Cp50220 => Some(&WINDOWS_31J),
+			G0::JisX0208 => Some(&JIS_X_0208),
+			G0::JisX0212 => Some(&JIS_X_0212),
+			G0::Gb2312 => Some(&GB_2312),
+			G0::KsC5601 => Some(&KS_X_1001),
+			G0::Ascii | G0::Roman | G0::Katakana => None,
+		}
+	}
+
+	fn escape(&mut self, bytes: &[u8]) -> Step
+	{
+		if let Some(&(escape, designation)) = ESCAPES.iter().find(|e| bytes.starts_with(e.0))
+		{
+			if !self.supports(designation)
+			{
+				return Step::Malformed(escape.len());
+			}
+			match designation
+			{
+				Designation::G0(g0) => self.g0 = g0,
+				Designation::G2(g2) => self.g2 = Some(g2),
+			}
+			return Step::Decoded(escape.len());
+		}
+		let incomplete = ESCAPES
+			.iter()
+			.map(|e| e.0)
+			.chain(Some(SS2))
+			.any(|e| e.len() > bytes.len() && e.starts_with(bytes));
+		if incomplete
+		{
+			Step::Incomplete
+		}
+		else
+		{
+			Step::Malformed(1)
+		}
+	}
+
+	fn single_shift(&self, bytes: &[u8], output: &mut String) -> Step
+	{
+		if bytes.len() < 3
+		{
+			return Step::Incomplete;
+		}
+		if !(0x20..=0x7F).contains(&bytes[2])
+		{
+			return Step::Malformed(2);
+		}
+		let b = bytes[2] | 0x80;
+		let c = match self.g2
+		{
+			Some(G2::Latin1) => char::from(b),
+			Some(G2::Greek) =>
+			{
+				match SingleByte::high(&ISO_8859_7).decode_byte(b)
+				{
+					Some(c) => c,
+					None => return Step::Malformed(3),
+				}
+			}
+			_ => return Step::Malformed(3),
+		};
+		output.push(c);
+		Step::Decoded(3)
+	}
+
+	fn designate(&mut self, g0: G0, output: &mut Vec<u8>)
+	{
+		if self.g0 != g0
+		{
+			if let Some(&(escape, _)) = ESCAPES.iter().find(|e| e.1 == Designation::G0(g0))
+			{
+				output.extend_from_slice(escape);
+			}
+			self.g0 = g0;
+		}
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		match c
+		{
+			'\x0E' | '\x0F' | '\x1B' => return false,
+			'\r' | '\n' =>
+			{
+				self.designate(G0::Ascii, output);
+				self.g2 = None;
+				output.push(c as u8);
+				return true;
+			}
+			'\\' | '~' =>
+			{
+				self.designate(G0::Ascii, output);
+				output.push(c as u8);
+				return true;
+			}
+			c if c.is_ascii() =>
+			{
+				if self.g0 != G0::Roman
+				{
+					self.designate(G0::Ascii, output);
+				}
+				output.push(c as u8);
+				return true;
+			}
+			'\u{A5}' | '\u{203E}' =>
+			{
+				self.designate(G0::Roman, output);
+				output.push(if c == '\u{A5}' { 0x5C } else { 0x7E });
+				return true;
+			}
+			_ =>
+			{}
+		}
+
+		let c = match u32::from(c)
+		{
+			c @ 0xFF61..=0xFF9F =>
+			{
+				char::from_u32(u32::from(FULLWIDTH_KATAKANA[c as usize - 0xFF61]))
+					.unwrap_or_default()
+			}
+			_ => c,
+		};
+		for &g0 in &[G0::JisX0208, G0::JisX0212, G0::Gb2312, G0::KsC5601]
+		{
+			if !self.supports(Designation::G0(g0))
+			{
+				continue;
+			}
+			let index = match self.index(g0)
+			{
+				Some(index) => index,
+				None => continue,
+			};
+			let pointer = if self.variant == Variant::Cp50220
+			{
+				windows_31j_jis_pointer(c)
+			}
+			else
+			{
+				index.pointer(c)
+			};
+			if let Some(pointer) = pointer
+			{
+				self.designate(g0, output);
+				output
+					.extend_from_slice(&[0x21 + (pointer / 94) as u8, 0x21 + (pointer % 94) as u8]);
+				return true;
+			}
+		}
+
+		if self.variant == Variant::Jp2
+		{
+			let g2 = match u32::from(c)
+			{
+				c @ 0xA0..=0xFF => Some((G2::Latin1, c as u8)),
+				_ =>
+				{
+					SingleByte::high(&ISO_8859_7)
+						.encode_char(c)
+						.filter(|&b| b >= 0xA0)
+						.map(|b| (G2::Greek, b))
+				}
+			};
+			if let Some((g2, b)) = g2
+			{
+				if self.g2 != Some(g2)
+				{
+					if let Some(&(escape, _)) = ESCAPES.iter().find(|e| e.1 == Designation::G2(g2))
+					{
+						output.extend_from_slice(escape);
+					}
+					self.g2 = Some(g2);
+				}
+				output.extend_from_slice(SS2);
+				output.push(b & 0x7F);
+				return true;
+			}
+		}
+		false
+	}
+}
+
+impl Stepper for Iso2022Jp
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		if b == 0x1B
+		{
+			if bytes.starts_with(SS2)
+			{
+				return self.single_shift(bytes, output);
+			}
+			return self.escape(bytes);
+		}
+
+		let katakana = |b: u8| char::from_u32(0xFF61 + u32::from(b) - 0x21);
+		if self.variant == Variant::Cp50220
+		{
+			match b
+			{
+				0x0E | 0x0F =>
+				{
+					self.shifted = b == 0x0E;
+					return Step::Decoded(1);
+				}
+				0x21..=0x5F if self.shifted =>
+				{
+					output.extend(katakana(b));
+					return Step::Decoded(1);
+				}
+				0xA1..=0xDF =>
+				{
+					output.extend(katakana(b - 0x80));
+					return Step::Decoded(1);
+				}
+				_ =>
+				{}
+			}
+		}
+		match b
+		{
+			0x0E | 0x0F | 0x80..=0xFF => return Step::Malformed(1),
+			0x00..=0x20 | 0x7F =>
+			{
+				output.push(char::from(b));
+				return Step::Decoded(1);
+			}
+			_ =>
+			{}
+		}
+
+		match self.g0
+		{
+			G0::Ascii => output.push(char::from(b)),
+			G0::Roman =>
+			{
+				output.push(match b
+				{
+					0x5C => '\u{A5}',
+					0x7E => '\u{203E}',
+					b => char::from(b),
+				})
+			}
+			G0::Katakana if b <= 0x5F => output.extend(katakana(b)),
+			G0::Katakana => return Step::Malformed(1),
+			g0 =>
+			{
+				if bytes.len() < 2
+				{
+					return Step::Incomplete;
+				}
+				if !(0x21..=0x7E).contains(&bytes[1])
+				{
+					return Step::Malformed(1);
+				}
+				let pointer = usize::from(b - 0x21) * 94 + usize::from(bytes[1] - 0x21);
+				match self.index(g0).and_then(|index| index.code_point(pointer))
+				{
+					Some(c) => output.push(c),
+					None => return Step::Malformed(2),
+				}
+				return Step::Decoded(2);
+			}
+		}
+		Step::Decoded(1)
+	}
+}
+
+impl RawEncoder for Iso2022Jp
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, output: &mut Vec<u8>)
+	{
+		self.designate(G0::Ascii, output);
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+	use alloc::vec::Vec;
+
+	#[test]
+	fn converts_iso_2022_jp()
+	{
+		let codec = CharacterSetEnum::ISO_2022_JP.codec().unwrap();
+		round_trip(codec, "a日本\nｂ", b"a\x1B$BF|K\\\x1B(B\n\x1B$B#b\x1B(B");
+		malformed(codec, b"a\x1B$BF\x1B(B", 4, b"F");
+		malformed(codec, b"a\x1B(Zb", 1, b"\x1B");
+	}
+
+	#[test]
+	fn converts_iso_2022_jp_2()
+	{
+		let codec = CharacterSetEnum::ISO_2022_JP_2.codec().unwrap();
+		round_trip(
+			codec,
+			"aé€ΑШ한",
+			b"a\x1B$(D+1\x1B$(C\"f\x1B$B&!':\x1B$(CGQ\x1B(B",
+		);
+		assert_eq!(codec.decode(b"\x1B.A\x1BNa"), "á");
+	}
+
+	#[test]
+	fn encodes_half_width_katakana_as_full_width_in_cp50220()
+	{
+		let codec = CharacterSetEnum::CP50220.codec().unwrap();
+		assert_eq!(codec.encode("aｱ").unwrap(), &b"a\x1B$B%\"\x1B(B"[..]);
+		round_trip(codec, "aア", b"a\x1B$B%\"\x1B(B");
+	}
+
+	#[test]
+	fn returns_to_ascii_before_a_strict_error()
+	{
+		let mut encoder = CharacterSetEnum::ISO_2022_JP.new_encoder().unwrap();
+		let mut encoded = Vec::new();
+		let error = encoder
+			.encode_to_vec("日本€", &mut encoded, false)
+			.unwrap_err();
+		assert_eq!((error.character(), error.offset()), ('€', 6));
+		assert_eq!(encoded, b"\x1B$BF|K\\\x1B(B");
+		encoder.encode_to_vec("ab", &mut encoded, true).unwrap();
+		assert_eq!(encoder.codec().decode(&encoded), "日本ab");
+	}
+}
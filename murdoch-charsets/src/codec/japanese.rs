@@ -0,0 +1,382 @@
+//! Shift_JIS, Windows-31J, EUC-JP and CP51932.
+//!
+//! `Shift_JIS` and `EUC-JP` follow JIS X 0208 (and JIS X 0212 for EUC-JP) as mapped by Unicode,
+//! encoding U+00A5 and U+203E to their JIS X 0201 Roman bytes one way. `Windows-31J` and
+//! `CP51932` follow Microsoft's tables instead, with the NEC and IBM extensions, and decode bytes
+//! without a character the way Windows does.
+use super::{
+	encode_chars,
+	tables::{
+		japanese::{
+			JIS_X_0208,
+			JIS_X_0212,
+			WINDOWS_31J,
+		},
+		Index,
+	},
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// Windows-31J single bytes outside ASCII and halfwidth katakana, kept for round trips.
+const WINDOWS_SINGLE_BYTES: [(u8, char); 5] = [
+	(0x80, '\u{80}'),
+	(0xA0, '\u{F8F0}'),
+	(0xFD, '\u{F8F1}'),
+	(0xFE, '\u{F8F2}'),
+	(0xFF, '\u{F8F3}'),
+];
+
+fn katakana(b: u8) -> Option<char>
+{
+	match b
+	{
+		0xA1..=0xDF => char::from_u32(0xFF61 + u32::from(b) - 0xA1),
+		_ => None,
+	}
+}
+
+fn katakana_byte(c: char) -> Option<u8>
+{
+	match u32::from(c)
+	{
+		c @ 0xFF61..=0xFF9F => Some((c - 0xFF61) as u8 + 0xA1),
+		_ => None,
+	}
+}
+
+/// The JIS X 0201 Roman characters that `Shift_JIS` and `EUC-JP` encode one way.
+fn roman_byte(c: char) -> Option<u8>
+{
+	match c
+	{
+		'\u{A5}' => Some(0x5C),
+		'\u{203E}' => Some(0x7E),
+		_ => None,
+	}
+}
+
+/// Returns the pointer of `c` within rows 1 to 94 of Windows-31J, which is all CP50220 and
+/// CP51932 can represent: characters only found in the IBM extensions use their NEC-selected
+/// duplicates in rows 89 to 92.
+pub(crate) fn windows_31j_jis_pointer(c: char) -> Option<usize>
+{
+	match WINDOWS_31J.pointer(c)
+	{
+		Some(pointer) if pointer >= 94 * 94 =>
+		{
+			(88 * 94..92 * 94).find(|&p| WINDOWS_31J.code_point(p) == Some(c))
+		}
+		pointer => pointer,
+	}
+}
+
+/// `Shift_JIS` or, if `windows` is set, `Windows-31J`.
+#[derive(Copy, Clone)]
+pub(crate) struct ShiftJis
+{
+	windows: bool,
+}
+
+impl ShiftJis
+{
+	pub(crate) fn new(windows: bool) -> Self
+	{
+		Self { windows }
+	}
+
+	fn index(&self) -> &'static Index
+	{
+		if self.windows
+		{
+			&WINDOWS_31J
+		}
+		else
+		{
+			&JIS_X_0208
+		}
+	}
+
+	fn encode_char(&self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if c.is_ascii()
+		{
+			output.push(c as u8);
+			return true;
+		}
+		if let Some(b) = katakana_byte(c)
+		{
+			output.push(b);
+			return true;
+		}
+		let single = if self.windows
+		{
+			WINDOWS_SINGLE_BYTES
+				.iter()
+				.find(|&&(_, s)| s == c)
+				.map(|&(b, _)| b)
+		}
+		else
+		{
+			roman_byte(c)
+		};
+		if let Some(b) = single
+		{
+			output.push(b);
+			return true;
+		}
+		match self.index().pointer(c)
+		{
+			Some(pointer) =>
+			{
+				let (lead, trail) = (pointer / 188, pointer % 188);
+				output.push(lead as u8 + if lead < 0x1F { 0x81 } else { 0xC1 });
+				output.push(trail as u8 + if trail < 0x3F { 0x40 } else { 0x41 });
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+impl Stepper for ShiftJis
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		let last_lead = if self.windows { 0xFC } else { 0xEF };
+		let lead_offset = match b
+		{
+			0x00..=0x7F =>
+			{
+				output.push(char::from(b));
+				return Step::Decoded(1);
+			}
+			0x81..=0x9F => 0x81,
+			0xE0..=0xFF if b <= last_lead => 0xC1,
+			_ =>
+			{
+				let single = katakana(b).or_else(|| {
+					WINDOWS_SINGLE_BYTES
+						.iter()
+						.find(|&&(s, _)| self.windows && s == b)
+						.map(|&(_, c)| c)
+				});
+				return match single
+				{
+					Some(c) =>
+					{
+						output.push(c);
+						Step::Decoded(1)
+					}
+					None => Step::Malformed(1),
+				};
+			}
+		};
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		let trail = bytes[1];
+		let trail_offset = match trail
+		{
+			0x40..=0x7E => 0x40,
+			0x80..=0xFC => 0x41,
+			_ => return Step::Malformed(1),
+		};
+		let pointer = usize::from(b - lead_offset) * 188 + usize::from(trail - trail_offset);
+		match self.index().code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			// An ASCII trail byte is decoded again on its own.
+			None if trail < 0x80 => Step::Malformed(1),
+			None => Step::Malformed(2),
+		}
+	}
+}
+
+impl RawEncoder for ShiftJis
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+/// `EUC-JP` or, if `windows` is set, `CP51932`, which has no JIS X 0212.
+#[derive(Copy, Clone)]
+pub(crate) struct EucJp
+{
+	windows: bool,
+}
+
+impl EucJp
+{
+	pub(crate) fn new(windows: bool) -> Self
+	{
+		Self { windows }
+	}
+
+	fn encode_char(&self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if c.is_ascii()
+		{
+			output.push(c as u8);
+			return true;
+		}
+		if let Some(b) = katakana_byte(c)
+		{
+			output.extend_from_slice(&[0x8E, b]);
+			return true;
+		}
+		let (prefix, pointer) = if self.windows
+		{
+			(None, windows_31j_jis_pointer(c))
+		}
+		else if let Some(b) = roman_byte(c)
+		{
+			output.push(b);
+			return true;
+		}
+		else
+		{
+			match JIS_X_0208.pointer(c)
+			{
+				Some(pointer) => (None, Some(pointer)),
+				None => (Some(0x8F), JIS_X_0212.pointer(c)),
+			}
+		};
+		match pointer
+		{
+			Some(pointer) =>
+			{
+				output.extend(prefix);
+				output
+					.extend_from_slice(&[0xA1 + (pointer / 94) as u8, 0xA1 + (pointer % 94) as u8]);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+impl Stepper for EucJp
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		let (index, len) = match b
+		{
+			0x00..=0x7F =>
+			{
+				output.push(char::from(b));
+				return Step::Decoded(1);
+			}
+			0x8E =>
+			{
+				if bytes.len() < 2
+				{
+					return Step::Incomplete;
+				}
+				return match katakana(bytes[1])
+				{
+					Some(c) =>
+					{
+						output.push(c);
+						Step::Decoded(2)
+					}
+					None => Step::Malformed(1),
+				};
+			}
+			0x8F if !self.windows => (&JIS_X_0212, 3),
+			0xA1..=0xFE if self.windows => (&WINDOWS_31J, 2),
+			0xA1..=0xFE => (&JIS_X_0208, 2),
+			_ => return Step::Malformed(1),
+		};
+		if bytes.len() < len
+		{
+			return Step::Incomplete;
+		}
+		let code = &bytes[len - 2..len];
+		// Bytes from the first invalid one on are decoded again.
+		if let Some(i) = code.iter().position(|b| !(0xA1..=0xFE).contains(b))
+		{
+			return Step::Malformed(len - 2 + i.max(1));
+		}
+		let pointer = usize::from(code[0] - 0xA1) * 94 + usize::from(code[1] - 0xA1);
+		match index.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(len)
+			}
+			None => Step::Malformed(len),
+		}
+	}
+}
+
+impl RawEncoder for EucJp
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_shift_jis()
+	{
+		let codec = CharacterSetEnum::SHIFT_JIS.codec().unwrap();
+		round_trip(codec, "日本語ｱ\\", b"\x93\xFA\x96\x7B\x8C\xEA\xB1\\");
+		malformed(codec, b"a\x93\x20", 1, b"\x93");
+		malformed(codec, b"a\xA0", 1, b"\xA0");
+
+		let codec = CharacterSetEnum::WINDOWS_31J.codec().unwrap();
+		round_trip(codec, "①髙ｱ~", b"\x87\x40\xFB\xFC\xB1~");
+		malformed(codec, b"a\x81", 1, b"\x81");
+	}
+
+	#[test]
+	fn converts_euc_jp()
+	{
+		let codec = CharacterSetEnum::EXTENDED_UNIX_CODE_PACKED_FORMAT_FOR_JAPANESE
+			.codec()
+			.unwrap();
+		round_trip(
+			codec,
+			"日本語ｱ丂",
+			b"\xC6\xFC\xCB\xDC\xB8\xEC\x8E\xB1\x8F\xB0\xA1",
+		);
+		malformed(codec, b"a\xA1\x41", 1, b"\xA1");
+
+		let codec = CharacterSetEnum::CP51932.codec().unwrap();
+		round_trip(codec, "①ｱ", b"\xAD\xA1\x8E\xB1");
+		malformed(codec, b"a\x8F\xA1\xA1", 1, b"\x8F");
+	}
+}
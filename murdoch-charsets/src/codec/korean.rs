@@ -0,0 +1,288 @@
+//! EUC-KR, the unified Hangul code and ISO-2022-KR (RFC 1557).
+//!
+//! `KS_C_5601-1987` is the label Windows gives its code page 949, so it decodes the unified
+//! Hangul code, a superset of EUC-KR adding the remaining 8822 precomposed syllables.
+use super::{
+	encode_chars,
+	tables::korean::{
+		KS_X_1001,
+		WINDOWS_949,
+	},
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// The designation of KS C 5601 to G1, written once at the start of ISO-2022-KR text.
+const HEADER: &[u8] = b"\x1B$)C";
+
+/// `EUC-KR` or, if `uhc` is set, the unified Hangul code.
+#[derive(Copy, Clone)]
+pub(crate) struct EucKr
+{
+	uhc: bool,
+}
+
+impl EucKr
+{
+	pub(crate) fn new(uhc: bool) -> Self
+	{
+		Self { uhc }
+	}
+
+	fn encode_char(&self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if c.is_ascii()
+		{
+			output.push(c as u8);
+			return true;
+		}
+		if self.uhc
+		{
+			return match WINDOWS_949.pointer(c)
+			{
+				Some(pointer) =>
+				{
+					output.extend_from_slice(&[
+						0x81 + (pointer / 190) as u8,
+						0x41 + (pointer % 190) as u8,
+					]);
+					true
+				}
+				None => false,
+			};
+		}
+		match KS_X_1001.pointer(c)
+		{
+			Some(pointer) =>
+			{
+				output
+					.extend_from_slice(&[0xA1 + (pointer / 94) as u8, 0xA1 + (pointer % 94) as u8]);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+impl Stepper for EucKr
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		if b < 0x80
+		{
+			output.push(char::from(b));
+			return Step::Decoded(1);
+		}
+		let (first, index, offset) = if self.uhc
+		{
+			(0x81, &WINDOWS_949, 0x41)
+		}
+		else
+		{
+			(0xA1, &KS_X_1001, 0xA1)
+		};
+		if !(first..=0xFE).contains(&b)
+		{
+			return Step::Malformed(1);
+		}
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		let trail = bytes[1];
+		if !(offset..=0xFE).contains(&trail)
+		{
+			return Step::Malformed(1);
+		}
+		let width = usize::from(0xFF - offset);
+		let pointer = usize::from(b - first) * width + usize::from(trail - offset);
+		match index.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			// An ASCII trail byte is decoded again on its own.
+			None if trail < 0x80 => Step::Malformed(1),
+			None => Step::Malformed(2),
+		}
+	}
+}
+
+impl RawEncoder for EucKr
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+/// ISO-2022-KR: KS C 5601 designated to G1 by a header, and invoked with SO and SI.
+pub(crate) struct Iso2022Kr
+{
+	/// Whether the header has been read or written.
+	designated: bool,
+	/// Whether SO has invoked KS C 5601.
+	shifted: bool,
+}
+
+impl Iso2022Kr
+{
+	pub(crate) fn new() -> Self
+	{
+		Self {
+			designated: false,
+			shifted: false,
+		}
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>) -> bool
+	{
+		if !self.designated
+		{
+			output.extend_from_slice(HEADER);
+			self.designated = true;
+		}
+		if c.is_ascii()
+		{
+			if matches!(c, '\x0E' | '\x0F' | '\x1B')
+			{
+				return false;
+			}
+			if self.shifted
+			{
+				output.push(0x0F);
+				self.shifted = false;
+			}
+			output.push(c as u8);
+			return true;
+		}
+		match KS_X_1001.pointer(c)
+		{
+			Some(pointer) =>
+			{
+				if !self.shifted
+				{
+					output.push(0x0E);
+					self.shifted = true;
+				}
+				output
+					.extend_from_slice(&[0x21 + (pointer / 94) as u8, 0x21 + (pointer % 94) as u8]);
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+impl Stepper for Iso2022Kr
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		match b
+		{
+			0x1B if bytes.starts_with(HEADER) =>
+			{
+				self.designated = true;
+				return Step::Decoded(HEADER.len());
+			}
+			0x1B if HEADER.starts_with(bytes) => return Step::Incomplete,
+			0x0E if self.designated =>
+			{
+				self.shifted = true;
+				return Step::Decoded(1);
+			}
+			0x0F =>
+			{
+				self.shifted = false;
+				return Step::Decoded(1);
+			}
+			0x0E | 0x1B | 0x80..=0xFF => return Step::Malformed(1),
+			_ =>
+			{}
+		}
+		if !self.shifted || b < 0x21 || b == 0x7F
+		{
+			output.push(char::from(b));
+			return Step::Decoded(1);
+		}
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		if !(0x21..=0x7E).contains(&bytes[1])
+		{
+			return Step::Malformed(1);
+		}
+		let pointer = usize::from(b - 0x21) * 94 + usize::from(bytes[1] - 0x21);
+		match KS_X_1001.code_point(pointer)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(2)
+			}
+			None => Step::Malformed(2),
+		}
+	}
+}
+
+impl RawEncoder for Iso2022Kr
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		encode_chars(input, output, |c, output| self.encode_char(c, output))
+	}
+
+	fn raw_finish(&mut self, output: &mut Vec<u8>)
+	{
+		if self.shifted
+		{
+			output.push(0x0F);
+			self.shifted = false;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_euc_kr()
+	{
+		let codec = CharacterSetEnum::EUC_KR.codec().unwrap();
+		round_trip(codec, "한국어a", b"\xC7\xD1\xB1\xB9\xBE\xEEa");
+		malformed(codec, b"a\xC7\x41", 1, b"\xC7");
+
+		let codec = CharacterSetEnum::KS_C_5601_1987.codec().unwrap();
+		round_trip(codec, "똠한a", b"\x8C\x63\xC7\xD1a");
+		malformed(codec, b"a\xC9\xA1", 1, b"\xC9\xA1");
+	}
+
+	#[test]
+	fn converts_iso_2022_kr()
+	{
+		let codec = CharacterSetEnum::ISO_2022_KR.codec().unwrap();
+		round_trip(codec, "a한국\nb", b"\x1B$)Ca\x0EGQ19\x0F\nb");
+		malformed(codec, b"a\x0E\x21\x21\x0F", 1, b"\x0E");
+	}
+}
@@ -0,0 +1,1300 @@
+//! Conversion between registered character sets and UTF-8, enabled by the `alloc` feature.
+//!
+//! `CharacterSetEnum::codec` returns a `Codec` for every character set the crate can convert:
+//!
+//! ```
+//! use murdoch_charsets::CharacterSetEnum;
+//!
+//! let codec = CharacterSetEnum::WINDOWS_1251.codec().unwrap();
+//! assert_eq!(codec.decode(b"\xCF\xF0\xE8\xE2\xE5\xF2"), "Привет");
+//! assert_eq!(
+//! 	codec.encode("Привет").unwrap(),
+//! 	&b"\xCF\xF0\xE8\xE2\xE5\xF2"[..]
+//! );
+//! ```
+//!
+//! Input that is already ASCII is returned borrowed when the character set maps ASCII to
+//! itself.
+mod big5;
+mod bocu_1;
+mod cesu_8;
+mod chinese;
+mod ebcdic;
+#[cfg(feature = "futures-io")]
+pub mod futures_io;
+#[cfg(feature = "std")]
+mod io;
+mod iso_2022_jp;
+mod japanese;
+mod korean;
+mod oem;
+mod scsu;
+mod single_byte;
+mod tables;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+mod utf_16;
+mod utf_32;
+mod utf_7;
+mod utf_8;
+
+#[cfg(feature = "std")]
+pub use self::io::{
+	DecodingReader,
+	EncodingWriter,
+};
+use self::{
+	big5::Big5,
+	bocu_1::Bocu1,
+	cesu_8::Cesu8,
+	chinese::{
+		Gb,
+		Hz,
+	},
+	ebcdic::Ebcdic,
+	iso_2022_jp::Iso2022Jp,
+	japanese::{
+		EucJp,
+		ShiftJis,
+	},
+	korean::{
+		EucKr,
+		Iso2022Kr,
+	},
+	oem::Oem,
+	scsu::Scsu,
+	single_byte::SingleByte,
+	utf_16::Utf16,
+	utf_32::Utf32,
+	utf_7::Utf7,
+	utf_8::Utf8,
+};
+use crate::{
+	CharacterSet,
+	CharacterSetEnum,
+};
+use alloc::{
+	borrow::Cow,
+	boxed::Box,
+	format,
+	string::String,
+	vec::Vec,
+};
+
+/// A converter between UTF-8 and a registered character set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Codec
+{
+	charset: CharacterSetEnum,
+	ebcdic_line_feed: EbcdicLineFeed,
+	oem_glyphs: bool,
+	imap_utf7: bool,
+}
+
+impl Codec
+{
+	pub fn charset(&self) -> CharacterSetEnum
+	{
+		self.charset
+	}
+
+	pub fn ebcdic_line_feed(&self) -> EbcdicLineFeed
+	{
+		self.ebcdic_line_feed
+	}
+
+	/// Sets the byte U+000A is converted from and to in EBCDIC code pages; other character sets
+	/// ignore it.
+	pub fn with_ebcdic_line_feed(self, ebcdic_line_feed: EbcdicLineFeed) -> Self
+	{
+		Self {
+			ebcdic_line_feed,
+			..self
+		}
+	}
+
+	pub fn oem_glyphs(&self) -> bool
+	{
+		self.oem_glyphs
+	}
+
+	/// Sets whether DOS code pages convert bytes `0x01..=0x1F` and `0x7F` to and from the glyphs
+	/// code page 437 displays for them, such as U+263A for `0x01`; other character sets ignore it.
+	pub fn with_oem_glyphs(self, oem_glyphs: bool) -> Self
+	{
+		Self { oem_glyphs, ..self }
+	}
+
+	pub fn imap_utf7(&self) -> bool
+	{
+		self.imap_utf7
+	}
+
+	/// Sets whether UTF-7 follows the modified UTF-7 of IMAP mailbox names (RFC 3501), shifting
+	/// with `&` and `,` instead of `+` and `/`; other character sets ignore it.
+	pub fn with_imap_utf7(self, imap_utf7: bool) -> Self
+	{
+		Self { imap_utf7, ..self }
+	}
+
+	/// Decodes `src`, replacing malformed or unmapped input with U+FFFD.
+	pub fn decode<'a>(&self, src: &'a [u8]) -> Cow<'a, str>
+	{
+		self.decode_with(src, ErrorMode::Replace)
+			.expect("decoding only fails in strict mode")
+	}
+
+	/// Decodes `src`, failing on the first malformed or unmapped sequence.
+	pub fn try_decode<'a>(&self, src: &'a [u8]) -> Result<Cow<'a, str>, DecodeError>
+	{
+		self.decode_with(src, ErrorMode::Strict)
+	}
+
+	/// Decodes `src`, handling malformed or unmapped input as `error_mode` says.
+	pub fn decode_with<'a>(
+		&self,
+		src: &'a [u8],
+		error_mode: ErrorMode,
+	) -> Result<Cow<'a, str>, DecodeError>
+	{
+		if self.kind().is_ascii_compatible() && src.is_ascii()
+		{
+			if let Ok(s) = core::str::from_utf8(src)
+			{
+				return Ok(Cow::Borrowed(s));
+			}
+		}
+
+		let mut output = String::with_capacity(src.len());
+		self.new_decoder()
+			.with_error_mode(error_mode)
+			.decode_to_string(src, &mut output, true)?;
+		Ok(Cow::Owned(output))
+	}
+
+	/// Encodes `src`, failing on the first character the character set cannot represent.
+	pub fn encode<'a>(&self, src: &'a str) -> Result<Cow<'a, [u8]>, EncodeError>
+	{
+		self.encode_with(src, ErrorMode::Strict)
+	}
+
+	/// Encodes `src`, handling characters the character set cannot represent as `error_mode`
+	/// says.
+	pub fn encode_with<'a>(
+		&self,
+		src: &'a str,
+		error_mode: ErrorMode,
+	) -> Result<Cow<'a, [u8]>, EncodeError>
+	{
+		if self.kind().is_ascii_compatible() && src.is_ascii()
+		{
+			return Ok(Cow::Borrowed(src.as_bytes()));
+		}
+
+		let mut output = Vec::with_capacity(src.len());
+		self.new_encoder()
+			.with_error_mode(error_mode)
+			.encode_to_vec(src, &mut output, true)?;
+		Ok(Cow::Owned(output))
+	}
+
+	/// Returns a decoder for input that arrives in pieces, which replaces malformed or unmapped
+	/// input with U+FFFD unless given another error mode.
+	pub fn new_decoder(&self) -> Decoder
+	{
+		Decoder {
+			codec: *self,
+			error_mode: ErrorMode::Replace,
+			raw: self.kind().decoder(),
+			position: 0,
+			recent: Pending::default(),
+		}
+	}
+
+	/// Returns an encoder for input that arrives in pieces, which fails on characters the
+	/// character set cannot represent unless given another error mode.
+	pub fn new_encoder(&self) -> Encoder
+	{
+		Encoder {
+			codec: *self,
+			error_mode: ErrorMode::Strict,
+			raw: self.kind().encoder(),
+			position: 0,
+		}
+	}
+
+	fn kind(&self) -> Kind
+	{
+		match Kind::of(self.charset).expect("a Codec is only created for a supported character set")
+		{
+			Kind::Ebcdic(ebcdic) => Kind::Ebcdic(ebcdic.with_line_feed(self.ebcdic_line_feed)),
+			Kind::Oem(oem) => Kind::Oem(oem.with_glyphs(self.oem_glyphs)),
+			Kind::Utf7(_) => Kind::Utf7(self.imap_utf7),
+			kind => kind,
+		}
+	}
+}
+
+impl CharacterSetEnum
+{
+	/// Returns the codec for this character set, or `None` if the crate cannot convert it.
+	pub fn codec(&self) -> Option<Codec>
+	{
+		Kind::of(*self).map(|_| {
+			Codec {
+				charset: *self,
+				ebcdic_line_feed: EbcdicLineFeed::Lf,
+				oem_glyphs: false,
+				imap_utf7: false,
+			}
+		})
+	}
+
+	/// Returns a decoder with the default codec options, or `None` if the crate cannot convert
+	/// this character set.
+	pub fn new_decoder(&self) -> Option<Decoder>
+	{
+		self.codec().map(|codec| codec.new_decoder())
+	}
+
+	/// Returns an encoder with the default codec options, or `None` if the crate cannot convert
+	/// this character set.
+	pub fn new_encoder(&self) -> Option<Encoder>
+	{
+		self.codec().map(|codec| codec.new_encoder())
+	}
+}
+
+/// How a conversion handles input it cannot convert: malformed or unmapped bytes when decoding,
+/// characters the character set cannot represent when encoding.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorMode
+{
+	/// Fails on the first one.
+	Strict,
+	/// Replaces each with U+FFFD when decoding and with `?` when encoding.
+	Replace,
+	/// Leaves them out.
+	Skip,
+	/// Encodes each character as an HTML decimal numeric character reference such as `&#8364;`,
+	/// as browsers do for form submissions. Decoding replaces with U+FFFD, as `Replace` does.
+	Html,
+}
+
+/// A decoder that keeps incomplete sequences and shift states between calls, so input can be
+/// split anywhere.
+pub struct Decoder
+{
+	codec: Codec,
+	error_mode: ErrorMode,
+	raw: Box<dyn RawDecoder + Send>,
+	/// Bytes of the current stream decoded by earlier calls.
+	position: usize,
+	/// The last bytes of earlier calls, where a malformed sequence may start.
+	recent: Pending,
+}
+
+impl Decoder
+{
+	pub fn codec(&self) -> Codec
+	{
+		self.codec
+	}
+
+	pub fn error_mode(&self) -> ErrorMode
+	{
+		self.error_mode
+	}
+
+	pub fn with_error_mode(self, error_mode: ErrorMode) -> Self
+	{
+		Self { error_mode, ..self }
+	}
+
+	/// Decodes `src` and appends it to `dst`, handling malformed or unmapped input as the error
+	/// mode says.
+	///
+	/// An incomplete sequence at the end of `src` is kept until the next call, unless `last` is
+	/// set; the decoder then returns to its initial state for a new stream. Returns the number
+	/// of bytes read, which is always `src.len()`, and the number of bytes written to `dst`.
+	///
+	/// In `ErrorMode::Strict`, the first error ends the stream: the input before it is decoded,
+	/// the error gives its offset from the start of the stream, and the decoder returns to its
+	/// initial state.
+	pub fn decode_to_string(
+		&mut self,
+		src: &[u8],
+		dst: &mut String,
+		last: bool,
+	) -> Result<(usize, usize), DecodeError>
+	{
+		let start = dst.len();
+		let mut done = 0;
+		while let (processed, Some(error)) = self.raw.raw_feed(&src[done..], dst)
+		{
+			self.malformed(src, done + processed, done + error.upto, &error, dst)?;
+			done += error.upto;
+		}
+		if last
+		{
+			if let Some(error) = self.raw.raw_finish(dst)
+			{
+				self.malformed(src, src.len(), src.len(), &error, dst)?;
+			}
+			self.reset();
+		}
+		else
+		{
+			self.position += src.len();
+			self.recent.append_evicting(src);
+		}
+		Ok((src.len(), dst.len() - start))
+	}
+
+	/// Handles the malformed sequence `error` found at `src[start..end]`, which may start in bytes
+	/// read before.
+	fn malformed(
+		&mut self,
+		src: &[u8],
+		start: usize,
+		end: usize,
+		error: &RawDecodeError,
+		dst: &mut String,
+	) -> Result<(), DecodeError>
+	{
+		match self.error_mode
+		{
+			ErrorMode::Strict =>
+			{
+				let held = error.held;
+				let from_src = held.min(start);
+				let recent = self.recent.as_slice();
+				let from_recent = &recent[recent.len().saturating_sub(held - from_src)..];
+				let mut bytes = Pending::default();
+				bytes.append_evicting(from_recent);
+				bytes.append_evicting(&src[start - from_src..end]);
+				bytes.truncate(bytes.len().saturating_sub(error.excess));
+				let error = DecodeError {
+					charset: self.codec.charset,
+					offset: (self.position + start).saturating_sub(held),
+					bytes,
+				};
+				self.reset();
+				Err(error)
+			}
+			ErrorMode::Replace | ErrorMode::Html =>
+			{
+				dst.push(char::REPLACEMENT_CHARACTER);
+				Ok(())
+			}
+			ErrorMode::Skip => Ok(()),
+		}
+	}
+
+	fn reset(&mut self)
+	{
+		self.raw = self.codec.kind().decoder();
+		self.position = 0;
+		self.recent = Pending::default();
+	}
+}
+
+impl core::fmt::Debug for Decoder
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		f.debug_struct("Decoder")
+			.field("codec", &self.codec)
+			.field("error_mode", &self.error_mode)
+			.finish()
+	}
+}
+
+/// An encoder that keeps shift states between calls, so the output of successive calls forms
+/// one stream.
+pub struct Encoder
+{
+	codec: Codec,
+	error_mode: ErrorMode,
+	raw: Box<dyn RawEncoder + Send>,
+	/// Bytes of the current stream encoded by earlier calls.
+	position: usize,
+}
+
+impl Encoder
+{
+	pub fn codec(&self) -> Codec
+	{
+		self.codec
+	}
+
+	pub fn error_mode(&self) -> ErrorMode
+	{
+		self.error_mode
+	}
+
+	pub fn with_error_mode(self, error_mode: ErrorMode) -> Self
+	{
+		Self { error_mode, ..self }
+	}
+
+	/// Encodes `src` and appends it to `dst`, handling characters the character set cannot
+	/// represent as the error mode says. With `last` set, also writes whatever the end of the
+	/// stream requires, such as a return to the initial shift state, and the encoder then returns
+	/// to its initial state for a new stream. Returns the number of bytes read, which is always
+	/// `src.len()`, and the number of bytes written to `dst`.
+	///
+	/// In `ErrorMode::Strict`, the first error ends the stream: the input before it is encoded and
+	/// finished as if `last` were set, the error gives its offset from the start of the stream, and
+	/// the encoder returns to its initial state.
+	pub fn encode_to_vec(
+		&mut self,
+		src: &str,
+		dst: &mut Vec<u8>,
+		last: bool,
+	) -> Result<(usize, usize), EncodeError>
+	{
+		let start = dst.len();
+		let mut done = 0;
+		while let (processed, Some(error)) = self.raw.raw_feed(&src[done..], dst)
+		{
+			let character = src[done + processed..done + error.upto]
+				.chars()
+				.next()
+				.unwrap_or_default();
+			match self.error_mode
+			{
+				ErrorMode::Strict =>
+				{
+					let error = EncodeError {
+						charset: self.codec.charset,
+						character,
+						offset: self.position + done + processed,
+					};
+					self.raw.raw_finish(dst);
+					self.reset();
+					return Err(error);
+				}
+				ErrorMode::Replace => self.substitute("?", dst),
+				ErrorMode::Skip =>
+				{}
+				ErrorMode::Html => self.substitute(&format!("&#{};", u32::from(character)), dst),
+			}
+			done += error.upto;
+		}
+		if last
+		{
+			self.raw.raw_finish(dst);
+			self.reset();
+		}
+		else
+		{
+			self.position += src.len();
+		}
+		Ok((src.len(), dst.len() - start))
+	}
+
+	/// Writes `replacement` in place of a character, or nothing if even that cannot be encoded.
+	fn substitute(&mut self, replacement: &str, dst: &mut Vec<u8>)
+	{
+		let _ = self.raw.raw_feed(replacement, dst);
+	}
+
+	fn reset(&mut self)
+	{
+		self.raw = self.codec.kind().encoder();
+		self.position = 0;
+	}
+}
+
+impl core::fmt::Debug for Encoder
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		f.debug_struct("Encoder")
+			.field("codec", &self.codec)
+			.field("error_mode", &self.error_mode)
+			.finish()
+	}
+}
+
+/// The EBCDIC control U+000A LINE FEED is converted from and to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EbcdicLineFeed
+{
+	/// Byte 0x25 (LF), with byte 0x15 (NL) converted to U+0085, as IBM maps them.
+	Lf,
+	/// Byte 0x15 (NL), with byte 0x25 converted to U+0085, as z/OS UNIX System Services uses.
+	Nl,
+}
+
+/// Error returned when the input is not valid in the source character set.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct DecodeError
+{
+	charset: CharacterSetEnum,
+	offset: usize,
+	bytes: Pending,
+}
+
+impl DecodeError
+{
+	pub fn charset(&self) -> CharacterSetEnum
+	{
+		self.charset
+	}
+
+	/// Returns the byte offset of the first malformed or unmapped sequence in the input.
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+
+	/// Returns the malformed or unmapped sequence, or its last 8 bytes if it is longer. An
+	/// incomplete sequence at the end of the input is returned as it is. It is empty where a
+	/// UTF-7 shift sequence ends wrongly, and the offset is then where it ends.
+	pub fn bytes(&self) -> &[u8]
+	{
+		self.bytes.as_slice()
+	}
+}
+
+impl core::fmt::Debug for DecodeError
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		f.debug_struct("DecodeError")
+			.field("charset", &self.charset)
+			.field("offset", &self.offset)
+			.field("bytes", &self.bytes())
+			.finish()
+	}
+}
+
+impl core::fmt::Display for DecodeError
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		write!(
+			f,
+			"the input is not valid {} at byte {} ({:02X?}).",
+			self.charset.name(),
+			self.offset,
+			self.bytes()
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Error returned when a character cannot be represented in the target character set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EncodeError
+{
+	charset: CharacterSetEnum,
+	character: char,
+	offset: usize,
+}
+
+impl EncodeError
+{
+	pub fn charset(&self) -> CharacterSetEnum
+	{
+		self.charset
+	}
+
+	pub fn character(&self) -> char
+	{
+		self.character
+	}
+
+	/// Returns the byte offset of the character in the UTF-8 input.
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+}
+
+impl core::fmt::Display for EncodeError
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		write!(
+			f,
+			"{:?} (U+{:04X}) at byte {} cannot be encoded in {}.",
+			self.character,
+			u32::from(self.character),
+			self.offset,
+			self.charset.name()
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
+
+/// How a character set is converted.
+#[derive(Copy, Clone)]
+enum Kind
+{
+	SingleByte(SingleByte),
+	Ebcdic(Ebcdic),
+	Oem(Oem),
+	/// UTF-7, or IMAP's modified UTF-7 if set.
+	Utf7(bool),
+	Utf8,
+	Utf16(Form),
+	/// UCS-2 limited to the code points up to the one given.
+	Ucs2(u16),
+	Utf32(Form),
+	Cesu8,
+	Scsu,
+	Bocu1,
+	Iso2022Jp(iso_2022_jp::Variant),
+	/// Shift_JIS, or Windows-31J if set.
+	ShiftJis(bool),
+	/// EUC-JP, or CP51932 if set.
+	EucJp(bool),
+	Gb(chinese::Variant),
+	Hz,
+	/// Big5, or Big5-HKSCS if set.
+	Big5(bool),
+	/// EUC-KR, or the unified Hangul code if set.
+	EucKr(bool),
+	Iso2022Kr,
+}
+
+impl Kind
+{
+	fn of(charset: CharacterSetEnum) -> Option<Self>
+	{
+		use self::tables::{
+			ebcdic,
+			single_byte::*,
+		};
+		use crate::CharacterSetEnum as C;
+
+		let high = |table| Some(Kind::SingleByte(SingleByte::high(table)));
+		let ebcdic = |table| Some(Kind::Ebcdic(Ebcdic::new(table)));
+		let seven = |table| Some(Kind::SingleByte(SingleByte::seven_bit(table)));
+		let oem = |table| Some(Kind::Oem(Oem::new(SingleByte::high(table))));
+		match charset
+		{
+			C::US_ASCII => high(&self::single_byte::US_ASCII),
+			C::ISO_8859_1_1987 => high(&ISO_8859_1),
+			C::ISO_8859_2_1987 => high(&ISO_8859_2),
+			C::ISO_8859_3_1988 => high(&ISO_8859_3),
+			C::ISO_8859_4_1988 => high(&ISO_8859_4),
+			C::ISO_8859_5_1988 => high(&ISO_8859_5),
+			C::ISO_8859_6_1987 | C::ISO_8859_6_E | C::ISO_8859_6_I => high(&ISO_8859_6),
+			C::ISO_8859_7_1987 => high(&ISO_8859_7),
+			C::ISO_8859_8_1988 | C::ISO_8859_8_E | C::ISO_8859_8_I => high(&ISO_8859_8),
+			C::ISO_8859_9_1989 => high(&ISO_8859_9),
+			C::ISO_8859_10 => high(&ISO_8859_10),
+			C::ISO_8859_13 => high(&ISO_8859_13),
+			C::ISO_8859_14 => high(&ISO_8859_14),
+			C::ISO_8859_15 => high(&ISO_8859_15),
+			C::ISO_8859_16 => high(&ISO_8859_16),
+			C::WINDOWS_874 => high(&WINDOWS_874),
+			C::WINDOWS_1250 => high(&WINDOWS_1250),
+			C::WINDOWS_1251 => high(&WINDOWS_1251),
+			C::WINDOWS_1252 => high(&WINDOWS_1252),
+			C::WINDOWS_1253 => high(&WINDOWS_1253),
+			C::WINDOWS_1254 => high(&WINDOWS_1254),
+			C::WINDOWS_1255 => high(&WINDOWS_1255),
+			C::WINDOWS_1256 => high(&WINDOWS_1256),
+			C::WINDOWS_1257 => high(&WINDOWS_1257),
+			C::WINDOWS_1258 => high(&WINDOWS_1258),
+			C::KOI8_R => high(&KOI8_R),
+			C::KOI8_U => high(&KOI8_U),
+			C::IBM437 => oem(&IBM437),
+			C::IBM775 => oem(&IBM775),
+			C::IBM850 => oem(&IBM850),
+			C::IBM852 => oem(&IBM852),
+			C::IBM855 => oem(&IBM855),
+			C::IBM857 => oem(&IBM857),
+			C::IBM00858 => oem(&IBM00858),
+			C::IBM860 => oem(&IBM860),
+			C::IBM861 => oem(&IBM861),
+			C::IBM862 => oem(&IBM862),
+			C::IBM863 => oem(&IBM863),
+			C::IBM865 => oem(&IBM865),
+			C::IBM866 => oem(&IBM866),
+			C::IBM869 => oem(&IBM869),
+			C::IBM864 => Some(Kind::Oem(Oem::new(SingleByte::full(&IBM864)))),
+			C::MACINTOSH => high(&MACINTOSH),
+			C::TIS_620 => high(&TIS_620),
+			C::HP_ROMAN8 => high(&HP_ROMAN8),
+			C::PTCP154 => high(&PTCP154),
+			C::KZ_1048 => high(&KZ_1048),
+			C::DEC_MCS => high(&DEC_MCS),
+			C::VISCII => Some(Kind::SingleByte(SingleByte::full(&VISCII))),
+			// glibc defines nothing above `0x7F` in either.
+			C::IBM891 | C::IBM903 => oem(&self::single_byte::US_ASCII),
+			C::IBM851 => oem(&IBM851),
+			C::IBM868 => oem(&IBM868),
+			C::IBM904 => oem(&IBM904),
+			C::ECMA_CYRILLIC => high(&ECMA_CYRILLIC),
+			C::IEC_P27_1 => high(&IEC_P27_1),
+			C::GOST_19768_74 => high(&GOST_19768_74),
+			C::ISO_10367_BOX => high(&ISO_10367_BOX),
+			C::CSN_369103 => Some(Kind::SingleByte(SingleByte::full(&CSN_369103))),
+			C::ADOBE_STANDARD_ENCODING =>
+			{
+				Some(Kind::SingleByte(SingleByte::full(&ADOBE_STANDARD_ENCODING)))
+			}
+			C::ISO_11548_1 => Some(Kind::SingleByte(SingleByte::full(&ISO_11548_1))),
+			C::BS_4730 => seven(&BS_4730),
+			C::SEN_850200_C => seven(&SEN_850200_C),
+			C::IT => seven(&IT),
+			C::ES => seven(&ES),
+			C::DIN_66003 => seven(&DIN_66003),
+			C::NS_4551_1 => seven(&NS_4551_1),
+			C::NF_Z_62_010 => seven(&NF_Z_62_010),
+			C::NATS_SEFI => seven(&NATS_SEFI),
+			C::NATS_DANO => seven(&NATS_DANO),
+			C::SEN_850200_B => seven(&SEN_850200_B),
+			C::JIS_C6220_1969_RO => seven(&JIS_C6220_1969_RO),
+			C::PT => seven(&PT),
+			C::GREEK7_OLD => seven(&GREEK7_OLD),
+			C::LATIN_GREEK => seven(&LATIN_GREEK),
+			C::NF_Z_62_010_1973 => seven(&NF_Z_62_010_1973),
+			C::LATIN_GREEK_1 => seven(&LATIN_GREEK_1),
+			C::ISO_5427 => seven(&ISO_5427),
+			C::INIS => seven(&INIS),
+			C::INIS_CYRILLIC => seven(&INIS_CYRILLIC),
+			C::ISO_5427_1981 => seven(&ISO_5427_1981),
+			C::ISO_5428_1980 => seven(&ISO_5428_1980),
+			C::GB_1988_80 => seven(&GB_1988_80),
+			C::NS_4551_2 => seven(&NS_4551_2),
+			C::PT2 => seven(&PT2),
+			C::ES2 => seven(&ES2),
+			C::MSZ_7795_3 => seven(&MSZ_7795_3),
+			C::GREEK7 => seven(&GREEK7),
+			C::ASMO_449 => seven(&ASMO_449),
+			C::JIS_C6229_1984_B => seven(&JIS_C6229_1984_B),
+			C::CSA_Z243_4_1985_1 => seven(&CSA_Z243_4_1985_1),
+			C::CSA_Z243_4_1985_2 => seven(&CSA_Z243_4_1985_2),
+			C::JUS_I_B1_002 => seven(&JUS_I_B1_002),
+			C::GREEK_CCITT => seven(&GREEK_CCITT),
+			C::NC_NC00_10_81 => seven(&NC_NC00_10_81),
+			C::DS_2089 => seven(&DS_2089),
+			C::KSC5636 => seven(&KSC5636),
+			C::BRF => seven(&BRF),
+			C::JIS_X0201 => Some(Kind::SingleByte(SingleByte::full(&JIS_X0201))),
+			C::JIS_C6220_1969_JP => seven(&JIS_C6220_1969_JP),
+			C::IBM037 => ebcdic(&ebcdic::IBM037),
+			C::IBM038 => ebcdic(&ebcdic::IBM038),
+			C::IBM273 => ebcdic(&ebcdic::IBM273),
+			C::IBM274 => ebcdic(&ebcdic::IBM274),
+			C::IBM275 => ebcdic(&ebcdic::IBM275),
+			C::IBM277 => ebcdic(&ebcdic::IBM277),
+			C::IBM278 => ebcdic(&ebcdic::IBM278),
+			C::IBM280 => ebcdic(&ebcdic::IBM280),
+			C::IBM281 => ebcdic(&ebcdic::IBM281),
+			C::IBM284 => ebcdic(&ebcdic::IBM284),
+			C::IBM285 => ebcdic(&ebcdic::IBM285),
+			C::IBM290 => ebcdic(&ebcdic::IBM290),
+			C::IBM297 => ebcdic(&ebcdic::IBM297),
+			C::IBM420 => ebcdic(&ebcdic::IBM420),
+			C::IBM423 => ebcdic(&ebcdic::IBM423),
+			C::IBM424 => ebcdic(&ebcdic::IBM424),
+			C::IBM500 => ebcdic(&ebcdic::IBM500),
+			C::IBM870 => ebcdic(&ebcdic::IBM870),
+			C::IBM871 => ebcdic(&ebcdic::IBM871),
+			C::IBM880 => ebcdic(&ebcdic::IBM880),
+			C::IBM905 => ebcdic(&ebcdic::IBM905),
+			C::IBM918 => ebcdic(&ebcdic::IBM918),
+			C::IBM_THAI => ebcdic(&ebcdic::IBM_THAI),
+			C::IBM1026 => ebcdic(&ebcdic::IBM1026),
+			C::IBM1047 => ebcdic(&ebcdic::IBM1047),
+			C::IBM00924 => ebcdic(&ebcdic::IBM00924),
+			C::IBM01140 => ebcdic(&ebcdic::IBM01140),
+			C::IBM01141 => ebcdic(&ebcdic::IBM01141),
+			C::IBM01142 => ebcdic(&ebcdic::IBM01142),
+			C::IBM01143 => ebcdic(&ebcdic::IBM01143),
+			C::IBM01144 => ebcdic(&ebcdic::IBM01144),
+			C::IBM01145 => ebcdic(&ebcdic::IBM01145),
+			C::IBM01146 => ebcdic(&ebcdic::IBM01146),
+			C::IBM01147 => ebcdic(&ebcdic::IBM01147),
+			C::IBM01148 => ebcdic(&ebcdic::IBM01148),
+			C::IBM01149 => ebcdic(&ebcdic::IBM01149),
+			C::EBCDIC_AT_DE => ebcdic(&ebcdic::EBCDIC_AT_DE),
+			C::EBCDIC_AT_DE_A => ebcdic(&ebcdic::EBCDIC_AT_DE_A),
+			C::EBCDIC_CA_FR => ebcdic(&ebcdic::EBCDIC_CA_FR),
+			C::EBCDIC_DK_NO => ebcdic(&ebcdic::EBCDIC_DK_NO),
+			C::EBCDIC_DK_NO_A => ebcdic(&ebcdic::EBCDIC_DK_NO_A),
+			C::EBCDIC_FI_SE => ebcdic(&ebcdic::EBCDIC_FI_SE),
+			C::EBCDIC_FI_SE_A => ebcdic(&ebcdic::EBCDIC_FI_SE_A),
+			C::EBCDIC_FR => ebcdic(&ebcdic::EBCDIC_FR),
+			C::EBCDIC_IT => ebcdic(&ebcdic::EBCDIC_IT),
+			C::EBCDIC_PT => ebcdic(&ebcdic::EBCDIC_PT),
+			C::EBCDIC_ES => ebcdic(&ebcdic::EBCDIC_ES),
+			C::EBCDIC_ES_A => ebcdic(&ebcdic::EBCDIC_ES_A),
+			C::EBCDIC_ES_S => ebcdic(&ebcdic::EBCDIC_ES_S),
+			C::EBCDIC_UK => ebcdic(&ebcdic::EBCDIC_UK),
+			C::EBCDIC_US => ebcdic(&ebcdic::EBCDIC_US),
+			C::UTF_7 | C::UNICODE_1_1_UTF_7 => Some(Kind::Utf7(false)),
+			C::UTF_8 => Some(Kind::Utf8),
+			C::UTF_16 => Some(Kind::Utf16(Form::Unmarked)),
+			C::UTF_16BE => Some(Kind::Utf16(Form::BigEndian)),
+			C::UTF_16LE => Some(Kind::Utf16(Form::LittleEndian)),
+			C::ISO_10646_UCS_2 | C::UNICODE_1_1 => Some(Kind::Ucs2(0xFFFF)),
+			C::ISO_10646_UCS_BASIC => Some(Kind::Ucs2(0x7F)),
+			C::ISO_10646_UNICODE_LATIN1 => Some(Kind::Ucs2(0xFF)),
+			C::UTF_32 => Some(Kind::Utf32(Form::Unmarked)),
+			C::UTF_32BE | C::ISO_10646_UCS_4 => Some(Kind::Utf32(Form::BigEndian)),
+			C::UTF_32LE => Some(Kind::Utf32(Form::LittleEndian)),
+			C::CESU_8 => Some(Kind::Cesu8),
+			C::SCSU => Some(Kind::Scsu),
+			C::BOCU_1 => Some(Kind::Bocu1),
+			C::ISO_2022_JP => Some(Kind::Iso2022Jp(iso_2022_jp::Variant::Jp)),
+			C::ISO_2022_JP_2 => Some(Kind::Iso2022Jp(iso_2022_jp::Variant::Jp2)),
+			C::CP50220 => Some(Kind::Iso2022Jp(iso_2022_jp::Variant::Cp50220)),
+			C::SHIFT_JIS => Some(Kind::ShiftJis(false)),
+			C::WINDOWS_31J => Some(Kind::ShiftJis(true)),
+			C::EXTENDED_UNIX_CODE_PACKED_FORMAT_FOR_JAPANESE => Some(Kind::EucJp(false)),
+			C::CP51932 => Some(Kind::EucJp(true)),
+			C::GB2312 | C::GB_2312_80 => Some(Kind::Gb(chinese::Variant::Gb2312)),
+			C::GBK => Some(Kind::Gb(chinese::Variant::Gbk)),
+			C::GB18030 => Some(Kind::Gb(chinese::Variant::Gb18030)),
+			C::HZ_GB_2312 => Some(Kind::Hz),
+			C::BIG5 => Some(Kind::Big5(false)),
+			C::BIG5_HKSCS => Some(Kind::Big5(true)),
+			C::EUC_KR => Some(Kind::EucKr(false)),
+			C::KS_C_5601_1987 => Some(Kind::EucKr(true)),
+			C::ISO_2022_KR => Some(Kind::Iso2022Kr),
+			_ => None,
+		}
+	}
+
+	/// Whether every ASCII byte decodes to itself and every ASCII character encodes to itself.
+	fn is_ascii_compatible(&self) -> bool
+	{
+		match self
+		{
+			Kind::SingleByte(single_byte) => single_byte.is_ascii_compatible(),
+			Kind::Ebcdic(_) | Kind::Utf7(_) | Kind::Scsu | Kind::Bocu1 => false,
+			Kind::Oem(oem) => oem.is_ascii_compatible(),
+			Kind::Utf8
+			| Kind::Cesu8
+			| Kind::ShiftJis(_)
+			| Kind::EucJp(_)
+			| Kind::Gb(_)
+			| Kind::Big5(_)
+			| Kind::EucKr(_) => true,
+			Kind::Utf16(_)
+			| Kind::Ucs2(_)
+			| Kind::Utf32(_)
+			| Kind::Iso2022Jp(_)
+			| Kind::Hz
+			| Kind::Iso2022Kr => false,
+		}
+	}
+
+	fn decoder(&self) -> Box<dyn RawDecoder + Send>
+	{
+		match self
+		{
+			Kind::SingleByte(single_byte) => Box::new(*single_byte),
+			Kind::Ebcdic(ebcdic) => Box::new(*ebcdic),
+			Kind::Oem(oem) => Box::new(*oem),
+			Kind::Utf7(imap) => Box::new(Utf7::new(*imap)),
+			Kind::Utf8 => Box::new(Utf8::default()),
+			Kind::Utf16(form) => Box::new(Stepped::new(Utf16::new(*form))),
+			Kind::Ucs2(max) => Box::new(Stepped::new(Utf16::ucs2(*max))),
+			Kind::Utf32(form) => Box::new(Stepped::new(Utf32::new(*form))),
+			Kind::Cesu8 => Box::new(Stepped::new(Cesu8)),
+			Kind::Scsu => Box::new(Stepped::new(Scsu::new())),
+			Kind::Bocu1 => Box::new(Stepped::new(Bocu1::new())),
+			Kind::Iso2022Jp(variant) => Box::new(Stepped::new(Iso2022Jp::new(*variant))),
+			Kind::ShiftJis(windows) => Box::new(Stepped::new(ShiftJis::new(*windows))),
+			Kind::EucJp(windows) => Box::new(Stepped::new(EucJp::new(*windows))),
+			Kind::Gb(variant) => Box::new(Stepped::new(Gb::new(*variant))),
+			Kind::Hz => Box::new(Stepped::new(Hz::new())),
+			Kind::Big5(hkscs) => Box::new(Stepped::new(Big5::new(*hkscs))),
+			Kind::EucKr(uhc) => Box::new(Stepped::new(EucKr::new(*uhc))),
+			Kind::Iso2022Kr => Box::new(Stepped::new(Iso2022Kr::new())),
+		}
+	}
+
+	fn encoder(&self) -> Box<dyn RawEncoder + Send>
+	{
+		match self
+		{
+			Kind::SingleByte(single_byte) => Box::new(*single_byte),
+			Kind::Ebcdic(ebcdic) => Box::new(*ebcdic),
+			Kind::Oem(oem) => Box::new(*oem),
+			Kind::Utf7(imap) => Box::new(Utf7::new(*imap)),
+			Kind::Utf8 => Box::new(Utf8::default()),
+			Kind::Utf16(form) => Box::new(Utf16::new(*form)),
+			Kind::Ucs2(max) => Box::new(Utf16::ucs2(*max)),
+			Kind::Utf32(form) => Box::new(Utf32::new(*form)),
+			Kind::Cesu8 => Box::new(Cesu8),
+			Kind::Scsu => Box::new(Scsu::new()),
+			Kind::Bocu1 => Box::new(Bocu1::new()),
+			Kind::Iso2022Jp(variant) => Box::new(Iso2022Jp::new(*variant)),
+			Kind::ShiftJis(windows) => Box::new(ShiftJis::new(*windows)),
+			Kind::EucJp(windows) => Box::new(EucJp::new(*windows)),
+			Kind::Gb(variant) => Box::new(Gb::new(*variant)),
+			Kind::Hz => Box::new(Hz::new()),
+			Kind::Big5(hkscs) => Box::new(Big5::new(*hkscs)),
+			Kind::EucKr(uhc) => Box::new(EucKr::new(*uhc)),
+			Kind::Iso2022Kr => Box::new(Iso2022Kr::new()),
+		}
+	}
+}
+
+/// Runs `encode_char` over `input` as `RawEncoder::raw_feed`, stopping at the first character it
+/// returns `false` for.
+pub(crate) fn encode_chars(
+	input: &str,
+	output: &mut Vec<u8>,
+	mut encode_char: impl FnMut(char, &mut Vec<u8>) -> bool,
+) -> (usize, Option<RawEncodeError>)
+{
+	for (i, c) in input.char_indices()
+	{
+		if !encode_char(c, output)
+		{
+			return (
+				i,
+				Some(RawEncodeError {
+					upto: i + c.len_utf8(),
+				}),
+			);
+		}
+	}
+	(input.len(), None)
+}
+
+/// Byte order of a UTF-16 or UTF-32 encoding scheme.
+#[derive(Copy, Clone)]
+pub(crate) enum Form
+{
+	/// Detected from a leading byte-order mark, big-endian without one.
+	Unmarked,
+	BigEndian,
+	LittleEndian,
+}
+
+/// Bytes of an incomplete sequence, held back until the next call.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Pending
+{
+	bytes: [u8; 8],
+	len: usize,
+}
+
+impl Pending
+{
+	pub(crate) fn push(&mut self, b: u8)
+	{
+		self.bytes[self.len] = b;
+		self.len += 1;
+	}
+
+	pub(crate) fn as_slice(&self) -> &[u8]
+	{
+		&self.bytes[..self.len]
+	}
+
+	pub(crate) fn is_empty(&self) -> bool
+	{
+		self.len == 0
+	}
+
+	pub(crate) fn len(&self) -> usize
+	{
+		self.len
+	}
+
+	pub(crate) fn truncate(&mut self, len: usize)
+	{
+		// Cleared so that equal contents compare equal.
+		for b in &mut self.bytes[len.min(self.len)..self.len]
+		{
+			*b = 0;
+		}
+		self.len = self.len.min(len);
+	}
+
+	/// Appends `bytes`, dropping the oldest ones beyond the capacity.
+	pub(crate) fn append_evicting(&mut self, bytes: &[u8])
+	{
+		for &b in bytes
+		{
+			if self.len == self.bytes.len()
+			{
+				self.bytes.copy_within(1.., 0);
+				self.len -= 1;
+			}
+			self.push(b);
+		}
+	}
+}
+
+/// A malformed or unmapped sequence found by a `RawDecoder`.
+pub(crate) struct RawDecodeError
+{
+	/// Index into the input just past the sequence; decoding resumes from there.
+	pub(crate) upto: usize,
+	/// How far before the returned position the sequence starts, in bytes fed by earlier calls.
+	pub(crate) held: usize,
+	/// How many of the held bytes at the end are not part of the sequence but decoded again.
+	pub(crate) excess: usize,
+}
+
+impl RawDecodeError
+{
+	pub(crate) fn new(upto: usize) -> Self
+	{
+		Self {
+			upto,
+			held: 0,
+			excess: 0,
+		}
+	}
+
+	pub(crate) fn held(self, held: usize) -> Self
+	{
+		Self { held, ..self }
+	}
+
+	pub(crate) fn excess(self, excess: usize) -> Self
+	{
+		Self { excess, ..self }
+	}
+}
+
+/// A character found by a `RawEncoder` that the character set cannot represent.
+pub(crate) struct RawEncodeError
+{
+	/// Index into the input just past the character; encoding resumes from there.
+	pub(crate) upto: usize,
+}
+
+/// Decoding state machine of a character set.
+///
+/// `raw_feed` decodes as much of `input` as it can into `output` and returns how many bytes
+/// were processed. It stops at the first malformed sequence, which then spans from the
+/// returned position, preceded by `RawDecodeError::held` bytes fed by earlier calls, up to
+/// `RawDecodeError::upto`.
+/// Incomplete sequences at the end of `input` are kept in the decoder for the next call.
+pub(crate) trait RawDecoder
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>);
+
+	/// Flushes the end of the input; an incomplete sequence still held is malformed.
+	fn raw_finish(&mut self, output: &mut String) -> Option<RawDecodeError>;
+}
+
+/// Encoding state machine of a character set.
+///
+/// `raw_feed` encodes as much of `input` as it can into `output` and returns how many bytes
+/// were processed. It stops at the first character it cannot represent, which spans from the
+/// returned position up to `RawEncodeError::upto`.
+pub(crate) trait RawEncoder
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>);
+
+	/// Writes whatever the end of the output requires, such as a return to the initial state.
+	fn raw_finish(&mut self, output: &mut Vec<u8>);
+}
+
+/// What a `Stepper` found at the start of its input.
+pub(crate) enum Step
+{
+	/// The input ends inside a sequence.
+	Incomplete,
+	/// The first `n` bytes were decoded.
+	Decoded(usize),
+	/// The first `n` bytes are malformed or unmapped.
+	Malformed(usize),
+}
+
+/// Decodes one sequence at a time; `Stepped` turns it into a `RawDecoder`.
+pub(crate) trait Stepper
+{
+	/// Decodes the sequence at the start of `bytes`, which is never empty.
+	///
+	/// Output is only written for `Step::Decoded`. After `Step::Incomplete` the same bytes are
+	/// stepped again with more appended.
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step;
+}
+
+/// Runs a `Stepper` over input split at arbitrary positions.
+pub(crate) struct Stepped<S>
+{
+	stepper: S,
+	pending: Pending,
+}
+
+impl<S> Stepped<S>
+{
+	pub(crate) fn new(stepper: S) -> Self
+	{
+		Self {
+			stepper,
+			pending: Pending::default(),
+		}
+	}
+}
+
+impl<S: Stepper> RawDecoder for Stepped<S>
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>)
+	{
+		let mut i = 0;
+		// Complete the sequence left over by the previous call one byte at a time. A step
+		// may consume less than the whole buffer, leaving the rest for the next step.
+		while !self.pending.is_empty()
+		{
+			let earlier = self.pending.len();
+			let mut buffer = self.pending;
+			let step = loop
+			{
+				match input.get(i)
+				{
+					Some(&b) => buffer.push(b),
+					None =>
+					{
+						self.pending = buffer;
+						return (input.len(), None);
+					}
+				}
+				i += 1;
+				match self.stepper.step(buffer.as_slice(), output)
+				{
+					Step::Incomplete => continue,
+					step => break step,
+				}
+			};
+			let (n, malformed) = match step
+			{
+				Step::Decoded(n) => (n, false),
+				Step::Malformed(n) => (n, true),
+				Step::Incomplete => unreachable!(),
+			};
+			self.pending = Pending::default();
+			for &b in &buffer.as_slice()[n.min(earlier)..earlier]
+			{
+				self.pending.push(b);
+			}
+			i = n.saturating_sub(earlier);
+			if malformed
+			{
+				let error = RawDecodeError::new(i)
+					.held(earlier)
+					.excess(earlier.saturating_sub(n));
+				return (0, Some(error));
+			}
+		}
+
+		while i < input.len()
+		{
+			match self.stepper.step(&input[i..], output)
+			{
+				Step::Incomplete =>
+				{
+					for &b in &input[i..]
+					{
+						self.pending.push(b);
+					}
+					break;
+				}
+				Step::Decoded(n) => i += n,
+				Step::Malformed(n) => return (i, Some(RawDecodeError::new(i + n))),
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut String) -> Option<RawDecodeError>
+	{
+		if self.pending.is_empty()
+		{
+			None
+		}
+		else
+		{
+			let held = self.pending.len();
+			self.pending = Pending::default();
+			Some(RawDecodeError::new(0).held(held))
+		}
+	}
+}
+
+#[cfg(test)]
+pub(crate) mod tests
+{
+	use super::*;
+
+	/// Checks that `text` and `bytes` convert into each other, in one call and split in two at
+	/// every position.
+	pub(crate) fn round_trip(codec: Codec, text: &str, bytes: &[u8])
+	{
+		assert_eq!(codec.try_decode(bytes).unwrap(), text);
+		assert_eq!(codec.encode(text).unwrap(), bytes);
+		for i in 0..=bytes.len()
+		{
+			let mut decoder = codec.new_decoder().with_error_mode(ErrorMode::Strict);
+			let mut decoded = String::new();
+			decoder
+				.decode_to_string(&bytes[..i], &mut decoded, false)
+				.unwrap();
+			decoder
+				.decode_to_string(&bytes[i..], &mut decoded, true)
+				.unwrap();
+			assert_eq!(decoded, text, "split at byte {}", i);
+		}
+		for i in (0..=text.len()).filter(|&i| text.is_char_boundary(i))
+		{
+			let mut encoder = codec.new_encoder().with_error_mode(ErrorMode::Strict);
+			let mut encoded = Vec::new();
+			encoder
+				.encode_to_vec(&text[..i], &mut encoded, false)
+				.unwrap();
+			encoder
+				.encode_to_vec(&text[i..], &mut encoded, true)
+				.unwrap();
+			assert_eq!(encoded, bytes, "split at byte {}", i);
+		}
+	}
+
+	/// Checks that strict decoding of `bytes` fails on `sequence` at `offset`, in one call and
+	/// split in two at every position.
+	pub(crate) fn malformed(codec: Codec, bytes: &[u8], offset: usize, sequence: &[u8])
+	{
+		for i in 0..=bytes.len()
+		{
+			let mut decoder = codec.new_decoder().with_error_mode(ErrorMode::Strict);
+			let mut decoded = String::new();
+			let error = decoder
+				.decode_to_string(&bytes[..i], &mut decoded, false)
+				.and_then(|_| decoder.decode_to_string(&bytes[i..], &mut decoded, true))
+				.unwrap_err();
+			assert_eq!(
+				(error.offset(), error.bytes()),
+				(offset, sequence),
+				"split at byte {}",
+				i
+			);
+		}
+	}
+
+	#[test]
+	fn debug_shows_the_malformed_bytes()
+	{
+		let error = CharacterSetEnum::UTF_8
+			.codec()
+			.unwrap()
+			.try_decode(b"a\xFF")
+			.unwrap_err();
+		assert_eq!(
+			format!("{:?}", error),
+			"DecodeError { charset: UTF_8, offset: 1, bytes: [255] }"
+		);
+	}
+}
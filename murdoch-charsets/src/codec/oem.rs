@@ -0,0 +1,164 @@
+//! DOS (OEM) code pages.
+//!
+//! With glyphs enabled, bytes `0x01..=0x1F` and `0x7F` decode to the symbols the IBM PC displays
+//! for them instead of controls. Encoding accepts both, preferring a code page's own byte for
+//! characters like U+00A7 that it also has in its upper half.
+use super::{
+	single_byte::SingleByte,
+	tables::Table,
+	RawDecodeError,
+	RawDecoder,
+	RawEncodeError,
+	RawEncoder,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// Code page 437 glyphs of `0x01..=0x1F`; `0x00` has none.
+static GLYPHS: Table<32> = Table::new([
+	0xFFFD, 0x263A, 0x263B, 0x2665, 0x2666, 0x2663, 0x2660, 0x2022, 0x25D8, 0x25CB, 0x25D9, 0x2642,
+	0x2640, 0x266A, 0x266B, 0x263C, 0x25BA, 0x25C4, 0x2195, 0x203C, 0x00B6, 0x00A7, 0x25AC, 0x21A8,
+	0x2191, 0x2193, 0x2192, 0x2190, 0x221F, 0x2194, 0x25B2, 0x25BC,
+]);
+
+/// Code page 437 glyph of `0x7F`.
+const HOUSE: char = '\u{2302}';
+
+#[derive(Copy, Clone)]
+pub(crate) struct Oem
+{
+	table: SingleByte,
+	glyphs: bool,
+}
+
+impl Oem
+{
+	pub(crate) fn new(table: SingleByte) -> Self
+	{
+		Self {
+			table,
+			glyphs: false,
+		}
+	}
+
+	pub(crate) fn with_glyphs(self, glyphs: bool) -> Self
+	{
+		Self { glyphs, ..self }
+	}
+
+	pub(crate) fn is_ascii_compatible(&self) -> bool
+	{
+		!self.glyphs && self.table.is_ascii_compatible()
+	}
+
+	fn decode_byte(&self, b: u8) -> Option<char>
+	{
+		match b
+		{
+			0x01..=0x1F if self.glyphs => SingleByte::new(0, &GLYPHS).decode_byte(b),
+			0x7F if self.glyphs => Some(HOUSE),
+			b => self.table.decode_byte(b),
+		}
+	}
+
+	fn encode_char(&self, c: char) -> Option<u8>
+	{
+		self.table.encode_char(c).or_else(|| {
+			if !self.glyphs
+			{
+				None
+			}
+			else if c == HOUSE
+			{
+				Some(0x7F)
+			}
+			else
+			{
+				SingleByte::new(0, &GLYPHS).encode_char(c)
+			}
+		})
+	}
+}
+
+impl RawDecoder for Oem
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>)
+	{
+		for (i, &b) in input.iter().enumerate()
+		{
+			match self.decode_byte(b)
+			{
+				Some(c) => output.push(c),
+				None => return (i, Some(RawDecodeError::new(i + 1))),
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut String) -> Option<RawDecodeError>
+	{
+		None
+	}
+}
+
+impl RawEncoder for Oem
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for (i, c) in input.char_indices()
+		{
+			match self.encode_char(c)
+			{
+				Some(b) => output.push(b),
+				None =>
+				{
+					return (
+						i,
+						Some(RawEncodeError {
+							upto: i + c.len_utf8(),
+						}),
+					)
+				}
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_dos_code_pages()
+	{
+		let codec = CharacterSetEnum::IBM437.codec().unwrap();
+		round_trip(codec, "é░\u{1}", b"\x82\xB0\x01");
+		assert!(codec.encode("☺").is_err());
+
+		let codec = CharacterSetEnum::IBM891.codec().unwrap();
+		malformed(codec, b"a\x80", 1, b"\x80");
+	}
+
+	#[test]
+	fn converts_glyphs_if_enabled()
+	{
+		let codec = CharacterSetEnum::IBM437
+			.codec()
+			.unwrap()
+			.with_oem_glyphs(true);
+		round_trip(codec, "☺§⌂é", b"\x01\x15\x7F\x82");
+		assert_eq!(codec.encode("\u{1}").unwrap(), &b"\x01"[..]);
+	}
+}
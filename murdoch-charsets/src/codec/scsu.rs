@@ -0,0 +1,449 @@
+//! SCSU, the Standard Compression Scheme for Unicode (Unicode Technical Standard #6).
+//!
+//! The encoder chooses windows greedily, one character at a time: it stays in single-byte mode
+//! while characters fall in a window it has or can define, and switches to Unicode mode for the
+//! rest, such as Han ideographs and Hangul syllables.
+use super::{
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// Offsets of the static windows, used by the quote tags.
+const STATIC_WINDOWS: [u32; 8] = [
+	0x0000, 0x0080, 0x0100, 0x0300, 0x2000, 0x2080, 0x2100, 0x3000,
+];
+
+/// Offsets of the dynamic windows at the start of the text.
+const DYNAMIC_WINDOWS: [u32; 8] = [
+	0x0080, 0x00C0, 0x0400, 0x0600, 0x0900, 0x3040, 0x30A0, 0xFF00,
+];
+
+// Tags of single-byte mode.
+const SQ0: u8 = 0x01;
+const SDX: u8 = 0x0B;
+const SQU: u8 = 0x0E;
+const SCU: u8 = 0x0F;
+const SC0: u8 = 0x10;
+const SD0: u8 = 0x18;
+
+// Tags of Unicode mode.
+const UC0: u8 = 0xE0;
+const UD0: u8 = 0xE8;
+const UQU: u8 = 0xF0;
+const UDX: u8 = 0xF1;
+
+/// The window offset a window definition byte selects.
+fn window_offset(x: u8) -> Option<u32>
+{
+	let x32 = u32::from(x);
+	match x
+	{
+		0x01..=0x67 => Some(x32 * 0x80),
+		0x68..=0xA7 => Some(x32 * 0x80 + 0xAC00),
+		0xF9 => Some(0x00C0),
+		0xFA => Some(0x0250),
+		0xFB => Some(0x0370),
+		0xFC => Some(0x0530),
+		0xFD => Some(0x3040),
+		0xFE => Some(0x30A0),
+		0xFF => Some(0xFF60),
+		_ => None,
+	}
+}
+
+/// The window definition byte of a half-block window containing `c`, if one can.
+fn window_byte(c: u32) -> Option<u8>
+{
+	match c
+	{
+		0x0080..=0x33FF => Some((c >> 7) as u8),
+		0xE000..=0xFFFF => Some(((c - 0xAC00) >> 7) as u8),
+		_ => None,
+	}
+}
+
+/// The offset an extended window definition selects, and the window it goes to.
+fn extended_window(high: u8, low: u8) -> (usize, u32)
+{
+	let offset = u32::from(high & 0x1F) << 8 | u32::from(low);
+	(usize::from(high >> 5), 0x10000 + offset * 0x80)
+}
+
+fn is_pass_through(b: u32) -> bool
+{
+	matches!(b, 0x00 | 0x09 | 0x0A | 0x0D | 0x20..=0x7F)
+}
+
+pub(crate) struct Scsu
+{
+	/// Whether Unicode mode is active rather than single-byte mode.
+	unicode: bool,
+	/// The active dynamic window.
+	window: usize,
+	offsets: [u32; 8],
+	/// The dynamic window the encoder redefines next.
+	next: usize,
+}
+
+impl Scsu
+{
+	pub(crate) fn new() -> Self
+	{
+		Self {
+			unicode: false,
+			window: 0,
+			offsets: DYNAMIC_WINDOWS,
+			next: 0,
+		}
+	}
+
+	/// Decodes the code unit read from the first `len` bytes, pairing a high surrogate with the
+	/// low surrogate that follows it, quoted or, in Unicode mode, as is.
+	fn unit(&self, unit: u16, len: usize, bytes: &[u8], output: &mut String) -> Step
+	{
+		match unit
+		{
+			0xD800..=0xDBFF =>
+			{}
+			0xDC00..=0xDFFF => return Step::Malformed(len),
+			unit =>
+			{
+				output.extend(char::from_u32(u32::from(unit)));
+				return Step::Decoded(len);
+			}
+		}
+		let rest = &bytes[len..];
+		let quote = if self.unicode { UQU } else { SQU };
+		let skip = match rest.first()
+		{
+			None => return Step::Incomplete,
+			Some(&b) if b == quote => 1,
+			Some(0xDC..=0xDF) if self.unicode => 0,
+			Some(_) => return Step::Malformed(len),
+		};
+		if rest.len() < skip + 2
+		{
+			return Step::Incomplete;
+		}
+		let low = u32::from(rest[skip]) << 8 | u32::from(rest[skip + 1]);
+		if !(0xDC00..=0xDFFF).contains(&low)
+		{
+			return Step::Malformed(len);
+		}
+		let c = 0x10000 + ((u32::from(unit) - 0xD800) << 10) + low - 0xDC00;
+		output.extend(char::from_u32(c));
+		Step::Decoded(len + skip + 2)
+	}
+
+	fn step_single_byte(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		let len = match b
+		{
+			SQ0..=0x08 | SD0..=0x1F => 2,
+			SDX | SQU => 3,
+			_ => 1,
+		};
+		if bytes.len() < len
+		{
+			return Step::Incomplete;
+		}
+		let c = match b
+		{
+			b if is_pass_through(u32::from(b)) => u32::from(b),
+			0x80..=0xFF => self.offsets[self.window] + u32::from(b - 0x80),
+			SQ0..=0x08 =>
+			{
+				let n = usize::from(b - SQ0);
+				match bytes[1]
+				{
+					t @ 0x00..=0x7F => STATIC_WINDOWS[n] + u32::from(t),
+					t => self.offsets[n] + u32::from(t - 0x80),
+				}
+			}
+			SDX =>
+			{
+				let (n, offset) = extended_window(bytes[1], bytes[2]);
+				self.offsets[n] = offset;
+				self.window = n;
+				return Step::Decoded(3);
+			}
+			SQU =>
+			{
+				let unit = u16::from(bytes[1]) << 8 | u16::from(bytes[2]);
+				return self.unit(unit, 3, bytes, output);
+			}
+			SCU =>
+			{
+				self.unicode = true;
+				return Step::Decoded(1);
+			}
+			SC0..=0x17 =>
+			{
+				self.window = usize::from(b - SC0);
+				return Step::Decoded(1);
+			}
+			SD0..=0x1F =>
+			{
+				return match window_offset(bytes[1])
+				{
+					Some(offset) =>
+					{
+						self.window = usize::from(b - SD0);
+						self.offsets[self.window] = offset;
+						Step::Decoded(2)
+					}
+					None => Step::Malformed(2),
+				};
+			}
+			_ => return Step::Malformed(1),
+		};
+		match char::from_u32(c)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(len)
+			}
+			None => Step::Malformed(len),
+		}
+	}
+
+	fn step_unicode(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		let b = bytes[0];
+		match b
+		{
+			UC0..=0xE7 =>
+			{
+				self.window = usize::from(b - UC0);
+				self.unicode = false;
+				return Step::Decoded(1);
+			}
+			0xF2 => return Step::Malformed(1),
+			_ =>
+			{}
+		}
+		let len = if b == UQU || b == UDX { 3 } else { 2 };
+		if bytes.len() < len
+		{
+			return Step::Incomplete;
+		}
+		match b
+		{
+			UD0..=0xEF =>
+			{
+				match window_offset(bytes[1])
+				{
+					Some(offset) =>
+					{
+						self.window = usize::from(b - UD0);
+						self.offsets[self.window] = offset;
+						self.unicode = false;
+						Step::Decoded(2)
+					}
+					None => Step::Malformed(2),
+				}
+			}
+			UDX =>
+			{
+				let (n, offset) = extended_window(bytes[1], bytes[2]);
+				self.offsets[n] = offset;
+				self.window = n;
+				self.unicode = false;
+				Step::Decoded(3)
+			}
+			UQU =>
+			{
+				let unit = u16::from(bytes[1]) << 8 | u16::from(bytes[2]);
+				self.unit(unit, 3, bytes, output)
+			}
+			_ =>
+			{
+				let unit = u16::from(b) << 8 | u16::from(bytes[1]);
+				self.unit(unit, 2, bytes, output)
+			}
+		}
+	}
+
+	/// The dynamic window containing `c`, preferring the active one.
+	fn window_for(&self, c: u32) -> Option<usize>
+	{
+		let contains = |n: usize| (self.offsets[n]..self.offsets[n] + 0x80).contains(&c);
+		if contains(self.window)
+		{
+			Some(self.window)
+		}
+		else
+		{
+			(0..8).find(|&n| contains(n))
+		}
+	}
+
+	/// Writes `c` through a window, defining one if needed, with the tags of the current mode.
+	/// Returns `false` if no window can hold it.
+	fn encode_windowed(&mut self, c: u32, output: &mut Vec<u8>) -> bool
+	{
+		let (change, define, define_extended) = if self.unicode
+		{
+			(UC0, UD0, UDX)
+		}
+		else
+		{
+			(SC0, SD0, SDX)
+		};
+		if let Some(n) = self.window_for(c)
+		{
+			if self.unicode || n != self.window
+			{
+				output.push(change + n as u8);
+			}
+			self.window = n;
+		}
+		else
+		{
+			let n = self.next;
+			if let Some(x) = window_byte(c)
+			{
+				output.extend_from_slice(&[define + n as u8, x]);
+				self.offsets[n] = window_offset(x).unwrap_or_default();
+			}
+			else if c >= 0x10000
+			{
+				let offset = (c - 0x10000) >> 7;
+				output.extend_from_slice(&[
+					define_extended,
+					((n as u32) << 5 | offset >> 8) as u8,
+					offset as u8,
+				]);
+				self.offsets[n] = 0x10000 + (offset << 7);
+			}
+			else
+			{
+				return false;
+			}
+			self.next = (n + 1) % 8;
+			self.window = n;
+		}
+		self.unicode = false;
+		output.push(0x80 + (c - self.offsets[self.window]) as u8);
+		true
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>)
+	{
+		let cp = u32::from(c);
+		if !self.unicode
+		{
+			if is_pass_through(cp)
+			{
+				output.push(cp as u8);
+			}
+			else if cp < 0x20
+			{
+				output.extend_from_slice(&[SQ0, cp as u8]);
+			}
+			else if !self.encode_windowed(cp, output)
+			{
+				output.push(SCU);
+				self.unicode = true;
+				self.encode_units(c, output);
+			}
+			return;
+		}
+		if (0x20..=0x7F).contains(&cp)
+		{
+			output.extend_from_slice(&[UC0 + self.window as u8, cp as u8]);
+			self.unicode = false;
+		}
+		else if !(0x20..0x10000).contains(&cp) || !self.encode_windowed(cp, output)
+		{
+			self.encode_units(c, output);
+		}
+	}
+
+	/// Writes `c` in Unicode mode, quoting code units that would read as tags.
+	fn encode_units(&self, c: char, output: &mut Vec<u8>)
+	{
+		let mut units = [0; 2];
+		for &mut unit in c.encode_utf16(&mut units)
+		{
+			if (0xE000..=0xF2FF).contains(&unit)
+			{
+				output.push(UQU);
+			}
+			output.extend_from_slice(&unit.to_be_bytes());
+		}
+	}
+}
+
+impl Stepper for Scsu
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		if self.unicode
+		{
+			self.step_unicode(bytes, output)
+		}
+		else
+		{
+			self.step_single_byte(bytes, output)
+		}
+	}
+}
+
+impl RawEncoder for Scsu
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for c in input.chars()
+		{
+			self.encode_char(c, output);
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_scsu()
+	{
+		let codec = CharacterSetEnum::SCSU.codec().unwrap();
+		round_trip(
+			codec,
+			"Öl 日本 Ж",
+			b"\xD6l \x0F\x65\xE5\x67\x2C\xE0\x20\x12\x96",
+		);
+		// As ICU encodes it, staying in Unicode mode for the space.
+		assert_eq!(
+			codec.decode(b"\xD6l \x0F\x65\xE5\x67\x2C\x00\x20\xE2\x96"),
+			"Öl 日本 Ж"
+		);
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::SCSU.codec().unwrap();
+		malformed(codec, b"a\x0C", 1, b"\x0C");
+		malformed(codec, b"a\x0F\xE8", 2, b"\xE8");
+	}
+}
@@ -0,0 +1,205 @@
+//! Table-driven single-byte character sets.
+use super::{
+	tables::Table,
+	RawDecodeError,
+	RawDecoder,
+	RawEncodeError,
+	RawEncoder,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+use core::convert::TryFrom;
+
+/// US-ASCII: nothing above `0x7F` is defined.
+pub(crate) static US_ASCII: Table<128> = Table::new([0xFFFD; 128]);
+
+/// Maps the bytes from `first` upwards through a table; bytes below `first` are ASCII, and bytes
+/// beyond the end of the table are unmapped.
+#[derive(Copy, Clone)]
+pub(crate) struct SingleByte
+{
+	first: u8,
+	code_points: &'static [u16],
+	bytes: &'static [u8],
+}
+
+impl SingleByte
+{
+	pub(crate) fn new<const N: usize>(first: u8, table: &'static Table<N>) -> Self
+	{
+		Self {
+			first,
+			code_points: &table.code_points,
+			bytes: &table.bytes,
+		}
+	}
+
+	/// A character set whose lower half is ASCII.
+	pub(crate) fn high(table: &'static Table<128>) -> Self
+	{
+		Self::new(0x80, table)
+	}
+
+	/// A 7-bit character set, such as a national variant of ISO 646; bytes above `0x7F` are
+	/// unmapped.
+	pub(crate) fn seven_bit(table: &'static Table<128>) -> Self
+	{
+		Self::new(0, table)
+	}
+
+	/// A character set that maps every byte itself, such as EBCDIC.
+	pub(crate) fn full(table: &'static Table<256>) -> Self
+	{
+		Self::new(0, table)
+	}
+
+	pub(crate) fn is_ascii_compatible(&self) -> bool
+	{
+		self.first == 0x80
+	}
+
+	pub(crate) fn decode_byte(&self, b: u8) -> Option<char>
+	{
+		if b < self.first
+		{
+			return Some(char::from(b));
+		}
+		match self.code_points.get(usize::from(b - self.first))
+		{
+			Some(0xFFFD) | None => None,
+			Some(&c) => char::from_u32(u32::from(c)),
+		}
+	}
+
+	pub(crate) fn encode_char(&self, c: char) -> Option<u8>
+	{
+		if u32::from(c) < u32::from(self.first)
+		{
+			return Some(c as u8);
+		}
+		let c = u16::try_from(u32::from(c)).ok().filter(|&c| c != 0xFFFD)?;
+		let code_point = |i: u8| self.code_points[usize::from(i)];
+		let i = self.bytes.partition_point(|&i| code_point(i) < c);
+		let i = self.bytes.get(i).copied().filter(|&i| code_point(i) == c)?;
+		Some(self.first + i)
+	}
+}
+
+impl RawDecoder for SingleByte
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>)
+	{
+		for (i, &b) in input.iter().enumerate()
+		{
+			match self.decode_byte(b)
+			{
+				Some(c) => output.push(c),
+				None => return (i, Some(RawDecodeError::new(i + 1))),
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut String) -> Option<RawDecodeError>
+	{
+		None
+	}
+}
+
+impl RawEncoder for SingleByte
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for (i, c) in input.char_indices()
+		{
+			match self.encode_char(c)
+			{
+				Some(b) => output.push(b),
+				None =>
+				{
+					return (
+						i,
+						Some(RawEncodeError {
+							upto: i + c.len_utf8(),
+						}),
+					)
+				}
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::super::{
+		tests::{
+			malformed,
+			round_trip,
+		},
+		Kind,
+	};
+	use crate::CharacterSetEnum;
+
+	#[test]
+	fn converts_single_byte_sets()
+	{
+		let codec = CharacterSetEnum::WINDOWS_1251.codec().unwrap();
+		round_trip(codec, "Привет", b"\xCF\xF0\xE8\xE2\xE5\xF2");
+
+		let codec = CharacterSetEnum::US_ASCII.codec().unwrap();
+		malformed(codec, b"a\x80", 1, b"\x80");
+		let codec = CharacterSetEnum::ISO_8859_3_1988.codec().unwrap();
+		malformed(codec, b"a\xA5", 1, b"\xA5");
+	}
+
+	#[test]
+	fn encodes_to_the_first_byte_decoding_to_a_character()
+	{
+		for charset in CharacterSetEnum::iter()
+		{
+			let table = match Kind::of(charset)
+			{
+				Some(Kind::SingleByte(table)) => table,
+				_ => continue,
+			};
+			for b in 0..=0xFF
+			{
+				if let Some(c) = table.decode_byte(b)
+				{
+					let first = (0..=0xFF).find(|&b| table.decode_byte(b) == Some(c));
+					assert_eq!(table.encode_char(c), first, "{} {:#04X}", charset, b);
+				}
+			}
+			assert_eq!(table.encode_char('\u{FFFD}'), None);
+		}
+	}
+
+	#[test]
+	fn converts_7_bit_sets()
+	{
+		let codec = CharacterSetEnum::DIN_66003.codec().unwrap();
+		assert_eq!(codec.decode(b"[\\]{|}~@"), "ÄÖÜäöüß§");
+		assert_eq!(codec.encode("Straße").unwrap(), &b"Stra~e"[..]);
+		assert!(codec.encode("[").is_err());
+		assert!(codec.try_decode(b"\x80").is_err());
+
+		let codec = CharacterSetEnum::BS_4730.codec().unwrap();
+		assert_eq!(codec.decode(b"#5"), "£5");
+		assert_eq!(codec.encode("£‾").unwrap(), &b"#~"[..]);
+	}
+
+	#[test]
+	fn converts_jis_x0201()
+	{
+		let codec = CharacterSetEnum::JIS_X0201.codec().unwrap();
+		assert_eq!(codec.decode(b"\x5C\xB1\xDF"), "¥ｱﾟ");
+		assert_eq!(codec.encode("‾ｶ").unwrap(), &b"\x7E\xB6"[..]);
+		assert!(codec.encode("\\").is_err());
+	}
+}
@@ -0,0 +1,8507 @@
+//! Tables of the Chinese coded character sets.
+use super::Index;
+
+/// GB 2312, indexed by `(row - 1) * 94 + (cell - 1)`.
+pub(crate) static GB_2312: Index = Index {
+	code_points: &[
+		0x3000, 0x3001, 0x3002, 0x30FB, 0x02C9, 0x02C7, 0x00A8, 0x3003, 0x3005, 0x2015, 0xFF5E,
+		0x2016, 0x2026, 0x2018, 0x2019, 0x201C, 0x201D, 0x3014, 0x3015, 0x3008, 0x3009, 0x300A,
+		0x300B, 0x300C, 0x300D, 0x300E, 0x300F, 0x3016, 0x3017, 0x3010, 0x3011, 0x00B1, 0x00D7,
+		0x00F7, 0x2236, 0x2227, 0x2228, 0x2211, 0x220F, 0x222A, 0x2229, 0x2208, 0x2237, 0x221A,
+		0x22A5, 0x2225, 0x2220, 0x2312, 0x2299, 0x222B, 0x222E, 0x2261, 0x224C, 0x2248, 0x223D,
+		0x221D, 0x2260, 0x226E, 0x226F, 0x2264, 0x2265, 0x221E, 0x2235, 0x2234, 0x2642, 0x2640,
+		0x00B0, 0x2032, 0x2033, 0x2103, 0xFF04, 0x00A4, 0xFFE0, 0xFFE1, 0x2030, 0x00A7, 0x2116,
+		0x2606, 0x2605, 0x25CB, 0x25CF, 0x25CE, 0x25C7, 0x25C6, 0x25A1, 0x25A0, 0x25B3, 0x25B2,
+		0x203B, 0x2192, 0x2190, 0x2191, 0x2193, 0x3013, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0x2488, 0x2489, 0x248A, 0x248B, 0x248C, 0x248D, 0x248E, 0x248F, 0x2490, 0x2491, 0x2492,
+		0x2493, 0x2494, 0x2495, 0x2496, 0x2497, 0x2498, 0x2499, 0x249A, 0x249B, 0x2474, 0x2475,
+		0x2476, 0x2477, 0x2478, 0x2479, 0x247A, 0x247B, 0x247C, 0x247D, 0x247E, 0x247F, 0x2480,
+		0x2481, 0x2482, 0x2483, 0x2484, 0x2485, 0x2486, 0x2487, 0x2460, 0x2461, 0x2462, 0x2463,
+		0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469, 0xFFFD, 0xFFFD, 0x3220, 0x3221, 0x3222,
+		0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229, 0xFFFD, 0xFFFD, 0x2160, 0x2161,
+		0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x216A, 0x216B, 0xFFFD,
+		0xFFFD, 0xFF01, 0xFF02, 0xFF03, 0xFFE5, 0xFF05, 0xFF06, 0xFF07, 0xFF08, 0xFF09, 0xFF0A,
+		0xFF0B, 0xFF0C, 0xFF0D, 0xFF0E, 0xFF0F, 0xFF10, 0xFF11, 0xFF12, 0xFF13, 0xFF14, 0xFF15,
+		0xFF16, 0xFF17, 0xFF18, 0xFF19, 0xFF1A, 0xFF1B, 0xFF1C, 0xFF1D, 0xFF1E, 0xFF1F, 0xFF20,
+		0xFF21, 0xFF22, 0xFF23, 0xFF24, 0xFF25, 0xFF26, 0xFF27, 0xFF28, 0xFF29, 0xFF2A, 0xFF2B,
+		0xFF2C, 0xFF2D, 0xFF2E, 0xFF2F, 0xFF30, 0xFF31, 0xFF32, 0xFF33, 0xFF34, 0xFF35, 0xFF36,
+		0xFF37, 0xFF38, 0xFF39, 0xFF3A, 0xFF3B, 0xFF3C, 0xFF3D, 0xFF3E, 0xFF3F, 0xFF40, 0xFF41,
+		0xFF42, 0xFF43, 0xFF44, 0xFF45, 0xFF46, 0xFF47, 0xFF48, 0xFF49, 0xFF4A, 0xFF4B, 0xFF4C,
+		0xFF4D, 0xFF4E, 0xFF4F, 0xFF50, 0xFF51, 0xFF52, 0xFF53, 0xFF54, 0xFF55, 0xFF56, 0xFF57,
+		0xFF58, 0xFF59, 0xFF5A, 0xFF5B, 0xFF5C, 0xFF5D, 0xFFE3, 0x3041, 0x3042, 0x3043, 0x3044,
+		0x3045, 0x3046, 0x3047, 0x3048, 0x3049, 0x304A, 0x304B, 0x304C, 0x304D, 0x304E, 0x304F,
+		0x3050, 0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059, 0x305A,
+		0x305B, 0x305C, 0x305D, 0x305E, 0x305F, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065,
+		0x3066, 0x3067, 0x3068, 0x3069, 0x306A, 0x306B, 0x306C, 0x306D, 0x306E, 0x306F, 0x3070,
+		0x3071, 0x3072, 0x3073, 0x3074, 0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307A, 0x307B,
+		0x307C, 0x307D, 0x307E, 0x307F, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086,
+		0x3087, 0x3088, 0x3089, 0x308A, 0x308B, 0x308C, 0x308D, 0x308E, 0x308F, 0x3090, 0x3091,
+		0x3092, 0x3093, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0x30A1, 0x30A2, 0x30A3, 0x30A4, 0x30A5, 0x30A6, 0x30A7, 0x30A8, 0x30A9,
+		0x30AA, 0x30AB, 0x30AC, 0x30AD, 0x30AE, 0x30AF, 0x30B0, 0x30B1, 0x30B2, 0x30B3, 0x30B4,
+		0x30B5, 0x30B6, 0x30B7, 0x30B8, 0x30B9, 0x30BA, 0x30BB, 0x30BC, 0x30BD, 0x30BE, 0x30BF,
+		0x30C0, 0x30C1, 0x30C2, 0x30C3, 0x30C4, 0x30C5, 0x30C6, 0x30C7, 0x30C8, 0x30C9, 0x30CA,
+		0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D0, 0x30D1, 0x30D2, 0x30D3, 0x30D4, 0x30D5,
+		0x30D6, 0x30D7, 0x30D8, 0x30D9, 0x30DA, 0x30DB, 0x30DC, 0x30DD, 0x30DE, 0x30DF, 0x30E0,
+		0x30E1, 0x30E2, 0x30E3, 0x30E4, 0x30E5, 0x30E6, 0x30E7, 0x30E8, 0x30E9, 0x30EA, 0x30EB,
+		0x30EC, 0x30ED, 0x30EE, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x30F3, 0x30F4, 0x30F5, 0x30F6,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x0391, 0x0392, 0x0393,
+		0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E,
+		0x039F, 0x03A0, 0x03A1, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x03B1, 0x03B2, 0x03B3, 0x03B4,
+		0x03B5, 0x03B6, 0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF,
+		0x03C0, 0x03C1, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0401, 0x0416,
+		0x0417, 0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420, 0x0421,
+		0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B, 0x042C,
+		0x042D, 0x042E, 0x042F, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x0430, 0x0431, 0x0432, 0x0433,
+		0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D,
+		0x043E, 0x043F, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448,
+		0x0449, 0x044A, 0x044B, 0x044C, 0x044D, 0x044E, 0x044F, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x0101, 0x00E1,
+		0x01CE, 0x00E0, 0x0113, 0x00E9, 0x011B, 0x00E8, 0x012B, 0x00ED, 0x01D0, 0x00EC, 0x014D,
+		0x00F3, 0x01D2, 0x00F2, 0x016B, 0x00FA, 0x01D4, 0x00F9, 0x01D6, 0x01D8, 0x01DA, 0x01DC,
+		0x00FC, 0x00EA, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0x3105, 0x3106, 0x3107, 0x3108, 0x3109, 0x310A, 0x310B, 0x310C, 0x310D, 0x310E,
+		0x310F, 0x3110, 0x3111, 0x3112, 0x3113, 0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119,
+		0x311A, 0x311B, 0x311C, 0x311D, 0x311E, 0x311F, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124,
+		0x3125, 0x3126, 0x3127, 0x3128, 0x3129, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x2500, 0x2501, 0x2502, 0x2503,
+		0x2504, 0x2505, 0x2506, 0x2507, 0x2508, 0x2509, 0x250A, 0x250B, 0x250C, 0x250D, 0x250E,
+		0x250F, 0x2510, 0x2511, 0x2512, 0x2513, 0x2514, 0x2515, 0x2516, 0x2517, 0x2518, 0x2519,
+		0x251A, 0x251B, 0x251C, 0x251D, 0x251E, 0x251F, 0x2520, 0x2521, 0x2522, 0x2523, 0x2524,
+		0x2525, 0x2526, 0x2527, 0x2528, 0x2529, 0x252A, 0x252B, 0x252C, 0x252D, 0x252E, 0x252F,
+		0x2530, 0x2531, 0x2532, 0x2533, 0x2534, 0x2535, 0x2536, 0x2537, 0x2538, 0x2539, 0x253A,
+		0x253B, 0x253C, 0x253D, 0x253E, 0x253F, 0x2540, 0x2541, 0x2542, 0x2543, 0x2544, 0x2545,
+		0x2546, 0x2547, 0x2548, 0x2549, 0x254A, 0x254B, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0x554A, 0x963F, 0x57C3, 0x6328, 0x54CE, 0x5509, 0x54C0, 0x7691, 0x764C,
+		0x853C, 0x77EE, 0x827E, 0x788D, 0x7231, 0x9698, 0x978D, 0x6C28, 0x5B89, 0x4FFA, 0x6309,
+		0x6697, 0x5CB8, 0x80FA, 0x6848, 0x80AE, 0x6602, 0x76CE, 0x51F9, 0x6556, 0x71AC, 0x7FF1,
+		0x8884, 0x50B2, 0x5965, 0x61CA, 0x6FB3, 0x82AD, 0x634C, 0x6252, 0x53ED, 0x5427, 0x7B06,
+		0x516B, 0x75A4, 0x5DF4, 0x62D4, 0x8DCB, 0x9776, 0x628A, 0x8019, 0x575D, 0x9738, 0x7F62,
+		0x7238, 0x767D, 0x67CF, 0x767E, 0x6446, 0x4F70, 0x8D25, 0x62DC, 0x7A17, 0x6591, 0x73ED,
+		0x642C, 0x6273, 0x822C, 0x9881, 0x677F, 0x7248, 0x626E, 0x62CC, 0x4F34, 0x74E3, 0x534A,
+		0x529E, 0x7ECA, 0x90A6, 0x5E2E, 0x6886, 0x699C, 0x8180, 0x7ED1, 0x68D2, 0x78C5, 0x868C,
+		0x9551, 0x508D, 0x8C24, 0x82DE, 0x80DE, 0x5305, 0x8912, 0x5265, 0x8584, 0x96F9, 0x4FDD,
+		0x5821, 0x9971, 0x5B9D, 0x62B1, 0x62A5, 0x66B4, 0x8C79, 0x9C8D, 0x7206, 0x676F, 0x7891,
+		0x60B2, 0x5351, 0x5317, 0x8F88, 0x80CC, 0x8D1D, 0x94A1, 0x500D, 0x72C8, 0x5907, 0x60EB,
+		0x7119, 0x88AB, 0x5954, 0x82EF, 0x672C, 0x7B28, 0x5D29, 0x7EF7, 0x752D, 0x6CF5, 0x8E66,
+		0x8FF8, 0x903C, 0x9F3B, 0x6BD4, 0x9119, 0x7B14, 0x5F7C, 0x78A7, 0x84D6, 0x853D, 0x6BD5,
+		0x6BD9, 0x6BD6, 0x5E01, 0x5E87, 0x75F9, 0x95ED, 0x655D, 0x5F0A, 0x5FC5, 0x8F9F, 0x58C1,
+		0x81C2, 0x907F, 0x965B, 0x97AD, 0x8FB9, 0x7F16, 0x8D2C, 0x6241, 0x4FBF, 0x53D8, 0x535E,
+		0x8FA8, 0x8FA9, 0x8FAB, 0x904D, 0x6807, 0x5F6A, 0x8198, 0x8868, 0x9CD6, 0x618B, 0x522B,
+		0x762A, 0x5F6C, 0x658C, 0x6FD2, 0x6EE8, 0x5BBE, 0x6448, 0x5175, 0x51B0, 0x67C4, 0x4E19,
+		0x79C9, 0x997C, 0x70B3, 0x75C5, 0x5E76, 0x73BB, 0x83E0, 0x64AD, 0x62E8, 0x94B5, 0x6CE2,
+		0x535A, 0x52C3, 0x640F, 0x94C2, 0x7B94, 0x4F2F, 0x5E1B, 0x8236, 0x8116, 0x818A, 0x6E24,
+		0x6CCA, 0x9A73, 0x6355, 0x535C, 0x54FA, 0x8865, 0x57E0, 0x4E0D, 0x5E03, 0x6B65, 0x7C3F,
+		0x90E8, 0x6016, 0x64E6, 0x731C, 0x88C1, 0x6750, 0x624D, 0x8D22, 0x776C, 0x8E29, 0x91C7,
+		0x5F69, 0x83DC, 0x8521, 0x9910, 0x53C2, 0x8695, 0x6B8B, 0x60ED, 0x60E8, 0x707F, 0x82CD,
+		0x8231, 0x4ED3, 0x6CA7, 0x85CF, 0x64CD, 0x7CD9, 0x69FD, 0x66F9, 0x8349, 0x5395, 0x7B56,
+		0x4FA7, 0x518C, 0x6D4B, 0x5C42, 0x8E6D, 0x63D2, 0x53C9, 0x832C, 0x8336, 0x67E5, 0x78B4,
+		0x643D, 0x5BDF, 0x5C94, 0x5DEE, 0x8BE7, 0x62C6, 0x67F4, 0x8C7A, 0x6400, 0x63BA, 0x8749,
+		0x998B, 0x8C17, 0x7F20, 0x94F2, 0x4EA7, 0x9610, 0x98A4, 0x660C, 0x7316, 0x573A, 0x5C1D,
+		0x5E38, 0x957F, 0x507F, 0x80A0, 0x5382, 0x655E, 0x7545, 0x5531, 0x5021, 0x8D85, 0x6284,
+		0x949E, 0x671D, 0x5632, 0x6F6E, 0x5DE2, 0x5435, 0x7092, 0x8F66, 0x626F, 0x64A4, 0x63A3,
+		0x5F7B, 0x6F88, 0x90F4, 0x81E3, 0x8FB0, 0x5C18, 0x6668, 0x5FF1, 0x6C89, 0x9648, 0x8D81,
+		0x886C, 0x6491, 0x79F0, 0x57CE, 0x6A59, 0x6210, 0x5448, 0x4E58, 0x7A0B, 0x60E9, 0x6F84,
+		0x8BDA, 0x627F, 0x901E, 0x9A8B, 0x79E4, 0x5403, 0x75F4, 0x6301, 0x5319, 0x6C60, 0x8FDF,
+		0x5F1B, 0x9A70, 0x803B, 0x9F7F, 0x4F88, 0x5C3A, 0x8D64, 0x7FC5, 0x65A5, 0x70BD, 0x5145,
+		0x51B2, 0x866B, 0x5D07, 0x5BA0, 0x62BD, 0x916C, 0x7574, 0x8E0C, 0x7A20, 0x6101, 0x7B79,
+		0x4EC7, 0x7EF8, 0x7785, 0x4E11, 0x81ED, 0x521D, 0x51FA, 0x6A71, 0x53A8, 0x8E87, 0x9504,
+		0x96CF, 0x6EC1, 0x9664, 0x695A, 0x7840, 0x50A8, 0x77D7, 0x6410, 0x89E6, 0x5904, 0x63E3,
+		0x5DDD, 0x7A7F, 0x693D, 0x4F20, 0x8239, 0x5598, 0x4E32, 0x75AE, 0x7A97, 0x5E62, 0x5E8A,
+		0x95EF, 0x521B, 0x5439, 0x708A, 0x6376, 0x9524, 0x5782, 0x6625, 0x693F, 0x9187, 0x5507,
+		0x6DF3, 0x7EAF, 0x8822, 0x6233, 0x7EF0, 0x75B5, 0x8328, 0x78C1, 0x96CC, 0x8F9E, 0x6148,
+		0x74F7, 0x8BCD, 0x6B64, 0x523A, 0x8D50, 0x6B21, 0x806A, 0x8471, 0x56F1, 0x5306, 0x4ECE,
+		0x4E1B, 0x51D1, 0x7C97, 0x918B, 0x7C07, 0x4FC3, 0x8E7F, 0x7BE1, 0x7A9C, 0x6467, 0x5D14,
+		0x50AC, 0x8106, 0x7601, 0x7CB9, 0x6DEC, 0x7FE0, 0x6751, 0x5B58, 0x5BF8, 0x78CB, 0x64AE,
+		0x6413, 0x63AA, 0x632B, 0x9519, 0x642D, 0x8FBE, 0x7B54, 0x7629, 0x6253, 0x5927, 0x5446,
+		0x6B79, 0x50A3, 0x6234, 0x5E26, 0x6B86, 0x4EE3, 0x8D37, 0x888B, 0x5F85, 0x902E, 0x6020,
+		0x803D, 0x62C5, 0x4E39, 0x5355, 0x90F8, 0x63B8, 0x80C6, 0x65E6, 0x6C2E, 0x4F46, 0x60EE,
+		0x6DE1, 0x8BDE, 0x5F39, 0x86CB, 0x5F53, 0x6321, 0x515A, 0x8361, 0x6863, 0x5200, 0x6363,
+		0x8E48, 0x5012, 0x5C9B, 0x7977, 0x5BFC, 0x5230, 0x7A3B, 0x60BC, 0x9053, 0x76D7, 0x5FB7,
+		0x5F97, 0x7684, 0x8E6C, 0x706F, 0x767B, 0x7B49, 0x77AA, 0x51F3, 0x9093, 0x5824, 0x4F4E,
+		0x6EF4, 0x8FEA, 0x654C, 0x7B1B, 0x72C4, 0x6DA4, 0x7FDF, 0x5AE1, 0x62B5, 0x5E95, 0x5730,
+		0x8482, 0x7B2C, 0x5E1D, 0x5F1F, 0x9012, 0x7F14, 0x98A0, 0x6382, 0x6EC7, 0x7898, 0x70B9,
+		0x5178, 0x975B, 0x57AB, 0x7535, 0x4F43, 0x7538, 0x5E97, 0x60E6, 0x5960, 0x6DC0, 0x6BBF,
+		0x7889, 0x53FC, 0x96D5, 0x51CB, 0x5201, 0x6389, 0x540A, 0x9493, 0x8C03, 0x8DCC, 0x7239,
+		0x789F, 0x8776, 0x8FED, 0x8C0D, 0x53E0, 0x4E01, 0x76EF, 0x53EE, 0x9489, 0x9876, 0x9F0E,
+		0x952D, 0x5B9A, 0x8BA2, 0x4E22, 0x4E1C, 0x51AC, 0x8463, 0x61C2, 0x52A8, 0x680B, 0x4F97,
+		0x606B, 0x51BB, 0x6D1E, 0x515C, 0x6296, 0x6597, 0x9661, 0x8C46, 0x9017, 0x75D8, 0x90FD,
+		0x7763, 0x6BD2, 0x728A, 0x72EC, 0x8BFB, 0x5835, 0x7779, 0x8D4C, 0x675C, 0x9540, 0x809A,
+		0x5EA6, 0x6E21, 0x5992, 0x7AEF, 0x77ED, 0x953B, 0x6BB5, 0x65AD, 0x7F0E, 0x5806, 0x5151,
+		0x961F, 0x5BF9, 0x58A9, 0x5428, 0x8E72, 0x6566, 0x987F, 0x56E4, 0x949D, 0x76FE, 0x9041,
+		0x6387, 0x54C6, 0x591A, 0x593A, 0x579B, 0x8EB2, 0x6735, 0x8DFA, 0x8235, 0x5241, 0x60F0,
+		0x5815, 0x86FE, 0x5CE8, 0x9E45, 0x4FC4, 0x989D, 0x8BB9, 0x5A25, 0x6076, 0x5384, 0x627C,
+		0x904F, 0x9102, 0x997F, 0x6069, 0x800C, 0x513F, 0x8033, 0x5C14, 0x9975, 0x6D31, 0x4E8C,
+		0x8D30, 0x53D1, 0x7F5A, 0x7B4F, 0x4F10, 0x4E4F, 0x9600, 0x6CD5, 0x73D0, 0x85E9, 0x5E06,
+		0x756A, 0x7FFB, 0x6A0A, 0x77FE, 0x9492, 0x7E41, 0x51E1, 0x70E6, 0x53CD, 0x8FD4, 0x8303,
+		0x8D29, 0x72AF, 0x996D, 0x6CDB, 0x574A, 0x82B3, 0x65B9, 0x80AA, 0x623F, 0x9632, 0x59A8,
+		0x4EFF, 0x8BBF, 0x7EBA, 0x653E, 0x83F2, 0x975E, 0x5561, 0x98DE, 0x80A5, 0x532A, 0x8BFD,
+		0x5420, 0x80BA, 0x5E9F, 0x6CB8, 0x8D39, 0x82AC, 0x915A, 0x5429, 0x6C1B, 0x5206, 0x7EB7,
+		0x575F, 0x711A, 0x6C7E, 0x7C89, 0x594B, 0x4EFD, 0x5FFF, 0x6124, 0x7CAA, 0x4E30, 0x5C01,
+		0x67AB, 0x8702, 0x5CF0, 0x950B, 0x98CE, 0x75AF, 0x70FD, 0x9022, 0x51AF, 0x7F1D, 0x8BBD,
+		0x5949, 0x51E4, 0x4F5B, 0x5426, 0x592B, 0x6577, 0x80A4, 0x5B75, 0x6276, 0x62C2, 0x8F90,
+		0x5E45, 0x6C1F, 0x7B26, 0x4F0F, 0x4FD8, 0x670D, 0x6D6E, 0x6DAA, 0x798F, 0x88B1, 0x5F17,
+		0x752B, 0x629A, 0x8F85, 0x4FEF, 0x91DC, 0x65A7, 0x812F, 0x8151, 0x5E9C, 0x8150, 0x8D74,
+		0x526F, 0x8986, 0x8D4B, 0x590D, 0x5085, 0x4ED8, 0x961C, 0x7236, 0x8179, 0x8D1F, 0x5BCC,
+		0x8BA3, 0x9644, 0x5987, 0x7F1A, 0x5490, 0x5676, 0x560E, 0x8BE5, 0x6539, 0x6982, 0x9499,
+		0x76D6, 0x6E89, 0x5E72, 0x7518, 0x6746, 0x67D1, 0x7AFF, 0x809D, 0x8D76, 0x611F, 0x79C6,
+		0x6562, 0x8D63, 0x5188, 0x521A, 0x94A2, 0x7F38, 0x809B, 0x7EB2, 0x5C97, 0x6E2F, 0x6760,
+		0x7BD9, 0x768B, 0x9AD8, 0x818F, 0x7F94, 0x7CD5, 0x641E, 0x9550, 0x7A3F, 0x544A, 0x54E5,
+		0x6B4C, 0x6401, 0x6208, 0x9E3D, 0x80F3, 0x7599, 0x5272, 0x9769, 0x845B, 0x683C, 0x86E4,
+		0x9601, 0x9694, 0x94EC, 0x4E2A, 0x5404, 0x7ED9, 0x6839, 0x8DDF, 0x8015, 0x66F4, 0x5E9A,
+		0x7FB9, 0x57C2, 0x803F, 0x6897, 0x5DE5, 0x653B, 0x529F, 0x606D, 0x9F9A, 0x4F9B, 0x8EAC,
+		0x516C, 0x5BAB, 0x5F13, 0x5DE9, 0x6C5E, 0x62F1, 0x8D21, 0x5171, 0x94A9, 0x52FE, 0x6C9F,
+		0x82DF, 0x72D7, 0x57A2, 0x6784, 0x8D2D, 0x591F, 0x8F9C, 0x83C7, 0x5495, 0x7B8D, 0x4F30,
+		0x6CBD, 0x5B64, 0x59D1, 0x9F13, 0x53E4, 0x86CA, 0x9AA8, 0x8C37, 0x80A1, 0x6545, 0x987E,
+		0x56FA, 0x96C7, 0x522E, 0x74DC, 0x5250, 0x5BE1, 0x6302, 0x8902, 0x4E56, 0x62D0, 0x602A,
+		0x68FA, 0x5173, 0x5B98, 0x51A0, 0x89C2, 0x7BA1, 0x9986, 0x7F50, 0x60EF, 0x704C, 0x8D2F,
+		0x5149, 0x5E7F, 0x901B, 0x7470, 0x89C4, 0x572D, 0x7845, 0x5F52, 0x9F9F, 0x95FA, 0x8F68,
+		0x9B3C, 0x8BE1, 0x7678, 0x6842, 0x67DC, 0x8DEA, 0x8D35, 0x523D, 0x8F8A, 0x6EDA, 0x68CD,
+		0x9505, 0x90ED, 0x56FD, 0x679C, 0x88F9, 0x8FC7, 0x54C8, 0x9AB8, 0x5B69, 0x6D77, 0x6C26,
+		0x4EA5, 0x5BB3, 0x9A87, 0x9163, 0x61A8, 0x90AF, 0x97E9, 0x542B, 0x6DB5, 0x5BD2, 0x51FD,
+		0x558A, 0x7F55, 0x7FF0, 0x64BC, 0x634D, 0x65F1, 0x61BE, 0x608D, 0x710A, 0x6C57, 0x6C49,
+		0x592F, 0x676D, 0x822A, 0x58D5, 0x568E, 0x8C6A, 0x6BEB, 0x90DD, 0x597D, 0x8017, 0x53F7,
+		0x6D69, 0x5475, 0x559D, 0x8377, 0x83CF, 0x6838, 0x79BE, 0x548C, 0x4F55, 0x5408, 0x76D2,
+		0x8C89, 0x9602, 0x6CB3, 0x6DB8, 0x8D6B, 0x8910, 0x9E64, 0x8D3A, 0x563F, 0x9ED1, 0x75D5,
+		0x5F88, 0x72E0, 0x6068, 0x54FC, 0x4EA8, 0x6A2A, 0x8861, 0x6052, 0x8F70, 0x54C4, 0x70D8,
+		0x8679, 0x9E3F, 0x6D2A, 0x5B8F, 0x5F18, 0x7EA2, 0x5589, 0x4FAF, 0x7334, 0x543C, 0x539A,
+		0x5019, 0x540E, 0x547C, 0x4E4E, 0x5FFD, 0x745A, 0x58F6, 0x846B, 0x80E1, 0x8774, 0x72D0,
+		0x7CCA, 0x6E56, 0x5F27, 0x864E, 0x552C, 0x62A4, 0x4E92, 0x6CAA, 0x6237, 0x82B1, 0x54D7,
+		0x534E, 0x733E, 0x6ED1, 0x753B, 0x5212, 0x5316, 0x8BDD, 0x69D0, 0x5F8A, 0x6000, 0x6DEE,
+		0x574F, 0x6B22, 0x73AF, 0x6853, 0x8FD8, 0x7F13, 0x6362, 0x60A3, 0x5524, 0x75EA, 0x8C62,
+		0x7115, 0x6DA3, 0x5BA6, 0x5E7B, 0x8352, 0x614C, 0x9EC4, 0x78FA, 0x8757, 0x7C27, 0x7687,
+		0x51F0, 0x60F6, 0x714C, 0x6643, 0x5E4C, 0x604D, 0x8C0E, 0x7070, 0x6325, 0x8F89, 0x5FBD,
+		0x6062, 0x86D4, 0x56DE, 0x6BC1, 0x6094, 0x6167, 0x5349, 0x60E0, 0x6666, 0x8D3F, 0x79FD,
+		0x4F1A, 0x70E9, 0x6C47, 0x8BB3, 0x8BF2, 0x7ED8, 0x8364, 0x660F, 0x5A5A, 0x9B42, 0x6D51,
+		0x6DF7, 0x8C41, 0x6D3B, 0x4F19, 0x706B, 0x83B7, 0x6216, 0x60D1, 0x970D, 0x8D27, 0x7978,
+		0x51FB, 0x573E, 0x57FA, 0x673A, 0x7578, 0x7A3D, 0x79EF, 0x7B95, 0x808C, 0x9965, 0x8FF9,
+		0x6FC0, 0x8BA5, 0x9E21, 0x59EC, 0x7EE9, 0x7F09, 0x5409, 0x6781, 0x68D8, 0x8F91, 0x7C4D,
+		0x96C6, 0x53CA, 0x6025, 0x75BE, 0x6C72, 0x5373, 0x5AC9, 0x7EA7, 0x6324, 0x51E0, 0x810A,
+		0x5DF1, 0x84DF, 0x6280, 0x5180, 0x5B63, 0x4F0E, 0x796D, 0x5242, 0x60B8, 0x6D4E, 0x5BC4,
+		0x5BC2, 0x8BA1, 0x8BB0, 0x65E2, 0x5FCC, 0x9645, 0x5993, 0x7EE7, 0x7EAA, 0x5609, 0x67B7,
+		0x5939, 0x4F73, 0x5BB6, 0x52A0, 0x835A, 0x988A, 0x8D3E, 0x7532, 0x94BE, 0x5047, 0x7A3C,
+		0x4EF7, 0x67B6, 0x9A7E, 0x5AC1, 0x6B7C, 0x76D1, 0x575A, 0x5C16, 0x7B3A, 0x95F4, 0x714E,
+		0x517C, 0x80A9, 0x8270, 0x5978, 0x7F04, 0x8327, 0x68C0, 0x67EC, 0x78B1, 0x7877, 0x62E3,
+		0x6361, 0x7B80, 0x4FED, 0x526A, 0x51CF, 0x8350, 0x69DB, 0x9274, 0x8DF5, 0x8D31, 0x89C1,
+		0x952E, 0x7BAD, 0x4EF6, 0x5065, 0x8230, 0x5251, 0x996F, 0x6E10, 0x6E85, 0x6DA7, 0x5EFA,
+		0x50F5, 0x59DC, 0x5C06, 0x6D46, 0x6C5F, 0x7586, 0x848B, 0x6868, 0x5956, 0x8BB2, 0x5320,
+		0x9171, 0x964D, 0x8549, 0x6912, 0x7901, 0x7126, 0x80F6, 0x4EA4, 0x90CA, 0x6D47, 0x9A84,
+		0x5A07, 0x56BC, 0x6405, 0x94F0, 0x77EB, 0x4FA5, 0x811A, 0x72E1, 0x89D2, 0x997A, 0x7F34,
+		0x7EDE, 0x527F, 0x6559, 0x9175, 0x8F7F, 0x8F83, 0x53EB, 0x7A96, 0x63ED, 0x63A5, 0x7686,
+		0x79F8, 0x8857, 0x9636, 0x622A, 0x52AB, 0x8282, 0x6854, 0x6770, 0x6377, 0x776B, 0x7AED,
+		0x6D01, 0x7ED3, 0x89E3, 0x59D0, 0x6212, 0x85C9, 0x82A5, 0x754C, 0x501F, 0x4ECB, 0x75A5,
+		0x8BEB, 0x5C4A, 0x5DFE, 0x7B4B, 0x65A4, 0x91D1, 0x4ECA, 0x6D25, 0x895F, 0x7D27, 0x9526,
+		0x4EC5, 0x8C28, 0x8FDB, 0x9773, 0x664B, 0x7981, 0x8FD1, 0x70EC, 0x6D78, 0x5C3D, 0x52B2,
+		0x8346, 0x5162, 0x830E, 0x775B, 0x6676, 0x9CB8, 0x4EAC, 0x60CA, 0x7CBE, 0x7CB3, 0x7ECF,
+		0x4E95, 0x8B66, 0x666F, 0x9888, 0x9759, 0x5883, 0x656C, 0x955C, 0x5F84, 0x75C9, 0x9756,
+		0x7ADF, 0x7ADE, 0x51C0, 0x70AF, 0x7A98, 0x63EA, 0x7A76, 0x7EA0, 0x7396, 0x97ED, 0x4E45,
+		0x7078, 0x4E5D, 0x9152, 0x53A9, 0x6551, 0x65E7, 0x81FC, 0x8205, 0x548E, 0x5C31, 0x759A,
+		0x97A0, 0x62D8, 0x72D9, 0x75BD, 0x5C45, 0x9A79, 0x83CA, 0x5C40, 0x5480, 0x77E9, 0x4E3E,
+		0x6CAE, 0x805A, 0x62D2, 0x636E, 0x5DE8, 0x5177, 0x8DDD, 0x8E1E, 0x952F, 0x4FF1, 0x53E5,
+		0x60E7, 0x70AC, 0x5267, 0x6350, 0x9E43, 0x5A1F, 0x5026, 0x7737, 0x5377, 0x7EE2, 0x6485,
+		0x652B, 0x6289, 0x6398, 0x5014, 0x7235, 0x89C9, 0x51B3, 0x8BC0, 0x7EDD, 0x5747, 0x83CC,
+		0x94A7, 0x519B, 0x541B, 0x5CFB, 0x4FCA, 0x7AE3, 0x6D5A, 0x90E1, 0x9A8F, 0x5580, 0x5496,
+		0x5361, 0x54AF, 0x5F00, 0x63E9, 0x6977, 0x51EF, 0x6168, 0x520A, 0x582A, 0x52D8, 0x574E,
+		0x780D, 0x770B, 0x5EB7, 0x6177, 0x7CE0, 0x625B, 0x6297, 0x4EA2, 0x7095, 0x8003, 0x62F7,
+		0x70E4, 0x9760, 0x5777, 0x82DB, 0x67EF, 0x68F5, 0x78D5, 0x9897, 0x79D1, 0x58F3, 0x54B3,
+		0x53EF, 0x6E34, 0x514B, 0x523B, 0x5BA2, 0x8BFE, 0x80AF, 0x5543, 0x57A6, 0x6073, 0x5751,
+		0x542D, 0x7A7A, 0x6050, 0x5B54, 0x63A7, 0x62A0, 0x53E3, 0x6263, 0x5BC7, 0x67AF, 0x54ED,
+		0x7A9F, 0x82E6, 0x9177, 0x5E93, 0x88E4, 0x5938, 0x57AE, 0x630E, 0x8DE8, 0x80EF, 0x5757,
+		0x7B77, 0x4FA9, 0x5FEB, 0x5BBD, 0x6B3E, 0x5321, 0x7B50, 0x72C2, 0x6846, 0x77FF, 0x7736,
+		0x65F7, 0x51B5, 0x4E8F, 0x76D4, 0x5CBF, 0x7AA5, 0x8475, 0x594E, 0x9B41, 0x5080, 0x9988,
+		0x6127, 0x6E83, 0x5764, 0x6606, 0x6346, 0x56F0, 0x62EC, 0x6269, 0x5ED3, 0x9614, 0x5783,
+		0x62C9, 0x5587, 0x8721, 0x814A, 0x8FA3, 0x5566, 0x83B1, 0x6765, 0x8D56, 0x84DD, 0x5A6A,
+		0x680F, 0x62E6, 0x7BEE, 0x9611, 0x5170, 0x6F9C, 0x8C30, 0x63FD, 0x89C8, 0x61D2, 0x7F06,
+		0x70C2, 0x6EE5, 0x7405, 0x6994, 0x72FC, 0x5ECA, 0x90CE, 0x6717, 0x6D6A, 0x635E, 0x52B3,
+		0x7262, 0x8001, 0x4F6C, 0x59E5, 0x916A, 0x70D9, 0x6D9D, 0x52D2, 0x4E50, 0x96F7, 0x956D,
+		0x857E, 0x78CA, 0x7D2F, 0x5121, 0x5792, 0x64C2, 0x808B, 0x7C7B, 0x6CEA, 0x68F1, 0x695E,
+		0x51B7, 0x5398, 0x68A8, 0x7281, 0x9ECE, 0x7BF1, 0x72F8, 0x79BB, 0x6F13, 0x7406, 0x674E,
+		0x91CC, 0x9CA4, 0x793C, 0x8389, 0x8354, 0x540F, 0x6817, 0x4E3D, 0x5389, 0x52B1, 0x783E,
+		0x5386, 0x5229, 0x5088, 0x4F8B, 0x4FD0, 0x75E2, 0x7ACB, 0x7C92, 0x6CA5, 0x96B6, 0x529B,
+		0x7483, 0x54E9, 0x4FE9, 0x8054, 0x83B2, 0x8FDE, 0x9570, 0x5EC9, 0x601C, 0x6D9F, 0x5E18,
+		0x655B, 0x8138, 0x94FE, 0x604B, 0x70BC, 0x7EC3, 0x7CAE, 0x51C9, 0x6881, 0x7CB1, 0x826F,
+		0x4E24, 0x8F86, 0x91CF, 0x667E, 0x4EAE, 0x8C05, 0x64A9, 0x804A, 0x50DA, 0x7597, 0x71CE,
+		0x5BE5, 0x8FBD, 0x6F66, 0x4E86, 0x6482, 0x9563, 0x5ED6, 0x6599, 0x5217, 0x88C2, 0x70C8,
+		0x52A3, 0x730E, 0x7433, 0x6797, 0x78F7, 0x9716, 0x4E34, 0x90BB, 0x9CDE, 0x6DCB, 0x51DB,
+		0x8D41, 0x541D, 0x62CE, 0x73B2, 0x83F1, 0x96F6, 0x9F84, 0x94C3, 0x4F36, 0x7F9A, 0x51CC,
+		0x7075, 0x9675, 0x5CAD, 0x9886, 0x53E6, 0x4EE4, 0x6E9C, 0x7409, 0x69B4, 0x786B, 0x998F,
+		0x7559, 0x5218, 0x7624, 0x6D41, 0x67F3, 0x516D, 0x9F99, 0x804B, 0x5499, 0x7B3C, 0x7ABF,
+		0x9686, 0x5784, 0x62E2, 0x9647, 0x697C, 0x5A04, 0x6402, 0x7BD3, 0x6F0F, 0x964B, 0x82A6,
+		0x5362, 0x9885, 0x5E90, 0x7089, 0x63B3, 0x5364, 0x864F, 0x9C81, 0x9E93, 0x788C, 0x9732,
+		0x8DEF, 0x8D42, 0x9E7F, 0x6F5E, 0x7984, 0x5F55, 0x9646, 0x622E, 0x9A74, 0x5415, 0x94DD,
+		0x4FA3, 0x65C5, 0x5C65, 0x5C61, 0x7F15, 0x8651, 0x6C2F, 0x5F8B, 0x7387, 0x6EE4, 0x7EFF,
+		0x5CE6, 0x631B, 0x5B6A, 0x6EE6, 0x5375, 0x4E71, 0x63A0, 0x7565, 0x62A1, 0x8F6E, 0x4F26,
+		0x4ED1, 0x6CA6, 0x7EB6, 0x8BBA, 0x841D, 0x87BA, 0x7F57, 0x903B, 0x9523, 0x7BA9, 0x9AA1,
+		0x88F8, 0x843D, 0x6D1B, 0x9A86, 0x7EDC, 0x5988, 0x9EBB, 0x739B, 0x7801, 0x8682, 0x9A6C,
+		0x9A82, 0x561B, 0x5417, 0x57CB, 0x4E70, 0x9EA6, 0x5356, 0x8FC8, 0x8109, 0x7792, 0x9992,
+		0x86EE, 0x6EE1, 0x8513, 0x66FC, 0x6162, 0x6F2B, 0x8C29, 0x8292, 0x832B, 0x76F2, 0x6C13,
+		0x5FD9, 0x83BD, 0x732B, 0x8305, 0x951A, 0x6BDB, 0x77DB, 0x94C6, 0x536F, 0x8302, 0x5192,
+		0x5E3D, 0x8C8C, 0x8D38, 0x4E48, 0x73AB, 0x679A, 0x6885, 0x9176, 0x9709, 0x7164, 0x6CA1,
+		0x7709, 0x5A92, 0x9541, 0x6BCF, 0x7F8E, 0x6627, 0x5BD0, 0x59B9, 0x5A9A, 0x95E8, 0x95F7,
+		0x4EEC, 0x840C, 0x8499, 0x6AAC, 0x76DF, 0x9530, 0x731B, 0x68A6, 0x5B5F, 0x772F, 0x919A,
+		0x9761, 0x7CDC, 0x8FF7, 0x8C1C, 0x5F25, 0x7C73, 0x79D8, 0x89C5, 0x6CCC, 0x871C, 0x5BC6,
+		0x5E42, 0x68C9, 0x7720, 0x7EF5, 0x5195, 0x514D, 0x52C9, 0x5A29, 0x7F05, 0x9762, 0x82D7,
+		0x63CF, 0x7784, 0x85D0, 0x79D2, 0x6E3A, 0x5E99, 0x5999, 0x8511, 0x706D, 0x6C11, 0x62BF,
+		0x76BF, 0x654F, 0x60AF, 0x95FD, 0x660E, 0x879F, 0x9E23, 0x94ED, 0x540D, 0x547D, 0x8C2C,
+		0x6478, 0x6479, 0x8611, 0x6A21, 0x819C, 0x78E8, 0x6469, 0x9B54, 0x62B9, 0x672B, 0x83AB,
+		0x58A8, 0x9ED8, 0x6CAB, 0x6F20, 0x5BDE, 0x964C, 0x8C0B, 0x725F, 0x67D0, 0x62C7, 0x7261,
+		0x4EA9, 0x59C6, 0x6BCD, 0x5893, 0x66AE, 0x5E55, 0x52DF, 0x6155, 0x6728, 0x76EE, 0x7766,
+		0x7267, 0x7A46, 0x62FF, 0x54EA, 0x5450, 0x94A0, 0x90A3, 0x5A1C, 0x7EB3, 0x6C16, 0x4E43,
+		0x5976, 0x8010, 0x5948, 0x5357, 0x7537, 0x96BE, 0x56CA, 0x6320, 0x8111, 0x607C, 0x95F9,
+		0x6DD6, 0x5462, 0x9981, 0x5185, 0x5AE9, 0x80FD, 0x59AE, 0x9713, 0x502A, 0x6CE5, 0x5C3C,
+		0x62DF, 0x4F60, 0x533F, 0x817B, 0x9006, 0x6EBA, 0x852B, 0x62C8, 0x5E74, 0x78BE, 0x64B5,
+		0x637B, 0x5FF5, 0x5A18, 0x917F, 0x9E1F, 0x5C3F, 0x634F, 0x8042, 0x5B7D, 0x556E, 0x954A,
+		0x954D, 0x6D85, 0x60A8, 0x67E0, 0x72DE, 0x51DD, 0x5B81, 0x62E7, 0x6CDE, 0x725B, 0x626D,
+		0x94AE, 0x7EBD, 0x8113, 0x6D53, 0x519C, 0x5F04, 0x5974, 0x52AA, 0x6012, 0x5973, 0x6696,
+		0x8650, 0x759F, 0x632A, 0x61E6, 0x7CEF, 0x8BFA, 0x54E6, 0x6B27, 0x9E25, 0x6BB4, 0x85D5,
+		0x5455, 0x5076, 0x6CA4, 0x556A, 0x8DB4, 0x722C, 0x5E15, 0x6015, 0x7436, 0x62CD, 0x6392,
+		0x724C, 0x5F98, 0x6E43, 0x6D3E, 0x6500, 0x6F58, 0x76D8, 0x78D0, 0x76FC, 0x7554, 0x5224,
+		0x53DB, 0x4E53, 0x5E9E, 0x65C1, 0x802A, 0x80D6, 0x629B, 0x5486, 0x5228, 0x70AE, 0x888D,
+		0x8DD1, 0x6CE1, 0x5478, 0x80DA, 0x57F9, 0x88F4, 0x8D54, 0x966A, 0x914D, 0x4F69, 0x6C9B,
+		0x55B7, 0x76C6, 0x7830, 0x62A8, 0x70F9, 0x6F8E, 0x5F6D, 0x84EC, 0x68DA, 0x787C, 0x7BF7,
+		0x81A8, 0x670B, 0x9E4F, 0x6367, 0x78B0, 0x576F, 0x7812, 0x9739, 0x6279, 0x62AB, 0x5288,
+		0x7435, 0x6BD7, 0x5564, 0x813E, 0x75B2, 0x76AE, 0x5339, 0x75DE, 0x50FB, 0x5C41, 0x8B6C,
+		0x7BC7, 0x504F, 0x7247, 0x9A97, 0x98D8, 0x6F02, 0x74E2, 0x7968, 0x6487, 0x77A5, 0x62FC,
+		0x9891, 0x8D2B, 0x54C1, 0x8058, 0x4E52, 0x576A, 0x82F9, 0x840D, 0x5E73, 0x51ED, 0x74F6,
+		0x8BC4, 0x5C4F, 0x5761, 0x6CFC, 0x9887, 0x5A46, 0x7834, 0x9B44, 0x8FEB, 0x7C95, 0x5256,
+		0x6251, 0x94FA, 0x4EC6, 0x8386, 0x8461, 0x83E9, 0x84B2, 0x57D4, 0x6734, 0x5703, 0x666E,
+		0x6D66, 0x8C31, 0x66DD, 0x7011, 0x671F, 0x6B3A, 0x6816, 0x621A, 0x59BB, 0x4E03, 0x51C4,
+		0x6F06, 0x67D2, 0x6C8F, 0x5176, 0x68CB, 0x5947, 0x6B67, 0x7566, 0x5D0E, 0x8110, 0x9F50,
+		0x65D7, 0x7948, 0x7941, 0x9A91, 0x8D77, 0x5C82, 0x4E5E, 0x4F01, 0x542F, 0x5951, 0x780C,
+		0x5668, 0x6C14, 0x8FC4, 0x5F03, 0x6C7D, 0x6CE3, 0x8BAB, 0x6390, 0x6070, 0x6D3D, 0x7275,
+		0x6266, 0x948E, 0x94C5, 0x5343, 0x8FC1, 0x7B7E, 0x4EDF, 0x8C26, 0x4E7E, 0x9ED4, 0x94B1,
+		0x94B3, 0x524D, 0x6F5C, 0x9063, 0x6D45, 0x8C34, 0x5811, 0x5D4C, 0x6B20, 0x6B49, 0x67AA,
+		0x545B, 0x8154, 0x7F8C, 0x5899, 0x8537, 0x5F3A, 0x62A2, 0x6A47, 0x9539, 0x6572, 0x6084,
+		0x6865, 0x77A7, 0x4E54, 0x4FA8, 0x5DE7, 0x9798, 0x64AC, 0x7FD8, 0x5CED, 0x4FCF, 0x7A8D,
+		0x5207, 0x8304, 0x4E14, 0x602F, 0x7A83, 0x94A6, 0x4FB5, 0x4EB2, 0x79E6, 0x7434, 0x52E4,
+		0x82B9, 0x64D2, 0x79BD, 0x5BDD, 0x6C81, 0x9752, 0x8F7B, 0x6C22, 0x503E, 0x537F, 0x6E05,
+		0x64CE, 0x6674, 0x6C30, 0x60C5, 0x9877, 0x8BF7, 0x5E86, 0x743C, 0x7A77, 0x79CB, 0x4E18,
+		0x90B1, 0x7403, 0x6C42, 0x56DA, 0x914B, 0x6CC5, 0x8D8B, 0x533A, 0x86C6, 0x66F2, 0x8EAF,
+		0x5C48, 0x9A71, 0x6E20, 0x53D6, 0x5A36, 0x9F8B, 0x8DA3, 0x53BB, 0x5708, 0x98A7, 0x6743,
+		0x919B, 0x6CC9, 0x5168, 0x75CA, 0x62F3, 0x72AC, 0x5238, 0x529D, 0x7F3A, 0x7094, 0x7638,
+		0x5374, 0x9E4A, 0x69B7, 0x786E, 0x96C0, 0x88D9, 0x7FA4, 0x7136, 0x71C3, 0x5189, 0x67D3,
+		0x74E4, 0x58E4, 0x6518, 0x56B7, 0x8BA9, 0x9976, 0x6270, 0x7ED5, 0x60F9, 0x70ED, 0x58EC,
+		0x4EC1, 0x4EBA, 0x5FCD, 0x97E7, 0x4EFB, 0x8BA4, 0x5203, 0x598A, 0x7EAB, 0x6254, 0x4ECD,
+		0x65E5, 0x620E, 0x8338, 0x84C9, 0x8363, 0x878D, 0x7194, 0x6EB6, 0x5BB9, 0x7ED2, 0x5197,
+		0x63C9, 0x67D4, 0x8089, 0x8339, 0x8815, 0x5112, 0x5B7A, 0x5982, 0x8FB1, 0x4E73, 0x6C5D,
+		0x5165, 0x8925, 0x8F6F, 0x962E, 0x854A, 0x745E, 0x9510, 0x95F0, 0x6DA6, 0x82E5, 0x5F31,
+		0x6492, 0x6D12, 0x8428, 0x816E, 0x9CC3, 0x585E, 0x8D5B, 0x4E09, 0x53C1, 0x4F1E, 0x6563,
+		0x6851, 0x55D3, 0x4E27, 0x6414, 0x9A9A, 0x626B, 0x5AC2, 0x745F, 0x8272, 0x6DA9, 0x68EE,
+		0x50E7, 0x838E, 0x7802, 0x6740, 0x5239, 0x6C99, 0x7EB1, 0x50BB, 0x5565, 0x715E, 0x7B5B,
+		0x6652, 0x73CA, 0x82EB, 0x6749, 0x5C71, 0x5220, 0x717D, 0x886B, 0x95EA, 0x9655, 0x64C5,
+		0x8D61, 0x81B3, 0x5584, 0x6C55, 0x6247, 0x7F2E, 0x5892, 0x4F24, 0x5546, 0x8D4F, 0x664C,
+		0x4E0A, 0x5C1A, 0x88F3, 0x68A2, 0x634E, 0x7A0D, 0x70E7, 0x828D, 0x52FA, 0x97F6, 0x5C11,
+		0x54E8, 0x90B5, 0x7ECD, 0x5962, 0x8D4A, 0x86C7, 0x820C, 0x820D, 0x8D66, 0x6444, 0x5C04,
+		0x6151, 0x6D89, 0x793E, 0x8BBE, 0x7837, 0x7533, 0x547B, 0x4F38, 0x8EAB, 0x6DF1, 0x5A20,
+		0x7EC5, 0x795E, 0x6C88, 0x5BA1, 0x5A76, 0x751A, 0x80BE, 0x614E, 0x6E17, 0x58F0, 0x751F,
+		0x7525, 0x7272, 0x5347, 0x7EF3, 0x7701, 0x76DB, 0x5269, 0x80DC, 0x5723, 0x5E08, 0x5931,
+		0x72EE, 0x65BD, 0x6E7F, 0x8BD7, 0x5C38, 0x8671, 0x5341, 0x77F3, 0x62FE, 0x65F6, 0x4EC0,
+		0x98DF, 0x8680, 0x5B9E, 0x8BC6, 0x53F2, 0x77E2, 0x4F7F, 0x5C4E, 0x9A76, 0x59CB, 0x5F0F,
+		0x793A, 0x58EB, 0x4E16, 0x67FF, 0x4E8B, 0x62ED, 0x8A93, 0x901D, 0x52BF, 0x662F, 0x55DC,
+		0x566C, 0x9002, 0x4ED5, 0x4F8D, 0x91CA, 0x9970, 0x6C0F, 0x5E02, 0x6043, 0x5BA4, 0x89C6,
+		0x8BD5, 0x6536, 0x624B, 0x9996, 0x5B88, 0x5BFF, 0x6388, 0x552E, 0x53D7, 0x7626, 0x517D,
+		0x852C, 0x67A2, 0x68B3, 0x6B8A, 0x6292, 0x8F93, 0x53D4, 0x8212, 0x6DD1, 0x758F, 0x4E66,
+		0x8D4E, 0x5B70, 0x719F, 0x85AF, 0x6691, 0x66D9, 0x7F72, 0x8700, 0x9ECD, 0x9F20, 0x5C5E,
+		0x672F, 0x8FF0, 0x6811, 0x675F, 0x620D, 0x7AD6, 0x5885, 0x5EB6, 0x6570, 0x6F31, 0x6055,
+		0x5237, 0x800D, 0x6454, 0x8870, 0x7529, 0x5E05, 0x6813, 0x62F4, 0x971C, 0x53CC, 0x723D,
+		0x8C01, 0x6C34, 0x7761, 0x7A0E, 0x542E, 0x77AC, 0x987A, 0x821C, 0x8BF4, 0x7855, 0x6714,
+		0x70C1, 0x65AF, 0x6495, 0x5636, 0x601D, 0x79C1, 0x53F8, 0x4E1D, 0x6B7B, 0x8086, 0x5BFA,
+		0x55E3, 0x56DB, 0x4F3A, 0x4F3C, 0x9972, 0x5DF3, 0x677E, 0x8038, 0x6002, 0x9882, 0x9001,
+		0x5B8B, 0x8BBC, 0x8BF5, 0x641C, 0x8258, 0x64DE, 0x55FD, 0x82CF, 0x9165, 0x4FD7, 0x7D20,
+		0x901F, 0x7C9F, 0x50F3, 0x5851, 0x6EAF, 0x5BBF, 0x8BC9, 0x8083, 0x9178, 0x849C, 0x7B97,
+		0x867D, 0x968B, 0x968F, 0x7EE5, 0x9AD3, 0x788E, 0x5C81, 0x7A57, 0x9042, 0x96A7, 0x795F,
+		0x5B59, 0x635F, 0x7B0B, 0x84D1, 0x68AD, 0x5506, 0x7F29, 0x7410, 0x7D22, 0x9501, 0x6240,
+		0x584C, 0x4ED6, 0x5B83, 0x5979, 0x5854, 0x736D, 0x631E, 0x8E4B, 0x8E0F, 0x80CE, 0x82D4,
+		0x62AC, 0x53F0, 0x6CF0, 0x915E, 0x592A, 0x6001, 0x6C70, 0x574D, 0x644A, 0x8D2A, 0x762B,
+		0x6EE9, 0x575B, 0x6A80, 0x75F0, 0x6F6D, 0x8C2D, 0x8C08, 0x5766, 0x6BEF, 0x8892, 0x78B3,
+		0x63A2, 0x53F9, 0x70AD, 0x6C64, 0x5858, 0x642A, 0x5802, 0x68E0, 0x819B, 0x5510, 0x7CD6,
+		0x5018, 0x8EBA, 0x6DCC, 0x8D9F, 0x70EB, 0x638F, 0x6D9B, 0x6ED4, 0x7EE6, 0x8404, 0x6843,
+		0x9003, 0x6DD8, 0x9676, 0x8BA8, 0x5957, 0x7279, 0x85E4, 0x817E, 0x75BC, 0x8A8A, 0x68AF,
+		0x5254, 0x8E22, 0x9511, 0x63D0, 0x9898, 0x8E44, 0x557C, 0x4F53, 0x66FF, 0x568F, 0x60D5,
+		0x6D95, 0x5243, 0x5C49, 0x5929, 0x6DFB, 0x586B, 0x7530, 0x751C, 0x606C, 0x8214, 0x8146,
+		0x6311, 0x6761, 0x8FE2, 0x773A, 0x8DF3, 0x8D34, 0x94C1, 0x5E16, 0x5385, 0x542C, 0x70C3,
+		0x6C40, 0x5EF7, 0x505C, 0x4EAD, 0x5EAD, 0x633A, 0x8247, 0x901A, 0x6850, 0x916E, 0x77B3,
+		0x540C, 0x94DC, 0x5F64, 0x7AE5, 0x6876, 0x6345, 0x7B52, 0x7EDF, 0x75DB, 0x5077, 0x6295,
+		0x5934, 0x900F, 0x51F8, 0x79C3, 0x7A81, 0x56FE, 0x5F92, 0x9014, 0x6D82, 0x5C60, 0x571F,
+		0x5410, 0x5154, 0x6E4D, 0x56E2, 0x63A8, 0x9893, 0x817F, 0x8715, 0x892A, 0x9000, 0x541E,
+		0x5C6F, 0x81C0, 0x62D6, 0x6258, 0x8131, 0x9E35, 0x9640, 0x9A6E, 0x9A7C, 0x692D, 0x59A5,
+		0x62D3, 0x553E, 0x6316, 0x54C7, 0x86D9, 0x6D3C, 0x5A03, 0x74E6, 0x889C, 0x6B6A, 0x5916,
+		0x8C4C, 0x5F2F, 0x6E7E, 0x73A9, 0x987D, 0x4E38, 0x70F7, 0x5B8C, 0x7897, 0x633D, 0x665A,
+		0x7696, 0x60CB, 0x5B9B, 0x5A49, 0x4E07, 0x8155, 0x6C6A, 0x738B, 0x4EA1, 0x6789, 0x7F51,
+		0x5F80, 0x65FA, 0x671B, 0x5FD8, 0x5984, 0x5A01, 0x5DCD, 0x5FAE, 0x5371, 0x97E6, 0x8FDD,
+		0x6845, 0x56F4, 0x552F, 0x60DF, 0x4E3A, 0x6F4D, 0x7EF4, 0x82C7, 0x840E, 0x59D4, 0x4F1F,
+		0x4F2A, 0x5C3E, 0x7EAC, 0x672A, 0x851A, 0x5473, 0x754F, 0x80C3, 0x5582, 0x9B4F, 0x4F4D,
+		0x6E2D, 0x8C13, 0x5C09, 0x6170, 0x536B, 0x761F, 0x6E29, 0x868A, 0x6587, 0x95FB, 0x7EB9,
+		0x543B, 0x7A33, 0x7D0A, 0x95EE, 0x55E1, 0x7FC1, 0x74EE, 0x631D, 0x8717, 0x6DA1, 0x7A9D,
+		0x6211, 0x65A1, 0x5367, 0x63E1, 0x6C83, 0x5DEB, 0x545C, 0x94A8, 0x4E4C, 0x6C61, 0x8BEC,
+		0x5C4B, 0x65E0, 0x829C, 0x68A7, 0x543E, 0x5434, 0x6BCB, 0x6B66, 0x4E94, 0x6342, 0x5348,
+		0x821E, 0x4F0D, 0x4FAE, 0x575E, 0x620A, 0x96FE, 0x6664, 0x7269, 0x52FF, 0x52A1, 0x609F,
+		0x8BEF, 0x6614, 0x7199, 0x6790, 0x897F, 0x7852, 0x77FD, 0x6670, 0x563B, 0x5438, 0x9521,
+		0x727A, 0x7A00, 0x606F, 0x5E0C, 0x6089, 0x819D, 0x5915, 0x60DC, 0x7184, 0x70EF, 0x6EAA,
+		0x6C50, 0x7280, 0x6A84, 0x88AD, 0x5E2D, 0x4E60, 0x5AB3, 0x559C, 0x94E3, 0x6D17, 0x7CFB,
+		0x9699, 0x620F, 0x7EC6, 0x778E, 0x867E, 0x5323, 0x971E, 0x8F96, 0x6687, 0x5CE1, 0x4FA0,
+		0x72ED, 0x4E0B, 0x53A6, 0x590F, 0x5413, 0x6380, 0x9528, 0x5148, 0x4ED9, 0x9C9C, 0x7EA4,
+		0x54B8, 0x8D24, 0x8854, 0x8237, 0x95F2, 0x6D8E, 0x5F26, 0x5ACC, 0x663E, 0x9669, 0x73B0,
+		0x732E, 0x53BF, 0x817A, 0x9985, 0x7FA1, 0x5BAA, 0x9677, 0x9650, 0x7EBF, 0x76F8, 0x53A2,
+		0x9576, 0x9999, 0x7BB1, 0x8944, 0x6E58, 0x4E61, 0x7FD4, 0x7965, 0x8BE6, 0x60F3, 0x54CD,
+		0x4EAB, 0x9879, 0x5DF7, 0x6A61, 0x50CF, 0x5411, 0x8C61, 0x8427, 0x785D, 0x9704, 0x524A,
+		0x54EE, 0x56A3, 0x9500, 0x6D88, 0x5BB5, 0x6DC6, 0x6653, 0x5C0F, 0x5B5D, 0x6821, 0x8096,
+		0x5578, 0x7B11, 0x6548, 0x6954, 0x4E9B, 0x6B47, 0x874E, 0x978B, 0x534F, 0x631F, 0x643A,
+		0x90AA, 0x659C, 0x80C1, 0x8C10, 0x5199, 0x68B0, 0x5378, 0x87F9, 0x61C8, 0x6CC4, 0x6CFB,
+		0x8C22, 0x5C51, 0x85AA, 0x82AF, 0x950C, 0x6B23, 0x8F9B, 0x65B0, 0x5FFB, 0x5FC3, 0x4FE1,
+		0x8845, 0x661F, 0x8165, 0x7329, 0x60FA, 0x5174, 0x5211, 0x578B, 0x5F62, 0x90A2, 0x884C,
+		0x9192, 0x5E78, 0x674F, 0x6027, 0x59D3, 0x5144, 0x51F6, 0x80F8, 0x5308, 0x6C79, 0x96C4,
+		0x718A, 0x4F11, 0x4FEE, 0x7F9E, 0x673D, 0x55C5, 0x9508, 0x79C0, 0x8896, 0x7EE3, 0x589F,
+		0x620C, 0x9700, 0x865A, 0x5618, 0x987B, 0x5F90, 0x8BB8, 0x84C4, 0x9157, 0x53D9, 0x65ED,
+		0x5E8F, 0x755C, 0x6064, 0x7D6E, 0x5A7F, 0x7EEA, 0x7EED, 0x8F69, 0x55A7, 0x5BA3, 0x60AC,
+		0x65CB, 0x7384, 0x9009, 0x7663, 0x7729, 0x7EDA, 0x9774, 0x859B, 0x5B66, 0x7A74, 0x96EA,
+		0x8840, 0x52CB, 0x718F, 0x5FAA, 0x65EC, 0x8BE2, 0x5BFB, 0x9A6F, 0x5DE1, 0x6B89, 0x6C5B,
+		0x8BAD, 0x8BAF, 0x900A, 0x8FC5, 0x538B, 0x62BC, 0x9E26, 0x9E2D, 0x5440, 0x4E2B, 0x82BD,
+		0x7259, 0x869C, 0x5D16, 0x8859, 0x6DAF, 0x96C5, 0x54D1, 0x4E9A, 0x8BB6, 0x7109, 0x54BD,
+		0x9609, 0x70DF, 0x6DF9, 0x76D0, 0x4E25, 0x7814, 0x8712, 0x5CA9, 0x5EF6, 0x8A00, 0x989C,
+		0x960E, 0x708E, 0x6CBF, 0x5944, 0x63A9, 0x773C, 0x884D, 0x6F14, 0x8273, 0x5830, 0x71D5,
+		0x538C, 0x781A, 0x96C1, 0x5501, 0x5F66, 0x7130, 0x5BB4, 0x8C1A, 0x9A8C, 0x6B83, 0x592E,
+		0x9E2F, 0x79E7, 0x6768, 0x626C, 0x4F6F, 0x75A1, 0x7F8A, 0x6D0B, 0x9633, 0x6C27, 0x4EF0,
+		0x75D2, 0x517B, 0x6837, 0x6F3E, 0x9080, 0x8170, 0x5996, 0x7476, 0x6447, 0x5C27, 0x9065,
+		0x7A91, 0x8C23, 0x59DA, 0x54AC, 0x8200, 0x836F, 0x8981, 0x8000, 0x6930, 0x564E, 0x8036,
+		0x7237, 0x91CE, 0x51B6, 0x4E5F, 0x9875, 0x6396, 0x4E1A, 0x53F6, 0x66F3, 0x814B, 0x591C,
+		0x6DB2, 0x4E00, 0x58F9, 0x533B, 0x63D6, 0x94F1, 0x4F9D, 0x4F0A, 0x8863, 0x9890, 0x5937,
+		0x9057, 0x79FB, 0x4EEA, 0x80F0, 0x7591, 0x6C82, 0x5B9C, 0x59E8, 0x5F5D, 0x6905, 0x8681,
+		0x501A, 0x5DF2, 0x4E59, 0x77E3, 0x4EE5, 0x827A, 0x6291, 0x6613, 0x9091, 0x5C79, 0x4EBF,
+		0x5F79, 0x81C6, 0x9038, 0x8084, 0x75AB, 0x4EA6, 0x88D4, 0x610F, 0x6BC5, 0x5FC6, 0x4E49,
+		0x76CA, 0x6EA2, 0x8BE3, 0x8BAE, 0x8C0A, 0x8BD1, 0x5F02, 0x7FFC, 0x7FCC, 0x7ECE, 0x8335,
+		0x836B, 0x56E0, 0x6BB7, 0x97F3, 0x9634, 0x59FB, 0x541F, 0x94F6, 0x6DEB, 0x5BC5, 0x996E,
+		0x5C39, 0x5F15, 0x9690, 0x5370, 0x82F1, 0x6A31, 0x5A74, 0x9E70, 0x5E94, 0x7F28, 0x83B9,
+		0x8424, 0x8425, 0x8367, 0x8747, 0x8FCE, 0x8D62, 0x76C8, 0x5F71, 0x9896, 0x786C, 0x6620,
+		0x54DF, 0x62E5, 0x4F63, 0x81C3, 0x75C8, 0x5EB8, 0x96CD, 0x8E0A, 0x86F9, 0x548F, 0x6CF3,
+		0x6D8C, 0x6C38, 0x607F, 0x52C7, 0x7528, 0x5E7D, 0x4F18, 0x60A0, 0x5FE7, 0x5C24, 0x7531,
+		0x90AE, 0x94C0, 0x72B9, 0x6CB9, 0x6E38, 0x9149, 0x6709, 0x53CB, 0x53F3, 0x4F51, 0x91C9,
+		0x8BF1, 0x53C8, 0x5E7C, 0x8FC2, 0x6DE4, 0x4E8E, 0x76C2, 0x6986, 0x865E, 0x611A, 0x8206,
+		0x4F59, 0x4FDE, 0x903E, 0x9C7C, 0x6109, 0x6E1D, 0x6E14, 0x9685, 0x4E88, 0x5A31, 0x96E8,
+		0x4E0E, 0x5C7F, 0x79B9, 0x5B87, 0x8BED, 0x7FBD, 0x7389, 0x57DF, 0x828B, 0x90C1, 0x5401,
+		0x9047, 0x55BB, 0x5CEA, 0x5FA1, 0x6108, 0x6B32, 0x72F1, 0x80B2, 0x8A89, 0x6D74, 0x5BD3,
+		0x88D5, 0x9884, 0x8C6B, 0x9A6D, 0x9E33, 0x6E0A, 0x51A4, 0x5143, 0x57A3, 0x8881, 0x539F,
+		0x63F4, 0x8F95, 0x56ED, 0x5458, 0x5706, 0x733F, 0x6E90, 0x7F18, 0x8FDC, 0x82D1, 0x613F,
+		0x6028, 0x9662, 0x66F0, 0x7EA6, 0x8D8A, 0x8DC3, 0x94A5, 0x5CB3, 0x7CA4, 0x6708, 0x60A6,
+		0x9605, 0x8018, 0x4E91, 0x90E7, 0x5300, 0x9668, 0x5141, 0x8FD0, 0x8574, 0x915D, 0x6655,
+		0x97F5, 0x5B55, 0x531D, 0x7838, 0x6742, 0x683D, 0x54C9, 0x707E, 0x5BB0, 0x8F7D, 0x518D,
+		0x5728, 0x54B1, 0x6512, 0x6682, 0x8D5E, 0x8D43, 0x810F, 0x846C, 0x906D, 0x7CDF, 0x51FF,
+		0x85FB, 0x67A3, 0x65E9, 0x6FA1, 0x86A4, 0x8E81, 0x566A, 0x9020, 0x7682, 0x7076, 0x71E5,
+		0x8D23, 0x62E9, 0x5219, 0x6CFD, 0x8D3C, 0x600E, 0x589E, 0x618E, 0x66FE, 0x8D60, 0x624E,
+		0x55B3, 0x6E23, 0x672D, 0x8F67, 0x94E1, 0x95F8, 0x7728, 0x6805, 0x69A8, 0x548B, 0x4E4D,
+		0x70B8, 0x8BC8, 0x6458, 0x658B, 0x5B85, 0x7A84, 0x503A, 0x5BE8, 0x77BB, 0x6BE1, 0x8A79,
+		0x7C98, 0x6CBE, 0x76CF, 0x65A9, 0x8F97, 0x5D2D, 0x5C55, 0x8638, 0x6808, 0x5360, 0x6218,
+		0x7AD9, 0x6E5B, 0x7EFD, 0x6A1F, 0x7AE0, 0x5F70, 0x6F33, 0x5F20, 0x638C, 0x6DA8, 0x6756,
+		0x4E08, 0x5E10, 0x8D26, 0x4ED7, 0x80C0, 0x7634, 0x969C, 0x62DB, 0x662D, 0x627E, 0x6CBC,
+		0x8D75, 0x7167, 0x7F69, 0x5146, 0x8087, 0x53EC, 0x906E, 0x6298, 0x54F2, 0x86F0, 0x8F99,
+		0x8005, 0x9517, 0x8517, 0x8FD9, 0x6D59, 0x73CD, 0x659F, 0x771F, 0x7504, 0x7827, 0x81FB,
+		0x8D1E, 0x9488, 0x4FA6, 0x6795, 0x75B9, 0x8BCA, 0x9707, 0x632F, 0x9547, 0x9635, 0x84B8,
+		0x6323, 0x7741, 0x5F81, 0x72F0, 0x4E89, 0x6014, 0x6574, 0x62EF, 0x6B63, 0x653F, 0x5E27,
+		0x75C7, 0x90D1, 0x8BC1, 0x829D, 0x679D, 0x652F, 0x5431, 0x8718, 0x77E5, 0x80A2, 0x8102,
+		0x6C41, 0x4E4B, 0x7EC7, 0x804C, 0x76F4, 0x690D, 0x6B96, 0x6267, 0x503C, 0x4F84, 0x5740,
+		0x6307, 0x6B62, 0x8DBE, 0x53EA, 0x65E8, 0x7EB8, 0x5FD7, 0x631A, 0x63B7, 0x81F3, 0x81F4,
+		0x7F6E, 0x5E1C, 0x5CD9, 0x5236, 0x667A, 0x79E9, 0x7A1A, 0x8D28, 0x7099, 0x75D4, 0x6EDE,
+		0x6CBB, 0x7A92, 0x4E2D, 0x76C5, 0x5FE0, 0x949F, 0x8877, 0x7EC8, 0x79CD, 0x80BF, 0x91CD,
+		0x4EF2, 0x4F17, 0x821F, 0x5468, 0x5DDE, 0x6D32, 0x8BCC, 0x7CA5, 0x8F74, 0x8098, 0x5E1A,
+		0x5492, 0x76B1, 0x5B99, 0x663C, 0x9AA4, 0x73E0, 0x682A, 0x86DB, 0x6731, 0x732A, 0x8BF8,
+		0x8BDB, 0x9010, 0x7AF9, 0x70DB, 0x716E, 0x62C4, 0x77A9, 0x5631, 0x4E3B, 0x8457, 0x67F1,
+		0x52A9, 0x86C0, 0x8D2E, 0x94F8, 0x7B51, 0x4F4F, 0x6CE8, 0x795D, 0x9A7B, 0x6293, 0x722A,
+		0x62FD, 0x4E13, 0x7816, 0x8F6C, 0x64B0, 0x8D5A, 0x7BC6, 0x6869, 0x5E84, 0x88C5, 0x5986,
+		0x649E, 0x58EE, 0x72B6, 0x690E, 0x9525, 0x8FFD, 0x8D58, 0x5760, 0x7F00, 0x8C06, 0x51C6,
+		0x6349, 0x62D9, 0x5353, 0x684C, 0x7422, 0x8301, 0x914C, 0x5544, 0x7740, 0x707C, 0x6D4A,
+		0x5179, 0x54A8, 0x8D44, 0x59FF, 0x6ECB, 0x6DC4, 0x5B5C, 0x7D2B, 0x4ED4, 0x7C7D, 0x6ED3,
+		0x5B50, 0x81EA, 0x6E0D, 0x5B57, 0x9B03, 0x68D5, 0x8E2A, 0x5B97, 0x7EFC, 0x603B, 0x7EB5,
+		0x90B9, 0x8D70, 0x594F, 0x63CD, 0x79DF, 0x8DB3, 0x5352, 0x65CF, 0x7956, 0x8BC5, 0x963B,
+		0x7EC4, 0x94BB, 0x7E82, 0x5634, 0x9189, 0x6700, 0x7F6A, 0x5C0A, 0x9075, 0x6628, 0x5DE6,
+		0x4F50, 0x67DE, 0x505A, 0x4F5C, 0x5750, 0x5EA7, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0x4E8D, 0x4E0C, 0x5140, 0x4E10, 0x5EFF, 0x5345, 0x4E15, 0x4E98, 0x4E1E, 0x9B32, 0x5B6C,
+		0x5669, 0x4E28, 0x79BA, 0x4E3F, 0x5315, 0x4E47, 0x592D, 0x723B, 0x536E, 0x6C10, 0x56DF,
+		0x80E4, 0x9997, 0x6BD3, 0x777E, 0x9F17, 0x4E36, 0x4E9F, 0x9F10, 0x4E5C, 0x4E69, 0x4E93,
+		0x8288, 0x5B5B, 0x556C, 0x560F, 0x4EC4, 0x538D, 0x539D, 0x53A3, 0x53A5, 0x53AE, 0x9765,
+		0x8D5D, 0x531A, 0x53F5, 0x5326, 0x532E, 0x533E, 0x8D5C, 0x5366, 0x5363, 0x5202, 0x5208,
+		0x520E, 0x522D, 0x5233, 0x523F, 0x5240, 0x524C, 0x525E, 0x5261, 0x525C, 0x84AF, 0x527D,
+		0x5282, 0x5281, 0x5290, 0x5293, 0x5182, 0x7F54, 0x4EBB, 0x4EC3, 0x4EC9, 0x4EC2, 0x4EE8,
+		0x4EE1, 0x4EEB, 0x4EDE, 0x4F1B, 0x4EF3, 0x4F22, 0x4F64, 0x4EF5, 0x4F25, 0x4F27, 0x4F09,
+		0x4F2B, 0x4F5E, 0x4F67, 0x6538, 0x4F5A, 0x4F5D, 0x4F5F, 0x4F57, 0x4F32, 0x4F3D, 0x4F76,
+		0x4F74, 0x4F91, 0x4F89, 0x4F83, 0x4F8F, 0x4F7E, 0x4F7B, 0x4FAA, 0x4F7C, 0x4FAC, 0x4F94,
+		0x4FE6, 0x4FE8, 0x4FEA, 0x4FC5, 0x4FDA, 0x4FE3, 0x4FDC, 0x4FD1, 0x4FDF, 0x4FF8, 0x5029,
+		0x504C, 0x4FF3, 0x502C, 0x500F, 0x502E, 0x502D, 0x4FFE, 0x501C, 0x500C, 0x5025, 0x5028,
+		0x507E, 0x5043, 0x5055, 0x5048, 0x504E, 0x506C, 0x507B, 0x50A5, 0x50A7, 0x50A9, 0x50BA,
+		0x50D6, 0x5106, 0x50ED, 0x50EC, 0x50E6, 0x50EE, 0x5107, 0x510B, 0x4EDD, 0x6C3D, 0x4F58,
+		0x4F65, 0x4FCE, 0x9FA0, 0x6C46, 0x7C74, 0x516E, 0x5DFD, 0x9EC9, 0x9998, 0x5181, 0x5914,
+		0x52F9, 0x530D, 0x8A07, 0x5310, 0x51EB, 0x5919, 0x5155, 0x4EA0, 0x5156, 0x4EB3, 0x886E,
+		0x88A4, 0x4EB5, 0x8114, 0x88D2, 0x7980, 0x5B34, 0x8803, 0x7FB8, 0x51AB, 0x51B1, 0x51BD,
+		0x51BC, 0x51C7, 0x5196, 0x51A2, 0x51A5, 0x8BA0, 0x8BA6, 0x8BA7, 0x8BAA, 0x8BB4, 0x8BB5,
+		0x8BB7, 0x8BC2, 0x8BC3, 0x8BCB, 0x8BCF, 0x8BCE, 0x8BD2, 0x8BD3, 0x8BD4, 0x8BD6, 0x8BD8,
+		0x8BD9, 0x8BDC, 0x8BDF, 0x8BE0, 0x8BE4, 0x8BE8, 0x8BE9, 0x8BEE, 0x8BF0, 0x8BF3, 0x8BF6,
+		0x8BF9, 0x8BFC, 0x8BFF, 0x8C00, 0x8C02, 0x8C04, 0x8C07, 0x8C0C, 0x8C0F, 0x8C11, 0x8C12,
+		0x8C14, 0x8C15, 0x8C16, 0x8C19, 0x8C1B, 0x8C18, 0x8C1D, 0x8C1F, 0x8C20, 0x8C21, 0x8C25,
+		0x8C27, 0x8C2A, 0x8C2B, 0x8C2E, 0x8C2F, 0x8C32, 0x8C33, 0x8C35, 0x8C36, 0x5369, 0x537A,
+		0x961D, 0x9622, 0x9621, 0x9631, 0x962A, 0x963D, 0x963C, 0x9642, 0x9649, 0x9654, 0x965F,
+		0x9667, 0x966C, 0x9672, 0x9674, 0x9688, 0x968D, 0x9697, 0x96B0, 0x9097, 0x909B, 0x909D,
+		0x9099, 0x90AC, 0x90A1, 0x90B4, 0x90B3, 0x90B6, 0x90BA, 0x90B8, 0x90B0, 0x90CF, 0x90C5,
+		0x90BE, 0x90D0, 0x90C4, 0x90C7, 0x90D3, 0x90E6, 0x90E2, 0x90DC, 0x90D7, 0x90DB, 0x90EB,
+		0x90EF, 0x90FE, 0x9104, 0x9122, 0x911E, 0x9123, 0x9131, 0x912F, 0x9139, 0x9143, 0x9146,
+		0x520D, 0x5942, 0x52A2, 0x52AC, 0x52AD, 0x52BE, 0x54FF, 0x52D0, 0x52D6, 0x52F0, 0x53DF,
+		0x71EE, 0x77CD, 0x5EF4, 0x51F5, 0x51FC, 0x9B2F, 0x53B6, 0x5F01, 0x755A, 0x5DEF, 0x574C,
+		0x57A9, 0x57A1, 0x587E, 0x58BC, 0x58C5, 0x58D1, 0x5729, 0x572C, 0x572A, 0x5733, 0x5739,
+		0x572E, 0x572F, 0x575C, 0x573B, 0x5742, 0x5769, 0x5785, 0x576B, 0x5786, 0x577C, 0x577B,
+		0x5768, 0x576D, 0x5776, 0x5773, 0x57AD, 0x57A4, 0x578C, 0x57B2, 0x57CF, 0x57A7, 0x57B4,
+		0x5793, 0x57A0, 0x57D5, 0x57D8, 0x57DA, 0x57D9, 0x57D2, 0x57B8, 0x57F4, 0x57EF, 0x57F8,
+		0x57E4, 0x57DD, 0x580B, 0x580D, 0x57FD, 0x57ED, 0x5800, 0x581E, 0x5819, 0x5844, 0x5820,
+		0x5865, 0x586C, 0x5881, 0x5889, 0x589A, 0x5880, 0x99A8, 0x9F19, 0x61FF, 0x8279, 0x827D,
+		0x827F, 0x828F, 0x828A, 0x82A8, 0x8284, 0x828E, 0x8291, 0x8297, 0x8299, 0x82AB, 0x82B8,
+		0x82BE, 0x82B0, 0x82C8, 0x82CA, 0x82E3, 0x8298, 0x82B7, 0x82AE, 0x82CB, 0x82CC, 0x82C1,
+		0x82A9, 0x82B4, 0x82A1, 0x82AA, 0x829F, 0x82C4, 0x82CE, 0x82A4, 0x82E1, 0x8309, 0x82F7,
+		0x82E4, 0x830F, 0x8307, 0x82DC, 0x82F4, 0x82D2, 0x82D8, 0x830C, 0x82FB, 0x82D3, 0x8311,
+		0x831A, 0x8306, 0x8314, 0x8315, 0x82E0, 0x82D5, 0x831C, 0x8351, 0x835B, 0x835C, 0x8308,
+		0x8392, 0x833C, 0x8334, 0x8331, 0x839B, 0x835E, 0x832F, 0x834F, 0x8347, 0x8343, 0x835F,
+		0x8340, 0x8317, 0x8360, 0x832D, 0x833A, 0x8333, 0x8366, 0x8365, 0x8368, 0x831B, 0x8369,
+		0x836C, 0x836A, 0x836D, 0x836E, 0x83B0, 0x8378, 0x83B3, 0x83B4, 0x83A0, 0x83AA, 0x8393,
+		0x839C, 0x8385, 0x837C, 0x83B6, 0x83A9, 0x837D, 0x83B8, 0x837B, 0x8398, 0x839E, 0x83A8,
+		0x83BA, 0x83BC, 0x83C1, 0x8401, 0x83E5, 0x83D8, 0x5807, 0x8418, 0x840B, 0x83DD, 0x83FD,
+		0x83D6, 0x841C, 0x8438, 0x8411, 0x8406, 0x83D4, 0x83DF, 0x840F, 0x8403, 0x83F8, 0x83F9,
+		0x83EA, 0x83C5, 0x83C0, 0x8426, 0x83F0, 0x83E1, 0x845C, 0x8451, 0x845A, 0x8459, 0x8473,
+		0x8487, 0x8488, 0x847A, 0x8489, 0x8478, 0x843C, 0x8446, 0x8469, 0x8476, 0x848C, 0x848E,
+		0x8431, 0x846D, 0x84C1, 0x84CD, 0x84D0, 0x84E6, 0x84BD, 0x84D3, 0x84CA, 0x84BF, 0x84BA,
+		0x84E0, 0x84A1, 0x84B9, 0x84B4, 0x8497, 0x84E5, 0x84E3, 0x850C, 0x750D, 0x8538, 0x84F0,
+		0x8539, 0x851F, 0x853A, 0x8556, 0x853B, 0x84FF, 0x84FC, 0x8559, 0x8548, 0x8568, 0x8564,
+		0x855E, 0x857A, 0x77A2, 0x8543, 0x8572, 0x857B, 0x85A4, 0x85A8, 0x8587, 0x858F, 0x8579,
+		0x85AE, 0x859C, 0x8585, 0x85B9, 0x85B7, 0x85B0, 0x85D3, 0x85C1, 0x85DC, 0x85FF, 0x8627,
+		0x8605, 0x8629, 0x8616, 0x863C, 0x5EFE, 0x5F08, 0x593C, 0x5941, 0x8037, 0x5955, 0x595A,
+		0x5958, 0x530F, 0x5C22, 0x5C25, 0x5C2C, 0x5C34, 0x624C, 0x626A, 0x629F, 0x62BB, 0x62CA,
+		0x62DA, 0x62D7, 0x62EE, 0x6322, 0x62F6, 0x6339, 0x634B, 0x6343, 0x63AD, 0x63F6, 0x6371,
+		0x637A, 0x638E, 0x63B4, 0x636D, 0x63AC, 0x638A, 0x6369, 0x63AE, 0x63BC, 0x63F2, 0x63F8,
+		0x63E0, 0x63FF, 0x63C4, 0x63DE, 0x63CE, 0x6452, 0x63C6, 0x63BE, 0x6445, 0x6441, 0x640B,
+		0x641B, 0x6420, 0x640C, 0x6426, 0x6421, 0x645E, 0x6484, 0x646D, 0x6496, 0x647A, 0x64B7,
+		0x64B8, 0x6499, 0x64BA, 0x64C0, 0x64D0, 0x64D7, 0x64E4, 0x64E2, 0x6509, 0x6525, 0x652E,
+		0x5F0B, 0x5FD2, 0x7519, 0x5F11, 0x535F, 0x53F1, 0x53FD, 0x53E9, 0x53E8, 0x53FB, 0x5412,
+		0x5416, 0x5406, 0x544B, 0x5452, 0x5453, 0x5454, 0x5456, 0x5443, 0x5421, 0x5457, 0x5459,
+		0x5423, 0x5432, 0x5482, 0x5494, 0x5477, 0x5471, 0x5464, 0x549A, 0x549B, 0x5484, 0x5476,
+		0x5466, 0x549D, 0x54D0, 0x54AD, 0x54C2, 0x54B4, 0x54D2, 0x54A7, 0x54A6, 0x54D3, 0x54D4,
+		0x5472, 0x54A3, 0x54D5, 0x54BB, 0x54BF, 0x54CC, 0x54D9, 0x54DA, 0x54DC, 0x54A9, 0x54AA,
+		0x54A4, 0x54DD, 0x54CF, 0x54DE, 0x551B, 0x54E7, 0x5520, 0x54FD, 0x5514, 0x54F3, 0x5522,
+		0x5523, 0x550F, 0x5511, 0x5527, 0x552A, 0x5567, 0x558F, 0x55B5, 0x5549, 0x556D, 0x5541,
+		0x5555, 0x553F, 0x5550, 0x553C, 0x5537, 0x5556, 0x5575, 0x5576, 0x5577, 0x5533, 0x5530,
+		0x555C, 0x558B, 0x55D2, 0x5583, 0x55B1, 0x55B9, 0x5588, 0x5581, 0x559F, 0x557E, 0x55D6,
+		0x5591, 0x557B, 0x55DF, 0x55BD, 0x55BE, 0x5594, 0x5599, 0x55EA, 0x55F7, 0x55C9, 0x561F,
+		0x55D1, 0x55EB, 0x55EC, 0x55D4, 0x55E6, 0x55DD, 0x55C4, 0x55EF, 0x55E5, 0x55F2, 0x55F3,
+		0x55CC, 0x55CD, 0x55E8, 0x55F5, 0x55E4, 0x8F94, 0x561E, 0x5608, 0x560C, 0x5601, 0x5624,
+		0x5623, 0x55FE, 0x5600, 0x5627, 0x562D, 0x5658, 0x5639, 0x5657, 0x562C, 0x564D, 0x5662,
+		0x5659, 0x565C, 0x564C, 0x5654, 0x5686, 0x5664, 0x5671, 0x566B, 0x567B, 0x567C, 0x5685,
+		0x5693, 0x56AF, 0x56D4, 0x56D7, 0x56DD, 0x56E1, 0x56F5, 0x56EB, 0x56F9, 0x56FF, 0x5704,
+		0x570A, 0x5709, 0x571C, 0x5E0F, 0x5E19, 0x5E14, 0x5E11, 0x5E31, 0x5E3B, 0x5E3C, 0x5E37,
+		0x5E44, 0x5E54, 0x5E5B, 0x5E5E, 0x5E61, 0x5C8C, 0x5C7A, 0x5C8D, 0x5C90, 0x5C96, 0x5C88,
+		0x5C98, 0x5C99, 0x5C91, 0x5C9A, 0x5C9C, 0x5CB5, 0x5CA2, 0x5CBD, 0x5CAC, 0x5CAB, 0x5CB1,
+		0x5CA3, 0x5CC1, 0x5CB7, 0x5CC4, 0x5CD2, 0x5CE4, 0x5CCB, 0x5CE5, 0x5D02, 0x5D03, 0x5D27,
+		0x5D26, 0x5D2E, 0x5D24, 0x5D1E, 0x5D06, 0x5D1B, 0x5D58, 0x5D3E, 0x5D34, 0x5D3D, 0x5D6C,
+		0x5D5B, 0x5D6F, 0x5D5D, 0x5D6B, 0x5D4B, 0x5D4A, 0x5D69, 0x5D74, 0x5D82, 0x5D99, 0x5D9D,
+		0x8C73, 0x5DB7, 0x5DC5, 0x5F73, 0x5F77, 0x5F82, 0x5F87, 0x5F89, 0x5F8C, 0x5F95, 0x5F99,
+		0x5F9C, 0x5FA8, 0x5FAD, 0x5FB5, 0x5FBC, 0x8862, 0x5F61, 0x72AD, 0x72B0, 0x72B4, 0x72B7,
+		0x72B8, 0x72C3, 0x72C1, 0x72CE, 0x72CD, 0x72D2, 0x72E8, 0x72EF, 0x72E9, 0x72F2, 0x72F4,
+		0x72F7, 0x7301, 0x72F3, 0x7303, 0x72FA, 0x72FB, 0x7317, 0x7313, 0x7321, 0x730A, 0x731E,
+		0x731D, 0x7315, 0x7322, 0x7339, 0x7325, 0x732C, 0x7338, 0x7331, 0x7350, 0x734D, 0x7357,
+		0x7360, 0x736C, 0x736F, 0x737E, 0x821B, 0x5925, 0x98E7, 0x5924, 0x5902, 0x9963, 0x9967,
+		0x9968, 0x9969, 0x996A, 0x996B, 0x996C, 0x9974, 0x9977, 0x997D, 0x9980, 0x9984, 0x9987,
+		0x998A, 0x998D, 0x9990, 0x9991, 0x9993, 0x9994, 0x9995, 0x5E80, 0x5E91, 0x5E8B, 0x5E96,
+		0x5EA5, 0x5EA0, 0x5EB9, 0x5EB5, 0x5EBE, 0x5EB3, 0x8D53, 0x5ED2, 0x5ED1, 0x5EDB, 0x5EE8,
+		0x5EEA, 0x81BA, 0x5FC4, 0x5FC9, 0x5FD6, 0x5FCF, 0x6003, 0x5FEE, 0x6004, 0x5FE1, 0x5FE4,
+		0x5FFE, 0x6005, 0x6006, 0x5FEA, 0x5FED, 0x5FF8, 0x6019, 0x6035, 0x6026, 0x601B, 0x600F,
+		0x600D, 0x6029, 0x602B, 0x600A, 0x603F, 0x6021, 0x6078, 0x6079, 0x607B, 0x607A, 0x6042,
+		0x606A, 0x607D, 0x6096, 0x609A, 0x60AD, 0x609D, 0x6083, 0x6092, 0x608C, 0x609B, 0x60EC,
+		0x60BB, 0x60B1, 0x60DD, 0x60D8, 0x60C6, 0x60DA, 0x60B4, 0x6120, 0x6126, 0x6115, 0x6123,
+		0x60F4, 0x6100, 0x610E, 0x612B, 0x614A, 0x6175, 0x61AC, 0x6194, 0x61A7, 0x61B7, 0x61D4,
+		0x61F5, 0x5FDD, 0x96B3, 0x95E9, 0x95EB, 0x95F1, 0x95F3, 0x95F5, 0x95F6, 0x95FC, 0x95FE,
+		0x9603, 0x9604, 0x9606, 0x9608, 0x960A, 0x960B, 0x960C, 0x960D, 0x960F, 0x9612, 0x9615,
+		0x9616, 0x9617, 0x9619, 0x961A, 0x4E2C, 0x723F, 0x6215, 0x6C35, 0x6C54, 0x6C5C, 0x6C4A,
+		0x6CA3, 0x6C85, 0x6C90, 0x6C94, 0x6C8C, 0x6C68, 0x6C69, 0x6C74, 0x6C76, 0x6C86, 0x6CA9,
+		0x6CD0, 0x6CD4, 0x6CAD, 0x6CF7, 0x6CF8, 0x6CF1, 0x6CD7, 0x6CB2, 0x6CE0, 0x6CD6, 0x6CFA,
+		0x6CEB, 0x6CEE, 0x6CB1, 0x6CD3, 0x6CEF, 0x6CFE, 0x6D39, 0x6D27, 0x6D0C, 0x6D43, 0x6D48,
+		0x6D07, 0x6D04, 0x6D19, 0x6D0E, 0x6D2B, 0x6D4D, 0x6D2E, 0x6D35, 0x6D1A, 0x6D4F, 0x6D52,
+		0x6D54, 0x6D33, 0x6D91, 0x6D6F, 0x6D9E, 0x6DA0, 0x6D5E, 0x6D93, 0x6D94, 0x6D5C, 0x6D60,
+		0x6D7C, 0x6D63, 0x6E1A, 0x6DC7, 0x6DC5, 0x6DDE, 0x6E0E, 0x6DBF, 0x6DE0, 0x6E11, 0x6DE6,
+		0x6DDD, 0x6DD9, 0x6E16, 0x6DAB, 0x6E0C, 0x6DAE, 0x6E2B, 0x6E6E, 0x6E4E, 0x6E6B, 0x6EB2,
+		0x6E5F, 0x6E86, 0x6E53, 0x6E54, 0x6E32, 0x6E25, 0x6E44, 0x6EDF, 0x6EB1, 0x6E98, 0x6EE0,
+		0x6F2D, 0x6EE2, 0x6EA5, 0x6EA7, 0x6EBD, 0x6EBB, 0x6EB7, 0x6ED7, 0x6EB4, 0x6ECF, 0x6E8F,
+		0x6EC2, 0x6E9F, 0x6F62, 0x6F46, 0x6F47, 0x6F24, 0x6F15, 0x6EF9, 0x6F2F, 0x6F36, 0x6F4B,
+		0x6F74, 0x6F2A, 0x6F09, 0x6F29, 0x6F89, 0x6F8D, 0x6F8C, 0x6F78, 0x6F72, 0x6F7C, 0x6F7A,
+		0x6FD1, 0x6FC9, 0x6FA7, 0x6FB9, 0x6FB6, 0x6FC2, 0x6FE1, 0x6FEE, 0x6FDE, 0x6FE0, 0x6FEF,
+		0x701A, 0x7023, 0x701B, 0x7039, 0x7035, 0x704F, 0x705E, 0x5B80, 0x5B84, 0x5B95, 0x5B93,
+		0x5BA5, 0x5BB8, 0x752F, 0x9A9E, 0x6434, 0x5BE4, 0x5BEE, 0x8930, 0x5BF0, 0x8E47, 0x8B07,
+		0x8FB6, 0x8FD3, 0x8FD5, 0x8FE5, 0x8FEE, 0x8FE4, 0x8FE9, 0x8FE6, 0x8FF3, 0x8FE8, 0x9005,
+		0x9004, 0x900B, 0x9026, 0x9011, 0x900D, 0x9016, 0x9021, 0x9035, 0x9036, 0x902D, 0x902F,
+		0x9044, 0x9051, 0x9052, 0x9050, 0x9068, 0x9058, 0x9062, 0x905B, 0x66B9, 0x9074, 0x907D,
+		0x9082, 0x9088, 0x9083, 0x908B, 0x5F50, 0x5F57, 0x5F56, 0x5F58, 0x5C3B, 0x54AB, 0x5C50,
+		0x5C59, 0x5B71, 0x5C63, 0x5C66, 0x7FBC, 0x5F2A, 0x5F29, 0x5F2D, 0x8274, 0x5F3C, 0x9B3B,
+		0x5C6E, 0x5981, 0x5983, 0x598D, 0x59A9, 0x59AA, 0x59A3, 0x5997, 0x59CA, 0x59AB, 0x599E,
+		0x59A4, 0x59D2, 0x59B2, 0x59AF, 0x59D7, 0x59BE, 0x5A05, 0x5A06, 0x59DD, 0x5A08, 0x59E3,
+		0x59D8, 0x59F9, 0x5A0C, 0x5A09, 0x5A32, 0x5A34, 0x5A11, 0x5A23, 0x5A13, 0x5A40, 0x5A67,
+		0x5A4A, 0x5A55, 0x5A3C, 0x5A62, 0x5A75, 0x80EC, 0x5AAA, 0x5A9B, 0x5A77, 0x5A7A, 0x5ABE,
+		0x5AEB, 0x5AB2, 0x5AD2, 0x5AD4, 0x5AB8, 0x5AE0, 0x5AE3, 0x5AF1, 0x5AD6, 0x5AE6, 0x5AD8,
+		0x5ADC, 0x5B09, 0x5B17, 0x5B16, 0x5B32, 0x5B37, 0x5B40, 0x5C15, 0x5C1C, 0x5B5A, 0x5B65,
+		0x5B73, 0x5B51, 0x5B53, 0x5B62, 0x9A75, 0x9A77, 0x9A78, 0x9A7A, 0x9A7F, 0x9A7D, 0x9A80,
+		0x9A81, 0x9A85, 0x9A88, 0x9A8A, 0x9A90, 0x9A92, 0x9A93, 0x9A96, 0x9A98, 0x9A9B, 0x9A9C,
+		0x9A9D, 0x9A9F, 0x9AA0, 0x9AA2, 0x9AA3, 0x9AA5, 0x9AA7, 0x7E9F, 0x7EA1, 0x7EA3, 0x7EA5,
+		0x7EA8, 0x7EA9, 0x7EAD, 0x7EB0, 0x7EBE, 0x7EC0, 0x7EC1, 0x7EC2, 0x7EC9, 0x7ECB, 0x7ECC,
+		0x7ED0, 0x7ED4, 0x7ED7, 0x7EDB, 0x7EE0, 0x7EE1, 0x7EE8, 0x7EEB, 0x7EEE, 0x7EEF, 0x7EF1,
+		0x7EF2, 0x7F0D, 0x7EF6, 0x7EFA, 0x7EFB, 0x7EFE, 0x7F01, 0x7F02, 0x7F03, 0x7F07, 0x7F08,
+		0x7F0B, 0x7F0C, 0x7F0F, 0x7F11, 0x7F12, 0x7F17, 0x7F19, 0x7F1C, 0x7F1B, 0x7F1F, 0x7F21,
+		0x7F22, 0x7F23, 0x7F24, 0x7F25, 0x7F26, 0x7F27, 0x7F2A, 0x7F2B, 0x7F2C, 0x7F2D, 0x7F2F,
+		0x7F30, 0x7F31, 0x7F32, 0x7F33, 0x7F35, 0x5E7A, 0x757F, 0x5DDB, 0x753E, 0x9095, 0x738E,
+		0x7391, 0x73AE, 0x73A2, 0x739F, 0x73CF, 0x73C2, 0x73D1, 0x73B7, 0x73B3, 0x73C0, 0x73C9,
+		0x73C8, 0x73E5, 0x73D9, 0x987C, 0x740A, 0x73E9, 0x73E7, 0x73DE, 0x73BA, 0x73F2, 0x740F,
+		0x742A, 0x745B, 0x7426, 0x7425, 0x7428, 0x7430, 0x742E, 0x742C, 0x741B, 0x741A, 0x7441,
+		0x745C, 0x7457, 0x7455, 0x7459, 0x7477, 0x746D, 0x747E, 0x749C, 0x748E, 0x7480, 0x7481,
+		0x7487, 0x748B, 0x749E, 0x74A8, 0x74A9, 0x7490, 0x74A7, 0x74D2, 0x74BA, 0x97EA, 0x97EB,
+		0x97EC, 0x674C, 0x6753, 0x675E, 0x6748, 0x6769, 0x67A5, 0x6787, 0x676A, 0x6773, 0x6798,
+		0x67A7, 0x6775, 0x67A8, 0x679E, 0x67AD, 0x678B, 0x6777, 0x677C, 0x67F0, 0x6809, 0x67D8,
+		0x680A, 0x67E9, 0x67B0, 0x680C, 0x67D9, 0x67B5, 0x67DA, 0x67B3, 0x67DD, 0x6800, 0x67C3,
+		0x67B8, 0x67E2, 0x680E, 0x67C1, 0x67FD, 0x6832, 0x6833, 0x6860, 0x6861, 0x684E, 0x6862,
+		0x6844, 0x6864, 0x6883, 0x681D, 0x6855, 0x6866, 0x6841, 0x6867, 0x6840, 0x683E, 0x684A,
+		0x6849, 0x6829, 0x68B5, 0x688F, 0x6874, 0x6877, 0x6893, 0x686B, 0x68C2, 0x696E, 0x68FC,
+		0x691F, 0x6920, 0x68F9, 0x6924, 0x68F0, 0x690B, 0x6901, 0x6957, 0x68E3, 0x6910, 0x6971,
+		0x6939, 0x6960, 0x6942, 0x695D, 0x6984, 0x696B, 0x6980, 0x6998, 0x6978, 0x6934, 0x69CC,
+		0x6987, 0x6988, 0x69CE, 0x6989, 0x6966, 0x6963, 0x6979, 0x699B, 0x69A7, 0x69BB, 0x69AB,
+		0x69AD, 0x69D4, 0x69B1, 0x69C1, 0x69CA, 0x69DF, 0x6995, 0x69E0, 0x698D, 0x69FF, 0x6A2F,
+		0x69ED, 0x6A17, 0x6A18, 0x6A65, 0x69F2, 0x6A44, 0x6A3E, 0x6AA0, 0x6A50, 0x6A5B, 0x6A35,
+		0x6A8E, 0x6A79, 0x6A3D, 0x6A28, 0x6A58, 0x6A7C, 0x6A91, 0x6A90, 0x6AA9, 0x6A97, 0x6AAB,
+		0x7337, 0x7352, 0x6B81, 0x6B82, 0x6B87, 0x6B84, 0x6B92, 0x6B93, 0x6B8D, 0x6B9A, 0x6B9B,
+		0x6BA1, 0x6BAA, 0x8F6B, 0x8F6D, 0x8F71, 0x8F72, 0x8F73, 0x8F75, 0x8F76, 0x8F78, 0x8F77,
+		0x8F79, 0x8F7A, 0x8F7C, 0x8F7E, 0x8F81, 0x8F82, 0x8F84, 0x8F87, 0x8F8B, 0x8F8D, 0x8F8E,
+		0x8F8F, 0x8F98, 0x8F9A, 0x8ECE, 0x620B, 0x6217, 0x621B, 0x621F, 0x6222, 0x6221, 0x6225,
+		0x6224, 0x622C, 0x81E7, 0x74EF, 0x74F4, 0x74FF, 0x750F, 0x7511, 0x7513, 0x6534, 0x65EE,
+		0x65EF, 0x65F0, 0x660A, 0x6619, 0x6772, 0x6603, 0x6615, 0x6600, 0x7085, 0x66F7, 0x661D,
+		0x6634, 0x6631, 0x6636, 0x6635, 0x8006, 0x665F, 0x6654, 0x6641, 0x664F, 0x6656, 0x6661,
+		0x6657, 0x6677, 0x6684, 0x668C, 0x66A7, 0x669D, 0x66BE, 0x66DB, 0x66DC, 0x66E6, 0x66E9,
+		0x8D32, 0x8D33, 0x8D36, 0x8D3B, 0x8D3D, 0x8D40, 0x8D45, 0x8D46, 0x8D48, 0x8D49, 0x8D47,
+		0x8D4D, 0x8D55, 0x8D59, 0x89C7, 0x89CA, 0x89CB, 0x89CC, 0x89CE, 0x89CF, 0x89D0, 0x89D1,
+		0x726E, 0x729F, 0x725D, 0x7266, 0x726F, 0x727E, 0x727F, 0x7284, 0x728B, 0x728D, 0x728F,
+		0x7292, 0x6308, 0x6332, 0x63B0, 0x643F, 0x64D8, 0x8004, 0x6BEA, 0x6BF3, 0x6BFD, 0x6BF5,
+		0x6BF9, 0x6C05, 0x6C07, 0x6C06, 0x6C0D, 0x6C15, 0x6C18, 0x6C19, 0x6C1A, 0x6C21, 0x6C29,
+		0x6C24, 0x6C2A, 0x6C32, 0x6535, 0x6555, 0x656B, 0x724D, 0x7252, 0x7256, 0x7230, 0x8662,
+		0x5216, 0x809F, 0x809C, 0x8093, 0x80BC, 0x670A, 0x80BD, 0x80B1, 0x80AB, 0x80AD, 0x80B4,
+		0x80B7, 0x80E7, 0x80E8, 0x80E9, 0x80EA, 0x80DB, 0x80C2, 0x80C4, 0x80D9, 0x80CD, 0x80D7,
+		0x6710, 0x80DD, 0x80EB, 0x80F1, 0x80F4, 0x80ED, 0x810D, 0x810E, 0x80F2, 0x80FC, 0x6715,
+		0x8112, 0x8C5A, 0x8136, 0x811E, 0x812C, 0x8118, 0x8132, 0x8148, 0x814C, 0x8153, 0x8174,
+		0x8159, 0x815A, 0x8171, 0x8160, 0x8169, 0x817C, 0x817D, 0x816D, 0x8167, 0x584D, 0x5AB5,
+		0x8188, 0x8182, 0x8191, 0x6ED5, 0x81A3, 0x81AA, 0x81CC, 0x6726, 0x81CA, 0x81BB, 0x81C1,
+		0x81A6, 0x6B24, 0x6B37, 0x6B39, 0x6B43, 0x6B46, 0x6B59, 0x98D1, 0x98D2, 0x98D3, 0x98D5,
+		0x98D9, 0x98DA, 0x6BB3, 0x5F40, 0x6BC2, 0x89F3, 0x6590, 0x9F51, 0x6593, 0x65BC, 0x65C6,
+		0x65C4, 0x65C3, 0x65CC, 0x65CE, 0x65D2, 0x65D6, 0x7080, 0x709C, 0x7096, 0x709D, 0x70BB,
+		0x70C0, 0x70B7, 0x70AB, 0x70B1, 0x70E8, 0x70CA, 0x7110, 0x7113, 0x7116, 0x712F, 0x7131,
+		0x7173, 0x715C, 0x7168, 0x7145, 0x7172, 0x714A, 0x7178, 0x717A, 0x7198, 0x71B3, 0x71B5,
+		0x71A8, 0x71A0, 0x71E0, 0x71D4, 0x71E7, 0x71F9, 0x721D, 0x7228, 0x706C, 0x7118, 0x7166,
+		0x71B9, 0x623E, 0x623D, 0x6243, 0x6248, 0x6249, 0x793B, 0x7940, 0x7946, 0x7949, 0x795B,
+		0x795C, 0x7953, 0x795A, 0x7962, 0x7957, 0x7960, 0x796F, 0x7967, 0x797A, 0x7985, 0x798A,
+		0x799A, 0x79A7, 0x79B3, 0x5FD1, 0x5FD0, 0x603C, 0x605D, 0x605A, 0x6067, 0x6041, 0x6059,
+		0x6063, 0x60AB, 0x6106, 0x610D, 0x615D, 0x61A9, 0x619D, 0x61CB, 0x61D1, 0x6206, 0x8080,
+		0x807F, 0x6C93, 0x6CF6, 0x6DFC, 0x77F6, 0x77F8, 0x7800, 0x7809, 0x7817, 0x7818, 0x7811,
+		0x65AB, 0x782D, 0x781C, 0x781D, 0x7839, 0x783A, 0x783B, 0x781F, 0x783C, 0x7825, 0x782C,
+		0x7823, 0x7829, 0x784E, 0x786D, 0x7856, 0x7857, 0x7826, 0x7850, 0x7847, 0x784C, 0x786A,
+		0x789B, 0x7893, 0x789A, 0x7887, 0x789C, 0x78A1, 0x78A3, 0x78B2, 0x78B9, 0x78A5, 0x78D4,
+		0x78D9, 0x78C9, 0x78EC, 0x78F2, 0x7905, 0x78F4, 0x7913, 0x7924, 0x791E, 0x7934, 0x9F9B,
+		0x9EF9, 0x9EFB, 0x9EFC, 0x76F1, 0x7704, 0x770D, 0x76F9, 0x7707, 0x7708, 0x771A, 0x7722,
+		0x7719, 0x772D, 0x7726, 0x7735, 0x7738, 0x7750, 0x7751, 0x7747, 0x7743, 0x775A, 0x7768,
+		0x7762, 0x7765, 0x777F, 0x778D, 0x777D, 0x7780, 0x778C, 0x7791, 0x779F, 0x77A0, 0x77B0,
+		0x77B5, 0x77BD, 0x753A, 0x7540, 0x754E, 0x754B, 0x7548, 0x755B, 0x7572, 0x7579, 0x7583,
+		0x7F58, 0x7F61, 0x7F5F, 0x8A48, 0x7F68, 0x7F74, 0x7F71, 0x7F79, 0x7F81, 0x7F7E, 0x76CD,
+		0x76E5, 0x8832, 0x9485, 0x9486, 0x9487, 0x948B, 0x948A, 0x948C, 0x948D, 0x948F, 0x9490,
+		0x9494, 0x9497, 0x9495, 0x949A, 0x949B, 0x949C, 0x94A3, 0x94A4, 0x94AB, 0x94AA, 0x94AD,
+		0x94AC, 0x94AF, 0x94B0, 0x94B2, 0x94B4, 0x94B6, 0x94B7, 0x94B8, 0x94B9, 0x94BA, 0x94BC,
+		0x94BD, 0x94BF, 0x94C4, 0x94C8, 0x94C9, 0x94CA, 0x94CB, 0x94CC, 0x94CD, 0x94CE, 0x94D0,
+		0x94D1, 0x94D2, 0x94D5, 0x94D6, 0x94D7, 0x94D9, 0x94D8, 0x94DB, 0x94DE, 0x94DF, 0x94E0,
+		0x94E2, 0x94E4, 0x94E5, 0x94E7, 0x94E8, 0x94EA, 0x94E9, 0x94EB, 0x94EE, 0x94EF, 0x94F3,
+		0x94F4, 0x94F5, 0x94F7, 0x94F9, 0x94FC, 0x94FD, 0x94FF, 0x9503, 0x9502, 0x9506, 0x9507,
+		0x9509, 0x950A, 0x950D, 0x950E, 0x950F, 0x9512, 0x9513, 0x9514, 0x9515, 0x9516, 0x9518,
+		0x951B, 0x951D, 0x951E, 0x951F, 0x9522, 0x952A, 0x952B, 0x9529, 0x952C, 0x9531, 0x9532,
+		0x9534, 0x9536, 0x9537, 0x9538, 0x953C, 0x953E, 0x953F, 0x9542, 0x9535, 0x9544, 0x9545,
+		0x9546, 0x9549, 0x954C, 0x954E, 0x954F, 0x9552, 0x9553, 0x9554, 0x9556, 0x9557, 0x9558,
+		0x9559, 0x955B, 0x955E, 0x955F, 0x955D, 0x9561, 0x9562, 0x9564, 0x9565, 0x9566, 0x9567,
+		0x9568, 0x9569, 0x956A, 0x956B, 0x956C, 0x956F, 0x9571, 0x9572, 0x9573, 0x953A, 0x77E7,
+		0x77EC, 0x96C9, 0x79D5, 0x79ED, 0x79E3, 0x79EB, 0x7A06, 0x5D47, 0x7A03, 0x7A02, 0x7A1E,
+		0x7A14, 0x7A39, 0x7A37, 0x7A51, 0x9ECF, 0x99A5, 0x7A70, 0x7688, 0x768E, 0x7693, 0x7699,
+		0x76A4, 0x74DE, 0x74E0, 0x752C, 0x9E20, 0x9E22, 0x9E28, 0x9E29, 0x9E2A, 0x9E2B, 0x9E2C,
+		0x9E32, 0x9E31, 0x9E36, 0x9E38, 0x9E37, 0x9E39, 0x9E3A, 0x9E3E, 0x9E41, 0x9E42, 0x9E44,
+		0x9E46, 0x9E47, 0x9E48, 0x9E49, 0x9E4B, 0x9E4C, 0x9E4E, 0x9E51, 0x9E55, 0x9E57, 0x9E5A,
+		0x9E5B, 0x9E5C, 0x9E5E, 0x9E63, 0x9E66, 0x9E67, 0x9E68, 0x9E69, 0x9E6A, 0x9E6B, 0x9E6C,
+		0x9E71, 0x9E6D, 0x9E73, 0x7592, 0x7594, 0x7596, 0x75A0, 0x759D, 0x75AC, 0x75A3, 0x75B3,
+		0x75B4, 0x75B8, 0x75C4, 0x75B1, 0x75B0, 0x75C3, 0x75C2, 0x75D6, 0x75CD, 0x75E3, 0x75E8,
+		0x75E6, 0x75E4, 0x75EB, 0x75E7, 0x7603, 0x75F1, 0x75FC, 0x75FF, 0x7610, 0x7600, 0x7605,
+		0x760C, 0x7617, 0x760A, 0x7625, 0x7618, 0x7615, 0x7619, 0x761B, 0x763C, 0x7622, 0x7620,
+		0x7640, 0x762D, 0x7630, 0x763F, 0x7635, 0x7643, 0x763E, 0x7633, 0x764D, 0x765E, 0x7654,
+		0x765C, 0x7656, 0x766B, 0x766F, 0x7FCA, 0x7AE6, 0x7A78, 0x7A79, 0x7A80, 0x7A86, 0x7A88,
+		0x7A95, 0x7AA6, 0x7AA0, 0x7AAC, 0x7AA8, 0x7AAD, 0x7AB3, 0x8864, 0x8869, 0x8872, 0x887D,
+		0x887F, 0x8882, 0x88A2, 0x88C6, 0x88B7, 0x88BC, 0x88C9, 0x88E2, 0x88CE, 0x88E3, 0x88E5,
+		0x88F1, 0x891A, 0x88FC, 0x88E8, 0x88FE, 0x88F0, 0x8921, 0x8919, 0x8913, 0x891B, 0x890A,
+		0x8934, 0x892B, 0x8936, 0x8941, 0x8966, 0x897B, 0x758B, 0x80E5, 0x76B2, 0x76B4, 0x77DC,
+		0x8012, 0x8014, 0x8016, 0x801C, 0x8020, 0x8022, 0x8025, 0x8026, 0x8027, 0x8029, 0x8028,
+		0x8031, 0x800B, 0x8035, 0x8043, 0x8046, 0x804D, 0x8052, 0x8069, 0x8071, 0x8983, 0x9878,
+		0x9880, 0x9883, 0x9889, 0x988C, 0x988D, 0x988F, 0x9894, 0x989A, 0x989B, 0x989E, 0x989F,
+		0x98A1, 0x98A2, 0x98A5, 0x98A6, 0x864D, 0x8654, 0x866C, 0x866E, 0x867F, 0x867A, 0x867C,
+		0x867B, 0x86A8, 0x868D, 0x868B, 0x86AC, 0x869D, 0x86A7, 0x86A3, 0x86AA, 0x8693, 0x86A9,
+		0x86B6, 0x86C4, 0x86B5, 0x86CE, 0x86B0, 0x86BA, 0x86B1, 0x86AF, 0x86C9, 0x86CF, 0x86B4,
+		0x86E9, 0x86F1, 0x86F2, 0x86ED, 0x86F3, 0x86D0, 0x8713, 0x86DE, 0x86F4, 0x86DF, 0x86D8,
+		0x86D1, 0x8703, 0x8707, 0x86F8, 0x8708, 0x870A, 0x870D, 0x8709, 0x8723, 0x873B, 0x871E,
+		0x8725, 0x872E, 0x871A, 0x873E, 0x8748, 0x8734, 0x8731, 0x8729, 0x8737, 0x873F, 0x8782,
+		0x8722, 0x877D, 0x877E, 0x877B, 0x8760, 0x8770, 0x874C, 0x876E, 0x878B, 0x8753, 0x8763,
+		0x877C, 0x8764, 0x8759, 0x8765, 0x8793, 0x87AF, 0x87A8, 0x87D2, 0x87C6, 0x8788, 0x8785,
+		0x87AD, 0x8797, 0x8783, 0x87AB, 0x87E5, 0x87AC, 0x87B5, 0x87B3, 0x87CB, 0x87D3, 0x87BD,
+		0x87D1, 0x87C0, 0x87CA, 0x87DB, 0x87EA, 0x87E0, 0x87EE, 0x8816, 0x8813, 0x87FE, 0x880A,
+		0x881B, 0x8821, 0x8839, 0x883C, 0x7F36, 0x7F42, 0x7F44, 0x7F45, 0x8210, 0x7AFA, 0x7AFD,
+		0x7B08, 0x7B03, 0x7B04, 0x7B15, 0x7B0A, 0x7B2B, 0x7B0F, 0x7B47, 0x7B38, 0x7B2A, 0x7B19,
+		0x7B2E, 0x7B31, 0x7B20, 0x7B25, 0x7B24, 0x7B33, 0x7B3E, 0x7B1E, 0x7B58, 0x7B5A, 0x7B45,
+		0x7B75, 0x7B4C, 0x7B5D, 0x7B60, 0x7B6E, 0x7B7B, 0x7B62, 0x7B72, 0x7B71, 0x7B90, 0x7BA6,
+		0x7BA7, 0x7BB8, 0x7BAC, 0x7B9D, 0x7BA8, 0x7B85, 0x7BAA, 0x7B9C, 0x7BA2, 0x7BAB, 0x7BB4,
+		0x7BD1, 0x7BC1, 0x7BCC, 0x7BDD, 0x7BDA, 0x7BE5, 0x7BE6, 0x7BEA, 0x7C0C, 0x7BFE, 0x7BFC,
+		0x7C0F, 0x7C16, 0x7C0B, 0x7C1F, 0x7C2A, 0x7C26, 0x7C38, 0x7C41, 0x7C40, 0x81FE, 0x8201,
+		0x8202, 0x8204, 0x81EC, 0x8844, 0x8221, 0x8222, 0x8223, 0x822D, 0x822F, 0x8228, 0x822B,
+		0x8238, 0x823B, 0x8233, 0x8234, 0x823E, 0x8244, 0x8249, 0x824B, 0x824F, 0x825A, 0x825F,
+		0x8268, 0x887E, 0x8885, 0x8888, 0x88D8, 0x88DF, 0x895E, 0x7F9D, 0x7F9F, 0x7FA7, 0x7FAF,
+		0x7FB0, 0x7FB2, 0x7C7C, 0x6549, 0x7C91, 0x7C9D, 0x7C9C, 0x7C9E, 0x7CA2, 0x7CB2, 0x7CBC,
+		0x7CBD, 0x7CC1, 0x7CC7, 0x7CCC, 0x7CCD, 0x7CC8, 0x7CC5, 0x7CD7, 0x7CE8, 0x826E, 0x66A8,
+		0x7FBF, 0x7FCE, 0x7FD5, 0x7FE5, 0x7FE1, 0x7FE6, 0x7FE9, 0x7FEE, 0x7FF3, 0x7CF8, 0x7D77,
+		0x7DA6, 0x7DAE, 0x7E47, 0x7E9B, 0x9EB8, 0x9EB4, 0x8D73, 0x8D84, 0x8D94, 0x8D91, 0x8DB1,
+		0x8D67, 0x8D6D, 0x8C47, 0x8C49, 0x914A, 0x9150, 0x914E, 0x914F, 0x9164, 0x9162, 0x9161,
+		0x9170, 0x9169, 0x916F, 0x917D, 0x917E, 0x9172, 0x9174, 0x9179, 0x918C, 0x9185, 0x9190,
+		0x918D, 0x9191, 0x91A2, 0x91A3, 0x91AA, 0x91AD, 0x91AE, 0x91AF, 0x91B5, 0x91B4, 0x91BA,
+		0x8C55, 0x9E7E, 0x8DB8, 0x8DEB, 0x8E05, 0x8E59, 0x8E69, 0x8DB5, 0x8DBF, 0x8DBC, 0x8DBA,
+		0x8DC4, 0x8DD6, 0x8DD7, 0x8DDA, 0x8DDE, 0x8DCE, 0x8DCF, 0x8DDB, 0x8DC6, 0x8DEC, 0x8DF7,
+		0x8DF8, 0x8DE3, 0x8DF9, 0x8DFB, 0x8DE4, 0x8E09, 0x8DFD, 0x8E14, 0x8E1D, 0x8E1F, 0x8E2C,
+		0x8E2E, 0x8E23, 0x8E2F, 0x8E3A, 0x8E40, 0x8E39, 0x8E35, 0x8E3D, 0x8E31, 0x8E49, 0x8E41,
+		0x8E42, 0x8E51, 0x8E52, 0x8E4A, 0x8E70, 0x8E76, 0x8E7C, 0x8E6F, 0x8E74, 0x8E85, 0x8E8F,
+		0x8E94, 0x8E90, 0x8E9C, 0x8E9E, 0x8C78, 0x8C82, 0x8C8A, 0x8C85, 0x8C98, 0x8C94, 0x659B,
+		0x89D6, 0x89DE, 0x89DA, 0x89DC, 0x89E5, 0x89EB, 0x89EF, 0x8A3E, 0x8B26, 0x9753, 0x96E9,
+		0x96F3, 0x96EF, 0x9706, 0x9701, 0x9708, 0x970F, 0x970E, 0x972A, 0x972D, 0x9730, 0x973E,
+		0x9F80, 0x9F83, 0x9F85, 0x9F86, 0x9F87, 0x9F88, 0x9F89, 0x9F8A, 0x9F8C, 0x9EFE, 0x9F0B,
+		0x9F0D, 0x96B9, 0x96BC, 0x96BD, 0x96CE, 0x96D2, 0x77BF, 0x96E0, 0x928E, 0x92AE, 0x92C8,
+		0x933E, 0x936A, 0x93CA, 0x938F, 0x943E, 0x946B, 0x9C7F, 0x9C82, 0x9C85, 0x9C86, 0x9C87,
+		0x9C88, 0x7A23, 0x9C8B, 0x9C8E, 0x9C90, 0x9C91, 0x9C92, 0x9C94, 0x9C95, 0x9C9A, 0x9C9B,
+		0x9C9E, 0x9C9F, 0x9CA0, 0x9CA1, 0x9CA2, 0x9CA3, 0x9CA5, 0x9CA6, 0x9CA7, 0x9CA8, 0x9CA9,
+		0x9CAB, 0x9CAD, 0x9CAE, 0x9CB0, 0x9CB1, 0x9CB2, 0x9CB3, 0x9CB4, 0x9CB5, 0x9CB6, 0x9CB7,
+		0x9CBA, 0x9CBB, 0x9CBC, 0x9CBD, 0x9CC4, 0x9CC5, 0x9CC6, 0x9CC7, 0x9CCA, 0x9CCB, 0x9CCC,
+		0x9CCD, 0x9CCE, 0x9CCF, 0x9CD0, 0x9CD3, 0x9CD4, 0x9CD5, 0x9CD7, 0x9CD8, 0x9CD9, 0x9CDC,
+		0x9CDD, 0x9CDF, 0x9CE2, 0x977C, 0x9785, 0x9791, 0x9792, 0x9794, 0x97AF, 0x97AB, 0x97A3,
+		0x97B2, 0x97B4, 0x9AB1, 0x9AB0, 0x9AB7, 0x9E58, 0x9AB6, 0x9ABA, 0x9ABC, 0x9AC1, 0x9AC0,
+		0x9AC5, 0x9AC2, 0x9ACB, 0x9ACC, 0x9AD1, 0x9B45, 0x9B43, 0x9B47, 0x9B49, 0x9B48, 0x9B4D,
+		0x9B51, 0x98E8, 0x990D, 0x992E, 0x9955, 0x9954, 0x9ADF, 0x9AE1, 0x9AE6, 0x9AEF, 0x9AEB,
+		0x9AFB, 0x9AED, 0x9AF9, 0x9B08, 0x9B0F, 0x9B13, 0x9B1F, 0x9B23, 0x9EBD, 0x9EBE, 0x7E3B,
+		0x9E82, 0x9E87, 0x9E88, 0x9E8B, 0x9E92, 0x93D6, 0x9E9D, 0x9E9F, 0x9EDB, 0x9EDC, 0x9EDD,
+		0x9EE0, 0x9EDF, 0x9EE2, 0x9EE9, 0x9EE7, 0x9EE5, 0x9EEA, 0x9EEF, 0x9F22, 0x9F2C, 0x9F2F,
+		0x9F39, 0x9F37, 0x9F3D, 0x9F3E, 0x9F44, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD,
+	],
+	pointers: &[
+		71, 75, 6, 66, 31, 32, 661, 659, 665, 663, 683, 669, 667, 673, 671, 33, 677, 675, 682, 658,
+		662, 664, 666, 670, 674, 660, 668, 672, 676, 678, 679, 680, 681, 5, 4, 470, 471, 472, 473,
+		474, 475, 476, 477, 478, 479, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489, 490, 491,
+		492, 493, 502, 503, 504, 505, 506, 507, 508, 509, 510, 511, 512, 513, 514, 515, 516, 517,
+		518, 519, 520, 521, 522, 523, 524, 525, 570, 564, 565, 566, 567, 568, 569, 571, 572, 573,
+		574, 575, 576, 577, 578, 579, 580, 581, 582, 583, 584, 585, 586, 587, 588, 589, 590, 591,
+		592, 593, 594, 595, 596, 612, 613, 614, 615, 616, 617, 619, 620, 621, 622, 623, 624, 625,
+		626, 627, 628, 629, 630, 631, 632, 633, 634, 635, 636, 637, 638, 639, 640, 641, 642, 643,
+		644, 618, 9, 11, 13, 14, 15, 16, 12, 74, 67, 68, 88, 69, 76, 174, 175, 176, 177, 178, 179,
+		180, 181, 182, 183, 184, 185, 90, 91, 89, 92, 41, 38, 37, 43, 55, 61, 46, 45, 35, 36, 40,
+		39, 49, 50, 63, 62, 34, 42, 54, 53, 52, 56, 51, 59, 60, 57, 58, 48, 44, 47, 150, 151, 152,
+		153, 154, 155, 156, 157, 158, 159, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140,
+		141, 142, 143, 144, 145, 146, 147, 148, 149, 110, 111, 112, 113, 114, 115, 116, 117, 118,
+		119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 755, 756, 757, 758, 759, 760, 761,
+		762, 763, 764, 765, 766, 767, 768, 769, 770, 771, 772, 773, 774, 775, 776, 777, 778, 779,
+		780, 781, 782, 783, 784, 785, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797,
+		798, 799, 800, 801, 802, 803, 804, 805, 806, 807, 808, 809, 810, 811, 812, 813, 814, 815,
+		816, 817, 818, 819, 820, 821, 822, 823, 824, 825, 826, 827, 828, 829, 830, 85, 84, 87, 86,
+		83, 82, 79, 81, 80, 78, 77, 65, 64, 0, 1, 2, 7, 8, 19, 20, 21, 22, 23, 24, 25, 26, 29, 30,
+		93, 17, 18, 27, 28, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295,
+		296, 297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313,
+		314, 315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326, 327, 328, 329, 330, 331,
+		332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349,
+		350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 376, 377, 378,
+		379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391, 392, 393, 394, 395, 396,
+		397, 398, 399, 400, 401, 402, 403, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413, 414,
+		415, 416, 417, 418, 419, 420, 421, 422, 423, 424, 425, 426, 427, 428, 429, 430, 431, 432,
+		433, 434, 435, 436, 437, 438, 439, 440, 441, 442, 443, 444, 445, 446, 447, 448, 449, 450,
+		451, 452, 453, 454, 455, 456, 457, 458, 459, 460, 461, 3, 694, 695, 696, 697, 698, 699,
+		700, 701, 702, 703, 704, 705, 706, 707, 708, 709, 710, 711, 712, 713, 714, 715, 716, 717,
+		718, 719, 720, 721, 722, 723, 724, 725, 726, 727, 728, 729, 730, 162, 163, 164, 165, 166,
+		167, 168, 169, 170, 171, 4632, 1974, 3540, 4217, 4928, 3758, 3806, 4357, 5171, 1624, 4774,
+		5173, 1774, 5083, 3621, 5176, 3885, 3651, 1594, 4626, 1837, 1984, 3978, 5178, 1983, 3036,
+		4558, 3764, 5182, 2247, 4541, 6263, 5029, 2132, 1799, 3064, 5197, 4207, 1883, 4239, 5068,
+		2999, 2782, 5184, 3332, 2760, 5186, 3215, 4674, 4995, 4287, 4894, 2434, 2073, 2967, 3502,
+		3433, 3610, 2307, 1734, 4655, 5200, 2762, 3559, 4623, 4339, 4394, 3926, 5201, 3183, 3151,
+		3738, 3583, 3050, 4771, 4976, 3887, 2067, 5170, 4757, 2906, 4831, 2448, 5202, 4298, 2739,
+		5177, 4550, 4426, 5198, 5342, 4221, 2845, 2658, 2354, 4669, 1687, 2413, 3311, 4400, 2734,
+		4139, 3040, 3626, 5344, 5347, 3708, 5242, 4663, 3871, 3707, 5245, 5243, 5207, 2717, 3522,
+		1771, 5244, 2712, 2704, 3717, 1836, 3157, 1651, 5123, 3896, 4038, 4931, 2183, 4364, 5321,
+		5249, 3581, 5247, 1875, 3085, 4657, 5246, 4644, 5248, 3234, 4597, 5038, 5251, 5254, 2631,
+		2596, 3711, 2128, 2101, 3560, 5257, 4638, 4302, 2568, 2159, 2072, 4478, 5039, 4736, 2522,
+		2508, 5250, 3760, 4245, 1796, 5252, 3802, 5255, 3156, 5256, 4246, 5258, 1611, 2287, 5266,
+		1482, 3077, 3835, 3984, 3985, 5267, 1951, 1890, 4256, 1924, 5076, 5159, 4750, 4110, 2395,
+		5265, 5323, 4763, 5262, 2147, 5162, 5263, 5259, 5264, 3356, 4721, 5253, 5324, 5260, 3452,
+		2961, 4591, 1468, 2586, 5269, 5268, 5275, 5277, 5274, 3878, 5272, 5003, 1753, 5271, 3006,
+		3897, 5273, 5270, 5279, 1990, 2264, 4637, 4355, 3135, 2667, 4963, 1661, 3611, 2894, 5276,
+		5278, 4303, 2427, 3625, 1570, 1842, 2050, 5283, 2820, 5325, 3617, 3007, 5287, 4002, 2160,
+		5284, 5286, 1506, 4764, 5288, 4454, 5285, 5280, 5281, 3016, 5282, 2620, 4479, 2170, 2792,
+		5292, 5289, 1428, 5297, 5299, 1525, 5294, 1904, 2808, 4081, 2431, 4653, 5298, 2703, 1702,
+		5300, 2800, 5301, 5290, 3352, 5293, 5296, 5295, 4901, 5002, 3638, 5303, 2594, 5305, 5291,
+		5306, 3488, 5304, 5161, 4138, 2632, 5307, 3411, 4156, 5308, 5302, 1696, 2913, 2182, 3005,
+		1497, 1871, 5309, 5310, 1787, 5311, 1848, 1442, 5312, 3780, 4404, 5313, 3044, 5317, 3773,
+		5316, 5315, 5318, 4006, 2640, 3484, 5314, 5319, 5320, 3734, 2973, 2062, 5172, 4835, 4803,
+		4471, 1759, 4942, 4363, 2321, 2862, 3261, 2023, 4170, 5341, 5343, 1898, 1994, 2729, 3740,
+		3676, 1452, 2266, 3096, 5329, 2941, 2273, 2311, 4460, 1591, 3545, 2788, 1947, 5115, 4599,
+		2607, 3915, 2566, 5333, 5240, 3347, 2213, 3694, 1662, 4850, 3211, 3260, 5359, 3728, 4437,
+		2817, 3392, 2313, 5360, 4802, 5361, 5354, 1985, 2142, 1592, 5355, 1760, 2811, 2905, 4622,
+		2981, 1992, 5357, 5356, 2752, 3541, 5103, 5358, 3032, 1961, 3079, 2622, 1838, 3068, 3382,
+		2561, 2085, 2146, 5339, 3507, 2832, 2486, 1921, 5492, 4472, 4160, 1437, 1777, 2530, 5493,
+		2364, 4861, 1901, 1962, 5223, 3713, 2121, 3619, 5224, 2834, 5478, 5225, 4461, 2457, 6985,
+		3055, 3092, 4875, 2214, 1805, 1776, 3789, 3431, 3440, 3004, 1583, 5226, 2301, 1908, 5227,
+		5019, 3949, 3680, 3777, 1829, 2863, 2339, 5228, 5229, 2044, 2570, 4115, 4410, 5230, 3587,
+		2303, 2634, 4103, 3519, 5233, 5231, 5232, 1503, 2796, 3856, 2621, 2178, 2239, 5235, 2674,
+		5237, 5236, 3475, 5238, 5239, 3013, 3681, 1485, 2261, 2588, 4310, 5480, 3058, 1988, 5071,
+		3395, 2688, 5481, 5482, 3001, 2727, 2958, 5483, 3891, 1607, 4733, 3262, 4522, 5485, 2966,
+		5486, 2836, 3317, 3629, 5487, 5335, 3814, 2275, 4309, 4833, 1501, 1835, 4474, 5336, 5776,
+		5338, 5185, 2458, 1520, 1746, 5215, 4842, 2650, 2898, 4350, 5217, 2110, 5218, 3482, 3659,
+		4634, 5219, 3357, 3867, 3578, 5175, 3852, 4300, 2503, 1484, 2453, 4430, 1519, 5143, 5106,
+		1884, 3185, 3336, 1606, 1620, 1572, 5845, 4915, 2827, 3113, 5222, 3118, 5221, 4281, 5421,
+		4261, 5189, 3209, 4700, 4232, 2557, 3685, 3150, 2802, 4439, 5422, 3639, 1698, 2055, 4133,
+		3003, 3000, 4536, 4576, 5208, 1659, 2982, 2430, 5209, 4806, 4388, 5210, 5211, 4358, 1779,
+		2764, 5212, 5495, 3670, 4379, 3759, 1643, 4753, 1667, 2553, 4748, 3958, 2087, 2069, 3922,
+		3666, 3913, 1571, 4497, 3432, 5488, 1973, 2877, 2292, 2793, 3084, 5849, 5848, 5008, 2679,
+		4944, 1449, 1976, 2860, 4049, 5846, 3876, 4749, 5216, 4627, 2386, 3977, 4071, 5850, 1959,
+		5847, 4784, 1743, 2248, 5853, 2396, 2547, 1964, 4147, 3286, 2432, 2997, 4169, 4405, 5851,
+		4360, 3133, 5852, 3181, 2818, 3070, 4179, 4692, 2112, 5860, 5863, 2148, 1450, 2027, 2119,
+		2361, 4134, 2871, 3964, 3561, 4989, 5864, 4295, 1710, 4321, 1806, 4268, 2429, 4294, 4540,
+		5859, 1869, 1733, 2231, 5854, 3326, 5855, 5856, 5857, 3410, 5858, 5861, 4810, 5862, 3597,
+		4285, 3345, 5869, 5874, 5041, 5868, 5885, 4251, 2388, 5873, 5867, 3445, 3834, 2433, 3287,
+		2780, 5865, 5872, 3439, 4893, 2394, 2769, 4728, 2193, 5049, 5866, 2285, 2826, 3099, 5870,
+		5871, 5875, 5886, 5896, 5882, 5881, 5116, 5894, 5895, 6466, 4612, 5877, 2828, 4852, 2859,
+		5879, 4367, 5888, 4553, 5889, 1416, 3500, 5878, 2418, 2036, 4194, 2349, 4846, 5890, 4399,
+		1414, 5898, 5876, 4549, 5880, 5883, 5884, 5887, 2452, 5891, 5892, 5893, 5897, 5899, 4719,
+		2232, 3405, 5901, 3817, 3015, 3325, 2881, 4411, 4947, 5905, 1621, 2412, 5903, 5484, 4579,
+		4031, 1814, 1415, 5908, 4079, 5909, 5904, 5900, 5902, 5906, 5907, 2472, 5910, 5911, 2446,
+		3912, 4237, 5928, 1701, 5927, 5922, 5921, 4192, 5919, 5917, 2867, 5111, 3803, 5915, 1410,
+		5920, 5918, 5923, 5929, 2107, 3478, 3781, 2931, 5912, 3413, 5205, 5916, 3375, 5924, 5925,
+		5926, 4422, 5941, 4109, 5938, 2825, 5936, 4254, 5932, 3797, 2927, 5935, 2426, 2365, 5930,
+		5913, 5940, 5945, 1798, 5946, 4341, 2389, 5937, 4507, 5933, 4884, 5914, 3454, 5934, 4786,
+		5943, 5944, 5957, 4482, 5949, 5962, 5963, 5951, 5931, 3763, 5954, 5939, 3893, 5956, 5942,
+		4272, 3982, 5966, 5959, 5955, 5964, 5947, 5952, 5953, 5958, 5960, 5961, 5965, 5948, 3999,
+		5974, 5975, 5971, 5969, 2583, 5970, 2195, 5206, 4491, 3180, 5968, 5950, 5973, 5972, 5976,
+		5981, 5977, 5067, 1707, 5151, 3974, 5979, 4320, 2406, 5986, 5982, 4618, 5987, 5980, 5978,
+		5984, 5985, 5983, 5989, 3564, 5181, 4868, 5991, 3894, 5990, 2194, 5992, 5993, 5994, 5988,
+		2380, 4112, 5995, 4412, 5996, 3699, 2663, 3339, 5997, 5998, 3655, 3983, 5999, 2499, 5191,
+		4687, 6000, 4172, 2031, 6002, 4809, 2920, 1834, 4236, 6001, 6003, 2299, 2345, 4163, 6004,
+		3529, 6005, 4811, 3671, 6007, 6006, 6008, 4168, 3858, 4851, 5506, 5508, 5507, 2326, 5511,
+		5512, 1935, 5509, 5510, 1692, 5514, 2531, 5004, 5515, 2814, 2094, 5499, 4055, 2837, 2464,
+		5163, 2870, 2892, 2602, 4060, 5513, 1460, 4304, 2123, 5100, 3511, 2917, 4066, 5522, 5516,
+		3503, 5518, 5523, 3470, 5525, 5524, 2851, 5521, 5520, 1810, 2925, 3103, 5517, 5519, 4462,
+		5528, 2974, 5533, 2039, 5534, 5501, 2279, 4804, 5527, 2868, 5531, 5500, 1949, 5526, 2888,
+		5529, 5532, 5540, 2256, 1412, 3182, 1730, 5530, 5539, 3527, 5535, 5536, 5538, 5537, 5545,
+		4781, 1623, 5544, 5549, 5542, 5541, 5543, 3447, 2532, 5548, 5550, 4076, 2022, 5671, 5546,
+		5547, 3592, 2046, 5552, 5551, 5554, 1507, 1923, 2835, 4574, 2007, 5553, 4037, 7038, 4007,
+		4041, 4074, 3756, 5555, 4119, 5556, 5502, 5560, 5557, 2744, 3944, 5558, 3801, 3314, 3600,
+		5559, 4879, 4487, 3300, 2026, 5503, 1561, 5504, 5505, 2379, 3697, 3884, 3706, 5094, 3848,
+		2858, 2437, 4633, 6135, 1791, 1527, 2181, 4359, 5334, 4329, 4201, 5340, 2037, 4630, 2282,
+		6134, 6132, 1868, 4117, 4052, 2149, 5187, 4586, 2376, 3860, 4158, 4641, 2887, 2585, 2038,
+		5770, 5771, 5479, 4568, 3547, 3335, 2145, 2127, 2911, 5139, 3562, 1531, 5773, 2648, 4096,
+		5775, 5774, 1955, 3820, 1443, 3397, 3394, 3333, 2610, 4040, 2384, 6480, 3736, 6481, 4228,
+		5092, 2191, 3173, 3714, 6482, 2015, 2580, 4604, 6486, 3273, 6489, 6485, 6490, 4190, 2100,
+		6483, 6484, 6488, 3350, 6493, 6492, 3230, 3539, 6495, 3312, 6487, 3881, 2698, 2290, 6491,
+		4470, 4244, 6494, 6501, 4611, 2641, 6498, 6500, 2962, 4649, 2544, 6502, 4691, 5118, 4229,
+		4197, 3107, 6496, 6497, 2662, 6499, 6504, 6503, 6507, 6509, 3368, 3329, 2799, 3838, 6508,
+		2053, 3263, 4772, 6505, 6506, 3667, 6514, 6510, 3514, 4216, 6512, 6513, 2516, 6515, 6511,
+		2936, 4703, 6516, 3843, 6520, 6521, 4503, 3224, 3231, 6519, 6518, 6524, 4340, 7039, 6527,
+		6522, 2599, 3768, 2558, 4374, 6525, 6526, 6531, 6533, 6534, 6528, 1932, 6529, 6532, 3348,
+		6523, 6530, 6535, 6537, 6536, 6538, 5351, 6539, 6540, 5126, 6546, 6547, 2874, 4841, 5129,
+		1855, 4026, 6543, 5204, 5121, 4419, 3242, 6548, 2567, 2289, 6544, 4518, 2351, 3148, 5180,
+		3928, 6469, 6545, 2152, 3735, 3374, 6409, 3383, 4039, 6410, 4899, 4777, 3909, 1427, 3993,
+		4209, 2423, 6412, 6411, 5133, 2312, 5051, 1981, 4215, 4648, 1509, 3874, 1763, 3842, 2864,
+		4508, 3903, 6413, 2477, 4383, 2267, 4848, 2355, 4582, 4415, 2587, 6414, 3726, 2896, 1589,
+		4009, 2574, 2573, 4695, 3255, 2879, 2188, 3229, 2363, 4795, 3633, 3304, 1673, 2304, 6418,
+		3047, 4902, 6419, 6421, 1856, 2025, 3981, 4527, 1907, 3910, 2133, 3827, 2642, 4259, 5155,
+		4418, 3816, 2064, 6541, 2603, 1721, 3807, 6542, 1693, 5777, 4739, 5778, 4607, 5779, 2770,
+		5780, 3865, 4697, 1754, 6465, 3354, 2726, 4247, 3371, 2779, 3485, 1664, 2776, 3663, 4116,
+		2707, 4290, 3879, 3510, 6467, 4445, 4912, 6468, 3937, 4167, 3138, 6470, 3137, 6471, 6479,
+		4180, 3788, 4662, 6023, 4775, 4021, 3558, 6027, 6022, 6024, 6025, 6030, 1674, 6026, 2219,
+		6028, 6029, 6031, 1905, 6032, 6034, 6039, 4561, 6037, 6036, 3082, 6038, 4825, 6033, 6041,
+		1431, 6035, 2908, 6040, 6042, 6045, 6043, 5018, 4354, 6044, 6046, 3146, 2048, 4787, 3616,
+		2136, 2819, 6047, 6048, 6054, 1762, 3550, 1847, 4545, 6055, 6053, 6052, 6050, 6049, 1535,
+		4911, 6051, 6058, 6059, 6057, 7421, 6066, 6065, 3593, 6056, 6061, 6063, 6067, 6064, 6060,
+		6062, 6068, 6069, 6070, 6071, 6073, 6074, 4230, 6640, 1793, 5042, 4529, 1709, 2259, 5158,
+		3612, 2787, 2269, 4284, 1675, 5498, 2563, 4654, 3987, 1454, 4402, 5330, 2708, 1553, 3901,
+		1625, 3954, 2078, 3859, 4326, 6009, 4929, 6012, 6011, 3416, 4132, 3024, 6010, 5048, 1612,
+		5017, 1938, 1873, 4982, 4338, 1488, 6013, 6016, 1694, 6014, 6015, 3212, 3256, 6017, 2156,
+		2490, 6018, 3316, 6019, 6020, 6021, 1802, 2202, 3506, 3363, 1599, 4467, 6638, 2478, 4754,
+		4735, 2322, 6156, 5090, 3647, 1554, 1803, 6158, 4499, 3115, 6157, 2885, 4705, 1934, 6159,
+		1953, 3272, 2254, 2175, 3434, 2114, 6161, 6160, 2013, 5164, 4140, 6165, 6163, 3945, 2840,
+		4724, 6162, 6164, 3021, 2953, 6168, 6167, 2923, 3053, 6169, 6170, 6171, 5491, 4562, 4137,
+		2639, 5768, 5174, 2829, 5496, 4681, 3567, 3393, 5769, 1558, 5841, 3882, 5844, 2268, 4698,
+		2166, 2424, 1749, 1939, 4924, 3249, 4373, 2444, 6474, 6473, 6475, 4203, 3750, 1894, 3602,
+		6477, 7065, 6461, 2328, 1896, 3129, 6463, 6462, 6464, 4650, 6089, 4463, 4149, 4580, 1639,
+		1578, 1585, 3460, 4922, 4715, 6075, 6076, 4664, 1716, 1546, 4224, 4974, 6077, 2747, 1878,
+		6078, 2409, 6079, 2461, 3142, 6080, 4493, 4164, 6081, 1914, 3422, 6082, 6083, 4788, 6084,
+		4524, 6085, 4231, 6086, 1913, 6087, 2496, 4453, 6173, 1559, 4673, 6174, 2578, 3709, 6176,
+		7143, 7142, 5842, 6175, 5011, 4227, 3201, 6238, 5031, 6180, 6181, 4738, 6185, 2895, 6186,
+		6178, 1723, 3367, 6187, 4452, 2435, 6182, 2129, 2462, 4053, 3990, 6177, 6179, 6183, 6184,
+		6196, 6193, 4878, 6192, 3396, 4977, 3417, 1629, 6188, 6191, 3022, 3975, 1880, 6198, 2554,
+		6190, 4469, 4818, 6194, 2309, 6195, 3622, 6189, 5135, 7144, 6197, 7148, 6203, 3902, 3028,
+		2491, 2873, 2416, 3948, 7149, 7146, 7145, 2497, 7150, 4501, 7147, 2411, 2060, 6204, 1991,
+		4122, 2262, 4325, 3572, 2869, 2054, 6199, 6200, 6202, 6201, 3342, 6205, 4732, 6210, 3607,
+		4327, 6212, 2372, 6211, 2501, 6206, 6207, 6213, 6209, 4311, 4737, 2471, 4828, 3379, 7151,
+		4509, 6208, 3280, 6216, 1518, 6221, 2571, 6215, 1910, 3644, 6219, 2735, 4214, 2526, 4113,
+		6218, 6220, 4330, 6217, 4238, 2504, 1954, 2794, 1647, 1736, 1528, 6214, 1646, 1891, 2318,
+		2045, 4398, 6226, 2487, 3704, 4459, 6227, 1769, 7152, 4789, 4767, 7153, 6228, 4671, 6224,
+		4761, 2209, 6222, 6225, 2130, 6223, 2915, 6229, 4817, 1825, 6230, 2480, 3846, 3828, 3318,
+		7154, 3194, 2502, 2833, 4260, 6231, 2841, 1582, 4880, 6233, 7156, 6234, 2358, 7155, 6232,
+		6235, 2371, 1987, 4441, 1444, 7157, 7158, 2946, 6236, 3402, 6237, 5563, 7159, 2235, 4305,
+		6868, 4488, 3942, 3719, 4346, 1732, 4279, 2699, 6265, 2525, 6869, 4916, 3538, 6870, 6871,
+		6873, 6872, 6875, 6874, 2687, 6876, 3131, 1818, 1872, 2450, 7119, 7118, 2098, 4036, 1569,
+		7120, 3799, 7121, 7122, 3907, 5781, 1634, 4883, 3520, 1448, 1867, 3716, 4183, 2843, 2878,
+		3575, 5001, 2922, 5782, 3767, 4590, 3387, 1480, 1713, 3702, 1475, 2153, 3473, 2056, 4937,
+		1739, 2565, 1704, 2806, 1458, 4659, 3920, 5080, 4157, 1995, 2844, 4946, 2168, 3438, 5783,
+		2876, 3154, 3603, 2447, 1511, 3457, 3474, 4048, 1510, 1933, 3297, 5784, 4537, 1764, 3277,
+		2154, 5065, 1882, 1677, 3309, 3362, 2926, 5785, 1481, 3419, 3071, 2308, 2785, 4191, 1455,
+		4182, 5787, 2773, 5105, 5786, 4935, 1470, 3355, 3104, 2617, 4720, 2938, 3384, 1603, 4874,
+		2921, 3888, 5788, 4979, 2271, 3678, 3956, 5790, 2848, 3497, 5082, 3869, 3324, 1745, 2305,
+		5005, 6953, 1429, 2889, 4125, 4193, 5012, 3147, 4275, 4043, 4431, 3340, 1897, 5789, 4972,
+		2560, 2494, 1413, 3401, 1861, 4968, 6954, 5791, 4141, 4211, 4299, 5793, 4152, 2919, 5104,
+		5792, 1447, 2369, 3810, 3372, 2797, 1619, 2957, 4027, 2618, 2470, 1902, 3468, 5803, 5800,
+		2786, 5796, 1808, 2692, 5797, 3366, 4361, 1943, 2035, 3911, 1963, 5802, 4925, 5798, 4086,
+		3571, 3420, 4625, 2807, 3152, 4070, 1715, 2682, 2875, 4173, 4569, 1860, 5801, 5794, 5804,
+		6955, 3117, 5799, 5013, 1886, 1681, 5805, 5815, 5810, 5814, 3729, 5140, 5812, 3267, 4106,
+		1666, 4635, 5811, 5808, 4282, 1792, 2830, 2755, 2681, 5806, 4807, 5795, 5807, 2944, 5809,
+		1680, 2234, 3108, 2664, 5818, 5821, 1608, 1789, 1859, 3765, 5819, 3996, 2228, 5820, 5823,
+		5822, 4075, 1474, 1863, 6417, 4432, 1672, 6956, 5817, 3826, 5816, 1467, 4606, 1590, 4056,
+		5813, 3951, 4897, 5824, 1846, 3295, 5826, 3289, 3290, 5828, 3051, 5825, 2804, 3495, 1728,
+		3751, 3973, 5827, 5831, 5093, 1714, 3042, 3614, 1602, 1858, 5086, 3365, 5829, 5830, 5832,
+		2368, 5833, 2975, 3794, 1654, 3641, 5834, 3631, 5835, 6957, 3998, 5837, 5836, 1630, 3425,
+		5838, 4853, 3698, 5839, 2805, 5840, 4988, 6884, 6977, 3906, 5261, 2197, 2260, 2104, 4981,
+		2297, 4424, 7846, 1927, 3279, 2765, 6978, 1438, 2675, 3025, 1557, 1699, 2211, 3761, 2029,
+		6979, 2745, 3946, 3606, 4978, 2150, 4265, 4898, 1586, 7068, 1472, 7070, 1996, 3054, 7985,
+		4434, 4956, 4280, 2710, 1757, 2172, 4909, 7172, 2020, 3972, 4451, 2096, 7071, 3862, 3435,
+		7074, 7073, 3136, 7072, 4510, 7075, 7076, 5144, 7077, 7078, 3553, 4291, 2577, 3718, 1888,
+		2766, 5009, 4864, 4525, 4498, 6885, 6886, 6887, 2370, 3870, 2904, 4225, 6893, 1435, 6891,
+		2918, 6888, 1690, 3282, 2515, 4660, 4313, 6892, 6889, 6896, 4456, 4718, 1811, 3228, 5157,
+		4936, 3892, 6898, 6897, 6900, 6899, 5052, 4375, 6904, 2489, 2721, 3805, 6905, 3784, 4417,
+		6903, 4839, 6906, 6908, 4212, 6902, 6907, 4307, 2505, 1722, 3530, 2741, 4319, 3642, 2732,
+		6909, 5020, 3039, 4854, 6910, 4353, 6911, 3931, 3398, 1430, 6913, 6912, 7864, 3315, 1512,
+		6454, 6914, 3932, 6915, 6916, 3533, 6917, 6918, 4820, 3661, 4628, 2253, 6895, 1657, 3193,
+		4881, 4111, 5153, 4827, 4747, 6990, 3466, 2161, 7007, 3970, 7017, 2955, 4226, 1706, 3535,
+		7047, 3319, 4249, 3298, 1533, 4886, 3938, 5057, 3528, 2041, 2533, 4481, 3776, 4844, 3673,
+		2204, 6703, 3787, 6700, 2991, 4468, 1633, 1854, 6701, 4927, 2010, 6702, 3941, 2221, 4126,
+		2933, 4589, 6704, 6707, 2377, 1516, 2691, 6890, 6708, 6711, 6716, 6717, 3988, 1478, 2548,
+		2280, 6706, 4222, 6715, 4315, 4964, 3061, 6709, 3217, 2346, 4987, 6713, 3917, 4863, 6705,
+		6710, 6712, 3596, 2134, 6714, 2880, 6723, 6728, 6726, 2597, 2584, 6732, 6735, 6731, 1593,
+		1465, 3308, 2205, 3543, 3695, 3730, 6720, 6725, 6727, 2336, 6729, 5160, 3380, 6733, 1670,
+		6722, 2614, 2853, 6718, 5070, 3095, 1678, 6736, 3886, 6730, 4891, 1577, 4914, 6719, 6721,
+		1989, 6724, 6734, 2937, 3940, 3955, 3537, 2998, 6746, 4420, 6755, 5055, 6737, 6738, 4600,
+		2392, 2250, 2242, 4845, 6752, 6751, 6749, 2335, 4091, 6743, 4235, 2901, 1433, 6754, 6753,
+		5107, 6741, 4144, 3762, 2467, 2690, 6747, 6739, 6740, 6742, 1900, 6744, 3608, 6748, 6750,
+		2647, 5089, 6761, 6758, 4151, 6759, 3033, 6745, 3218, 1489, 6757, 6760, 2258, 3809, 3241,
+		4293, 2983, 4030, 4102, 4438, 3918, 6756, 2613, 6762, 3257, 3546, 2342, 1493, 5131, 2549,
+		3462, 4077, 6773, 3772, 6769, 2979, 2854, 6767, 2310, 6764, 6771, 4651, 6770, 4999, 5096,
+		6774, 2654, 6765, 6766, 6768, 4189, 4617, 6785, 6776, 1795, 1812, 6778, 4425, 6772, 1785,
+		6779, 2980, 6777, 6792, 6791, 6781, 6763, 6775, 2831, 6784, 6793, 3106, 6782, 2198, 6780,
+		4759, 6787, 6788, 6790, 6806, 2951, 6804, 6783, 6794, 1490, 6795, 4892, 6797, 6798, 6800,
+		3088, 3687, 6796, 6801, 6802, 6786, 6789, 2460, 6799, 2624, 6803, 6805, 6809, 6813, 1656,
+		6807, 2081, 6810, 6811, 4920, 3292, 6823, 2414, 6808, 4702, 6819, 6822, 6815, 6814, 3604,
+		6817, 6824, 1731, 6818, 4403, 6812, 1778, 6821, 6825, 4061, 4336, 6820, 6827, 6826, 6829,
+		6816, 6828, 6830, 3237, 3594, 1831, 2465, 4449, 7052, 3406, 4790, 7053, 7054, 3536, 2897,
+		7055, 7056, 4427, 3595, 2233, 7057, 5006, 4980, 1828, 1626, 4297, 3548, 4200, 1870, 3979,
+		2600, 6833, 6834, 4585, 6836, 1874, 6835, 4530, 3919, 1645, 6839, 6837, 6838, 5000, 6840,
+		6841, 6842, 6843, 7064, 3408, 2019, 4688, 1957, 2500, 7066, 4672, 4296, 3313, 3226, 2003,
+		5194, 1543, 1550, 1552, 3477, 1551, 3206, 4904, 6959, 2382, 4067, 6960, 6962, 6963, 6961,
+		6964, 6966, 6965, 6967, 3900, 5190, 3276, 3200, 3565, 6968, 3331, 6969, 6970, 6971, 2120,
+		2157, 6972, 3637, 6974, 2353, 4596, 1426, 6973, 6975, 1889, 3141, 3643, 6976, 3961, 6266,
+		4731, 5322, 4136, 4994, 3654, 5327, 2510, 2375, 6269, 4334, 6267, 3798, 2374, 4531, 6268,
+		3739, 2270, 2644, 1747, 4288, 4073, 6275, 6276, 4219, 4054, 2556, 6277, 6278, 4475, 3568,
+		2125, 3634, 4647, 4283, 6271, 6279, 3841, 1724, 6274, 3544, 6272, 7162, 6273, 3778, 3453,
+		2276, 3222, 6270, 3412, 3011, 3158, 1652, 6280, 2449, 3302, 6283, 2783, 6294, 6288, 2400,
+		2115, 4744, 5027, 4938, 2288, 4907, 4567, 4442, 3657, 3675, 1617, 3253, 6281, 6295, 6282,
+		2075, 6290, 6287, 2093, 3385, 6289, 3444, 1605, 3569, 3353, 5077, 2978, 6292, 6293, 6296,
+		4050, 6286, 4729, 1538, 7163, 6284, 6285, 6291, 4443, 3512, 4876, 6297, 2695, 6304, 6303,
+		4594, 6300, 6306, 3752, 4343, 6305, 6311, 3170, 1993, 2713, 6299, 2422, 6307, 6309, 2066,
+		5043, 6315, 6310, 6298, 2521, 4196, 3573, 3424, 3094, 6301, 3590, 2643, 2660, 6302, 5114,
+		1663, 6308, 2572, 6312, 2518, 6313, 3391, 6314, 4954, 2822, 6323, 6320, 6324, 6326, 3531,
+		2387, 2956, 2162, 6317, 4794, 2352, 2725, 6325, 4166, 3378, 4414, 3829, 4730, 4372, 6316,
+		6321, 6322, 4114, 4087, 2965, 6318, 3023, 6319, 4277, 2476, 1930, 3748, 2638, 4926, 3771,
+		2163, 6339, 6341, 4547, 4631, 2362, 2401, 6332, 1956, 5120, 6329, 4416, 6328, 3067, 4083,
+		3924, 3344, 4093, 6337, 6336, 6330, 6333, 1892, 4756, 6335, 4694, 1852, 2463, 3837, 1815,
+		2519, 4556, 4118, 7164, 3640, 4801, 6340, 5128, 6331, 2636, 6334, 4769, 6338, 3847, 6327,
+		4768, 3665, 2014, 4885, 1616, 6352, 4263, 6342, 4257, 2220, 6351, 2861, 4745, 3271, 3423,
+		6353, 4171, 6344, 6349, 6350, 2443, 4393, 4918, 6347, 6345, 6343, 4204, 3863, 2916, 2637,
+		6348, 2201, 6368, 4813, 6356, 3086, 6370, 4676, 6360, 6361, 4333, 4008, 6355, 6346, 6366,
+		3725, 6364, 3360, 6363, 6362, 1783, 6369, 1944, 5119, 6367, 2455, 5125, 4088, 7043, 6365,
+		2341, 5026, 6354, 6357, 3191, 6359, 3144, 2949, 3149, 1588, 4059, 1925, 6376, 3492, 3542,
+		6382, 3110, 2989, 4572, 6375, 3303, 6374, 6383, 6381, 3195, 6358, 6377, 3947, 4923, 6378,
+		4601, 6372, 6373, 6379, 4240, 3426, 3588, 3127, 6371, 3049, 4063, 1708, 6388, 6380, 6387,
+		6390, 6389, 1737, 1717, 6384, 6386, 6385, 3459, 2942, 4865, 6393, 1445, 6395, 6394, 2541,
+		6396, 6392, 6391, 1587, 6399, 6400, 6397, 6398, 6401, 3534, 6402, 6404, 6403, 6406, 6405,
+		2319, 6407, 6408, 2523, 7114, 3275, 1917, 2493, 3080, 4871, 2761, 5113, 4847, 1648, 7079,
+		6894, 3116, 1807, 4566, 1711, 3683, 2846, 7081, 5024, 7080, 7082, 7086, 2795, 4072, 3441,
+		2753, 7087, 1597, 7085, 4895, 1946, 7083, 3029, 1758, 7084, 3971, 2948, 4135, 3057, 7089,
+		2419, 2964, 5063, 4555, 2849, 2086, 3812, 7088, 2509, 4085, 2724, 3705, 4332, 4208, 3458,
+		2140, 4552, 2373, 7090, 7091, 2475, 7092, 7115, 1529, 2124, 2656, 7093, 4581, 7094, 3692,
+		7098, 7100, 2488, 2606, 7096, 3782, 3221, 7116, 4940, 7097, 5064, 7099, 7095, 7101, 7102,
+		3790, 4331, 4477, 4523, 3724, 7103, 4314, 3929, 7107, 7106, 1439, 7104, 7105, 7117, 3693,
+		3046, 7109, 4575, 7108, 4872, 7110, 5489, 7111, 1515, 7112, 7113, 5081, 3415, 6983, 1423,
+		2809, 2185, 4620, 1463, 1968, 5188, 3959, 6264, 3489, 1479, 3421, 6980, 6981, 6982, 4543,
+		3386, 6943, 3307, 3310, 2959, 6944, 3322, 4308, 6941, 6945, 3851, 3574, 4097, 4323, 6946,
+		6947, 4335, 2984, 6948, 2004, 6949, 6950, 6951, 6952, 6942, 3679, 6090, 2091, 6091, 6092,
+		5095, 6093, 6094, 4743, 6096, 2900, 6095, 1929, 1526, 6098, 6097, 2441, 6099, 2278, 2774,
+		3381, 2410, 2669, 6100, 6102, 2005, 4356, 3861, 6101, 4975, 4791, 6103, 6107, 6104, 6105,
+		2987, 6109, 6110, 2952, 6106, 6108, 6114, 3059, 6112, 6117, 1691, 6111, 3240, 1631, 6116,
+		6115, 6113, 6118, 6120, 4458, 5058, 3203, 6121, 4378, 6123, 2428, 6831, 6122, 6119, 2454,
+		4812, 6125, 6124, 6832, 6126, 6127, 6128, 4042, 6129, 6130, 4511, 3143, 4780, 4220, 6643,
+		6644, 2758, 3175, 6647, 6646, 4205, 3216, 6645, 2466, 4377, 3072, 6652, 6651, 6663, 1600,
+		6653, 6649, 6655, 6654, 3785, 4955, 6648, 2076, 6650, 6657, 6662, 5054, 6656, 6661, 6660,
+		1473, 6664, 3653, 2950, 2990, 3087, 6659, 6665, 4033, 6675, 6674, 5108, 6669, 6668, 6670,
+		6666, 6673, 6672, 6671, 3060, 3628, 3476, 3418, 3648, 6676, 6679, 6678, 6680, 2436, 6667,
+		6677, 3745, 3769, 6682, 2324, 4605, 6681, 6683, 6686, 6687, 3014, 6688, 6689, 6685, 6693,
+		6684, 6690, 6694, 6691, 6692, 6696, 6695, 2302, 7437, 7438, 3493, 1483, 3696, 4198, 4274,
+		6878, 6879, 3508, 1826, 6880, 4958, 5728, 6881, 6882, 6883, 2203, 5843, 3844, 4121, 3849,
+		3850, 4734, 3953, 2167, 7439, 1537, 6415, 4120, 4740, 2592, 3833, 1950, 3337, 1952, 7251,
+		2456, 6641, 7252, 1700, 7255, 7254, 2702, 7253, 4252, 3430, 3091, 5497, 7256, 4500, 3153,
+		3549, 2079, 7257, 1766, 2534, 7258, 6639, 7259, 2645, 7585, 3925, 4646, 7483, 7484, 7485,
+		3045, 2238, 2771, 7487, 3400, 7486, 4592, 7489, 1453, 2705, 4668, 7488, 1800, 2139, 7495,
+		7494, 3480, 7490, 7491, 1820, 7492, 4965, 4100, 2775, 2555, 7497, 7496, 7493, 1598, 4983,
+		4723, 2748, 3677, 7499, 4598, 5025, 2408, 7498, 2000, 4155, 3483, 3008, 7500, 7503, 7502,
+		7505, 7501, 2473, 7504, 4062, 7507, 1744, 1555, 7508, 7509, 7511, 1850, 7506, 7512, 7515,
+		7513, 7510, 7518, 7514, 7517, 7519, 7520, 4262, 7523, 7522, 3093, 7516, 3914, 1866, 1584,
+		4058, 7525, 7526, 7531, 4933, 7528, 3684, 7521, 7530, 7527, 7524, 7529, 1418, 7532, 7534,
+		7536, 7535, 7533, 4513, 7537, 7538, 2334, 1918, 1464, 1466, 4870, 1915, 2683, 2485, 7432,
+		2223, 7433, 1417, 7434, 4213, 7435, 7436, 3481, 5050, 7587, 7588, 3278, 4758, 5030, 3455,
+		4714, 4675, 7270, 1436, 4908, 4557, 2601, 2397, 2907, 2200, 1912, 3427, 3855, 3238, 7271,
+		3320, 1975, 7219, 3199, 4998, 4387, 7222, 3429, 2033, 3854, 7220, 7223, 7224, 3223, 2839,
+		7221, 7227, 7225, 4957, 3258, 7226, 7229, 4890, 4514, 7228, 3243, 7230, 2903, 2801, 7231,
+		4128, 4570, 5112, 4973, 7235, 7234, 7232, 7233, 7236, 2731, 3962, 7238, 2002, 7239, 3321,
+		7237, 2693, 1636, 2008, 7242, 5195, 7240, 7243, 3268, 1773, 7244, 7241, 4348, 7245, 3188,
+		7246, 7247, 5744, 3496, 3609, 5066, 1920, 3965, 7248, 4146, 7249, 4903, 7250, 8025, 5490,
+		1788, 3207, 7589, 3877, 4656, 4991, 7413, 2781, 2666, 7414, 2017, 1420, 3868, 7165, 7166,
+		4318, 2082, 2902, 7167, 3176, 3775, 7168, 3563, 2838, 7171, 3471, 4559, 5084, 7169, 7170,
+		4577, 7174, 7175, 7179, 7183, 7181, 7189, 4959, 7184, 7182, 7173, 3456, 3515, 3832, 4843,
+		7176, 7177, 7178, 7180, 3002, 1786, 2327, 7191, 7192, 7185, 7190, 4317, 3969, 7187, 7188,
+		4408, 7193, 3089, 4717, 7186, 3688, 2616, 3463, 7197, 1958, 3122, 1422, 4020, 1517, 7195,
+		4210, 1945, 7196, 7194, 7198, 1969, 7199, 7200, 7203, 1547, 3469, 2615, 7201, 4069, 1671,
+		7202, 3364, 1822, 1494, 7206, 2971, 1857, 3428, 7204, 2855, 7205, 3294, 7207, 7208, 7210,
+		3062, 2482, 2655, 7209, 7211, 7213, 7212, 7214, 3883, 7123, 2994, 3830, 7124, 3555, 7125,
+		3554, 7126, 7129, 5145, 7132, 7130, 7127, 7128, 5078, 3840, 4025, 7133, 7131, 4396, 7135,
+		3494, 2569, 7134, 1906, 2529, 7136, 5350, 2722, 3128, 7137, 7138, 2164, 7139, 7140, 7141,
+		4776, 5183, 2988, 3632, 2393, 4484, 3976, 4161, 2210, 1595, 3650, 5035, 2857, 3270, 7416,
+		3251, 5141, 7418, 1742, 3627, 4588, 5021, 7419, 7417, 2536, 1729, 2684, 4643, 2507, 4324,
+		7423, 7422, 7420, 1735, 3811, 3963, 7425, 1471, 5022, 7424, 1768, 8042, 4269, 7427, 7426,
+		1909, 2595, 2535, 2230, 3323, 7428, 4022, 7431, 4519, 2756, 3649, 7541, 7542, 2872, 1794,
+		7543, 4162, 3623, 4900, 7544, 7545, 3618, 4609, 5028, 7546, 2680, 1801, 2754, 1845, 4278,
+		2882, 7548, 2909, 7547, 7550, 7549, 7551, 7552, 3101, 3009, 3943, 4917, 2751, 2750, 4921,
+		2821, 4150, 7540, 2694, 2016, 5062, 7742, 7743, 2206, 7745, 7746, 1451, 7744, 7748, 4028,
+		7750, 4423, 1545, 7747, 7754, 1928, 7762, 7757, 7759, 7758, 2158, 1534, 7753, 7749, 1937,
+		7755, 7756, 7760, 7752, 2604, 3100, 7761, 7765, 7751, 1919, 2709, 7767, 2071, 2899, 5075,
+		4153, 1865, 1660, 7763, 7764, 3783, 7768, 7769, 7772, 7770, 7774, 7773, 7766, 2893, 1770,
+		7771, 3580, 2619, 7782, 2286, 7775, 1610, 2537, 4014, 7784, 7780, 2315, 7785, 7776, 7777,
+		7781, 3166, 7783, 7786, 7779, 2630, 4391, 7787, 7778, 7789, 5088, 3487, 7790, 7788, 3109,
+		2222, 7792, 7791, 1844, 7793, 7794, 7795, 2939, 2986, 3464, 7798, 7797, 1841, 7801, 7796,
+		7799, 7800, 7802, 7804, 2484, 7803, 7805, 1627, 7807, 7806, 2551, 3250, 5328, 2977, 7845,
+		5124, 2126, 7847, 3010, 3518, 1839, 4906, 7849, 7848, 7850, 4005, 7851, 4826, 5045, 2131,
+		3031, 3034, 7852, 2737, 1851, 7853, 7854, 2736, 7855, 7860, 7856, 7859, 2442, 7857, 7858,
+		2227, 4080, 7861, 1655, 3246, 4860, 2842, 7862, 3403, 7874, 4344, 4270, 4003, 4034, 2715,
+		5122, 2972, 4502, 7875, 7876, 7877, 8150, 2084, 7878, 5150, 7879, 6574, 2757, 6575, 2425,
+		6576, 4366, 6577, 4821, 2559, 6578, 6579, 2582, 3715, 4248, 6580, 1816, 6581, 3779, 2218,
+		3330, 5136, 3159, 2122, 5010, 4267, 2103, 3389, 6582, 4386, 6583, 6584, 6585, 3030, 5148,
+		3839, 4347, 4996, 5034, 6586, 1486, 6587, 6588, 3819, 4684, 2738, 6589, 1492, 3727, 2696,
+		6590, 3703, 6591, 2513, 2249, 4515, 6592, 3172, 2813, 2673, 4154, 6593, 6594, 2803, 4486,
+		4018, 4089, 2581, 6595, 2545, 4504, 6596, 4505, 6597, 6598, 1819, 6599, 6600, 3853, 4241,
+		3259, 6602, 1536, 1772, 6603, 6604, 5134, 4919, 6605, 3145, 5101, 6606, 6607, 6608, 2611,
+		3264, 2947, 6609, 6610, 2546, 6611, 6612, 6601, 2021, 6613, 6614, 6615, 2469, 1941, 3139,
+		1567, 6616, 4814, 6617, 2192, 6619, 6618, 2143, 6620, 1685, 6621, 6622, 6623, 6624, 6625,
+		6626, 6627, 4706, 4032, 6628, 6629, 6630, 6631, 3800, 6632, 6633, 6634, 6635, 6636, 2672,
+		6637, 7737, 2216, 3682, 7738, 7739, 7740, 2317, 4223, 5241, 2366, 3163, 7260, 2070, 7262,
+		7261, 1462, 7264, 4941, 5154, 5016, 7266, 3933, 7265, 7267, 7269, 7268, 4593, 3599, 3227,
+		2226, 3078, 7839, 4480, 7840, 4382, 3691, 7841, 7842, 7843, 7844, 5353, 2255, 6472, 4779,
+		7865, 4273, 1756, 7539, 4683, 7866, 4395, 7867, 3615, 1931, 1853, 7869, 7868, 7870, 7871,
+		7872, 2367, 1440, 7873, 2080, 4682, 4616, 2960, 2847, 6958, 4950, 6901, 7602, 2061, 3950,
+		3334, 7590, 7591, 2252, 7592, 2385, 4830, 1459, 7593, 7594, 7595, 7596, 7597, 7598, 7600,
+		7599, 3436, 7601, 2063, 7603, 4619, 5772, 3989, 1751, 1881, 2257, 3373, 7604, 7605, 3043,
+		3098, 4997, 7606, 7607, 3017, 3501, 2784, 7608, 1832, 7609, 7161, 7160, 4011, 4667, 3980,
+		4943, 3731, 2976, 2538, 6988, 4421, 5047, 2012, 2217, 6987, 2207, 6986, 1697, 2296, 4992,
+		2151, 2109, 2608, 2097, 6993, 6994, 1434, 2866, 6992, 4792, 6995, 6996, 2113, 6989, 6991,
+		3845, 5036, 4932, 4435, 7002, 4253, 7003, 1887, 1522, 7005, 4046, 3437, 7006, 7004, 3446,
+		7001, 3857, 7008, 1500, 2439, 5192, 7586, 6997, 6998, 6999, 7000, 7009, 6517, 7012, 2891,
+		4645, 7010, 7015, 2237, 7011, 2657, 4473, 1432, 7016, 3349, 4993, 1849, 3187, 2562, 7013,
+		7014, 4857, 3551, 3341, 7018, 3390, 5348, 1614, 7023, 2668, 7021, 7022, 2173, 4184, 7024,
+		7020, 3026, 3479, 4124, 7025, 2929, 4629, 7026, 2176, 2174, 7027, 3598, 4218, 7029, 7030,
+		7032, 4457, 7037, 7033, 7036, 3754, 4603, 7031, 7028, 2186, 4380, 3358, 7034, 7035, 4099,
+		4175, 1491, 7041, 7040, 1615, 2225, 7042, 1579, 4078, 3293, 4328, 7044, 7051, 3465, 7045,
+		3796, 6172, 7049, 4181, 7050, 1562, 4722, 4665, 7048, 7046, 1719, 6877, 5127, 7812, 1775,
+		5014, 5015, 4960, 2767, 7808, 4613, 7809, 7810, 7811, 2768, 4762, 3823, 3824, 7741, 3923,
+		4123, 6131, 3967, 4301, 5040, 7814, 7815, 7816, 7819, 2378, 7820, 1476, 7817, 7818, 2633,
+		1650, 7823, 7824, 2043, 1613, 4370, 7821, 1797, 7822, 7825, 7826, 4142, 7827, 7828, 7829,
+		3997, 7830, 7831, 7832, 7863, 3035, 2609, 3770, 4573, 6476, 5564, 4658, 5565, 1421, 5566,
+		2689, 5570, 5203, 5568, 4782, 3813, 5571, 5567, 5572, 3197, 5573, 5582, 5574, 4292, 4986,
+		5592, 5590, 5595, 2701, 3112, 5569, 5588, 5591, 5575, 2117, 1446, 5584, 4447, 5578, 2451,
+		2095, 5589, 5583, 5576, 3630, 4542, 5577, 5587, 5593, 4242, 5579, 5580, 5585, 5586, 1649,
+		5594, 4000, 4816, 5604, 5608, 4047, 5615, 3266, 5605, 2852, 5602, 1499, 2277, 5614, 5596,
+		5581, 5599, 3749, 2883, 3786, 1532, 4701, 5603, 5598, 3504, 5607, 5109, 3210, 2089, 3620,
+		3204, 5611, 5601, 5620, 5597, 5606, 2730, 5600, 5609, 5612, 5613, 5633, 5610, 5641, 5616,
+		2612, 1821, 3198, 1668, 5635, 5627, 5624, 5637, 5623, 4685, 1669, 3720, 3732, 5636, 5622,
+		5632, 5630, 2728, 5629, 1658, 5628, 2623, 5617, 2479, 2996, 2589, 5618, 5619, 5626, 5631,
+		5634, 1899, 3722, 2514, 5639, 5638, 4710, 5640, 5642, 5644, 4686, 5643, 5645, 5646, 4614,
+		2390, 5648, 5661, 5656, 5659, 5655, 3523, 2995, 3774, 5621, 5653, 5662, 5625, 5654, 5663,
+		5651, 5664, 5658, 5652, 3299, 5647, 2932, 3018, 5649, 5650, 5657, 2524, 5660, 4707, 5665,
+		5666, 3202, 5689, 5667, 5688, 2284, 2778, 2815, 2391, 5681, 5676, 5670, 1640, 5674, 5682,
+		1601, 5692, 5669, 3525, 5687, 5691, 3073, 2105, 5685, 5686, 5675, 5668, 5684, 4090, 5680,
+		5673, 3235, 3505, 4243, 5683, 5679, 5672, 5677, 3161, 4708, 4709, 5690, 4407, 3753, 5709,
+		5678, 5703, 3169, 5704, 5694, 5069, 5696, 5695, 2241, 5693, 3524, 1986, 5705, 2438, 4858,
+		5710, 1833, 5697, 2910, 5706, 5702, 5700, 1936, 5698, 5699, 5701, 2646, 5707, 5708, 5724,
+		3236, 4013, 5721, 5234, 3526, 5723, 4971, 5722, 5719, 5715, 5718, 5711, 4495, 3721, 5717,
+		5712, 5713, 4029, 5716, 1548, 2935, 2564, 5720, 5726, 5725, 5714, 3461, 5730, 5737, 5736,
+		5727, 3274, 3192, 4952, 4250, 5732, 1641, 3361, 3916, 3601, 5729, 5731, 5733, 5735, 1419,
+		1549, 5745, 5739, 2653, 3744, 5734, 5738, 5742, 5741, 5740, 5746, 4837, 5752, 5743, 5747,
+		2970, 1504, 5755, 5750, 5751, 4517, 5754, 5748, 5749, 4446, 5753, 3930, 5758, 5757, 5756,
+		5760, 2700, 1653, 3269, 5759, 3409, 5761, 4098, 2077, 4862, 5762, 5764, 3291, 5766, 5763,
+		5765, 4913, 5767, 7627, 2445, 3119, 3399, 3140, 7628, 4490, 4760, 6984, 1761, 7629, 7630,
+		3866, 2420, 7632, 7634, 7633, 4015, 4349, 7631, 3873, 4652, 3177, 4264, 7637, 1495, 7636,
+		7643, 1644, 4544, 7639, 7641, 4866, 7640, 7635, 7644, 7642, 7638, 7652, 7649, 7651, 7655,
+		7647, 7645, 7650, 5072, 7646, 3660, 3822, 7653, 2293, 1895, 7648, 7654, 7661, 7667, 2498,
+		7666, 4195, 5056, 7663, 7665, 2243, 7656, 7659, 3190, 4948, 7657, 7658, 7660, 7664, 7670,
+		4727, 2047, 3934, 2135, 7668, 7669, 7671, 7674, 7672, 7673, 4560, 7662, 4176, 4276, 4990,
+		7680, 3254, 7677, 2928, 7689, 7675, 7678, 7685, 7679, 7684, 7683, 7686, 7676, 7681, 7687,
+		4711, 7682, 1682, 7695, 4428, 7698, 2483, 7702, 7693, 7699, 7701, 7703, 7696, 7694, 2440,
+		1970, 7692, 7700, 7690, 7691, 7688, 7713, 7710, 7709, 7697, 3723, 7704, 7712, 3283, 7706,
+		7714, 7716, 7711, 7705, 7718, 7717, 3162, 7721, 7723, 7708, 7724, 7719, 7722, 7707, 7720,
+		7725, 7727, 7715, 7726, 7728, 4440, 7731, 5352, 7732, 7730, 3733, 7729, 7733, 7734, 1817,
+		7272, 7735, 7736, 4521, 7813, 4455, 4465, 4571, 4369, 2685, 4546, 2415, 6088, 4639, 7553,
+		1622, 1580, 7554, 3791, 1727, 5345, 3952, 7555, 5033, 7556, 7833, 7557, 4805, 7558, 1441,
+		7834, 7835, 1877, 3442, 4068, 4485, 4199, 7559, 5346, 1530, 4337, 2165, 7561, 7562, 1632,
+		3056, 5091, 7560, 7563, 7565, 5349, 4670, 4796, 7836, 3690, 7837, 7564, 7566, 2886, 7567,
+		7571, 7573, 7568, 3808, 3448, 3168, 2347, 7570, 7572, 2306, 7578, 2403, 1502, 7576, 7575,
+		7569, 7577, 7574, 3741, 4177, 7580, 6420, 7579, 7581, 7582, 4392, 7838, 2714, 7583, 7584,
+		4316, 4615, 7610, 2179, 2628, 2314, 2325, 3252, 3904, 6933, 2945, 2810, 6934, 6935, 6936,
+		6937, 6938, 6939, 6940, 2670, 7986, 7988, 7989, 7987, 2697, 7990, 1790, 7991, 7992, 7067,
+		4563, 5337, 7993, 7263, 4905, 4793, 4101, 3889, 6423, 7994, 2740, 3486, 5362, 2575, 1982,
+		2189, 3712, 2542, 5363, 5364, 4095, 3700, 5365, 3570, 4532, 4678, 4533, 2576, 2649, 2511,
+		5366, 5367, 4551, 5368, 4494, 2052, 3160, 3994, 2144, 3831, 2102, 2812, 4985, 5369, 5370,
+		3509, 5146, 3875, 4896, 4010, 4966, 5371, 5044, 1827, 5373, 5372, 4680, 5374, 5375, 5376,
+		3905, 5377, 3864, 5378, 5379, 1738, 5060, 5380, 2459, 1893, 5381, 5382, 2333, 4526, 4677,
+		5383, 2196, 4397, 1676, 5384, 5385, 2706, 4289, 4778, 5386, 4312, 5387, 4752, 2512, 5388,
+		3968, 3995, 5389, 3646, 5059, 5390, 3404, 2006, 5391, 2111, 2865, 5392, 5393, 3960, 5394,
+		1966, 5395, 3041, 5102, 5396, 4065, 4679, 3306, 5397, 1972, 2492, 5398, 4436, 5399, 5400,
+		4258, 5401, 5402, 5403, 1684, 5406, 5404, 4583, 5405, 3248, 5407, 5408, 5409, 5410, 4444,
+		4610, 1498, 5411, 3582, 5412, 2718, 3196, 5413, 5414, 3288, 4064, 5415, 5416, 2943, 3532,
+		5417, 5418, 3591, 5419, 5420, 2295, 2520, 1998, 7889, 7890, 4202, 7920, 7019, 4406, 2474,
+		2381, 4798, 6072, 7979, 1513, 1679, 7980, 7982, 2398, 7981, 3213, 7984, 7983, 1523, 4961,
+		2187, 2272, 1635, 4873, 4368, 1469, 4930, 2528, 5023, 2090, 4057, 3499, 1568, 2281, 5073,
+		2320, 2068, 2627, 6919, 6920, 4130, 2338, 6921, 1876, 3214, 2116, 2405, 6922, 4877, 6923,
+		2591, 2506, 6924, 3069, 3125, 4856, 5117, 6925, 6926, 6929, 6927, 6928, 3821, 2180, 2009,
+		6930, 3927, 3804, 1830, 6166, 3449, 6931, 2934, 5099, 6932, 5087, 3757, 5220, 5214, 4855,
+		4882, 3795, 4713, 2212, 1755, 3825, 7887, 2402, 7888, 5138, 7882, 2177, 4939, 2208, 3557,
+		1726, 7883, 1703, 4822, 3658, 7885, 7884, 4084, 3669, 7886, 5142, 3414, 7927, 7922, 7930,
+		7929, 5007, 7928, 4823, 7931, 7939, 1456, 1967, 7936, 7937, 3443, 7932, 7933, 7934, 7938,
+		2789, 7935, 2251, 7943, 7946, 2890, 2337, 7923, 7940, 3124, 4129, 2626, 7941, 7942, 7944,
+		2042, 7945, 7948, 7924, 7947, 4726, 1767, 4045, 7949, 7950, 2790, 7951, 4104, 7954, 1637,
+		5132, 7952, 7953, 7955, 7961, 7959, 7958, 7956, 7960, 7957, 7963, 7964, 4108, 6422, 1903,
+		7962, 7967, 4044, 7965, 7966, 7925, 1539, 7926, 1916, 1665, 7971, 7968, 2028, 7972, 7969,
+		7970, 1843, 4867, 7973, 1780, 7974, 7976, 7975, 7977, 7978, 3836, 2265, 3662, 2040, 4082,
+		6867, 1712, 4887, 2331, 4506, 6844, 5085, 6845, 3155, 3742, 2417, 6846, 6847, 6848, 5046,
+		6849, 6850, 6852, 6851, 6853, 6854, 3636, 6855, 4849, 6856, 2677, 6857, 6858, 2678, 6859,
+		2169, 3037, 6860, 1521, 2495, 2340, 6861, 6862, 6863, 6864, 2155, 2550, 3921, 5967, 4808,
+		4352, 4910, 6865, 4949, 6866, 4450, 2283, 1824, 1560, 2930, 1573, 1574, 1575, 1720, 3737,
+		6424, 1566, 3048, 1864, 3579, 4755, 3566, 4535, 2348, 3186, 4712, 4836, 2723, 6425, 2088,
+		6426, 2468, 4953, 2719, 4815, 4234, 3019, 1748, 4127, 6429, 6427, 6431, 6433, 6430, 1926,
+		3517, 1971, 6428, 3939, 6432, 3247, 1540, 2540, 5098, 4178, 3992, 3895, 4092, 6435, 6434,
+		3359, 4512, 4534, 6436, 6439, 4159, 5061, 6438, 1940, 4165, 6440, 1999, 4143, 2323, 3890,
+		1740, 4004, 4869, 6441, 2141, 6437, 6444, 1879, 6445, 6442, 6443, 4666, 3164, 1541, 4765,
+		2034, 4023, 6446, 4785, 1576, 2057, 6449, 6447, 6448, 1911, 4642, 6451, 6453, 6452, 3589,
+		4608, 6450, 4859, 4945, 6455, 5156, 6456, 1563, 4602, 6457, 6459, 6458, 6460, 4661, 1922,
+		6642, 5442, 5445, 5443, 5444, 5447, 4464, 3328, 1487, 4433, 5446, 4741, 2359, 5453, 3652,
+		5449, 5448, 3818, 5450, 5452, 5137, 5451, 3065, 5456, 4783, 5458, 5455, 5459, 2659, 2954,
+		5454, 5457, 4984, 5460, 5464, 5465, 5463, 2383, 2823, 5462, 5461, 4832, 1628, 5466, 2344,
+		5467, 1718, 1885, 2001, 5468, 2058, 5469, 1544, 5471, 5470, 5472, 5474, 5473, 5475, 5476,
+		5477, 4746, 7891, 3656, 5110, 3451, 7893, 7894, 7892, 2763, 4496, 2118, 4838, 4051, 7897,
+		7896, 2357, 7895, 4001, 7899, 2963, 1765, 4145, 7900, 7898, 2651, 7903, 7904, 2676, 3219,
+		2884, 4012, 7905, 7901, 7902, 3369, 7907, 1813, 5152, 1840, 7906, 7909, 7908, 7910, 4466,
+		3244, 3674, 7911, 7912, 7913, 7914, 7915, 7916, 7918, 7917, 7919, 1638, 4751, 3898, 2992,
+		5037, 4621, 3038, 2711, 2171, 2625, 8027, 8028, 8029, 8030, 8031, 8033, 8032, 8156, 8034,
+		8035, 7273, 7274, 7275, 4962, 1977, 7277, 7276, 7278, 7279, 3576, 7280, 7281, 2083, 1965,
+		7282, 7284, 7283, 2199, 7285, 7286, 7287, 2032, 1705, 5032, 3327, 1524, 2215, 7288, 7289,
+		4824, 3624, 2816, 4286, 2274, 7291, 7290, 7293, 7292, 3388, 7294, 7295, 3585, 7296, 3586,
+		7297, 1604, 7298, 7299, 7300, 7301, 7302, 5149, 7303, 7304, 2593, 7305, 4742, 4131, 1609,
+		3076, 7306, 3577, 3208, 7307, 7308, 7309, 7310, 7311, 7312, 7313, 7314, 7315, 7316, 7317,
+		7318, 7319, 7321, 7320, 7322, 4148, 3134, 7323, 7324, 7325, 4888, 7326, 4342, 7327, 7328,
+		7329, 7330, 7332, 7331, 7333, 2246, 3285, 7334, 7335, 2665, 4636, 1686, 7336, 7337, 7338,
+		4693, 7339, 5074, 7340, 3521, 7341, 7342, 3027, 7343, 4413, 4035, 7345, 7344, 1781, 2343,
+		7346, 7347, 4483, 7348, 7349, 2137, 4448, 7350, 7351, 7352, 3746, 4105, 7353, 7354, 7355,
+		7356, 7357, 4951, 7358, 1862, 3205, 7359, 7360, 7361, 7362, 4322, 7363, 3165, 1809, 5097,
+		2716, 4362, 7366, 7364, 7365, 7367, 1980, 2629, 2791, 3239, 7368, 7369, 7370, 7378, 7371,
+		7372, 7373, 3605, 7412, 2018, 7374, 7375, 7376, 2011, 3225, 7377, 7379, 7380, 7381, 4969,
+		7382, 3376, 7383, 3377, 7384, 7385, 2229, 1496, 7386, 7387, 7388, 7389, 7390, 7391, 7392,
+		7393, 2746, 7396, 7394, 7395, 7397, 7398, 3052, 7399, 7400, 7401, 7402, 7403, 7404, 7405,
+		7406, 7407, 2969, 7408, 3020, 7409, 7410, 7411, 4389, 1695, 3232, 6240, 3792, 6241, 1556,
+		4271, 1804, 3747, 6242, 4371, 6243, 2605, 6244, 6245, 3233, 4889, 3343, 2330, 4266, 6246,
+		3281, 6247, 2074, 2244, 2399, 6248, 6249, 4829, 6250, 6251, 4554, 6252, 6253, 6254, 6255,
+		4565, 6256, 1688, 2940, 6257, 2924, 6258, 6259, 6260, 6261, 6262, 2184, 5423, 2024, 5425,
+		5424, 5427, 3743, 5426, 2099, 4595, 4690, 4970, 2686, 5147, 5429, 5428, 1411, 4186, 5430,
+		2190, 2579, 3130, 3105, 1725, 5431, 3111, 3305, 2652, 4385, 5432, 3793, 1564, 5433, 1997,
+		4819, 1784, 5434, 4834, 4376, 3450, 5435, 5436, 5437, 3081, 4094, 4384, 4770, 3102, 5438,
+		4016, 5439, 4017, 4699, 2245, 5440, 1424, 4345, 4934, 4024, 5441, 6239, 3012, 8020, 8021,
+		8022, 3338, 3689, 4578, 4476, 4548, 2552, 2300, 7415, 1823, 4725, 8023, 1782, 8024, 1960,
+		8026, 4773, 7996, 4520, 7998, 7997, 3074, 2968, 1505, 4306, 4489, 8000, 4409, 7999, 4967,
+		8001, 3220, 2527, 8003, 8002, 3351, 3063, 3957, 4351, 8004, 8005, 8006, 3123, 1461, 3472,
+		8007, 3635, 7995, 2749, 2743, 1948, 2106, 2850, 3245, 3265, 5213, 2240, 2720, 4516, 1457,
+		8099, 8100, 4429, 1425, 8101, 8102, 8103, 3613, 2772, 8106, 8105, 1565, 8104, 8107, 8108,
+		4233, 3710, 2360, 6697, 6698, 6699, 2759, 4689, 4840, 3815, 4624, 1978, 3645, 7611, 4401,
+		3966, 4492, 6658, 4206, 2298, 2030, 7612, 1477, 3991, 7613, 4797, 3114, 3083, 3513, 2742,
+		7614, 2590, 7615, 7616, 7617, 4640, 3498, 4174, 7618, 4716, 2856, 4107, 7619, 7620, 4564,
+		2051, 7621, 7622, 1942, 7623, 7624, 1689, 7625, 7626, 3672, 2138, 7058, 7059, 7060, 7061,
+		3491, 7062, 7063, 2108, 3872, 6133, 8130, 8131, 1642, 8132, 8134, 8133, 6136, 2539, 6137,
+		6138, 6139, 6140, 6141, 6142, 2092, 4696, 2635, 3899, 1508, 3986, 6143, 2065, 3701, 6144,
+		2671, 1596, 6145, 2059, 6146, 3346, 6147, 4381, 2316, 6148, 2914, 6149, 1683, 6150, 3090,
+		6151, 6152, 3189, 6153, 6154, 6155, 3908, 5193, 5332, 4390, 7430, 5561, 3178, 4799, 4187,
+		4528, 1750, 3664, 1618, 3132, 6549, 3880, 6550, 6551, 2777, 6552, 5079, 4188, 6554, 2598,
+		6553, 6555, 6556, 3179, 2661, 6557, 3171, 2356, 6558, 6559, 1741, 4584, 2824, 6560, 3556,
+		6561, 6562, 6563, 3490, 6564, 3766, 6565, 6566, 6567, 6416, 6568, 6569, 3167, 6570, 6571,
+		5053, 6572, 6573, 2294, 8110, 8109, 8113, 8111, 2350, 8114, 8115, 8117, 8116, 8119, 8118,
+		8120, 8121, 8122, 4019, 2224, 8135, 8136, 8137, 8139, 8141, 8138, 8142, 8140, 5130, 8143,
+		8144, 8145, 8146, 8147, 5494, 5179, 6478, 2332, 2912, 2517, 8124, 3516, 8123, 8125, 8127,
+		8126, 8128, 4255, 8129, 3296, 4766, 8036, 3120, 8037, 8038, 8039, 8040, 8041, 8043, 1514,
+		8044, 8045, 8046, 8047, 8048, 8049, 8050, 8051, 4365, 8052, 8053, 8054, 8055, 8056, 8057,
+		2993, 8058, 8059, 8060, 8061, 8062, 8063, 8064, 8065, 8066, 8067, 8068, 8069, 8070, 8071,
+		8072, 8073, 2733, 8074, 8075, 8076, 8077, 3755, 8078, 8079, 8080, 8081, 8082, 8083, 8084,
+		8085, 8086, 8087, 8088, 8089, 8090, 8091, 1581, 8092, 8093, 8094, 8095, 8096, 3066, 8097,
+		8098, 3370, 7440, 2543, 7441, 3284, 3407, 4538, 7442, 7443, 7444, 7445, 7446, 4539, 4587,
+		7448, 7447, 4800, 4185, 7449, 7451, 7450, 7452, 7453, 2236, 7454, 2421, 7455, 7456, 2798,
+		7457, 2049, 7458, 7459, 7460, 7461, 3686, 7462, 7463, 7464, 3467, 7465, 7466, 7467, 8112,
+		7468, 7469, 7470, 7471, 7472, 2404, 7473, 7474, 7475, 7476, 7477, 7478, 7479, 7481, 4704,
+		7480, 7482, 7921, 3126, 8151, 8152, 8153, 8154, 8155, 3121, 8157, 8158, 3184, 7881, 7880,
+		3174, 8148, 8149, 2481, 5331, 3935, 2985, 7429, 2407, 3584, 3301, 8159, 8160, 8161, 8163,
+		8162, 8164, 8167, 8166, 8165, 8168, 8169, 7216, 7217, 7218, 8017, 8018, 8019, 1979, 5199,
+		2291, 5196, 5562, 3936, 8170, 8171, 8172, 8174, 8173, 1542, 8175, 8176, 8177, 3552, 7069,
+		1752, 8008, 8009, 3075, 8010, 8011, 8012, 8013, 8014, 8015, 3668, 8016, 3097, 2263, 7215,
+		2329, 5326, 188, 189, 190, 70, 192, 193, 194, 195, 196, 197, 198, 199, 200, 201, 202, 203,
+		204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219, 220, 221,
+		222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239,
+		240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257,
+		258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275,
+		276, 277, 278, 279, 280, 10, 72, 73, 281, 191,
+	],
+};
+
+/// The two-byte codes of GB18030-2005, shared with GBK, indexed by `(lead - 0x81) * 190` plus the
+/// offset of the trail byte within `0x40..=0x7E` and `0x80..=0xFE`.
+pub(crate) static GB18030: Index = Index {
+	code_points: &[
+		0x4E02, 0x4E04, 0x4E05, 0x4E06, 0x4E0F, 0x4E12, 0x4E17, 0x4E1F, 0x4E20, 0x4E21, 0x4E23,
+		0x4E26, 0x4E29, 0x4E2E, 0x4E2F, 0x4E31, 0x4E33, 0x4E35, 0x4E37, 0x4E3C, 0x4E40, 0x4E41,
+		0x4E42, 0x4E44, 0x4E46, 0x4E4A, 0x4E51, 0x4E55, 0x4E57, 0x4E5A, 0x4E5B, 0x4E62, 0x4E63,
+		0x4E64, 0x4E65, 0x4E67, 0x4E68, 0x4E6A, 0x4E6B, 0x4E6C, 0x4E6D, 0x4E6E, 0x4E6F, 0x4E72,
+		0x4E74, 0x4E75, 0x4E76, 0x4E77, 0x4E78, 0x4E79, 0x4E7A, 0x4E7B, 0x4E7C, 0x4E7D, 0x4E7F,
+		0x4E80, 0x4E81, 0x4E82, 0x4E83, 0x4E84, 0x4E85, 0x4E87, 0x4E8A, 0x4E90, 0x4E96, 0x4E97,
+		0x4E99, 0x4E9C, 0x4E9D, 0x4E9E, 0x4EA3, 0x4EAA, 0x4EAF, 0x4EB0, 0x4EB1, 0x4EB4, 0x4EB6,
+		0x4EB7, 0x4EB8, 0x4EB9, 0x4EBC, 0x4EBD, 0x4EBE, 0x4EC8, 0x4ECC, 0x4ECF, 0x4ED0, 0x4ED2,
+		0x4EDA, 0x4EDB, 0x4EDC, 0x4EE0, 0x4EE2, 0x4EE6, 0x4EE7, 0x4EE9, 0x4EED, 0x4EEE, 0x4EEF,
+		0x4EF1, 0x4EF4, 0x4EF8, 0x4EF9, 0x4EFA, 0x4EFC, 0x4EFE, 0x4F00, 0x4F02, 0x4F03, 0x4F04,
+		0x4F05, 0x4F06, 0x4F07, 0x4F08, 0x4F0B, 0x4F0C, 0x4F12, 0x4F13, 0x4F14, 0x4F15, 0x4F16,
+		0x4F1C, 0x4F1D, 0x4F21, 0x4F23, 0x4F28, 0x4F29, 0x4F2C, 0x4F2D, 0x4F2E, 0x4F31, 0x4F33,
+		0x4F35, 0x4F37, 0x4F39, 0x4F3B, 0x4F3E, 0x4F3F, 0x4F40, 0x4F41, 0x4F42, 0x4F44, 0x4F45,
+		0x4F47, 0x4F48, 0x4F49, 0x4F4A, 0x4F4B, 0x4F4C, 0x4F52, 0x4F54, 0x4F56, 0x4F61, 0x4F62,
+		0x4F66, 0x4F68, 0x4F6A, 0x4F6B, 0x4F6D, 0x4F6E, 0x4F71, 0x4F72, 0x4F75, 0x4F77, 0x4F78,
+		0x4F79, 0x4F7A, 0x4F7D, 0x4F80, 0x4F81, 0x4F82, 0x4F85, 0x4F86, 0x4F87, 0x4F8A, 0x4F8C,
+		0x4F8E, 0x4F90, 0x4F92, 0x4F93, 0x4F95, 0x4F96, 0x4F98, 0x4F99, 0x4F9A, 0x4F9C, 0x4F9E,
+		0x4F9F, 0x4FA1, 0x4FA2, 0x4FA4, 0x4FAB, 0x4FAD, 0x4FB0, 0x4FB1, 0x4FB2, 0x4FB3, 0x4FB4,
+		0x4FB6, 0x4FB7, 0x4FB8, 0x4FB9, 0x4FBA, 0x4FBB, 0x4FBC, 0x4FBD, 0x4FBE, 0x4FC0, 0x4FC1,
+		0x4FC2, 0x4FC6, 0x4FC7, 0x4FC8, 0x4FC9, 0x4FCB, 0x4FCC, 0x4FCD, 0x4FD2, 0x4FD3, 0x4FD4,
+		0x4FD5, 0x4FD6, 0x4FD9, 0x4FDB, 0x4FE0, 0x4FE2, 0x4FE4, 0x4FE5, 0x4FE7, 0x4FEB, 0x4FEC,
+		0x4FF0, 0x4FF2, 0x4FF4, 0x4FF5, 0x4FF6, 0x4FF7, 0x4FF9, 0x4FFB, 0x4FFC, 0x4FFD, 0x4FFF,
+		0x5000, 0x5001, 0x5002, 0x5003, 0x5004, 0x5005, 0x5006, 0x5007, 0x5008, 0x5009, 0x500A,
+		0x500B, 0x500E, 0x5010, 0x5011, 0x5013, 0x5015, 0x5016, 0x5017, 0x501B, 0x501D, 0x501E,
+		0x5020, 0x5022, 0x5023, 0x5024, 0x5027, 0x502B, 0x502F, 0x5030, 0x5031, 0x5032, 0x5033,
+		0x5034, 0x5035, 0x5036, 0x5037, 0x5038, 0x5039, 0x503B, 0x503D, 0x503F, 0x5040, 0x5041,
+		0x5042, 0x5044, 0x5045, 0x5046, 0x5049, 0x504A, 0x504B, 0x504D, 0x5050, 0x5051, 0x5052,
+		0x5053, 0x5054, 0x5056, 0x5057, 0x5058, 0x5059, 0x505B, 0x505D, 0x505E, 0x505F, 0x5060,
+		0x5061, 0x5062, 0x5063, 0x5064, 0x5066, 0x5067, 0x5068, 0x5069, 0x506A, 0x506B, 0x506D,
+		0x506E, 0x506F, 0x5070, 0x5071, 0x5072, 0x5073, 0x5074, 0x5075, 0x5078, 0x5079, 0x507A,
+		0x507C, 0x507D, 0x5081, 0x5082, 0x5083, 0x5084, 0x5086, 0x5087, 0x5089, 0x508A, 0x508B,
+		0x508C, 0x508E, 0x508F, 0x5090, 0x5091, 0x5092, 0x5093, 0x5094, 0x5095, 0x5096, 0x5097,
+		0x5098, 0x5099, 0x509A, 0x509B, 0x509C, 0x509D, 0x509E, 0x509F, 0x50A0, 0x50A1, 0x50A2,
+		0x50A4, 0x50A6, 0x50AA, 0x50AB, 0x50AD, 0x50AE, 0x50AF, 0x50B0, 0x50B1, 0x50B3, 0x50B4,
+		0x50B5, 0x50B6, 0x50B7, 0x50B8, 0x50B9, 0x50BC, 0x50BD, 0x50BE, 0x50BF, 0x50C0, 0x50C1,
+		0x50C2, 0x50C3, 0x50C4, 0x50C5, 0x50C6, 0x50C7, 0x50C8, 0x50C9, 0x50CA, 0x50CB, 0x50CC,
+		0x50CD, 0x50CE, 0x50D0, 0x50D1, 0x50D2, 0x50D3, 0x50D4, 0x50D5, 0x50D7, 0x50D8, 0x50D9,
+		0x50DB, 0x50DC, 0x50DD, 0x50DE, 0x50DF, 0x50E0, 0x50E1, 0x50E2, 0x50E3, 0x50E4, 0x50E5,
+		0x50E8, 0x50E9, 0x50EA, 0x50EB, 0x50EF, 0x50F0, 0x50F1, 0x50F2, 0x50F4, 0x50F6, 0x50F7,
+		0x50F8, 0x50F9, 0x50FA, 0x50FC, 0x50FD, 0x50FE, 0x50FF, 0x5100, 0x5101, 0x5102, 0x5103,
+		0x5104, 0x5105, 0x5108, 0x5109, 0x510A, 0x510C, 0x510D, 0x510E, 0x510F, 0x5110, 0x5111,
+		0x5113, 0x5114, 0x5115, 0x5116, 0x5117, 0x5118, 0x5119, 0x511A, 0x511B, 0x511C, 0x511D,
+		0x511E, 0x511F, 0x5120, 0x5122, 0x5123, 0x5124, 0x5125, 0x5126, 0x5127, 0x5128, 0x5129,
+		0x512A, 0x512B, 0x512C, 0x512D, 0x512E, 0x512F, 0x5130, 0x5131, 0x5132, 0x5133, 0x5134,
+		0x5135, 0x5136, 0x5137, 0x5138, 0x5139, 0x513A, 0x513B, 0x513C, 0x513D, 0x513E, 0x5142,
+		0x5147, 0x514A, 0x514C, 0x514E, 0x514F, 0x5150, 0x5152, 0x5153, 0x5157, 0x5158, 0x5159,
+		0x515B, 0x515D, 0x515E, 0x515F, 0x5160, 0x5161, 0x5163, 0x5164, 0x5166, 0x5167, 0x5169,
+		0x516A, 0x516F, 0x5172, 0x517A, 0x517E, 0x517F, 0x5183, 0x5184, 0x5186, 0x5187, 0x518A,
+		0x518B, 0x518E, 0x518F, 0x5190, 0x5191, 0x5193, 0x5194, 0x5198, 0x519A, 0x519D, 0x519E,
+		0x519F, 0x51A1, 0x51A3, 0x51A6, 0x51A7, 0x51A8, 0x51A9, 0x51AA, 0x51AD, 0x51AE, 0x51B4,
+		0x51B8, 0x51B9, 0x51BA, 0x51BE, 0x51BF, 0x51C1, 0x51C2, 0x51C3, 0x51C5, 0x51C8, 0x51CA,
+		0x51CD, 0x51CE, 0x51D0, 0x51D2, 0x51D3, 0x51D4, 0x51D5, 0x51D6, 0x51D7, 0x51D8, 0x51D9,
+		0x51DA, 0x51DC, 0x51DE, 0x51DF, 0x51E2, 0x51E3, 0x51E5, 0x51E6, 0x51E7, 0x51E8, 0x51E9,
+		0x51EA, 0x51EC, 0x51EE, 0x51F1, 0x51F2, 0x51F4, 0x51F7, 0x51FE, 0x5204, 0x5205, 0x5209,
+		0x520B, 0x520C, 0x520F, 0x5210, 0x5213, 0x5214, 0x5215, 0x521C, 0x521E, 0x521F, 0x5221,
+		0x5222, 0x5223, 0x5225, 0x5226, 0x5227, 0x522A, 0x522C, 0x522F, 0x5231, 0x5232, 0x5234,
+		0x5235, 0x523C, 0x523E, 0x5244, 0x5245, 0x5246, 0x5247, 0x5248, 0x5249, 0x524B, 0x524E,
+		0x524F, 0x5252, 0x5253, 0x5255, 0x5257, 0x5258, 0x5259, 0x525A, 0x525B, 0x525D, 0x525F,
+		0x5260, 0x5262, 0x5263, 0x5264, 0x5266, 0x5268, 0x526B, 0x526C, 0x526D, 0x526E, 0x5270,
+		0x5271, 0x5273, 0x5274, 0x5275, 0x5276, 0x5277, 0x5278, 0x5279, 0x527A, 0x527B, 0x527C,
+		0x527E, 0x5280, 0x5283, 0x5284, 0x5285, 0x5286, 0x5287, 0x5289, 0x528A, 0x528B, 0x528C,
+		0x528D, 0x528E, 0x528F, 0x5291, 0x5292, 0x5294, 0x5295, 0x5296, 0x5297, 0x5298, 0x5299,
+		0x529A, 0x529C, 0x52A4, 0x52A5, 0x52A6, 0x52A7, 0x52AE, 0x52AF, 0x52B0, 0x52B4, 0x52B5,
+		0x52B6, 0x52B7, 0x52B8, 0x52B9, 0x52BA, 0x52BB, 0x52BC, 0x52BD, 0x52C0, 0x52C1, 0x52C2,
+		0x52C4, 0x52C5, 0x52C6, 0x52C8, 0x52CA, 0x52CC, 0x52CD, 0x52CE, 0x52CF, 0x52D1, 0x52D3,
+		0x52D4, 0x52D5, 0x52D7, 0x52D9, 0x52DA, 0x52DB, 0x52DC, 0x52DD, 0x52DE, 0x52E0, 0x52E1,
+		0x52E2, 0x52E3, 0x52E5, 0x52E6, 0x52E7, 0x52E8, 0x52E9, 0x52EA, 0x52EB, 0x52EC, 0x52ED,
+		0x52EE, 0x52EF, 0x52F1, 0x52F2, 0x52F3, 0x52F4, 0x52F5, 0x52F6, 0x52F7, 0x52F8, 0x52FB,
+		0x52FC, 0x52FD, 0x5301, 0x5302, 0x5303, 0x5304, 0x5307, 0x5309, 0x530A, 0x530B, 0x530C,
+		0x530E, 0x5311, 0x5312, 0x5313, 0x5314, 0x5318, 0x531B, 0x531C, 0x531E, 0x531F, 0x5322,
+		0x5324, 0x5325, 0x5327, 0x5328, 0x5329, 0x532B, 0x532C, 0x532D, 0x532F, 0x5330, 0x5331,
+		0x5332, 0x5333, 0x5334, 0x5335, 0x5336, 0x5337, 0x5338, 0x533C, 0x533D, 0x5340, 0x5342,
+		0x5344, 0x5346, 0x534B, 0x534C, 0x534D, 0x5350, 0x5354, 0x5358, 0x5359, 0x535B, 0x535D,
+		0x5365, 0x5368, 0x536A, 0x536C, 0x536D, 0x5372, 0x5376, 0x5379, 0x537B, 0x537C, 0x537D,
+		0x537E, 0x5380, 0x5381, 0x5383, 0x5387, 0x5388, 0x538A, 0x538E, 0x538F, 0x5390, 0x5391,
+		0x5392, 0x5393, 0x5394, 0x5396, 0x5397, 0x5399, 0x539B, 0x539C, 0x539E, 0x53A0, 0x53A1,
+		0x53A4, 0x53A7, 0x53AA, 0x53AB, 0x53AC, 0x53AD, 0x53AF, 0x53B0, 0x53B1, 0x53B2, 0x53B3,
+		0x53B4, 0x53B5, 0x53B7, 0x53B8, 0x53B9, 0x53BA, 0x53BC, 0x53BD, 0x53BE, 0x53C0, 0x53C3,
+		0x53C4, 0x53C5, 0x53C6, 0x53C7, 0x53CE, 0x53CF, 0x53D0, 0x53D2, 0x53D3, 0x53D5, 0x53DA,
+		0x53DC, 0x53DD, 0x53DE, 0x53E1, 0x53E2, 0x53E7, 0x53F4, 0x53FA, 0x53FE, 0x53FF, 0x5400,
+		0x5402, 0x5405, 0x5407, 0x540B, 0x5414, 0x5418, 0x5419, 0x541A, 0x541C, 0x5422, 0x5424,
+		0x5425, 0x542A, 0x5430, 0x5433, 0x5436, 0x5437, 0x543A, 0x543D, 0x543F, 0x5441, 0x5442,
+		0x5444, 0x5445, 0x5447, 0x5449, 0x544C, 0x544D, 0x544E, 0x544F, 0x5451, 0x545A, 0x545D,
+		0x545E, 0x545F, 0x5460, 0x5461, 0x5463, 0x5465, 0x5467, 0x5469, 0x546A, 0x546B, 0x546C,
+		0x546D, 0x546E, 0x546F, 0x5470, 0x5474, 0x5479, 0x547A, 0x547E, 0x547F, 0x5481, 0x5483,
+		0x5485, 0x5487, 0x5488, 0x5489, 0x548A, 0x548D, 0x5491, 0x5493, 0x5497, 0x5498, 0x549C,
+		0x549E, 0x549F, 0x54A0, 0x54A1, 0x54A2, 0x54A5, 0x54AE, 0x54B0, 0x54B2, 0x54B5, 0x54B6,
+		0x54B7, 0x54B9, 0x54BA, 0x54BC, 0x54BE, 0x54C3, 0x54C5, 0x54CA, 0x54CB, 0x54D6, 0x54D8,
+		0x54DB, 0x54E0, 0x54E1, 0x54E2, 0x54E3, 0x54E4, 0x54EB, 0x54EC, 0x54EF, 0x54F0, 0x54F1,
+		0x54F4, 0x54F5, 0x54F6, 0x54F7, 0x54F8, 0x54F9, 0x54FB, 0x54FE, 0x5500, 0x5502, 0x5503,
+		0x5504, 0x5505, 0x5508, 0x550A, 0x550B, 0x550C, 0x550D, 0x550E, 0x5512, 0x5513, 0x5515,
+		0x5516, 0x5517, 0x5518, 0x5519, 0x551A, 0x551C, 0x551D, 0x551E, 0x551F, 0x5521, 0x5525,
+		0x5526, 0x5528, 0x5529, 0x552B, 0x552D, 0x5532, 0x5534, 0x5535, 0x5536, 0x5538, 0x5539,
+		0x553A, 0x553B, 0x553D, 0x5540, 0x5542, 0x5545, 0x5547, 0x5548, 0x554B, 0x554C, 0x554D,
+		0x554E, 0x554F, 0x5551, 0x5552, 0x5553, 0x5554, 0x5557, 0x5558, 0x5559, 0x555A, 0x555B,
+		0x555D, 0x555E, 0x555F, 0x5560, 0x5562, 0x5563, 0x5568, 0x5569, 0x556B, 0x556F, 0x5570,
+		0x5571, 0x5572, 0x5573, 0x5574, 0x5579, 0x557A, 0x557D, 0x557F, 0x5585, 0x5586, 0x558C,
+		0x558D, 0x558E, 0x5590, 0x5592, 0x5593, 0x5595, 0x5596, 0x5597, 0x559A, 0x559B, 0x559E,
+		0x55A0, 0x55A1, 0x55A2, 0x55A3, 0x55A4, 0x55A5, 0x55A6, 0x55A8, 0x55A9, 0x55AA, 0x55AB,
+		0x55AC, 0x55AD, 0x55AE, 0x55AF, 0x55B0, 0x55B2, 0x55B4, 0x55B6, 0x55B8, 0x55BA, 0x55BC,
+		0x55BF, 0x55C0, 0x55C1, 0x55C2, 0x55C3, 0x55C6, 0x55C7, 0x55C8, 0x55CA, 0x55CB, 0x55CE,
+		0x55CF, 0x55D0, 0x55D5, 0x55D7, 0x55D8, 0x55D9, 0x55DA, 0x55DB, 0x55DE, 0x55E0, 0x55E2,
+		0x55E7, 0x55E9, 0x55ED, 0x55EE, 0x55F0, 0x55F1, 0x55F4, 0x55F6, 0x55F8, 0x55F9, 0x55FA,
+		0x55FB, 0x55FC, 0x55FF, 0x5602, 0x5603, 0x5604, 0x5605, 0x5606, 0x5607, 0x560A, 0x560B,
+		0x560D, 0x5610, 0x5611, 0x5612, 0x5613, 0x5614, 0x5615, 0x5616, 0x5617, 0x5619, 0x561A,
+		0x561C, 0x561D, 0x5620, 0x5621, 0x5622, 0x5625, 0x5626, 0x5628, 0x5629, 0x562A, 0x562B,
+		0x562E, 0x562F, 0x5630, 0x5633, 0x5635, 0x5637, 0x5638, 0x563A, 0x563C, 0x563D, 0x563E,
+		0x5640, 0x5641, 0x5642, 0x5643, 0x5644, 0x5645, 0x5646, 0x5647, 0x5648, 0x5649, 0x564A,
+		0x564B, 0x564F, 0x5650, 0x5651, 0x5652, 0x5653, 0x5655, 0x5656, 0x565A, 0x565B, 0x565D,
+		0x565E, 0x565F, 0x5660, 0x5661, 0x5663, 0x5665, 0x5666, 0x5667, 0x566D, 0x566E, 0x566F,
+		0x5670, 0x5672, 0x5673, 0x5674, 0x5675, 0x5677, 0x5678, 0x5679, 0x567A, 0x567D, 0x567E,
+		0x567F, 0x5680, 0x5681, 0x5682, 0x5683, 0x5684, 0x5687, 0x5688, 0x5689, 0x568A, 0x568B,
+		0x568C, 0x568D, 0x5690, 0x5691, 0x5692, 0x5694, 0x5695, 0x5696, 0x5697, 0x5698, 0x5699,
+		0x569A, 0x569B, 0x569C, 0x569D, 0x569E, 0x569F, 0x56A0, 0x56A1, 0x56A2, 0x56A4, 0x56A5,
+		0x56A6, 0x56A7, 0x56A8, 0x56A9, 0x56AA, 0x56AB, 0x56AC, 0x56AD, 0x56AE, 0x56B0, 0x56B1,
+		0x56B2, 0x56B3, 0x56B4, 0x56B5, 0x56B6, 0x56B8, 0x56B9, 0x56BA, 0x56BB, 0x56BD, 0x56BE,
+		0x56BF, 0x56C0, 0x56C1, 0x56C2, 0x56C3, 0x56C4, 0x56C5, 0x56C6, 0x56C7, 0x56C8, 0x56C9,
+		0x56CB, 0x56CC, 0x56CD, 0x56CE, 0x56CF, 0x56D0, 0x56D1, 0x56D2, 0x56D3, 0x56D5, 0x56D6,
+		0x56D8, 0x56D9, 0x56DC, 0x56E3, 0x56E5, 0x56E6, 0x56E7, 0x56E8, 0x56E9, 0x56EA, 0x56EC,
+		0x56EE, 0x56EF, 0x56F2, 0x56F3, 0x56F6, 0x56F7, 0x56F8, 0x56FB, 0x56FC, 0x5700, 0x5701,
+		0x5702, 0x5705, 0x5707, 0x570B, 0x570C, 0x570D, 0x570E, 0x570F, 0x5710, 0x5711, 0x5712,
+		0x5713, 0x5714, 0x5715, 0x5716, 0x5717, 0x5718, 0x5719, 0x571A, 0x571B, 0x571D, 0x571E,
+		0x5720, 0x5721, 0x5722, 0x5724, 0x5725, 0x5726, 0x5727, 0x572B, 0x5731, 0x5732, 0x5734,
+		0x5735, 0x5736, 0x5737, 0x5738, 0x573C, 0x573D, 0x573F, 0x5741, 0x5743, 0x5744, 0x5745,
+		0x5746, 0x5748, 0x5749, 0x574B, 0x5752, 0x5753, 0x5754, 0x5755, 0x5756, 0x5758, 0x5759,
+		0x5762, 0x5763, 0x5765, 0x5767, 0x576C, 0x576E, 0x5770, 0x5771, 0x5772, 0x5774, 0x5775,
+		0x5778, 0x5779, 0x577A, 0x577D, 0x577E, 0x577F, 0x5780, 0x5781, 0x5787, 0x5788, 0x5789,
+		0x578A, 0x578D, 0x578E, 0x578F, 0x5790, 0x5791, 0x5794, 0x5795, 0x5796, 0x5797, 0x5798,
+		0x5799, 0x579A, 0x579C, 0x579D, 0x579E, 0x579F, 0x57A5, 0x57A8, 0x57AA, 0x57AC, 0x57AF,
+		0x57B0, 0x57B1, 0x57B3, 0x57B5, 0x57B6, 0x57B7, 0x57B9, 0x57BA, 0x57BB, 0x57BC, 0x57BD,
+		0x57BE, 0x57BF, 0x57C0, 0x57C1, 0x57C4, 0x57C5, 0x57C6, 0x57C7, 0x57C8, 0x57C9, 0x57CA,
+		0x57CC, 0x57CD, 0x57D0, 0x57D1, 0x57D3, 0x57D6, 0x57D7, 0x57DB, 0x57DC, 0x57DE, 0x57E1,
+		0x57E2, 0x57E3, 0x57E5, 0x57E6, 0x57E7, 0x57E8, 0x57E9, 0x57EA, 0x57EB, 0x57EC, 0x57EE,
+		0x57F0, 0x57F1, 0x57F2, 0x57F3, 0x57F5, 0x57F6, 0x57F7, 0x57FB, 0x57FC, 0x57FE, 0x57FF,
+		0x5801, 0x5803, 0x5804, 0x5805, 0x5808, 0x5809, 0x580A, 0x580C, 0x580E, 0x580F, 0x5810,
+		0x5812, 0x5813, 0x5814, 0x5816, 0x5817, 0x5818, 0x581A, 0x581B, 0x581C, 0x581D, 0x581F,
+		0x5822, 0x5823, 0x5825, 0x5826, 0x5827, 0x5828, 0x5829, 0x582B, 0x582C, 0x582D, 0x582E,
+		0x582F, 0x5831, 0x5832, 0x5833, 0x5834, 0x5836, 0x5837, 0x5838, 0x5839, 0x583A, 0x583B,
+		0x583C, 0x583D, 0x583E, 0x583F, 0x5840, 0x5841, 0x5842, 0x5843, 0x5845, 0x5846, 0x5847,
+		0x5848, 0x5849, 0x584A, 0x584B, 0x584E, 0x584F, 0x5850, 0x5852, 0x5853, 0x5855, 0x5856,
+		0x5857, 0x5859, 0x585A, 0x585B, 0x585C, 0x585D, 0x585F, 0x5860, 0x5861, 0x5862, 0x5863,
+		0x5864, 0x5866, 0x5867, 0x5868, 0x5869, 0x586A, 0x586D, 0x586E, 0x586F, 0x5870, 0x5871,
+		0x5872, 0x5873, 0x5874, 0x5875, 0x5876, 0x5877, 0x5878, 0x5879, 0x587A, 0x587B, 0x587C,
+		0x587D, 0x587F, 0x5882, 0x5884, 0x5886, 0x5887, 0x5888, 0x588A, 0x588B, 0x588C, 0x588D,
+		0x588E, 0x588F, 0x5890, 0x5891, 0x5894, 0x5895, 0x5896, 0x5897, 0x5898, 0x589B, 0x589C,
+		0x589D, 0x58A0, 0x58A1, 0x58A2, 0x58A3, 0x58A4, 0x58A5, 0x58A6, 0x58A7, 0x58AA, 0x58AB,
+		0x58AC, 0x58AD, 0x58AE, 0x58AF, 0x58B0, 0x58B1, 0x58B2, 0x58B3, 0x58B4, 0x58B5, 0x58B6,
+		0x58B7, 0x58B8, 0x58B9, 0x58BA, 0x58BB, 0x58BD, 0x58BE, 0x58BF, 0x58C0, 0x58C2, 0x58C3,
+		0x58C4, 0x58C6, 0x58C7, 0x58C8, 0x58C9, 0x58CA, 0x58CB, 0x58CC, 0x58CD, 0x58CE, 0x58CF,
+		0x58D0, 0x58D2, 0x58D3, 0x58D4, 0x58D6, 0x58D7, 0x58D8, 0x58D9, 0x58DA, 0x58DB, 0x58DC,
+		0x58DD, 0x58DE, 0x58DF, 0x58E0, 0x58E1, 0x58E2, 0x58E3, 0x58E5, 0x58E6, 0x58E7, 0x58E8,
+		0x58E9, 0x58EA, 0x58ED, 0x58EF, 0x58F1, 0x58F2, 0x58F4, 0x58F5, 0x58F7, 0x58F8, 0x58FA,
+		0x58FB, 0x58FC, 0x58FD, 0x58FE, 0x58FF, 0x5900, 0x5901, 0x5903, 0x5905, 0x5906, 0x5908,
+		0x5909, 0x590A, 0x590B, 0x590C, 0x590E, 0x5910, 0x5911, 0x5912, 0x5913, 0x5917, 0x5918,
+		0x591B, 0x591D, 0x591E, 0x5920, 0x5921, 0x5922, 0x5923, 0x5926, 0x5928, 0x592C, 0x5930,
+		0x5932, 0x5933, 0x5935, 0x5936, 0x593B, 0x593D, 0x593E, 0x593F, 0x5940, 0x5943, 0x5945,
+		0x5946, 0x594A, 0x594C, 0x594D, 0x5950, 0x5952, 0x5953, 0x5959, 0x595B, 0x595C, 0x595D,
+		0x595E, 0x595F, 0x5961, 0x5963, 0x5964, 0x5966, 0x5967, 0x5968, 0x5969, 0x596A, 0x596B,
+		0x596C, 0x596D, 0x596E, 0x596F, 0x5970, 0x5971, 0x5972, 0x5975, 0x5977, 0x597A, 0x597B,
+		0x597C, 0x597E, 0x597F, 0x5980, 0x5985, 0x5989, 0x598B, 0x598C, 0x598E, 0x598F, 0x5990,
+		0x5991, 0x5994, 0x5995, 0x5998, 0x599A, 0x599B, 0x599C, 0x599D, 0x599F, 0x59A0, 0x59A1,
+		0x59A2, 0x59A6, 0x59A7, 0x59AC, 0x59AD, 0x59B0, 0x59B1, 0x59B3, 0x59B4, 0x59B5, 0x59B6,
+		0x59B7, 0x59B8, 0x59BA, 0x59BC, 0x59BD, 0x59BF, 0x59C0, 0x59C1, 0x59C2, 0x59C3, 0x59C4,
+		0x59C5, 0x59C7, 0x59C8, 0x59C9, 0x59CC, 0x59CD, 0x59CE, 0x59CF, 0x59D5, 0x59D6, 0x59D9,
+		0x59DB, 0x59DE, 0x59DF, 0x59E0, 0x59E1, 0x59E2, 0x59E4, 0x59E6, 0x59E7, 0x59E9, 0x59EA,
+		0x59EB, 0x59ED, 0x59EE, 0x59EF, 0x59F0, 0x59F1, 0x59F2, 0x59F3, 0x59F4, 0x59F5, 0x59F6,
+		0x59F7, 0x59F8, 0x59FA, 0x59FC, 0x59FD, 0x59FE, 0x5A00, 0x5A02, 0x5A0A, 0x5A0B, 0x5A0D,
+		0x5A0E, 0x5A0F, 0x5A10, 0x5A12, 0x5A14, 0x5A15, 0x5A16, 0x5A17, 0x5A19, 0x5A1A, 0x5A1B,
+		0x5A1D, 0x5A1E, 0x5A21, 0x5A22, 0x5A24, 0x5A26, 0x5A27, 0x5A28, 0x5A2A, 0x5A2B, 0x5A2C,
+		0x5A2D, 0x5A2E, 0x5A2F, 0x5A30, 0x5A33, 0x5A35, 0x5A37, 0x5A38, 0x5A39, 0x5A3A, 0x5A3B,
+		0x5A3D, 0x5A3E, 0x5A3F, 0x5A41, 0x5A42, 0x5A43, 0x5A44, 0x5A45, 0x5A47, 0x5A48, 0x5A4B,
+		0x5A4C, 0x5A4D, 0x5A4E, 0x5A4F, 0x5A50, 0x5A51, 0x5A52, 0x5A53, 0x5A54, 0x5A56, 0x5A57,
+		0x5A58, 0x5A59, 0x5A5B, 0x5A5C, 0x5A5D, 0x5A5E, 0x5A5F, 0x5A60, 0x5A61, 0x5A63, 0x5A64,
+		0x5A65, 0x5A66, 0x5A68, 0x5A69, 0x5A6B, 0x5A6C, 0x5A6D, 0x5A6E, 0x5A6F, 0x5A70, 0x5A71,
+		0x5A72, 0x5A73, 0x5A78, 0x5A79, 0x5A7B, 0x5A7C, 0x5A7D, 0x5A7E, 0x5A80, 0x5A81, 0x5A82,
+		0x5A83, 0x5A84, 0x5A85, 0x5A86, 0x5A87, 0x5A88, 0x5A89, 0x5A8A, 0x5A8B, 0x5A8C, 0x5A8D,
+		0x5A8E, 0x5A8F, 0x5A90, 0x5A91, 0x5A93, 0x5A94, 0x5A95, 0x5A96, 0x5A97, 0x5A98, 0x5A99,
+		0x5A9C, 0x5A9D, 0x5A9E, 0x5A9F, 0x5AA0, 0x5AA1, 0x5AA2, 0x5AA3, 0x5AA4, 0x5AA5, 0x5AA6,
+		0x5AA7, 0x5AA8, 0x5AA9, 0x5AAB, 0x5AAC, 0x5AAD, 0x5AAE, 0x5AAF, 0x5AB0, 0x5AB1, 0x5AB4,
+		0x5AB6, 0x5AB7, 0x5AB9, 0x5ABA, 0x5ABB, 0x5ABC, 0x5ABD, 0x5ABF, 0x5AC0, 0x5AC3, 0x5AC4,
+		0x5AC5, 0x5AC6, 0x5AC7, 0x5AC8, 0x5ACA, 0x5ACB, 0x5ACD, 0x5ACE, 0x5ACF, 0x5AD0, 0x5AD1,
+		0x5AD3, 0x5AD5, 0x5AD7, 0x5AD9, 0x5ADA, 0x5ADB, 0x5ADD, 0x5ADE, 0x5ADF, 0x5AE2, 0x5AE4,
+		0x5AE5, 0x5AE7, 0x5AE8, 0x5AEA, 0x5AEC, 0x5AED, 0x5AEE, 0x5AEF, 0x5AF0, 0x5AF2, 0x5AF3,
+		0x5AF4, 0x5AF5, 0x5AF6, 0x5AF7, 0x5AF8, 0x5AF9, 0x5AFA, 0x5AFB, 0x5AFC, 0x5AFD, 0x5AFE,
+		0x5AFF, 0x5B00, 0x5B01, 0x5B02, 0x5B03, 0x5B04, 0x5B05, 0x5B06, 0x5B07, 0x5B08, 0x5B0A,
+		0x5B0B, 0x5B0C, 0x5B0D, 0x5B0E, 0x5B0F, 0x5B10, 0x5B11, 0x5B12, 0x5B13, 0x5B14, 0x5B15,
+		0x5B18, 0x5B19, 0x5B1A, 0x5B1B, 0x5B1C, 0x5B1D, 0x5B1E, 0x5B1F, 0x5B20, 0x5B21, 0x5B22,
+		0x5B23, 0x5B24, 0x5B25, 0x5B26, 0x5B27, 0x5B28, 0x5B29, 0x5B2A, 0x5B2B, 0x5B2C, 0x5B2D,
+		0x5B2E, 0x5B2F, 0x5B30, 0x5B31, 0x5B33, 0x5B35, 0x5B36, 0x5B38, 0x5B39, 0x5B3A, 0x5B3B,
+		0x5B3C, 0x5B3D, 0x5B3E, 0x5B3F, 0x5B41, 0x5B42, 0x5B43, 0x5B44, 0x5B45, 0x5B46, 0x5B47,
+		0x5B48, 0x5B49, 0x5B4A, 0x5B4B, 0x5B4C, 0x5B4D, 0x5B4E, 0x5B4F, 0x5B52, 0x5B56, 0x5B5E,
+		0x5B60, 0x5B61, 0x5B67, 0x5B68, 0x5B6B, 0x5B6D, 0x5B6E, 0x5B6F, 0x5B72, 0x5B74, 0x5B76,
+		0x5B77, 0x5B78, 0x5B79, 0x5B7B, 0x5B7C, 0x5B7E, 0x5B7F, 0x5B82, 0x5B86, 0x5B8A, 0x5B8D,
+		0x5B8E, 0x5B90, 0x5B91, 0x5B92, 0x5B94, 0x5B96, 0x5B9F, 0x5BA7, 0x5BA8, 0x5BA9, 0x5BAC,
+		0x5BAD, 0x5BAE, 0x5BAF, 0x5BB1, 0x5BB2, 0x5BB7, 0x5BBA, 0x5BBB, 0x5BBC, 0x5BC0, 0x5BC1,
+		0x5BC3, 0x5BC8, 0x5BC9, 0x5BCA, 0x5BCB, 0x5BCD, 0x5BCE, 0x5BCF, 0x5BD1, 0x5BD4, 0x5BD5,
+		0x5BD6, 0x5BD7, 0x5BD8, 0x5BD9, 0x5BDA, 0x5BDB, 0x5BDC, 0x5BE0, 0x5BE2, 0x5BE3, 0x5BE6,
+		0x5BE7, 0x5BE9, 0x5BEA, 0x5BEB, 0x5BEC, 0x5BED, 0x5BEF, 0x5BF1, 0x5BF2, 0x5BF3, 0x5BF4,
+		0x5BF5, 0x5BF6, 0x5BF7, 0x5BFD, 0x5BFE, 0x5C00, 0x5C02, 0x5C03, 0x5C05, 0x5C07, 0x5C08,
+		0x5C0B, 0x5C0C, 0x5C0D, 0x5C0E, 0x5C10, 0x5C12, 0x5C13, 0x5C17, 0x5C19, 0x5C1B, 0x5C1E,
+		0x5C1F, 0x5C20, 0x5C21, 0x5C23, 0x5C26, 0x5C28, 0x5C29, 0x5C2A, 0x5C2B, 0x5C2D, 0x5C2E,
+		0x5C2F, 0x5C30, 0x5C32, 0x5C33, 0x5C35, 0x5C36, 0x5C37, 0x5C43, 0x5C44, 0x5C46, 0x5C47,
+		0x5C4C, 0x5C4D, 0x5C52, 0x5C53, 0x5C54, 0x5C56, 0x5C57, 0x5C58, 0x5C5A, 0x5C5B, 0x5C5C,
+		0x5C5D, 0x5C5F, 0x5C62, 0x5C64, 0x5C67, 0x5C68, 0x5C69, 0x5C6A, 0x5C6B, 0x5C6C, 0x5C6D,
+		0x5C70, 0x5C72, 0x5C73, 0x5C74, 0x5C75, 0x5C76, 0x5C77, 0x5C78, 0x5C7B, 0x5C7C, 0x5C7D,
+		0x5C7E, 0x5C80, 0x5C83, 0x5C84, 0x5C85, 0x5C86, 0x5C87, 0x5C89, 0x5C8A, 0x5C8B, 0x5C8E,
+		0x5C8F, 0x5C92, 0x5C93, 0x5C95, 0x5C9D, 0x5C9E, 0x5C9F, 0x5CA0, 0x5CA1, 0x5CA4, 0x5CA5,
+		0x5CA6, 0x5CA7, 0x5CA8, 0x5CAA, 0x5CAE, 0x5CAF, 0x5CB0, 0x5CB2, 0x5CB4, 0x5CB6, 0x5CB9,
+		0x5CBA, 0x5CBB, 0x5CBC, 0x5CBE, 0x5CC0, 0x5CC2, 0x5CC3, 0x5CC5, 0x5CC6, 0x5CC7, 0x5CC8,
+		0x5CC9, 0x5CCA, 0x5CCC, 0x5CCD, 0x5CCE, 0x5CCF, 0x5CD0, 0x5CD1, 0x5CD3, 0x5CD4, 0x5CD5,
+		0x5CD6, 0x5CD7, 0x5CD8, 0x5CDA, 0x5CDB, 0x5CDC, 0x5CDD, 0x5CDE, 0x5CDF, 0x5CE0, 0x5CE2,
+		0x5CE3, 0x5CE7, 0x5CE9, 0x5CEB, 0x5CEC, 0x5CEE, 0x5CEF, 0x5CF1, 0x5CF2, 0x5CF3, 0x5CF4,
+		0x5CF5, 0x5CF6, 0x5CF7, 0x5CF8, 0x5CF9, 0x5CFA, 0x5CFC, 0x5CFD, 0x5CFE, 0x5CFF, 0x5D00,
+		0x5D01, 0x5D04, 0x5D05, 0x5D08, 0x5D09, 0x5D0A, 0x5D0B, 0x5D0C, 0x5D0D, 0x5D0F, 0x5D10,
+		0x5D11, 0x5D12, 0x5D13, 0x5D15, 0x5D17, 0x5D18, 0x5D19, 0x5D1A, 0x5D1C, 0x5D1D, 0x5D1F,
+		0x5D20, 0x5D21, 0x5D22, 0x5D23, 0x5D25, 0x5D28, 0x5D2A, 0x5D2B, 0x5D2C, 0x5D2F, 0x5D30,
+		0x5D31, 0x5D32, 0x5D33, 0x5D35, 0x5D36, 0x5D37, 0x5D38, 0x5D39, 0x5D3A, 0x5D3B, 0x5D3C,
+		0x5D3F, 0x5D40, 0x5D41, 0x5D42, 0x5D43, 0x5D44, 0x5D45, 0x5D46, 0x5D48, 0x5D49, 0x5D4D,
+		0x5D4E, 0x5D4F, 0x5D50, 0x5D51, 0x5D52, 0x5D53, 0x5D54, 0x5D55, 0x5D56, 0x5D57, 0x5D59,
+		0x5D5A, 0x5D5C, 0x5D5E, 0x5D5F, 0x5D60, 0x5D61, 0x5D62, 0x5D63, 0x5D64, 0x5D65, 0x5D66,
+		0x5D67, 0x5D68, 0x5D6A, 0x5D6D, 0x5D6E, 0x5D70, 0x5D71, 0x5D72, 0x5D73, 0x5D75, 0x5D76,
+		0x5D77, 0x5D78, 0x5D79, 0x5D7A, 0x5D7B, 0x5D7C, 0x5D7D, 0x5D7E, 0x5D7F, 0x5D80, 0x5D81,
+		0x5D83, 0x5D84, 0x5D85, 0x5D86, 0x5D87, 0x5D88, 0x5D89, 0x5D8A, 0x5D8B, 0x5D8C, 0x5D8D,
+		0x5D8E, 0x5D8F, 0x5D90, 0x5D91, 0x5D92, 0x5D93, 0x5D94, 0x5D95, 0x5D96, 0x5D97, 0x5D98,
+		0x5D9A, 0x5D9B, 0x5D9C, 0x5D9E, 0x5D9F, 0x5DA0, 0x5DA1, 0x5DA2, 0x5DA3, 0x5DA4, 0x5DA5,
+		0x5DA6, 0x5DA7, 0x5DA8, 0x5DA9, 0x5DAA, 0x5DAB, 0x5DAC, 0x5DAD, 0x5DAE, 0x5DAF, 0x5DB0,
+		0x5DB1, 0x5DB2, 0x5DB3, 0x5DB4, 0x5DB5, 0x5DB6, 0x5DB8, 0x5DB9, 0x5DBA, 0x5DBB, 0x5DBC,
+		0x5DBD, 0x5DBE, 0x5DBF, 0x5DC0, 0x5DC1, 0x5DC2, 0x5DC3, 0x5DC4, 0x5DC6, 0x5DC7, 0x5DC8,
+		0x5DC9, 0x5DCA, 0x5DCB, 0x5DCC, 0x5DCE, 0x5DCF, 0x5DD0, 0x5DD1, 0x5DD2, 0x5DD3, 0x5DD4,
+		0x5DD5, 0x5DD6, 0x5DD7, 0x5DD8, 0x5DD9, 0x5DDA, 0x5DDC, 0x5DDF, 0x5DE0, 0x5DE3, 0x5DE4,
+		0x5DEA, 0x5DEC, 0x5DED, 0x5DF0, 0x5DF5, 0x5DF6, 0x5DF8, 0x5DF9, 0x5DFA, 0x5DFB, 0x5DFC,
+		0x5DFF, 0x5E00, 0x5E04, 0x5E07, 0x5E09, 0x5E0A, 0x5E0B, 0x5E0D, 0x5E0E, 0x5E12, 0x5E13,
+		0x5E17, 0x5E1E, 0x5E1F, 0x5E20, 0x5E21, 0x5E22, 0x5E23, 0x5E24, 0x5E25, 0x5E28, 0x5E29,
+		0x5E2A, 0x5E2B, 0x5E2C, 0x5E2F, 0x5E30, 0x5E32, 0x5E33, 0x5E34, 0x5E35, 0x5E36, 0x5E39,
+		0x5E3A, 0x5E3E, 0x5E3F, 0x5E40, 0x5E41, 0x5E43, 0x5E46, 0x5E47, 0x5E48, 0x5E49, 0x5E4A,
+		0x5E4B, 0x5E4D, 0x5E4E, 0x5E4F, 0x5E50, 0x5E51, 0x5E52, 0x5E53, 0x5E56, 0x5E57, 0x5E58,
+		0x5E59, 0x5E5A, 0x5E5C, 0x5E5D, 0x5E5F, 0x5E60, 0x5E63, 0x5E64, 0x5E65, 0x5E66, 0x5E67,
+		0x5E68, 0x5E69, 0x5E6A, 0x5E6B, 0x5E6C, 0x5E6D, 0x5E6E, 0x5E6F, 0x5E70, 0x5E71, 0x5E75,
+		0x5E77, 0x5E79, 0x5E7E, 0x5E81, 0x5E82, 0x5E83, 0x5E85, 0x5E88, 0x5E89, 0x5E8C, 0x5E8D,
+		0x5E8E, 0x5E92, 0x5E98, 0x5E9B, 0x5E9D, 0x5EA1, 0x5EA2, 0x5EA3, 0x5EA4, 0x5EA8, 0x5EA9,
+		0x5EAA, 0x5EAB, 0x5EAC, 0x5EAE, 0x5EAF, 0x5EB0, 0x5EB1, 0x5EB2, 0x5EB4, 0x5EBA, 0x5EBB,
+		0x5EBC, 0x5EBD, 0x5EBF, 0x5EC0, 0x5EC1, 0x5EC2, 0x5EC3, 0x5EC4, 0x5EC5, 0x5EC6, 0x5EC7,
+		0x5EC8, 0x5ECB, 0x5ECC, 0x5ECD, 0x5ECE, 0x5ECF, 0x5ED0, 0x5ED4, 0x5ED5, 0x5ED7, 0x5ED8,
+		0x5ED9, 0x5EDA, 0x5EDC, 0x5EDD, 0x5EDE, 0x5EDF, 0x5EE0, 0x5EE1, 0x5EE2, 0x5EE3, 0x5EE4,
+		0x5EE5, 0x5EE6, 0x5EE7, 0x5EE9, 0x5EEB, 0x5EEC, 0x5EED, 0x5EEE, 0x5EEF, 0x5EF0, 0x5EF1,
+		0x5EF2, 0x5EF3, 0x5EF5, 0x5EF8, 0x5EF9, 0x5EFB, 0x5EFC, 0x5EFD, 0x5F05, 0x5F06, 0x5F07,
+		0x5F09, 0x5F0C, 0x5F0D, 0x5F0E, 0x5F10, 0x5F12, 0x5F14, 0x5F16, 0x5F19, 0x5F1A, 0x5F1C,
+		0x5F1D, 0x5F1E, 0x5F21, 0x5F22, 0x5F23, 0x5F24, 0x5F28, 0x5F2B, 0x5F2C, 0x5F2E, 0x5F30,
+		0x5F32, 0x5F33, 0x5F34, 0x5F35, 0x5F36, 0x5F37, 0x5F38, 0x5F3B, 0x5F3D, 0x5F3E, 0x5F3F,
+		0x5F41, 0x5F42, 0x5F43, 0x5F44, 0x5F45, 0x5F46, 0x5F47, 0x5F48, 0x5F49, 0x5F4A, 0x5F4B,
+		0x5F4C, 0x5F4D, 0x5F4E, 0x5F4F, 0x5F51, 0x5F54, 0x5F59, 0x5F5A, 0x5F5B, 0x5F5C, 0x5F5E,
+		0x5F5F, 0x5F60, 0x5F63, 0x5F65, 0x5F67, 0x5F68, 0x5F6B, 0x5F6E, 0x5F6F, 0x5F72, 0x5F74,
+		0x5F75, 0x5F76, 0x5F78, 0x5F7A, 0x5F7D, 0x5F7E, 0x5F7F, 0x5F83, 0x5F86, 0x5F8D, 0x5F8E,
+		0x5F8F, 0x5F91, 0x5F93, 0x5F94, 0x5F96, 0x5F9A, 0x5F9B, 0x5F9D, 0x5F9E, 0x5F9F, 0x5FA0,
+		0x5FA2, 0x5FA3, 0x5FA4, 0x5FA5, 0x5FA6, 0x5FA7, 0x5FA9, 0x5FAB, 0x5FAC, 0x5FAF, 0x5FB0,
+		0x5FB1, 0x5FB2, 0x5FB3, 0x5FB4, 0x5FB6, 0x5FB8, 0x5FB9, 0x5FBA, 0x5FBB, 0x5FBE, 0x5FBF,
+		0x5FC0, 0x5FC1, 0x5FC2, 0x5FC7, 0x5FC8, 0x5FCA, 0x5FCB, 0x5FCE, 0x5FD3, 0x5FD4, 0x5FD5,
+		0x5FDA, 0x5FDB, 0x5FDC, 0x5FDE, 0x5FDF, 0x5FE2, 0x5FE3, 0x5FE5, 0x5FE6, 0x5FE8, 0x5FE9,
+		0x5FEC, 0x5FEF, 0x5FF0, 0x5FF2, 0x5FF3, 0x5FF4, 0x5FF6, 0x5FF7, 0x5FF9, 0x5FFA, 0x5FFC,
+		0x6007, 0x6008, 0x6009, 0x600B, 0x600C, 0x6010, 0x6011, 0x6013, 0x6017, 0x6018, 0x601A,
+		0x601E, 0x601F, 0x6022, 0x6023, 0x6024, 0x602C, 0x602D, 0x602E, 0x6030, 0x6031, 0x6032,
+		0x6033, 0x6034, 0x6036, 0x6037, 0x6038, 0x6039, 0x603A, 0x603D, 0x603E, 0x6040, 0x6044,
+		0x6045, 0x6046, 0x6047, 0x6048, 0x6049, 0x604A, 0x604C, 0x604E, 0x604F, 0x6051, 0x6053,
+		0x6054, 0x6056, 0x6057, 0x6058, 0x605B, 0x605C, 0x605E, 0x605F, 0x6060, 0x6061, 0x6065,
+		0x6066, 0x606E, 0x6071, 0x6072, 0x6074, 0x6075, 0x6077, 0x607E, 0x6080, 0x6081, 0x6082,
+		0x6085, 0x6086, 0x6087, 0x6088, 0x608A, 0x608B, 0x608E, 0x608F, 0x6090, 0x6091, 0x6093,
+		0x6095, 0x6097, 0x6098, 0x6099, 0x609C, 0x609E, 0x60A1, 0x60A2, 0x60A4, 0x60A5, 0x60A7,
+		0x60A9, 0x60AA, 0x60AE, 0x60B0, 0x60B3, 0x60B5, 0x60B6, 0x60B7, 0x60B9, 0x60BA, 0x60BD,
+		0x60BE, 0x60BF, 0x60C0, 0x60C1, 0x60C2, 0x60C3, 0x60C4, 0x60C7, 0x60C8, 0x60C9, 0x60CC,
+		0x60CD, 0x60CE, 0x60CF, 0x60D0, 0x60D2, 0x60D3, 0x60D4, 0x60D6, 0x60D7, 0x60D9, 0x60DB,
+		0x60DE, 0x60E1, 0x60E2, 0x60E3, 0x60E4, 0x60E5, 0x60EA, 0x60F1, 0x60F2, 0x60F5, 0x60F7,
+		0x60F8, 0x60FB, 0x60FC, 0x60FD, 0x60FE, 0x60FF, 0x6102, 0x6103, 0x6104, 0x6105, 0x6107,
+		0x610A, 0x610B, 0x610C, 0x6110, 0x6111, 0x6112, 0x6113, 0x6114, 0x6116, 0x6117, 0x6118,
+		0x6119, 0x611B, 0x611C, 0x611D, 0x611E, 0x6121, 0x6122, 0x6125, 0x6128, 0x6129, 0x612A,
+		0x612C, 0x612D, 0x612E, 0x612F, 0x6130, 0x6131, 0x6132, 0x6133, 0x6134, 0x6135, 0x6136,
+		0x6137, 0x6138, 0x6139, 0x613A, 0x613B, 0x613C, 0x613D, 0x613E, 0x6140, 0x6141, 0x6142,
+		0x6143, 0x6144, 0x6145, 0x6146, 0x6147, 0x6149, 0x614B, 0x614D, 0x614F, 0x6150, 0x6152,
+		0x6153, 0x6154, 0x6156, 0x6157, 0x6158, 0x6159, 0x615A, 0x615B, 0x615C, 0x615E, 0x615F,
+		0x6160, 0x6161, 0x6163, 0x6164, 0x6165, 0x6166, 0x6169, 0x616A, 0x616B, 0x616C, 0x616D,
+		0x616E, 0x616F, 0x6171, 0x6172, 0x6173, 0x6174, 0x6176, 0x6178, 0x6179, 0x617A, 0x617B,
+		0x617C, 0x617D, 0x617E, 0x617F, 0x6180, 0x6181, 0x6182, 0x6183, 0x6184, 0x6185, 0x6186,
+		0x6187, 0x6188, 0x6189, 0x618A, 0x618C, 0x618D, 0x618F, 0x6190, 0x6191, 0x6192, 0x6193,
+		0x6195, 0x6196, 0x6197, 0x6198, 0x6199, 0x619A, 0x619B, 0x619C, 0x619E, 0x619F, 0x61A0,
+		0x61A1, 0x61A2, 0x61A3, 0x61A4, 0x61A5, 0x61A6, 0x61AA, 0x61AB, 0x61AD, 0x61AE, 0x61AF,
+		0x61B0, 0x61B1, 0x61B2, 0x61B3, 0x61B4, 0x61B5, 0x61B6, 0x61B8, 0x61B9, 0x61BA, 0x61BB,
+		0x61BC, 0x61BD, 0x61BF, 0x61C0, 0x61C1, 0x61C3, 0x61C4, 0x61C5, 0x61C6, 0x61C7, 0x61C9,
+		0x61CC, 0x61CD, 0x61CE, 0x61CF, 0x61D0, 0x61D3, 0x61D5, 0x61D6, 0x61D7, 0x61D8, 0x61D9,
+		0x61DA, 0x61DB, 0x61DC, 0x61DD, 0x61DE, 0x61DF, 0x61E0, 0x61E1, 0x61E2, 0x61E3, 0x61E4,
+		0x61E5, 0x61E7, 0x61E8, 0x61E9, 0x61EA, 0x61EB, 0x61EC, 0x61ED, 0x61EE, 0x61EF, 0x61F0,
+		0x61F1, 0x61F2, 0x61F3, 0x61F4, 0x61F6, 0x61F7, 0x61F8, 0x61F9, 0x61FA, 0x61FB, 0x61FC,
+		0x61FD, 0x61FE, 0x6200, 0x6201, 0x6202, 0x6203, 0x6204, 0x6205, 0x6207, 0x6209, 0x6213,
+		0x6214, 0x6219, 0x621C, 0x621D, 0x621E, 0x6220, 0x6223, 0x6226, 0x6227, 0x6228, 0x6229,
+		0x622B, 0x622D, 0x622F, 0x6230, 0x6231, 0x6232, 0x6235, 0x6236, 0x6238, 0x6239, 0x623A,
+		0x623B, 0x623C, 0x6242, 0x6244, 0x6245, 0x6246, 0x624A, 0x624F, 0x6250, 0x6255, 0x6256,
+		0x6257, 0x6259, 0x625A, 0x625C, 0x625D, 0x625E, 0x625F, 0x6260, 0x6261, 0x6262, 0x6264,
+		0x6265, 0x6268, 0x6271, 0x6272, 0x6274, 0x6275, 0x6277, 0x6278, 0x627A, 0x627B, 0x627D,
+		0x6281, 0x6282, 0x6283, 0x6285, 0x6286, 0x6287, 0x6288, 0x628B, 0x628C, 0x628D, 0x628E,
+		0x628F, 0x6290, 0x6294, 0x6299, 0x629C, 0x629D, 0x629E, 0x62A3, 0x62A6, 0x62A7, 0x62A9,
+		0x62AA, 0x62AD, 0x62AE, 0x62AF, 0x62B0, 0x62B2, 0x62B3, 0x62B4, 0x62B6, 0x62B7, 0x62B8,
+		0x62BA, 0x62BE, 0x62C0, 0x62C1, 0x62C3, 0x62CB, 0x62CF, 0x62D1, 0x62D5, 0x62DD, 0x62DE,
+		0x62E0, 0x62E1, 0x62E4, 0x62EA, 0x62EB, 0x62F0, 0x62F2, 0x62F5, 0x62F8, 0x62F9, 0x62FA,
+		0x62FB, 0x6300, 0x6303, 0x6304, 0x6305, 0x6306, 0x630A, 0x630B, 0x630C, 0x630D, 0x630F,
+		0x6310, 0x6312, 0x6313, 0x6314, 0x6315, 0x6317, 0x6318, 0x6319, 0x631C, 0x6326, 0x6327,
+		0x6329, 0x632C, 0x632D, 0x632E, 0x6330, 0x6331, 0x6333, 0x6334, 0x6335, 0x6336, 0x6337,
+		0x6338, 0x633B, 0x633C, 0x633E, 0x633F, 0x6340, 0x6341, 0x6344, 0x6347, 0x6348, 0x634A,
+		0x6351, 0x6352, 0x6353, 0x6354, 0x6356, 0x6357, 0x6358, 0x6359, 0x635A, 0x635B, 0x635C,
+		0x635D, 0x6360, 0x6364, 0x6365, 0x6366, 0x6368, 0x636A, 0x636B, 0x636C, 0x636F, 0x6370,
+		0x6372, 0x6373, 0x6374, 0x6375, 0x6378, 0x6379, 0x637C, 0x637D, 0x637E, 0x637F, 0x6381,
+		0x6383, 0x6384, 0x6385, 0x6386, 0x638B, 0x638D, 0x6391, 0x6393, 0x6394, 0x6395, 0x6397,
+		0x6399, 0x639A, 0x639B, 0x639C, 0x639D, 0x639E, 0x639F, 0x63A1, 0x63A4, 0x63A6, 0x63AB,
+		0x63AF, 0x63B1, 0x63B2, 0x63B5, 0x63B6, 0x63B9, 0x63BB, 0x63BD, 0x63BF, 0x63C0, 0x63C1,
+		0x63C2, 0x63C3, 0x63C5, 0x63C7, 0x63C8, 0x63CA, 0x63CB, 0x63CC, 0x63D1, 0x63D3, 0x63D4,
+		0x63D5, 0x63D7, 0x63D8, 0x63D9, 0x63DA, 0x63DB, 0x63DC, 0x63DD, 0x63DF, 0x63E2, 0x63E4,
+		0x63E5, 0x63E6, 0x63E7, 0x63E8, 0x63EB, 0x63EC, 0x63EE, 0x63EF, 0x63F0, 0x63F1, 0x63F3,
+		0x63F5, 0x63F7, 0x63F9, 0x63FA, 0x63FB, 0x63FC, 0x63FE, 0x6403, 0x6404, 0x6406, 0x6407,
+		0x6408, 0x6409, 0x640A, 0x640D, 0x640E, 0x6411, 0x6412, 0x6415, 0x6416, 0x6417, 0x6418,
+		0x6419, 0x641A, 0x641D, 0x641F, 0x6422, 0x6423, 0x6424, 0x6425, 0x6427, 0x6428, 0x6429,
+		0x642B, 0x642E, 0x642F, 0x6430, 0x6431, 0x6432, 0x6433, 0x6435, 0x6436, 0x6437, 0x6438,
+		0x6439, 0x643B, 0x643C, 0x643E, 0x6440, 0x6442, 0x6443, 0x6449, 0x644B, 0x644C, 0x644D,
+		0x644E, 0x644F, 0x6450, 0x6451, 0x6453, 0x6455, 0x6456, 0x6457, 0x6459, 0x645A, 0x645B,
+		0x645C, 0x645D, 0x645F, 0x6460, 0x6461, 0x6462, 0x6463, 0x6464, 0x6465, 0x6466, 0x6468,
+		0x646A, 0x646B, 0x646C, 0x646E, 0x646F, 0x6470, 0x6471, 0x6472, 0x6473, 0x6474, 0x6475,
+		0x6476, 0x6477, 0x647B, 0x647C, 0x647D, 0x647E, 0x647F, 0x6480, 0x6481, 0x6483, 0x6486,
+		0x6488, 0x6489, 0x648A, 0x648B, 0x648C, 0x648D, 0x648E, 0x648F, 0x6490, 0x6493, 0x6494,
+		0x6497, 0x6498, 0x649A, 0x649B, 0x649C, 0x649D, 0x649F, 0x64A0, 0x64A1, 0x64A2, 0x64A3,
+		0x64A5, 0x64A6, 0x64A7, 0x64A8, 0x64AA, 0x64AB, 0x64AF, 0x64B1, 0x64B2, 0x64B3, 0x64B4,
+		0x64B6, 0x64B9, 0x64BB, 0x64BD, 0x64BE, 0x64BF, 0x64C1, 0x64C3, 0x64C4, 0x64C6, 0x64C7,
+		0x64C8, 0x64C9, 0x64CA, 0x64CB, 0x64CC, 0x64CF, 0x64D1, 0x64D3, 0x64D4, 0x64D5, 0x64D6,
+		0x64D9, 0x64DA, 0x64DB, 0x64DC, 0x64DD, 0x64DF, 0x64E0, 0x64E1, 0x64E3, 0x64E5, 0x64E7,
+		0x64E8, 0x64E9, 0x64EA, 0x64EB, 0x64EC, 0x64ED, 0x64EE, 0x64EF, 0x64F0, 0x64F1, 0x64F2,
+		0x64F3, 0x64F4, 0x64F5, 0x64F6, 0x64F7, 0x64F8, 0x64F9, 0x64FA, 0x64FB, 0x64FC, 0x64FD,
+		0x64FE, 0x64FF, 0x6501, 0x6502, 0x6503, 0x6504, 0x6505, 0x6506, 0x6507, 0x6508, 0x650A,
+		0x650B, 0x650C, 0x650D, 0x650E, 0x650F, 0x6510, 0x6511, 0x6513, 0x6514, 0x6515, 0x6516,
+		0x6517, 0x6519, 0x651A, 0x651B, 0x651C, 0x651D, 0x651E, 0x651F, 0x6520, 0x6521, 0x6522,
+		0x6523, 0x6524, 0x6526, 0x6527, 0x6528, 0x6529, 0x652A, 0x652C, 0x652D, 0x6530, 0x6531,
+		0x6532, 0x6533, 0x6537, 0x653A, 0x653C, 0x653D, 0x6540, 0x6541, 0x6542, 0x6543, 0x6544,
+		0x6546, 0x6547, 0x654A, 0x654B, 0x654D, 0x654E, 0x6550, 0x6552, 0x6553, 0x6554, 0x6557,
+		0x6558, 0x655A, 0x655C, 0x655F, 0x6560, 0x6561, 0x6564, 0x6565, 0x6567, 0x6568, 0x6569,
+		0x656A, 0x656D, 0x656E, 0x656F, 0x6571, 0x6573, 0x6575, 0x6576, 0x6578, 0x6579, 0x657A,
+		0x657B, 0x657C, 0x657D, 0x657E, 0x657F, 0x6580, 0x6581, 0x6582, 0x6583, 0x6584, 0x6585,
+		0x6586, 0x6588, 0x6589, 0x658A, 0x658D, 0x658E, 0x658F, 0x6592, 0x6594, 0x6595, 0x6596,
+		0x6598, 0x659A, 0x659D, 0x659E, 0x65A0, 0x65A2, 0x65A3, 0x65A6, 0x65A8, 0x65AA, 0x65AC,
+		0x65AE, 0x65B1, 0x65B2, 0x65B3, 0x65B4, 0x65B5, 0x65B6, 0x65B7, 0x65B8, 0x65BA, 0x65BB,
+		0x65BE, 0x65BF, 0x65C0, 0x65C2, 0x65C7, 0x65C8, 0x65C9, 0x65CA, 0x65CD, 0x65D0, 0x65D1,
+		0x65D3, 0x65D4, 0x65D5, 0x65D8, 0x65D9, 0x65DA, 0x65DB, 0x65DC, 0x65DD, 0x65DE, 0x65DF,
+		0x65E1, 0x65E3, 0x65E4, 0x65EA, 0x65EB, 0x65F2, 0x65F3, 0x65F4, 0x65F5, 0x65F8, 0x65F9,
+		0x65FB, 0x65FC, 0x65FD, 0x65FE, 0x65FF, 0x6601, 0x6604, 0x6605, 0x6607, 0x6608, 0x6609,
+		0x660B, 0x660D, 0x6610, 0x6611, 0x6612, 0x6616, 0x6617, 0x6618, 0x661A, 0x661B, 0x661C,
+		0x661E, 0x6621, 0x6622, 0x6623, 0x6624, 0x6626, 0x6629, 0x662A, 0x662B, 0x662C, 0x662E,
+		0x6630, 0x6632, 0x6633, 0x6637, 0x6638, 0x6639, 0x663A, 0x663B, 0x663D, 0x663F, 0x6640,
+		0x6642, 0x6644, 0x6645, 0x6646, 0x6647, 0x6648, 0x6649, 0x664A, 0x664D, 0x664E, 0x6650,
+		0x6651, 0x6658, 0x6659, 0x665B, 0x665C, 0x665D, 0x665E, 0x6660, 0x6662, 0x6663, 0x6665,
+		0x6667, 0x6669, 0x666A, 0x666B, 0x666C, 0x666D, 0x6671, 0x6672, 0x6673, 0x6675, 0x6678,
+		0x6679, 0x667B, 0x667C, 0x667D, 0x667F, 0x6680, 0x6681, 0x6683, 0x6685, 0x6686, 0x6688,
+		0x6689, 0x668A, 0x668B, 0x668D, 0x668E, 0x668F, 0x6690, 0x6692, 0x6693, 0x6694, 0x6695,
+		0x6698, 0x6699, 0x669A, 0x669B, 0x669C, 0x669E, 0x669F, 0x66A0, 0x66A1, 0x66A2, 0x66A3,
+		0x66A4, 0x66A5, 0x66A6, 0x66A9, 0x66AA, 0x66AB, 0x66AC, 0x66AD, 0x66AF, 0x66B0, 0x66B1,
+		0x66B2, 0x66B3, 0x66B5, 0x66B6, 0x66B7, 0x66B8, 0x66BA, 0x66BB, 0x66BC, 0x66BD, 0x66BF,
+		0x66C0, 0x66C1, 0x66C2, 0x66C3, 0x66C4, 0x66C5, 0x66C6, 0x66C7, 0x66C8, 0x66C9, 0x66CA,
+		0x66CB, 0x66CC, 0x66CD, 0x66CE, 0x66CF, 0x66D0, 0x66D1, 0x66D2, 0x66D3, 0x66D4, 0x66D5,
+		0x66D6, 0x66D7, 0x66D8, 0x66DA, 0x66DE, 0x66DF, 0x66E0, 0x66E1, 0x66E2, 0x66E3, 0x66E4,
+		0x66E5, 0x66E7, 0x66E8, 0x66EA, 0x66EB, 0x66EC, 0x66ED, 0x66EE, 0x66EF, 0x66F1, 0x66F5,
+		0x66F6, 0x66F8, 0x66FA, 0x66FB, 0x66FD, 0x6701, 0x6702, 0x6703, 0x6704, 0x6705, 0x6706,
+		0x6707, 0x670C, 0x670E, 0x670F, 0x6711, 0x6712, 0x6713, 0x6716, 0x6718, 0x6719, 0x671A,
+		0x671C, 0x671E, 0x6720, 0x6721, 0x6722, 0x6723, 0x6724, 0x6725, 0x6727, 0x6729, 0x672E,
+		0x6730, 0x6732, 0x6733, 0x6736, 0x6737, 0x6738, 0x6739, 0x673B, 0x673C, 0x673E, 0x673F,
+		0x6741, 0x6744, 0x6745, 0x6747, 0x674A, 0x674B, 0x674D, 0x6752, 0x6754, 0x6755, 0x6757,
+		0x6758, 0x6759, 0x675A, 0x675B, 0x675D, 0x6762, 0x6763, 0x6764, 0x6766, 0x6767, 0x676B,
+		0x676C, 0x676E, 0x6771, 0x6774, 0x6776, 0x6778, 0x6779, 0x677A, 0x677B, 0x677D, 0x6780,
+		0x6782, 0x6783, 0x6785, 0x6786, 0x6788, 0x678A, 0x678C, 0x678D, 0x678E, 0x678F, 0x6791,
+		0x6792, 0x6793, 0x6794, 0x6796, 0x6799, 0x679B, 0x679F, 0x67A0, 0x67A1, 0x67A4, 0x67A6,
+		0x67A9, 0x67AC, 0x67AE, 0x67B1, 0x67B2, 0x67B4, 0x67B9, 0x67BA, 0x67BB, 0x67BC, 0x67BD,
+		0x67BE, 0x67BF, 0x67C0, 0x67C2, 0x67C5, 0x67C6, 0x67C7, 0x67C8, 0x67C9, 0x67CA, 0x67CB,
+		0x67CC, 0x67CD, 0x67CE, 0x67D5, 0x67D6, 0x67D7, 0x67DB, 0x67DF, 0x67E1, 0x67E3, 0x67E4,
+		0x67E6, 0x67E7, 0x67E8, 0x67EA, 0x67EB, 0x67ED, 0x67EE, 0x67F2, 0x67F5, 0x67F6, 0x67F7,
+		0x67F8, 0x67F9, 0x67FA, 0x67FB, 0x67FC, 0x67FE, 0x6801, 0x6802, 0x6803, 0x6804, 0x6806,
+		0x680D, 0x6810, 0x6812, 0x6814, 0x6815, 0x6818, 0x6819, 0x681A, 0x681B, 0x681C, 0x681E,
+		0x681F, 0x6820, 0x6822, 0x6823, 0x6824, 0x6825, 0x6826, 0x6827, 0x6828, 0x682B, 0x682C,
+		0x682D, 0x682E, 0x682F, 0x6830, 0x6831, 0x6834, 0x6835, 0x6836, 0x683A, 0x683B, 0x683F,
+		0x6847, 0x684B, 0x684D, 0x684F, 0x6852, 0x6856, 0x6857, 0x6858, 0x6859, 0x685A, 0x685B,
+		0x685C, 0x685D, 0x685E, 0x685F, 0x686A, 0x686C, 0x686D, 0x686E, 0x686F, 0x6870, 0x6871,
+		0x6872, 0x6873, 0x6875, 0x6878, 0x6879, 0x687A, 0x687B, 0x687C, 0x687D, 0x687E, 0x687F,
+		0x6880, 0x6882, 0x6884, 0x6887, 0x6888, 0x6889, 0x688A, 0x688B, 0x688C, 0x688D, 0x688E,
+		0x6890, 0x6891, 0x6892, 0x6894, 0x6895, 0x6896, 0x6898, 0x6899, 0x689A, 0x689B, 0x689C,
+		0x689D, 0x689E, 0x689F, 0x68A0, 0x68A1, 0x68A3, 0x68A4, 0x68A5, 0x68A9, 0x68AA, 0x68AB,
+		0x68AC, 0x68AE, 0x68B1, 0x68B2, 0x68B4, 0x68B6, 0x68B7, 0x68B8, 0x68B9, 0x68BA, 0x68BB,
+		0x68BC, 0x68BD, 0x68BE, 0x68BF, 0x68C1, 0x68C3, 0x68C4, 0x68C5, 0x68C6, 0x68C7, 0x68C8,
+		0x68CA, 0x68CC, 0x68CE, 0x68CF, 0x68D0, 0x68D1, 0x68D3, 0x68D4, 0x68D6, 0x68D7, 0x68D9,
+		0x68DB, 0x68DC, 0x68DD, 0x68DE, 0x68DF, 0x68E1, 0x68E2, 0x68E4, 0x68E5, 0x68E6, 0x68E7,
+		0x68E8, 0x68E9, 0x68EA, 0x68EB, 0x68EC, 0x68ED, 0x68EF, 0x68F2, 0x68F3, 0x68F4, 0x68F6,
+		0x68F7, 0x68F8, 0x68FB, 0x68FD, 0x68FE, 0x68FF, 0x6900, 0x6902, 0x6903, 0x6904, 0x6906,
+		0x6907, 0x6908, 0x6909, 0x690A, 0x690C, 0x690F, 0x6911, 0x6913, 0x6914, 0x6915, 0x6916,
+		0x6917, 0x6918, 0x6919, 0x691A, 0x691B, 0x691C, 0x691D, 0x691E, 0x6921, 0x6922, 0x6923,
+		0x6925, 0x6926, 0x6927, 0x6928, 0x6929, 0x692A, 0x692B, 0x692C, 0x692E, 0x692F, 0x6931,
+		0x6932, 0x6933, 0x6935, 0x6936, 0x6937, 0x6938, 0x693A, 0x693B, 0x693C, 0x693E, 0x6940,
+		0x6941, 0x6943, 0x6944, 0x6945, 0x6946, 0x6947, 0x6948, 0x6949, 0x694A, 0x694B, 0x694C,
+		0x694D, 0x694E, 0x694F, 0x6950, 0x6951, 0x6952, 0x6953, 0x6955, 0x6956, 0x6958, 0x6959,
+		0x695B, 0x695C, 0x695F, 0x6961, 0x6962, 0x6964, 0x6965, 0x6967, 0x6968, 0x6969, 0x696A,
+		0x696C, 0x696D, 0x696F, 0x6970, 0x6972, 0x6973, 0x6974, 0x6975, 0x6976, 0x697A, 0x697B,
+		0x697D, 0x697E, 0x697F, 0x6981, 0x6983, 0x6985, 0x698A, 0x698B, 0x698C, 0x698E, 0x698F,
+		0x6990, 0x6991, 0x6992, 0x6993, 0x6996, 0x6997, 0x6999, 0x699A, 0x699D, 0x699E, 0x699F,
+		0x69A0, 0x69A1, 0x69A2, 0x69A3, 0x69A4, 0x69A5, 0x69A6, 0x69A9, 0x69AA, 0x69AC, 0x69AE,
+		0x69AF, 0x69B0, 0x69B2, 0x69B3, 0x69B5, 0x69B6, 0x69B8, 0x69B9, 0x69BA, 0x69BC, 0x69BD,
+		0x69BE, 0x69BF, 0x69C0, 0x69C2, 0x69C3, 0x69C4, 0x69C5, 0x69C6, 0x69C7, 0x69C8, 0x69C9,
+		0x69CB, 0x69CD, 0x69CF, 0x69D1, 0x69D2, 0x69D3, 0x69D5, 0x69D6, 0x69D7, 0x69D8, 0x69D9,
+		0x69DA, 0x69DC, 0x69DD, 0x69DE, 0x69E1, 0x69E2, 0x69E3, 0x69E4, 0x69E5, 0x69E6, 0x69E7,
+		0x69E8, 0x69E9, 0x69EA, 0x69EB, 0x69EC, 0x69EE, 0x69EF, 0x69F0, 0x69F1, 0x69F3, 0x69F4,
+		0x69F5, 0x69F6, 0x69F7, 0x69F8, 0x69F9, 0x69FA, 0x69FB, 0x69FC, 0x69FE, 0x6A00, 0x6A01,
+		0x6A02, 0x6A03, 0x6A04, 0x6A05, 0x6A06, 0x6A07, 0x6A08, 0x6A09, 0x6A0B, 0x6A0C, 0x6A0D,
+		0x6A0E, 0x6A0F, 0x6A10, 0x6A11, 0x6A12, 0x6A13, 0x6A14, 0x6A15, 0x6A16, 0x6A19, 0x6A1A,
+		0x6A1B, 0x6A1C, 0x6A1D, 0x6A1E, 0x6A20, 0x6A22, 0x6A23, 0x6A24, 0x6A25, 0x6A26, 0x6A27,
+		0x6A29, 0x6A2B, 0x6A2C, 0x6A2D, 0x6A2E, 0x6A30, 0x6A32, 0x6A33, 0x6A34, 0x6A36, 0x6A37,
+		0x6A38, 0x6A39, 0x6A3A, 0x6A3B, 0x6A3C, 0x6A3F, 0x6A40, 0x6A41, 0x6A42, 0x6A43, 0x6A45,
+		0x6A46, 0x6A48, 0x6A49, 0x6A4A, 0x6A4B, 0x6A4C, 0x6A4D, 0x6A4E, 0x6A4F, 0x6A51, 0x6A52,
+		0x6A53, 0x6A54, 0x6A55, 0x6A56, 0x6A57, 0x6A5A, 0x6A5C, 0x6A5D, 0x6A5E, 0x6A5F, 0x6A60,
+		0x6A62, 0x6A63, 0x6A64, 0x6A66, 0x6A67, 0x6A68, 0x6A69, 0x6A6A, 0x6A6B, 0x6A6C, 0x6A6D,
+		0x6A6E, 0x6A6F, 0x6A70, 0x6A72, 0x6A73, 0x6A74, 0x6A75, 0x6A76, 0x6A77, 0x6A78, 0x6A7A,
+		0x6A7B, 0x6A7D, 0x6A7E, 0x6A7F, 0x6A81, 0x6A82, 0x6A83, 0x6A85, 0x6A86, 0x6A87, 0x6A88,
+		0x6A89, 0x6A8A, 0x6A8B, 0x6A8C, 0x6A8D, 0x6A8F, 0x6A92, 0x6A93, 0x6A94, 0x6A95, 0x6A96,
+		0x6A98, 0x6A99, 0x6A9A, 0x6A9B, 0x6A9C, 0x6A9D, 0x6A9E, 0x6A9F, 0x6AA1, 0x6AA2, 0x6AA3,
+		0x6AA4, 0x6AA5, 0x6AA6, 0x6AA7, 0x6AA8, 0x6AAA, 0x6AAD, 0x6AAE, 0x6AAF, 0x6AB0, 0x6AB1,
+		0x6AB2, 0x6AB3, 0x6AB4, 0x6AB5, 0x6AB6, 0x6AB7, 0x6AB8, 0x6AB9, 0x6ABA, 0x6ABB, 0x6ABC,
+		0x6ABD, 0x6ABE, 0x6ABF, 0x6AC0, 0x6AC1, 0x6AC2, 0x6AC3, 0x6AC4, 0x6AC5, 0x6AC6, 0x6AC7,
+		0x6AC8, 0x6AC9, 0x6ACA, 0x6ACB, 0x6ACC, 0x6ACD, 0x6ACE, 0x6ACF, 0x6AD0, 0x6AD1, 0x6AD2,
+		0x6AD3, 0x6AD4, 0x6AD5, 0x6AD6, 0x6AD7, 0x6AD8, 0x6AD9, 0x6ADA, 0x6ADB, 0x6ADC, 0x6ADD,
+		0x6ADE, 0x6ADF, 0x6AE0, 0x6AE1, 0x6AE2, 0x6AE3, 0x6AE4, 0x6AE5, 0x6AE6, 0x6AE7, 0x6AE8,
+		0x6AE9, 0x6AEA, 0x6AEB, 0x6AEC, 0x6AED, 0x6AEE, 0x6AEF, 0x6AF0, 0x6AF1, 0x6AF2, 0x6AF3,
+		0x6AF4, 0x6AF5, 0x6AF6, 0x6AF7, 0x6AF8, 0x6AF9, 0x6AFA, 0x6AFB, 0x6AFC, 0x6AFD, 0x6AFE,
+		0x6AFF, 0x6B00, 0x6B01, 0x6B02, 0x6B03, 0x6B04, 0x6B05, 0x6B06, 0x6B07, 0x6B08, 0x6B09,
+		0x6B0A, 0x6B0B, 0x6B0C, 0x6B0D, 0x6B0E, 0x6B0F, 0x6B10, 0x6B11, 0x6B12, 0x6B13, 0x6B14,
+		0x6B15, 0x6B16, 0x6B17, 0x6B18, 0x6B19, 0x6B1A, 0x6B1B, 0x6B1C, 0x6B1D, 0x6B1E, 0x6B1F,
+		0x6B25, 0x6B26, 0x6B28, 0x6B29, 0x6B2A, 0x6B2B, 0x6B2C, 0x6B2D, 0x6B2E, 0x6B2F, 0x6B30,
+		0x6B31, 0x6B33, 0x6B34, 0x6B35, 0x6B36, 0x6B38, 0x6B3B, 0x6B3C, 0x6B3D, 0x6B3F, 0x6B40,
+		0x6B41, 0x6B42, 0x6B44, 0x6B45, 0x6B48, 0x6B4A, 0x6B4B, 0x6B4D, 0x6B4E, 0x6B4F, 0x6B50,
+		0x6B51, 0x6B52, 0x6B53, 0x6B54, 0x6B55, 0x6B56, 0x6B57, 0x6B58, 0x6B5A, 0x6B5B, 0x6B5C,
+		0x6B5D, 0x6B5E, 0x6B5F, 0x6B60, 0x6B61, 0x6B68, 0x6B69, 0x6B6B, 0x6B6C, 0x6B6D, 0x6B6E,
+		0x6B6F, 0x6B70, 0x6B71, 0x6B72, 0x6B73, 0x6B74, 0x6B75, 0x6B76, 0x6B77, 0x6B78, 0x6B7A,
+		0x6B7D, 0x6B7E, 0x6B7F, 0x6B80, 0x6B85, 0x6B88, 0x6B8C, 0x6B8E, 0x6B8F, 0x6B90, 0x6B91,
+		0x6B94, 0x6B95, 0x6B97, 0x6B98, 0x6B99, 0x6B9C, 0x6B9D, 0x6B9E, 0x6B9F, 0x6BA0, 0x6BA2,
+		0x6BA3, 0x6BA4, 0x6BA5, 0x6BA6, 0x6BA7, 0x6BA8, 0x6BA9, 0x6BAB, 0x6BAC, 0x6BAD, 0x6BAE,
+		0x6BAF, 0x6BB0, 0x6BB1, 0x6BB2, 0x6BB6, 0x6BB8, 0x6BB9, 0x6BBA, 0x6BBB, 0x6BBC, 0x6BBD,
+		0x6BBE, 0x6BC0, 0x6BC3, 0x6BC4, 0x6BC6, 0x6BC7, 0x6BC8, 0x6BC9, 0x6BCA, 0x6BCC, 0x6BCE,
+		0x6BD0, 0x6BD1, 0x6BD8, 0x6BDA, 0x6BDC, 0x6BDD, 0x6BDE, 0x6BDF, 0x6BE0, 0x6BE2, 0x6BE3,
+		0x6BE4, 0x6BE5, 0x6BE6, 0x6BE7, 0x6BE8, 0x6BE9, 0x6BEC, 0x6BED, 0x6BEE, 0x6BF0, 0x6BF1,
+		0x6BF2, 0x6BF4, 0x6BF6, 0x6BF7, 0x6BF8, 0x6BFA, 0x6BFB, 0x6BFC, 0x6BFE, 0x6BFF, 0x6C00,
+		0x6C01, 0x6C02, 0x6C03, 0x6C04, 0x6C08, 0x6C09, 0x6C0A, 0x6C0B, 0x6C0C, 0x6C0E, 0x6C12,
+		0x6C17, 0x6C1C, 0x6C1D, 0x6C1E, 0x6C20, 0x6C23, 0x6C25, 0x6C2B, 0x6C2C, 0x6C2D, 0x6C31,
+		0x6C33, 0x6C36, 0x6C37, 0x6C39, 0x6C3A, 0x6C3B, 0x6C3C, 0x6C3E, 0x6C3F, 0x6C43, 0x6C44,
+		0x6C45, 0x6C48, 0x6C4B, 0x6C4C, 0x6C4D, 0x6C4E, 0x6C4F, 0x6C51, 0x6C52, 0x6C53, 0x6C56,
+		0x6C58, 0x6C59, 0x6C5A, 0x6C62, 0x6C63, 0x6C65, 0x6C66, 0x6C67, 0x6C6B, 0x6C6C, 0x6C6D,
+		0x6C6E, 0x6C6F, 0x6C71, 0x6C73, 0x6C75, 0x6C77, 0x6C78, 0x6C7A, 0x6C7B, 0x6C7C, 0x6C7F,
+		0x6C80, 0x6C84, 0x6C87, 0x6C8A, 0x6C8B, 0x6C8D, 0x6C8E, 0x6C91, 0x6C92, 0x6C95, 0x6C96,
+		0x6C97, 0x6C98, 0x6C9A, 0x6C9C, 0x6C9D, 0x6C9E, 0x6CA0, 0x6CA2, 0x6CA8, 0x6CAC, 0x6CAF,
+		0x6CB0, 0x6CB4, 0x6CB5, 0x6CB6, 0x6CB7, 0x6CBA, 0x6CC0, 0x6CC1, 0x6CC2, 0x6CC3, 0x6CC6,
+		0x6CC7, 0x6CC8, 0x6CCB, 0x6CCD, 0x6CCE, 0x6CCF, 0x6CD1, 0x6CD2, 0x6CD8, 0x6CD9, 0x6CDA,
+		0x6CDC, 0x6CDD, 0x6CDF, 0x6CE4, 0x6CE6, 0x6CE7, 0x6CE9, 0x6CEC, 0x6CED, 0x6CF2, 0x6CF4,
+		0x6CF9, 0x6CFF, 0x6D00, 0x6D02, 0x6D03, 0x6D05, 0x6D06, 0x6D08, 0x6D09, 0x6D0A, 0x6D0D,
+		0x6D0F, 0x6D10, 0x6D11, 0x6D13, 0x6D14, 0x6D15, 0x6D16, 0x6D18, 0x6D1C, 0x6D1D, 0x6D1F,
+		0x6D20, 0x6D21, 0x6D22, 0x6D23, 0x6D24, 0x6D26, 0x6D28, 0x6D29, 0x6D2C, 0x6D2D, 0x6D2F,
+		0x6D30, 0x6D34, 0x6D36, 0x6D37, 0x6D38, 0x6D3A, 0x6D3F, 0x6D40, 0x6D42, 0x6D44, 0x6D49,
+		0x6D4C, 0x6D50, 0x6D55, 0x6D56, 0x6D57, 0x6D58, 0x6D5B, 0x6D5D, 0x6D5F, 0x6D61, 0x6D62,
+		0x6D64, 0x6D65, 0x6D67, 0x6D68, 0x6D6B, 0x6D6C, 0x6D6D, 0x6D70, 0x6D71, 0x6D72, 0x6D73,
+		0x6D75, 0x6D76, 0x6D79, 0x6D7A, 0x6D7B, 0x6D7D, 0x6D7E, 0x6D7F, 0x6D80, 0x6D81, 0x6D83,
+		0x6D84, 0x6D86, 0x6D87, 0x6D8A, 0x6D8B, 0x6D8D, 0x6D8F, 0x6D90, 0x6D92, 0x6D96, 0x6D97,
+		0x6D98, 0x6D99, 0x6D9A, 0x6D9C, 0x6DA2, 0x6DA5, 0x6DAC, 0x6DAD, 0x6DB0, 0x6DB1, 0x6DB3,
+		0x6DB4, 0x6DB6, 0x6DB7, 0x6DB9, 0x6DBA, 0x6DBB, 0x6DBC, 0x6DBD, 0x6DBE, 0x6DC1, 0x6DC2,
+		0x6DC3, 0x6DC8, 0x6DC9, 0x6DCA, 0x6DCD, 0x6DCE, 0x6DCF, 0x6DD0, 0x6DD2, 0x6DD3, 0x6DD4,
+		0x6DD5, 0x6DD7, 0x6DDA, 0x6DDB, 0x6DDC, 0x6DDF, 0x6DE2, 0x6DE3, 0x6DE5, 0x6DE7, 0x6DE8,
+		0x6DE9, 0x6DEA, 0x6DED, 0x6DEF, 0x6DF0, 0x6DF2, 0x6DF4, 0x6DF5, 0x6DF6, 0x6DF8, 0x6DFA,
+		0x6DFD, 0x6DFE, 0x6DFF, 0x6E00, 0x6E01, 0x6E02, 0x6E03, 0x6E04, 0x6E06, 0x6E07, 0x6E08,
+		0x6E09, 0x6E0B, 0x6E0F, 0x6E12, 0x6E13, 0x6E15, 0x6E18, 0x6E19, 0x6E1B, 0x6E1C, 0x6E1E,
+		0x6E1F, 0x6E22, 0x6E26, 0x6E27, 0x6E28, 0x6E2A, 0x6E2C, 0x6E2E, 0x6E30, 0x6E31, 0x6E33,
+		0x6E35, 0x6E36, 0x6E37, 0x6E39, 0x6E3B, 0x6E3C, 0x6E3D, 0x6E3E, 0x6E3F, 0x6E40, 0x6E41,
+		0x6E42, 0x6E45, 0x6E46, 0x6E47, 0x6E48, 0x6E49, 0x6E4A, 0x6E4B, 0x6E4C, 0x6E4F, 0x6E50,
+		0x6E51, 0x6E52, 0x6E55, 0x6E57, 0x6E59, 0x6E5A, 0x6E5C, 0x6E5D, 0x6E5E, 0x6E60, 0x6E61,
+		0x6E62, 0x6E63, 0x6E64, 0x6E65, 0x6E66, 0x6E67, 0x6E68, 0x6E69, 0x6E6A, 0x6E6C, 0x6E6D,
+		0x6E6F, 0x6E70, 0x6E71, 0x6E72, 0x6E73, 0x6E74, 0x6E75, 0x6E76, 0x6E77, 0x6E78, 0x6E79,
+		0x6E7A, 0x6E7B, 0x6E7C, 0x6E7D, 0x6E80, 0x6E81, 0x6E82, 0x6E84, 0x6E87, 0x6E88, 0x6E8A,
+		0x6E8B, 0x6E8C, 0x6E8D, 0x6E8E, 0x6E91, 0x6E92, 0x6E93, 0x6E94, 0x6E95, 0x6E96, 0x6E97,
+		0x6E99, 0x6E9A, 0x6E9B, 0x6E9D, 0x6E9E, 0x6EA0, 0x6EA1, 0x6EA3, 0x6EA4, 0x6EA6, 0x6EA8,
+		0x6EA9, 0x6EAB, 0x6EAC, 0x6EAD, 0x6EAE, 0x6EB0, 0x6EB3, 0x6EB5, 0x6EB8, 0x6EB9, 0x6EBC,
+		0x6EBE, 0x6EBF, 0x6EC0, 0x6EC3, 0x6EC4, 0x6EC5, 0x6EC6, 0x6EC8, 0x6EC9, 0x6ECA, 0x6ECC,
+		0x6ECD, 0x6ECE, 0x6ED0, 0x6ED2, 0x6ED6, 0x6ED8, 0x6ED9, 0x6EDB, 0x6EDC, 0x6EDD, 0x6EE3,
+		0x6EE7, 0x6EEA, 0x6EEB, 0x6EEC, 0x6EED, 0x6EEE, 0x6EEF, 0x6EF0, 0x6EF1, 0x6EF2, 0x6EF3,
+		0x6EF5, 0x6EF6, 0x6EF7, 0x6EF8, 0x6EFA, 0x6EFB, 0x6EFC, 0x6EFD, 0x6EFE, 0x6EFF, 0x6F00,
+		0x6F01, 0x6F03, 0x6F04, 0x6F05, 0x6F07, 0x6F08, 0x6F0A, 0x6F0B, 0x6F0C, 0x6F0D, 0x6F0E,
+		0x6F10, 0x6F11, 0x6F12, 0x6F16, 0x6F17, 0x6F18, 0x6F19, 0x6F1A, 0x6F1B, 0x6F1C, 0x6F1D,
+		0x6F1E, 0x6F1F, 0x6F21, 0x6F22, 0x6F23, 0x6F25, 0x6F26, 0x6F27, 0x6F28, 0x6F2C, 0x6F2E,
+		0x6F30, 0x6F32, 0x6F34, 0x6F35, 0x6F37, 0x6F38, 0x6F39, 0x6F3A, 0x6F3B, 0x6F3C, 0x6F3D,
+		0x6F3F, 0x6F40, 0x6F41, 0x6F42, 0x6F43, 0x6F44, 0x6F45, 0x6F48, 0x6F49, 0x6F4A, 0x6F4C,
+		0x6F4E, 0x6F4F, 0x6F50, 0x6F51, 0x6F52, 0x6F53, 0x6F54, 0x6F55, 0x6F56, 0x6F57, 0x6F59,
+		0x6F5A, 0x6F5B, 0x6F5D, 0x6F5F, 0x6F60, 0x6F61, 0x6F63, 0x6F64, 0x6F65, 0x6F67, 0x6F68,
+		0x6F69, 0x6F6A, 0x6F6B, 0x6F6C, 0x6F6F, 0x6F70, 0x6F71, 0x6F73, 0x6F75, 0x6F76, 0x6F77,
+		0x6F79, 0x6F7B, 0x6F7D, 0x6F7E, 0x6F7F, 0x6F80, 0x6F81, 0x6F82, 0x6F83, 0x6F85, 0x6F86,
+		0x6F87, 0x6F8A, 0x6F8B, 0x6F8F, 0x6F90, 0x6F91, 0x6F92, 0x6F93, 0x6F94, 0x6F95, 0x6F96,
+		0x6F97, 0x6F98, 0x6F99, 0x6F9A, 0x6F9B, 0x6F9D, 0x6F9E, 0x6F9F, 0x6FA0, 0x6FA2, 0x6FA3,
+		0x6FA4, 0x6FA5, 0x6FA6, 0x6FA8, 0x6FA9, 0x6FAA, 0x6FAB, 0x6FAC, 0x6FAD, 0x6FAE, 0x6FAF,
+		0x6FB0, 0x6FB1, 0x6FB2, 0x6FB4, 0x6FB5, 0x6FB7, 0x6FB8, 0x6FBA, 0x6FBB, 0x6FBC, 0x6FBD,
+		0x6FBE, 0x6FBF, 0x6FC1, 0x6FC3, 0x6FC4, 0x6FC5, 0x6FC6, 0x6FC7, 0x6FC8, 0x6FCA, 0x6FCB,
+		0x6FCC, 0x6FCD, 0x6FCE, 0x6FCF, 0x6FD0, 0x6FD3, 0x6FD4, 0x6FD5, 0x6FD6, 0x6FD7, 0x6FD8,
+		0x6FD9, 0x6FDA, 0x6FDB, 0x6FDC, 0x6FDD, 0x6FDF, 0x6FE2, 0x6FE3, 0x6FE4, 0x6FE5, 0x6FE6,
+		0x6FE7, 0x6FE8, 0x6FE9, 0x6FEA, 0x6FEB, 0x6FEC, 0x6FED, 0x6FF0, 0x6FF1, 0x6FF2, 0x6FF3,
+		0x6FF4, 0x6FF5, 0x6FF6, 0x6FF7, 0x6FF8, 0x6FF9, 0x6FFA, 0x6FFB, 0x6FFC, 0x6FFD, 0x6FFE,
+		0x6FFF, 0x7000, 0x7001, 0x7002, 0x7003, 0x7004, 0x7005, 0x7006, 0x7007, 0x7008, 0x7009,
+		0x700A, 0x700B, 0x700C, 0x700D, 0x700E, 0x700F, 0x7010, 0x7012, 0x7013, 0x7014, 0x7015,
+		0x7016, 0x7017, 0x7018, 0x7019, 0x701C, 0x701D, 0x701E, 0x701F, 0x7020, 0x7021, 0x7022,
+		0x7024, 0x7025, 0x7026, 0x7027, 0x7028, 0x7029, 0x702A, 0x702B, 0x702C, 0x702D, 0x702E,
+		0x702F, 0x7030, 0x7031, 0x7032, 0x7033, 0x7034, 0x7036, 0x7037, 0x7038, 0x703A, 0x703B,
+		0x703C, 0x703D, 0x703E, 0x703F, 0x7040, 0x7041, 0x7042, 0x7043, 0x7044, 0x7045, 0x7046,
+		0x7047, 0x7048, 0x7049, 0x704A, 0x704B, 0x704D, 0x704E, 0x7050, 0x7051, 0x7052, 0x7053,
+		0x7054, 0x7055, 0x7056, 0x7057, 0x7058, 0x7059, 0x705A, 0x705B, 0x705C, 0x705D, 0x705F,
+		0x7060, 0x7061, 0x7062, 0x7063, 0x7064, 0x7065, 0x7066, 0x7067, 0x7068, 0x7069, 0x706A,
+		0x706E, 0x7071, 0x7072, 0x7073, 0x7074, 0x7077, 0x7079, 0x707A, 0x707B, 0x707D, 0x7081,
+		0x7082, 0x7083, 0x7084, 0x7086, 0x7087, 0x7088, 0x708B, 0x708C, 0x708D, 0x708F, 0x7090,
+		0x7091, 0x7093, 0x7097, 0x7098, 0x709A, 0x709B, 0x709E, 0x709F, 0x70A0, 0x70A1, 0x70A2,
+		0x70A3, 0x70A4, 0x70A5, 0x70A6, 0x70A7, 0x70A8, 0x70A9, 0x70AA, 0x70B0, 0x70B2, 0x70B4,
+		0x70B5, 0x70B6, 0x70BA, 0x70BE, 0x70BF, 0x70C4, 0x70C5, 0x70C6, 0x70C7, 0x70C9, 0x70CB,
+		0x70CC, 0x70CD, 0x70CE, 0x70CF, 0x70D0, 0x70D1, 0x70D2, 0x70D3, 0x70D4, 0x70D5, 0x70D6,
+		0x70D7, 0x70DA, 0x70DC, 0x70DD, 0x70DE, 0x70E0, 0x70E1, 0x70E2, 0x70E3, 0x70E5, 0x70EA,
+		0x70EE, 0x70F0, 0x70F1, 0x70F2, 0x70F3, 0x70F4, 0x70F5, 0x70F6, 0x70F8, 0x70FA, 0x70FB,
+		0x70FC, 0x70FE, 0x70FF, 0x7100, 0x7101, 0x7102, 0x7103, 0x7104, 0x7105, 0x7106, 0x7107,
+		0x7108, 0x710B, 0x710C, 0x710D, 0x710E, 0x710F, 0x7111, 0x7112, 0x7114, 0x7117, 0x711B,
+		0x711C, 0x711D, 0x711E, 0x711F, 0x7120, 0x7121, 0x7122, 0x7123, 0x7124, 0x7125, 0x7127,
+		0x7128, 0x7129, 0x712A, 0x712B, 0x712C, 0x712D, 0x712E, 0x7132, 0x7133, 0x7134, 0x7135,
+		0x7137, 0x7138, 0x7139, 0x713A, 0x713B, 0x713C, 0x713D, 0x713E, 0x713F, 0x7140, 0x7141,
+		0x7142, 0x7143, 0x7144, 0x7146, 0x7147, 0x7148, 0x7149, 0x714B, 0x714D, 0x714F, 0x7150,
+		0x7151, 0x7152, 0x7153, 0x7154, 0x7155, 0x7156, 0x7157, 0x7158, 0x7159, 0x715A, 0x715B,
+		0x715D, 0x715F, 0x7160, 0x7161, 0x7162, 0x7163, 0x7165, 0x7169, 0x716A, 0x716B, 0x716C,
+		0x716D, 0x716F, 0x7170, 0x7171, 0x7174, 0x7175, 0x7176, 0x7177, 0x7179, 0x717B, 0x717C,
+		0x717E, 0x717F, 0x7180, 0x7181, 0x7182, 0x7183, 0x7185, 0x7186, 0x7187, 0x7188, 0x7189,
+		0x718B, 0x718C, 0x718D, 0x718E, 0x7190, 0x7191, 0x7192, 0x7193, 0x7195, 0x7196, 0x7197,
+		0x719A, 0x719B, 0x719C, 0x719D, 0x719E, 0x71A1, 0x71A2, 0x71A3, 0x71A4, 0x71A5, 0x71A6,
+		0x71A7, 0x71A9, 0x71AA, 0x71AB, 0x71AD, 0x71AE, 0x71AF, 0x71B0, 0x71B1, 0x71B2, 0x71B4,
+		0x71B6, 0x71B7, 0x71B8, 0x71BA, 0x71BB, 0x71BC, 0x71BD, 0x71BE, 0x71BF, 0x71C0, 0x71C1,
+		0x71C2, 0x71C4, 0x71C5, 0x71C6, 0x71C7, 0x71C8, 0x71C9, 0x71CA, 0x71CB, 0x71CC, 0x71CD,
+		0x71CF, 0x71D0, 0x71D1, 0x71D2, 0x71D3, 0x71D6, 0x71D7, 0x71D8, 0x71D9, 0x71DA, 0x71DB,
+		0x71DC, 0x71DD, 0x71DE, 0x71DF, 0x71E1, 0x71E2, 0x71E3, 0x71E4, 0x71E6, 0x71E8, 0x71E9,
+		0x71EA, 0x71EB, 0x71EC, 0x71ED, 0x71EF, 0x71F0, 0x71F1, 0x71F2, 0x71F3, 0x71F4, 0x71F5,
+		0x71F6, 0x71F7, 0x71F8, 0x71FA, 0x71FB, 0x71FC, 0x71FD, 0x71FE, 0x71FF, 0x7200, 0x7201,
+		0x7202, 0x7203, 0x7204, 0x7205, 0x7207, 0x7208, 0x7209, 0x720A, 0x720B, 0x720C, 0x720D,
+		0x720E, 0x720F, 0x7210, 0x7211, 0x7212, 0x7213, 0x7214, 0x7215, 0x7216, 0x7217, 0x7218,
+		0x7219, 0x721A, 0x721B, 0x721C, 0x721E, 0x721F, 0x7220, 0x7221, 0x7222, 0x7223, 0x7224,
+		0x7225, 0x7226, 0x7227, 0x7229, 0x722B, 0x722D, 0x722E, 0x722F, 0x7232, 0x7233, 0x7234,
+		0x723A, 0x723C, 0x723E, 0x7240, 0x7241, 0x7242, 0x7243, 0x7244, 0x7245, 0x7246, 0x7249,
+		0x724A, 0x724B, 0x724E, 0x724F, 0x7250, 0x7251, 0x7253, 0x7254, 0x7255, 0x7257, 0x7258,
+		0x725A, 0x725C, 0x725E, 0x7260, 0x7263, 0x7264, 0x7265, 0x7268, 0x726A, 0x726B, 0x726C,
+		0x726D, 0x7270, 0x7271, 0x7273, 0x7274, 0x7276, 0x7277, 0x7278, 0x727B, 0x727C, 0x727D,
+		0x7282, 0x7283, 0x7285, 0x7286, 0x7287, 0x7288, 0x7289, 0x728C, 0x728E, 0x7290, 0x7291,
+		0x7293, 0x7294, 0x7295, 0x7296, 0x7297, 0x7298, 0x7299, 0x729A, 0x729B, 0x729C, 0x729D,
+		0x729E, 0x72A0, 0x72A1, 0x72A2, 0x72A3, 0x72A4, 0x72A5, 0x72A6, 0x72A7, 0x72A8, 0x72A9,
+		0x72AA, 0x72AB, 0x72AE, 0x72B1, 0x72B2, 0x72B3, 0x72B5, 0x72BA, 0x72BB, 0x72BC, 0x72BD,
+		0x72BE, 0x72BF, 0x72C0, 0x72C5, 0x72C6, 0x72C7, 0x72C9, 0x72CA, 0x72CB, 0x72CC, 0x72CF,
+		0x72D1, 0x72D3, 0x72D4, 0x72D5, 0x72D6, 0x72D8, 0x72DA, 0x72DB, 0xE4C6, 0xE4C7, 0xE4C8,
+		0xE4C9, 0xE4CA, 0xE4CB, 0xE4CC, 0xE4CD, 0xE4CE, 0xE4CF, 0xE4D0, 0xE4D1, 0xE4D2, 0xE4D3,
+		0xE4D4, 0xE4D5, 0xE4D6, 0xE4D7, 0xE4D8, 0xE4D9, 0xE4DA, 0xE4DB, 0xE4DC, 0xE4DD, 0xE4DE,
+		0xE4DF, 0xE4E0, 0xE4E1, 0xE4E2, 0xE4E3, 0xE4E4, 0xE4E5, 0xE4E6, 0xE4E7, 0xE4E8, 0xE4E9,
+		0xE4EA, 0xE4EB, 0xE4EC, 0xE4ED, 0xE4EE, 0xE4EF, 0xE4F0, 0xE4F1, 0xE4F2, 0xE4F3, 0xE4F4,
+		0xE4F5, 0xE4F6, 0xE4F7, 0xE4F8, 0xE4F9, 0xE4FA, 0xE4FB, 0xE4FC, 0xE4FD, 0xE4FE, 0xE4FF,
+		0xE500, 0xE501, 0xE502, 0xE503, 0xE504, 0xE505, 0xE506, 0xE507, 0xE508, 0xE509, 0xE50A,
+		0xE50B, 0xE50C, 0xE50D, 0xE50E, 0xE50F, 0xE510, 0xE511, 0xE512, 0xE513, 0xE514, 0xE515,
+		0xE516, 0xE517, 0xE518, 0xE519, 0xE51A, 0xE51B, 0xE51C, 0xE51D, 0xE51E, 0xE51F, 0xE520,
+		0xE521, 0xE522, 0xE523, 0xE524, 0xE525, 0x3000, 0x3001, 0x3002, 0x00B7, 0x02C9, 0x02C7,
+		0x00A8, 0x3003, 0x3005, 0x2014, 0xFF5E, 0x2016, 0x2026, 0x2018, 0x2019, 0x201C, 0x201D,
+		0x3014, 0x3015, 0x3008, 0x3009, 0x300A, 0x300B, 0x300C, 0x300D, 0x300E, 0x300F, 0x3016,
+		0x3017, 0x3010, 0x3011, 0x00B1, 0x00D7, 0x00F7, 0x2236, 0x2227, 0x2228, 0x2211, 0x220F,
+		0x222A, 0x2229, 0x2208, 0x2237, 0x221A, 0x22A5, 0x2225, 0x2220, 0x2312, 0x2299, 0x222B,
+		0x222E, 0x2261, 0x224C, 0x2248, 0x223D, 0x221D, 0x2260, 0x226E, 0x226F, 0x2264, 0x2265,
+		0x221E, 0x2235, 0x2234, 0x2642, 0x2640, 0x00B0, 0x2032, 0x2033, 0x2103, 0xFF04, 0x00A4,
+		0xFFE0, 0xFFE1, 0x2030, 0x00A7, 0x2116, 0x2606, 0x2605, 0x25CB, 0x25CF, 0x25CE, 0x25C7,
+		0x25C6, 0x25A1, 0x25A0, 0x25B3, 0x25B2, 0x203B, 0x2192, 0x2190, 0x2191, 0x2193, 0x3013,
+		0xE526, 0xE527, 0xE528, 0xE529, 0xE52A, 0xE52B, 0xE52C, 0xE52D, 0xE52E, 0xE52F, 0xE530,
+		0xE531, 0xE532, 0xE533, 0xE534, 0xE535, 0xE536, 0xE537, 0xE538, 0xE539, 0xE53A, 0xE53B,
+		0xE53C, 0xE53D, 0xE53E, 0xE53F, 0xE540, 0xE541, 0xE542, 0xE543, 0xE544, 0xE545, 0xE546,
+		0xE547, 0xE548, 0xE549, 0xE54A, 0xE54B, 0xE54C, 0xE54D, 0xE54E, 0xE54F, 0xE550, 0xE551,
+		0xE552, 0xE553, 0xE554, 0xE555, 0xE556, 0xE557, 0xE558, 0xE559, 0xE55A, 0xE55B, 0xE55C,
+		0xE55D, 0xE55E, 0xE55F, 0xE560, 0xE561, 0xE562, 0xE563, 0xE564, 0xE565, 0xE566, 0xE567,
+		0xE568, 0xE569, 0xE56A, 0xE56B, 0xE56C, 0xE56D, 0xE56E, 0xE56F, 0xE570, 0xE571, 0xE572,
+		0xE573, 0xE574, 0xE575, 0xE576, 0xE577, 0xE578, 0xE579, 0xE57A, 0xE57B, 0xE57C, 0xE57D,
+		0xE57E, 0xE57F, 0xE580, 0xE581, 0xE582, 0xE583, 0xE584, 0xE585, 0x2170, 0x2171, 0x2172,
+		0x2173, 0x2174, 0x2175, 0x2176, 0x2177, 0x2178, 0x2179, 0xE766, 0xE767, 0xE768, 0xE769,
+		0xE76A, 0xE76B, 0x2488, 0x2489, 0x248A, 0x248B, 0x248C, 0x248D, 0x248E, 0x248F, 0x2490,
+		0x2491, 0x2492, 0x2493, 0x2494, 0x2495, 0x2496, 0x2497, 0x2498, 0x2499, 0x249A, 0x249B,
+		0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247A, 0x247B, 0x247C, 0x247D, 0x247E,
+		0x247F, 0x2480, 0x2481, 0x2482, 0x2483, 0x2484, 0x2485, 0x2486, 0x2487, 0x2460, 0x2461,
+		0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468, 0x2469, 0x20AC, 0xE76D, 0x3220,
+		0x3221, 0x3222, 0x3223, 0x3224, 0x3225, 0x3226, 0x3227, 0x3228, 0x3229, 0xE76E, 0xE76F,
+		0x2160, 0x2161, 0x2162, 0x2163, 0x2164, 0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x216A,
+		0x216B, 0xE770, 0xE771, 0xE586, 0xE587, 0xE588, 0xE589, 0xE58A, 0xE58B, 0xE58C, 0xE58D,
+		0xE58E, 0xE58F, 0xE590, 0xE591, 0xE592, 0xE593, 0xE594, 0xE595, 0xE596, 0xE597, 0xE598,
+		0xE599, 0xE59A, 0xE59B, 0xE59C, 0xE59D, 0xE59E, 0xE59F, 0xE5A0, 0xE5A1, 0xE5A2, 0xE5A3,
+		0xE5A4, 0xE5A5, 0xE5A6, 0xE5A7, 0xE5A8, 0xE5A9, 0xE5AA, 0xE5AB, 0xE5AC, 0xE5AD, 0xE5AE,
+		0xE5AF, 0xE5B0, 0xE5B1, 0xE5B2, 0xE5B3, 0xE5B4, 0xE5B5, 0xE5B6, 0xE5B7, 0xE5B8, 0xE5B9,
+		0xE5BA, 0xE5BB, 0xE5BC, 0xE5BD, 0xE5BE, 0xE5BF, 0xE5C0, 0xE5C1, 0xE5C2, 0xE5C3, 0xE5C4,
+		0xE5C5, 0xE5C6, 0xE5C7, 0xE5C8, 0xE5C9, 0xE5CA, 0xE5CB, 0xE5CC, 0xE5CD, 0xE5CE, 0xE5CF,
+		0xE5D0, 0xE5D1, 0xE5D2, 0xE5D3, 0xE5D4, 0xE5D5, 0xE5D6, 0xE5D7, 0xE5D8, 0xE5D9, 0xE5DA,
+		0xE5DB, 0xE5DC, 0xE5DD, 0xE5DE, 0xE5DF, 0xE5E0, 0xE5E1, 0xE5E2, 0xE5E3, 0xE5E4, 0xE5E5,
+		0xFF01, 0xFF02, 0xFF03, 0xFFE5, 0xFF05, 0xFF06, 0xFF07, 0xFF08, 0xFF09, 0xFF0A, 0xFF0B,
+		0xFF0C, 0xFF0D, 0xFF0E, 0xFF0F, 0xFF10, 0xFF11, 0xFF12, 0xFF13, 0xFF14, 0xFF15, 0xFF16,
+		0xFF17, 0xFF18, 0xFF19, 0xFF1A, 0xFF1B, 0xFF1C, 0xFF1D, 0xFF1E, 0xFF1F, 0xFF20, 0xFF21,
+		0xFF22, 0xFF23, 0xFF24, 0xFF25, 0xFF26, 0xFF27, 0xFF28, 0xFF29, 0xFF2A, 0xFF2B, 0xFF2C,
+		0xFF2D, 0xFF2E, 0xFF2F, 0xFF30, 0xFF31, 0xFF32, 0xFF33, 0xFF34, 0xFF35, 0xFF36, 0xFF37,
+		0xFF38, 0xFF39, 0xFF3A, 0xFF3B, 0xFF3C, 0xFF3D, 0xFF3E, 0xFF3F, 0xFF40, 0xFF41, 0xFF42,
+		0xFF43, 0xFF44, 0xFF45, 0xFF46, 0xFF47, 0xFF48, 0xFF49, 0xFF4A, 0xFF4B, 0xFF4C, 0xFF4D,
+		0xFF4E, 0xFF4F, 0xFF50, 0xFF51, 0xFF52, 0xFF53, 0xFF54, 0xFF55, 0xFF56, 0xFF57, 0xFF58,
+		0xFF59, 0xFF5A, 0xFF5B, 0xFF5C, 0xFF5D, 0xFFE3, 0xE5E6, 0xE5E7, 0xE5E8, 0xE5E9, 0xE5EA,
+		0xE5EB, 0xE5EC, 0xE5ED, 0xE5EE, 0xE5EF, 0xE5F0, 0xE5F1, 0xE5F2, 0xE5F3, 0xE5F4, 0xE5F5,
+		0xE5F6, 0xE5F7, 0xE5F8, 0xE5F9, 0xE5FA, 0xE5FB, 0xE5FC, 0xE5FD, 0xE5FE, 0xE5FF, 0xE600,
+		0xE601, 0xE602, 0xE603, 0xE604, 0xE605, 0xE606, 0xE607, 0xE608, 0xE609, 0xE60A, 0xE60B,
+		0xE60C, 0xE60D, 0xE60E, 0xE60F, 0xE610, 0xE611, 0xE612, 0xE613, 0xE614, 0xE615, 0xE616,
+		0xE617, 0xE618, 0xE619, 0xE61A, 0xE61B, 0xE61C, 0xE61D, 0xE61E, 0xE61F, 0xE620, 0xE621,
+		0xE622, 0xE623, 0xE624, 0xE625, 0xE626, 0xE627, 0xE628, 0xE629, 0xE62A, 0xE62B, 0xE62C,
+		0xE62D, 0xE62E, 0xE62F, 0xE630, 0xE631, 0xE632, 0xE633, 0xE634, 0xE635, 0xE636, 0xE637,
+		0xE638, 0xE639, 0xE63A, 0xE63B, 0xE63C, 0xE63D, 0xE63E, 0xE63F, 0xE640, 0xE641, 0xE642,
+		0xE643, 0xE644, 0xE645, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045, 0x3046, 0x3047, 0x3048,
+		0x3049, 0x304A, 0x304B, 0x304C, 0x304D, 0x304E, 0x304F, 0x3050, 0x3051, 0x3052, 0x3053,
+		0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059, 0x305A, 0x305B, 0x305C, 0x305D, 0x305E,
+		0x305F, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066, 0x3067, 0x3068, 0x3069,
+		0x306A, 0x306B, 0x306C, 0x306D, 0x306E, 0x306F, 0x3070, 0x3071, 0x3072, 0x3073, 0x3074,
+		0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307A, 0x307B, 0x307C, 0x307D, 0x307E, 0x307F,
+		0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086, 0x3087, 0x3088, 0x3089, 0x308A,
+		0x308B, 0x308C, 0x308D, 0x308E, 0x308F, 0x3090, 0x3091, 0x3092, 0x3093, 0xE772, 0xE773,
+		0xE774, 0xE775, 0xE776, 0xE777, 0xE778, 0xE779, 0xE77A, 0xE77B, 0xE77C, 0xE646, 0xE647,
+		0xE648, 0xE649, 0xE64A, 0xE64B, 0xE64C, 0xE64D, 0xE64E, 0xE64F, 0xE650, 0xE651, 0xE652,
+		0xE653, 0xE654, 0xE655, 0xE656, 0xE657, 0xE658, 0xE659, 0xE65A, 0xE65B, 0xE65C, 0xE65D,
+		0xE65E, 0xE65F, 0xE660, 0xE661, 0xE662, 0xE663, 0xE664, 0xE665, 0xE666, 0xE667, 0xE668,
+		0xE669, 0xE66A, 0xE66B, 0xE66C, 0xE66D, 0xE66E, 0xE66F, 0xE670, 0xE671, 0xE672, 0xE673,
+		0xE674, 0xE675, 0xE676, 0xE677, 0xE678, 0xE679, 0xE67A, 0xE67B, 0xE67C, 0xE67D, 0xE67E,
+		0xE67F, 0xE680, 0xE681, 0xE682, 0xE683, 0xE684, 0xE685, 0xE686, 0xE687, 0xE688, 0xE689,
+		0xE68A, 0xE68B, 0xE68C, 0xE68D, 0xE68E, 0xE68F, 0xE690, 0xE691, 0xE692, 0xE693, 0xE694,
+		0xE695, 0xE696, 0xE697, 0xE698, 0xE699, 0xE69A, 0xE69B, 0xE69C, 0xE69D, 0xE69E, 0xE69F,
+		0xE6A0, 0xE6A1, 0xE6A2, 0xE6A3, 0xE6A4, 0xE6A5, 0x30A1, 0x30A2, 0x30A3, 0x30A4, 0x30A5,
+		0x30A6, 0x30A7, 0x30A8, 0x30A9, 0x30AA, 0x30AB, 0x30AC, 0x30AD, 0x30AE, 0x30AF, 0x30B0,
+		0x30B1, 0x30B2, 0x30B3, 0x30B4, 0x30B5, 0x30B6, 0x30B7, 0x30B8, 0x30B9, 0x30BA, 0x30BB,
+		0x30BC, 0x30BD, 0x30BE, 0x30BF, 0x30C0, 0x30C1, 0x30C2, 0x30C3, 0x30C4, 0x30C5, 0x30C6,
+		0x30C7, 0x30C8, 0x30C9, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D0, 0x30D1,
+		0x30D2, 0x30D3, 0x30D4, 0x30D5, 0x30D6, 0x30D7, 0x30D8, 0x30D9, 0x30DA, 0x30DB, 0x30DC,
+		0x30DD, 0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E3, 0x30E4, 0x30E5, 0x30E6, 0x30E7,
+		0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EE, 0x30EF, 0x30F0, 0x30F1, 0x30F2,
+		0x30F3, 0x30F4, 0x30F5, 0x30F6, 0xE77D, 0xE77E, 0xE77F, 0xE780, 0xE781, 0xE782, 0xE783,
+		0xE784, 0xE6A6, 0xE6A7, 0xE6A8, 0xE6A9, 0xE6AA, 0xE6AB, 0xE6AC, 0xE6AD, 0xE6AE, 0xE6AF,
+		0xE6B0, 0xE6B1, 0xE6B2, 0xE6B3, 0xE6B4, 0xE6B5, 0xE6B6, 0xE6B7, 0xE6B8, 0xE6B9, 0xE6BA,
+		0xE6BB, 0xE6BC, 0xE6BD, 0xE6BE, 0xE6BF, 0xE6C0, 0xE6C1, 0xE6C2, 0xE6C3, 0xE6C4, 0xE6C5,
+		0xE6C6, 0xE6C7, 0xE6C8, 0xE6C9, 0xE6CA, 0xE6CB, 0xE6CC, 0xE6CD, 0xE6CE, 0xE6CF, 0xE6D0,
+		0xE6D1, 0xE6D2, 0xE6D3, 0xE6D4, 0xE6D5, 0xE6D6, 0xE6D7, 0xE6D8, 0xE6D9, 0xE6DA, 0xE6DB,
+		0xE6DC, 0xE6DD, 0xE6DE, 0xE6DF, 0xE6E0, 0xE6E1, 0xE6E2, 0xE6E3, 0xE6E4, 0xE6E5, 0xE6E6,
+		0xE6E7, 0xE6E8, 0xE6E9, 0xE6EA, 0xE6EB, 0xE6EC, 0xE6ED, 0xE6EE, 0xE6EF, 0xE6F0, 0xE6F1,
+		0xE6F2, 0xE6F3, 0xE6F4, 0xE6F5, 0xE6F6, 0xE6F7, 0xE6F8, 0xE6F9, 0xE6FA, 0xE6FB, 0xE6FC,
+		0xE6FD, 0xE6FE, 0xE6FF, 0xE700, 0xE701, 0xE702, 0xE703, 0xE704, 0xE705, 0x0391, 0x0392,
+		0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D,
+		0x039E, 0x039F, 0x03A0, 0x03A1, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9,
+		0xE785, 0xE786, 0xE787, 0xE788, 0xE789, 0xE78A, 0xE78B, 0xE78C, 0x03B1, 0x03B2, 0x03B3,
+		0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE,
+		0x03BF, 0x03C0, 0x03C1, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0xE78D,
+		0xE78E, 0xE78F, 0xE790, 0xE791, 0xE792, 0xE793, 0xFE35, 0xFE36, 0xFE39, 0xFE3A, 0xFE3F,
+		0xFE40, 0xFE3D, 0xFE3E, 0xFE41, 0xFE42, 0xFE43, 0xFE44, 0xE794, 0xE795, 0xFE3B, 0xFE3C,
+		0xFE37, 0xFE38, 0xFE31, 0xE796, 0xFE33, 0xFE34, 0xE797, 0xE798, 0xE799, 0xE79A, 0xE79B,
+		0xE79C, 0xE79D, 0xE79E, 0xE79F, 0xE706, 0xE707, 0xE708, 0xE709, 0xE70A, 0xE70B, 0xE70C,
+		0xE70D, 0xE70E, 0xE70F, 0xE710, 0xE711, 0xE712, 0xE713, 0xE714, 0xE715, 0xE716, 0xE717,
+		0xE718, 0xE719, 0xE71A, 0xE71B, 0xE71C, 0xE71D, 0xE71E, 0xE71F, 0xE720, 0xE721, 0xE722,
+		0xE723, 0xE724, 0xE725, 0xE726, 0xE727, 0xE728, 0xE729, 0xE72A, 0xE72B, 0xE72C, 0xE72D,
+		0xE72E, 0xE72F, 0xE730, 0xE731, 0xE732, 0xE733, 0xE734, 0xE735, 0xE736, 0xE737, 0xE738,
+		0xE739, 0xE73A, 0xE73B, 0xE73C, 0xE73D, 0xE73E, 0xE73F, 0xE740, 0xE741, 0xE742, 0xE743,
+		0xE744, 0xE745, 0xE746, 0xE747, 0xE748, 0xE749, 0xE74A, 0xE74B, 0xE74C, 0xE74D, 0xE74E,
+		0xE74F, 0xE750, 0xE751, 0xE752, 0xE753, 0xE754, 0xE755, 0xE756, 0xE757, 0xE758, 0xE759,
+		0xE75A, 0xE75B, 0xE75C, 0xE75D, 0xE75E, 0xE75F, 0xE760, 0xE761, 0xE762, 0xE763, 0xE764,
+		0xE765, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0401, 0x0416, 0x0417, 0x0418,
+		0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420, 0x0421, 0x0422, 0x0423,
+		0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B, 0x042C, 0x042D, 0x042E,
+		0x042F, 0xE7A0, 0xE7A1, 0xE7A2, 0xE7A3, 0xE7A4, 0xE7A5, 0xE7A6, 0xE7A7, 0xE7A8, 0xE7A9,
+		0xE7AA, 0xE7AB, 0xE7AC, 0xE7AD, 0xE7AE, 0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435,
+		0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E, 0x043F,
+		0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044A,
+		0x044B, 0x044C, 0x044D, 0x044E, 0x044F, 0xE7AF, 0xE7B0, 0xE7B1, 0xE7B2, 0xE7B3, 0xE7B4,
+		0xE7B5, 0xE7B6, 0xE7B7, 0xE7B8, 0xE7B9, 0xE7BA, 0xE7BB, 0x02CA, 0x02CB, 0x02D9, 0x2013,
+		0x2015, 0x2025, 0x2035, 0x2105, 0x2109, 0x2196, 0x2197, 0x2198, 0x2199, 0x2215, 0x221F,
+		0x2223, 0x2252, 0x2266, 0x2267, 0x22BF, 0x2550, 0x2551, 0x2552, 0x2553, 0x2554, 0x2555,
+		0x2556, 0x2557, 0x2558, 0x2559, 0x255A, 0x255B, 0x255C, 0x255D, 0x255E, 0x255F, 0x2560,
+		0x2561, 0x2562, 0x2563, 0x2564, 0x2565, 0x2566, 0x2567, 0x2568, 0x2569, 0x256A, 0x256B,
+		0x256C, 0x256D, 0x256E, 0x256F, 0x2570, 0x2571, 0x2572, 0x2573, 0x2581, 0x2582, 0x2583,
+		0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x2589, 0x258A, 0x258B, 0x258C, 0x258D, 0x258E,
+		0x258F, 0x2593, 0x2594, 0x2595, 0x25BC, 0x25BD, 0x25E2, 0x25E3, 0x25E4, 0x25E5, 0x2609,
+		0x2295, 0x3012, 0x301D, 0x301E, 0xE7BC, 0xE7BD, 0xE7BE, 0xE7BF, 0xE7C0, 0xE7C1, 0xE7C2,
+		0xE7C3, 0xE7C4, 0xE7C5, 0xE7C6, 0x0101, 0x00E1, 0x01CE, 0x00E0, 0x0113, 0x00E9, 0x011B,
+		0x00E8, 0x012B, 0x00ED, 0x01D0, 0x00EC, 0x014D, 0x00F3, 0x01D2, 0x00F2, 0x016B, 0x00FA,
+		0x01D4, 0x00F9, 0x01D6, 0x01D8, 0x01DA, 0x01DC, 0x00FC, 0x00EA, 0x0251, 0x1E3F, 0x0144,
+		0x0148, 0x01F9, 0x0261, 0xE7C9, 0xE7CA, 0xE7CB, 0xE7CC, 0x3105, 0x3106, 0x3107, 0x3108,
+		0x3109, 0x310A, 0x310B, 0x310C, 0x310D, 0x310E, 0x310F, 0x3110, 0x3111, 0x3112, 0x3113,
+		0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119, 0x311A, 0x311B, 0x311C, 0x311D, 0x311E,
+		0x311F, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129,
+		0xE7CD, 0xE7CE, 0xE7CF, 0xE7D0, 0xE7D1, 0xE7D2, 0xE7D3, 0xE7D4, 0xE7D5, 0xE7D6, 0xE7D7,
+		0xE7D8, 0xE7D9, 0xE7DA, 0xE7DB, 0xE7DC, 0xE7DD, 0xE7DE, 0xE7DF, 0xE7E0, 0xE7E1, 0x3021,
+		0x3022, 0x3023, 0x3024, 0x3025, 0x3026, 0x3027, 0x3028, 0x3029, 0x32A3, 0x338E, 0x338F,
+		0x339C, 0x339D, 0x339E, 0x33A1, 0x33C4, 0x33CE, 0x33D1, 0x33D2, 0x33D5, 0xFE30, 0xFFE2,
+		0xFFE4, 0xE7E2, 0x2121, 0x3231, 0xE7E3, 0x2010, 0xE7E4, 0xE7E5, 0xE7E6, 0x30FC, 0x309B,
+		0x309C, 0x30FD, 0x30FE, 0x3006, 0x309D, 0x309E, 0xFE49, 0xFE4A, 0xFE4B, 0xFE4C, 0xFE4D,
+		0xFE4E, 0xFE4F, 0xFE50, 0xFE51, 0xFE52, 0xFE54, 0xFE55, 0xFE56, 0xFE57, 0xFE59, 0xFE5A,
+		0xFE5B, 0xFE5C, 0xFE5D, 0xFE5E, 0xFE5F, 0xFE60, 0xFE61, 0xFE62, 0xFE63, 0xFE64, 0xFE65,
+		0xFE66, 0xFE68, 0xFE69, 0xFE6A, 0xFE6B, 0x303E, 0x2FF0, 0x2FF1, 0x2FF2, 0x2FF3, 0x2FF4,
+		0x2FF5, 0x2FF6, 0x2FF7, 0x2FF8, 0x2FF9, 0x2FFA, 0x2FFB, 0x3007, 0xE7F4, 0xE7F5, 0xE7F6,
+		0xE7F7, 0xE7F8, 0xE7F9, 0xE7FA, 0xE7FB, 0xE7FC, 0xE7FD, 0xE7FE, 0xE7FF, 0xE800, 0x2500,
+		0x2501, 0x2502, 0x2503, 0x2504, 0x2505, 0x2506, 0x2507, 0x2508, 0x2509, 0x250A, 0x250B,
+		0x250C, 0x250D, 0x250E, 0x250F, 0x2510, 0x2511, 0x2512, 0x2513, 0x2514, 0x2515, 0x2516,
+		0x2517, 0x2518, 0x2519, 0x251A, 0x251B, 0x251C, 0x251D, 0x251E, 0x251F, 0x2520, 0x2521,
+		0x2522, 0x2523, 0x2524, 0x2525, 0x2526, 0x2527, 0x2528, 0x2529, 0x252A, 0x252B, 0x252C,
+		0x252D, 0x252E, 0x252F, 0x2530, 0x2531, 0x2532, 0x2533, 0x2534, 0x2535, 0x2536, 0x2537,
+		0x2538, 0x2539, 0x253A, 0x253B, 0x253C, 0x253D, 0x253E, 0x253F, 0x2540, 0x2541, 0x2542,
+		0x2543, 0x2544, 0x2545, 0x2546, 0x2547, 0x2548, 0x2549, 0x254A, 0x254B, 0xE801, 0xE802,
+		0xE803, 0xE804, 0xE805, 0xE806, 0xE807, 0xE808, 0xE809, 0xE80A, 0xE80B, 0xE80C, 0xE80D,
+		0xE80E, 0xE80F, 0x72DC, 0x72DD, 0x72DF, 0x72E2, 0x72E3, 0x72E4, 0x72E5, 0x72E6, 0x72E7,
+		0x72EA, 0x72EB, 0x72F5, 0x72F6, 0x72F9, 0x72FD, 0x72FE, 0x72FF, 0x7300, 0x7302, 0x7304,
+		0x7305, 0x7306, 0x7307, 0x7308, 0x7309, 0x730B, 0x730C, 0x730D, 0x730F, 0x7310, 0x7311,
+		0x7312, 0x7314, 0x7318, 0x7319, 0x731A, 0x731F, 0x7320, 0x7323, 0x7324, 0x7326, 0x7327,
+		0x7328, 0x732D, 0x732F, 0x7330, 0x7332, 0x7333, 0x7335, 0x7336, 0x733A, 0x733B, 0x733C,
+		0x733D, 0x7340, 0x7341, 0x7342, 0x7343, 0x7344, 0x7345, 0x7346, 0x7347, 0x7348, 0x7349,
+		0x734A, 0x734B, 0x734C, 0x734E, 0x734F, 0x7351, 0x7353, 0x7354, 0x7355, 0x7356, 0x7358,
+		0x7359, 0x735A, 0x735B, 0x735C, 0x735D, 0x735E, 0x735F, 0x7361, 0x7362, 0x7363, 0x7364,
+		0x7365, 0x7366, 0x7367, 0x7368, 0x7369, 0x736A, 0x736B, 0x736E, 0x7370, 0x7371, 0xE000,
+		0xE001, 0xE002, 0xE003, 0xE004, 0xE005, 0xE006, 0xE007, 0xE008, 0xE009, 0xE00A, 0xE00B,
+		0xE00C, 0xE00D, 0xE00E, 0xE00F, 0xE010, 0xE011, 0xE012, 0xE013, 0xE014, 0xE015, 0xE016,
+		0xE017, 0xE018, 0xE019, 0xE01A, 0xE01B, 0xE01C, 0xE01D, 0xE01E, 0xE01F, 0xE020, 0xE021,
+		0xE022, 0xE023, 0xE024, 0xE025, 0xE026, 0xE027, 0xE028, 0xE029, 0xE02A, 0xE02B, 0xE02C,
+		0xE02D, 0xE02E, 0xE02F, 0xE030, 0xE031, 0xE032, 0xE033, 0xE034, 0xE035, 0xE036, 0xE037,
+		0xE038, 0xE039, 0xE03A, 0xE03B, 0xE03C, 0xE03D, 0xE03E, 0xE03F, 0xE040, 0xE041, 0xE042,
+		0xE043, 0xE044, 0xE045, 0xE046, 0xE047, 0xE048, 0xE049, 0xE04A, 0xE04B, 0xE04C, 0xE04D,
+		0xE04E, 0xE04F, 0xE050, 0xE051, 0xE052, 0xE053, 0xE054, 0xE055, 0xE056, 0xE057, 0xE058,
+		0xE059, 0xE05A, 0xE05B, 0xE05C, 0xE05D, 0x7372, 0x7373, 0x7374, 0x7375, 0x7376, 0x7377,
+		0x7378, 0x7379, 0x737A, 0x737B, 0x737C, 0x737D, 0x737F, 0x7380, 0x7381, 0x7382, 0x7383,
+		0x7385, 0x7386, 0x7388, 0x738A, 0x738C, 0x738D, 0x738F, 0x7390, 0x7392, 0x7393, 0x7394,
+		0x7395, 0x7397, 0x7398, 0x7399, 0x739A, 0x739C, 0x739D, 0x739E, 0x73A0, 0x73A1, 0x73A3,
+		0x73A4, 0x73A5, 0x73A6, 0x73A7, 0x73A8, 0x73AA, 0x73AC, 0x73AD, 0x73B1, 0x73B4, 0x73B5,
+		0x73B6, 0x73B8, 0x73B9, 0x73BC, 0x73BD, 0x73BE, 0x73BF, 0x73C1, 0x73C3, 0x73C4, 0x73C5,
+		0x73C6, 0x73C7, 0x73CB, 0x73CC, 0x73CE, 0x73D2, 0x73D3, 0x73D4, 0x73D5, 0x73D6, 0x73D7,
+		0x73D8, 0x73DA, 0x73DB, 0x73DC, 0x73DD, 0x73DF, 0x73E1, 0x73E2, 0x73E3, 0x73E4, 0x73E6,
+		0x73E8, 0x73EA, 0x73EB, 0x73EC, 0x73EE, 0x73EF, 0x73F0, 0x73F1, 0x73F3, 0x73F4, 0x73F5,
+		0x73F6, 0x73F7, 0xE05E, 0xE05F, 0xE060, 0xE061, 0xE062, 0xE063, 0xE064, 0xE065, 0xE066,
+		0xE067, 0xE068, 0xE069, 0xE06A, 0xE06B, 0xE06C, 0xE06D, 0xE06E, 0xE06F, 0xE070, 0xE071,
+		0xE072, 0xE073, 0xE074, 0xE075, 0xE076, 0xE077, 0xE078, 0xE079, 0xE07A, 0xE07B, 0xE07C,
+		0xE07D, 0xE07E, 0xE07F, 0xE080, 0xE081, 0xE082, 0xE083, 0xE084, 0xE085, 0xE086, 0xE087,
+		0xE088, 0xE089, 0xE08A, 0xE08B, 0xE08C, 0xE08D, 0xE08E, 0xE08F, 0xE090, 0xE091, 0xE092,
+		0xE093, 0xE094, 0xE095, 0xE096, 0xE097, 0xE098, 0xE099, 0xE09A, 0xE09B, 0xE09C, 0xE09D,
+		0xE09E, 0xE09F, 0xE0A0, 0xE0A1, 0xE0A2, 0xE0A3, 0xE0A4, 0xE0A5, 0xE0A6, 0xE0A7, 0xE0A8,
+		0xE0A9, 0xE0AA, 0xE0AB, 0xE0AC, 0xE0AD, 0xE0AE, 0xE0AF, 0xE0B0, 0xE0B1, 0xE0B2, 0xE0B3,
+		0xE0B4, 0xE0B5, 0xE0B6, 0xE0B7, 0xE0B8, 0xE0B9, 0xE0BA, 0xE0BB, 0x73F8, 0x73F9, 0x73FA,
+		0x73FB, 0x73FC, 0x73FD, 0x73FE, 0x73FF, 0x7400, 0x7401, 0x7402, 0x7404, 0x7407, 0x7408,
+		0x740B, 0x740C, 0x740D, 0x740E, 0x7411, 0x7412, 0x7413, 0x7414, 0x7415, 0x7416, 0x7417,
+		0x7418, 0x7419, 0x741C, 0x741D, 0x741E, 0x741F, 0x7420, 0x7421, 0x7423, 0x7424, 0x7427,
+		0x7429, 0x742B, 0x742D, 0x742F, 0x7431, 0x7432, 0x7437, 0x7438, 0x7439, 0x743A, 0x743B,
+		0x743D, 0x743E, 0x743F, 0x7440, 0x7442, 0x7443, 0x7444, 0x7445, 0x7446, 0x7447, 0x7448,
+		0x7449, 0x744A, 0x744B, 0x744C, 0x744D, 0x744E, 0x744F, 0x7450, 0x7451, 0x7452, 0x7453,
+		0x7454, 0x7456, 0x7458, 0x745D, 0x7460, 0x7461, 0x7462, 0x7463, 0x7464, 0x7465, 0x7466,
+		0x7467, 0x7468, 0x7469, 0x746A, 0x746B, 0x746C, 0x746E, 0x746F, 0x7471, 0x7472, 0x7473,
+		0x7474, 0x7475, 0x7478, 0x7479, 0x747A, 0xE0BC, 0xE0BD, 0xE0BE, 0xE0BF, 0xE0C0, 0xE0C1,
+		0xE0C2, 0xE0C3, 0xE0C4, 0xE0C5, 0xE0C6, 0xE0C7, 0xE0C8, 0xE0C9, 0xE0CA, 0xE0CB, 0xE0CC,
+		0xE0CD, 0xE0CE, 0xE0CF, 0xE0D0, 0xE0D1, 0xE0D2, 0xE0D3, 0xE0D4, 0xE0D5, 0xE0D6, 0xE0D7,
+		0xE0D8, 0xE0D9, 0xE0DA, 0xE0DB, 0xE0DC, 0xE0DD, 0xE0DE, 0xE0DF, 0xE0E0, 0xE0E1, 0xE0E2,
+		0xE0E3, 0xE0E4, 0xE0E5, 0xE0E6, 0xE0E7, 0xE0E8, 0xE0E9, 0xE0EA, 0xE0EB, 0xE0EC, 0xE0ED,
+		0xE0EE, 0xE0EF, 0xE0F0, 0xE0F1, 0xE0F2, 0xE0F3, 0xE0F4, 0xE0F5, 0xE0F6, 0xE0F7, 0xE0F8,
+		0xE0F9, 0xE0FA, 0xE0FB, 0xE0FC, 0xE0FD, 0xE0FE, 0xE0FF, 0xE100, 0xE101, 0xE102, 0xE103,
+		0xE104, 0xE105, 0xE106, 0xE107, 0xE108, 0xE109, 0xE10A, 0xE10B, 0xE10C, 0xE10D, 0xE10E,
+		0xE10F, 0xE110, 0xE111, 0xE112, 0xE113, 0xE114, 0xE115, 0xE116, 0xE117, 0xE118, 0xE119,
+		0x747B, 0x747C, 0x747D, 0x747F, 0x7482, 0x7484, 0x7485, 0x7486, 0x7488, 0x7489, 0x748A,
+		0x748C, 0x748D, 0x748F, 0x7491, 0x7492, 0x7493, 0x7494, 0x7495, 0x7496, 0x7497, 0x7498,
+		0x7499, 0x749A, 0x749B, 0x749D, 0x749F, 0x74A0, 0x74A1, 0x74A2, 0x74A3, 0x74A4, 0x74A5,
+		0x74A6, 0x74AA, 0x74AB, 0x74AC, 0x74AD, 0x74AE, 0x74AF, 0x74B0, 0x74B1, 0x74B2, 0x74B3,
+		0x74B4, 0x74B5, 0x74B6, 0x74B7, 0x74B8, 0x74B9, 0x74BB, 0x74BC, 0x74BD, 0x74BE, 0x74BF,
+		0x74C0, 0x74C1, 0x74C2, 0x74C3, 0x74C4, 0x74C5, 0x74C6, 0x74C7, 0x74C8, 0x74C9, 0x74CA,
+		0x74CB, 0x74CC, 0x74CD, 0x74CE, 0x74CF, 0x74D0, 0x74D1, 0x74D3, 0x74D4, 0x74D5, 0x74D6,
+		0x74D7, 0x74D8, 0x74D9, 0x74DA, 0x74DB, 0x74DD, 0x74DF, 0x74E1, 0x74E5, 0x74E7, 0x74E8,
+		0x74E9, 0x74EA, 0x74EB, 0x74EC, 0x74ED, 0x74F0, 0x74F1, 0x74F2, 0xE11A, 0xE11B, 0xE11C,
+		0xE11D, 0xE11E, 0xE11F, 0xE120, 0xE121, 0xE122, 0xE123, 0xE124, 0xE125, 0xE126, 0xE127,
+		0xE128, 0xE129, 0xE12A, 0xE12B, 0xE12C, 0xE12D, 0xE12E, 0xE12F, 0xE130, 0xE131, 0xE132,
+		0xE133, 0xE134, 0xE135, 0xE136, 0xE137, 0xE138, 0xE139, 0xE13A, 0xE13B, 0xE13C, 0xE13D,
+		0xE13E, 0xE13F, 0xE140, 0xE141, 0xE142, 0xE143, 0xE144, 0xE145, 0xE146, 0xE147, 0xE148,
+		0xE149, 0xE14A, 0xE14B, 0xE14C, 0xE14D, 0xE14E, 0xE14F, 0xE150, 0xE151, 0xE152, 0xE153,
+		0xE154, 0xE155, 0xE156, 0xE157, 0xE158, 0xE159, 0xE15A, 0xE15B, 0xE15C, 0xE15D, 0xE15E,
+		0xE15F, 0xE160, 0xE161, 0xE162, 0xE163, 0xE164, 0xE165, 0xE166, 0xE167, 0xE168, 0xE169,
+		0xE16A, 0xE16B, 0xE16C, 0xE16D, 0xE16E, 0xE16F, 0xE170, 0xE171, 0xE172, 0xE173, 0xE174,
+		0xE175, 0xE176, 0xE177, 0x74F3, 0x74F5, 0x74F8, 0x74F9, 0x74FA, 0x74FB, 0x74FC, 0x74FD,
+		0x74FE, 0x7500, 0x7501, 0x7502, 0x7503, 0x7505, 0x7506, 0x7507, 0x7508, 0x7509, 0x750A,
+		0x750B, 0x750C, 0x750E, 0x7510, 0x7512, 0x7514, 0x7515, 0x7516, 0x7517, 0x751B, 0x751D,
+		0x751E, 0x7520, 0x7521, 0x7522, 0x7523, 0x7524, 0x7526, 0x7527, 0x752A, 0x752E, 0x7534,
+		0x7536, 0x7539, 0x753C, 0x753D, 0x753F, 0x7541, 0x7542, 0x7543, 0x7544, 0x7546, 0x7547,
+		0x7549, 0x754A, 0x754D, 0x7550, 0x7551, 0x7552, 0x7553, 0x7555, 0x7556, 0x7557, 0x7558,
+		0x755D, 0x755E, 0x755F, 0x7560, 0x7561, 0x7562, 0x7563, 0x7564, 0x7567, 0x7568, 0x7569,
+		0x756B, 0x756C, 0x756D, 0x756E, 0x756F, 0x7570, 0x7571, 0x7573, 0x7575, 0x7576, 0x7577,
+		0x757A, 0x757B, 0x757C, 0x757D, 0x757E, 0x7580, 0x7581, 0x7582, 0x7584, 0x7585, 0x7587,
+		0xE178, 0xE179, 0xE17A, 0xE17B, 0xE17C, 0xE17D, 0xE17E, 0xE17F, 0xE180, 0xE181, 0xE182,
+		0xE183, 0xE184, 0xE185, 0xE186, 0xE187, 0xE188, 0xE189, 0xE18A, 0xE18B, 0xE18C, 0xE18D,
+		0xE18E, 0xE18F, 0xE190, 0xE191, 0xE192, 0xE193, 0xE194, 0xE195, 0xE196, 0xE197, 0xE198,
+		0xE199, 0xE19A, 0xE19B, 0xE19C, 0xE19D, 0xE19E, 0xE19F, 0xE1A0, 0xE1A1, 0xE1A2, 0xE1A3,
+		0xE1A4, 0xE1A5, 0xE1A6, 0xE1A7, 0xE1A8, 0xE1A9, 0xE1AA, 0xE1AB, 0xE1AC, 0xE1AD, 0xE1AE,
+		0xE1AF, 0xE1B0, 0xE1B1, 0xE1B2, 0xE1B3, 0xE1B4, 0xE1B5, 0xE1B6, 0xE1B7, 0xE1B8, 0xE1B9,
+		0xE1BA, 0xE1BB, 0xE1BC, 0xE1BD, 0xE1BE, 0xE1BF, 0xE1C0, 0xE1C1, 0xE1C2, 0xE1C3, 0xE1C4,
+		0xE1C5, 0xE1C6, 0xE1C7, 0xE1C8, 0xE1C9, 0xE1CA, 0xE1CB, 0xE1CC, 0xE1CD, 0xE1CE, 0xE1CF,
+		0xE1D0, 0xE1D1, 0xE1D2, 0xE1D3, 0xE1D4, 0xE1D5, 0x7588, 0x7589, 0x758A, 0x758C, 0x758D,
+		0x758E, 0x7590, 0x7593, 0x7595, 0x7598, 0x759B, 0x759C, 0x759E, 0x75A2, 0x75A6, 0x75A7,
+		0x75A8, 0x75A9, 0x75AA, 0x75AD, 0x75B6, 0x75B7, 0x75BA, 0x75BB, 0x75BF, 0x75C0, 0x75C1,
+		0x75C6, 0x75CB, 0x75CC, 0x75CE, 0x75CF, 0x75D0, 0x75D1, 0x75D3, 0x75D7, 0x75D9, 0x75DA,
+		0x75DC, 0x75DD, 0x75DF, 0x75E0, 0x75E1, 0x75E5, 0x75E9, 0x75EC, 0x75ED, 0x75EE, 0x75EF,
+		0x75F2, 0x75F3, 0x75F5, 0x75F6, 0x75F7, 0x75F8, 0x75FA, 0x75FB, 0x75FD, 0x75FE, 0x7602,
+		0x7604, 0x7606, 0x7607, 0x7608, 0x7609, 0x760B, 0x760D, 0x760E, 0x760F, 0x7611, 0x7612,
+		0x7613, 0x7614, 0x7616, 0x761A, 0x761C, 0x761D, 0x761E, 0x7621, 0x7623, 0x7627, 0x7628,
+		0x762C, 0x762E, 0x762F, 0x7631, 0x7632, 0x7636, 0x7637, 0x7639, 0x763A, 0x763B, 0x763D,
+		0x7641, 0x7642, 0x7644, 0xE1D6, 0xE1D7, 0xE1D8, 0xE1D9, 0xE1DA, 0xE1DB, 0xE1DC, 0xE1DD,
+		0xE1DE, 0xE1DF, 0xE1E0, 0xE1E1, 0xE1E2, 0xE1E3, 0xE1E4, 0xE1E5, 0xE1E6, 0xE1E7, 0xE1E8,
+		0xE1E9, 0xE1EA, 0xE1EB, 0xE1EC, 0xE1ED, 0xE1EE, 0xE1EF, 0xE1F0, 0xE1F1, 0xE1F2, 0xE1F3,
+		0xE1F4, 0xE1F5, 0xE1F6, 0xE1F7, 0xE1F8, 0xE1F9, 0xE1FA, 0xE1FB, 0xE1FC, 0xE1FD, 0xE1FE,
+		0xE1FF, 0xE200, 0xE201, 0xE202, 0xE203, 0xE204, 0xE205, 0xE206, 0xE207, 0xE208, 0xE209,
+		0xE20A, 0xE20B, 0xE20C, 0xE20D, 0xE20E, 0xE20F, 0xE210, 0xE211, 0xE212, 0xE213, 0xE214,
+		0xE215, 0xE216, 0xE217, 0xE218, 0xE219, 0xE21A, 0xE21B, 0xE21C, 0xE21D, 0xE21E, 0xE21F,
+		0xE220, 0xE221, 0xE222, 0xE223, 0xE224, 0xE225, 0xE226, 0xE227, 0xE228, 0xE229, 0xE22A,
+		0xE22B, 0xE22C, 0xE22D, 0xE22E, 0xE22F, 0xE230, 0xE231, 0xE232, 0xE233, 0x7645, 0x7646,
+		0x7647, 0x7648, 0x7649, 0x764A, 0x764B, 0x764E, 0x764F, 0x7650, 0x7651, 0x7652, 0x7653,
+		0x7655, 0x7657, 0x7658, 0x7659, 0x765A, 0x765B, 0x765D, 0x765F, 0x7660, 0x7661, 0x7662,
+		0x7664, 0x7665, 0x7666, 0x7667, 0x7668, 0x7669, 0x766A, 0x766C, 0x766D, 0x766E, 0x7670,
+		0x7671, 0x7672, 0x7673, 0x7674, 0x7675, 0x7676, 0x7677, 0x7679, 0x767A, 0x767C, 0x767F,
+		0x7680, 0x7681, 0x7683, 0x7685, 0x7689, 0x768A, 0x768C, 0x768D, 0x768F, 0x7690, 0x7692,
+		0x7694, 0x7695, 0x7697, 0x7698, 0x769A, 0x769B, 0x769C, 0x769D, 0x769E, 0x769F, 0x76A0,
+		0x76A1, 0x76A2, 0x76A3, 0x76A5, 0x76A6, 0x76A7, 0x76A8, 0x76A9, 0x76AA, 0x76AB, 0x76AC,
+		0x76AD, 0x76AF, 0x76B0, 0x76B3, 0x76B5, 0x76B6, 0x76B7, 0x76B8, 0x76B9, 0x76BA, 0x76BB,
+		0x76BC, 0x76BD, 0x76BE, 0x76C0, 0x76C1, 0x76C3, 0x554A, 0x963F, 0x57C3, 0x6328, 0x54CE,
+		0x5509, 0x54C0, 0x7691, 0x764C, 0x853C, 0x77EE, 0x827E, 0x788D, 0x7231, 0x9698, 0x978D,
+		0x6C28, 0x5B89, 0x4FFA, 0x6309, 0x6697, 0x5CB8, 0x80FA, 0x6848, 0x80AE, 0x6602, 0x76CE,
+		0x51F9, 0x6556, 0x71AC, 0x7FF1, 0x8884, 0x50B2, 0x5965, 0x61CA, 0x6FB3, 0x82AD, 0x634C,
+		0x6252, 0x53ED, 0x5427, 0x7B06, 0x516B, 0x75A4, 0x5DF4, 0x62D4, 0x8DCB, 0x9776, 0x628A,
+		0x8019, 0x575D, 0x9738, 0x7F62, 0x7238, 0x767D, 0x67CF, 0x767E, 0x6446, 0x4F70, 0x8D25,
+		0x62DC, 0x7A17, 0x6591, 0x73ED, 0x642C, 0x6273, 0x822C, 0x9881, 0x677F, 0x7248, 0x626E,
+		0x62CC, 0x4F34, 0x74E3, 0x534A, 0x529E, 0x7ECA, 0x90A6, 0x5E2E, 0x6886, 0x699C, 0x8180,
+		0x7ED1, 0x68D2, 0x78C5, 0x868C, 0x9551, 0x508D, 0x8C24, 0x82DE, 0x80DE, 0x5305, 0x8912,
+		0x5265, 0x76C4, 0x76C7, 0x76C9, 0x76CB, 0x76CC, 0x76D3, 0x76D5, 0x76D9, 0x76DA, 0x76DC,
+		0x76DD, 0x76DE, 0x76E0, 0x76E1, 0x76E2, 0x76E3, 0x76E4, 0x76E6, 0x76E7, 0x76E8, 0x76E9,
+		0x76EA, 0x76EB, 0x76EC, 0x76ED, 0x76F0, 0x76F3, 0x76F5, 0x76F6, 0x76F7, 0x76FA, 0x76FB,
+		0x76FD, 0x76FF, 0x7700, 0x7702, 0x7703, 0x7705, 0x7706, 0x770A, 0x770C, 0x770E, 0x770F,
+		0x7710, 0x7711, 0x7712, 0x7713, 0x7714, 0x7715, 0x7716, 0x7717, 0x7718, 0x771B, 0x771C,
+		0x771D, 0x771E, 0x7721, 0x7723, 0x7724, 0x7725, 0x7727, 0x772A, 0x772B, 0x772C, 0x772E,
+		0x7730, 0x7731, 0x7732, 0x7733, 0x7734, 0x7739, 0x773B, 0x773D, 0x773E, 0x773F, 0x7742,
+		0x7744, 0x7745, 0x7746, 0x7748, 0x7749, 0x774A, 0x774B, 0x774C, 0x774D, 0x774E, 0x774F,
+		0x7752, 0x7753, 0x7754, 0x7755, 0x7756, 0x7757, 0x7758, 0x7759, 0x775C, 0x8584, 0x96F9,
+		0x4FDD, 0x5821, 0x9971, 0x5B9D, 0x62B1, 0x62A5, 0x66B4, 0x8C79, 0x9C8D, 0x7206, 0x676F,
+		0x7891, 0x60B2, 0x5351, 0x5317, 0x8F88, 0x80CC, 0x8D1D, 0x94A1, 0x500D, 0x72C8, 0x5907,
+		0x60EB, 0x7119, 0x88AB, 0x5954, 0x82EF, 0x672C, 0x7B28, 0x5D29, 0x7EF7, 0x752D, 0x6CF5,
+		0x8E66, 0x8FF8, 0x903C, 0x9F3B, 0x6BD4, 0x9119, 0x7B14, 0x5F7C, 0x78A7, 0x84D6, 0x853D,
+		0x6BD5, 0x6BD9, 0x6BD6, 0x5E01, 0x5E87, 0x75F9, 0x95ED, 0x655D, 0x5F0A, 0x5FC5, 0x8F9F,
+		0x58C1, 0x81C2, 0x907F, 0x965B, 0x97AD, 0x8FB9, 0x7F16, 0x8D2C, 0x6241, 0x4FBF, 0x53D8,
+		0x535E, 0x8FA8, 0x8FA9, 0x8FAB, 0x904D, 0x6807, 0x5F6A, 0x8198, 0x8868, 0x9CD6, 0x618B,
+		0x522B, 0x762A, 0x5F6C, 0x658C, 0x6FD2, 0x6EE8, 0x5BBE, 0x6448, 0x5175, 0x51B0, 0x67C4,
+		0x4E19, 0x79C9, 0x997C, 0x70B3, 0x775D, 0x775E, 0x775F, 0x7760, 0x7764, 0x7767, 0x7769,
+		0x776A, 0x776D, 0x776E, 0x776F, 0x7770, 0x7771, 0x7772, 0x7773, 0x7774, 0x7775, 0x7776,
+		0x7777, 0x7778, 0x777A, 0x777B, 0x777C, 0x7781, 0x7782, 0x7783, 0x7786, 0x7787, 0x7788,
+		0x7789, 0x778A, 0x778B, 0x778F, 0x7790, 0x7793, 0x7794, 0x7795, 0x7796, 0x7797, 0x7798,
+		0x7799, 0x779A, 0x779B, 0x779C, 0x779D, 0x779E, 0x77A1, 0x77A3, 0x77A4, 0x77A6, 0x77A8,
+		0x77AB, 0x77AD, 0x77AE, 0x77AF, 0x77B1, 0x77B2, 0x77B4, 0x77B6, 0x77B7, 0x77B8, 0x77B9,
+		0x77BA, 0x77BC, 0x77BE, 0x77C0, 0x77C1, 0x77C2, 0x77C3, 0x77C4, 0x77C5, 0x77C6, 0x77C7,
+		0x77C8, 0x77C9, 0x77CA, 0x77CB, 0x77CC, 0x77CE, 0x77CF, 0x77D0, 0x77D1, 0x77D2, 0x77D3,
+		0x77D4, 0x77D5, 0x77D6, 0x77D8, 0x77D9, 0x77DA, 0x77DD, 0x77DE, 0x77DF, 0x77E0, 0x77E1,
+		0x77E4, 0x75C5, 0x5E76, 0x73BB, 0x83E0, 0x64AD, 0x62E8, 0x94B5, 0x6CE2, 0x535A, 0x52C3,
+		0x640F, 0x94C2, 0x7B94, 0x4F2F, 0x5E1B, 0x8236, 0x8116, 0x818A, 0x6E24, 0x6CCA, 0x9A73,
+		0x6355, 0x535C, 0x54FA, 0x8865, 0x57E0, 0x4E0D, 0x5E03, 0x6B65, 0x7C3F, 0x90E8, 0x6016,
+		0x64E6, 0x731C, 0x88C1, 0x6750, 0x624D, 0x8D22, 0x776C, 0x8E29, 0x91C7, 0x5F69, 0x83DC,
+		0x8521, 0x9910, 0x53C2, 0x8695, 0x6B8B, 0x60ED, 0x60E8, 0x707F, 0x82CD, 0x8231, 0x4ED3,
+		0x6CA7, 0x85CF, 0x64CD, 0x7CD9, 0x69FD, 0x66F9, 0x8349, 0x5395, 0x7B56, 0x4FA7, 0x518C,
+		0x6D4B, 0x5C42, 0x8E6D, 0x63D2, 0x53C9, 0x832C, 0x8336, 0x67E5, 0x78B4, 0x643D, 0x5BDF,
+		0x5C94, 0x5DEE, 0x8BE7, 0x62C6, 0x67F4, 0x8C7A, 0x6400, 0x63BA, 0x8749, 0x998B, 0x8C17,
+		0x7F20, 0x94F2, 0x4EA7, 0x9610, 0x98A4, 0x660C, 0x7316, 0x77E6, 0x77E8, 0x77EA, 0x77EF,
+		0x77F0, 0x77F1, 0x77F2, 0x77F4, 0x77F5, 0x77F7, 0x77F9, 0x77FA, 0x77FB, 0x77FC, 0x7803,
+		0x7804, 0x7805, 0x7806, 0x7807, 0x7808, 0x780A, 0x780B, 0x780E, 0x780F, 0x7810, 0x7813,
+		0x7815, 0x7819, 0x781B, 0x781E, 0x7820, 0x7821, 0x7822, 0x7824, 0x7828, 0x782A, 0x782B,
+		0x782E, 0x782F, 0x7831, 0x7832, 0x7833, 0x7835, 0x7836, 0x783D, 0x783F, 0x7841, 0x7842,
+		0x7843, 0x7844, 0x7846, 0x7848, 0x7849, 0x784A, 0x784B, 0x784D, 0x784F, 0x7851, 0x7853,
+		0x7854, 0x7858, 0x7859, 0x785A, 0x785B, 0x785C, 0x785E, 0x785F, 0x7860, 0x7861, 0x7862,
+		0x7863, 0x7864, 0x7865, 0x7866, 0x7867, 0x7868, 0x7869, 0x786F, 0x7870, 0x7871, 0x7872,
+		0x7873, 0x7874, 0x7875, 0x7876, 0x7878, 0x7879, 0x787A, 0x787B, 0x787D, 0x787E, 0x787F,
+		0x7880, 0x7881, 0x7882, 0x7883, 0x573A, 0x5C1D, 0x5E38, 0x957F, 0x507F, 0x80A0, 0x5382,
+		0x655E, 0x7545, 0x5531, 0x5021, 0x8D85, 0x6284, 0x949E, 0x671D, 0x5632, 0x6F6E, 0x5DE2,
+		0x5435, 0x7092, 0x8F66, 0x626F, 0x64A4, 0x63A3, 0x5F7B, 0x6F88, 0x90F4, 0x81E3, 0x8FB0,
+		0x5C18, 0x6668, 0x5FF1, 0x6C89, 0x9648, 0x8D81, 0x886C, 0x6491, 0x79F0, 0x57CE, 0x6A59,
+		0x6210, 0x5448, 0x4E58, 0x7A0B, 0x60E9, 0x6F84, 0x8BDA, 0x627F, 0x901E, 0x9A8B, 0x79E4,
+		0x5403, 0x75F4, 0x6301, 0x5319, 0x6C60, 0x8FDF, 0x5F1B, 0x9A70, 0x803B, 0x9F7F, 0x4F88,
+		0x5C3A, 0x8D64, 0x7FC5, 0x65A5, 0x70BD, 0x5145, 0x51B2, 0x866B, 0x5D07, 0x5BA0, 0x62BD,
+		0x916C, 0x7574, 0x8E0C, 0x7A20, 0x6101, 0x7B79, 0x4EC7, 0x7EF8, 0x7785, 0x4E11, 0x81ED,
+		0x521D, 0x51FA, 0x6A71, 0x53A8, 0x8E87, 0x9504, 0x96CF, 0x6EC1, 0x9664, 0x695A, 0x7884,
+		0x7885, 0x7886, 0x7888, 0x788A, 0x788B, 0x788F, 0x7890, 0x7892, 0x7894, 0x7895, 0x7896,
+		0x7899, 0x789D, 0x789E, 0x78A0, 0x78A2, 0x78A4, 0x78A6, 0x78A8, 0x78A9, 0x78AA, 0x78AB,
+		0x78AC, 0x78AD, 0x78AE, 0x78AF, 0x78B5, 0x78B6, 0x78B7, 0x78B8, 0x78BA, 0x78BB, 0x78BC,
+		0x78BD, 0x78BF, 0x78C0, 0x78C2, 0x78C3, 0x78C4, 0x78C6, 0x78C7, 0x78C8, 0x78CC, 0x78CD,
+		0x78CE, 0x78CF, 0x78D1, 0x78D2, 0x78D3, 0x78D6, 0x78D7, 0x78D8, 0x78DA, 0x78DB, 0x78DC,
+		0x78DD, 0x78DE, 0x78DF, 0x78E0, 0x78E1, 0x78E2, 0x78E3, 0x78E4, 0x78E5, 0x78E6, 0x78E7,
+		0x78E9, 0x78EA, 0x78EB, 0x78ED, 0x78EE, 0x78EF, 0x78F0, 0x78F1, 0x78F3, 0x78F5, 0x78F6,
+		0x78F8, 0x78F9, 0x78FB, 0x78FC, 0x78FD, 0x78FE, 0x78FF, 0x7900, 0x7902, 0x7903, 0x7904,
+		0x7906, 0x7907, 0x7908, 0x7909, 0x790A, 0x790B, 0x790C, 0x7840, 0x50A8, 0x77D7, 0x6410,
+		0x89E6, 0x5904, 0x63E3, 0x5DDD, 0x7A7F, 0x693D, 0x4F20, 0x8239, 0x5598, 0x4E32, 0x75AE,
+		0x7A97, 0x5E62, 0x5E8A, 0x95EF, 0x521B, 0x5439, 0x708A, 0x6376, 0x9524, 0x5782, 0x6625,
+		0x693F, 0x9187, 0x5507, 0x6DF3, 0x7EAF, 0x8822, 0x6233, 0x7EF0, 0x75B5, 0x8328, 0x78C1,
+		0x96CC, 0x8F9E, 0x6148, 0x74F7, 0x8BCD, 0x6B64, 0x523A, 0x8D50, 0x6B21, 0x806A, 0x8471,
+		0x56F1, 0x5306, 0x4ECE, 0x4E1B, 0x51D1, 0x7C97, 0x918B, 0x7C07, 0x4FC3, 0x8E7F, 0x7BE1,
+		0x7A9C, 0x6467, 0x5D14, 0x50AC, 0x8106, 0x7601, 0x7CB9, 0x6DEC, 0x7FE0, 0x6751, 0x5B58,
+		0x5BF8, 0x78CB, 0x64AE, 0x6413, 0x63AA, 0x632B, 0x9519, 0x642D, 0x8FBE, 0x7B54, 0x7629,
+		0x6253, 0x5927, 0x5446, 0x6B79, 0x50A3, 0x6234, 0x5E26, 0x6B86, 0x4EE3, 0x8D37, 0x888B,
+		0x5F85, 0x902E, 0x790D, 0x790E, 0x790F, 0x7910, 0x7911, 0x7912, 0x7914, 0x7915, 0x7916,
+		0x7917, 0x7918, 0x7919, 0x791A, 0x791B, 0x791C, 0x791D, 0x791F, 0x7920, 0x7921, 0x7922,
+		0x7923, 0x7925, 0x7926, 0x7927, 0x7928, 0x7929, 0x792A, 0x792B, 0x792C, 0x792D, 0x792E,
+		0x792F, 0x7930, 0x7931, 0x7932, 0x7933, 0x7935, 0x7936, 0x7937, 0x7938, 0x7939, 0x793D,
+		0x793F, 0x7942, 0x7943, 0x7944, 0x7945, 0x7947, 0x794A, 0x794B, 0x794C, 0x794D, 0x794E,
+		0x794F, 0x7950, 0x7951, 0x7952, 0x7954, 0x7955, 0x7958, 0x7959, 0x7961, 0x7963, 0x7964,
+		0x7966, 0x7969, 0x796A, 0x796B, 0x796C, 0x796E, 0x7970, 0x7971, 0x7972, 0x7973, 0x7974,
+		0x7975, 0x7976, 0x7979, 0x797B, 0x797C, 0x797D, 0x797E, 0x797F, 0x7982, 0x7983, 0x7986,
+		0x7987, 0x7988, 0x7989, 0x798B, 0x798C, 0x798D, 0x798E, 0x7990, 0x7991, 0x7992, 0x6020,
+		0x803D, 0x62C5, 0x4E39, 0x5355, 0x90F8, 0x63B8, 0x80C6, 0x65E6, 0x6C2E, 0x4F46, 0x60EE,
+		0x6DE1, 0x8BDE, 0x5F39, 0x86CB, 0x5F53, 0x6321, 0x515A, 0x8361, 0x6863, 0x5200, 0x6363,
+		0x8E48, 0x5012, 0x5C9B, 0x7977, 0x5BFC, 0x5230, 0x7A3B, 0x60BC, 0x9053, 0x76D7, 0x5FB7,
+		0x5F97, 0x7684, 0x8E6C, 0x706F, 0x767B, 0x7B49, 0x77AA, 0x51F3, 0x9093, 0x5824, 0x4F4E,
+		0x6EF4, 0x8FEA, 0x654C, 0x7B1B, 0x72C4, 0x6DA4, 0x7FDF, 0x5AE1, 0x62B5, 0x5E95, 0x5730,
+		0x8482, 0x7B2C, 0x5E1D, 0x5F1F, 0x9012, 0x7F14, 0x98A0, 0x6382, 0x6EC7, 0x7898, 0x70B9,
+		0x5178, 0x975B, 0x57AB, 0x7535, 0x4F43, 0x7538, 0x5E97, 0x60E6, 0x5960, 0x6DC0, 0x6BBF,
+		0x7889, 0x53FC, 0x96D5, 0x51CB, 0x5201, 0x6389, 0x540A, 0x9493, 0x8C03, 0x8DCC, 0x7239,
+		0x789F, 0x8776, 0x8FED, 0x8C0D, 0x53E0, 0x7993, 0x7994, 0x7995, 0x7996, 0x7997, 0x7998,
+		0x7999, 0x799B, 0x799C, 0x799D, 0x799E, 0x799F, 0x79A0, 0x79A1, 0x79A2, 0x79A3, 0x79A4,
+		0x79A5, 0x79A6, 0x79A8, 0x79A9, 0x79AA, 0x79AB, 0x79AC, 0x79AD, 0x79AE, 0x79AF, 0x79B0,
+		0x79B1, 0x79B2, 0x79B4, 0x79B5, 0x79B6, 0x79B7, 0x79B8, 0x79BC, 0x79BF, 0x79C2, 0x79C4,
+		0x79C5, 0x79C7, 0x79C8, 0x79CA, 0x79CC, 0x79CE, 0x79CF, 0x79D0, 0x79D3, 0x79D4, 0x79D6,
+		0x79D7, 0x79D9, 0x79DA, 0x79DB, 0x79DC, 0x79DD, 0x79DE, 0x79E0, 0x79E1, 0x79E2, 0x79E5,
+		0x79E8, 0x79EA, 0x79EC, 0x79EE, 0x79F1, 0x79F2, 0x79F3, 0x79F4, 0x79F5, 0x79F6, 0x79F7,
+		0x79F9, 0x79FA, 0x79FC, 0x79FE, 0x79FF, 0x7A01, 0x7A04, 0x7A05, 0x7A07, 0x7A08, 0x7A09,
+		0x7A0A, 0x7A0C, 0x7A0F, 0x7A10, 0x7A11, 0x7A12, 0x7A13, 0x7A15, 0x7A16, 0x7A18, 0x7A19,
+		0x7A1B, 0x7A1C, 0x4E01, 0x76EF, 0x53EE, 0x9489, 0x9876, 0x9F0E, 0x952D, 0x5B9A, 0x8BA2,
+		0x4E22, 0x4E1C, 0x51AC, 0x8463, 0x61C2, 0x52A8, 0x680B, 0x4F97, 0x606B, 0x51BB, 0x6D1E,
+		0x515C, 0x6296, 0x6597, 0x9661, 0x8C46, 0x9017, 0x75D8, 0x90FD, 0x7763, 0x6BD2, 0x728A,
+		0x72EC, 0x8BFB, 0x5835, 0x7779, 0x8D4C, 0x675C, 0x9540, 0x809A, 0x5EA6, 0x6E21, 0x5992,
+		0x7AEF, 0x77ED, 0x953B, 0x6BB5, 0x65AD, 0x7F0E, 0x5806, 0x5151, 0x961F, 0x5BF9, 0x58A9,
+		0x5428, 0x8E72, 0x6566, 0x987F, 0x56E4, 0x949D, 0x76FE, 0x9041, 0x6387, 0x54C6, 0x591A,
+		0x593A, 0x579B, 0x8EB2, 0x6735, 0x8DFA, 0x8235, 0x5241, 0x60F0, 0x5815, 0x86FE, 0x5CE8,
+		0x9E45, 0x4FC4, 0x989D, 0x8BB9, 0x5A25, 0x6076, 0x5384, 0x627C, 0x904F, 0x9102, 0x997F,
+		0x6069, 0x800C, 0x513F, 0x8033, 0x5C14, 0x9975, 0x6D31, 0x4E8C, 0x7A1D, 0x7A1F, 0x7A21,
+		0x7A22, 0x7A24, 0x7A25, 0x7A26, 0x7A27, 0x7A28, 0x7A29, 0x7A2A, 0x7A2B, 0x7A2C, 0x7A2D,
+		0x7A2E, 0x7A2F, 0x7A30, 0x7A31, 0x7A32, 0x7A34, 0x7A35, 0x7A36, 0x7A38, 0x7A3A, 0x7A3E,
+		0x7A40, 0x7A41, 0x7A42, 0x7A43, 0x7A44, 0x7A45, 0x7A47, 0x7A48, 0x7A49, 0x7A4A, 0x7A4B,
+		0x7A4C, 0x7A4D, 0x7A4E, 0x7A4F, 0x7A50, 0x7A52, 0x7A53, 0x7A54, 0x7A55, 0x7A56, 0x7A58,
+		0x7A59, 0x7A5A, 0x7A5B, 0x7A5C, 0x7A5D, 0x7A5E, 0x7A5F, 0x7A60, 0x7A61, 0x7A62, 0x7A63,
+		0x7A64, 0x7A65, 0x7A66, 0x7A67, 0x7A68, 0x7A69, 0x7A6A, 0x7A6B, 0x7A6C, 0x7A6D, 0x7A6E,
+		0x7A6F, 0x7A71, 0x7A72, 0x7A73, 0x7A75, 0x7A7B, 0x7A7C, 0x7A7D, 0x7A7E, 0x7A82, 0x7A85,
+		0x7A87, 0x7A89, 0x7A8A, 0x7A8B, 0x7A8C, 0x7A8E, 0x7A8F, 0x7A90, 0x7A93, 0x7A94, 0x7A99,
+		0x7A9A, 0x7A9B, 0x7A9E, 0x7AA1, 0x7AA2, 0x8D30, 0x53D1, 0x7F5A, 0x7B4F, 0x4F10, 0x4E4F,
+		0x9600, 0x6CD5, 0x73D0, 0x85E9, 0x5E06, 0x756A, 0x7FFB, 0x6A0A, 0x77FE, 0x9492, 0x7E41,
+		0x51E1, 0x70E6, 0x53CD, 0x8FD4, 0x8303, 0x8D29, 0x72AF, 0x996D, 0x6CDB, 0x574A, 0x82B3,
+		0x65B9, 0x80AA, 0x623F, 0x9632, 0x59A8, 0x4EFF, 0x8BBF, 0x7EBA, 0x653E, 0x83F2, 0x975E,
+		0x5561, 0x98DE, 0x80A5, 0x532A, 0x8BFD, 0x5420, 0x80BA, 0x5E9F, 0x6CB8, 0x8D39, 0x82AC,
+		0x915A, 0x5429, 0x6C1B, 0x5206, 0x7EB7, 0x575F, 0x711A, 0x6C7E, 0x7C89, 0x594B, 0x4EFD,
+		0x5FFF, 0x6124, 0x7CAA, 0x4E30, 0x5C01, 0x67AB, 0x8702, 0x5CF0, 0x950B, 0x98CE, 0x75AF,
+		0x70FD, 0x9022, 0x51AF, 0x7F1D, 0x8BBD, 0x5949, 0x51E4, 0x4F5B, 0x5426, 0x592B, 0x6577,
+		0x80A4, 0x5B75, 0x6276, 0x62C2, 0x8F90, 0x5E45, 0x6C1F, 0x7B26, 0x4F0F, 0x4FD8, 0x670D,
+		0x7AA3, 0x7AA4, 0x7AA7, 0x7AA9, 0x7AAA, 0x7AAB, 0x7AAE, 0x7AAF, 0x7AB0, 0x7AB1, 0x7AB2,
+		0x7AB4, 0x7AB5, 0x7AB6, 0x7AB7, 0x7AB8, 0x7AB9, 0x7ABA, 0x7ABB, 0x7ABC, 0x7ABD, 0x7ABE,
+		0x7AC0, 0x7AC1, 0x7AC2, 0x7AC3, 0x7AC4, 0x7AC5, 0x7AC6, 0x7AC7, 0x7AC8, 0x7AC9, 0x7ACA,
+		0x7ACC, 0x7ACD, 0x7ACE, 0x7ACF, 0x7AD0, 0x7AD1, 0x7AD2, 0x7AD3, 0x7AD4, 0x7AD5, 0x7AD7,
+		0x7AD8, 0x7ADA, 0x7ADB, 0x7ADC, 0x7ADD, 0x7AE1, 0x7AE2, 0x7AE4, 0x7AE7, 0x7AE8, 0x7AE9,
+		0x7AEA, 0x7AEB, 0x7AEC, 0x7AEE, 0x7AF0, 0x7AF1, 0x7AF2, 0x7AF3, 0x7AF4, 0x7AF5, 0x7AF6,
+		0x7AF7, 0x7AF8, 0x7AFB, 0x7AFC, 0x7AFE, 0x7B00, 0x7B01, 0x7B02, 0x7B05, 0x7B07, 0x7B09,
+		0x7B0C, 0x7B0D, 0x7B0E, 0x7B10, 0x7B12, 0x7B13, 0x7B16, 0x7B17, 0x7B18, 0x7B1A, 0x7B1C,
+		0x7B1D, 0x7B1F, 0x7B21, 0x7B22, 0x7B23, 0x7B27, 0x7B29, 0x7B2D, 0x6D6E, 0x6DAA, 0x798F,
+		0x88B1, 0x5F17, 0x752B, 0x629A, 0x8F85, 0x4FEF, 0x91DC, 0x65A7, 0x812F, 0x8151, 0x5E9C,
+		0x8150, 0x8D74, 0x526F, 0x8986, 0x8D4B, 0x590D, 0x5085, 0x4ED8, 0x961C, 0x7236, 0x8179,
+		0x8D1F, 0x5BCC, 0x8BA3, 0x9644, 0x5987, 0x7F1A, 0x5490, 0x5676, 0x560E, 0x8BE5, 0x6539,
+		0x6982, 0x9499, 0x76D6, 0x6E89, 0x5E72, 0x7518, 0x6746, 0x67D1, 0x7AFF, 0x809D, 0x8D76,
+		0x611F, 0x79C6, 0x6562, 0x8D63, 0x5188, 0x521A, 0x94A2, 0x7F38, 0x809B, 0x7EB2, 0x5C97,
+		0x6E2F, 0x6760, 0x7BD9, 0x768B, 0x9AD8, 0x818F, 0x7F94, 0x7CD5, 0x641E, 0x9550, 0x7A3F,
+		0x544A, 0x54E5, 0x6B4C, 0x6401, 0x6208, 0x9E3D, 0x80F3, 0x7599, 0x5272, 0x9769, 0x845B,
+		0x683C, 0x86E4, 0x9601, 0x9694, 0x94EC, 0x4E2A, 0x5404, 0x7ED9, 0x6839, 0x8DDF, 0x8015,
+		0x66F4, 0x5E9A, 0x7FB9, 0x7B2F, 0x7B30, 0x7B32, 0x7B34, 0x7B35, 0x7B36, 0x7B37, 0x7B39,
+		0x7B3B, 0x7B3D, 0x7B3F, 0x7B40, 0x7B41, 0x7B42, 0x7B43, 0x7B44, 0x7B46, 0x7B48, 0x7B4A,
+		0x7B4D, 0x7B4E, 0x7B53, 0x7B55, 0x7B57, 0x7B59, 0x7B5C, 0x7B5E, 0x7B5F, 0x7B61, 0x7B63,
+		0x7B64, 0x7B65, 0x7B66, 0x7B67, 0x7B68, 0x7B69, 0x7B6A, 0x7B6B, 0x7B6C, 0x7B6D, 0x7B6F,
+		0x7B70, 0x7B73, 0x7B74, 0x7B76, 0x7B78, 0x7B7A, 0x7B7C, 0x7B7D, 0x7B7F, 0x7B81, 0x7B82,
+		0x7B83, 0x7B84, 0x7B86, 0x7B87, 0x7B88, 0x7B89, 0x7B8A, 0x7B8B, 0x7B8C, 0x7B8E, 0x7B8F,
+		0x7B91, 0x7B92, 0x7B93, 0x7B96, 0x7B98, 0x7B99, 0x7B9A, 0x7B9B, 0x7B9E, 0x7B9F, 0x7BA0,
+		0x7BA3, 0x7BA4, 0x7BA5, 0x7BAE, 0x7BAF, 0x7BB0, 0x7BB2, 0x7BB3, 0x7BB5, 0x7BB6, 0x7BB7,
+		0x7BB9, 0x7BBA, 0x7BBB, 0x7BBC, 0x7BBD, 0x7BBE, 0x7BBF, 0x7BC0, 0x7BC2, 0x7BC3, 0x7BC4,
+		0x57C2, 0x803F, 0x6897, 0x5DE5, 0x653B, 0x529F, 0x606D, 0x9F9A, 0x4F9B, 0x8EAC, 0x516C,
+		0x5BAB, 0x5F13, 0x5DE9, 0x6C5E, 0x62F1, 0x8D21, 0x5171, 0x94A9, 0x52FE, 0x6C9F, 0x82DF,
+		0x72D7, 0x57A2, 0x6784, 0x8D2D, 0x591F, 0x8F9C, 0x83C7, 0x5495, 0x7B8D, 0x4F30, 0x6CBD,
+		0x5B64, 0x59D1, 0x9F13, 0x53E4, 0x86CA, 0x9AA8, 0x8C37, 0x80A1, 0x6545, 0x987E, 0x56FA,
+		0x96C7, 0x522E, 0x74DC, 0x5250, 0x5BE1, 0x6302, 0x8902, 0x4E56, 0x62D0, 0x602A, 0x68FA,
+		0x5173, 0x5B98, 0x51A0, 0x89C2, 0x7BA1, 0x9986, 0x7F50, 0x60EF, 0x704C, 0x8D2F, 0x5149,
+		0x5E7F, 0x901B, 0x7470, 0x89C4, 0x572D, 0x7845, 0x5F52, 0x9F9F, 0x95FA, 0x8F68, 0x9B3C,
+		0x8BE1, 0x7678, 0x6842, 0x67DC, 0x8DEA, 0x8D35, 0x523D, 0x8F8A, 0x6EDA, 0x68CD, 0x9505,
+		0x90ED, 0x56FD, 0x679C, 0x88F9, 0x8FC7, 0x54C8, 0x7BC5, 0x7BC8, 0x7BC9, 0x7BCA, 0x7BCB,
+		0x7BCD, 0x7BCE, 0x7BCF, 0x7BD0, 0x7BD2, 0x7BD4, 0x7BD5, 0x7BD6, 0x7BD7, 0x7BD8, 0x7BDB,
+		0x7BDC, 0x7BDE, 0x7BDF, 0x7BE0, 0x7BE2, 0x7BE3, 0x7BE4, 0x7BE7, 0x7BE8, 0x7BE9, 0x7BEB,
+		0x7BEC, 0x7BED, 0x7BEF, 0x7BF0, 0x7BF2, 0x7BF3, 0x7BF4, 0x7BF5, 0x7BF6, 0x7BF8, 0x7BF9,
+		0x7BFA, 0x7BFB, 0x7BFD, 0x7BFF, 0x7C00, 0x7C01, 0x7C02, 0x7C03, 0x7C04, 0x7C05, 0x7C06,
+		0x7C08, 0x7C09, 0x7C0A, 0x7C0D, 0x7C0E, 0x7C10, 0x7C11, 0x7C12, 0x7C13, 0x7C14, 0x7C15,
+		0x7C17, 0x7C18, 0x7C19, 0x7C1A, 0x7C1B, 0x7C1C, 0x7C1D, 0x7C1E, 0x7C20, 0x7C21, 0x7C22,
+		0x7C23, 0x7C24, 0x7C25, 0x7C28, 0x7C29, 0x7C2B, 0x7C2C, 0x7C2D, 0x7C2E, 0x7C2F, 0x7C30,
+		0x7C31, 0x7C32, 0x7C33, 0x7C34, 0x7C35, 0x7C36, 0x7C37, 0x7C39, 0x7C3A, 0x7C3B, 0x7C3C,
+		0x7C3D, 0x7C3E, 0x7C42, 0x9AB8, 0x5B69, 0x6D77, 0x6C26, 0x4EA5, 0x5BB3, 0x9A87, 0x9163,
+		0x61A8, 0x90AF, 0x97E9, 0x542B, 0x6DB5, 0x5BD2, 0x51FD, 0x558A, 0x7F55, 0x7FF0, 0x64BC,
+		0x634D, 0x65F1, 0x61BE, 0x608D, 0x710A, 0x6C57, 0x6C49, 0x592F, 0x676D, 0x822A, 0x58D5,
+		0x568E, 0x8C6A, 0x6BEB, 0x90DD, 0x597D, 0x8017, 0x53F7, 0x6D69, 0x5475, 0x559D, 0x8377,
+		0x83CF, 0x6838, 0x79BE, 0x548C, 0x4F55, 0x5408, 0x76D2, 0x8C89, 0x9602, 0x6CB3, 0x6DB8,
+		0x8D6B, 0x8910, 0x9E64, 0x8D3A, 0x563F, 0x9ED1, 0x75D5, 0x5F88, 0x72E0, 0x6068, 0x54FC,
+		0x4EA8, 0x6A2A, 0x8861, 0x6052, 0x8F70, 0x54C4, 0x70D8, 0x8679, 0x9E3F, 0x6D2A, 0x5B8F,
+		0x5F18, 0x7EA2, 0x5589, 0x4FAF, 0x7334, 0x543C, 0x539A, 0x5019, 0x540E, 0x547C, 0x4E4E,
+		0x5FFD, 0x745A, 0x58F6, 0x846B, 0x80E1, 0x8774, 0x72D0, 0x7CCA, 0x6E56, 0x7C43, 0x7C44,
+		0x7C45, 0x7C46, 0x7C47, 0x7C48, 0x7C49, 0x7C4A, 0x7C4B, 0x7C4C, 0x7C4E, 0x7C4F, 0x7C50,
+		0x7C51, 0x7C52, 0x7C53, 0x7C54, 0x7C55, 0x7C56, 0x7C57, 0x7C58, 0x7C59, 0x7C5A, 0x7C5B,
+		0x7C5C, 0x7C5D, 0x7C5E, 0x7C5F, 0x7C60, 0x7C61, 0x7C62, 0x7C63, 0x7C64, 0x7C65, 0x7C66,
+		0x7C67, 0x7C68, 0x7C69, 0x7C6A, 0x7C6B, 0x7C6C, 0x7C6D, 0x7C6E, 0x7C6F, 0x7C70, 0x7C71,
+		0x7C72, 0x7C75, 0x7C76, 0x7C77, 0x7C78, 0x7C79, 0x7C7A, 0x7C7E, 0x7C7F, 0x7C80, 0x7C81,
+		0x7C82, 0x7C83, 0x7C84, 0x7C85, 0x7C86, 0x7C87, 0x7C88, 0x7C8A, 0x7C8B, 0x7C8C, 0x7C8D,
+		0x7C8E, 0x7C8F, 0x7C90, 0x7C93, 0x7C94, 0x7C96, 0x7C99, 0x7C9A, 0x7C9B, 0x7CA0, 0x7CA1,
+		0x7CA3, 0x7CA6, 0x7CA7, 0x7CA8, 0x7CA9, 0x7CAB, 0x7CAC, 0x7CAD, 0x7CAF, 0x7CB0, 0x7CB4,
+		0x7CB5, 0x7CB6, 0x7CB7, 0x7CB8, 0x7CBA, 0x7CBB, 0x5F27, 0x864E, 0x552C, 0x62A4, 0x4E92,
+		0x6CAA, 0x6237, 0x82B1, 0x54D7, 0x534E, 0x733E, 0x6ED1, 0x753B, 0x5212, 0x5316, 0x8BDD,
+		0x69D0, 0x5F8A, 0x6000, 0x6DEE, 0x574F, 0x6B22, 0x73AF, 0x6853, 0x8FD8, 0x7F13, 0x6362,
+		0x60A3, 0x5524, 0x75EA, 0x8C62, 0x7115, 0x6DA3, 0x5BA6, 0x5E7B, 0x8352, 0x614C, 0x9EC4,
+		0x78FA, 0x8757, 0x7C27, 0x7687, 0x51F0, 0x60F6, 0x714C, 0x6643, 0x5E4C, 0x604D, 0x8C0E,
+		0x7070, 0x6325, 0x8F89, 0x5FBD, 0x6062, 0x86D4, 0x56DE, 0x6BC1, 0x6094, 0x6167, 0x5349,
+		0x60E0, 0x6666, 0x8D3F, 0x79FD, 0x4F1A, 0x70E9, 0x6C47, 0x8BB3, 0x8BF2, 0x7ED8, 0x8364,
+		0x660F, 0x5A5A, 0x9B42, 0x6D51, 0x6DF7, 0x8C41, 0x6D3B, 0x4F19, 0x706B, 0x83B7, 0x6216,
+		0x60D1, 0x970D, 0x8D27, 0x7978, 0x51FB, 0x573E, 0x57FA, 0x673A, 0x7578, 0x7A3D, 0x79EF,
+		0x7B95, 0x7CBF, 0x7CC0, 0x7CC2, 0x7CC3, 0x7CC4, 0x7CC6, 0x7CC9, 0x7CCB, 0x7CCE, 0x7CCF,
+		0x7CD0, 0x7CD1, 0x7CD2, 0x7CD3, 0x7CD4, 0x7CD8, 0x7CDA, 0x7CDB, 0x7CDD, 0x7CDE, 0x7CE1,
+		0x7CE2, 0x7CE3, 0x7CE4, 0x7CE5, 0x7CE6, 0x7CE7, 0x7CE9, 0x7CEA, 0x7CEB, 0x7CEC, 0x7CED,
+		0x7CEE, 0x7CF0, 0x7CF1, 0x7CF2, 0x7CF3, 0x7CF4, 0x7CF5, 0x7CF6, 0x7CF7, 0x7CF9, 0x7CFA,
+		0x7CFC, 0x7CFD, 0x7CFE, 0x7CFF, 0x7D00, 0x7D01, 0x7D02, 0x7D03, 0x7D04, 0x7D05, 0x7D06,
+		0x7D07, 0x7D08, 0x7D09, 0x7D0B, 0x7D0C, 0x7D0D, 0x7D0E, 0x7D0F, 0x7D10, 0x7D11, 0x7D12,
+		0x7D13, 0x7D14, 0x7D15, 0x7D16, 0x7D17, 0x7D18, 0x7D19, 0x7D1A, 0x7D1B, 0x7D1C, 0x7D1D,
+		0x7D1E, 0x7D1F, 0x7D21, 0x7D23, 0x7D24, 0x7D25, 0x7D26, 0x7D28, 0x7D29, 0x7D2A, 0x7D2C,
+		0x7D2D, 0x7D2E, 0x7D30, 0x7D31, 0x7D32, 0x7D33, 0x7D34, 0x7D35, 0x7D36, 0x808C, 0x9965,
+		0x8FF9, 0x6FC0, 0x8BA5, 0x9E21, 0x59EC, 0x7EE9, 0x7F09, 0x5409, 0x6781, 0x68D8, 0x8F91,
+		0x7C4D, 0x96C6, 0x53CA, 0x6025, 0x75BE, 0x6C72, 0x5373, 0x5AC9, 0x7EA7, 0x6324, 0x51E0,
+		0x810A, 0x5DF1, 0x84DF, 0x6280, 0x5180, 0x5B63, 0x4F0E, 0x796D, 0x5242, 0x60B8, 0x6D4E,
+		0x5BC4, 0x5BC2, 0x8BA1, 0x8BB0, 0x65E2, 0x5FCC, 0x9645, 0x5993, 0x7EE7, 0x7EAA, 0x5609,
+		0x67B7, 0x5939, 0x4F73, 0x5BB6, 0x52A0, 0x835A, 0x988A, 0x8D3E, 0x7532, 0x94BE, 0x5047,
+		0x7A3C, 0x4EF7, 0x67B6, 0x9A7E, 0x5AC1, 0x6B7C, 0x76D1, 0x575A, 0x5C16, 0x7B3A, 0x95F4,
+		0x714E, 0x517C, 0x80A9, 0x8270, 0x5978, 0x7F04, 0x8327, 0x68C0, 0x67EC, 0x78B1, 0x7877,
+		0x62E3, 0x6361, 0x7B80, 0x4FED, 0x526A, 0x51CF, 0x8350, 0x69DB, 0x9274, 0x8DF5, 0x8D31,
+		0x89C1, 0x952E, 0x7BAD, 0x4EF6, 0x7D37, 0x7D38, 0x7D39, 0x7D3A, 0x7D3B, 0x7D3C, 0x7D3D,
+		0x7D3E, 0x7D3F, 0x7D40, 0x7D41, 0x7D42, 0x7D43, 0x7D44, 0x7D45, 0x7D46, 0x7D47, 0x7D48,
+		0x7D49, 0x7D4A, 0x7D4B, 0x7D4C, 0x7D4D, 0x7D4E, 0x7D4F, 0x7D50, 0x7D51, 0x7D52, 0x7D53,
+		0x7D54, 0x7D55, 0x7D56, 0x7D57, 0x7D58, 0x7D59, 0x7D5A, 0x7D5B, 0x7D5C, 0x7D5D, 0x7D5E,
+		0x7D5F, 0x7D60, 0x7D61, 0x7D62, 0x7D63, 0x7D64, 0x7D65, 0x7D66, 0x7D67, 0x7D68, 0x7D69,
+		0x7D6A, 0x7D6B, 0x7D6C, 0x7D6D, 0x7D6F, 0x7D70, 0x7D71, 0x7D72, 0x7D73, 0x7D74, 0x7D75,
+		0x7D76, 0x7D78, 0x7D79, 0x7D7A, 0x7D7B, 0x7D7C, 0x7D7D, 0x7D7E, 0x7D7F, 0x7D80, 0x7D81,
+		0x7D82, 0x7D83, 0x7D84, 0x7D85, 0x7D86, 0x7D87, 0x7D88, 0x7D89, 0x7D8A, 0x7D8B, 0x7D8C,
+		0x7D8D, 0x7D8E, 0x7D8F, 0x7D90, 0x7D91, 0x7D92, 0x7D93, 0x7D94, 0x7D95, 0x7D96, 0x7D97,
+		0x7D98, 0x5065, 0x8230, 0x5251, 0x996F, 0x6E10, 0x6E85, 0x6DA7, 0x5EFA, 0x50F5, 0x59DC,
+		0x5C06, 0x6D46, 0x6C5F, 0x7586, 0x848B, 0x6868, 0x5956, 0x8BB2, 0x5320, 0x9171, 0x964D,
+		0x8549, 0x6912, 0x7901, 0x7126, 0x80F6, 0x4EA4, 0x90CA, 0x6D47, 0x9A84, 0x5A07, 0x56BC,
+		0x6405, 0x94F0, 0x77EB, 0x4FA5, 0x811A, 0x72E1, 0x89D2, 0x997A, 0x7F34, 0x7EDE, 0x527F,
+		0x6559, 0x9175, 0x8F7F, 0x8F83, 0x53EB, 0x7A96, 0x63ED, 0x63A5, 0x7686, 0x79F8, 0x8857,
+		0x9636, 0x622A, 0x52AB, 0x8282, 0x6854, 0x6770, 0x6377, 0x776B, 0x7AED, 0x6D01, 0x7ED3,
+		0x89E3, 0x59D0, 0x6212, 0x85C9, 0x82A5, 0x754C, 0x501F, 0x4ECB, 0x75A5, 0x8BEB, 0x5C4A,
+		0x5DFE, 0x7B4B, 0x65A4, 0x91D1, 0x4ECA, 0x6D25, 0x895F, 0x7D27, 0x9526, 0x4EC5, 0x8C28,
+		0x8FDB, 0x9773, 0x664B, 0x7981, 0x8FD1, 0x70EC, 0x6D78, 0x7D99, 0x7D9A, 0x7D9B, 0x7D9C,
+		0x7D9D, 0x7D9E, 0x7D9F, 0x7DA0, 0x7DA1, 0x7DA2, 0x7DA3, 0x7DA4, 0x7DA5, 0x7DA7, 0x7DA8,
+		0x7DA9, 0x7DAA, 0x7DAB, 0x7DAC, 0x7DAD, 0x7DAF, 0x7DB0, 0x7DB1, 0x7DB2, 0x7DB3, 0x7DB4,
+		0x7DB5, 0x7DB6, 0x7DB7, 0x7DB8, 0x7DB9, 0x7DBA, 0x7DBB, 0x7DBC, 0x7DBD, 0x7DBE, 0x7DBF,
+		0x7DC0, 0x7DC1, 0x7DC2, 0x7DC3, 0x7DC4, 0x7DC5, 0x7DC6, 0x7DC7, 0x7DC8, 0x7DC9, 0x7DCA,
+		0x7DCB, 0x7DCC, 0x7DCD, 0x7DCE, 0x7DCF, 0x7DD0, 0x7DD1, 0x7DD2, 0x7DD3, 0x7DD4, 0x7DD5,
+		0x7DD6, 0x7DD7, 0x7DD8, 0x7DD9, 0x7DDA, 0x7DDB, 0x7DDC, 0x7DDD, 0x7DDE, 0x7DDF, 0x7DE0,
+		0x7DE1, 0x7DE2, 0x7DE3, 0x7DE4, 0x7DE5, 0x7DE6, 0x7DE7, 0x7DE8, 0x7DE9, 0x7DEA, 0x7DEB,
+		0x7DEC, 0x7DED, 0x7DEE, 0x7DEF, 0x7DF0, 0x7DF1, 0x7DF2, 0x7DF3, 0x7DF4, 0x7DF5, 0x7DF6,
+		0x7DF7, 0x7DF8, 0x7DF9, 0x7DFA, 0x5C3D, 0x52B2, 0x8346, 0x5162, 0x830E, 0x775B, 0x6676,
+		0x9CB8, 0x4EAC, 0x60CA, 0x7CBE, 0x7CB3, 0x7ECF, 0x4E95, 0x8B66, 0x666F, 0x9888, 0x9759,
+		0x5883, 0x656C, 0x955C, 0x5F84, 0x75C9, 0x9756, 0x7ADF, 0x7ADE, 0x51C0, 0x70AF, 0x7A98,
+		0x63EA, 0x7A76, 0x7EA0, 0x7396, 0x97ED, 0x4E45, 0x7078, 0x4E5D, 0x9152, 0x53A9, 0x6551,
+		0x65E7, 0x81FC, 0x8205, 0x548E, 0x5C31, 0x759A, 0x97A0, 0x62D8, 0x72D9, 0x75BD, 0x5C45,
+		0x9A79, 0x83CA, 0x5C40, 0x5480, 0x77E9, 0x4E3E, 0x6CAE, 0x805A, 0x62D2, 0x636E, 0x5DE8,
+		0x5177, 0x8DDD, 0x8E1E, 0x952F, 0x4FF1, 0x53E5, 0x60E7, 0x70AC, 0x5267, 0x6350, 0x9E43,
+		0x5A1F, 0x5026, 0x7737, 0x5377, 0x7EE2, 0x6485, 0x652B, 0x6289, 0x6398, 0x5014, 0x7235,
+		0x89C9, 0x51B3, 0x8BC0, 0x7EDD, 0x5747, 0x83CC, 0x94A7, 0x519B, 0x541B, 0x5CFB, 0x7DFB,
+		0x7DFC, 0x7DFD, 0x7DFE, 0x7DFF, 0x7E00, 0x7E01, 0x7E02, 0x7E03, 0x7E04, 0x7E05, 0x7E06,
+		0x7E07, 0x7E08, 0x7E09, 0x7E0A, 0x7E0B, 0x7E0C, 0x7E0D, 0x7E0E, 0x7E0F, 0x7E10, 0x7E11,
+		0x7E12, 0x7E13, 0x7E14, 0x7E15, 0x7E16, 0x7E17, 0x7E18, 0x7E19, 0x7E1A, 0x7E1B, 0x7E1C,
+		0x7E1D, 0x7E1E, 0x7E1F, 0x7E20, 0x7E21, 0x7E22, 0x7E23, 0x7E24, 0x7E25, 0x7E26, 0x7E27,
+		0x7E28, 0x7E29, 0x7E2A, 0x7E2B, 0x7E2C, 0x7E2D, 0x7E2E, 0x7E2F, 0x7E30, 0x7E31, 0x7E32,
+		0x7E33, 0x7E34, 0x7E35, 0x7E36, 0x7E37, 0x7E38, 0x7E39, 0x7E3A, 0x7E3C, 0x7E3D, 0x7E3E,
+		0x7E3F, 0x7E40, 0x7E42, 0x7E43, 0x7E44, 0x7E45, 0x7E46, 0x7E48, 0x7E49, 0x7E4A, 0x7E4B,
+		0x7E4C, 0x7E4D, 0x7E4E, 0x7E4F, 0x7E50, 0x7E51, 0x7E52, 0x7E53, 0x7E54, 0x7E55, 0x7E56,
+		0x7E57, 0x7E58, 0x7E59, 0x7E5A, 0x7E5B, 0x7E5C, 0x7E5D, 0x4FCA, 0x7AE3, 0x6D5A, 0x90E1,
+		0x9A8F, 0x5580, 0x5496, 0x5361, 0x54AF, 0x5F00, 0x63E9, 0x6977, 0x51EF, 0x6168, 0x520A,
+		0x582A, 0x52D8, 0x574E, 0x780D, 0x770B, 0x5EB7, 0x6177, 0x7CE0, 0x625B, 0x6297, 0x4EA2,
+		0x7095, 0x8003, 0x62F7, 0x70E4, 0x9760, 0x5777, 0x82DB, 0x67EF, 0x68F5, 0x78D5, 0x9897,
+		0x79D1, 0x58F3, 0x54B3, 0x53EF, 0x6E34, 0x514B, 0x523B, 0x5BA2, 0x8BFE, 0x80AF, 0x5543,
+		0x57A6, 0x6073, 0x5751, 0x542D, 0x7A7A, 0x6050, 0x5B54, 0x63A7, 0x62A0, 0x53E3, 0x6263,
+		0x5BC7, 0x67AF, 0x54ED, 0x7A9F, 0x82E6, 0x9177, 0x5E93, 0x88E4, 0x5938, 0x57AE, 0x630E,
+		0x8DE8, 0x80EF, 0x5757, 0x7B77, 0x4FA9, 0x5FEB, 0x5BBD, 0x6B3E, 0x5321, 0x7B50, 0x72C2,
+		0x6846, 0x77FF, 0x7736, 0x65F7, 0x51B5, 0x4E8F, 0x76D4, 0x5CBF, 0x7AA5, 0x8475, 0x594E,
+		0x9B41, 0x5080, 0x7E5E, 0x7E5F, 0x7E60, 0x7E61, 0x7E62, 0x7E63, 0x7E64, 0x7E65, 0x7E66,
+		0x7E67, 0x7E68, 0x7E69, 0x7E6A, 0x7E6B, 0x7E6C, 0x7E6D, 0x7E6E, 0x7E6F, 0x7E70, 0x7E71,
+		0x7E72, 0x7E73, 0x7E74, 0x7E75, 0x7E76, 0x7E77, 0x7E78, 0x7E79, 0x7E7A, 0x7E7B, 0x7E7C,
+		0x7E7D, 0x7E7E, 0x7E7F, 0x7E80, 0x7E81, 0x7E83, 0x7E84, 0x7E85, 0x7E86, 0x7E87, 0x7E88,
+		0x7E89, 0x7E8A, 0x7E8B, 0x7E8C, 0x7E8D, 0x7E8E, 0x7E8F, 0x7E90, 0x7E91, 0x7E92, 0x7E93,
+		0x7E94, 0x7E95, 0x7E96, 0x7E97, 0x7E98, 0x7E99, 0x7E9A, 0x7E9C, 0x7E9D, 0x7E9E, 0x7EAE,
+		0x7EB4, 0x7EBB, 0x7EBC, 0x7ED6, 0x7EE4, 0x7EEC, 0x7EF9, 0x7F0A, 0x7F10, 0x7F1E, 0x7F37,
+		0x7F39, 0x7F3B, 0x7F3C, 0x7F3D, 0x7F3E, 0x7F3F, 0x7F40, 0x7F41, 0x7F43, 0x7F46, 0x7F47,
+		0x7F48, 0x7F49, 0x7F4A, 0x7F4B, 0x7F4C, 0x7F4D, 0x7F4E, 0x7F4F, 0x7F52, 0x7F53, 0x9988,
+		0x6127, 0x6E83, 0x5764, 0x6606, 0x6346, 0x56F0, 0x62EC, 0x6269, 0x5ED3, 0x9614, 0x5783,
+		0x62C9, 0x5587, 0x8721, 0x814A, 0x8FA3, 0x5566, 0x83B1, 0x6765, 0x8D56, 0x84DD, 0x5A6A,
+		0x680F, 0x62E6, 0x7BEE, 0x9611, 0x5170, 0x6F9C, 0x8C30, 0x63FD, 0x89C8, 0x61D2, 0x7F06,
+		0x70C2, 0x6EE5, 0x7405, 0x6994, 0x72FC, 0x5ECA, 0x90CE, 0x6717, 0x6D6A, 0x635E, 0x52B3,
+		0x7262, 0x8001, 0x4F6C, 0x59E5, 0x916A, 0x70D9, 0x6D9D, 0x52D2, 0x4E50, 0x96F7, 0x956D,
+		0x857E, 0x78CA, 0x7D2F, 0x5121, 0x5792, 0x64C2, 0x808B, 0x7C7B, 0x6CEA, 0x68F1, 0x695E,
+		0x51B7, 0x5398, 0x68A8, 0x7281, 0x9ECE, 0x7BF1, 0x72F8, 0x79BB, 0x6F13, 0x7406, 0x674E,
+		0x91CC, 0x9CA4, 0x793C, 0x8389, 0x8354, 0x540F, 0x6817, 0x4E3D, 0x5389, 0x52B1, 0x783E,
+		0x5386, 0x5229, 0x5088, 0x4F8B, 0x4FD0, 0x7F56, 0x7F59, 0x7F5B, 0x7F5C, 0x7F5D, 0x7F5E,
+		0x7F60, 0x7F63, 0x7F64, 0x7F65, 0x7F66, 0x7F67, 0x7F6B, 0x7F6C, 0x7F6D, 0x7F6F, 0x7F70,
+		0x7F73, 0x7F75, 0x7F76, 0x7F77, 0x7F78, 0x7F7A, 0x7F7B, 0x7F7C, 0x7F7D, 0x7F7F, 0x7F80,
+		0x7F82, 0x7F83, 0x7F84, 0x7F85, 0x7F86, 0x7F87, 0x7F88, 0x7F89, 0x7F8B, 0x7F8D, 0x7F8F,
+		0x7F90, 0x7F91, 0x7F92, 0x7F93, 0x7F95, 0x7F96, 0x7F97, 0x7F98, 0x7F99, 0x7F9B, 0x7F9C,
+		0x7FA0, 0x7FA2, 0x7FA3, 0x7FA5, 0x7FA6, 0x7FA8, 0x7FA9, 0x7FAA, 0x7FAB, 0x7FAC, 0x7FAD,
+		0x7FAE, 0x7FB1, 0x7FB3, 0x7FB4, 0x7FB5, 0x7FB6, 0x7FB7, 0x7FBA, 0x7FBB, 0x7FBE, 0x7FC0,
+		0x7FC2, 0x7FC3, 0x7FC4, 0x7FC6, 0x7FC7, 0x7FC8, 0x7FC9, 0x7FCB, 0x7FCD, 0x7FCF, 0x7FD0,
+		0x7FD1, 0x7FD2, 0x7FD3, 0x7FD6, 0x7FD7, 0x7FD9, 0x7FDA, 0x7FDB, 0x7FDC, 0x7FDD, 0x7FDE,
+		0x7FE2, 0x7FE3, 0x75E2, 0x7ACB, 0x7C92, 0x6CA5, 0x96B6, 0x529B, 0x7483, 0x54E9, 0x4FE9,
+		0x8054, 0x83B2, 0x8FDE, 0x9570, 0x5EC9, 0x601C, 0x6D9F, 0x5E18, 0x655B, 0x8138, 0x94FE,
+		0x604B, 0x70BC, 0x7EC3, 0x7CAE, 0x51C9, 0x6881, 0x7CB1, 0x826F, 0x4E24, 0x8F86, 0x91CF,
+		0x667E, 0x4EAE, 0x8C05, 0x64A9, 0x804A, 0x50DA, 0x7597, 0x71CE, 0x5BE5, 0x8FBD, 0x6F66,
+		0x4E86, 0x6482, 0x9563, 0x5ED6, 0x6599, 0x5217, 0x88C2, 0x70C8, 0x52A3, 0x730E, 0x7433,
+		0x6797, 0x78F7, 0x9716, 0x4E34, 0x90BB, 0x9CDE, 0x6DCB, 0x51DB, 0x8D41, 0x541D, 0x62CE,
+		0x73B2, 0x83F1, 0x96F6, 0x9F84, 0x94C3, 0x4F36, 0x7F9A, 0x51CC, 0x7075, 0x9675, 0x5CAD,
+		0x9886, 0x53E6, 0x4EE4, 0x6E9C, 0x7409, 0x69B4, 0x786B, 0x998F, 0x7559, 0x5218, 0x7624,
+		0x6D41, 0x67F3, 0x516D, 0x9F99, 0x804B, 0x5499, 0x7B3C, 0x7ABF, 0x7FE4, 0x7FE7, 0x7FE8,
+		0x7FEA, 0x7FEB, 0x7FEC, 0x7FED, 0x7FEF, 0x7FF2, 0x7FF4, 0x7FF5, 0x7FF6, 0x7FF7, 0x7FF8,
+		0x7FF9, 0x7FFA, 0x7FFD, 0x7FFE, 0x7FFF, 0x8002, 0x8007, 0x8008, 0x8009, 0x800A, 0x800E,
+		0x800F, 0x8011, 0x8013, 0x801A, 0x801B, 0x801D, 0x801E, 0x801F, 0x8021, 0x8023, 0x8024,
+		0x802B, 0x802C, 0x802D, 0x802E, 0x802F, 0x8030, 0x8032, 0x8034, 0x8039, 0x803A, 0x803C,
+		0x803E, 0x8040, 0x8041, 0x8044, 0x8045, 0x8047, 0x8048, 0x8049, 0x804E, 0x804F, 0x8050,
+		0x8051, 0x8053, 0x8055, 0x8056, 0x8057, 0x8059, 0x805B, 0x805C, 0x805D, 0x805E, 0x805F,
+		0x8060, 0x8061, 0x8062, 0x8063, 0x8064, 0x8065, 0x8066, 0x8067, 0x8068, 0x806B, 0x806C,
+		0x806D, 0x806E, 0x806F, 0x8070, 0x8072, 0x8073, 0x8074, 0x8075, 0x8076, 0x8077, 0x8078,
+		0x8079, 0x807A, 0x807B, 0x807C, 0x807D, 0x9686, 0x5784, 0x62E2, 0x9647, 0x697C, 0x5A04,
+		0x6402, 0x7BD3, 0x6F0F, 0x964B, 0x82A6, 0x5362, 0x9885, 0x5E90, 0x7089, 0x63B3, 0x5364,
+		0x864F, 0x9C81, 0x9E93, 0x788C, 0x9732, 0x8DEF, 0x8D42, 0x9E7F, 0x6F5E, 0x7984, 0x5F55,
+		0x9646, 0x622E, 0x9A74, 0x5415, 0x94DD, 0x4FA3, 0x65C5, 0x5C65, 0x5C61, 0x7F15, 0x8651,
+		0x6C2F, 0x5F8B, 0x7387, 0x6EE4, 0x7EFF, 0x5CE6, 0x631B, 0x5B6A, 0x6EE6, 0x5375, 0x4E71,
+		0x63A0, 0x7565, 0x62A1, 0x8F6E, 0x4F26, 0x4ED1, 0x6CA6, 0x7EB6, 0x8BBA, 0x841D, 0x87BA,
+		0x7F57, 0x903B, 0x9523, 0x7BA9, 0x9AA1, 0x88F8, 0x843D, 0x6D1B, 0x9A86, 0x7EDC, 0x5988,
+		0x9EBB, 0x739B, 0x7801, 0x8682, 0x9A6C, 0x9A82, 0x561B, 0x5417, 0x57CB, 0x4E70, 0x9EA6,
+		0x5356, 0x8FC8, 0x8109, 0x7792, 0x9992, 0x86EE, 0x6EE1, 0x8513, 0x66FC, 0x6162, 0x6F2B,
+		0x807E, 0x8081, 0x8082, 0x8085, 0x8088, 0x808A, 0x808D, 0x808E, 0x808F, 0x8090, 0x8091,
+		0x8092, 0x8094, 0x8095, 0x8097, 0x8099, 0x809E, 0x80A3, 0x80A6, 0x80A7, 0x80A8, 0x80AC,
+		0x80B0, 0x80B3, 0x80B5, 0x80B6, 0x80B8, 0x80B9, 0x80BB, 0x80C5, 0x80C7, 0x80C8, 0x80C9,
+		0x80CA, 0x80CB, 0x80CF, 0x80D0, 0x80D1, 0x80D2, 0x80D3, 0x80D4, 0x80D5, 0x80D8, 0x80DF,
+		0x80E0, 0x80E2, 0x80E3, 0x80E6, 0x80EE, 0x80F5, 0x80F7, 0x80F9, 0x80FB, 0x80FE, 0x80FF,
+		0x8100, 0x8101, 0x8103, 0x8104, 0x8105, 0x8107, 0x8108, 0x810B, 0x810C, 0x8115, 0x8117,
+		0x8119, 0x811B, 0x811C, 0x811D, 0x811F, 0x8120, 0x8121, 0x8122, 0x8123, 0x8124, 0x8125,
+		0x8126, 0x8127, 0x8128, 0x8129, 0x812A, 0x812B, 0x812D, 0x812E, 0x8130, 0x8133, 0x8134,
+		0x8135, 0x8137, 0x8139, 0x813A, 0x813B, 0x813C, 0x813D, 0x813F, 0x8C29, 0x8292, 0x832B,
+		0x76F2, 0x6C13, 0x5FD9, 0x83BD, 0x732B, 0x8305, 0x951A, 0x6BDB, 0x77DB, 0x94C6, 0x536F,
+		0x8302, 0x5192, 0x5E3D, 0x8C8C, 0x8D38, 0x4E48, 0x73AB, 0x679A, 0x6885, 0x9176, 0x9709,
+		0x7164, 0x6CA1, 0x7709, 0x5A92, 0x9541, 0x6BCF, 0x7F8E, 0x6627, 0x5BD0, 0x59B9, 0x5A9A,
+		0x95E8, 0x95F7, 0x4EEC, 0x840C, 0x8499, 0x6AAC, 0x76DF, 0x9530, 0x731B, 0x68A6, 0x5B5F,
+		0x772F, 0x919A, 0x9761, 0x7CDC, 0x8FF7, 0x8C1C, 0x5F25, 0x7C73, 0x79D8, 0x89C5, 0x6CCC,
+		0x871C, 0x5BC6, 0x5E42, 0x68C9, 0x7720, 0x7EF5, 0x5195, 0x514D, 0x52C9, 0x5A29, 0x7F05,
+		0x9762, 0x82D7, 0x63CF, 0x7784, 0x85D0, 0x79D2, 0x6E3A, 0x5E99, 0x5999, 0x8511, 0x706D,
+		0x6C11, 0x62BF, 0x76BF, 0x654F, 0x60AF, 0x95FD, 0x660E, 0x879F, 0x9E23, 0x94ED, 0x540D,
+		0x547D, 0x8C2C, 0x6478, 0x8140, 0x8141, 0x8142, 0x8143, 0x8144, 0x8145, 0x8147, 0x8149,
+		0x814D, 0x814E, 0x814F, 0x8152, 0x8156, 0x8157, 0x8158, 0x815B, 0x815C, 0x815D, 0x815E,
+		0x815F, 0x8161, 0x8162, 0x8163, 0x8164, 0x8166, 0x8168, 0x816A, 0x816B, 0x816C, 0x816F,
+		0x8172, 0x8173, 0x8175, 0x8176, 0x8177, 0x8178, 0x8181, 0x8183, 0x8184, 0x8185, 0x8186,
+		0x8187, 0x8189, 0x818B, 0x818C, 0x818D, 0x818E, 0x8190, 0x8192, 0x8193, 0x8194, 0x8195,
+		0x8196, 0x8197, 0x8199, 0x819A, 0x819E, 0x819F, 0x81A0, 0x81A1, 0x81A2, 0x81A4, 0x81A5,
+		0x81A7, 0x81A9, 0x81AB, 0x81AC, 0x81AD, 0x81AE, 0x81AF, 0x81B0, 0x81B1, 0x81B2, 0x81B4,
+		0x81B5, 0x81B6, 0x81B7, 0x81B8, 0x81B9, 0x81BC, 0x81BD, 0x81BE, 0x81BF, 0x81C4, 0x81C5,
+		0x81C7, 0x81C8, 0x81C9, 0x81CB, 0x81CD, 0x81CE, 0x81CF, 0x81D0, 0x81D1, 0x81D2, 0x81D3,
+		0x6479, 0x8611, 0x6A21, 0x819C, 0x78E8, 0x6469, 0x9B54, 0x62B9, 0x672B, 0x83AB, 0x58A8,
+		0x9ED8, 0x6CAB, 0x6F20, 0x5BDE, 0x964C, 0x8C0B, 0x725F, 0x67D0, 0x62C7, 0x7261, 0x4EA9,
+		0x59C6, 0x6BCD, 0x5893, 0x66AE, 0x5E55, 0x52DF, 0x6155, 0x6728, 0x76EE, 0x7766, 0x7267,
+		0x7A46, 0x62FF, 0x54EA, 0x5450, 0x94A0, 0x90A3, 0x5A1C, 0x7EB3, 0x6C16, 0x4E43, 0x5976,
+		0x8010, 0x5948, 0x5357, 0x7537, 0x96BE, 0x56CA, 0x6320, 0x8111, 0x607C, 0x95F9, 0x6DD6,
+		0x5462, 0x9981, 0x5185, 0x5AE9, 0x80FD, 0x59AE, 0x9713, 0x502A, 0x6CE5, 0x5C3C, 0x62DF,
+		0x4F60, 0x533F, 0x817B, 0x9006, 0x6EBA, 0x852B, 0x62C8, 0x5E74, 0x78BE, 0x64B5, 0x637B,
+		0x5FF5, 0x5A18, 0x917F, 0x9E1F, 0x5C3F, 0x634F, 0x8042, 0x5B7D, 0x556E, 0x954A, 0x954D,
+		0x6D85, 0x60A8, 0x67E0, 0x72DE, 0x51DD, 0x5B81, 0x81D4, 0x81D5, 0x81D6, 0x81D7, 0x81D8,
+		0x81D9, 0x81DA, 0x81DB, 0x81DC, 0x81DD, 0x81DE, 0x81DF, 0x81E0, 0x81E1, 0x81E2, 0x81E4,
+		0x81E5, 0x81E6, 0x81E8, 0x81E9, 0x81EB, 0x81EE, 0x81EF, 0x81F0, 0x81F1, 0x81F2, 0x81F5,
+		0x81F6, 0x81F7, 0x81F8, 0x81F9, 0x81FA, 0x81FD, 0x81FF, 0x8203, 0x8207, 0x8208, 0x8209,
+		0x820A, 0x820B, 0x820E, 0x820F, 0x8211, 0x8213, 0x8215, 0x8216, 0x8217, 0x8218, 0x8219,
+		0x821A, 0x821D, 0x8220, 0x8224, 0x8225, 0x8226, 0x8227, 0x8229, 0x822E, 0x8232, 0x823A,
+		0x823C, 0x823D, 0x823F, 0x8240, 0x8241, 0x8242, 0x8243, 0x8245, 0x8246, 0x8248, 0x824A,
+		0x824C, 0x824D, 0x824E, 0x8250, 0x8251, 0x8252, 0x8253, 0x8254, 0x8255, 0x8256, 0x8257,
+		0x8259, 0x825B, 0x825C, 0x825D, 0x825E, 0x8260, 0x8261, 0x8262, 0x8263, 0x8264, 0x8265,
+		0x8266, 0x8267, 0x8269, 0x62E7, 0x6CDE, 0x725B, 0x626D, 0x94AE, 0x7EBD, 0x8113, 0x6D53,
+		0x519C, 0x5F04, 0x5974, 0x52AA, 0x6012, 0x5973, 0x6696, 0x8650, 0x759F, 0x632A, 0x61E6,
+		0x7CEF, 0x8BFA, 0x54E6, 0x6B27, 0x9E25, 0x6BB4, 0x85D5, 0x5455, 0x5076, 0x6CA4, 0x556A,
+		0x8DB4, 0x722C, 0x5E15, 0x6015, 0x7436, 0x62CD, 0x6392, 0x724C, 0x5F98, 0x6E43, 0x6D3E,
+		0x6500, 0x6F58, 0x76D8, 0x78D0, 0x76FC, 0x7554, 0x5224, 0x53DB, 0x4E53, 0x5E9E, 0x65C1,
+		0x802A, 0x80D6, 0x629B, 0x5486, 0x5228, 0x70AE, 0x888D, 0x8DD1, 0x6CE1, 0x5478, 0x80DA,
+		0x57F9, 0x88F4, 0x8D54, 0x966A, 0x914D, 0x4F69, 0x6C9B, 0x55B7, 0x76C6, 0x7830, 0x62A8,
+		0x70F9, 0x6F8E, 0x5F6D, 0x84EC, 0x68DA, 0x787C, 0x7BF7, 0x81A8, 0x670B, 0x9E4F, 0x6367,
+		0x78B0, 0x576F, 0x7812, 0x9739, 0x6279, 0x62AB, 0x5288, 0x7435, 0x6BD7, 0x826A, 0x826B,
+		0x826C, 0x826D, 0x8271, 0x8275, 0x8276, 0x8277, 0x8278, 0x827B, 0x827C, 0x8280, 0x8281,
+		0x8283, 0x8285, 0x8286, 0x8287, 0x8289, 0x828C, 0x8290, 0x8293, 0x8294, 0x8295, 0x8296,
+		0x829A, 0x829B, 0x829E, 0x82A0, 0x82A2, 0x82A3, 0x82A7, 0x82B2, 0x82B5, 0x82B6, 0x82BA,
+		0x82BB, 0x82BC, 0x82BF, 0x82C0, 0x82C2, 0x82C3, 0x82C5, 0x82C6, 0x82C9, 0x82D0, 0x82D6,
+		0x82D9, 0x82DA, 0x82DD, 0x82E2, 0x82E7, 0x82E8, 0x82E9, 0x82EA, 0x82EC, 0x82ED, 0x82EE,
+		0x82F0, 0x82F2, 0x82F3, 0x82F5, 0x82F6, 0x82F8, 0x82FA, 0x82FC, 0x82FD, 0x82FE, 0x82FF,
+		0x8300, 0x830A, 0x830B, 0x830D, 0x8310, 0x8312, 0x8313, 0x8316, 0x8318, 0x8319, 0x831D,
+		0x831E, 0x831F, 0x8320, 0x8321, 0x8322, 0x8323, 0x8324, 0x8325, 0x8326, 0x8329, 0x832A,
+		0x832E, 0x8330, 0x8332, 0x8337, 0x833B, 0x833D, 0x5564, 0x813E, 0x75B2, 0x76AE, 0x5339,
+		0x75DE, 0x50FB, 0x5C41, 0x8B6C, 0x7BC7, 0x504F, 0x7247, 0x9A97, 0x98D8, 0x6F02, 0x74E2,
+		0x7968, 0x6487, 0x77A5, 0x62FC, 0x9891, 0x8D2B, 0x54C1, 0x8058, 0x4E52, 0x576A, 0x82F9,
+		0x840D, 0x5E73, 0x51ED, 0x74F6, 0x8BC4, 0x5C4F, 0x5761, 0x6CFC, 0x9887, 0x5A46, 0x7834,
+		0x9B44, 0x8FEB, 0x7C95, 0x5256, 0x6251, 0x94FA, 0x4EC6, 0x8386, 0x8461, 0x83E9, 0x84B2,
+		0x57D4, 0x6734, 0x5703, 0x666E, 0x6D66, 0x8C31, 0x66DD, 0x7011, 0x671F, 0x6B3A, 0x6816,
+		0x621A, 0x59BB, 0x4E03, 0x51C4, 0x6F06, 0x67D2, 0x6C8F, 0x5176, 0x68CB, 0x5947, 0x6B67,
+		0x7566, 0x5D0E, 0x8110, 0x9F50, 0x65D7, 0x7948, 0x7941, 0x9A91, 0x8D77, 0x5C82, 0x4E5E,
+		0x4F01, 0x542F, 0x5951, 0x780C, 0x5668, 0x6C14, 0x8FC4, 0x5F03, 0x6C7D, 0x6CE3, 0x8BAB,
+		0x6390, 0x833E, 0x833F, 0x8341, 0x8342, 0x8344, 0x8345, 0x8348, 0x834A, 0x834B, 0x834C,
+		0x834D, 0x834E, 0x8353, 0x8355, 0x8356, 0x8357, 0x8358, 0x8359, 0x835D, 0x8362, 0x8370,
+		0x8371, 0x8372, 0x8373, 0x8374, 0x8375, 0x8376, 0x8379, 0x837A, 0x837E, 0x837F, 0x8380,
+		0x8381, 0x8382, 0x8383, 0x8384, 0x8387, 0x8388, 0x838A, 0x838B, 0x838C, 0x838D, 0x838F,
+		0x8390, 0x8391, 0x8394, 0x8395, 0x8396, 0x8397, 0x8399, 0x839A, 0x839D, 0x839F, 0x83A1,
+		0x83A2, 0x83A3, 0x83A4, 0x83A5, 0x83A6, 0x83A7, 0x83AC, 0x83AD, 0x83AE, 0x83AF, 0x83B5,
+		0x83BB, 0x83BE, 0x83BF, 0x83C2, 0x83C3, 0x83C4, 0x83C6, 0x83C8, 0x83C9, 0x83CB, 0x83CD,
+		0x83CE, 0x83D0, 0x83D1, 0x83D2, 0x83D3, 0x83D5, 0x83D7, 0x83D9, 0x83DA, 0x83DB, 0x83DE,
+		0x83E2, 0x83E3, 0x83E4, 0x83E6, 0x83E7, 0x83E8, 0x83EB, 0x83EC, 0x83ED, 0x6070, 0x6D3D,
+		0x7275, 0x6266, 0x948E, 0x94C5, 0x5343, 0x8FC1, 0x7B7E, 0x4EDF, 0x8C26, 0x4E7E, 0x9ED4,
+		0x94B1, 0x94B3, 0x524D, 0x6F5C, 0x9063, 0x6D45, 0x8C34, 0x5811, 0x5D4C, 0x6B20, 0x6B49,
+		0x67AA, 0x545B, 0x8154, 0x7F8C, 0x5899, 0x8537, 0x5F3A, 0x62A2, 0x6A47, 0x9539, 0x6572,
+		0x6084, 0x6865, 0x77A7, 0x4E54, 0x4FA8, 0x5DE7, 0x9798, 0x64AC, 0x7FD8, 0x5CED, 0x4FCF,
+		0x7A8D, 0x5207, 0x8304, 0x4E14, 0x602F, 0x7A83, 0x94A6, 0x4FB5, 0x4EB2, 0x79E6, 0x7434,
+		0x52E4, 0x82B9, 0x64D2, 0x79BD, 0x5BDD, 0x6C81, 0x9752, 0x8F7B, 0x6C22, 0x503E, 0x537F,
+		0x6E05, 0x64CE, 0x6674, 0x6C30, 0x60C5, 0x9877, 0x8BF7, 0x5E86, 0x743C, 0x7A77, 0x79CB,
+		0x4E18, 0x90B1, 0x7403, 0x6C42, 0x56DA, 0x914B, 0x6CC5, 0x8D8B, 0x533A, 0x86C6, 0x66F2,
+		0x8EAF, 0x5C48, 0x9A71, 0x6E20, 0x83EE, 0x83EF, 0x83F3, 0x83F4, 0x83F5, 0x83F6, 0x83F7,
+		0x83FA, 0x83FB, 0x83FC, 0x83FE, 0x83FF, 0x8400, 0x8402, 0x8405, 0x8407, 0x8408, 0x8409,
+		0x840A, 0x8410, 0x8412, 0x8413, 0x8414, 0x8415, 0x8416, 0x8417, 0x8419, 0x841A, 0x841B,
+		0x841E, 0x841F, 0x8420, 0x8421, 0x8422, 0x8423, 0x8429, 0x842A, 0x842B, 0x842C, 0x842D,
+		0x842E, 0x842F, 0x8430, 0x8432, 0x8433, 0x8434, 0x8435, 0x8436, 0x8437, 0x8439, 0x843A,
+		0x843B, 0x843E, 0x843F, 0x8440, 0x8441, 0x8442, 0x8443, 0x8444, 0x8445, 0x8447, 0x8448,
+		0x8449, 0x844A, 0x844B, 0x844C, 0x844D, 0x844E, 0x844F, 0x8450, 0x8452, 0x8453, 0x8454,
+		0x8455, 0x8456, 0x8458, 0x845D, 0x845E, 0x845F, 0x8460, 0x8462, 0x8464, 0x8465, 0x8466,
+		0x8467, 0x8468, 0x846A, 0x846E, 0x846F, 0x8470, 0x8472, 0x8474, 0x8477, 0x8479, 0x847B,
+		0x847C, 0x53D6, 0x5A36, 0x9F8B, 0x8DA3, 0x53BB, 0x5708, 0x98A7, 0x6743, 0x919B, 0x6CC9,
+		0x5168, 0x75CA, 0x62F3, 0x72AC, 0x5238, 0x529D, 0x7F3A, 0x7094, 0x7638, 0x5374, 0x9E4A,
+		0x69B7, 0x786E, 0x96C0, 0x88D9, 0x7FA4, 0x7136, 0x71C3, 0x5189, 0x67D3, 0x74E4, 0x58E4,
+		0x6518, 0x56B7, 0x8BA9, 0x9976, 0x6270, 0x7ED5, 0x60F9, 0x70ED, 0x58EC, 0x4EC1, 0x4EBA,
+		0x5FCD, 0x97E7, 0x4EFB, 0x8BA4, 0x5203, 0x598A, 0x7EAB, 0x6254, 0x4ECD, 0x65E5, 0x620E,
+		0x8338, 0x84C9, 0x8363, 0x878D, 0x7194, 0x6EB6, 0x5BB9, 0x7ED2, 0x5197, 0x63C9, 0x67D4,
+		0x8089, 0x8339, 0x8815, 0x5112, 0x5B7A, 0x5982, 0x8FB1, 0x4E73, 0x6C5D, 0x5165, 0x8925,
+		0x8F6F, 0x962E, 0x854A, 0x745E, 0x9510, 0x95F0, 0x6DA6, 0x82E5, 0x5F31, 0x6492, 0x6D12,
+		0x8428, 0x816E, 0x9CC3, 0x585E, 0x8D5B, 0x4E09, 0x53C1, 0x847D, 0x847E, 0x847F, 0x8480,
+		0x8481, 0x8483, 0x8484, 0x8485, 0x8486, 0x848A, 0x848D, 0x848F, 0x8490, 0x8491, 0x8492,
+		0x8493, 0x8494, 0x8495, 0x8496, 0x8498, 0x849A, 0x849B, 0x849D, 0x849E, 0x849F, 0x84A0,
+		0x84A2, 0x84A3, 0x84A4, 0x84A5, 0x84A6, 0x84A7, 0x84A8, 0x84A9, 0x84AA, 0x84AB, 0x84AC,
+		0x84AD, 0x84AE, 0x84B0, 0x84B1, 0x84B3, 0x84B5, 0x84B6, 0x84B7, 0x84BB, 0x84BC, 0x84BE,
+		0x84C0, 0x84C2, 0x84C3, 0x84C5, 0x84C6, 0x84C7, 0x84C8, 0x84CB, 0x84CC, 0x84CE, 0x84CF,
+		0x84D2, 0x84D4, 0x84D5, 0x84D7, 0x84D8, 0x84D9, 0x84DA, 0x84DB, 0x84DC, 0x84DE, 0x84E1,
+		0x84E2, 0x84E4, 0x84E7, 0x84E8, 0x84E9, 0x84EA, 0x84EB, 0x84ED, 0x84EE, 0x84EF, 0x84F1,
+		0x84F2, 0x84F3, 0x84F4, 0x84F5, 0x84F6, 0x84F7, 0x84F8, 0x84F9, 0x84FA, 0x84FB, 0x84FD,
+		0x84FE, 0x8500, 0x8501, 0x8502, 0x4F1E, 0x6563, 0x6851, 0x55D3, 0x4E27, 0x6414, 0x9A9A,
+		0x626B, 0x5AC2, 0x745F, 0x8272, 0x6DA9, 0x68EE, 0x50E7, 0x838E, 0x7802, 0x6740, 0x5239,
+		0x6C99, 0x7EB1, 0x50BB, 0x5565, 0x715E, 0x7B5B, 0x6652, 0x73CA, 0x82EB, 0x6749, 0x5C71,
+		0x5220, 0x717D, 0x886B, 0x95EA, 0x9655, 0x64C5, 0x8D61, 0x81B3, 0x5584, 0x6C55, 0x6247,
+		0x7F2E, 0x5892, 0x4F24, 0x5546, 0x8D4F, 0x664C, 0x4E0A, 0x5C1A, 0x88F3, 0x68A2, 0x634E,
+		0x7A0D, 0x70E7, 0x828D, 0x52FA, 0x97F6, 0x5C11, 0x54E8, 0x90B5, 0x7ECD, 0x5962, 0x8D4A,
+		0x86C7, 0x820C, 0x820D, 0x8D66, 0x6444, 0x5C04, 0x6151, 0x6D89, 0x793E, 0x8BBE, 0x7837,
+		0x7533, 0x547B, 0x4F38, 0x8EAB, 0x6DF1, 0x5A20, 0x7EC5, 0x795E, 0x6C88, 0x5BA1, 0x5A76,
+		0x751A, 0x80BE, 0x614E, 0x6E17, 0x58F0, 0x751F, 0x7525, 0x7272, 0x5347, 0x7EF3, 0x8503,
+		0x8504, 0x8505, 0x8506, 0x8507, 0x8508, 0x8509, 0x850A, 0x850B, 0x850D, 0x850E, 0x850F,
+		0x8510, 0x8512, 0x8514, 0x8515, 0x8516, 0x8518, 0x8519, 0x851B, 0x851C, 0x851D, 0x851E,
+		0x8520, 0x8522, 0x8523, 0x8524, 0x8525, 0x8526, 0x8527, 0x8528, 0x8529, 0x852A, 0x852D,
+		0x852E, 0x852F, 0x8530, 0x8531, 0x8532, 0x8533, 0x8534, 0x8535, 0x8536, 0x853E, 0x853F,
+		0x8540, 0x8541, 0x8542, 0x8544, 0x8545, 0x8546, 0x8547, 0x854B, 0x854C, 0x854D, 0x854E,
+		0x854F, 0x8550, 0x8551, 0x8552, 0x8553, 0x8554, 0x8555, 0x8557, 0x8558, 0x855A, 0x855B,
+		0x855C, 0x855D, 0x855F, 0x8560, 0x8561, 0x8562, 0x8563, 0x8565, 0x8566, 0x8567, 0x8569,
+		0x856A, 0x856B, 0x856C, 0x856D, 0x856E, 0x856F, 0x8570, 0x8571, 0x8573, 0x8575, 0x8576,
+		0x8577, 0x8578, 0x857C, 0x857D, 0x857F, 0x8580, 0x8581, 0x7701, 0x76DB, 0x5269, 0x80DC,
+		0x5723, 0x5E08, 0x5931, 0x72EE, 0x65BD, 0x6E7F, 0x8BD7, 0x5C38, 0x8671, 0x5341, 0x77F3,
+		0x62FE, 0x65F6, 0x4EC0, 0x98DF, 0x8680, 0x5B9E, 0x8BC6, 0x53F2, 0x77E2, 0x4F7F, 0x5C4E,
+		0x9A76, 0x59CB, 0x5F0F, 0x793A, 0x58EB, 0x4E16, 0x67FF, 0x4E8B, 0x62ED, 0x8A93, 0x901D,
+		0x52BF, 0x662F, 0x55DC, 0x566C, 0x9002, 0x4ED5, 0x4F8D, 0x91CA, 0x9970, 0x6C0F, 0x5E02,
+		0x6043, 0x5BA4, 0x89C6, 0x8BD5, 0x6536, 0x624B, 0x9996, 0x5B88, 0x5BFF, 0x6388, 0x552E,
+		0x53D7, 0x7626, 0x517D, 0x852C, 0x67A2, 0x68B3, 0x6B8A, 0x6292, 0x8F93, 0x53D4, 0x8212,
+		0x6DD1, 0x758F, 0x4E66, 0x8D4E, 0x5B70, 0x719F, 0x85AF, 0x6691, 0x66D9, 0x7F72, 0x8700,
+		0x9ECD, 0x9F20, 0x5C5E, 0x672F, 0x8FF0, 0x6811, 0x675F, 0x620D, 0x7AD6, 0x5885, 0x5EB6,
+		0x6570, 0x6F31, 0x8582, 0x8583, 0x8586, 0x8588, 0x8589, 0x858A, 0x858B, 0x858C, 0x858D,
+		0x858E, 0x8590, 0x8591, 0x8592, 0x8593, 0x8594, 0x8595, 0x8596, 0x8597, 0x8598, 0x8599,
+		0x859A, 0x859D, 0x859E, 0x859F, 0x85A0, 0x85A1, 0x85A2, 0x85A3, 0x85A5, 0x85A6, 0x85A7,
+		0x85A9, 0x85AB, 0x85AC, 0x85AD, 0x85B1, 0x85B2, 0x85B3, 0x85B4, 0x85B5, 0x85B6, 0x85B8,
+		0x85BA, 0x85BB, 0x85BC, 0x85BD, 0x85BE, 0x85BF, 0x85C0, 0x85C2, 0x85C3, 0x85C4, 0x85C5,
+		0x85C6, 0x85C7, 0x85C8, 0x85CA, 0x85CB, 0x85CC, 0x85CD, 0x85CE, 0x85D1, 0x85D2, 0x85D4,
+		0x85D6, 0x85D7, 0x85D8, 0x85D9, 0x85DA, 0x85DB, 0x85DD, 0x85DE, 0x85DF, 0x85E0, 0x85E1,
+		0x85E2, 0x85E3, 0x85E5, 0x85E6, 0x85E7, 0x85E8, 0x85EA, 0x85EB, 0x85EC, 0x85ED, 0x85EE,
+		0x85EF, 0x85F0, 0x85F1, 0x85F2, 0x85F3, 0x85F4, 0x85F5, 0x85F6, 0x85F7, 0x85F8, 0x6055,
+		0x5237, 0x800D, 0x6454, 0x8870, 0x7529, 0x5E05, 0x6813, 0x62F4, 0x971C, 0x53CC, 0x723D,
+		0x8C01, 0x6C34, 0x7761, 0x7A0E, 0x542E, 0x77AC, 0x987A, 0x821C, 0x8BF4, 0x7855, 0x6714,
+		0x70C1, 0x65AF, 0x6495, 0x5636, 0x601D, 0x79C1, 0x53F8, 0x4E1D, 0x6B7B, 0x8086, 0x5BFA,
+		0x55E3, 0x56DB, 0x4F3A, 0x4F3C, 0x9972, 0x5DF3, 0x677E, 0x8038, 0x6002, 0x9882, 0x9001,
+		0x5B8B, 0x8BBC, 0x8BF5, 0x641C, 0x8258, 0x64DE, 0x55FD, 0x82CF, 0x9165, 0x4FD7, 0x7D20,
+		0x901F, 0x7C9F, 0x50F3, 0x5851, 0x6EAF, 0x5BBF, 0x8BC9, 0x8083, 0x9178, 0x849C, 0x7B97,
+		0x867D, 0x968B, 0x968F, 0x7EE5, 0x9AD3, 0x788E, 0x5C81, 0x7A57, 0x9042, 0x96A7, 0x795F,
+		0x5B59, 0x635F, 0x7B0B, 0x84D1, 0x68AD, 0x5506, 0x7F29, 0x7410, 0x7D22, 0x9501, 0x6240,
+		0x584C, 0x4ED6, 0x5B83, 0x5979, 0x5854, 0x85F9, 0x85FA, 0x85FC, 0x85FD, 0x85FE, 0x8600,
+		0x8601, 0x8602, 0x8603, 0x8604, 0x8606, 0x8607, 0x8608, 0x8609, 0x860A, 0x860B, 0x860C,
+		0x860D, 0x860E, 0x860F, 0x8610, 0x8612, 0x8613, 0x8614, 0x8615, 0x8617, 0x8618, 0x8619,
+		0x861A, 0x861B, 0x861C, 0x861D, 0x861E, 0x861F, 0x8620, 0x8621, 0x8622, 0x8623, 0x8624,
+		0x8625, 0x8626, 0x8628, 0x862A, 0x862B, 0x862C, 0x862D, 0x862E, 0x862F, 0x8630, 0x8631,
+		0x8632, 0x8633, 0x8634, 0x8635, 0x8636, 0x8637, 0x8639, 0x863A, 0x863B, 0x863D, 0x863E,
+		0x863F, 0x8640, 0x8641, 0x8642, 0x8643, 0x8644, 0x8645, 0x8646, 0x8647, 0x8648, 0x8649,
+		0x864A, 0x864B, 0x864C, 0x8652, 0x8653, 0x8655, 0x8656, 0x8657, 0x8658, 0x8659, 0x865B,
+		0x865C, 0x865D, 0x865F, 0x8660, 0x8661, 0x8663, 0x8664, 0x8665, 0x8666, 0x8667, 0x8668,
+		0x8669, 0x866A, 0x736D, 0x631E, 0x8E4B, 0x8E0F, 0x80CE, 0x82D4, 0x62AC, 0x53F0, 0x6CF0,
+		0x915E, 0x592A, 0x6001, 0x6C70, 0x574D, 0x644A, 0x8D2A, 0x762B, 0x6EE9, 0x575B, 0x6A80,
+		0x75F0, 0x6F6D, 0x8C2D, 0x8C08, 0x5766, 0x6BEF, 0x8892, 0x78B3, 0x63A2, 0x53F9, 0x70AD,
+		0x6C64, 0x5858, 0x642A, 0x5802, 0x68E0, 0x819B, 0x5510, 0x7CD6, 0x5018, 0x8EBA, 0x6DCC,
+		0x8D9F, 0x70EB, 0x638F, 0x6D9B, 0x6ED4, 0x7EE6, 0x8404, 0x6843, 0x9003, 0x6DD8, 0x9676,
+		0x8BA8, 0x5957, 0x7279, 0x85E4, 0x817E, 0x75BC, 0x8A8A, 0x68AF, 0x5254, 0x8E22, 0x9511,
+		0x63D0, 0x9898, 0x8E44, 0x557C, 0x4F53, 0x66FF, 0x568F, 0x60D5, 0x6D95, 0x5243, 0x5C49,
+		0x5929, 0x6DFB, 0x586B, 0x7530, 0x751C, 0x606C, 0x8214, 0x8146, 0x6311, 0x6761, 0x8FE2,
+		0x773A, 0x8DF3, 0x8D34, 0x94C1, 0x5E16, 0x5385, 0x542C, 0x70C3, 0x866D, 0x866F, 0x8670,
+		0x8672, 0x8673, 0x8674, 0x8675, 0x8676, 0x8677, 0x8678, 0x8683, 0x8684, 0x8685, 0x8686,
+		0x8687, 0x8688, 0x8689, 0x868E, 0x868F, 0x8690, 0x8691, 0x8692, 0x8694, 0x8696, 0x8697,
+		0x8698, 0x8699, 0x869A, 0x869B, 0x869E, 0x869F, 0x86A0, 0x86A1, 0x86A2, 0x86A5, 0x86A6,
+		0x86AB, 0x86AD, 0x86AE, 0x86B2, 0x86B3, 0x86B7, 0x86B8, 0x86B9, 0x86BB, 0x86BC, 0x86BD,
+		0x86BE, 0x86BF, 0x86C1, 0x86C2, 0x86C3, 0x86C5, 0x86C8, 0x86CC, 0x86CD, 0x86D2, 0x86D3,
+		0x86D5, 0x86D6, 0x86D7, 0x86DA, 0x86DC, 0x86DD, 0x86E0, 0x86E1, 0x86E2, 0x86E3, 0x86E5,
+		0x86E6, 0x86E7, 0x86E8, 0x86EA, 0x86EB, 0x86EC, 0x86EF, 0x86F5, 0x86F6, 0x86F7, 0x86FA,
+		0x86FB, 0x86FC, 0x86FD, 0x86FF, 0x8701, 0x8704, 0x8705, 0x8706, 0x870B, 0x870C, 0x870E,
+		0x870F, 0x8710, 0x8711, 0x8714, 0x8716, 0x6C40, 0x5EF7, 0x505C, 0x4EAD, 0x5EAD, 0x633A,
+		0x8247, 0x901A, 0x6850, 0x916E, 0x77B3, 0x540C, 0x94DC, 0x5F64, 0x7AE5, 0x6876, 0x6345,
+		0x7B52, 0x7EDF, 0x75DB, 0x5077, 0x6295, 0x5934, 0x900F, 0x51F8, 0x79C3, 0x7A81, 0x56FE,
+		0x5F92, 0x9014, 0x6D82, 0x5C60, 0x571F, 0x5410, 0x5154, 0x6E4D, 0x56E2, 0x63A8, 0x9893,
+		0x817F, 0x8715, 0x892A, 0x9000, 0x541E, 0x5C6F, 0x81C0, 0x62D6, 0x6258, 0x8131, 0x9E35,
+		0x9640, 0x9A6E, 0x9A7C, 0x692D, 0x59A5, 0x62D3, 0x553E, 0x6316, 0x54C7, 0x86D9, 0x6D3C,
+		0x5A03, 0x74E6, 0x889C, 0x6B6A, 0x5916, 0x8C4C, 0x5F2F, 0x6E7E, 0x73A9, 0x987D, 0x4E38,
+		0x70F7, 0x5B8C, 0x7897, 0x633D, 0x665A, 0x7696, 0x60CB, 0x5B9B, 0x5A49, 0x4E07, 0x8155,
+		0x6C6A, 0x738B, 0x4EA1, 0x6789, 0x7F51, 0x5F80, 0x65FA, 0x671B, 0x5FD8, 0x5984, 0x5A01,
+		0x8719, 0x871B, 0x871D, 0x871F, 0x8720, 0x8724, 0x8726, 0x8727, 0x8728, 0x872A, 0x872B,
+		0x872C, 0x872D, 0x872F, 0x8730, 0x8732, 0x8733, 0x8735, 0x8736, 0x8738, 0x8739, 0x873A,
+		0x873C, 0x873D, 0x8740, 0x8741, 0x8742, 0x8743, 0x8744, 0x8745, 0x8746, 0x874A, 0x874B,
+		0x874D, 0x874F, 0x8750, 0x8751, 0x8752, 0x8754, 0x8755, 0x8756, 0x8758, 0x875A, 0x875B,
+		0x875C, 0x875D, 0x875E, 0x875F, 0x8761, 0x8762, 0x8766, 0x8767, 0x8768, 0x8769, 0x876A,
+		0x876B, 0x876C, 0x876D, 0x876F, 0x8771, 0x8772, 0x8773, 0x8775, 0x8777, 0x8778, 0x8779,
+		0x877A, 0x877F, 0x8780, 0x8781, 0x8784, 0x8786, 0x8787, 0x8789, 0x878A, 0x878C, 0x878E,
+		0x878F, 0x8790, 0x8791, 0x8792, 0x8794, 0x8795, 0x8796, 0x8798, 0x8799, 0x879A, 0x879B,
+		0x879C, 0x879D, 0x879E, 0x87A0, 0x87A1, 0x87A2, 0x87A3, 0x87A4, 0x5DCD, 0x5FAE, 0x5371,
+		0x97E6, 0x8FDD, 0x6845, 0x56F4, 0x552F, 0x60DF, 0x4E3A, 0x6F4D, 0x7EF4, 0x82C7, 0x840E,
+		0x59D4, 0x4F1F, 0x4F2A, 0x5C3E, 0x7EAC, 0x672A, 0x851A, 0x5473, 0x754F, 0x80C3, 0x5582,
+		0x9B4F, 0x4F4D, 0x6E2D, 0x8C13, 0x5C09, 0x6170, 0x536B, 0x761F, 0x6E29, 0x868A, 0x6587,
+		0x95FB, 0x7EB9, 0x543B, 0x7A33, 0x7D0A, 0x95EE, 0x55E1, 0x7FC1, 0x74EE, 0x631D, 0x8717,
+		0x6DA1, 0x7A9D, 0x6211, 0x65A1, 0x5367, 0x63E1, 0x6C83, 0x5DEB, 0x545C, 0x94A8, 0x4E4C,
+		0x6C61, 0x8BEC, 0x5C4B, 0x65E0, 0x829C, 0x68A7, 0x543E, 0x5434, 0x6BCB, 0x6B66, 0x4E94,
+		0x6342, 0x5348, 0x821E, 0x4F0D, 0x4FAE, 0x575E, 0x620A, 0x96FE, 0x6664, 0x7269, 0x52FF,
+		0x52A1, 0x609F, 0x8BEF, 0x6614, 0x7199, 0x6790, 0x897F, 0x7852, 0x77FD, 0x6670, 0x563B,
+		0x5438, 0x9521, 0x727A, 0x87A5, 0x87A6, 0x87A7, 0x87A9, 0x87AA, 0x87AE, 0x87B0, 0x87B1,
+		0x87B2, 0x87B4, 0x87B6, 0x87B7, 0x87B8, 0x87B9, 0x87BB, 0x87BC, 0x87BE, 0x87BF, 0x87C1,
+		0x87C2, 0x87C3, 0x87C4, 0x87C5, 0x87C7, 0x87C8, 0x87C9, 0x87CC, 0x87CD, 0x87CE, 0x87CF,
+		0x87D0, 0x87D4, 0x87D5, 0x87D6, 0x87D7, 0x87D8, 0x87D9, 0x87DA, 0x87DC, 0x87DD, 0x87DE,
+		0x87DF, 0x87E1, 0x87E2, 0x87E3, 0x87E4, 0x87E6, 0x87E7, 0x87E8, 0x87E9, 0x87EB, 0x87EC,
+		0x87ED, 0x87EF, 0x87F0, 0x87F1, 0x87F2, 0x87F3, 0x87F4, 0x87F5, 0x87F6, 0x87F7, 0x87F8,
+		0x87FA, 0x87FB, 0x87FC, 0x87FD, 0x87FF, 0x8800, 0x8801, 0x8802, 0x8804, 0x8805, 0x8806,
+		0x8807, 0x8808, 0x8809, 0x880B, 0x880C, 0x880D, 0x880E, 0x880F, 0x8810, 0x8811, 0x8812,
+		0x8814, 0x8817, 0x8818, 0x8819, 0x881A, 0x881C, 0x881D, 0x881E, 0x881F, 0x8820, 0x8823,
+		0x7A00, 0x606F, 0x5E0C, 0x6089, 0x819D, 0x5915, 0x60DC, 0x7184, 0x70EF, 0x6EAA, 0x6C50,
+		0x7280, 0x6A84, 0x88AD, 0x5E2D, 0x4E60, 0x5AB3, 0x559C, 0x94E3, 0x6D17, 0x7CFB, 0x9699,
+		0x620F, 0x7EC6, 0x778E, 0x867E, 0x5323, 0x971E, 0x8F96, 0x6687, 0x5CE1, 0x4FA0, 0x72ED,
+		0x4E0B, 0x53A6, 0x590F, 0x5413, 0x6380, 0x9528, 0x5148, 0x4ED9, 0x9C9C, 0x7EA4, 0x54B8,
+		0x8D24, 0x8854, 0x8237, 0x95F2, 0x6D8E, 0x5F26, 0x5ACC, 0x663E, 0x9669, 0x73B0, 0x732E,
+		0x53BF, 0x817A, 0x9985, 0x7FA1, 0x5BAA, 0x9677, 0x9650, 0x7EBF, 0x76F8, 0x53A2, 0x9576,
+		0x9999, 0x7BB1, 0x8944, 0x6E58, 0x4E61, 0x7FD4, 0x7965, 0x8BE6, 0x60F3, 0x54CD, 0x4EAB,
+		0x9879, 0x5DF7, 0x6A61, 0x50CF, 0x5411, 0x8C61, 0x8427, 0x785D, 0x9704, 0x524A, 0x54EE,
+		0x56A3, 0x9500, 0x6D88, 0x5BB5, 0x6DC6, 0x6653, 0x8824, 0x8825, 0x8826, 0x8827, 0x8828,
+		0x8829, 0x882A, 0x882B, 0x882C, 0x882D, 0x882E, 0x882F, 0x8830, 0x8831, 0x8833, 0x8834,
+		0x8835, 0x8836, 0x8837, 0x8838, 0x883A, 0x883B, 0x883D, 0x883E, 0x883F, 0x8841, 0x8842,
+		0x8843, 0x8846, 0x8847, 0x8848, 0x8849, 0x884A, 0x884B, 0x884E, 0x884F, 0x8850, 0x8851,
+		0x8852, 0x8853, 0x8855, 0x8856, 0x8858, 0x885A, 0x885B, 0x885C, 0x885D, 0x885E, 0x885F,
+		0x8860, 0x8866, 0x8867, 0x886A, 0x886D, 0x886F, 0x8871, 0x8873, 0x8874, 0x8875, 0x8876,
+		0x8878, 0x8879, 0x887A, 0x887B, 0x887C, 0x8880, 0x8883, 0x8886, 0x8887, 0x8889, 0x888A,
+		0x888C, 0x888E, 0x888F, 0x8890, 0x8891, 0x8893, 0x8894, 0x8895, 0x8897, 0x8898, 0x8899,
+		0x889A, 0x889B, 0x889D, 0x889E, 0x889F, 0x88A0, 0x88A1, 0x88A3, 0x88A5, 0x88A6, 0x88A7,
+		0x88A8, 0x88A9, 0x88AA, 0x5C0F, 0x5B5D, 0x6821, 0x8096, 0x5578, 0x7B11, 0x6548, 0x6954,
+		0x4E9B, 0x6B47, 0x874E, 0x978B, 0x534F, 0x631F, 0x643A, 0x90AA, 0x659C, 0x80C1, 0x8C10,
+		0x5199, 0x68B0, 0x5378, 0x87F9, 0x61C8, 0x6CC4, 0x6CFB, 0x8C22, 0x5C51, 0x85AA, 0x82AF,
+		0x950C, 0x6B23, 0x8F9B, 0x65B0, 0x5FFB, 0x5FC3, 0x4FE1, 0x8845, 0x661F, 0x8165, 0x7329,
+		0x60FA, 0x5174, 0x5211, 0x578B, 0x5F62, 0x90A2, 0x884C, 0x9192, 0x5E78, 0x674F, 0x6027,
+		0x59D3, 0x5144, 0x51F6, 0x80F8, 0x5308, 0x6C79, 0x96C4, 0x718A, 0x4F11, 0x4FEE, 0x7F9E,
+		0x673D, 0x55C5, 0x9508, 0x79C0, 0x8896, 0x7EE3, 0x589F, 0x620C, 0x9700, 0x865A, 0x5618,
+		0x987B, 0x5F90, 0x8BB8, 0x84C4, 0x9157, 0x53D9, 0x65ED, 0x5E8F, 0x755C, 0x6064, 0x7D6E,
+		0x5A7F, 0x7EEA, 0x7EED, 0x8F69, 0x55A7, 0x5BA3, 0x60AC, 0x65CB, 0x7384, 0x88AC, 0x88AE,
+		0x88AF, 0x88B0, 0x88B2, 0x88B3, 0x88B4, 0x88B5, 0x88B6, 0x88B8, 0x88B9, 0x88BA, 0x88BB,
+		0x88BD, 0x88BE, 0x88BF, 0x88C0, 0x88C3, 0x88C4, 0x88C7, 0x88C8, 0x88CA, 0x88CB, 0x88CC,
+		0x88CD, 0x88CF, 0x88D0, 0x88D1, 0x88D3, 0x88D6, 0x88D7, 0x88DA, 0x88DB, 0x88DC, 0x88DD,
+		0x88DE, 0x88E0, 0x88E1, 0x88E6, 0x88E7, 0x88E9, 0x88EA, 0x88EB, 0x88EC, 0x88ED, 0x88EE,
+		0x88EF, 0x88F2, 0x88F5, 0x88F6, 0x88F7, 0x88FA, 0x88FB, 0x88FD, 0x88FF, 0x8900, 0x8901,
+		0x8903, 0x8904, 0x8905, 0x8906, 0x8907, 0x8908, 0x8909, 0x890B, 0x890C, 0x890D, 0x890E,
+		0x890F, 0x8911, 0x8914, 0x8915, 0x8916, 0x8917, 0x8918, 0x891C, 0x891D, 0x891E, 0x891F,
+		0x8920, 0x8922, 0x8923, 0x8924, 0x8926, 0x8927, 0x8928, 0x8929, 0x892C, 0x892D, 0x892E,
+		0x892F, 0x8931, 0x8932, 0x8933, 0x8935, 0x8937, 0x9009, 0x7663, 0x7729, 0x7EDA, 0x9774,
+		0x859B, 0x5B66, 0x7A74, 0x96EA, 0x8840, 0x52CB, 0x718F, 0x5FAA, 0x65EC, 0x8BE2, 0x5BFB,
+		0x9A6F, 0x5DE1, 0x6B89, 0x6C5B, 0x8BAD, 0x8BAF, 0x900A, 0x8FC5, 0x538B, 0x62BC, 0x9E26,
+		0x9E2D, 0x5440, 0x4E2B, 0x82BD, 0x7259, 0x869C, 0x5D16, 0x8859, 0x6DAF, 0x96C5, 0x54D1,
+		0x4E9A, 0x8BB6, 0x7109, 0x54BD, 0x9609, 0x70DF, 0x6DF9, 0x76D0, 0x4E25, 0x7814, 0x8712,
+		0x5CA9, 0x5EF6, 0x8A00, 0x989C, 0x960E, 0x708E, 0x6CBF, 0x5944, 0x63A9, 0x773C, 0x884D,
+		0x6F14, 0x8273, 0x5830, 0x71D5, 0x538C, 0x781A, 0x96C1, 0x5501, 0x5F66, 0x7130, 0x5BB4,
+		0x8C1A, 0x9A8C, 0x6B83, 0x592E, 0x9E2F, 0x79E7, 0x6768, 0x626C, 0x4F6F, 0x75A1, 0x7F8A,
+		0x6D0B, 0x9633, 0x6C27, 0x4EF0, 0x75D2, 0x517B, 0x6837, 0x6F3E, 0x9080, 0x8170, 0x5996,
+		0x7476, 0x8938, 0x8939, 0x893A, 0x893B, 0x893C, 0x893D, 0x893E, 0x893F, 0x8940, 0x8942,
+		0x8943, 0x8945, 0x8946, 0x8947, 0x8948, 0x8949, 0x894A, 0x894B, 0x894C, 0x894D, 0x894E,
+		0x894F, 0x8950, 0x8951, 0x8952, 0x8953, 0x8954, 0x8955, 0x8956, 0x8957, 0x8958, 0x8959,
+		0x895A, 0x895B, 0x895C, 0x895D, 0x8960, 0x8961, 0x8962, 0x8963, 0x8964, 0x8965, 0x8967,
+		0x8968, 0x8969, 0x896A, 0x896B, 0x896C, 0x896D, 0x896E, 0x896F, 0x8970, 0x8971, 0x8972,
+		0x8973, 0x8974, 0x8975, 0x8976, 0x8977, 0x8978, 0x8979, 0x897A, 0x897C, 0x897D, 0x897E,
+		0x8980, 0x8982, 0x8984, 0x8985, 0x8987, 0x8988, 0x8989, 0x898A, 0x898B, 0x898C, 0x898D,
+		0x898E, 0x898F, 0x8990, 0x8991, 0x8992, 0x8993, 0x8994, 0x8995, 0x8996, 0x8997, 0x8998,
+		0x8999, 0x899A, 0x899B, 0x899C, 0x899D, 0x899E, 0x899F, 0x89A0, 0x89A1, 0x6447, 0x5C27,
+		0x9065, 0x7A91, 0x8C23, 0x59DA, 0x54AC, 0x8200, 0x836F, 0x8981, 0x8000, 0x6930, 0x564E,
+		0x8036, 0x7237, 0x91CE, 0x51B6, 0x4E5F, 0x9875, 0x6396, 0x4E1A, 0x53F6, 0x66F3, 0x814B,
+		0x591C, 0x6DB2, 0x4E00, 0x58F9, 0x533B, 0x63D6, 0x94F1, 0x4F9D, 0x4F0A, 0x8863, 0x9890,
+		0x5937, 0x9057, 0x79FB, 0x4EEA, 0x80F0, 0x7591, 0x6C82, 0x5B9C, 0x59E8, 0x5F5D, 0x6905,
+		0x8681, 0x501A, 0x5DF2, 0x4E59, 0x77E3, 0x4EE5, 0x827A, 0x6291, 0x6613, 0x9091, 0x5C79,
+		0x4EBF, 0x5F79, 0x81C6, 0x9038, 0x8084, 0x75AB, 0x4EA6, 0x88D4, 0x610F, 0x6BC5, 0x5FC6,
+		0x4E49, 0x76CA, 0x6EA2, 0x8BE3, 0x8BAE, 0x8C0A, 0x8BD1, 0x5F02, 0x7FFC, 0x7FCC, 0x7ECE,
+		0x8335, 0x836B, 0x56E0, 0x6BB7, 0x97F3, 0x9634, 0x59FB, 0x541F, 0x94F6, 0x6DEB, 0x5BC5,
+		0x996E, 0x5C39, 0x5F15, 0x9690, 0x89A2, 0x89A3, 0x89A4, 0x89A5, 0x89A6, 0x89A7, 0x89A8,
+		0x89A9, 0x89AA, 0x89AB, 0x89AC, 0x89AD, 0x89AE, 0x89AF, 0x89B0, 0x89B1, 0x89B2, 0x89B3,
+		0x89B4, 0x89B5, 0x89B6, 0x89B7, 0x89B8, 0x89B9, 0x89BA, 0x89BB, 0x89BC, 0x89BD, 0x89BE,
+		0x89BF, 0x89C0, 0x89C3, 0x89CD, 0x89D3, 0x89D4, 0x89D5, 0x89D7, 0x89D8, 0x89D9, 0x89DB,
+		0x89DD, 0x89DF, 0x89E0, 0x89E1, 0x89E2, 0x89E4, 0x89E7, 0x89E8, 0x89E9, 0x89EA, 0x89EC,
+		0x89ED, 0x89EE, 0x89F0, 0x89F1, 0x89F2, 0x89F4, 0x89F5, 0x89F6, 0x89F7, 0x89F8, 0x89F9,
+		0x89FA, 0x89FB, 0x89FC, 0x89FD, 0x89FE, 0x89FF, 0x8A01, 0x8A02, 0x8A03, 0x8A04, 0x8A05,
+		0x8A06, 0x8A08, 0x8A09, 0x8A0A, 0x8A0B, 0x8A0C, 0x8A0D, 0x8A0E, 0x8A0F, 0x8A10, 0x8A11,
+		0x8A12, 0x8A13, 0x8A14, 0x8A15, 0x8A16, 0x8A17, 0x8A18, 0x8A19, 0x8A1A, 0x8A1B, 0x8A1C,
+		0x8A1D, 0x5370, 0x82F1, 0x6A31, 0x5A74, 0x9E70, 0x5E94, 0x7F28, 0x83B9, 0x8424, 0x8425,
+		0x8367, 0x8747, 0x8FCE, 0x8D62, 0x76C8, 0x5F71, 0x9896, 0x786C, 0x6620, 0x54DF, 0x62E5,
+		0x4F63, 0x81C3, 0x75C8, 0x5EB8, 0x96CD, 0x8E0A, 0x86F9, 0x548F, 0x6CF3, 0x6D8C, 0x6C38,
+		0x607F, 0x52C7, 0x7528, 0x5E7D, 0x4F18, 0x60A0, 0x5FE7, 0x5C24, 0x7531, 0x90AE, 0x94C0,
+		0x72B9, 0x6CB9, 0x6E38, 0x9149, 0x6709, 0x53CB, 0x53F3, 0x4F51, 0x91C9, 0x8BF1, 0x53C8,
+		0x5E7C, 0x8FC2, 0x6DE4, 0x4E8E, 0x76C2, 0x6986, 0x865E, 0x611A, 0x8206, 0x4F59, 0x4FDE,
+		0x903E, 0x9C7C, 0x6109, 0x6E1D, 0x6E14, 0x9685, 0x4E88, 0x5A31, 0x96E8, 0x4E0E, 0x5C7F,
+		0x79B9, 0x5B87, 0x8BED, 0x7FBD, 0x7389, 0x57DF, 0x828B, 0x90C1, 0x5401, 0x9047, 0x55BB,
+		0x5CEA, 0x5FA1, 0x6108, 0x6B32, 0x72F1, 0x80B2, 0x8A89, 0x8A1E, 0x8A1F, 0x8A20, 0x8A21,
+		0x8A22, 0x8A23, 0x8A24, 0x8A25, 0x8A26, 0x8A27, 0x8A28, 0x8A29, 0x8A2A, 0x8A2B, 0x8A2C,
+		0x8A2D, 0x8A2E, 0x8A2F, 0x8A30, 0x8A31, 0x8A32, 0x8A33, 0x8A34, 0x8A35, 0x8A36, 0x8A37,
+		0x8A38, 0x8A39, 0x8A3A, 0x8A3B, 0x8A3C, 0x8A3D, 0x8A3F, 0x8A40, 0x8A41, 0x8A42, 0x8A43,
+		0x8A44, 0x8A45, 0x8A46, 0x8A47, 0x8A49, 0x8A4A, 0x8A4B, 0x8A4C, 0x8A4D, 0x8A4E, 0x8A4F,
+		0x8A50, 0x8A51, 0x8A52, 0x8A53, 0x8A54, 0x8A55, 0x8A56, 0x8A57, 0x8A58, 0x8A59, 0x8A5A,
+		0x8A5B, 0x8A5C, 0x8A5D, 0x8A5E, 0x8A5F, 0x8A60, 0x8A61, 0x8A62, 0x8A63, 0x8A64, 0x8A65,
+		0x8A66, 0x8A67, 0x8A68, 0x8A69, 0x8A6A, 0x8A6B, 0x8A6C, 0x8A6D, 0x8A6E, 0x8A6F, 0x8A70,
+		0x8A71, 0x8A72, 0x8A73, 0x8A74, 0x8A75, 0x8A76, 0x8A77, 0x8A78, 0x8A7A, 0x8A7B, 0x8A7C,
+		0x8A7D, 0x8A7E, 0x8A7F, 0x8A80, 0x6D74, 0x5BD3, 0x88D5, 0x9884, 0x8C6B, 0x9A6D, 0x9E33,
+		0x6E0A, 0x51A4, 0x5143, 0x57A3, 0x8881, 0x539F, 0x63F4, 0x8F95, 0x56ED, 0x5458, 0x5706,
+		0x733F, 0x6E90, 0x7F18, 0x8FDC, 0x82D1, 0x613F, 0x6028, 0x9662, 0x66F0, 0x7EA6, 0x8D8A,
+		0x8DC3, 0x94A5, 0x5CB3, 0x7CA4, 0x6708, 0x60A6, 0x9605, 0x8018, 0x4E91, 0x90E7, 0x5300,
+		0x9668, 0x5141, 0x8FD0, 0x8574, 0x915D, 0x6655, 0x97F5, 0x5B55, 0x531D, 0x7838, 0x6742,
+		0x683D, 0x54C9, 0x707E, 0x5BB0, 0x8F7D, 0x518D, 0x5728, 0x54B1, 0x6512, 0x6682, 0x8D5E,
+		0x8D43, 0x810F, 0x846C, 0x906D, 0x7CDF, 0x51FF, 0x85FB, 0x67A3, 0x65E9, 0x6FA1, 0x86A4,
+		0x8E81, 0x566A, 0x9020, 0x7682, 0x7076, 0x71E5, 0x8D23, 0x62E9, 0x5219, 0x6CFD, 0x8D3C,
+		0x600E, 0x589E, 0x618E, 0x66FE, 0x8D60, 0x624E, 0x55B3, 0x6E23, 0x672D, 0x8F67, 0x8A81,
+		0x8A82, 0x8A83, 0x8A84, 0x8A85, 0x8A86, 0x8A87, 0x8A88, 0x8A8B, 0x8A8C, 0x8A8D, 0x8A8E,
+		0x8A8F, 0x8A90, 0x8A91, 0x8A92, 0x8A94, 0x8A95, 0x8A96, 0x8A97, 0x8A98, 0x8A99, 0x8A9A,
+		0x8A9B, 0x8A9C, 0x8A9D, 0x8A9E, 0x8A9F, 0x8AA0, 0x8AA1, 0x8AA2, 0x8AA3, 0x8AA4, 0x8AA5,
+		0x8AA6, 0x8AA7, 0x8AA8, 0x8AA9, 0x8AAA, 0x8AAB, 0x8AAC, 0x8AAD, 0x8AAE, 0x8AAF, 0x8AB0,
+		0x8AB1, 0x8AB2, 0x8AB3, 0x8AB4, 0x8AB5, 0x8AB6, 0x8AB7, 0x8AB8, 0x8AB9, 0x8ABA, 0x8ABB,
+		0x8ABC, 0x8ABD, 0x8ABE, 0x8ABF, 0x8AC0, 0x8AC1, 0x8AC2, 0x8AC3, 0x8AC4, 0x8AC5, 0x8AC6,
+		0x8AC7, 0x8AC8, 0x8AC9, 0x8ACA, 0x8ACB, 0x8ACC, 0x8ACD, 0x8ACE, 0x8ACF, 0x8AD0, 0x8AD1,
+		0x8AD2, 0x8AD3, 0x8AD4, 0x8AD5, 0x8AD6, 0x8AD7, 0x8AD8, 0x8AD9, 0x8ADA, 0x8ADB, 0x8ADC,
+		0x8ADD, 0x8ADE, 0x8ADF, 0x8AE0, 0x8AE1, 0x8AE2, 0x8AE3, 0x94E1, 0x95F8, 0x7728, 0x6805,
+		0x69A8, 0x548B, 0x4E4D, 0x70B8, 0x8BC8, 0x6458, 0x658B, 0x5B85, 0x7A84, 0x503A, 0x5BE8,
+		0x77BB, 0x6BE1, 0x8A79, 0x7C98, 0x6CBE, 0x76CF, 0x65A9, 0x8F97, 0x5D2D, 0x5C55, 0x8638,
+		0x6808, 0x5360, 0x6218, 0x7AD9, 0x6E5B, 0x7EFD, 0x6A1F, 0x7AE0, 0x5F70, 0x6F33, 0x5F20,
+		0x638C, 0x6DA8, 0x6756, 0x4E08, 0x5E10, 0x8D26, 0x4ED7, 0x80C0, 0x7634, 0x969C, 0x62DB,
+		0x662D, 0x627E, 0x6CBC, 0x8D75, 0x7167, 0x7F69, 0x5146, 0x8087, 0x53EC, 0x906E, 0x6298,
+		0x54F2, 0x86F0, 0x8F99, 0x8005, 0x9517, 0x8517, 0x8FD9, 0x6D59, 0x73CD, 0x659F, 0x771F,
+		0x7504, 0x7827, 0x81FB, 0x8D1E, 0x9488, 0x4FA6, 0x6795, 0x75B9, 0x8BCA, 0x9707, 0x632F,
+		0x9547, 0x9635, 0x84B8, 0x6323, 0x7741, 0x5F81, 0x72F0, 0x4E89, 0x6014, 0x6574, 0x62EF,
+		0x6B63, 0x653F, 0x8AE4, 0x8AE5, 0x8AE6, 0x8AE7, 0x8AE8, 0x8AE9, 0x8AEA, 0x8AEB, 0x8AEC,
+		0x8AED, 0x8AEE, 0x8AEF, 0x8AF0, 0x8AF1, 0x8AF2, 0x8AF3, 0x8AF4, 0x8AF5, 0x8AF6, 0x8AF7,
+		0x8AF8, 0x8AF9, 0x8AFA, 0x8AFB, 0x8AFC, 0x8AFD, 0x8AFE, 0x8AFF, 0x8B00, 0x8B01, 0x8B02,
+		0x8B03, 0x8B04, 0x8B05, 0x8B06, 0x8B08, 0x8B09, 0x8B0A, 0x8B0B, 0x8B0C, 0x8B0D, 0x8B0E,
+		0x8B0F, 0x8B10, 0x8B11, 0x8B12, 0x8B13, 0x8B14, 0x8B15, 0x8B16, 0x8B17, 0x8B18, 0x8B19,
+		0x8B1A, 0x8B1B, 0x8B1C, 0x8B1D, 0x8B1E, 0x8B1F, 0x8B20, 0x8B21, 0x8B22, 0x8B23, 0x8B24,
+		0x8B25, 0x8B27, 0x8B28, 0x8B29, 0x8B2A, 0x8B2B, 0x8B2C, 0x8B2D, 0x8B2E, 0x8B2F, 0x8B30,
+		0x8B31, 0x8B32, 0x8B33, 0x8B34, 0x8B35, 0x8B36, 0x8B37, 0x8B38, 0x8B39, 0x8B3A, 0x8B3B,
+		0x8B3C, 0x8B3D, 0x8B3E, 0x8B3F, 0x8B40, 0x8B41, 0x8B42, 0x8B43, 0x8B44, 0x8B45, 0x5E27,
+		0x75C7, 0x90D1, 0x8BC1, 0x829D, 0x679D, 0x652F, 0x5431, 0x8718, 0x77E5, 0x80A2, 0x8102,
+		0x6C41, 0x4E4B, 0x7EC7, 0x804C, 0x76F4, 0x690D, 0x6B96, 0x6267, 0x503C, 0x4F84, 0x5740,
+		0x6307, 0x6B62, 0x8DBE, 0x53EA, 0x65E8, 0x7EB8, 0x5FD7, 0x631A, 0x63B7, 0x81F3, 0x81F4,
+		0x7F6E, 0x5E1C, 0x5CD9, 0x5236, 0x667A, 0x79E9, 0x7A1A, 0x8D28, 0x7099, 0x75D4, 0x6EDE,
+		0x6CBB, 0x7A92, 0x4E2D, 0x76C5, 0x5FE0, 0x949F, 0x8877, 0x7EC8, 0x79CD, 0x80BF, 0x91CD,
+		0x4EF2, 0x4F17, 0x821F, 0x5468, 0x5DDE, 0x6D32, 0x8BCC, 0x7CA5, 0x8F74, 0x8098, 0x5E1A,
+		0x5492, 0x76B1, 0x5B99, 0x663C, 0x9AA4, 0x73E0, 0x682A, 0x86DB, 0x6731, 0x732A, 0x8BF8,
+		0x8BDB, 0x9010, 0x7AF9, 0x70DB, 0x716E, 0x62C4, 0x77A9, 0x5631, 0x4E3B, 0x8457, 0x67F1,
+		0x52A9, 0x86C0, 0x8D2E, 0x94F8, 0x7B51, 0x8B46, 0x8B47, 0x8B48, 0x8B49, 0x8B4A, 0x8B4B,
+		0x8B4C, 0x8B4D, 0x8B4E, 0x8B4F, 0x8B50, 0x8B51, 0x8B52, 0x8B53, 0x8B54, 0x8B55, 0x8B56,
+		0x8B57, 0x8B58, 0x8B59, 0x8B5A, 0x8B5B, 0x8B5C, 0x8B5D, 0x8B5E, 0x8B5F, 0x8B60, 0x8B61,
+		0x8B62, 0x8B63, 0x8B64, 0x8B65, 0x8B67, 0x8B68, 0x8B69, 0x8B6A, 0x8B6B, 0x8B6D, 0x8B6E,
+		0x8B6F, 0x8B70, 0x8B71, 0x8B72, 0x8B73, 0x8B74, 0x8B75, 0x8B76, 0x8B77, 0x8B78, 0x8B79,
+		0x8B7A, 0x8B7B, 0x8B7C, 0x8B7D, 0x8B7E, 0x8B7F, 0x8B80, 0x8B81, 0x8B82, 0x8B83, 0x8B84,
+		0x8B85, 0x8B86, 0x8B87, 0x8B88, 0x8B89, 0x8B8A, 0x8B8B, 0x8B8C, 0x8B8D, 0x8B8E, 0x8B8F,
+		0x8B90, 0x8B91, 0x8B92, 0x8B93, 0x8B94, 0x8B95, 0x8B96, 0x8B97, 0x8B98, 0x8B99, 0x8B9A,
+		0x8B9B, 0x8B9C, 0x8B9D, 0x8B9E, 0x8B9F, 0x8BAC, 0x8BB1, 0x8BBB, 0x8BC7, 0x8BD0, 0x8BEA,
+		0x8C09, 0x8C1E, 0x4F4F, 0x6CE8, 0x795D, 0x9A7B, 0x6293, 0x722A, 0x62FD, 0x4E13, 0x7816,
+		0x8F6C, 0x64B0, 0x8D5A, 0x7BC6, 0x6869, 0x5E84, 0x88C5, 0x5986, 0x649E, 0x58EE, 0x72B6,
+		0x690E, 0x9525, 0x8FFD, 0x8D58, 0x5760, 0x7F00, 0x8C06, 0x51C6, 0x6349, 0x62D9, 0x5353,
+		0x684C, 0x7422, 0x8301, 0x914C, 0x5544, 0x7740, 0x707C, 0x6D4A, 0x5179, 0x54A8, 0x8D44,
+		0x59FF, 0x6ECB, 0x6DC4, 0x5B5C, 0x7D2B, 0x4ED4, 0x7C7D, 0x6ED3, 0x5B50, 0x81EA, 0x6E0D,
+		0x5B57, 0x9B03, 0x68D5, 0x8E2A, 0x5B97, 0x7EFC, 0x603B, 0x7EB5, 0x90B9, 0x8D70, 0x594F,
+		0x63CD, 0x79DF, 0x8DB3, 0x5352, 0x65CF, 0x7956, 0x8BC5, 0x963B, 0x7EC4, 0x94BB, 0x7E82,
+		0x5634, 0x9189, 0x6700, 0x7F6A, 0x5C0A, 0x9075, 0x6628, 0x5DE6, 0x4F50, 0x67DE, 0x505A,
+		0x4F5C, 0x5750, 0x5EA7, 0xE810, 0xE811, 0xE812, 0xE813, 0xE814, 0x8C38, 0x8C39, 0x8C3A,
+		0x8C3B, 0x8C3C, 0x8C3D, 0x8C3E, 0x8C3F, 0x8C40, 0x8C42, 0x8C43, 0x8C44, 0x8C45, 0x8C48,
+		0x8C4A, 0x8C4B, 0x8C4D, 0x8C4E, 0x8C4F, 0x8C50, 0x8C51, 0x8C52, 0x8C53, 0x8C54, 0x8C56,
+		0x8C57, 0x8C58, 0x8C59, 0x8C5B, 0x8C5C, 0x8C5D, 0x8C5E, 0x8C5F, 0x8C60, 0x8C63, 0x8C64,
+		0x8C65, 0x8C66, 0x8C67, 0x8C68, 0x8C69, 0x8C6C, 0x8C6D, 0x8C6E, 0x8C6F, 0x8C70, 0x8C71,
+		0x8C72, 0x8C74, 0x8C75, 0x8C76, 0x8C77, 0x8C7B, 0x8C7C, 0x8C7D, 0x8C7E, 0x8C7F, 0x8C80,
+		0x8C81, 0x8C83, 0x8C84, 0x8C86, 0x8C87, 0x8C88, 0x8C8B, 0x8C8D, 0x8C8E, 0x8C8F, 0x8C90,
+		0x8C91, 0x8C92, 0x8C93, 0x8C95, 0x8C96, 0x8C97, 0x8C99, 0x8C9A, 0x8C9B, 0x8C9C, 0x8C9D,
+		0x8C9E, 0x8C9F, 0x8CA0, 0x8CA1, 0x8CA2, 0x8CA3, 0x8CA4, 0x8CA5, 0x8CA6, 0x8CA7, 0x8CA8,
+		0x8CA9, 0x8CAA, 0x8CAB, 0x8CAC, 0x8CAD, 0x4E8D, 0x4E0C, 0x5140, 0x4E10, 0x5EFF, 0x5345,
+		0x4E15, 0x4E98, 0x4E1E, 0x9B32, 0x5B6C, 0x5669, 0x4E28, 0x79BA, 0x4E3F, 0x5315, 0x4E47,
+		0x592D, 0x723B, 0x536E, 0x6C10, 0x56DF, 0x80E4, 0x9997, 0x6BD3, 0x777E, 0x9F17, 0x4E36,
+		0x4E9F, 0x9F10, 0x4E5C, 0x4E69, 0x4E93, 0x8288, 0x5B5B, 0x556C, 0x560F, 0x4EC4, 0x538D,
+		0x539D, 0x53A3, 0x53A5, 0x53AE, 0x9765, 0x8D5D, 0x531A, 0x53F5, 0x5326, 0x532E, 0x533E,
+		0x8D5C, 0x5366, 0x5363, 0x5202, 0x5208, 0x520E, 0x522D, 0x5233, 0x523F, 0x5240, 0x524C,
+		0x525E, 0x5261, 0x525C, 0x84AF, 0x527D, 0x5282, 0x5281, 0x5290, 0x5293, 0x5182, 0x7F54,
+		0x4EBB, 0x4EC3, 0x4EC9, 0x4EC2, 0x4EE8, 0x4EE1, 0x4EEB, 0x4EDE, 0x4F1B, 0x4EF3, 0x4F22,
+		0x4F64, 0x4EF5, 0x4F25, 0x4F27, 0x4F09, 0x4F2B, 0x4F5E, 0x4F67, 0x6538, 0x4F5A, 0x4F5D,
+		0x8CAE, 0x8CAF, 0x8CB0, 0x8CB1, 0x8CB2, 0x8CB3, 0x8CB4, 0x8CB5, 0x8CB6, 0x8CB7, 0x8CB8,
+		0x8CB9, 0x8CBA, 0x8CBB, 0x8CBC, 0x8CBD, 0x8CBE, 0x8CBF, 0x8CC0, 0x8CC1, 0x8CC2, 0x8CC3,
+		0x8CC4, 0x8CC5, 0x8CC6, 0x8CC7, 0x8CC8, 0x8CC9, 0x8CCA, 0x8CCB, 0x8CCC, 0x8CCD, 0x8CCE,
+		0x8CCF, 0x8CD0, 0x8CD1, 0x8CD2, 0x8CD3, 0x8CD4, 0x8CD5, 0x8CD6, 0x8CD7, 0x8CD8, 0x8CD9,
+		0x8CDA, 0x8CDB, 0x8CDC, 0x8CDD, 0x8CDE, 0x8CDF, 0x8CE0, 0x8CE1, 0x8CE2, 0x8CE3, 0x8CE4,
+		0x8CE5, 0x8CE6, 0x8CE7, 0x8CE8, 0x8CE9, 0x8CEA, 0x8CEB, 0x8CEC, 0x8CED, 0x8CEE, 0x8CEF,
+		0x8CF0, 0x8CF1, 0x8CF2, 0x8CF3, 0x8CF4, 0x8CF5, 0x8CF6, 0x8CF7, 0x8CF8, 0x8CF9, 0x8CFA,
+		0x8CFB, 0x8CFC, 0x8CFD, 0x8CFE, 0x8CFF, 0x8D00, 0x8D01, 0x8D02, 0x8D03, 0x8D04, 0x8D05,
+		0x8D06, 0x8D07, 0x8D08, 0x8D09, 0x8D0A, 0x8D0B, 0x8D0C, 0x8D0D, 0x4F5F, 0x4F57, 0x4F32,
+		0x4F3D, 0x4F76, 0x4F74, 0x4F91, 0x4F89, 0x4F83, 0x4F8F, 0x4F7E, 0x4F7B, 0x4FAA, 0x4F7C,
+		0x4FAC, 0x4F94, 0x4FE6, 0x4FE8, 0x4FEA, 0x4FC5, 0x4FDA, 0x4FE3, 0x4FDC, 0x4FD1, 0x4FDF,
+		0x4FF8, 0x5029, 0x504C, 0x4FF3, 0x502C, 0x500F, 0x502E, 0x502D, 0x4FFE, 0x501C, 0x500C,
+		0x5025, 0x5028, 0x507E, 0x5043, 0x5055, 0x5048, 0x504E, 0x506C, 0x507B, 0x50A5, 0x50A7,
+		0x50A9, 0x50BA, 0x50D6, 0x5106, 0x50ED, 0x50EC, 0x50E6, 0x50EE, 0x5107, 0x510B, 0x4EDD,
+		0x6C3D, 0x4F58, 0x4F65, 0x4FCE, 0x9FA0, 0x6C46, 0x7C74, 0x516E, 0x5DFD, 0x9EC9, 0x9998,
+		0x5181, 0x5914, 0x52F9, 0x530D, 0x8A07, 0x5310, 0x51EB, 0x5919, 0x5155, 0x4EA0, 0x5156,
+		0x4EB3, 0x886E, 0x88A4, 0x4EB5, 0x8114, 0x88D2, 0x7980, 0x5B34, 0x8803, 0x7FB8, 0x51AB,
+		0x51B1, 0x51BD, 0x51BC, 0x8D0E, 0x8D0F, 0x8D10, 0x8D11, 0x8D12, 0x8D13, 0x8D14, 0x8D15,
+		0x8D16, 0x8D17, 0x8D18, 0x8D19, 0x8D1A, 0x8D1B, 0x8D1C, 0x8D20, 0x8D51, 0x8D52, 0x8D57,
+		0x8D5F, 0x8D65, 0x8D68, 0x8D69, 0x8D6A, 0x8D6C, 0x8D6E, 0x8D6F, 0x8D71, 0x8D72, 0x8D78,
+		0x8D79, 0x8D7A, 0x8D7B, 0x8D7C, 0x8D7D, 0x8D7E, 0x8D7F, 0x8D80, 0x8D82, 0x8D83, 0x8D86,
+		0x8D87, 0x8D88, 0x8D89, 0x8D8C, 0x8D8D, 0x8D8E, 0x8D8F, 0x8D90, 0x8D92, 0x8D93, 0x8D95,
+		0x8D96, 0x8D97, 0x8D98, 0x8D99, 0x8D9A, 0x8D9B, 0x8D9C, 0x8D9D, 0x8D9E, 0x8DA0, 0x8DA1,
+		0x8DA2, 0x8DA4, 0x8DA5, 0x8DA6, 0x8DA7, 0x8DA8, 0x8DA9, 0x8DAA, 0x8DAB, 0x8DAC, 0x8DAD,
+		0x8DAE, 0x8DAF, 0x8DB0, 0x8DB2, 0x8DB6, 0x8DB7, 0x8DB9, 0x8DBB, 0x8DBD, 0x8DC0, 0x8DC1,
+		0x8DC2, 0x8DC5, 0x8DC7, 0x8DC8, 0x8DC9, 0x8DCA, 0x8DCD, 0x8DD0, 0x8DD2, 0x8DD3, 0x8DD4,
+		0x51C7, 0x5196, 0x51A2, 0x51A5, 0x8BA0, 0x8BA6, 0x8BA7, 0x8BAA, 0x8BB4, 0x8BB5, 0x8BB7,
+		0x8BC2, 0x8BC3, 0x8BCB, 0x8BCF, 0x8BCE, 0x8BD2, 0x8BD3, 0x8BD4, 0x8BD6, 0x8BD8, 0x8BD9,
+		0x8BDC, 0x8BDF, 0x8BE0, 0x8BE4, 0x8BE8, 0x8BE9, 0x8BEE, 0x8BF0, 0x8BF3, 0x8BF6, 0x8BF9,
+		0x8BFC, 0x8BFF, 0x8C00, 0x8C02, 0x8C04, 0x8C07, 0x8C0C, 0x8C0F, 0x8C11, 0x8C12, 0x8C14,
+		0x8C15, 0x8C16, 0x8C19, 0x8C1B, 0x8C18, 0x8C1D, 0x8C1F, 0x8C20, 0x8C21, 0x8C25, 0x8C27,
+		0x8C2A, 0x8C2B, 0x8C2E, 0x8C2F, 0x8C32, 0x8C33, 0x8C35, 0x8C36, 0x5369, 0x537A, 0x961D,
+		0x9622, 0x9621, 0x9631, 0x962A, 0x963D, 0x963C, 0x9642, 0x9649, 0x9654, 0x965F, 0x9667,
+		0x966C, 0x9672, 0x9674, 0x9688, 0x968D, 0x9697, 0x96B0, 0x9097, 0x909B, 0x909D, 0x9099,
+		0x90AC, 0x90A1, 0x90B4, 0x90B3, 0x90B6, 0x90BA, 0x8DD5, 0x8DD8, 0x8DD9, 0x8DDC, 0x8DE0,
+		0x8DE1, 0x8DE2, 0x8DE5, 0x8DE6, 0x8DE7, 0x8DE9, 0x8DED, 0x8DEE, 0x8DF0, 0x8DF1, 0x8DF2,
+		0x8DF4, 0x8DF6, 0x8DFC, 0x8DFE, 0x8DFF, 0x8E00, 0x8E01, 0x8E02, 0x8E03, 0x8E04, 0x8E06,
+		0x8E07, 0x8E08, 0x8E0B, 0x8E0D, 0x8E0E, 0x8E10, 0x8E11, 0x8E12, 0x8E13, 0x8E15, 0x8E16,
+		0x8E17, 0x8E18, 0x8E19, 0x8E1A, 0x8E1B, 0x8E1C, 0x8E20, 0x8E21, 0x8E24, 0x8E25, 0x8E26,
+		0x8E27, 0x8E28, 0x8E2B, 0x8E2D, 0x8E30, 0x8E32, 0x8E33, 0x8E34, 0x8E36, 0x8E37, 0x8E38,
+		0x8E3B, 0x8E3C, 0x8E3E, 0x8E3F, 0x8E43, 0x8E45, 0x8E46, 0x8E4C, 0x8E4D, 0x8E4E, 0x8E4F,
+		0x8E50, 0x8E53, 0x8E54, 0x8E55, 0x8E56, 0x8E57, 0x8E58, 0x8E5A, 0x8E5B, 0x8E5C, 0x8E5D,
+		0x8E5E, 0x8E5F, 0x8E60, 0x8E61, 0x8E62, 0x8E63, 0x8E64, 0x8E65, 0x8E67, 0x8E68, 0x8E6A,
+		0x8E6B, 0x8E6E, 0x8E71, 0x90B8, 0x90B0, 0x90CF, 0x90C5, 0x90BE, 0x90D0, 0x90C4, 0x90C7,
+		0x90D3, 0x90E6, 0x90E2, 0x90DC, 0x90D7, 0x90DB, 0x90EB, 0x90EF, 0x90FE, 0x9104, 0x9122,
+		0x911E, 0x9123, 0x9131, 0x912F, 0x9139, 0x9143, 0x9146, 0x520D, 0x5942, 0x52A2, 0x52AC,
+		0x52AD, 0x52BE, 0x54FF, 0x52D0, 0x52D6, 0x52F0, 0x53DF, 0x71EE, 0x77CD, 0x5EF4, 0x51F5,
+		0x51FC, 0x9B2F, 0x53B6, 0x5F01, 0x755A, 0x5DEF, 0x574C, 0x57A9, 0x57A1, 0x587E, 0x58BC,
+		0x58C5, 0x58D1, 0x5729, 0x572C, 0x572A, 0x5733, 0x5739, 0x572E, 0x572F, 0x575C, 0x573B,
+		0x5742, 0x5769, 0x5785, 0x576B, 0x5786, 0x577C, 0x577B, 0x5768, 0x576D, 0x5776, 0x5773,
+		0x57AD, 0x57A4, 0x578C, 0x57B2, 0x57CF, 0x57A7, 0x57B4, 0x5793, 0x57A0, 0x57D5, 0x57D8,
+		0x57DA, 0x57D9, 0x57D2, 0x57B8, 0x57F4, 0x57EF, 0x57F8, 0x57E4, 0x57DD, 0x8E73, 0x8E75,
+		0x8E77, 0x8E78, 0x8E79, 0x8E7A, 0x8E7B, 0x8E7D, 0x8E7E, 0x8E80, 0x8E82, 0x8E83, 0x8E84,
+		0x8E86, 0x8E88, 0x8E89, 0x8E8A, 0x8E8B, 0x8E8C, 0x8E8D, 0x8E8E, 0x8E91, 0x8E92, 0x8E93,
+		0x8E95, 0x8E96, 0x8E97, 0x8E98, 0x8E99, 0x8E9A, 0x8E9B, 0x8E9D, 0x8E9F, 0x8EA0, 0x8EA1,
+		0x8EA2, 0x8EA3, 0x8EA4, 0x8EA5, 0x8EA6, 0x8EA7, 0x8EA8, 0x8EA9, 0x8EAA, 0x8EAD, 0x8EAE,
+		0x8EB0, 0x8EB1, 0x8EB3, 0x8EB4, 0x8EB5, 0x8EB6, 0x8EB7, 0x8EB8, 0x8EB9, 0x8EBB, 0x8EBC,
+		0x8EBD, 0x8EBE, 0x8EBF, 0x8EC0, 0x8EC1, 0x8EC2, 0x8EC3, 0x8EC4, 0x8EC5, 0x8EC6, 0x8EC7,
+		0x8EC8, 0x8EC9, 0x8ECA, 0x8ECB, 0x8ECC, 0x8ECD, 0x8ECF, 0x8ED0, 0x8ED1, 0x8ED2, 0x8ED3,
+		0x8ED4, 0x8ED5, 0x8ED6, 0x8ED7, 0x8ED8, 0x8ED9, 0x8EDA, 0x8EDB, 0x8EDC, 0x8EDD, 0x8EDE,
+		0x8EDF, 0x8EE0, 0x8EE1, 0x8EE2, 0x8EE3, 0x8EE4, 0x580B, 0x580D, 0x57FD, 0x57ED, 0x5800,
+		0x581E, 0x5819, 0x5844, 0x5820, 0x5865, 0x586C, 0x5881, 0x5889, 0x589A, 0x5880, 0x99A8,
+		0x9F19, 0x61FF, 0x8279, 0x827D, 0x827F, 0x828F, 0x828A, 0x82A8, 0x8284, 0x828E, 0x8291,
+		0x8297, 0x8299, 0x82AB, 0x82B8, 0x82BE, 0x82B0, 0x82C8, 0x82CA, 0x82E3, 0x8298, 0x82B7,
+		0x82AE, 0x82CB, 0x82CC, 0x82C1, 0x82A9, 0x82B4, 0x82A1, 0x82AA, 0x829F, 0x82C4, 0x82CE,
+		0x82A4, 0x82E1, 0x8309, 0x82F7, 0x82E4, 0x830F, 0x8307, 0x82DC, 0x82F4, 0x82D2, 0x82D8,
+		0x830C, 0x82FB, 0x82D3, 0x8311, 0x831A, 0x8306, 0x8314, 0x8315, 0x82E0, 0x82D5, 0x831C,
+		0x8351, 0x835B, 0x835C, 0x8308, 0x8392, 0x833C, 0x8334, 0x8331, 0x839B, 0x835E, 0x832F,
+		0x834F, 0x8347, 0x8343, 0x835F, 0x8340, 0x8317, 0x8360, 0x832D, 0x833A, 0x8333, 0x8366,
+		0x8365, 0x8EE5, 0x8EE6, 0x8EE7, 0x8EE8, 0x8EE9, 0x8EEA, 0x8EEB, 0x8EEC, 0x8EED, 0x8EEE,
+		0x8EEF, 0x8EF0, 0x8EF1, 0x8EF2, 0x8EF3, 0x8EF4, 0x8EF5, 0x8EF6, 0x8EF7, 0x8EF8, 0x8EF9,
+		0x8EFA, 0x8EFB, 0x8EFC, 0x8EFD, 0x8EFE, 0x8EFF, 0x8F00, 0x8F01, 0x8F02, 0x8F03, 0x8F04,
+		0x8F05, 0x8F06, 0x8F07, 0x8F08, 0x8F09, 0x8F0A, 0x8F0B, 0x8F0C, 0x8F0D, 0x8F0E, 0x8F0F,
+		0x8F10, 0x8F11, 0x8F12, 0x8F13, 0x8F14, 0x8F15, 0x8F16, 0x8F17, 0x8F18, 0x8F19, 0x8F1A,
+		0x8F1B, 0x8F1C, 0x8F1D, 0x8F1E, 0x8F1F, 0x8F20, 0x8F21, 0x8F22, 0x8F23, 0x8F24, 0x8F25,
+		0x8F26, 0x8F27, 0x8F28, 0x8F29, 0x8F2A, 0x8F2B, 0x8F2C, 0x8F2D, 0x8F2E, 0x8F2F, 0x8F30,
+		0x8F31, 0x8F32, 0x8F33, 0x8F34, 0x8F35, 0x8F36, 0x8F37, 0x8F38, 0x8F39, 0x8F3A, 0x8F3B,
+		0x8F3C, 0x8F3D, 0x8F3E, 0x8F3F, 0x8F40, 0x8F41, 0x8F42, 0x8F43, 0x8F44, 0x8368, 0x831B,
+		0x8369, 0x836C, 0x836A, 0x836D, 0x836E, 0x83B0, 0x8378, 0x83B3, 0x83B4, 0x83A0, 0x83AA,
+		0x8393, 0x839C, 0x8385, 0x837C, 0x83B6, 0x83A9, 0x837D, 0x83B8, 0x837B, 0x8398, 0x839E,
+		0x83A8, 0x83BA, 0x83BC, 0x83C1, 0x8401, 0x83E5, 0x83D8, 0x5807, 0x8418, 0x840B, 0x83DD,
+		0x83FD, 0x83D6, 0x841C, 0x8438, 0x8411, 0x8406, 0x83D4, 0x83DF, 0x840F, 0x8403, 0x83F8,
+		0x83F9, 0x83EA, 0x83C5, 0x83C0, 0x8426, 0x83F0, 0x83E1, 0x845C, 0x8451, 0x845A, 0x8459,
+		0x8473, 0x8487, 0x8488, 0x847A, 0x8489, 0x8478, 0x843C, 0x8446, 0x8469, 0x8476, 0x848C,
+		0x848E, 0x8431, 0x846D, 0x84C1, 0x84CD, 0x84D0, 0x84E6, 0x84BD, 0x84D3, 0x84CA, 0x84BF,
+		0x84BA, 0x84E0, 0x84A1, 0x84B9, 0x84B4, 0x8497, 0x84E5, 0x84E3, 0x850C, 0x750D, 0x8538,
+		0x84F0, 0x8539, 0x851F, 0x853A, 0x8F45, 0x8F46, 0x8F47, 0x8F48, 0x8F49, 0x8F4A, 0x8F4B,
+		0x8F4C, 0x8F4D, 0x8F4E, 0x8F4F, 0x8F50, 0x8F51, 0x8F52, 0x8F53, 0x8F54, 0x8F55, 0x8F56,
+		0x8F57, 0x8F58, 0x8F59, 0x8F5A, 0x8F5B, 0x8F5C, 0x8F5D, 0x8F5E, 0x8F5F, 0x8F60, 0x8F61,
+		0x8F62, 0x8F63, 0x8F64, 0x8F65, 0x8F6A, 0x8F80, 0x8F8C, 0x8F92, 0x8F9D, 0x8FA0, 0x8FA1,
+		0x8FA2, 0x8FA4, 0x8FA5, 0x8FA6, 0x8FA7, 0x8FAA, 0x8FAC, 0x8FAD, 0x8FAE, 0x8FAF, 0x8FB2,
+		0x8FB3, 0x8FB4, 0x8FB5, 0x8FB7, 0x8FB8, 0x8FBA, 0x8FBB, 0x8FBC, 0x8FBF, 0x8FC0, 0x8FC3,
+		0x8FC6, 0x8FC9, 0x8FCA, 0x8FCB, 0x8FCC, 0x8FCD, 0x8FCF, 0x8FD2, 0x8FD6, 0x8FD7, 0x8FDA,
+		0x8FE0, 0x8FE1, 0x8FE3, 0x8FE7, 0x8FEC, 0x8FEF, 0x8FF1, 0x8FF2, 0x8FF4, 0x8FF5, 0x8FF6,
+		0x8FFA, 0x8FFB, 0x8FFC, 0x8FFE, 0x8FFF, 0x9007, 0x9008, 0x900C, 0x900E, 0x9013, 0x9015,
+		0x9018, 0x8556, 0x853B, 0x84FF, 0x84FC, 0x8559, 0x8548, 0x8568, 0x8564, 0x855E, 0x857A,
+		0x77A2, 0x8543, 0x8572, 0x857B, 0x85A4, 0x85A8, 0x8587, 0x858F, 0x8579, 0x85AE, 0x859C,
+		0x8585, 0x85B9, 0x85B7, 0x85B0, 0x85D3, 0x85C1, 0x85DC, 0x85FF, 0x8627, 0x8605, 0x8629,
+		0x8616, 0x863C, 0x5EFE, 0x5F08, 0x593C, 0x5941, 0x8037, 0x5955, 0x595A, 0x5958, 0x530F,
+		0x5C22, 0x5C25, 0x5C2C, 0x5C34, 0x624C, 0x626A, 0x629F, 0x62BB, 0x62CA, 0x62DA, 0x62D7,
+		0x62EE, 0x6322, 0x62F6, 0x6339, 0x634B, 0x6343, 0x63AD, 0x63F6, 0x6371, 0x637A, 0x638E,
+		0x63B4, 0x636D, 0x63AC, 0x638A, 0x6369, 0x63AE, 0x63BC, 0x63F2, 0x63F8, 0x63E0, 0x63FF,
+		0x63C4, 0x63DE, 0x63CE, 0x6452, 0x63C6, 0x63BE, 0x6445, 0x6441, 0x640B, 0x641B, 0x6420,
+		0x640C, 0x6426, 0x6421, 0x645E, 0x6484, 0x646D, 0x6496, 0x9019, 0x901C, 0x9023, 0x9024,
+		0x9025, 0x9027, 0x9028, 0x9029, 0x902A, 0x902B, 0x902C, 0x9030, 0x9031, 0x9032, 0x9033,
+		0x9034, 0x9037, 0x9039, 0x903A, 0x903D, 0x903F, 0x9040, 0x9043, 0x9045, 0x9046, 0x9048,
+		0x9049, 0x904A, 0x904B, 0x904C, 0x904E, 0x9054, 0x9055, 0x9056, 0x9059, 0x905A, 0x905C,
+		0x905D, 0x905E, 0x905F, 0x9060, 0x9061, 0x9064, 0x9066, 0x9067, 0x9069, 0x906A, 0x906B,
+		0x906C, 0x906F, 0x9070, 0x9071, 0x9072, 0x9073, 0x9076, 0x9077, 0x9078, 0x9079, 0x907A,
+		0x907B, 0x907C, 0x907E, 0x9081, 0x9084, 0x9085, 0x9086, 0x9087, 0x9089, 0x908A, 0x908C,
+		0x908D, 0x908E, 0x908F, 0x9090, 0x9092, 0x9094, 0x9096, 0x9098, 0x909A, 0x909C, 0x909E,
+		0x909F, 0x90A0, 0x90A4, 0x90A5, 0x90A7, 0x90A8, 0x90A9, 0x90AB, 0x90AD, 0x90B2, 0x90B7,
+		0x90BC, 0x90BD, 0x90BF, 0x90C0, 0x647A, 0x64B7, 0x64B8, 0x6499, 0x64BA, 0x64C0, 0x64D0,
+		0x64D7, 0x64E4, 0x64E2, 0x6509, 0x6525, 0x652E, 0x5F0B, 0x5FD2, 0x7519, 0x5F11, 0x535F,
+		0x53F1, 0x53FD, 0x53E9, 0x53E8, 0x53FB, 0x5412, 0x5416, 0x5406, 0x544B, 0x5452, 0x5453,
+		0x5454, 0x5456, 0x5443, 0x5421, 0x5457, 0x5459, 0x5423, 0x5432, 0x5482, 0x5494, 0x5477,
+		0x5471, 0x5464, 0x549A, 0x549B, 0x5484, 0x5476, 0x5466, 0x549D, 0x54D0, 0x54AD, 0x54C2,
+		0x54B4, 0x54D2, 0x54A7, 0x54A6, 0x54D3, 0x54D4, 0x5472, 0x54A3, 0x54D5, 0x54BB, 0x54BF,
+		0x54CC, 0x54D9, 0x54DA, 0x54DC, 0x54A9, 0x54AA, 0x54A4, 0x54DD, 0x54CF, 0x54DE, 0x551B,
+		0x54E7, 0x5520, 0x54FD, 0x5514, 0x54F3, 0x5522, 0x5523, 0x550F, 0x5511, 0x5527, 0x552A,
+		0x5567, 0x558F, 0x55B5, 0x5549, 0x556D, 0x5541, 0x5555, 0x553F, 0x5550, 0x553C, 0x90C2,
+		0x90C3, 0x90C6, 0x90C8, 0x90C9, 0x90CB, 0x90CC, 0x90CD, 0x90D2, 0x90D4, 0x90D5, 0x90D6,
+		0x90D8, 0x90D9, 0x90DA, 0x90DE, 0x90DF, 0x90E0, 0x90E3, 0x90E4, 0x90E5, 0x90E9, 0x90EA,
+		0x90EC, 0x90EE, 0x90F0, 0x90F1, 0x90F2, 0x90F3, 0x90F5, 0x90F6, 0x90F7, 0x90F9, 0x90FA,
+		0x90FB, 0x90FC, 0x90FF, 0x9100, 0x9101, 0x9103, 0x9105, 0x9106, 0x9107, 0x9108, 0x9109,
+		0x910A, 0x910B, 0x910C, 0x910D, 0x910E, 0x910F, 0x9110, 0x9111, 0x9112, 0x9113, 0x9114,
+		0x9115, 0x9116, 0x9117, 0x9118, 0x911A, 0x911B, 0x911C, 0x911D, 0x911F, 0x9120, 0x9121,
+		0x9124, 0x9125, 0x9126, 0x9127, 0x9128, 0x9129, 0x912A, 0x912B, 0x912C, 0x912D, 0x912E,
+		0x9130, 0x9132, 0x9133, 0x9134, 0x9135, 0x9136, 0x9137, 0x9138, 0x913A, 0x913B, 0x913C,
+		0x913D, 0x913E, 0x913F, 0x9140, 0x9141, 0x9142, 0x9144, 0x5537, 0x5556, 0x5575, 0x5576,
+		0x5577, 0x5533, 0x5530, 0x555C, 0x558B, 0x55D2, 0x5583, 0x55B1, 0x55B9, 0x5588, 0x5581,
+		0x559F, 0x557E, 0x55D6, 0x5591, 0x557B, 0x55DF, 0x55BD, 0x55BE, 0x5594, 0x5599, 0x55EA,
+		0x55F7, 0x55C9, 0x561F, 0x55D1, 0x55EB, 0x55EC, 0x55D4, 0x55E6, 0x55DD, 0x55C4, 0x55EF,
+		0x55E5, 0x55F2, 0x55F3, 0x55CC, 0x55CD, 0x55E8, 0x55F5, 0x55E4, 0x8F94, 0x561E, 0x5608,
+		0x560C, 0x5601, 0x5624, 0x5623, 0x55FE, 0x5600, 0x5627, 0x562D, 0x5658, 0x5639, 0x5657,
+		0x562C, 0x564D, 0x5662, 0x5659, 0x565C, 0x564C, 0x5654, 0x5686, 0x5664, 0x5671, 0x566B,
+		0x567B, 0x567C, 0x5685, 0x5693, 0x56AF, 0x56D4, 0x56D7, 0x56DD, 0x56E1, 0x56F5, 0x56EB,
+		0x56F9, 0x56FF, 0x5704, 0x570A, 0x5709, 0x571C, 0x5E0F, 0x5E19, 0x5E14, 0x5E11, 0x5E31,
+		0x5E3B, 0x5E3C, 0x9145, 0x9147, 0x9148, 0x9151, 0x9153, 0x9154, 0x9155, 0x9156, 0x9158,
+		0x9159, 0x915B, 0x915C, 0x915F, 0x9160, 0x9166, 0x9167, 0x9168, 0x916B, 0x916D, 0x9173,
+		0x917A, 0x917B, 0x917C, 0x9180, 0x9181, 0x9182, 0x9183, 0x9184, 0x9186, 0x9188, 0x918A,
+		0x918E, 0x918F, 0x9193, 0x9194, 0x9195, 0x9196, 0x9197, 0x9198, 0x9199, 0x919C, 0x919D,
+		0x919E, 0x919F, 0x91A0, 0x91A1, 0x91A4, 0x91A5, 0x91A6, 0x91A7, 0x91A8, 0x91A9, 0x91AB,
+		0x91AC, 0x91B0, 0x91B1, 0x91B2, 0x91B3, 0x91B6, 0x91B7, 0x91B8, 0x91B9, 0x91BB, 0x91BC,
+		0x91BD, 0x91BE, 0x91BF, 0x91C0, 0x91C1, 0x91C2, 0x91C3, 0x91C4, 0x91C5, 0x91C6, 0x91C8,
+		0x91CB, 0x91D0, 0x91D2, 0x91D3, 0x91D4, 0x91D5, 0x91D6, 0x91D7, 0x91D8, 0x91D9, 0x91DA,
+		0x91DB, 0x91DD, 0x91DE, 0x91DF, 0x91E0, 0x91E1, 0x91E2, 0x91E3, 0x91E4, 0x91E5, 0x5E37,
+		0x5E44, 0x5E54, 0x5E5B, 0x5E5E, 0x5E61, 0x5C8C, 0x5C7A, 0x5C8D, 0x5C90, 0x5C96, 0x5C88,
+		0x5C98, 0x5C99, 0x5C91, 0x5C9A, 0x5C9C, 0x5CB5, 0x5CA2, 0x5CBD, 0x5CAC, 0x5CAB, 0x5CB1,
+		0x5CA3, 0x5CC1, 0x5CB7, 0x5CC4, 0x5CD2, 0x5CE4, 0x5CCB, 0x5CE5, 0x5D02, 0x5D03, 0x5D27,
+		0x5D26, 0x5D2E, 0x5D24, 0x5D1E, 0x5D06, 0x5D1B, 0x5D58, 0x5D3E, 0x5D34, 0x5D3D, 0x5D6C,
+		0x5D5B, 0x5D6F, 0x5D5D, 0x5D6B, 0x5D4B, 0x5D4A, 0x5D69, 0x5D74, 0x5D82, 0x5D99, 0x5D9D,
+		0x8C73, 0x5DB7, 0x5DC5, 0x5F73, 0x5F77, 0x5F82, 0x5F87, 0x5F89, 0x5F8C, 0x5F95, 0x5F99,
+		0x5F9C, 0x5FA8, 0x5FAD, 0x5FB5, 0x5FBC, 0x8862, 0x5F61, 0x72AD, 0x72B0, 0x72B4, 0x72B7,
+		0x72B8, 0x72C3, 0x72C1, 0x72CE, 0x72CD, 0x72D2, 0x72E8, 0x72EF, 0x72E9, 0x72F2, 0x72F4,
+		0x72F7, 0x7301, 0x72F3, 0x7303, 0x72FA, 0x91E6, 0x91E7, 0x91E8, 0x91E9, 0x91EA, 0x91EB,
+		0x91EC, 0x91ED, 0x91EE, 0x91EF, 0x91F0, 0x91F1, 0x91F2, 0x91F3, 0x91F4, 0x91F5, 0x91F6,
+		0x91F7, 0x91F8, 0x91F9, 0x91FA, 0x91FB, 0x91FC, 0x91FD, 0x91FE, 0x91FF, 0x9200, 0x9201,
+		0x9202, 0x9203, 0x9204, 0x9205, 0x9206, 0x9207, 0x9208, 0x9209, 0x920A, 0x920B, 0x920C,
+		0x920D, 0x920E, 0x920F, 0x9210, 0x9211, 0x9212, 0x9213, 0x9214, 0x9215, 0x9216, 0x9217,
+		0x9218, 0x9219, 0x921A, 0x921B, 0x921C, 0x921D, 0x921E, 0x921F, 0x9220, 0x9221, 0x9222,
+		0x9223, 0x9224, 0x9225, 0x9226, 0x9227, 0x9228, 0x9229, 0x922A, 0x922B, 0x922C, 0x922D,
+		0x922E, 0x922F, 0x9230, 0x9231, 0x9232, 0x9233, 0x9234, 0x9235, 0x9236, 0x9237, 0x9238,
+		0x9239, 0x923A, 0x923B, 0x923C, 0x923D, 0x923E, 0x923F, 0x9240, 0x9241, 0x9242, 0x9243,
+		0x9244, 0x9245, 0x72FB, 0x7317, 0x7313, 0x7321, 0x730A, 0x731E, 0x731D, 0x7315, 0x7322,
+		0x7339, 0x7325, 0x732C, 0x7338, 0x7331, 0x7350, 0x734D, 0x7357, 0x7360, 0x736C, 0x736F,
+		0x737E, 0x821B, 0x5925, 0x98E7, 0x5924, 0x5902, 0x9963, 0x9967, 0x9968, 0x9969, 0x996A,
+		0x996B, 0x996C, 0x9974, 0x9977, 0x997D, 0x9980, 0x9984, 0x9987, 0x998A, 0x998D, 0x9990,
+		0x9991, 0x9993, 0x9994, 0x9995, 0x5E80, 0x5E91, 0x5E8B, 0x5E96, 0x5EA5, 0x5EA0, 0x5EB9,
+		0x5EB5, 0x5EBE, 0x5EB3, 0x8D53, 0x5ED2, 0x5ED1, 0x5EDB, 0x5EE8, 0x5EEA, 0x81BA, 0x5FC4,
+		0x5FC9, 0x5FD6, 0x5FCF, 0x6003, 0x5FEE, 0x6004, 0x5FE1, 0x5FE4, 0x5FFE, 0x6005, 0x6006,
+		0x5FEA, 0x5FED, 0x5FF8, 0x6019, 0x6035, 0x6026, 0x601B, 0x600F, 0x600D, 0x6029, 0x602B,
+		0x600A, 0x603F, 0x6021, 0x6078, 0x6079, 0x607B, 0x607A, 0x6042, 0x9246, 0x9247, 0x9248,
+		0x9249, 0x924A, 0x924B, 0x924C, 0x924D, 0x924E, 0x924F, 0x9250, 0x9251, 0x9252, 0x9253,
+		0x9254, 0x9255, 0x9256, 0x9257, 0x9258, 0x9259, 0x925A, 0x925B, 0x925C, 0x925D, 0x925E,
+		0x925F, 0x9260, 0x9261, 0x9262, 0x9263, 0x9264, 0x9265, 0x9266, 0x9267, 0x9268, 0x9269,
+		0x926A, 0x926B, 0x926C, 0x926D, 0x926E, 0x926F, 0x9270, 0x9271, 0x9272, 0x9273, 0x9275,
+		0x9276, 0x9277, 0x9278, 0x9279, 0x927A, 0x927B, 0x927C, 0x927D, 0x927E, 0x927F, 0x9280,
+		0x9281, 0x9282, 0x9283, 0x9284, 0x9285, 0x9286, 0x9287, 0x9288, 0x9289, 0x928A, 0x928B,
+		0x928C, 0x928D, 0x928F, 0x9290, 0x9291, 0x9292, 0x9293, 0x9294, 0x9295, 0x9296, 0x9297,
+		0x9298, 0x9299, 0x929A, 0x929B, 0x929C, 0x929D, 0x929E, 0x929F, 0x92A0, 0x92A1, 0x92A2,
+		0x92A3, 0x92A4, 0x92A5, 0x92A6, 0x92A7, 0x606A, 0x607D, 0x6096, 0x609A, 0x60AD, 0x609D,
+		0x6083, 0x6092, 0x608C, 0x609B, 0x60EC, 0x60BB, 0x60B1, 0x60DD, 0x60D8, 0x60C6, 0x60DA,
+		0x60B4, 0x6120, 0x6126, 0x6115, 0x6123, 0x60F4, 0x6100, 0x610E, 0x612B, 0x614A, 0x6175,
+		0x61AC, 0x6194, 0x61A7, 0x61B7, 0x61D4, 0x61F5, 0x5FDD, 0x96B3, 0x95E9, 0x95EB, 0x95F1,
+		0x95F3, 0x95F5, 0x95F6, 0x95FC, 0x95FE, 0x9603, 0x9604, 0x9606, 0x9608, 0x960A, 0x960B,
+		0x960C, 0x960D, 0x960F, 0x9612, 0x9615, 0x9616, 0x9617, 0x9619, 0x961A, 0x4E2C, 0x723F,
+		0x6215, 0x6C35, 0x6C54, 0x6C5C, 0x6C4A, 0x6CA3, 0x6C85, 0x6C90, 0x6C94, 0x6C8C, 0x6C68,
+		0x6C69, 0x6C74, 0x6C76, 0x6C86, 0x6CA9, 0x6CD0, 0x6CD4, 0x6CAD, 0x6CF7, 0x6CF8, 0x6CF1,
+		0x6CD7, 0x6CB2, 0x6CE0, 0x6CD6, 0x6CFA, 0x6CEB, 0x6CEE, 0x6CB1, 0x6CD3, 0x6CEF, 0x6CFE,
+		0x92A8, 0x92A9, 0x92AA, 0x92AB, 0x92AC, 0x92AD, 0x92AF, 0x92B0, 0x92B1, 0x92B2, 0x92B3,
+		0x92B4, 0x92B5, 0x92B6, 0x92B7, 0x92B8, 0x92B9, 0x92BA, 0x92BB, 0x92BC, 0x92BD, 0x92BE,
+		0x92BF, 0x92C0, 0x92C1, 0x92C2, 0x92C3, 0x92C4, 0x92C5, 0x92C6, 0x92C7, 0x92C9, 0x92CA,
+		0x92CB, 0x92CC, 0x92CD, 0x92CE, 0x92CF, 0x92D0, 0x92D1, 0x92D2, 0x92D3, 0x92D4, 0x92D5,
+		0x92D6, 0x92D7, 0x92D8, 0x92D9, 0x92DA, 0x92DB, 0x92DC, 0x92DD, 0x92DE, 0x92DF, 0x92E0,
+		0x92E1, 0x92E2, 0x92E3, 0x92E4, 0x92E5, 0x92E6, 0x92E7, 0x92E8, 0x92E9, 0x92EA, 0x92EB,
+		0x92EC, 0x92ED, 0x92EE, 0x92EF, 0x92F0, 0x92F1, 0x92F2, 0x92F3, 0x92F4, 0x92F5, 0x92F6,
+		0x92F7, 0x92F8, 0x92F9, 0x92FA, 0x92FB, 0x92FC, 0x92FD, 0x92FE, 0x92FF, 0x9300, 0x9301,
+		0x9302, 0x9303, 0x9304, 0x9305, 0x9306, 0x9307, 0x9308, 0x9309, 0x6D39, 0x6D27, 0x6D0C,
+		0x6D43, 0x6D48, 0x6D07, 0x6D04, 0x6D19, 0x6D0E, 0x6D2B, 0x6D4D, 0x6D2E, 0x6D35, 0x6D1A,
+		0x6D4F, 0x6D52, 0x6D54, 0x6D33, 0x6D91, 0x6D6F, 0x6D9E, 0x6DA0, 0x6D5E, 0x6D93, 0x6D94,
+		0x6D5C, 0x6D60, 0x6D7C, 0x6D63, 0x6E1A, 0x6DC7, 0x6DC5, 0x6DDE, 0x6E0E, 0x6DBF, 0x6DE0,
+		0x6E11, 0x6DE6, 0x6DDD, 0x6DD9, 0x6E16, 0x6DAB, 0x6E0C, 0x6DAE, 0x6E2B, 0x6E6E, 0x6E4E,
+		0x6E6B, 0x6EB2, 0x6E5F, 0x6E86, 0x6E53, 0x6E54, 0x6E32, 0x6E25, 0x6E44, 0x6EDF, 0x6EB1,
+		0x6E98, 0x6EE0, 0x6F2D, 0x6EE2, 0x6EA5, 0x6EA7, 0x6EBD, 0x6EBB, 0x6EB7, 0x6ED7, 0x6EB4,
+		0x6ECF, 0x6E8F, 0x6EC2, 0x6E9F, 0x6F62, 0x6F46, 0x6F47, 0x6F24, 0x6F15, 0x6EF9, 0x6F2F,
+		0x6F36, 0x6F4B, 0x6F74, 0x6F2A, 0x6F09, 0x6F29, 0x6F89, 0x6F8D, 0x6F8C, 0x6F78, 0x6F72,
+		0x6F7C, 0x6F7A, 0x6FD1, 0x930A, 0x930B, 0x930C, 0x930D, 0x930E, 0x930F, 0x9310, 0x9311,
+		0x9312, 0x9313, 0x9314, 0x9315, 0x9316, 0x9317, 0x9318, 0x9319, 0x931A, 0x931B, 0x931C,
+		0x931D, 0x931E, 0x931F, 0x9320, 0x9321, 0x9322, 0x9323, 0x9324, 0x9325, 0x9326, 0x9327,
+		0x9328, 0x9329, 0x932A, 0x932B, 0x932C, 0x932D, 0x932E, 0x932F, 0x9330, 0x9331, 0x9332,
+		0x9333, 0x9334, 0x9335, 0x9336, 0x9337, 0x9338, 0x9339, 0x933A, 0x933B, 0x933C, 0x933D,
+		0x933F, 0x9340, 0x9341, 0x9342, 0x9343, 0x9344, 0x9345, 0x9346, 0x9347, 0x9348, 0x9349,
+		0x934A, 0x934B, 0x934C, 0x934D, 0x934E, 0x934F, 0x9350, 0x9351, 0x9352, 0x9353, 0x9354,
+		0x9355, 0x9356, 0x9357, 0x9358, 0x9359, 0x935A, 0x935B, 0x935C, 0x935D, 0x935E, 0x935F,
+		0x9360, 0x9361, 0x9362, 0x9363, 0x9364, 0x9365, 0x9366, 0x9367, 0x9368, 0x9369, 0x936B,
+		0x6FC9, 0x6FA7, 0x6FB9, 0x6FB6, 0x6FC2, 0x6FE1, 0x6FEE, 0x6FDE, 0x6FE0, 0x6FEF, 0x701A,
+		0x7023, 0x701B, 0x7039, 0x7035, 0x704F, 0x705E, 0x5B80, 0x5B84, 0x5B95, 0x5B93, 0x5BA5,
+		0x5BB8, 0x752F, 0x9A9E, 0x6434, 0x5BE4, 0x5BEE, 0x8930, 0x5BF0, 0x8E47, 0x8B07, 0x8FB6,
+		0x8FD3, 0x8FD5, 0x8FE5, 0x8FEE, 0x8FE4, 0x8FE9, 0x8FE6, 0x8FF3, 0x8FE8, 0x9005, 0x9004,
+		0x900B, 0x9026, 0x9011, 0x900D, 0x9016, 0x9021, 0x9035, 0x9036, 0x902D, 0x902F, 0x9044,
+		0x9051, 0x9052, 0x9050, 0x9068, 0x9058, 0x9062, 0x905B, 0x66B9, 0x9074, 0x907D, 0x9082,
+		0x9088, 0x9083, 0x908B, 0x5F50, 0x5F57, 0x5F56, 0x5F58, 0x5C3B, 0x54AB, 0x5C50, 0x5C59,
+		0x5B71, 0x5C63, 0x5C66, 0x7FBC, 0x5F2A, 0x5F29, 0x5F2D, 0x8274, 0x5F3C, 0x9B3B, 0x5C6E,
+		0x5981, 0x5983, 0x598D, 0x59A9, 0x59AA, 0x59A3, 0x936C, 0x936D, 0x936E, 0x936F, 0x9370,
+		0x9371, 0x9372, 0x9373, 0x9374, 0x9375, 0x9376, 0x9377, 0x9378, 0x9379, 0x937A, 0x937B,
+		0x937C, 0x937D, 0x937E, 0x937F, 0x9380, 0x9381, 0x9382, 0x9383, 0x9384, 0x9385, 0x9386,
+		0x9387, 0x9388, 0x9389, 0x938A, 0x938B, 0x938C, 0x938D, 0x938E, 0x9390, 0x9391, 0x9392,
+		0x9393, 0x9394, 0x9395, 0x9396, 0x9397, 0x9398, 0x9399, 0x939A, 0x939B, 0x939C, 0x939D,
+		0x939E, 0x939F, 0x93A0, 0x93A1, 0x93A2, 0x93A3, 0x93A4, 0x93A5, 0x93A6, 0x93A7, 0x93A8,
+		0x93A9, 0x93AA, 0x93AB, 0x93AC, 0x93AD, 0x93AE, 0x93AF, 0x93B0, 0x93B1, 0x93B2, 0x93B3,
+		0x93B4, 0x93B5, 0x93B6, 0x93B7, 0x93B8, 0x93B9, 0x93BA, 0x93BB, 0x93BC, 0x93BD, 0x93BE,
+		0x93BF, 0x93C0, 0x93C1, 0x93C2, 0x93C3, 0x93C4, 0x93C5, 0x93C6, 0x93C7, 0x93C8, 0x93C9,
+		0x93CB, 0x93CC, 0x93CD, 0x5997, 0x59CA, 0x59AB, 0x599E, 0x59A4, 0x59D2, 0x59B2, 0x59AF,
+		0x59D7, 0x59BE, 0x5A05, 0x5A06, 0x59DD, 0x5A08, 0x59E3, 0x59D8, 0x59F9, 0x5A0C, 0x5A09,
+		0x5A32, 0x5A34, 0x5A11, 0x5A23, 0x5A13, 0x5A40, 0x5A67, 0x5A4A, 0x5A55, 0x5A3C, 0x5A62,
+		0x5A75, 0x80EC, 0x5AAA, 0x5A9B, 0x5A77, 0x5A7A, 0x5ABE, 0x5AEB, 0x5AB2, 0x5AD2, 0x5AD4,
+		0x5AB8, 0x5AE0, 0x5AE3, 0x5AF1, 0x5AD6, 0x5AE6, 0x5AD8, 0x5ADC, 0x5B09, 0x5B17, 0x5B16,
+		0x5B32, 0x5B37, 0x5B40, 0x5C15, 0x5C1C, 0x5B5A, 0x5B65, 0x5B73, 0x5B51, 0x5B53, 0x5B62,
+		0x9A75, 0x9A77, 0x9A78, 0x9A7A, 0x9A7F, 0x9A7D, 0x9A80, 0x9A81, 0x9A85, 0x9A88, 0x9A8A,
+		0x9A90, 0x9A92, 0x9A93, 0x9A96, 0x9A98, 0x9A9B, 0x9A9C, 0x9A9D, 0x9A9F, 0x9AA0, 0x9AA2,
+		0x9AA3, 0x9AA5, 0x9AA7, 0x7E9F, 0x7EA1, 0x7EA3, 0x7EA5, 0x7EA8, 0x7EA9, 0x93CE, 0x93CF,
+		0x93D0, 0x93D1, 0x93D2, 0x93D3, 0x93D4, 0x93D5, 0x93D7, 0x93D8, 0x93D9, 0x93DA, 0x93DB,
+		0x93DC, 0x93DD, 0x93DE, 0x93DF, 0x93E0, 0x93E1, 0x93E2, 0x93E3, 0x93E4, 0x93E5, 0x93E6,
+		0x93E7, 0x93E8, 0x93E9, 0x93EA, 0x93EB, 0x93EC, 0x93ED, 0x93EE, 0x93EF, 0x93F0, 0x93F1,
+		0x93F2, 0x93F3, 0x93F4, 0x93F5, 0x93F6, 0x93F7, 0x93F8, 0x93F9, 0x93FA, 0x93FB, 0x93FC,
+		0x93FD, 0x93FE, 0x93FF, 0x9400, 0x9401, 0x9402, 0x9403, 0x9404, 0x9405, 0x9406, 0x9407,
+		0x9408, 0x9409, 0x940A, 0x940B, 0x940C, 0x940D, 0x940E, 0x940F, 0x9410, 0x9411, 0x9412,
+		0x9413, 0x9414, 0x9415, 0x9416, 0x9417, 0x9418, 0x9419, 0x941A, 0x941B, 0x941C, 0x941D,
+		0x941E, 0x941F, 0x9420, 0x9421, 0x9422, 0x9423, 0x9424, 0x9425, 0x9426, 0x9427, 0x9428,
+		0x9429, 0x942A, 0x942B, 0x942C, 0x942D, 0x942E, 0x7EAD, 0x7EB0, 0x7EBE, 0x7EC0, 0x7EC1,
+		0x7EC2, 0x7EC9, 0x7ECB, 0x7ECC, 0x7ED0, 0x7ED4, 0x7ED7, 0x7EDB, 0x7EE0, 0x7EE1, 0x7EE8,
+		0x7EEB, 0x7EEE, 0x7EEF, 0x7EF1, 0x7EF2, 0x7F0D, 0x7EF6, 0x7EFA, 0x7EFB, 0x7EFE, 0x7F01,
+		0x7F02, 0x7F03, 0x7F07, 0x7F08, 0x7F0B, 0x7F0C, 0x7F0F, 0x7F11, 0x7F12, 0x7F17, 0x7F19,
+		0x7F1C, 0x7F1B, 0x7F1F, 0x7F21, 0x7F22, 0x7F23, 0x7F24, 0x7F25, 0x7F26, 0x7F27, 0x7F2A,
+		0x7F2B, 0x7F2C, 0x7F2D, 0x7F2F, 0x7F30, 0x7F31, 0x7F32, 0x7F33, 0x7F35, 0x5E7A, 0x757F,
+		0x5DDB, 0x753E, 0x9095, 0x738E, 0x7391, 0x73AE, 0x73A2, 0x739F, 0x73CF, 0x73C2, 0x73D1,
+		0x73B7, 0x73B3, 0x73C0, 0x73C9, 0x73C8, 0x73E5, 0x73D9, 0x987C, 0x740A, 0x73E9, 0x73E7,
+		0x73DE, 0x73BA, 0x73F2, 0x740F, 0x742A, 0x745B, 0x7426, 0x7425, 0x7428, 0x7430, 0x742E,
+		0x742C, 0x942F, 0x9430, 0x9431, 0x9432, 0x9433, 0x9434, 0x9435, 0x9436, 0x9437, 0x9438,
+		0x9439, 0x943A, 0x943B, 0x943C, 0x943D, 0x943F, 0x9440, 0x9441, 0x9442, 0x9443, 0x9444,
+		0x9445, 0x9446, 0x9447, 0x9448, 0x9449, 0x944A, 0x944B, 0x944C, 0x944D, 0x944E, 0x944F,
+		0x9450, 0x9451, 0x9452, 0x9453, 0x9454, 0x9455, 0x9456, 0x9457, 0x9458, 0x9459, 0x945A,
+		0x945B, 0x945C, 0x945D, 0x945E, 0x945F, 0x9460, 0x9461, 0x9462, 0x9463, 0x9464, 0x9465,
+		0x9466, 0x9467, 0x9468, 0x9469, 0x946A, 0x946C, 0x946D, 0x946E, 0x946F, 0x9470, 0x9471,
+		0x9472, 0x9473, 0x9474, 0x9475, 0x9476, 0x9477, 0x9478, 0x9479, 0x947A, 0x947B, 0x947C,
+		0x947D, 0x947E, 0x947F, 0x9480, 0x9481, 0x9482, 0x9483, 0x9484, 0x9491, 0x9496, 0x9498,
+		0x94C7, 0x94CF, 0x94D3, 0x94D4, 0x94DA, 0x94E6, 0x94FB, 0x951C, 0x9520, 0x741B, 0x741A,
+		0x7441, 0x745C, 0x7457, 0x7455, 0x7459, 0x7477, 0x746D, 0x747E, 0x749C, 0x748E, 0x7480,
+		0x7481, 0x7487, 0x748B, 0x749E, 0x74A8, 0x74A9, 0x7490, 0x74A7, 0x74D2, 0x74BA, 0x97EA,
+		0x97EB, 0x97EC, 0x674C, 0x6753, 0x675E, 0x6748, 0x6769, 0x67A5, 0x6787, 0x676A, 0x6773,
+		0x6798, 0x67A7, 0x6775, 0x67A8, 0x679E, 0x67AD, 0x678B, 0x6777, 0x677C, 0x67F0, 0x6809,
+		0x67D8, 0x680A, 0x67E9, 0x67B0, 0x680C, 0x67D9, 0x67B5, 0x67DA, 0x67B3, 0x67DD, 0x6800,
+		0x67C3, 0x67B8, 0x67E2, 0x680E, 0x67C1, 0x67FD, 0x6832, 0x6833, 0x6860, 0x6861, 0x684E,
+		0x6862, 0x6844, 0x6864, 0x6883, 0x681D, 0x6855, 0x6866, 0x6841, 0x6867, 0x6840, 0x683E,
+		0x684A, 0x6849, 0x6829, 0x68B5, 0x688F, 0x6874, 0x6877, 0x6893, 0x686B, 0x68C2, 0x696E,
+		0x68FC, 0x691F, 0x6920, 0x68F9, 0x9527, 0x9533, 0x953D, 0x9543, 0x9548, 0x954B, 0x9555,
+		0x955A, 0x9560, 0x956E, 0x9574, 0x9575, 0x9577, 0x9578, 0x9579, 0x957A, 0x957B, 0x957C,
+		0x957D, 0x957E, 0x9580, 0x9581, 0x9582, 0x9583, 0x9584, 0x9585, 0x9586, 0x9587, 0x9588,
+		0x9589, 0x958A, 0x958B, 0x958C, 0x958D, 0x958E, 0x958F, 0x9590, 0x9591, 0x9592, 0x9593,
+		0x9594, 0x9595, 0x9596, 0x9597, 0x9598, 0x9599, 0x959A, 0x959B, 0x959C, 0x959D, 0x959E,
+		0x959F, 0x95A0, 0x95A1, 0x95A2, 0x95A3, 0x95A4, 0x95A5, 0x95A6, 0x95A7, 0x95A8, 0x95A9,
+		0x95AA, 0x95AB, 0x95AC, 0x95AD, 0x95AE, 0x95AF, 0x95B0, 0x95B1, 0x95B2, 0x95B3, 0x95B4,
+		0x95B5, 0x95B6, 0x95B7, 0x95B8, 0x95B9, 0x95BA, 0x95BB, 0x95BC, 0x95BD, 0x95BE, 0x95BF,
+		0x95C0, 0x95C1, 0x95C2, 0x95C3, 0x95C4, 0x95C5, 0x95C6, 0x95C7, 0x95C8, 0x95C9, 0x95CA,
+		0x95CB, 0x6924, 0x68F0, 0x690B, 0x6901, 0x6957, 0x68E3, 0x6910, 0x6971, 0x6939, 0x6960,
+		0x6942, 0x695D, 0x6984, 0x696B, 0x6980, 0x6998, 0x6978, 0x6934, 0x69CC, 0x6987, 0x6988,
+		0x69CE, 0x6989, 0x6966, 0x6963, 0x6979, 0x699B, 0x69A7, 0x69BB, 0x69AB, 0x69AD, 0x69D4,
+		0x69B1, 0x69C1, 0x69CA, 0x69DF, 0x6995, 0x69E0, 0x698D, 0x69FF, 0x6A2F, 0x69ED, 0x6A17,
+		0x6A18, 0x6A65, 0x69F2, 0x6A44, 0x6A3E, 0x6AA0, 0x6A50, 0x6A5B, 0x6A35, 0x6A8E, 0x6A79,
+		0x6A3D, 0x6A28, 0x6A58, 0x6A7C, 0x6A91, 0x6A90, 0x6AA9, 0x6A97, 0x6AAB, 0x7337, 0x7352,
+		0x6B81, 0x6B82, 0x6B87, 0x6B84, 0x6B92, 0x6B93, 0x6B8D, 0x6B9A, 0x6B9B, 0x6BA1, 0x6BAA,
+		0x8F6B, 0x8F6D, 0x8F71, 0x8F72, 0x8F73, 0x8F75, 0x8F76, 0x8F78, 0x8F77, 0x8F79, 0x8F7A,
+		0x8F7C, 0x8F7E, 0x8F81, 0x8F82, 0x8F84, 0x8F87, 0x8F8B, 0x95CC, 0x95CD, 0x95CE, 0x95CF,
+		0x95D0, 0x95D1, 0x95D2, 0x95D3, 0x95D4, 0x95D5, 0x95D6, 0x95D7, 0x95D8, 0x95D9, 0x95DA,
+		0x95DB, 0x95DC, 0x95DD, 0x95DE, 0x95DF, 0x95E0, 0x95E1, 0x95E2, 0x95E3, 0x95E4, 0x95E5,
+		0x95E6, 0x95E7, 0x95EC, 0x95FF, 0x9607, 0x9613, 0x9618, 0x961B, 0x961E, 0x9620, 0x9623,
+		0x9624, 0x9625, 0x9626, 0x9627, 0x9628, 0x9629, 0x962B, 0x962C, 0x962D, 0x962F, 0x9630,
+		0x9637, 0x9638, 0x9639, 0x963A, 0x963E, 0x9641, 0x9643, 0x964A, 0x964E, 0x964F, 0x9651,
+		0x9652, 0x9653, 0x9656, 0x9657, 0x9658, 0x9659, 0x965A, 0x965C, 0x965D, 0x965E, 0x9660,
+		0x9663, 0x9665, 0x9666, 0x966B, 0x966D, 0x966E, 0x966F, 0x9670, 0x9671, 0x9673, 0x9678,
+		0x9679, 0x967A, 0x967B, 0x967C, 0x967D, 0x967E, 0x967F, 0x9680, 0x9681, 0x9682, 0x9683,
+		0x9684, 0x9687, 0x9689, 0x968A, 0x8F8D, 0x8F8E, 0x8F8F, 0x8F98, 0x8F9A, 0x8ECE, 0x620B,
+		0x6217, 0x621B, 0x621F, 0x6222, 0x6221, 0x6225, 0x6224, 0x622C, 0x81E7, 0x74EF, 0x74F4,
+		0x74FF, 0x750F, 0x7511, 0x7513, 0x6534, 0x65EE, 0x65EF, 0x65F0, 0x660A, 0x6619, 0x6772,
+		0x6603, 0x6615, 0x6600, 0x7085, 0x66F7, 0x661D, 0x6634, 0x6631, 0x6636, 0x6635, 0x8006,
+		0x665F, 0x6654, 0x6641, 0x664F, 0x6656, 0x6661, 0x6657, 0x6677, 0x6684, 0x668C, 0x66A7,
+		0x669D, 0x66BE, 0x66DB, 0x66DC, 0x66E6, 0x66E9, 0x8D32, 0x8D33, 0x8D36, 0x8D3B, 0x8D3D,
+		0x8D40, 0x8D45, 0x8D46, 0x8D48, 0x8D49, 0x8D47, 0x8D4D, 0x8D55, 0x8D59, 0x89C7, 0x89CA,
+		0x89CB, 0x89CC, 0x89CE, 0x89CF, 0x89D0, 0x89D1, 0x726E, 0x729F, 0x725D, 0x7266, 0x726F,
+		0x727E, 0x727F, 0x7284, 0x728B, 0x728D, 0x728F, 0x7292, 0x6308, 0x6332, 0x63B0, 0x968C,
+		0x968E, 0x9691, 0x9692, 0x9693, 0x9695, 0x9696, 0x969A, 0x969B, 0x969D, 0x969E, 0x969F,
+		0x96A0, 0x96A1, 0x96A2, 0x96A3, 0x96A4, 0x96A5, 0x96A6, 0x96A8, 0x96A9, 0x96AA, 0x96AB,
+		0x96AC, 0x96AD, 0x96AE, 0x96AF, 0x96B1, 0x96B2, 0x96B4, 0x96B5, 0x96B7, 0x96B8, 0x96BA,
+		0x96BB, 0x96BF, 0x96C2, 0x96C3, 0x96C8, 0x96CA, 0x96CB, 0x96D0, 0x96D1, 0x96D3, 0x96D4,
+		0x96D6, 0x96D7, 0x96D8, 0x96D9, 0x96DA, 0x96DB, 0x96DC, 0x96DD, 0x96DE, 0x96DF, 0x96E1,
+		0x96E2, 0x96E3, 0x96E4, 0x96E5, 0x96E6, 0x96E7, 0x96EB, 0x96EC, 0x96ED, 0x96EE, 0x96F0,
+		0x96F1, 0x96F2, 0x96F4, 0x96F5, 0x96F8, 0x96FA, 0x96FB, 0x96FC, 0x96FD, 0x96FF, 0x9702,
+		0x9703, 0x9705, 0x970A, 0x970B, 0x970C, 0x9710, 0x9711, 0x9712, 0x9714, 0x9715, 0x9717,
+		0x9718, 0x9719, 0x971A, 0x971B, 0x971D, 0x971F, 0x9720, 0x643F, 0x64D8, 0x8004, 0x6BEA,
+		0x6BF3, 0x6BFD, 0x6BF5, 0x6BF9, 0x6C05, 0x6C07, 0x6C06, 0x6C0D, 0x6C15, 0x6C18, 0x6C19,
+		0x6C1A, 0x6C21, 0x6C29, 0x6C24, 0x6C2A, 0x6C32, 0x6535, 0x6555, 0x656B, 0x724D, 0x7252,
+		0x7256, 0x7230, 0x8662, 0x5216, 0x809F, 0x809C, 0x8093, 0x80BC, 0x670A, 0x80BD, 0x80B1,
+		0x80AB, 0x80AD, 0x80B4, 0x80B7, 0x80E7, 0x80E8, 0x80E9, 0x80EA, 0x80DB, 0x80C2, 0x80C4,
+		0x80D9, 0x80CD, 0x80D7, 0x6710, 0x80DD, 0x80EB, 0x80F1, 0x80F4, 0x80ED, 0x810D, 0x810E,
+		0x80F2, 0x80FC, 0x6715, 0x8112, 0x8C5A, 0x8136, 0x811E, 0x812C, 0x8118, 0x8132, 0x8148,
+		0x814C, 0x8153, 0x8174, 0x8159, 0x815A, 0x8171, 0x8160, 0x8169, 0x817C, 0x817D, 0x816D,
+		0x8167, 0x584D, 0x5AB5, 0x8188, 0x8182, 0x8191, 0x6ED5, 0x81A3, 0x81AA, 0x81CC, 0x6726,
+		0x81CA, 0x81BB, 0x9721, 0x9722, 0x9723, 0x9724, 0x9725, 0x9726, 0x9727, 0x9728, 0x9729,
+		0x972B, 0x972C, 0x972E, 0x972F, 0x9731, 0x9733, 0x9734, 0x9735, 0x9736, 0x9737, 0x973A,
+		0x973B, 0x973C, 0x973D, 0x973F, 0x9740, 0x9741, 0x9742, 0x9743, 0x9744, 0x9745, 0x9746,
+		0x9747, 0x9748, 0x9749, 0x974A, 0x974B, 0x974C, 0x974D, 0x974E, 0x974F, 0x9750, 0x9751,
+		0x9754, 0x9755, 0x9757, 0x9758, 0x975A, 0x975C, 0x975D, 0x975F, 0x9763, 0x9764, 0x9766,
+		0x9767, 0x9768, 0x976A, 0x976B, 0x976C, 0x976D, 0x976E, 0x976F, 0x9770, 0x9771, 0x9772,
+		0x9775, 0x9777, 0x9778, 0x9779, 0x977A, 0x977B, 0x977D, 0x977E, 0x977F, 0x9780, 0x9781,
+		0x9782, 0x9783, 0x9784, 0x9786, 0x9787, 0x9788, 0x9789, 0x978A, 0x978C, 0x978E, 0x978F,
+		0x9790, 0x9793, 0x9795, 0x9796, 0x9797, 0x9799, 0x979A, 0x979B, 0x979C, 0x979D, 0x81C1,
+		0x81A6, 0x6B24, 0x6B37, 0x6B39, 0x6B43, 0x6B46, 0x6B59, 0x98D1, 0x98D2, 0x98D3, 0x98D5,
+		0x98D9, 0x98DA, 0x6BB3, 0x5F40, 0x6BC2, 0x89F3, 0x6590, 0x9F51, 0x6593, 0x65BC, 0x65C6,
+		0x65C4, 0x65C3, 0x65CC, 0x65CE, 0x65D2, 0x65D6, 0x7080, 0x709C, 0x7096, 0x709D, 0x70BB,
+		0x70C0, 0x70B7, 0x70AB, 0x70B1, 0x70E8, 0x70CA, 0x7110, 0x7113, 0x7116, 0x712F, 0x7131,
+		0x7173, 0x715C, 0x7168, 0x7145, 0x7172, 0x714A, 0x7178, 0x717A, 0x7198, 0x71B3, 0x71B5,
+		0x71A8, 0x71A0, 0x71E0, 0x71D4, 0x71E7, 0x71F9, 0x721D, 0x7228, 0x706C, 0x7118, 0x7166,
+		0x71B9, 0x623E, 0x623D, 0x6243, 0x6248, 0x6249, 0x793B, 0x7940, 0x7946, 0x7949, 0x795B,
+		0x795C, 0x7953, 0x795A, 0x7962, 0x7957, 0x7960, 0x796F, 0x7967, 0x797A, 0x7985, 0x798A,
+		0x799A, 0x79A7, 0x79B3, 0x5FD1, 0x5FD0, 0x979E, 0x979F, 0x97A1, 0x97A2, 0x97A4, 0x97A5,
+		0x97A6, 0x97A7, 0x97A8, 0x97A9, 0x97AA, 0x97AC, 0x97AE, 0x97B0, 0x97B1, 0x97B3, 0x97B5,
+		0x97B6, 0x97B7, 0x97B8, 0x97B9, 0x97BA, 0x97BB, 0x97BC, 0x97BD, 0x97BE, 0x97BF, 0x97C0,
+		0x97C1, 0x97C2, 0x97C3, 0x97C4, 0x97C5, 0x97C6, 0x97C7, 0x97C8, 0x97C9, 0x97CA, 0x97CB,
+		0x97CC, 0x97CD, 0x97CE, 0x97CF, 0x97D0, 0x97D1, 0x97D2, 0x97D3, 0x97D4, 0x97D5, 0x97D6,
+		0x97D7, 0x97D8, 0x97D9, 0x97DA, 0x97DB, 0x97DC, 0x97DD, 0x97DE, 0x97DF, 0x97E0, 0x97E1,
+		0x97E2, 0x97E3, 0x97E4, 0x97E5, 0x97E8, 0x97EE, 0x97EF, 0x97F0, 0x97F1, 0x97F2, 0x97F4,
+		0x97F7, 0x97F8, 0x97F9, 0x97FA, 0x97FB, 0x97FC, 0x97FD, 0x97FE, 0x97FF, 0x9800, 0x9801,
+		0x9802, 0x9803, 0x9804, 0x9805, 0x9806, 0x9807, 0x9808, 0x9809, 0x980A, 0x980B, 0x980C,
+		0x980D, 0x980E, 0x603C, 0x605D, 0x605A, 0x6067, 0x6041, 0x6059, 0x6063, 0x60AB, 0x6106,
+		0x610D, 0x615D, 0x61A9, 0x619D, 0x61CB, 0x61D1, 0x6206, 0x8080, 0x807F, 0x6C93, 0x6CF6,
+		0x6DFC, 0x77F6, 0x77F8, 0x7800, 0x7809, 0x7817, 0x7818, 0x7811, 0x65AB, 0x782D, 0x781C,
+		0x781D, 0x7839, 0x783A, 0x783B, 0x781F, 0x783C, 0x7825, 0x782C, 0x7823, 0x7829, 0x784E,
+		0x786D, 0x7856, 0x7857, 0x7826, 0x7850, 0x7847, 0x784C, 0x786A, 0x789B, 0x7893, 0x789A,
+		0x7887, 0x789C, 0x78A1, 0x78A3, 0x78B2, 0x78B9, 0x78A5, 0x78D4, 0x78D9, 0x78C9, 0x78EC,
+		0x78F2, 0x7905, 0x78F4, 0x7913, 0x7924, 0x791E, 0x7934, 0x9F9B, 0x9EF9, 0x9EFB, 0x9EFC,
+		0x76F1, 0x7704, 0x770D, 0x76F9, 0x7707, 0x7708, 0x771A, 0x7722, 0x7719, 0x772D, 0x7726,
+		0x7735, 0x7738, 0x7750, 0x7751, 0x7747, 0x7743, 0x775A, 0x7768, 0x980F, 0x9810, 0x9811,
+		0x9812, 0x9813, 0x9814, 0x9815, 0x9816, 0x9817, 0x9818, 0x9819, 0x981A, 0x981B, 0x981C,
+		0x981D, 0x981E, 0x981F, 0x9820, 0x9821, 0x9822, 0x9823, 0x9824, 0x9825, 0x9826, 0x9827,
+		0x9828, 0x9829, 0x982A, 0x982B, 0x982C, 0x982D, 0x982E, 0x982F, 0x9830, 0x9831, 0x9832,
+		0x9833, 0x9834, 0x9835, 0x9836, 0x9837, 0x9838, 0x9839, 0x983A, 0x983B, 0x983C, 0x983D,
+		0x983E, 0x983F, 0x9840, 0x9841, 0x9842, 0x9843, 0x9844, 0x9845, 0x9846, 0x9847, 0x9848,
+		0x9849, 0x984A, 0x984B, 0x984C, 0x984D, 0x984E, 0x984F, 0x9850, 0x9851, 0x9852, 0x9853,
+		0x9854, 0x9855, 0x9856, 0x9857, 0x9858, 0x9859, 0x985A, 0x985B, 0x985C, 0x985D, 0x985E,
+		0x985F, 0x9860, 0x9861, 0x9862, 0x9863, 0x9864, 0x9865, 0x9866, 0x9867, 0x9868, 0x9869,
+		0x986A, 0x986B, 0x986C, 0x986D, 0x986E, 0x7762, 0x7765, 0x777F, 0x778D, 0x777D, 0x7780,
+		0x778C, 0x7791, 0x779F, 0x77A0, 0x77B0, 0x77B5, 0x77BD, 0x753A, 0x7540, 0x754E, 0x754B,
+		0x7548, 0x755B, 0x7572, 0x7579, 0x7583, 0x7F58, 0x7F61, 0x7F5F, 0x8A48, 0x7F68, 0x7F74,
+		0x7F71, 0x7F79, 0x7F81, 0x7F7E, 0x76CD, 0x76E5, 0x8832, 0x9485, 0x9486, 0x9487, 0x948B,
+		0x948A, 0x948C, 0x948D, 0x948F, 0x9490, 0x9494, 0x9497, 0x9495, 0x949A, 0x949B, 0x949C,
+		0x94A3, 0x94A4, 0x94AB, 0x94AA, 0x94AD, 0x94AC, 0x94AF, 0x94B0, 0x94B2, 0x94B4, 0x94B6,
+		0x94B7, 0x94B8, 0x94B9, 0x94BA, 0x94BC, 0x94BD, 0x94BF, 0x94C4, 0x94C8, 0x94C9, 0x94CA,
+		0x94CB, 0x94CC, 0x94CD, 0x94CE, 0x94D0, 0x94D1, 0x94D2, 0x94D5, 0x94D6, 0x94D7, 0x94D9,
+		0x94D8, 0x94DB, 0x94DE, 0x94DF, 0x94E0, 0x94E2, 0x94E4, 0x94E5, 0x94E7, 0x94E8, 0x94EA,
+		0x986F, 0x9870, 0x9871, 0x9872, 0x9873, 0x9874, 0x988B, 0x988E, 0x9892, 0x9895, 0x9899,
+		0x98A3, 0x98A8, 0x98A9, 0x98AA, 0x98AB, 0x98AC, 0x98AD, 0x98AE, 0x98AF, 0x98B0, 0x98B1,
+		0x98B2, 0x98B3, 0x98B4, 0x98B5, 0x98B6, 0x98B7, 0x98B8, 0x98B9, 0x98BA, 0x98BB, 0x98BC,
+		0x98BD, 0x98BE, 0x98BF, 0x98C0, 0x98C1, 0x98C2, 0x98C3, 0x98C4, 0x98C5, 0x98C6, 0x98C7,
+		0x98C8, 0x98C9, 0x98CA, 0x98CB, 0x98CC, 0x98CD, 0x98CF, 0x98D0, 0x98D4, 0x98D6, 0x98D7,
+		0x98DB, 0x98DC, 0x98DD, 0x98E0, 0x98E1, 0x98E2, 0x98E3, 0x98E4, 0x98E5, 0x98E6, 0x98E9,
+		0x98EA, 0x98EB, 0x98EC, 0x98ED, 0x98EE, 0x98EF, 0x98F0, 0x98F1, 0x98F2, 0x98F3, 0x98F4,
+		0x98F5, 0x98F6, 0x98F7, 0x98F8, 0x98F9, 0x98FA, 0x98FB, 0x98FC, 0x98FD, 0x98FE, 0x98FF,
+		0x9900, 0x9901, 0x9902, 0x9903, 0x9904, 0x9905, 0x9906, 0x9907, 0x94E9, 0x94EB, 0x94EE,
+		0x94EF, 0x94F3, 0x94F4, 0x94F5, 0x94F7, 0x94F9, 0x94FC, 0x94FD, 0x94FF, 0x9503, 0x9502,
+		0x9506, 0x9507, 0x9509, 0x950A, 0x950D, 0x950E, 0x950F, 0x9512, 0x9513, 0x9514, 0x9515,
+		0x9516, 0x9518, 0x951B, 0x951D, 0x951E, 0x951F, 0x9522, 0x952A, 0x952B, 0x9529, 0x952C,
+		0x9531, 0x9532, 0x9534, 0x9536, 0x9537, 0x9538, 0x953C, 0x953E, 0x953F, 0x9542, 0x9535,
+		0x9544, 0x9545, 0x9546, 0x9549, 0x954C, 0x954E, 0x954F, 0x9552, 0x9553, 0x9554, 0x9556,
+		0x9557, 0x9558, 0x9559, 0x955B, 0x955E, 0x955F, 0x955D, 0x9561, 0x9562, 0x9564, 0x9565,
+		0x9566, 0x9567, 0x9568, 0x9569, 0x956A, 0x956B, 0x956C, 0x956F, 0x9571, 0x9572, 0x9573,
+		0x953A, 0x77E7, 0x77EC, 0x96C9, 0x79D5, 0x79ED, 0x79E3, 0x79EB, 0x7A06, 0x5D47, 0x7A03,
+		0x7A02, 0x7A1E, 0x7A14, 0x9908, 0x9909, 0x990A, 0x990B, 0x990C, 0x990E, 0x990F, 0x9911,
+		0x9912, 0x9913, 0x9914, 0x9915, 0x9916, 0x9917, 0x9918, 0x9919, 0x991A, 0x991B, 0x991C,
+		0x991D, 0x991E, 0x991F, 0x9920, 0x9921, 0x9922, 0x9923, 0x9924, 0x9925, 0x9926, 0x9927,
+		0x9928, 0x9929, 0x992A, 0x992B, 0x992C, 0x992D, 0x992F, 0x9930, 0x9931, 0x9932, 0x9933,
+		0x9934, 0x9935, 0x9936, 0x9937, 0x9938, 0x9939, 0x993A, 0x993B, 0x993C, 0x993D, 0x993E,
+		0x993F, 0x9940, 0x9941, 0x9942, 0x9943, 0x9944, 0x9945, 0x9946, 0x9947, 0x9948, 0x9949,
+		0x994A, 0x994B, 0x994C, 0x994D, 0x994E, 0x994F, 0x9950, 0x9951, 0x9952, 0x9953, 0x9956,
+		0x9957, 0x9958, 0x9959, 0x995A, 0x995B, 0x995C, 0x995D, 0x995E, 0x995F, 0x9960, 0x9961,
+		0x9962, 0x9964, 0x9966, 0x9973, 0x9978, 0x9979, 0x997B, 0x997E, 0x9982, 0x9983, 0x9989,
+		0x7A39, 0x7A37, 0x7A51, 0x9ECF, 0x99A5, 0x7A70, 0x7688, 0x768E, 0x7693, 0x7699, 0x76A4,
+		0x74DE, 0x74E0, 0x752C, 0x9E20, 0x9E22, 0x9E28, 0x9E29, 0x9E2A, 0x9E2B, 0x9E2C, 0x9E32,
+		0x9E31, 0x9E36, 0x9E38, 0x9E37, 0x9E39, 0x9E3A, 0x9E3E, 0x9E41, 0x9E42, 0x9E44, 0x9E46,
+		0x9E47, 0x9E48, 0x9E49, 0x9E4B, 0x9E4C, 0x9E4E, 0x9E51, 0x9E55, 0x9E57, 0x9E5A, 0x9E5B,
+		0x9E5C, 0x9E5E, 0x9E63, 0x9E66, 0x9E67, 0x9E68, 0x9E69, 0x9E6A, 0x9E6B, 0x9E6C, 0x9E71,
+		0x9E6D, 0x9E73, 0x7592, 0x7594, 0x7596, 0x75A0, 0x759D, 0x75AC, 0x75A3, 0x75B3, 0x75B4,
+		0x75B8, 0x75C4, 0x75B1, 0x75B0, 0x75C3, 0x75C2, 0x75D6, 0x75CD, 0x75E3, 0x75E8, 0x75E6,
+		0x75E4, 0x75EB, 0x75E7, 0x7603, 0x75F1, 0x75FC, 0x75FF, 0x7610, 0x7600, 0x7605, 0x760C,
+		0x7617, 0x760A, 0x7625, 0x7618, 0x7615, 0x7619, 0x998C, 0x998E, 0x999A, 0x999B, 0x999C,
+		0x999D, 0x999E, 0x999F, 0x99A0, 0x99A1, 0x99A2, 0x99A3, 0x99A4, 0x99A6, 0x99A7, 0x99A9,
+		0x99AA, 0x99AB, 0x99AC, 0x99AD, 0x99AE, 0x99AF, 0x99B0, 0x99B1, 0x99B2, 0x99B3, 0x99B4,
+		0x99B5, 0x99B6, 0x99B7, 0x99B8, 0x99B9, 0x99BA, 0x99BB, 0x99BC, 0x99BD, 0x99BE, 0x99BF,
+		0x99C0, 0x99C1, 0x99C2, 0x99C3, 0x99C4, 0x99C5, 0x99C6, 0x99C7, 0x99C8, 0x99C9, 0x99CA,
+		0x99CB, 0x99CC, 0x99CD, 0x99CE, 0x99CF, 0x99D0, 0x99D1, 0x99D2, 0x99D3, 0x99D4, 0x99D5,
+		0x99D6, 0x99D7, 0x99D8, 0x99D9, 0x99DA, 0x99DB, 0x99DC, 0x99DD, 0x99DE, 0x99DF, 0x99E0,
+		0x99E1, 0x99E2, 0x99E3, 0x99E4, 0x99E5, 0x99E6, 0x99E7, 0x99E8, 0x99E9, 0x99EA, 0x99EB,
+		0x99EC, 0x99ED, 0x99EE, 0x99EF, 0x99F0, 0x99F1, 0x99F2, 0x99F3, 0x99F4, 0x99F5, 0x99F6,
+		0x99F7, 0x99F8, 0x99F9, 0x761B, 0x763C, 0x7622, 0x7620, 0x7640, 0x762D, 0x7630, 0x763F,
+		0x7635, 0x7643, 0x763E, 0x7633, 0x764D, 0x765E, 0x7654, 0x765C, 0x7656, 0x766B, 0x766F,
+		0x7FCA, 0x7AE6, 0x7A78, 0x7A79, 0x7A80, 0x7A86, 0x7A88, 0x7A95, 0x7AA6, 0x7AA0, 0x7AAC,
+		0x7AA8, 0x7AAD, 0x7AB3, 0x8864, 0x8869, 0x8872, 0x887D, 0x887F, 0x8882, 0x88A2, 0x88C6,
+		0x88B7, 0x88BC, 0x88C9, 0x88E2, 0x88CE, 0x88E3, 0x88E5, 0x88F1, 0x891A, 0x88FC, 0x88E8,
+		0x88FE, 0x88F0, 0x8921, 0x8919, 0x8913, 0x891B, 0x890A, 0x8934, 0x892B, 0x8936, 0x8941,
+		0x8966, 0x897B, 0x758B, 0x80E5, 0x76B2, 0x76B4, 0x77DC, 0x8012, 0x8014, 0x8016, 0x801C,
+		0x8020, 0x8022, 0x8025, 0x8026, 0x8027, 0x8029, 0x8028, 0x8031, 0x800B, 0x8035, 0x8043,
+		0x8046, 0x804D, 0x8052, 0x8069, 0x8071, 0x8983, 0x9878, 0x9880, 0x9883, 0x99FA, 0x99FB,
+		0x99FC, 0x99FD, 0x99FE, 0x99FF, 0x9A00, 0x9A01, 0x9A02, 0x9A03, 0x9A04, 0x9A05, 0x9A06,
+		0x9A07, 0x9A08, 0x9A09, 0x9A0A, 0x9A0B, 0x9A0C, 0x9A0D, 0x9A0E, 0x9A0F, 0x9A10, 0x9A11,
+		0x9A12, 0x9A13, 0x9A14, 0x9A15, 0x9A16, 0x9A17, 0x9A18, 0x9A19, 0x9A1A, 0x9A1B, 0x9A1C,
+		0x9A1D, 0x9A1E, 0x9A1F, 0x9A20, 0x9A21, 0x9A22, 0x9A23, 0x9A24, 0x9A25, 0x9A26, 0x9A27,
+		0x9A28, 0x9A29, 0x9A2A, 0x9A2B, 0x9A2C, 0x9A2D, 0x9A2E, 0x9A2F, 0x9A30, 0x9A31, 0x9A32,
+		0x9A33, 0x9A34, 0x9A35, 0x9A36, 0x9A37, 0x9A38, 0x9A39, 0x9A3A, 0x9A3B, 0x9A3C, 0x9A3D,
+		0x9A3E, 0x9A3F, 0x9A40, 0x9A41, 0x9A42, 0x9A43, 0x9A44, 0x9A45, 0x9A46, 0x9A47, 0x9A48,
+		0x9A49, 0x9A4A, 0x9A4B, 0x9A4C, 0x9A4D, 0x9A4E, 0x9A4F, 0x9A50, 0x9A51, 0x9A52, 0x9A53,
+		0x9A54, 0x9A55, 0x9A56, 0x9A57, 0x9A58, 0x9A59, 0x9889, 0x988C, 0x988D, 0x988F, 0x9894,
+		0x989A, 0x989B, 0x989E, 0x989F, 0x98A1, 0x98A2, 0x98A5, 0x98A6, 0x864D, 0x8654, 0x866C,
+		0x866E, 0x867F, 0x867A, 0x867C, 0x867B, 0x86A8, 0x868D, 0x868B, 0x86AC, 0x869D, 0x86A7,
+		0x86A3, 0x86AA, 0x8693, 0x86A9, 0x86B6, 0x86C4, 0x86B5, 0x86CE, 0x86B0, 0x86BA, 0x86B1,
+		0x86AF, 0x86C9, 0x86CF, 0x86B4, 0x86E9, 0x86F1, 0x86F2, 0x86ED, 0x86F3, 0x86D0, 0x8713,
+		0x86DE, 0x86F4, 0x86DF, 0x86D8, 0x86D1, 0x8703, 0x8707, 0x86F8, 0x8708, 0x870A, 0x870D,
+		0x8709, 0x8723, 0x873B, 0x871E, 0x8725, 0x872E, 0x871A, 0x873E, 0x8748, 0x8734, 0x8731,
+		0x8729, 0x8737, 0x873F, 0x8782, 0x8722, 0x877D, 0x877E, 0x877B, 0x8760, 0x8770, 0x874C,
+		0x876E, 0x878B, 0x8753, 0x8763, 0x877C, 0x8764, 0x8759, 0x8765, 0x8793, 0x87AF, 0x87A8,
+		0x87D2, 0x9A5A, 0x9A5B, 0x9A5C, 0x9A5D, 0x9A5E, 0x9A5F, 0x9A60, 0x9A61, 0x9A62, 0x9A63,
+		0x9A64, 0x9A65, 0x9A66, 0x9A67, 0x9A68, 0x9A69, 0x9A6A, 0x9A6B, 0x9A72, 0x9A83, 0x9A89,
+		0x9A8D, 0x9A8E, 0x9A94, 0x9A95, 0x9A99, 0x9AA6, 0x9AA9, 0x9AAA, 0x9AAB, 0x9AAC, 0x9AAD,
+		0x9AAE, 0x9AAF, 0x9AB2, 0x9AB3, 0x9AB4, 0x9AB5, 0x9AB9, 0x9ABB, 0x9ABD, 0x9ABE, 0x9ABF,
+		0x9AC3, 0x9AC4, 0x9AC6, 0x9AC7, 0x9AC8, 0x9AC9, 0x9ACA, 0x9ACD, 0x9ACE, 0x9ACF, 0x9AD0,
+		0x9AD2, 0x9AD4, 0x9AD5, 0x9AD6, 0x9AD7, 0x9AD9, 0x9ADA, 0x9ADB, 0x9ADC, 0x9ADD, 0x9ADE,
+		0x9AE0, 0x9AE2, 0x9AE3, 0x9AE4, 0x9AE5, 0x9AE7, 0x9AE8, 0x9AE9, 0x9AEA, 0x9AEC, 0x9AEE,
+		0x9AF0, 0x9AF1, 0x9AF2, 0x9AF3, 0x9AF4, 0x9AF5, 0x9AF6, 0x9AF7, 0x9AF8, 0x9AFA, 0x9AFC,
+		0x9AFD, 0x9AFE, 0x9AFF, 0x9B00, 0x9B01, 0x9B02, 0x9B04, 0x9B05, 0x9B06, 0x87C6, 0x8788,
+		0x8785, 0x87AD, 0x8797, 0x8783, 0x87AB, 0x87E5, 0x87AC, 0x87B5, 0x87B3, 0x87CB, 0x87D3,
+		0x87BD, 0x87D1, 0x87C0, 0x87CA, 0x87DB, 0x87EA, 0x87E0, 0x87EE, 0x8816, 0x8813, 0x87FE,
+		0x880A, 0x881B, 0x8821, 0x8839, 0x883C, 0x7F36, 0x7F42, 0x7F44, 0x7F45, 0x8210, 0x7AFA,
+		0x7AFD, 0x7B08, 0x7B03, 0x7B04, 0x7B15, 0x7B0A, 0x7B2B, 0x7B0F, 0x7B47, 0x7B38, 0x7B2A,
+		0x7B19, 0x7B2E, 0x7B31, 0x7B20, 0x7B25, 0x7B24, 0x7B33, 0x7B3E, 0x7B1E, 0x7B58, 0x7B5A,
+		0x7B45, 0x7B75, 0x7B4C, 0x7B5D, 0x7B60, 0x7B6E, 0x7B7B, 0x7B62, 0x7B72, 0x7B71, 0x7B90,
+		0x7BA6, 0x7BA7, 0x7BB8, 0x7BAC, 0x7B9D, 0x7BA8, 0x7B85, 0x7BAA, 0x7B9C, 0x7BA2, 0x7BAB,
+		0x7BB4, 0x7BD1, 0x7BC1, 0x7BCC, 0x7BDD, 0x7BDA, 0x7BE5, 0x7BE6, 0x7BEA, 0x7C0C, 0x7BFE,
+		0x7BFC, 0x7C0F, 0x7C16, 0x7C0B, 0x9B07, 0x9B09, 0x9B0A, 0x9B0B, 0x9B0C, 0x9B0D, 0x9B0E,
+		0x9B10, 0x9B11, 0x9B12, 0x9B14, 0x9B15, 0x9B16, 0x9B17, 0x9B18, 0x9B19, 0x9B1A, 0x9B1B,
+		0x9B1C, 0x9B1D, 0x9B1E, 0x9B20, 0x9B21, 0x9B22, 0x9B24, 0x9B25, 0x9B26, 0x9B27, 0x9B28,
+		0x9B29, 0x9B2A, 0x9B2B, 0x9B2C, 0x9B2D, 0x9B2E, 0x9B30, 0x9B31, 0x9B33, 0x9B34, 0x9B35,
+		0x9B36, 0x9B37, 0x9B38, 0x9B39, 0x9B3A, 0x9B3D, 0x9B3E, 0x9B3F, 0x9B40, 0x9B46, 0x9B4A,
+		0x9B4B, 0x9B4C, 0x9B4E, 0x9B50, 0x9B52, 0x9B53, 0x9B55, 0x9B56, 0x9B57, 0x9B58, 0x9B59,
+		0x9B5A, 0x9B5B, 0x9B5C, 0x9B5D, 0x9B5E, 0x9B5F, 0x9B60, 0x9B61, 0x9B62, 0x9B63, 0x9B64,
+		0x9B65, 0x9B66, 0x9B67, 0x9B68, 0x9B69, 0x9B6A, 0x9B6B, 0x9B6C, 0x9B6D, 0x9B6E, 0x9B6F,
+		0x9B70, 0x9B71, 0x9B72, 0x9B73, 0x9B74, 0x9B75, 0x9B76, 0x9B77, 0x9B78, 0x9B79, 0x9B7A,
+		0x9B7B, 0x7C1F, 0x7C2A, 0x7C26, 0x7C38, 0x7C41, 0x7C40, 0x81FE, 0x8201, 0x8202, 0x8204,
+		0x81EC, 0x8844, 0x8221, 0x8222, 0x8223, 0x822D, 0x822F, 0x8228, 0x822B, 0x8238, 0x823B,
+		0x8233, 0x8234, 0x823E, 0x8244, 0x8249, 0x824B, 0x824F, 0x825A, 0x825F, 0x8268, 0x887E,
+		0x8885, 0x8888, 0x88D8, 0x88DF, 0x895E, 0x7F9D, 0x7F9F, 0x7FA7, 0x7FAF, 0x7FB0, 0x7FB2,
+		0x7C7C, 0x6549, 0x7C91, 0x7C9D, 0x7C9C, 0x7C9E, 0x7CA2, 0x7CB2, 0x7CBC, 0x7CBD, 0x7CC1,
+		0x7CC7, 0x7CCC, 0x7CCD, 0x7CC8, 0x7CC5, 0x7CD7, 0x7CE8, 0x826E, 0x66A8, 0x7FBF, 0x7FCE,
+		0x7FD5, 0x7FE5, 0x7FE1, 0x7FE6, 0x7FE9, 0x7FEE, 0x7FF3, 0x7CF8, 0x7D77, 0x7DA6, 0x7DAE,
+		0x7E47, 0x7E9B, 0x9EB8, 0x9EB4, 0x8D73, 0x8D84, 0x8D94, 0x8D91, 0x8DB1, 0x8D67, 0x8D6D,
+		0x8C47, 0x8C49, 0x914A, 0x9150, 0x914E, 0x914F, 0x9164, 0x9B7C, 0x9B7D, 0x9B7E, 0x9B7F,
+		0x9B80, 0x9B81, 0x9B82, 0x9B83, 0x9B84, 0x9B85, 0x9B86, 0x9B87, 0x9B88, 0x9B89, 0x9B8A,
+		0x9B8B, 0x9B8C, 0x9B8D, 0x9B8E, 0x9B8F, 0x9B90, 0x9B91, 0x9B92, 0x9B93, 0x9B94, 0x9B95,
+		0x9B96, 0x9B97, 0x9B98, 0x9B99, 0x9B9A, 0x9B9B, 0x9B9C, 0x9B9D, 0x9B9E, 0x9B9F, 0x9BA0,
+		0x9BA1, 0x9BA2, 0x9BA3, 0x9BA4, 0x9BA5, 0x9BA6, 0x9BA7, 0x9BA8, 0x9BA9, 0x9BAA, 0x9BAB,
+		0x9BAC, 0x9BAD, 0x9BAE, 0x9BAF, 0x9BB0, 0x9BB1, 0x9BB2, 0x9BB3, 0x9BB4, 0x9BB5, 0x9BB6,
+		0x9BB7, 0x9BB8, 0x9BB9, 0x9BBA, 0x9BBB, 0x9BBC, 0x9BBD, 0x9BBE, 0x9BBF, 0x9BC0, 0x9BC1,
+		0x9BC2, 0x9BC3, 0x9BC4, 0x9BC5, 0x9BC6, 0x9BC7, 0x9BC8, 0x9BC9, 0x9BCA, 0x9BCB, 0x9BCC,
+		0x9BCD, 0x9BCE, 0x9BCF, 0x9BD0, 0x9BD1, 0x9BD2, 0x9BD3, 0x9BD4, 0x9BD5, 0x9BD6, 0x9BD7,
+		0x9BD8, 0x9BD9, 0x9BDA, 0x9BDB, 0x9162, 0x9161, 0x9170, 0x9169, 0x916F, 0x917D, 0x917E,
+		0x9172, 0x9174, 0x9179, 0x918C, 0x9185, 0x9190, 0x918D, 0x9191, 0x91A2, 0x91A3, 0x91AA,
+		0x91AD, 0x91AE, 0x91AF, 0x91B5, 0x91B4, 0x91BA, 0x8C55, 0x9E7E, 0x8DB8, 0x8DEB, 0x8E05,
+		0x8E59, 0x8E69, 0x8DB5, 0x8DBF, 0x8DBC, 0x8DBA, 0x8DC4, 0x8DD6, 0x8DD7, 0x8DDA, 0x8DDE,
+		0x8DCE, 0x8DCF, 0x8DDB, 0x8DC6, 0x8DEC, 0x8DF7, 0x8DF8, 0x8DE3, 0x8DF9, 0x8DFB, 0x8DE4,
+		0x8E09, 0x8DFD, 0x8E14, 0x8E1D, 0x8E1F, 0x8E2C, 0x8E2E, 0x8E23, 0x8E2F, 0x8E3A, 0x8E40,
+		0x8E39, 0x8E35, 0x8E3D, 0x8E31, 0x8E49, 0x8E41, 0x8E42, 0x8E51, 0x8E52, 0x8E4A, 0x8E70,
+		0x8E76, 0x8E7C, 0x8E6F, 0x8E74, 0x8E85, 0x8E8F, 0x8E94, 0x8E90, 0x8E9C, 0x8E9E, 0x8C78,
+		0x8C82, 0x8C8A, 0x8C85, 0x8C98, 0x8C94, 0x659B, 0x89D6, 0x89DE, 0x89DA, 0x89DC, 0x9BDC,
+		0x9BDD, 0x9BDE, 0x9BDF, 0x9BE0, 0x9BE1, 0x9BE2, 0x9BE3, 0x9BE4, 0x9BE5, 0x9BE6, 0x9BE7,
+		0x9BE8, 0x9BE9, 0x9BEA, 0x9BEB, 0x9BEC, 0x9BED, 0x9BEE, 0x9BEF, 0x9BF0, 0x9BF1, 0x9BF2,
+		0x9BF3, 0x9BF4, 0x9BF5, 0x9BF6, 0x9BF7, 0x9BF8, 0x9BF9, 0x9BFA, 0x9BFB, 0x9BFC, 0x9BFD,
+		0x9BFE, 0x9BFF, 0x9C00, 0x9C01, 0x9C02, 0x9C03, 0x9C04, 0x9C05, 0x9C06, 0x9C07, 0x9C08,
+		0x9C09, 0x9C0A, 0x9C0B, 0x9C0C, 0x9C0D, 0x9C0E, 0x9C0F, 0x9C10, 0x9C11, 0x9C12, 0x9C13,
+		0x9C14, 0x9C15, 0x9C16, 0x9C17, 0x9C18, 0x9C19, 0x9C1A, 0x9C1B, 0x9C1C, 0x9C1D, 0x9C1E,
+		0x9C1F, 0x9C20, 0x9C21, 0x9C22, 0x9C23, 0x9C24, 0x9C25, 0x9C26, 0x9C27, 0x9C28, 0x9C29,
+		0x9C2A, 0x9C2B, 0x9C2C, 0x9C2D, 0x9C2E, 0x9C2F, 0x9C30, 0x9C31, 0x9C32, 0x9C33, 0x9C34,
+		0x9C35, 0x9C36, 0x9C37, 0x9C38, 0x9C39, 0x9C3A, 0x9C3B, 0x89E5, 0x89EB, 0x89EF, 0x8A3E,
+		0x8B26, 0x9753, 0x96E9, 0x96F3, 0x96EF, 0x9706, 0x9701, 0x9708, 0x970F, 0x970E, 0x972A,
+		0x972D, 0x9730, 0x973E, 0x9F80, 0x9F83, 0x9F85, 0x9F86, 0x9F87, 0x9F88, 0x9F89, 0x9F8A,
+		0x9F8C, 0x9EFE, 0x9F0B, 0x9F0D, 0x96B9, 0x96BC, 0x96BD, 0x96CE, 0x96D2, 0x77BF, 0x96E0,
+		0x928E, 0x92AE, 0x92C8, 0x933E, 0x936A, 0x93CA, 0x938F, 0x943E, 0x946B, 0x9C7F, 0x9C82,
+		0x9C85, 0x9C86, 0x9C87, 0x9C88, 0x7A23, 0x9C8B, 0x9C8E, 0x9C90, 0x9C91, 0x9C92, 0x9C94,
+		0x9C95, 0x9C9A, 0x9C9B, 0x9C9E, 0x9C9F, 0x9CA0, 0x9CA1, 0x9CA2, 0x9CA3, 0x9CA5, 0x9CA6,
+		0x9CA7, 0x9CA8, 0x9CA9, 0x9CAB, 0x9CAD, 0x9CAE, 0x9CB0, 0x9CB1, 0x9CB2, 0x9CB3, 0x9CB4,
+		0x9CB5, 0x9CB6, 0x9CB7, 0x9CBA, 0x9CBB, 0x9CBC, 0x9CBD, 0x9CC4, 0x9CC5, 0x9CC6, 0x9CC7,
+		0x9CCA, 0x9CCB, 0x9C3C, 0x9C3D, 0x9C3E, 0x9C3F, 0x9C40, 0x9C41, 0x9C42, 0x9C43, 0x9C44,
+		0x9C45, 0x9C46, 0x9C47, 0x9C48, 0x9C49, 0x9C4A, 0x9C4B, 0x9C4C, 0x9C4D, 0x9C4E, 0x9C4F,
+		0x9C50, 0x9C51, 0x9C52, 0x9C53, 0x9C54, 0x9C55, 0x9C56, 0x9C57, 0x9C58, 0x9C59, 0x9C5A,
+		0x9C5B, 0x9C5C, 0x9C5D, 0x9C5E, 0x9C5F, 0x9C60, 0x9C61, 0x9C62, 0x9C63, 0x9C64, 0x9C65,
+		0x9C66, 0x9C67, 0x9C68, 0x9C69, 0x9C6A, 0x9C6B, 0x9C6C, 0x9C6D, 0x9C6E, 0x9C6F, 0x9C70,
+		0x9C71, 0x9C72, 0x9C73, 0x9C74, 0x9C75, 0x9C76, 0x9C77, 0x9C78, 0x9C79, 0x9C7A, 0x9C7B,
+		0x9C7D, 0x9C7E, 0x9C80, 0x9C83, 0x9C84, 0x9C89, 0x9C8A, 0x9C8C, 0x9C8F, 0x9C93, 0x9C96,
+		0x9C97, 0x9C98, 0x9C99, 0x9C9D, 0x9CAA, 0x9CAC, 0x9CAF, 0x9CB9, 0x9CBE, 0x9CBF, 0x9CC0,
+		0x9CC1, 0x9CC2, 0x9CC8, 0x9CC9, 0x9CD1, 0x9CD2, 0x9CDA, 0x9CDB, 0x9CE0, 0x9CE1, 0x9CCC,
+		0x9CCD, 0x9CCE, 0x9CCF, 0x9CD0, 0x9CD3, 0x9CD4, 0x9CD5, 0x9CD7, 0x9CD8, 0x9CD9, 0x9CDC,
+		0x9CDD, 0x9CDF, 0x9CE2, 0x977C, 0x9785, 0x9791, 0x9792, 0x9794, 0x97AF, 0x97AB, 0x97A3,
+		0x97B2, 0x97B4, 0x9AB1, 0x9AB0, 0x9AB7, 0x9E58, 0x9AB6, 0x9ABA, 0x9ABC, 0x9AC1, 0x9AC0,
+		0x9AC5, 0x9AC2, 0x9ACB, 0x9ACC, 0x9AD1, 0x9B45, 0x9B43, 0x9B47, 0x9B49, 0x9B48, 0x9B4D,
+		0x9B51, 0x98E8, 0x990D, 0x992E, 0x9955, 0x9954, 0x9ADF, 0x9AE1, 0x9AE6, 0x9AEF, 0x9AEB,
+		0x9AFB, 0x9AED, 0x9AF9, 0x9B08, 0x9B0F, 0x9B13, 0x9B1F, 0x9B23, 0x9EBD, 0x9EBE, 0x7E3B,
+		0x9E82, 0x9E87, 0x9E88, 0x9E8B, 0x9E92, 0x93D6, 0x9E9D, 0x9E9F, 0x9EDB, 0x9EDC, 0x9EDD,
+		0x9EE0, 0x9EDF, 0x9EE2, 0x9EE9, 0x9EE7, 0x9EE5, 0x9EEA, 0x9EEF, 0x9F22, 0x9F2C, 0x9F2F,
+		0x9F39, 0x9F37, 0x9F3D, 0x9F3E, 0x9F44, 0x9CE3, 0x9CE4, 0x9CE5, 0x9CE6, 0x9CE7, 0x9CE8,
+		0x9CE9, 0x9CEA, 0x9CEB, 0x9CEC, 0x9CED, 0x9CEE, 0x9CEF, 0x9CF0, 0x9CF1, 0x9CF2, 0x9CF3,
+		0x9CF4, 0x9CF5, 0x9CF6, 0x9CF7, 0x9CF8, 0x9CF9, 0x9CFA, 0x9CFB, 0x9CFC, 0x9CFD, 0x9CFE,
+		0x9CFF, 0x9D00, 0x9D01, 0x9D02, 0x9D03, 0x9D04, 0x9D05, 0x9D06, 0x9D07, 0x9D08, 0x9D09,
+		0x9D0A, 0x9D0B, 0x9D0C, 0x9D0D, 0x9D0E, 0x9D0F, 0x9D10, 0x9D11, 0x9D12, 0x9D13, 0x9D14,
+		0x9D15, 0x9D16, 0x9D17, 0x9D18, 0x9D19, 0x9D1A, 0x9D1B, 0x9D1C, 0x9D1D, 0x9D1E, 0x9D1F,
+		0x9D20, 0x9D21, 0x9D22, 0x9D23, 0x9D24, 0x9D25, 0x9D26, 0x9D27, 0x9D28, 0x9D29, 0x9D2A,
+		0x9D2B, 0x9D2C, 0x9D2D, 0x9D2E, 0x9D2F, 0x9D30, 0x9D31, 0x9D32, 0x9D33, 0x9D34, 0x9D35,
+		0x9D36, 0x9D37, 0x9D38, 0x9D39, 0x9D3A, 0x9D3B, 0x9D3C, 0x9D3D, 0x9D3E, 0x9D3F, 0x9D40,
+		0x9D41, 0x9D42, 0xE234, 0xE235, 0xE236, 0xE237, 0xE238, 0xE239, 0xE23A, 0xE23B, 0xE23C,
+		0xE23D, 0xE23E, 0xE23F, 0xE240, 0xE241, 0xE242, 0xE243, 0xE244, 0xE245, 0xE246, 0xE247,
+		0xE248, 0xE249, 0xE24A, 0xE24B, 0xE24C, 0xE24D, 0xE24E, 0xE24F, 0xE250, 0xE251, 0xE252,
+		0xE253, 0xE254, 0xE255, 0xE256, 0xE257, 0xE258, 0xE259, 0xE25A, 0xE25B, 0xE25C, 0xE25D,
+		0xE25E, 0xE25F, 0xE260, 0xE261, 0xE262, 0xE263, 0xE264, 0xE265, 0xE266, 0xE267, 0xE268,
+		0xE269, 0xE26A, 0xE26B, 0xE26C, 0xE26D, 0xE26E, 0xE26F, 0xE270, 0xE271, 0xE272, 0xE273,
+		0xE274, 0xE275, 0xE276, 0xE277, 0xE278, 0xE279, 0xE27A, 0xE27B, 0xE27C, 0xE27D, 0xE27E,
+		0xE27F, 0xE280, 0xE281, 0xE282, 0xE283, 0xE284, 0xE285, 0xE286, 0xE287, 0xE288, 0xE289,
+		0xE28A, 0xE28B, 0xE28C, 0xE28D, 0xE28E, 0xE28F, 0xE290, 0xE291, 0x9D43, 0x9D44, 0x9D45,
+		0x9D46, 0x9D47, 0x9D48, 0x9D49, 0x9D4A, 0x9D4B, 0x9D4C, 0x9D4D, 0x9D4E, 0x9D4F, 0x9D50,
+		0x9D51, 0x9D52, 0x9D53, 0x9D54, 0x9D55, 0x9D56, 0x9D57, 0x9D58, 0x9D59, 0x9D5A, 0x9D5B,
+		0x9D5C, 0x9D5D, 0x9D5E, 0x9D5F, 0x9D60, 0x9D61, 0x9D62, 0x9D63, 0x9D64, 0x9D65, 0x9D66,
+		0x9D67, 0x9D68, 0x9D69, 0x9D6A, 0x9D6B, 0x9D6C, 0x9D6D, 0x9D6E, 0x9D6F, 0x9D70, 0x9D71,
+		0x9D72, 0x9D73, 0x9D74, 0x9D75, 0x9D76, 0x9D77, 0x9D78, 0x9D79, 0x9D7A, 0x9D7B, 0x9D7C,
+		0x9D7D, 0x9D7E, 0x9D7F, 0x9D80, 0x9D81, 0x9D82, 0x9D83, 0x9D84, 0x9D85, 0x9D86, 0x9D87,
+		0x9D88, 0x9D89, 0x9D8A, 0x9D8B, 0x9D8C, 0x9D8D, 0x9D8E, 0x9D8F, 0x9D90, 0x9D91, 0x9D92,
+		0x9D93, 0x9D94, 0x9D95, 0x9D96, 0x9D97, 0x9D98, 0x9D99, 0x9D9A, 0x9D9B, 0x9D9C, 0x9D9D,
+		0x9D9E, 0x9D9F, 0x9DA0, 0x9DA1, 0x9DA2, 0xE292, 0xE293, 0xE294, 0xE295, 0xE296, 0xE297,
+		0xE298, 0xE299, 0xE29A, 0xE29B, 0xE29C, 0xE29D, 0xE29E, 0xE29F, 0xE2A0, 0xE2A1, 0xE2A2,
+		0xE2A3, 0xE2A4, 0xE2A5, 0xE2A6, 0xE2A7, 0xE2A8, 0xE2A9, 0xE2AA, 0xE2AB, 0xE2AC, 0xE2AD,
+		0xE2AE, 0xE2AF, 0xE2B0, 0xE2B1, 0xE2B2, 0xE2B3, 0xE2B4, 0xE2B5, 0xE2B6, 0xE2B7, 0xE2B8,
+		0xE2B9, 0xE2BA, 0xE2BB, 0xE2BC, 0xE2BD, 0xE2BE, 0xE2BF, 0xE2C0, 0xE2C1, 0xE2C2, 0xE2C3,
+		0xE2C4, 0xE2C5, 0xE2C6, 0xE2C7, 0xE2C8, 0xE2C9, 0xE2CA, 0xE2CB, 0xE2CC, 0xE2CD, 0xE2CE,
+		0xE2CF, 0xE2D0, 0xE2D1, 0xE2D2, 0xE2D3, 0xE2D4, 0xE2D5, 0xE2D6, 0xE2D7, 0xE2D8, 0xE2D9,
+		0xE2DA, 0xE2DB, 0xE2DC, 0xE2DD, 0xE2DE, 0xE2DF, 0xE2E0, 0xE2E1, 0xE2E2, 0xE2E3, 0xE2E4,
+		0xE2E5, 0xE2E6, 0xE2E7, 0xE2E8, 0xE2E9, 0xE2EA, 0xE2EB, 0xE2EC, 0xE2ED, 0xE2EE, 0xE2EF,
+		0x9DA3, 0x9DA4, 0x9DA5, 0x9DA6, 0x9DA7, 0x9DA8, 0x9DA9, 0x9DAA, 0x9DAB, 0x9DAC, 0x9DAD,
+		0x9DAE, 0x9DAF, 0x9DB0, 0x9DB1, 0x9DB2, 0x9DB3, 0x9DB4, 0x9DB5, 0x9DB6, 0x9DB7, 0x9DB8,
+		0x9DB9, 0x9DBA, 0x9DBB, 0x9DBC, 0x9DBD, 0x9DBE, 0x9DBF, 0x9DC0, 0x9DC1, 0x9DC2, 0x9DC3,
+		0x9DC4, 0x9DC5, 0x9DC6, 0x9DC7, 0x9DC8, 0x9DC9, 0x9DCA, 0x9DCB, 0x9DCC, 0x9DCD, 0x9DCE,
+		0x9DCF, 0x9DD0, 0x9DD1, 0x9DD2, 0x9DD3, 0x9DD4, 0x9DD5, 0x9DD6, 0x9DD7, 0x9DD8, 0x9DD9,
+		0x9DDA, 0x9DDB, 0x9DDC, 0x9DDD, 0x9DDE, 0x9DDF, 0x9DE0, 0x9DE1, 0x9DE2, 0x9DE3, 0x9DE4,
+		0x9DE5, 0x9DE6, 0x9DE7, 0x9DE8, 0x9DE9, 0x9DEA, 0x9DEB, 0x9DEC, 0x9DED, 0x9DEE, 0x9DEF,
+		0x9DF0, 0x9DF1, 0x9DF2, 0x9DF3, 0x9DF4, 0x9DF5, 0x9DF6, 0x9DF7, 0x9DF8, 0x9DF9, 0x9DFA,
+		0x9DFB, 0x9DFC, 0x9DFD, 0x9DFE, 0x9DFF, 0x9E00, 0x9E01, 0x9E02, 0xE2F0, 0xE2F1, 0xE2F2,
+		0xE2F3, 0xE2F4, 0xE2F5, 0xE2F6, 0xE2F7, 0xE2F8, 0xE2F9, 0xE2FA, 0xE2FB, 0xE2FC, 0xE2FD,
+		0xE2FE, 0xE2FF, 0xE300, 0xE301, 0xE302, 0xE303, 0xE304, 0xE305, 0xE306, 0xE307, 0xE308,
+		0xE309, 0xE30A, 0xE30B, 0xE30C, 0xE30D, 0xE30E, 0xE30F, 0xE310, 0xE311, 0xE312, 0xE313,
+		0xE314, 0xE315, 0xE316, 0xE317, 0xE318, 0xE319, 0xE31A, 0xE31B, 0xE31C, 0xE31D, 0xE31E,
+		0xE31F, 0xE320, 0xE321, 0xE322, 0xE323, 0xE324, 0xE325, 0xE326, 0xE327, 0xE328, 0xE329,
+		0xE32A, 0xE32B, 0xE32C, 0xE32D, 0xE32E, 0xE32F, 0xE330, 0xE331, 0xE332, 0xE333, 0xE334,
+		0xE335, 0xE336, 0xE337, 0xE338, 0xE339, 0xE33A, 0xE33B, 0xE33C, 0xE33D, 0xE33E, 0xE33F,
+		0xE340, 0xE341, 0xE342, 0xE343, 0xE344, 0xE345, 0xE346, 0xE347, 0xE348, 0xE349, 0xE34A,
+		0xE34B, 0xE34C, 0xE34D, 0x9E03, 0x9E04, 0x9E05, 0x9E06, 0x9E07, 0x9E08, 0x9E09, 0x9E0A,
+		0x9E0B, 0x9E0C, 0x9E0D, 0x9E0E, 0x9E0F, 0x9E10, 0x9E11, 0x9E12, 0x9E13, 0x9E14, 0x9E15,
+		0x9E16, 0x9E17, 0x9E18, 0x9E19, 0x9E1A, 0x9E1B, 0x9E1C, 0x9E1D, 0x9E1E, 0x9E24, 0x9E27,
+		0x9E2E, 0x9E30, 0x9E34, 0x9E3B, 0x9E3C, 0x9E40, 0x9E4D, 0x9E50, 0x9E52, 0x9E53, 0x9E54,
+		0x9E56, 0x9E59, 0x9E5D, 0x9E5F, 0x9E60, 0x9E61, 0x9E62, 0x9E65, 0x9E6E, 0x9E6F, 0x9E72,
+		0x9E74, 0x9E75, 0x9E76, 0x9E77, 0x9E78, 0x9E79, 0x9E7A, 0x9E7B, 0x9E7C, 0x9E7D, 0x9E80,
+		0x9E81, 0x9E83, 0x9E84, 0x9E85, 0x9E86, 0x9E89, 0x9E8A, 0x9E8C, 0x9E8D, 0x9E8E, 0x9E8F,
+		0x9E90, 0x9E91, 0x9E94, 0x9E95, 0x9E96, 0x9E97, 0x9E98, 0x9E99, 0x9E9A, 0x9E9B, 0x9E9C,
+		0x9E9E, 0x9EA0, 0x9EA1, 0x9EA2, 0x9EA3, 0x9EA4, 0x9EA5, 0x9EA7, 0x9EA8, 0x9EA9, 0x9EAA,
+		0xE34E, 0xE34F, 0xE350, 0xE351, 0xE352, 0xE353, 0xE354, 0xE355, 0xE356, 0xE357, 0xE358,
+		0xE359, 0xE35A, 0xE35B, 0xE35C, 0xE35D, 0xE35E, 0xE35F, 0xE360, 0xE361, 0xE362, 0xE363,
+		0xE364, 0xE365, 0xE366, 0xE367, 0xE368, 0xE369, 0xE36A, 0xE36B, 0xE36C, 0xE36D, 0xE36E,
+		0xE36F, 0xE370, 0xE371, 0xE372, 0xE373, 0xE374, 0xE375, 0xE376, 0xE377, 0xE378, 0xE379,
+		0xE37A, 0xE37B, 0xE37C, 0xE37D, 0xE37E, 0xE37F, 0xE380, 0xE381, 0xE382, 0xE383, 0xE384,
+		0xE385, 0xE386, 0xE387, 0xE388, 0xE389, 0xE38A, 0xE38B, 0xE38C, 0xE38D, 0xE38E, 0xE38F,
+		0xE390, 0xE391, 0xE392, 0xE393, 0xE394, 0xE395, 0xE396, 0xE397, 0xE398, 0xE399, 0xE39A,
+		0xE39B, 0xE39C, 0xE39D, 0xE39E, 0xE39F, 0xE3A0, 0xE3A1, 0xE3A2, 0xE3A3, 0xE3A4, 0xE3A5,
+		0xE3A6, 0xE3A7, 0xE3A8, 0xE3A9, 0xE3AA, 0xE3AB, 0x9EAB, 0x9EAC, 0x9EAD, 0x9EAE, 0x9EAF,
+		0x9EB0, 0x9EB1, 0x9EB2, 0x9EB3, 0x9EB5, 0x9EB6, 0x9EB7, 0x9EB9, 0x9EBA, 0x9EBC, 0x9EBF,
+		0x9EC0, 0x9EC1, 0x9EC2, 0x9EC3, 0x9EC5, 0x9EC6, 0x9EC7, 0x9EC8, 0x9ECA, 0x9ECB, 0x9ECC,
+		0x9ED0, 0x9ED2, 0x9ED3, 0x9ED5, 0x9ED6, 0x9ED7, 0x9ED9, 0x9EDA, 0x9EDE, 0x9EE1, 0x9EE3,
+		0x9EE4, 0x9EE6, 0x9EE8, 0x9EEB, 0x9EEC, 0x9EED, 0x9EEE, 0x9EF0, 0x9EF1, 0x9EF2, 0x9EF3,
+		0x9EF4, 0x9EF5, 0x9EF6, 0x9EF7, 0x9EF8, 0x9EFA, 0x9EFD, 0x9EFF, 0x9F00, 0x9F01, 0x9F02,
+		0x9F03, 0x9F04, 0x9F05, 0x9F06, 0x9F07, 0x9F08, 0x9F09, 0x9F0A, 0x9F0C, 0x9F0F, 0x9F11,
+		0x9F12, 0x9F14, 0x9F15, 0x9F16, 0x9F18, 0x9F1A, 0x9F1B, 0x9F1C, 0x9F1D, 0x9F1E, 0x9F1F,
+		0x9F21, 0x9F23, 0x9F24, 0x9F25, 0x9F26, 0x9F27, 0x9F28, 0x9F29, 0x9F2A, 0x9F2B, 0x9F2D,
+		0x9F2E, 0x9F30, 0x9F31, 0xE3AC, 0xE3AD, 0xE3AE, 0xE3AF, 0xE3B0, 0xE3B1, 0xE3B2, 0xE3B3,
+		0xE3B4, 0xE3B5, 0xE3B6, 0xE3B7, 0xE3B8, 0xE3B9, 0xE3BA, 0xE3BB, 0xE3BC, 0xE3BD, 0xE3BE,
+		0xE3BF, 0xE3C0, 0xE3C1, 0xE3C2, 0xE3C3, 0xE3C4, 0xE3C5, 0xE3C6, 0xE3C7, 0xE3C8, 0xE3C9,
+		0xE3CA, 0xE3CB, 0xE3CC, 0xE3CD, 0xE3CE, 0xE3CF, 0xE3D0, 0xE3D1, 0xE3D2, 0xE3D3, 0xE3D4,
+		0xE3D5, 0xE3D6, 0xE3D7, 0xE3D8, 0xE3D9, 0xE3DA, 0xE3DB, 0xE3DC, 0xE3DD, 0xE3DE, 0xE3DF,
+		0xE3E0, 0xE3E1, 0xE3E2, 0xE3E3, 0xE3E4, 0xE3E5, 0xE3E6, 0xE3E7, 0xE3E8, 0xE3E9, 0xE3EA,
+		0xE3EB, 0xE3EC, 0xE3ED, 0xE3EE, 0xE3EF, 0xE3F0, 0xE3F1, 0xE3F2, 0xE3F3, 0xE3F4, 0xE3F5,
+		0xE3F6, 0xE3F7, 0xE3F8, 0xE3F9, 0xE3FA, 0xE3FB, 0xE3FC, 0xE3FD, 0xE3FE, 0xE3FF, 0xE400,
+		0xE401, 0xE402, 0xE403, 0xE404, 0xE405, 0xE406, 0xE407, 0xE408, 0xE409, 0x9F32, 0x9F33,
+		0x9F34, 0x9F35, 0x9F36, 0x9F38, 0x9F3A, 0x9F3C, 0x9F3F, 0x9F40, 0x9F41, 0x9F42, 0x9F43,
+		0x9F45, 0x9F46, 0x9F47, 0x9F48, 0x9F49, 0x9F4A, 0x9F4B, 0x9F4C, 0x9F4D, 0x9F4E, 0x9F4F,
+		0x9F52, 0x9F53, 0x9F54, 0x9F55, 0x9F56, 0x9F57, 0x9F58, 0x9F59, 0x9F5A, 0x9F5B, 0x9F5C,
+		0x9F5D, 0x9F5E, 0x9F5F, 0x9F60, 0x9F61, 0x9F62, 0x9F63, 0x9F64, 0x9F65, 0x9F66, 0x9F67,
+		0x9F68, 0x9F69, 0x9F6A, 0x9F6B, 0x9F6C, 0x9F6D, 0x9F6E, 0x9F6F, 0x9F70, 0x9F71, 0x9F72,
+		0x9F73, 0x9F74, 0x9F75, 0x9F76, 0x9F77, 0x9F78, 0x9F79, 0x9F7A, 0x9F7B, 0x9F7C, 0x9F7D,
+		0x9F7E, 0x9F81, 0x9F82, 0x9F8D, 0x9F8E, 0x9F8F, 0x9F90, 0x9F91, 0x9F92, 0x9F93, 0x9F94,
+		0x9F95, 0x9F96, 0x9F97, 0x9F98, 0x9F9C, 0x9F9D, 0x9F9E, 0x9FA1, 0x9FA2, 0x9FA3, 0x9FA4,
+		0x9FA5, 0xF92C, 0xF979, 0xF995, 0xF9E7, 0xF9F1, 0xE40A, 0xE40B, 0xE40C, 0xE40D, 0xE40E,
+		0xE40F, 0xE410, 0xE411, 0xE412, 0xE413, 0xE414, 0xE415, 0xE416, 0xE417, 0xE418, 0xE419,
+		0xE41A, 0xE41B, 0xE41C, 0xE41D, 0xE41E, 0xE41F, 0xE420, 0xE421, 0xE422, 0xE423, 0xE424,
+		0xE425, 0xE426, 0xE427, 0xE428, 0xE429, 0xE42A, 0xE42B, 0xE42C, 0xE42D, 0xE42E, 0xE42F,
+		0xE430, 0xE431, 0xE432, 0xE433, 0xE434, 0xE435, 0xE436, 0xE437, 0xE438, 0xE439, 0xE43A,
+		0xE43B, 0xE43C, 0xE43D, 0xE43E, 0xE43F, 0xE440, 0xE441, 0xE442, 0xE443, 0xE444, 0xE445,
+		0xE446, 0xE447, 0xE448, 0xE449, 0xE44A, 0xE44B, 0xE44C, 0xE44D, 0xE44E, 0xE44F, 0xE450,
+		0xE451, 0xE452, 0xE453, 0xE454, 0xE455, 0xE456, 0xE457, 0xE458, 0xE459, 0xE45A, 0xE45B,
+		0xE45C, 0xE45D, 0xE45E, 0xE45F, 0xE460, 0xE461, 0xE462, 0xE463, 0xE464, 0xE465, 0xE466,
+		0xE467, 0xFA0C, 0xFA0D, 0xFA0E, 0xFA0F, 0xFA11, 0xFA13, 0xFA14, 0xFA18, 0xFA1F, 0xFA20,
+		0xFA21, 0xFA23, 0xFA24, 0xFA27, 0xFA28, 0xFA29, 0x2E81, 0xE816, 0xE817, 0xE818, 0x2E84,
+		0x3473, 0x3447, 0x2E88, 0x2E8B, 0xE81E, 0x359E, 0x361A, 0x360E, 0x2E8C, 0x2E97, 0x396E,
+		0x3918, 0xE826, 0x39CF, 0x39DF, 0x3A73, 0x39D0, 0xE82B, 0xE82C, 0x3B4E, 0x3C6E, 0x3CE0,
+		0x2EA7, 0xE831, 0xE832, 0x2EAA, 0x4056, 0x415F, 0x2EAE, 0x4337, 0x2EB3, 0x2EB6, 0x2EB7,
+		0xE83B, 0x43B1, 0x43AC, 0x2EBB, 0x43DD, 0x44D6, 0x4661, 0x464C, 0xE843, 0x4723, 0x4729,
+		0x477C, 0x478D, 0x2ECA, 0x4947, 0x497A, 0x497D, 0x4982, 0x4983, 0x4985, 0x4986, 0x499F,
+		0x499B, 0x49B7, 0x49B6, 0xE854, 0xE855, 0x4CA3, 0x4C9F, 0x4CA0, 0x4CA1, 0x4C77, 0x4CA2,
+		0x4D13, 0x4D14, 0x4D15, 0x4D16, 0x4D17, 0x4D18, 0x4D19, 0x4DAE, 0xE864, 0xE468, 0xE469,
+		0xE46A, 0xE46B, 0xE46C, 0xE46D, 0xE46E, 0xE46F, 0xE470, 0xE471, 0xE472, 0xE473, 0xE474,
+		0xE475, 0xE476, 0xE477, 0xE478, 0xE479, 0xE47A, 0xE47B, 0xE47C, 0xE47D, 0xE47E, 0xE47F,
+		0xE480, 0xE481, 0xE482, 0xE483, 0xE484, 0xE485, 0xE486, 0xE487, 0xE488, 0xE489, 0xE48A,
+		0xE48B, 0xE48C, 0xE48D, 0xE48E, 0xE48F, 0xE490, 0xE491, 0xE492, 0xE493, 0xE494, 0xE495,
+		0xE496, 0xE497, 0xE498, 0xE499, 0xE49A, 0xE49B, 0xE49C, 0xE49D, 0xE49E, 0xE49F, 0xE4A0,
+		0xE4A1, 0xE4A2, 0xE4A3, 0xE4A4, 0xE4A5, 0xE4A6, 0xE4A7, 0xE4A8, 0xE4A9, 0xE4AA, 0xE4AB,
+		0xE4AC, 0xE4AD, 0xE4AE, 0xE4AF, 0xE4B0, 0xE4B1, 0xE4B2, 0xE4B3, 0xE4B4, 0xE4B5, 0xE4B6,
+		0xE4B7, 0xE4B8, 0xE4B9, 0xE4BA, 0xE4BB, 0xE4BC, 0xE4BD, 0xE4BE, 0xE4BF, 0xE4C0, 0xE4C1,
+		0xE4C2, 0xE4C3, 0xE4C4, 0xE4C5,
+	],
+	pointers: &[
+		6247, 6251, 6182, 6242, 6207, 6179, 6208, 7509, 7507, 7513, 7511, 7531, 7517, 7515, 7521,
+		7519, 6209, 7525, 7523, 7530, 7506, 7510, 7512, 7514, 7534, 7535, 7518, 7522, 7508, 7516,
+		7520, 7524, 7526, 7527, 7528, 7529, 7536, 7532, 7537, 6181, 6180, 7410, 7411, 7412, 7126,
+		7127, 7128, 7129, 7130, 7131, 7132, 7133, 7134, 7135, 7136, 7137, 7138, 7139, 7140, 7141,
+		7142, 7143, 7144, 7145, 7146, 7147, 7148, 7149, 7158, 7159, 7160, 7161, 7162, 7163, 7164,
+		7165, 7166, 7167, 7168, 7169, 7170, 7171, 7172, 7173, 7174, 7175, 7176, 7177, 7178, 7179,
+		7180, 7181, 7322, 7316, 7317, 7318, 7319, 7320, 7321, 7323, 7324, 7325, 7326, 7327, 7328,
+		7329, 7330, 7331, 7332, 7333, 7334, 7335, 7336, 7337, 7338, 7339, 7340, 7341, 7342, 7343,
+		7344, 7345, 7346, 7347, 7348, 7364, 7365, 7366, 7367, 7368, 7369, 7371, 7372, 7373, 7374,
+		7375, 7376, 7377, 7378, 7379, 7380, 7381, 7382, 7383, 7384, 7385, 7386, 7387, 7388, 7389,
+		7390, 7391, 7392, 7393, 7394, 7395, 7396, 7370, 7533, 7628, 7413, 6185, 7414, 6187, 6189,
+		6190, 6191, 6192, 7415, 6188, 6250, 6243, 6244, 7416, 6264, 6432, 6245, 7417, 7418, 6252,
+		7625, 6446, 6447, 6448, 6449, 6450, 6451, 6452, 6453, 6454, 6455, 6456, 6457, 6366, 6367,
+		6368, 6369, 6370, 6371, 6372, 6373, 6374, 6375, 6266, 6267, 6265, 6268, 7419, 7420, 7421,
+		7422, 6217, 6214, 6213, 7423, 6219, 6231, 6237, 7424, 6222, 7425, 6221, 6211, 6212, 6216,
+		6215, 6225, 6226, 6239, 6238, 6210, 6218, 6230, 6229, 6228, 7426, 6232, 6227, 6235, 6236,
+		7427, 7428, 6233, 6234, 7491, 6224, 6220, 7429, 6223, 6422, 6423, 6424, 6425, 6426, 6427,
+		6428, 6429, 6430, 6431, 6402, 6403, 6404, 6405, 6406, 6407, 6408, 6409, 6410, 6411, 6412,
+		6413, 6414, 6415, 6416, 6417, 6418, 6419, 6420, 6421, 6382, 6383, 6384, 6385, 6386, 6387,
+		6388, 6389, 6390, 6391, 6392, 6393, 6394, 6395, 6396, 6397, 6398, 6399, 6400, 6401, 7699,
+		7700, 7701, 7702, 7703, 7704, 7705, 7706, 7707, 7708, 7709, 7710, 7711, 7712, 7713, 7714,
+		7715, 7716, 7717, 7718, 7719, 7720, 7721, 7722, 7723, 7724, 7725, 7726, 7727, 7728, 7729,
+		7730, 7731, 7732, 7733, 7734, 7735, 7736, 7737, 7738, 7739, 7740, 7741, 7742, 7743, 7744,
+		7745, 7746, 7747, 7748, 7749, 7750, 7751, 7752, 7753, 7754, 7755, 7756, 7757, 7758, 7759,
+		7760, 7761, 7762, 7763, 7764, 7765, 7766, 7767, 7768, 7769, 7770, 7771, 7772, 7773, 7774,
+		7430, 7431, 7432, 7433, 7434, 7435, 7436, 7437, 7438, 7439, 7440, 7441, 7442, 7443, 7444,
+		7445, 7446, 7447, 7448, 7449, 7450, 7451, 7452, 7453, 7454, 7455, 7456, 7457, 7458, 7459,
+		7460, 7461, 7462, 7463, 7464, 7465, 7466, 7467, 7468, 7469, 7470, 7471, 7472, 7473, 7474,
+		7475, 7476, 7477, 7478, 7479, 7480, 7481, 7482, 7483, 6261, 6260, 6263, 6262, 7484, 7485,
+		6259, 6258, 6255, 6257, 6256, 7486, 7487, 7488, 7489, 6254, 6253, 7490, 6241, 6240, 23766,
+		23770, 23773, 23774, 23779, 23780, 23793, 23796, 23799, 23801, 23802, 23803, 23807, 23817,
+		7673, 7674, 7675, 7676, 7677, 7678, 7679, 7680, 7681, 7682, 7683, 7684, 6176, 6177, 6178,
+		6183, 6184, 7637, 7685, 6195, 6196, 6197, 6198, 6199, 6200, 6201, 6202, 6205, 6206, 7492,
+		6269, 6193, 6194, 6203, 6204, 7493, 7494, 7600, 7601, 7602, 7603, 7604, 7605, 7606, 7607,
+		7608, 7672, 6746, 6747, 6748, 6749, 6750, 6751, 6752, 6753, 6754, 6755, 6756, 6757, 6758,
+		6759, 6760, 6761, 6762, 6763, 6764, 6765, 6766, 6767, 6768, 6769, 6770, 6771, 6772, 6773,
+		6774, 6775, 6776, 6777, 6778, 6779, 6780, 6781, 6782, 6783, 6784, 6785, 6786, 6787, 6788,
+		6789, 6790, 6791, 6792, 6793, 6794, 6795, 6796, 6797, 6798, 6799, 6800, 6801, 6802, 6803,
+		6804, 6805, 6806, 6807, 6808, 6809, 6810, 6811, 6812, 6813, 6814, 6815, 6816, 6817, 6818,
+		6819, 6820, 6821, 6822, 6823, 6824, 6825, 6826, 6827, 6828, 7633, 7634, 7638, 7639, 6936,
+		6937, 6938, 6939, 6940, 6941, 6942, 6943, 6944, 6945, 6946, 6947, 6948, 6949, 6950, 6951,
+		6952, 6953, 6954, 6955, 6956, 6957, 6958, 6959, 6960, 6961, 6962, 6963, 6964, 6965, 6966,
+		6967, 6968, 6969, 6970, 6971, 6972, 6973, 6974, 6975, 6976, 6977, 6978, 6979, 6980, 6981,
+		6982, 6983, 6984, 6985, 6986, 6987, 6988, 6989, 6990, 6991, 6992, 6993, 6994, 6995, 6996,
+		6997, 6998, 6999, 7000, 7001, 7002, 7003, 7004, 7005, 7006, 7007, 7008, 7009, 7010, 7011,
+		7012, 7013, 7014, 7015, 7016, 7017, 7018, 7019, 7020, 7021, 7632, 7635, 7636, 7542, 7543,
+		7544, 7545, 7546, 7547, 7548, 7549, 7550, 7551, 7552, 7553, 7554, 7555, 7556, 7557, 7558,
+		7559, 7560, 7561, 7562, 7563, 7564, 7565, 7566, 7567, 7568, 7569, 7570, 7571, 7572, 7573,
+		7574, 7575, 7576, 7577, 7578, 6434, 6435, 6436, 6437, 6438, 6439, 6440, 6441, 6442, 6443,
+		7626, 7609, 7610, 7611, 7612, 7613, 7614, 7615, 7616, 7617, 7618, 7619, 7620, 23772, 23771,
+		23776, 23778, 23777, 23782, 23781, 23784, 23787, 23785, 23786, 23790, 23791, 23792, 23797,
+		23798, 23800, 23806, 23805, 23808, 23809, 23811, 23810, 23813, 23814, 23815, 23816, 23818,
+		23819, 23820, 23821, 23822, 23823, 23824, 23826, 23825, 23828, 23827, 23835, 23832, 23833,
+		23834, 23836, 23831, 23837, 23838, 23839, 23840, 23841, 23842, 23843, 23844, 15512, 10166,
+		0, 13268, 1, 2, 3, 14617, 16096, 13678, 13822, 14949, 16627, 9432, 15750, 4, 16629, 9678,
+		5, 16443, 13445, 16632, 13997, 6, 13475, 9306, 15506, 9837, 10176, 14186, 16634, 7, 8, 9,
+		10175, 10, 12284, 15342, 11, 13780, 16638, 12, 10631, 15325, 18775, 16293, 13, 14, 10420,
+		15, 9799, 16, 12312, 17, 16653, 18, 14607, 9979, 14735, 16332, 19, 12151, 11742, 16640, 20,
+		21, 22, 12868, 23, 11720, 24, 16642, 12655, 15554, 25, 16259, 14783, 16062, 11010, 10361,
+		12119, 26, 13230, 13065, 13434, 27, 10787, 28, 9638, 15535, 29, 30, 16656, 11722, 13287,
+		15503, 14931, 14986, 31, 32, 33, 34, 14038, 35, 36, 16657, 37, 38, 39, 40, 41, 42, 12527,
+		12495, 43, 13658, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 13407, 54, 55, 56, 57, 58, 59,
+		60, 12298, 61, 15747, 16144, 62, 13999, 10259, 16626, 15733, 11962, 63, 15903, 11120,
+		16658, 14794, 11699, 64, 65, 16633, 66, 15334, 15114, 67, 68, 69, 16654, 16894, 14621,
+		11901, 70, 11522, 10930, 15549, 9495, 10989, 12847, 71, 14992, 11694, 14539, 12288, 72, 73,
+		74, 13450, 16896, 75, 16899, 76, 77, 78, 79, 13628, 16698, 80, 81, 82, 15543, 13983, 13627,
+		16701, 16699, 16663, 11581, 13250, 9675, 83, 16700, 11576, 11568, 84, 13637, 9836, 85, 86,
+		12501, 87, 9459, 16483, 14008, 14246, 16099, 10567, 14956, 88, 89, 90, 16873, 16705, 13405,
+		91, 16703, 92, 9875, 12333, 15537, 93, 94, 16702, 95, 15524, 16704, 12674, 96, 97, 98,
+		15381, 99, 16302, 16707, 100, 16710, 11399, 11364, 101, 102, 103, 13631, 104, 10416, 105,
+		10389, 106, 13288, 107, 108, 109, 110, 111, 112, 113, 16713, 15518, 114, 115, 14798, 11336,
+		10447, 10360, 15166, 116, 117, 118, 119, 120, 16303, 15712, 11194, 11180, 16706, 121, 122,
+		13776, 14741, 9796, 123, 16708, 124, 13818, 16711, 12500, 16712, 125, 126, 14742, 16714,
+		127, 128, 129, 9419, 10767, 130, 16818, 131, 9098, 132, 12325, 133, 13851, 134, 14192, 135,
+		14193, 16819, 136, 137, 138, 139, 140, 10047, 141, 142, 9986, 143, 144, 145, 146, 147, 148,
+		14752, 10020, 16436, 16519, 15726, 149, 14414, 150, 10971, 151, 16817, 16875, 15739, 16718,
+		10435, 16522, 16719, 16715, 16816, 12892, 152, 153, 15697, 16709, 16876, 154, 16716, 155,
+		13084, 156, 157, 12113, 158, 159, 15375, 9084, 160, 161, 11354, 16821, 162, 16820, 163,
+		164, 165, 166, 16827, 16829, 167, 16826, 13990, 168, 169, 170, 16824, 16267, 171, 172, 173,
+		9657, 16823, 174, 12158, 175, 14009, 176, 16825, 177, 16822, 178, 179, 16831, 180, 181,
+		10182, 182, 183, 184, 10744, 185, 15517, 186, 187, 14947, 188, 189, 12479, 190, 11531,
+		16131, 9469, 13435, 11950, 16828, 191, 16830, 192, 14799, 11003, 193, 194, 195, 196, 197,
+		13449, 198, 199, 200, 201, 202, 203, 204, 205, 206, 9282, 207, 208, 209, 9842, 10242,
+		16835, 210, 211, 212, 213, 11876, 214, 215, 216, 16877, 13441, 12159, 16839, 217, 218, 219,
+		220, 221, 14210, 10448, 222, 16836, 223, 16838, 9218, 15740, 16840, 224, 15142, 225, 16837,
+		226, 227, 16832, 228, 16833, 12264, 16834, 229, 230, 11388, 15167, 10554, 231, 11752, 232,
+		16844, 233, 234, 235, 236, 16841, 237, 9044, 238, 239, 240, 16849, 241, 242, 243, 244, 245,
+		246, 247, 248, 249, 250, 251, 252, 253, 16851, 9237, 254, 16846, 255, 256, 10000, 257,
+		11768, 258, 259, 260, 14385, 11007, 15533, 261, 16850, 262, 263, 11567, 264, 9606, 265,
+		266, 267, 16852, 11760, 268, 16853, 16842, 12888, 269, 16845, 16848, 16847, 270, 271, 272,
+		273, 274, 275, 276, 277, 278, 279, 280, 16069, 281, 16266, 282, 13462, 283, 284, 285, 286,
+		16855, 287, 288, 289, 11362, 16857, 290, 291, 292, 16843, 293, 16858, 13216, 294, 295, 296,
+		297, 298, 16856, 299, 300, 301, 302, 16521, 303, 14538, 304, 305, 306, 307, 308, 309, 310,
+		311, 11496, 312, 313, 314, 315, 316, 317, 16859, 318, 319, 320, 321, 322, 323, 324, 325,
+		326, 13043, 14556, 327, 328, 329, 16860, 330, 331, 16854, 9600, 11969, 332, 333, 334, 335,
+		10566, 336, 337, 12157, 338, 339, 340, 341, 9113, 342, 343, 344, 345, 346, 347, 348, 349,
+		350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 9871, 363, 16861, 364,
+		16862, 9787, 16863, 365, 366, 9848, 367, 368, 369, 370, 371, 9058, 372, 373, 374, 375, 376,
+		377, 378, 16864, 13796, 379, 380, 381, 382, 383, 384, 385, 386, 387, 388, 389, 390, 391,
+		392, 393, 394, 395, 396, 397, 14996, 398, 399, 400, 401, 402, 403, 16865, 404, 405, 406,
+		12292, 407, 408, 409, 410, 411, 412, 413, 414, 415, 416, 417, 16869, 13789, 418, 419, 420,
+		421, 16868, 16867, 16870, 422, 423, 424, 425, 14214, 426, 11504, 427, 428, 429, 430, 431,
+		13212, 432, 433, 434, 435, 436, 437, 438, 439, 440, 441, 16866, 16871, 442, 443, 444,
+		16872, 445, 446, 447, 448, 449, 450, 13654, 451, 452, 453, 454, 455, 456, 457, 458, 459,
+		460, 461, 462, 463, 464, 12125, 465, 466, 467, 468, 469, 470, 471, 472, 473, 474, 475, 476,
+		477, 478, 479, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489, 490, 491, 492, 493, 10254,
+		16628, 15907, 494, 15875, 15159, 9663, 16110, 495, 14955, 10801, 496, 11918, 497, 12701,
+		498, 499, 500, 10215, 501, 502, 14570, 16893, 16895, 503, 504, 505, 9994, 506, 10186, 507,
+		508, 509, 510, 511, 11689, 512, 513, 13660, 514, 515, 13596, 516, 517, 9068, 10746, 12344,
+		16881, 518, 12093, 10753, 519, 10791, 15148, 9303, 13273, 11748, 10043, 16475, 520, 15383,
+		11375, 14027, 521, 522, 11334, 16885, 16696, 523, 524, 12883, 525, 526, 10597, 13614, 527,
+		528, 9470, 15922, 529, 530, 531, 532, 12651, 533, 534, 12700, 17007, 13648, 535, 15125,
+		536, 11777, 13024, 537, 538, 539, 10793, 540, 17008, 541, 15874, 17009, 542, 543, 544, 545,
+		546, 16906, 10177, 547, 548, 10430, 9304, 16907, 9664, 11771, 549, 11961, 15502, 12133,
+		550, 551, 552, 10184, 16909, 16908, 553, 554, 11712, 555, 556, 557, 13269, 558, 16463,
+		17006, 559, 12280, 560, 10057, 12327, 561, 562, 11390, 563, 9838, 564, 565, 566, 567, 568,
+		569, 570, 571, 572, 12316, 573, 12918, 574, 575, 11329, 10373, 576, 577, 10434, 578, 579,
+		580, 581, 582, 583, 16891, 584, 13235, 585, 11888, 11158, 586, 587, 10017, 588, 17236,
+		15160, 589, 14560, 9053, 9681, 11202, 17237, 10940, 590, 15933, 9997, 10058, 16679, 13633,
+		591, 592, 10409, 13443, 16680, 593, 11890, 594, 595, 17222, 16681, 596, 597, 15149, 11129,
+		598, 599, 600, 20265, 12303, 12340, 15947, 10598, 9805, 601, 9680, 602, 603, 13805, 604,
+		605, 606, 13063, 607, 608, 609, 13072, 12156, 610, 9295, 611, 16682, 10781, 612, 10004,
+		613, 614, 16683, 615, 616, 16283, 14157, 13600, 13793, 9829, 11919, 617, 10819, 618, 16684,
+		16685, 10236, 11338, 14419, 619, 620, 621, 622, 623, 624, 15002, 625, 16686, 13411, 626,
+		627, 10783, 11498, 628, 629, 14407, 630, 13247, 631, 632, 633, 634, 635, 16689, 636, 16687,
+		637, 638, 16688, 639, 640, 641, 9119, 642, 11756, 643, 13968, 11389, 644, 645, 646, 647,
+		10562, 648, 649, 10623, 650, 651, 652, 653, 654, 655, 656, 657, 658, 659, 16691, 660,
+		11538, 661, 16693, 16692, 662, 663, 664, 665, 666, 13107, 667, 668, 669, 670, 671, 672,
+		673, 16694, 674, 675, 16695, 676, 677, 678, 679, 680, 681, 682, 12261, 683, 13601, 9101,
+		10741, 11356, 14806, 17224, 12306, 684, 685, 686, 687, 10180, 16335, 13027, 11552, 17225,
+		17226, 688, 689, 690, 12153, 11687, 12110, 691, 692, 693, 694, 695, 696, 697, 698, 699,
+		700, 17227, 14003, 701, 702, 703, 9415, 704, 705, 706, 15709, 707, 12702, 708, 15306, 709,
+		710, 711, 712, 17229, 713, 12118, 714, 715, 716, 17230, 717, 11892, 718, 719, 720, 721,
+		722, 723, 12853, 724, 725, 726, 727, 13453, 728, 729, 730, 731, 732, 733, 734, 735, 736,
+		737, 738, 17231, 739, 740, 741, 742, 743, 744, 745, 746, 16887, 13830, 747, 748, 749,
+		10755, 14805, 15905, 750, 751, 752, 753, 9117, 9835, 754, 15162, 755, 756, 757, 758, 16888,
+		759, 17808, 16890, 760, 761, 762, 763, 16641, 11130, 9232, 764, 9650, 16671, 765, 766,
+		15914, 767, 768, 11514, 11954, 769, 14942, 770, 771, 16673, 772, 773, 774, 10398, 775, 776,
+		777, 16674, 778, 779, 780, 781, 782, 783, 784, 785, 786, 787, 13210, 13483, 15514, 788,
+		789, 16675, 12893, 790, 13979, 791, 13402, 792, 16631, 793, 13868, 14796, 11175, 9100, 794,
+		795, 796, 11125, 15118, 797, 9231, 16503, 16466, 798, 9980, 12529, 12872, 799, 800, 9414,
+		801, 9428, 802, 9284, 17973, 16083, 11883, 12457, 16678, 12462, 803, 16677, 14777, 804,
+		17069, 805, 14757, 806, 807, 16645, 12649, 15676, 14728, 808, 11325, 13605, 12494, 809,
+		11762, 15127, 810, 17070, 811, 812, 813, 814, 13463, 815, 816, 9602, 817, 10247, 14437,
+		12155, 818, 819, 12152, 820, 15320, 15360, 16664, 821, 822, 823, 824, 825, 826, 827, 9467,
+		828, 829, 12134, 830, 11006, 831, 832, 16665, 833, 15878, 834, 835, 14980, 16666, 836,
+		16667, 14950, 837, 9683, 11724, 838, 839, 840, 841, 16668, 842, 843, 844, 845, 846, 847,
+		848, 17239, 849, 850, 851, 852, 13590, 853, 854, 855, 14971, 856, 13679, 9451, 857, 858,
+		859, 860, 861, 15729, 9475, 11321, 15724, 14166, 10375, 862, 863, 864, 10357, 865, 866,
+		14034, 867, 13586, 14025, 9283, 15185, 868, 13064, 869, 870, 871, 17232, 10069, 872, 873,
+		11933, 10772, 11753, 12332, 874, 17977, 17976, 16272, 11543, 16112, 9065, 10168, 11916,
+		14353, 17974, 13988, 15725, 875, 16672, 15507, 10962, 14185, 14375, 876, 17978, 10055,
+		17975, 877, 878, 879, 15760, 880, 9647, 10632, 881, 17981, 882, 10972, 11315, 10060, 883,
+		14547, 12726, 11008, 12149, 14569, 14997, 17979, 14952, 884, 12477, 17980, 12525, 885, 886,
+		887, 11778, 888, 12318, 14579, 15572, 10400, 17988, 889, 17991, 890, 891, 10436, 9066,
+		10219, 10407, 892, 10937, 14438, 11927, 14172, 13289, 893, 16253, 17992, 894, 14791, 9614,
+		895, 896, 14817, 9806, 897, 14764, 11005, 898, 14790, 899, 15324, 900, 901, 17987, 902,
+		903, 9869, 904, 9637, 905, 10615, 17982, 906, 907, 908, 909, 12862, 910, 17983, 17984,
+		17985, 13042, 17986, 17989, 15882, 17990, 911, 13421, 14781, 912, 913, 914, 915, 916,
+		12881, 917, 17997, 918, 18002, 919, 16305, 920, 921, 922, 923, 924, 925, 926, 927, 17996,
+		18013, 14747, 928, 10964, 18001, 17995, 13077, 929, 930, 13850, 11009, 12727, 931, 932,
+		11740, 933, 17993, 934, 18000, 935, 13071, 936, 937, 938, 939, 16061, 10970, 940, 11729,
+		15704, 10577, 941, 16313, 942, 17994, 10765, 11882, 943, 944, 12347, 17998, 17999, 945,
+		18003, 946, 947, 948, 949, 950, 18014, 18024, 951, 18010, 18009, 16476, 18022, 18023,
+		19170, 15492, 18005, 952, 11884, 953, 15924, 954, 11915, 18007, 955, 956, 957, 14959, 958,
+		959, 18016, 960, 15337, 961, 18017, 9032, 13228, 18006, 962, 10994, 963, 10228, 14594,
+		10829, 15918, 964, 965, 18018, 14991, 9030, 18026, 18004, 15333, 18008, 18011, 18012,
+		18015, 966, 11124, 967, 18019, 18020, 968, 18021, 18025, 18027, 15695, 969, 970, 971, 972,
+		973, 10616, 13037, 18029, 13833, 12263, 12861, 974, 975, 11937, 15003, 976, 977, 978,
+		16115, 18033, 979, 980, 981, 982, 983, 984, 9429, 985, 10988, 18031, 986, 17228, 987,
+		15363, 988, 989, 990, 991, 14239, 9814, 992, 9031, 993, 994, 995, 996, 997, 18036, 14383,
+		18037, 998, 999, 18032, 1000, 1001, 1002, 1003, 1004, 1005, 18028, 1006, 1007, 1008, 1009,
+		18030, 1010, 18034, 18035, 11144, 1011, 1012, 18038, 1013, 1014, 18039, 1015, 11118, 1016,
+		14024, 14733, 18152, 9605, 1017, 18151, 1018, 1019, 1020, 18146, 1021, 1022, 1023, 1024,
+		18049, 1025, 14592, 18047, 1026, 18045, 1027, 11923, 16471, 1028, 13819, 1029, 1030, 18043,
+		9026, 1031, 1032, 1033, 1034, 1035, 18048, 1036, 1037, 1038, 1039, 18046, 18147, 1040,
+		1041, 1042, 1043, 1044, 18153, 1045, 1046, 1047, 1048, 10395, 1049, 1050, 13206, 13797,
+		12083, 18040, 1051, 1052, 13045, 1053, 16661, 18044, 12911, 1054, 1055, 1056, 1057, 1058,
+		1059, 18148, 18149, 18150, 15110, 1060, 1061, 18165, 14413, 1062, 18162, 1063, 11881,
+		18160, 14750, 18156, 13813, 1064, 1065, 12079, 18159, 11002, 10941, 18154, 1066, 1067,
+		1068, 18041, 1069, 18164, 1070, 1071, 18169, 1072, 1073, 1074, 9798, 18170, 1075, 1076,
+		14933, 10965, 1077, 18161, 1078, 1079, 1080, 1081, 1082, 1083, 1084, 15195, 1085, 1086,
+		1087, 1088, 1089, 1090, 1091, 1092, 1093, 18157, 1094, 15956, 1095, 18042, 1096, 13086,
+		1097, 18158, 1098, 15762, 1099, 18167, 18168, 1100, 1101, 1102, 1103, 1104, 18181, 15170,
+		1105, 1106, 1107, 18173, 1108, 1109, 18186, 18187, 1110, 1111, 1112, 18175, 18155, 13779,
+		18178, 1113, 18163, 1114, 1115, 1116, 1117, 1118, 14005, 18180, 1119, 18166, 1120, 14768,
+		1121, 14190, 18190, 18183, 18179, 1122, 18188, 1123, 18171, 18176, 18177, 1124, 1125,
+		18182, 1126, 1127, 18184, 18185, 1128, 18189, 1129, 18172, 1130, 1131, 1132, 1133, 1134,
+		14207, 18198, 1135, 18199, 18195, 1136, 1137, 1138, 1139, 1140, 1141, 18193, 11351, 1142,
+		1143, 18194, 1144, 10579, 16662, 1145, 1146, 1147, 1148, 1149, 1150, 1151, 1152, 15179,
+		1153, 1154, 12524, 1155, 1156, 18192, 18174, 1157, 1158, 1159, 18197, 18196, 1160, 1161,
+		18200, 1162, 1163, 1164, 1165, 18205, 18201, 1166, 1167, 1168, 16331, 9611, 1169, 16511,
+		1170, 14182, 1171, 1172, 18203, 1173, 14816, 1174, 1175, 1176, 10982, 1177, 1178, 1179,
+		1180, 1181, 1182, 1183, 1184, 1185, 1186, 1187, 1188, 18210, 18206, 15498, 1189, 1190,
+		1191, 1192, 1193, 18211, 1194, 1195, 18204, 18202, 18208, 1196, 1197, 18209, 1198, 1199,
+		1200, 1201, 1202, 18207, 1203, 18213, 1204, 1205, 1206, 13292, 16637, 15940, 18215, 14006,
+		1207, 1208, 1209, 1210, 18214, 1211, 1212, 1213, 1214, 10578, 1215, 1216, 1217, 1218,
+		18216, 18217, 1219, 1220, 1221, 1222, 1223, 1224, 1225, 1226, 18218, 18212, 1227, 1228,
+		1229, 1230, 1231, 1232, 1233, 10956, 14416, 1234, 1235, 1236, 18219, 1237, 1238, 1239,
+		1240, 1241, 1242, 1243, 1244, 1245, 1246, 1247, 1248, 1249, 1250, 1251, 15004, 1252, 1253,
+		1254, 1255, 1256, 1257, 1258, 1259, 1260, 1261, 1262, 18220, 1263, 1264, 1265, 1266, 1267,
+		1268, 1269, 13619, 1270, 1271, 1272, 1273, 11527, 1274, 1275, 1276, 1277, 1278, 1279, 1280,
+		1281, 1282, 1283, 1284, 1285, 1286, 12875, 1287, 1288, 1289, 1290, 1291, 1292, 1293, 1294,
+		1295, 18221, 1296, 1297, 18222, 1298, 1299, 13479, 14191, 1300, 18223, 11171, 16647, 15567,
+		18224, 14572, 1301, 10223, 1302, 1303, 1304, 1305, 1306, 1307, 18226, 1308, 15881, 1309,
+		1310, 12072, 9834, 1311, 1312, 14732, 18225, 1313, 1314, 1315, 18227, 10779, 1316, 1317,
+		10825, 14563, 18228, 1318, 1319, 1320, 13257, 18229, 1321, 15883, 1322, 13591, 18231,
+		18230, 1323, 1324, 1325, 1326, 1327, 1328, 1329, 1330, 1331, 1332, 1333, 1334, 1335, 1336,
+		1337, 1338, 1339, 18232, 1340, 1341, 14568, 1342, 1343, 1344, 13970, 1345, 1346, 1347,
+		1348, 15923, 17250, 17252, 1349, 17251, 10806, 17255, 17256, 10031, 1350, 1351, 17253,
+		1352, 1353, 1354, 1355, 1356, 17254, 9596, 17258, 1357, 1358, 11203, 1359, 16268, 1360,
+		17259, 1361, 1362, 1363, 1364, 11774, 1365, 1366, 10382, 1367, 17243, 14359, 11893, 11136,
+		16523, 11926, 1368, 1369, 1370, 1371, 1372, 11948, 1373, 1374, 11370, 14364, 17257, 9076,
+		14800, 10411, 16460, 13239, 1375, 1376, 12069, 1377, 14370, 1378, 17266, 17260, 13231,
+		17262, 1379, 17267, 1380, 13102, 1381, 1382, 1383, 17269, 1384, 1385, 17268, 11907, 1386,
+		1387, 1388, 17265, 17264, 1389, 1390, 1391, 1392, 1393, 9810, 12077, 12447, 17261, 17263,
+		1394, 1395, 1396, 1397, 15150, 17272, 1398, 1399, 1400, 1401, 1402, 12126, 17277, 1403,
+		1404, 1405, 1406, 1407, 1408, 1409, 10231, 1410, 1411, 1412, 1413, 17278, 17245, 10759,
+		15876, 17271, 1414, 11924, 17275, 1415, 17244, 1416, 10045, 1417, 17270, 11944, 1418, 1419,
+		1420, 17273, 1421, 17276, 1422, 1423, 1424, 17284, 1425, 1426, 1427, 1428, 1429, 1430,
+		1431, 1432, 1433, 10736, 9028, 1434, 1435, 1436, 1437, 1438, 1439, 1440, 12526, 1441, 1442,
+		9634, 17274, 1443, 1444, 17283, 1445, 13255, 17279, 1446, 1447, 17280, 17282, 17281, 1448,
+		1449, 17289, 1450, 15757, 9431, 1451, 1452, 1453, 17288, 1454, 1455, 1456, 1457, 1458,
+		1459, 1460, 1461, 17389, 1462, 17286, 1463, 1464, 1465, 1466, 17285, 1467, 1468, 1469,
+		17287, 13079, 11204, 1470, 1471, 17388, 1472, 1473, 17390, 1474, 14380, 1475, 1476, 1477,
+		10214, 17607, 1478, 1479, 1480, 17386, 1481, 17387, 1482, 1483, 1484, 13416, 1485, 1486,
+		1487, 10238, 1488, 1489, 1490, 17392, 1491, 1492, 1493, 1494, 17391, 1495, 17394, 9219,
+		1496, 1497, 10019, 1498, 1499, 1500, 1501, 1502, 11891, 1503, 1504, 1505, 1506, 1507,
+		15358, 1508, 1509, 1510, 1511, 10199, 1512, 1513, 1514, 1515, 1516, 1517, 1518, 1519, 1520,
+		1521, 1522, 1523, 1524, 1525, 17393, 1526, 1527, 1528, 1529, 1530, 1531, 1532, 14245,
+		20318, 1533, 1534, 1535, 14215, 1536, 1537, 14249, 1538, 1539, 1540, 14378, 1541, 1542,
+		1543, 1544, 1545, 13676, 1546, 1547, 1548, 1549, 1550, 1551, 17395, 1552, 1553, 1554, 1555,
+		1556, 14423, 17396, 1557, 1558, 1559, 1560, 1561, 1562, 1563, 1564, 1565, 1566, 1567, 1568,
+		1569, 1570, 1571, 1572, 1573, 17246, 1574, 17400, 17397, 1575, 11704, 1576, 14056, 1577,
+		1578, 1579, 17398, 1580, 1581, 1582, 1583, 1584, 1585, 1586, 1587, 13817, 12850, 1588,
+		1589, 1590, 1591, 1592, 13424, 17399, 1593, 1594, 1595, 15951, 15175, 1596, 1597, 1598,
+		1599, 1600, 1601, 1602, 1603, 12836, 10218, 1604, 1605, 1606, 1607, 1608, 1609, 1610, 1611,
+		1612, 1613, 1614, 1615, 1616, 1617, 1618, 1619, 1620, 1621, 17247, 1622, 1623, 1624, 1625,
+		9273, 1626, 1627, 1628, 17248, 1629, 1630, 1631, 1632, 1633, 1634, 1635, 1636, 1637, 1638,
+		1639, 17249, 1640, 1641, 1642, 10955, 1643, 1644, 1645, 1646, 1647, 1648, 1649, 1650, 1651,
+		1652, 1653, 1654, 1655, 1656, 13617, 1657, 1658, 1659, 1660, 1661, 1662, 13996, 13626,
+		1663, 16454, 1664, 13864, 1665, 1666, 11914, 1667, 1668, 11013, 1669, 1670, 15513, 1671,
+		1672, 1673, 1674, 1675, 1676, 1677, 1678, 18551, 1679, 9791, 1680, 1681, 9239, 1682, 1683,
+		1684, 1685, 1686, 10565, 1687, 14951, 1688, 1689, 1690, 1691, 16886, 14921, 14601, 1692,
+		1693, 16892, 10229, 1694, 15510, 1695, 1696, 10762, 1697, 1698, 1699, 1700, 18550, 18548,
+		1701, 9868, 1702, 14421, 14356, 10437, 1703, 16643, 15370, 10952, 1704, 13972, 1705, 1706,
+		14558, 1707, 1708, 15521, 11943, 11353, 10230, 1709, 17802, 1710, 1711, 1712, 1713, 17803,
+		17223, 1714, 15352, 1715, 1716, 13275, 12871, 10433, 1717, 10415, 1718, 1719, 11967, 16499,
+		1720, 13290, 1721, 1722, 9243, 17805, 11512, 14400, 17807, 1723, 17806, 1724, 1725, 1726,
+		1727, 1728, 10051, 1729, 13836, 1730, 1731, 9059, 1732, 1733, 1734, 1735, 1736, 1737, 1738,
+		1739, 1740, 1741, 1742, 1743, 1744, 13029, 13026, 1745, 12869, 1746, 11378, 14248, 1747,
+		1748, 1749, 10960, 1750, 1751, 1752, 19184, 13656, 19185, 14628, 1753, 16452, 10575, 12517,
+		1754, 13634, 1755, 1756, 19186, 1757, 1758, 1759, 1760, 10207, 11348, 1761, 1762, 15388,
+		19286, 1763, 12713, 1764, 1765, 1766, 1767, 19289, 1768, 1769, 1770, 1771, 19189, 19290,
+		14590, 1772, 1773, 10388, 19187, 19188, 19288, 1774, 1775, 12886, 19293, 1776, 1777, 19292,
+		1778, 1779, 1780, 1781, 1782, 1783, 12670, 1784, 13267, 1785, 1786, 19295, 1787, 1788,
+		1789, 1790, 1791, 1792, 1793, 12848, 1794, 1795, 1796, 19287, 13993, 1797, 1798, 1799,
+		1800, 11562, 10770, 19291, 15158, 14740, 1801, 1802, 19294, 19301, 1803, 15491, 1804,
+		11505, 19298, 1805, 1806, 1807, 1808, 1809, 19300, 1810, 12114, 1811, 1812, 15529, 1813,
+		1814, 1815, 11312, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1823, 1824, 1825, 1826, 1827,
+		19302, 1828, 15571, 1829, 1830, 1831, 16478, 1832, 14629, 1833, 14597, 12451, 19296, 19297,
+		11526, 19299, 19304, 1834, 1835, 19303, 1836, 1837, 1838, 1839, 19307, 1840, 19309, 1841,
+		1842, 1843, 1844, 12904, 1845, 1846, 1847, 12865, 1848, 1849, 11759, 13854, 1850, 1851,
+		19308, 1852, 10245, 1853, 1854, 1855, 12703, 1856, 1857, 1858, 1859, 1860, 1861, 1862,
+		15748, 19305, 1863, 19306, 1864, 13587, 1865, 1866, 1867, 1868, 1869, 19314, 1870, 1871,
+		1872, 19310, 1873, 1874, 1875, 1876, 1877, 13242, 1878, 1879, 14616, 19312, 1880, 1881,
+		1882, 1883, 1884, 1885, 1886, 1887, 1888, 1889, 19313, 1890, 1891, 1892, 1893, 11188, 1894,
+		1895, 1896, 1897, 1898, 1899, 1900, 19315, 1901, 1902, 1903, 1904, 19311, 1905, 1906,
+		12088, 1907, 1908, 1909, 1910, 1911, 1912, 1913, 1914, 1915, 15679, 19316, 13859, 19320,
+		1916, 1917, 19321, 1918, 1919, 1920, 1921, 15191, 1922, 1923, 1924, 1925, 1926, 1927, 1928,
+		1929, 1930, 1931, 1932, 1933, 1934, 1935, 1936, 1937, 1938, 1939, 12664, 1940, 1941, 1942,
+		1943, 1944, 1945, 1946, 12671, 19319, 1947, 1948, 1949, 1950, 1951, 1952, 1953, 1954, 1955,
+		1956, 1957, 1958, 1959, 1960, 19318, 1961, 1962, 1963, 1964, 1965, 1966, 1967, 19324,
+		14932, 1968, 20319, 1969, 1970, 19327, 1971, 1972, 1973, 1974, 1975, 19322, 1976, 1977,
+		11367, 13784, 1978, 1979, 1980, 1981, 1982, 1983, 11326, 1984, 1985, 14966, 1986, 1987,
+		1988, 1989, 1990, 19325, 1991, 19326, 1992, 19331, 1993, 19333, 1994, 1995, 1996, 19334,
+		1997, 1998, 1999, 19328, 10028, 2000, 19329, 2001, 2002, 19332, 2003, 2004, 12884, 2005,
+		19323, 2006, 2007, 2008, 2009, 2010, 19330, 2011, 2012, 2013, 2014, 2015, 2016, 2017, 2018,
+		2019, 2020, 2021, 2022, 2023, 2024, 2025, 2026, 2027, 2028, 2029, 2030, 2031, 2032, 2033,
+		19335, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2042, 2043, 2044, 2045, 19337,
+		19336, 2046, 2047, 2048, 2049, 2050, 2051, 2052, 2053, 2054, 2055, 2056, 2057, 2058, 2059,
+		2060, 2061, 2062, 2063, 2064, 2065, 2066, 2067, 2068, 2069, 2070, 2071, 19338, 2072, 16903,
+		2073, 2074, 19339, 2075, 2076, 2077, 2078, 2079, 2080, 2081, 2082, 19340, 2083, 2084, 2085,
+		2086, 2087, 2088, 2089, 2090, 2091, 2092, 2093, 2094, 2095, 2096, 2097, 16486, 19346, 2098,
+		19347, 11930, 15913, 2099, 16489, 9855, 14234, 19343, 16660, 16481, 15107, 2100, 12682,
+		2101, 2102, 19348, 11335, 10769, 19344, 15302, 2103, 2104, 10927, 12492, 2105, 16636, 2106,
+		2107, 2108, 14040, 19173, 2109, 19345, 2110, 10440, 2111, 2112, 2113, 2114, 13655, 2115,
+		2116, 12910, 2117, 2118, 19113, 12919, 2119, 14247, 19114, 16067, 2120, 15753, 14021, 9043,
+		2121, 14201, 14609, 2122, 2123, 10999, 2124, 2125, 2126, 19116, 2127, 19115, 2128, 16493,
+		10792, 16315, 10173, 14615, 15528, 9221, 13986, 2129, 9667, 13858, 11920, 15196, 14015,
+		19117, 11149, 2130, 2131, 2132, 14975, 10747, 2133, 2134, 2135, 2136, 15920, 2137, 2138,
+		10931, 15366, 15007, 11355, 2139, 19118, 13646, 2140, 2141, 2142, 11952, 9301, 14217, 2143,
+		2144, 11342, 2145, 11341, 15575, 12695, 11935, 2146, 2147, 2148, 2149, 10572, 2150, 2151,
+		2152, 12669, 2153, 10939, 15867, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 2161, 2162,
+		13457, 12840, 9481, 2163, 10784, 2164, 2165, 19122, 12295, 2166, 2167, 16070, 2168, 2169,
+		2170, 2171, 2172, 19123, 2173, 19125, 2174, 2175, 2176, 2177, 2178, 2179, 2180, 9856,
+		10217, 14189, 15311, 10003, 2181, 2182, 14022, 2183, 10421, 2184, 2185, 13843, 2186, 11506,
+		2187, 2188, 14755, 16515, 2189, 2190, 2191, 2192, 15106, 2193, 13832, 2194, 2195, 10256,
+		19341, 11371, 2196, 9625, 2197, 13823, 2198, 19342, 9597, 2199, 2200, 2201, 2202, 17809,
+		2203, 15715, 17810, 2204, 15487, 2205, 2206, 2207, 2208, 17811, 2209, 2210, 2211, 2212,
+		11730, 2213, 2214, 17812, 2215, 2216, 2217, 13977, 15577, 9658, 19169, 12890, 11686, 14743,
+		12907, 11739, 13213, 9472, 2218, 2219, 11736, 2220, 2221, 13487, 14420, 11571, 14786, 2222,
+		2223, 13991, 13238, 19171, 15133, 2224, 2225, 2226, 16080, 2227, 2228, 2229, 19172, 2230,
+		2231, 2232, 2233, 14049, 2234, 14567, 12482, 2235, 19174, 2236, 12481, 19175, 2237, 2238,
+		2239, 2240, 2241, 2242, 2243, 19183, 14580, 2244, 13804, 2245, 2246, 2247, 2248, 2249,
+		2250, 2251, 15542, 18343, 2252, 2253, 2254, 2255, 15751, 2256, 14229, 13286, 2257, 2258,
+		2259, 2260, 2261, 18347, 2262, 2263, 2264, 18342, 18344, 2265, 2266, 18345, 18350, 2267,
+		2268, 9482, 2269, 18346, 10603, 18348, 18349, 18351, 10001, 18352, 2270, 2271, 2272, 2273,
+		2274, 18354, 18359, 2275, 2276, 2277, 2278, 2279, 15345, 2280, 18357, 18356, 12330, 2281,
+		2282, 2283, 18358, 2284, 15897, 2285, 18353, 2286, 18361, 9047, 2287, 2288, 2289, 2290,
+		18355, 2291, 11964, 2292, 18360, 2293, 2294, 18362, 2295, 2296, 2297, 2298, 2299, 2300,
+		18365, 2301, 2302, 2303, 2304, 2305, 2306, 18363, 2307, 2308, 2309, 2310, 2311, 2312,
+		16282, 2313, 2314, 2315, 2316, 2317, 2318, 2319, 14946, 2320, 2321, 18364, 18366, 12490,
+		2322, 10240, 2323, 15763, 2324, 2325, 13440, 2326, 2327, 10424, 2328, 2329, 2330, 2331,
+		2332, 2333, 2334, 2335, 2336, 2337, 11779, 2338, 2339, 2340, 2341, 2342, 2343, 18367,
+		18368, 2344, 2345, 18374, 9666, 2346, 2347, 2348, 2349, 2350, 2351, 13278, 2352, 2353,
+		2354, 2355, 2356, 9847, 2357, 15329, 2358, 2359, 2360, 2361, 18375, 2362, 2363, 18373,
+		2364, 2365, 2366, 2367, 2368, 18372, 2369, 18370, 18369, 2370, 9247, 2371, 2372, 2373,
+		16079, 18371, 2374, 2375, 2376, 2377, 2378, 18378, 2379, 2380, 2381, 2382, 2383, 2384,
+		2385, 2386, 18379, 18377, 2387, 2388, 2389, 2390, 2391, 2392, 2393, 2394, 21085, 2395,
+		2396, 18386, 18385, 13417, 2397, 2398, 2399, 2400, 2401, 2402, 2403, 2404, 2405, 2406,
+		2407, 18376, 2408, 2409, 18381, 2410, 18383, 2411, 2412, 2413, 2414, 2415, 2416, 2417,
+		2418, 2419, 2420, 2421, 18387, 2422, 18384, 18380, 2423, 2424, 18382, 2425, 2426, 2427,
+		2428, 18388, 2429, 2430, 2431, 2432, 2433, 2434, 2435, 2436, 2437, 2438, 2439, 2440, 2441,
+		18389, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2449, 2450, 2451, 2452, 2453, 2454, 2455,
+		2456, 2457, 2458, 2459, 2460, 2461, 2462, 2463, 18390, 2464, 2465, 2466, 18391, 2467, 2468,
+		2469, 2470, 2471, 2472, 2473, 2474, 2475, 2476, 2477, 2478, 2479, 2480, 2481, 2482, 2483,
+		2484, 2485, 2486, 2487, 2488, 2489, 2490, 2491, 18393, 2492, 2493, 2494, 2495, 2496, 2497,
+		2498, 2499, 2500, 2501, 2502, 2503, 2504, 18394, 2505, 2506, 2507, 2508, 2509, 2510, 2511,
+		14726, 2512, 2513, 2514, 2515, 2516, 2517, 2518, 2519, 2520, 2521, 2522, 2523, 2524, 19536,
+		2525, 9793, 16306, 2526, 2527, 15313, 9613, 2528, 2529, 10739, 16518, 13436, 11747, 10749,
+		2530, 14780, 2531, 2532, 9483, 17242, 2533, 11331, 15534, 14195, 9070, 2534, 2535, 14994,
+		2536, 2537, 2538, 2539, 2540, 16882, 11572, 2541, 2542, 9265, 14013, 9433, 2543, 14162,
+		10366, 2544, 13971, 2545, 2546, 2547, 14918, 2548, 2549, 18233, 16097, 18236, 2550, 2551,
+		18235, 13048, 14436, 2552, 12272, 18234, 16312, 9420, 16281, 10034, 2553, 2554, 2555, 2556,
+		2557, 2558, 2559, 2560, 9873, 16246, 2561, 2562, 2563, 2564, 2565, 14930, 9104, 2566, 2567,
+		18237, 2568, 2569, 2570, 2571, 2572, 18336, 9598, 2573, 2574, 18238, 18239, 12652, 2575,
+		2576, 2577, 2578, 12696, 2579, 18337, 10444, 2580, 2581, 2582, 2583, 2584, 2585, 11162,
+		2586, 2587, 2588, 2589, 2590, 2591, 2592, 18338, 12852, 2593, 2594, 2595, 2596, 2597,
+		18339, 2598, 2599, 18340, 2600, 2601, 18341, 9802, 2602, 2603, 2604, 2605, 2606, 2607,
+		2608, 2609, 2610, 2611, 2612, 2613, 2614, 2615, 2616, 10586, 13234, 12899, 2617, 9407,
+		2618, 15155, 2619, 19534, 11150, 15730, 15711, 2620, 10802, 18572, 2621, 2622, 2623, 16450,
+		2624, 13471, 9266, 2625, 2626, 9803, 18574, 2627, 2628, 2629, 15187, 12459, 18573, 2630,
+		11941, 15681, 10030, 18575, 10049, 2631, 12712, 10638, 2632, 10559, 2633, 13066, 10402,
+		18577, 2634, 2635, 2636, 2637, 18576, 10205, 16524, 2638, 2639, 2640, 2641, 2642, 14540,
+		2643, 2644, 2645, 2646, 2647, 18581, 2648, 18579, 14057, 11896, 15700, 18578, 2649, 2650,
+		2651, 2652, 18580, 2653, 2654, 2655, 2656, 2657, 2658, 2659, 2660, 2661, 2662, 12269,
+		12105, 2663, 2664, 2665, 2666, 2667, 2668, 18584, 18583, 12075, 2669, 2670, 12301, 2671,
+		2672, 2673, 2674, 18585, 2675, 2676, 2677, 2678, 2679, 2680, 2681, 2682, 2683, 2684, 2685,
+		2686, 18586, 2687, 18587, 2688, 2689, 2690, 2691, 2692, 2693, 2694, 2695, 2696, 17235,
+		2697, 15346, 14537, 2698, 2699, 11503, 2700, 2701, 2702, 17800, 16630, 11885, 17240, 15561,
+		13295, 13025, 2703, 2704, 2705, 17801, 2706, 9270, 17969, 2707, 2708, 2709, 13994, 2710,
+		17972, 2711, 10748, 2712, 15578, 2713, 10550, 11000, 2714, 2715, 9653, 2716, 2717, 2718,
+		10035, 16092, 2719, 2720, 2721, 2722, 12689, 14965, 11116, 2723, 19178, 19177, 2724, 2725,
+		19179, 2726, 14603, 2727, 13670, 2728, 2729, 2730, 2731, 2732, 2733, 2734, 9990, 13426,
+		2735, 19181, 2736, 2737, 2738, 20441, 2739, 2740, 2741, 2742, 2743, 2744, 2745, 2746, 2747,
+		2748, 2749, 2750, 2751, 2752, 2753, 19165, 2754, 10808, 9992, 2755, 12473, 19167, 19166,
+		19168, 2756, 2757, 2758, 2759, 15530, 2760, 2761, 2762, 18409, 15151, 2763, 14549, 2764,
+		15364, 2765, 2766, 9447, 9290, 2767, 9297, 13092, 2768, 2769, 16090, 15691, 2770, 18395,
+		2771, 2772, 2773, 18396, 2774, 15544, 2775, 9620, 9258, 2776, 2777, 2778, 14624, 16142,
+		18397, 2779, 11707, 9878, 2780, 18398, 10985, 18399, 11133, 12486, 18400, 2781, 2782, 2783,
+		15181, 2784, 14564, 2785, 2786, 18401, 2787, 10010, 13054, 18402, 2788, 2789, 18403, 2790,
+		2791, 2792, 2793, 15764, 2794, 2795, 2796, 2797, 2798, 2799, 18404, 2800, 15308, 2801,
+		2802, 18405, 14727, 2803, 2804, 2805, 2806, 2807, 2808, 18406, 2809, 10009, 2810, 2811,
+		2812, 2813, 18407, 11168, 2814, 2815, 2816, 2817, 2818, 15141, 18589, 9271, 15553, 2819,
+		2820, 18590, 2821, 2822, 11346, 13629, 2823, 18592, 20519, 20518, 17970, 2824, 2825, 2826,
+		18591, 16275, 14627, 12641, 2827, 2828, 2829, 18750, 2830, 2831, 16295, 18596, 2832, 2833,
+		18597, 2834, 2835, 15714, 2836, 2837, 18601, 11951, 2838, 18602, 18594, 2839, 2840, 9627,
+		2841, 2842, 2843, 12903, 2844, 2845, 18603, 2846, 2847, 15140, 2848, 11011, 18598, 10417,
+		11134, 14357, 14198, 18593, 18595, 18599, 18600, 2849, 2850, 2851, 18612, 2852, 2853,
+		18609, 15950, 18608, 2854, 2855, 13028, 2856, 16145, 13049, 9437, 2857, 2858, 18604, 2859,
+		18607, 12270, 14183, 2860, 2861, 9976, 18614, 2862, 2863, 2864, 11322, 18606, 15157, 15890,
+		18610, 10789, 18611, 2865, 2866, 2867, 13446, 2868, 2869, 2870, 2871, 2872, 18605, 2873,
+		2874, 2875, 2876, 2877, 16495, 20616, 2878, 2879, 18613, 2880, 20620, 18619, 14014, 2881,
+		2882, 2883, 2884, 2885, 2886, 2887, 12276, 2888, 11163, 2889, 2890, 11929, 2891, 10992,
+		2892, 2893, 14156, 2894, 2895, 2896, 20621, 20618, 2897, 2898, 20617, 2899, 2900, 2901,
+		2902, 11169, 20622, 15189, 2903, 2904, 20619, 10987, 10252, 18716, 10183, 14426, 10742,
+		2905, 14917, 13396, 2906, 2907, 11925, 2908, 2909, 10246, 2910, 18615, 18616, 18618, 18617,
+		12878, 18717, 2911, 15708, 2912, 2913, 2914, 18722, 13431, 2915, 2916, 2917, 2918, 14919,
+		2919, 2920, 18724, 10948, 2921, 2922, 2923, 2924, 18723, 2925, 11173, 2926, 18718, 2927,
+		2928, 2929, 18719, 18725, 2930, 18721, 2931, 14807, 15713, 2932, 2933, 11143, 2934, 2935,
+		15900, 2936, 12915, 2937, 2938, 20623, 15197, 18720, 2939, 12720, 2940, 18728, 9230, 2941,
+		18733, 2942, 2943, 2944, 11339, 2945, 2946, 18727, 10006, 2947, 2948, 2949, 2950, 2951,
+		2952, 2953, 2954, 13468, 18731, 2955, 2956, 2957, 11695, 14614, 2958, 2959, 2960, 2961,
+		2962, 11198, 2963, 2964, 2965, 14417, 2966, 2967, 18730, 2968, 18732, 2969, 14922, 18729,
+		2970, 14734, 11176, 2971, 2972, 2973, 2974, 2975, 10050, 11754, 9455, 9640, 2976, 9240,
+		18726, 9454, 9987, 10798, 10237, 2977, 2978, 14990, 18738, 2979, 11159, 2980, 2981, 13624,
+		15147, 2982, 2983, 2984, 2985, 2986, 18739, 9673, 2987, 2988, 2989, 2990, 20624, 2991,
+		15765, 15743, 2992, 2993, 2994, 20625, 18740, 15551, 2995, 2996, 2997, 2998, 2999, 18736,
+		3000, 3001, 3002, 3003, 15737, 3004, 3005, 3006, 3007, 10593, 18734, 3008, 3009, 18737,
+		10418, 3010, 18735, 12067, 3011, 3012, 3013, 18741, 3014, 3015, 3016, 3017, 3018, 3019,
+		3020, 3021, 3022, 3023, 3024, 3025, 3026, 3027, 3028, 3029, 3030, 3031, 3032, 15889, 3033,
+		3034, 3035, 3036, 3037, 3038, 3039, 3040, 9825, 3041, 18742, 3042, 11152, 3043, 13862,
+		3044, 3045, 13844, 3046, 3047, 3048, 12854, 3049, 3050, 3051, 3052, 3053, 3054, 3055,
+		20626, 3056, 3057, 3058, 3059, 12538, 3060, 3061, 3062, 3063, 11174, 11889, 3064, 3065,
+		3066, 3067, 3068, 3069, 3070, 14756, 3071, 3072, 3073, 3074, 18743, 3075, 11897, 3076,
+		3077, 3078, 3079, 3080, 3081, 3082, 3083, 3084, 3085, 3086, 3087, 3088, 3089, 3090, 3091,
+		3092, 3093, 3094, 9294, 3095, 3096, 15952, 3097, 3098, 3099, 3100, 3101, 18745, 3102, 3103,
+		3104, 3105, 3106, 3107, 3108, 3109, 20628, 3110, 3111, 3112, 3113, 3114, 3115, 3116, 3117,
+		3118, 18746, 10934, 20627, 3119, 3120, 18744, 3121, 3122, 3123, 3124, 3125, 3126, 3127,
+		3128, 3129, 3130, 18747, 3131, 3132, 3133, 3134, 3135, 3136, 10947, 3137, 3138, 3139,
+		10179, 3140, 3141, 3142, 3143, 3144, 15129, 3145, 9060, 20629, 3146, 3147, 3148, 3149,
+		3150, 20630, 12098, 3151, 18748, 3152, 3153, 3154, 3155, 3156, 3157, 3158, 3159, 3160,
+		3161, 3162, 3163, 3164, 3165, 3166, 3167, 3168, 13034, 3169, 3170, 3171, 3172, 3173, 3174,
+		3175, 3176, 3177, 3178, 3179, 3180, 3181, 3182, 18749, 3183, 3184, 3185, 3186, 3187, 3188,
+		3189, 3190, 3191, 17403, 3192, 3193, 3194, 3195, 3196, 3197, 20631, 3198, 10619, 3199,
+		14801, 20052, 15176, 14054, 13639, 14938, 9636, 14775, 11563, 3200, 3201, 18777, 11197,
+		20053, 16084, 3202, 13266, 20054, 3203, 3204, 3205, 20055, 3206, 20057, 20056, 3207, 20059,
+		20058, 3208, 3209, 3210, 3211, 11551, 3212, 20060, 3213, 12475, 3214, 3215, 3216, 3217,
+		9818, 9872, 3218, 3219, 11122, 3220, 3221, 3222, 3223, 3224, 20495, 20494, 10386, 14244,
+		9281, 3225, 20496, 3226, 3227, 3228, 13815, 20497, 20498, 3229, 14019, 17813, 9442, 15955,
+		3230, 3231, 13248, 9064, 9867, 13636, 3232, 3233, 3234, 14583, 3235, 3236, 11899, 3237,
+		3238, 3239, 3240, 3241, 3242, 3243, 11934, 3244, 3245, 13399, 16265, 3246, 12074, 17814,
+		13783, 15374, 13019, 9096, 9617, 13622, 3247, 3248, 9091, 3249, 3250, 10441, 3251, 3252,
+		13105, 3253, 3254, 10248, 3255, 16105, 9643, 11333, 3256, 3257, 3258, 9608, 3259, 3260,
+		3261, 3262, 11766, 9074, 3263, 3264, 3265, 3266, 3267, 3268, 15539, 14032, 16440, 3269,
+		14557, 10187, 11900, 16114, 3270, 10552, 13070, 3271, 3272, 3273, 17815, 11932, 12498,
+		13427, 3274, 11119, 9223, 3275, 3276, 13089, 3277, 3278, 13106, 14352, 3279, 3280, 3281,
+		3282, 9222, 3283, 3284, 3285, 10029, 3286, 3287, 3288, 12833, 3289, 17816, 15321, 9668,
+		3290, 12717, 3291, 3292, 10442, 3293, 16329, 9978, 9485, 12845, 12898, 12078, 17817, 3294,
+		9097, 13051, 12319, 3295, 10788, 3296, 11745, 14591, 9071, 3297, 14582, 17819, 11733,
+		16465, 17818, 16103, 9086, 3298, 3299, 12891, 3300, 3301, 12448, 11385, 3302, 15696, 12090,
+		13016, 9411, 15946, 3303, 3304, 12073, 14000, 17820, 16147, 3305, 10751, 3306, 13598,
+		14164, 3307, 17822, 11904, 3308, 3309, 3310, 3311, 13225, 16442, 13981, 12860, 3312, 9649,
+		10785, 3313, 3314, 3315, 3316, 16269, 20137, 9045, 3317, 3318, 3319, 3320, 11945, 3321,
+		3322, 14429, 3323, 3324, 3325, 3326, 14593, 3327, 3328, 3329, 16276, 12491, 3330, 14771,
+		14347, 15119, 12876, 9993, 17821, 16140, 11328, 11166, 3331, 3332, 9029, 3333, 13033, 9861,
+		3334, 3335, 3336, 16136, 3337, 3338, 20138, 3339, 3340, 3341, 3342, 3343, 3344, 17823,
+		14541, 3345, 3346, 14611, 3347, 3348, 3349, 3350, 14795, 17825, 3351, 14552, 12071, 3352,
+		3353, 16464, 3354, 17824, 9063, 10945, 13826, 12908, 11757, 3355, 3356, 3357, 3358, 9427,
+		3359, 3360, 3361, 3362, 3363, 3364, 3365, 3366, 12109, 14235, 3367, 11386, 11142, 9998,
+		3368, 3369, 3370, 13100, 3371, 17835, 3372, 3373, 3374, 17832, 11746, 3375, 3376, 17828,
+		3377, 3378, 3379, 3380, 9808, 11556, 3381, 3382, 17829, 12902, 3383, 3384, 3385, 3386,
+		14953, 3387, 10039, 3388, 3389, 3390, 3391, 10227, 14023, 10059, 17834, 3392, 16093, 3393,
+		17830, 14390, 13299, 3394, 13052, 3395, 3396, 3397, 15505, 3398, 11767, 3399, 3400, 3401,
+		3402, 3403, 3404, 3405, 12496, 3406, 14374, 9619, 3407, 11546, 3408, 11931, 14573, 15353,
+		9860, 3409, 17833, 17826, 17836, 3410, 20139, 3411, 3412, 12461, 17831, 3413, 3414, 16277,
+		9982, 3415, 9489, 3416, 17837, 3417, 17847, 3418, 3419, 3420, 3421, 3422, 17842, 3423,
+		17846, 3424, 3425, 13649, 3426, 3427, 3428, 16500, 17844, 12707, 14410, 3429, 9474, 3430,
+		3431, 3432, 15515, 3433, 3434, 3435, 3436, 3437, 3438, 3439, 17843, 3440, 17840, 14778,
+		3441, 9792, 3442, 3443, 3444, 3445, 3446, 11886, 11715, 3447, 3448, 11545, 3449, 3450,
+		3451, 3452, 17838, 3453, 15879, 3454, 17827, 3455, 17839, 3456, 3457, 3458, 3459, 12096,
+		3460, 17841, 9488, 10618, 12452, 3461, 3462, 11528, 3463, 3464, 3465, 3466, 3467, 17850,
+		17853, 3468, 3469, 9416, 9789, 3470, 3471, 9859, 13781, 3472, 3473, 3474, 3475, 3476, 3477,
+		17851, 14204, 3478, 10612, 3479, 17852, 17855, 3480, 3481, 3482, 3483, 17854, 3484, 3485,
+		3486, 14379, 3487, 9090, 9863, 3488, 3489, 3490, 3491, 3492, 3493, 19121, 3494, 3495, 3496,
+		3497, 3498, 15120, 3499, 3500, 9480, 3501, 20236, 3502, 17849, 3503, 3504, 13842, 17848,
+		9083, 15486, 9302, 3505, 14360, 3506, 3507, 3508, 3509, 3510, 3511, 3512, 17845, 3513,
+		14159, 3514, 3515, 3516, 16065, 3517, 3518, 3519, 3520, 3521, 17856, 3522, 3523, 3524,
+		3525, 3526, 3527, 3528, 3529, 9846, 3530, 12831, 3531, 3532, 3533, 17858, 3534, 3535, 3536,
+		3537, 3538, 3539, 3540, 3541, 3542, 3543, 12729, 12826, 17956, 3544, 3545, 3546, 3547,
+		3548, 3549, 3550, 12299, 3551, 17857, 11764, 3552, 13223, 3553, 3554, 3555, 3556, 3557,
+		3558, 3559, 3560, 3561, 9632, 13671, 3562, 3563, 14181, 17859, 3564, 3565, 17959, 3566,
+		3567, 3568, 3569, 16453, 3570, 3571, 3572, 3573, 3574, 9618, 3575, 3576, 3577, 3578, 12290,
+		3579, 3580, 13438, 9410, 9858, 3581, 16446, 3582, 3583, 3584, 3585, 12901, 3586, 17957,
+		17958, 3587, 17960, 3588, 10944, 3589, 3590, 3591, 17961, 3592, 12127, 3593, 3594, 13810,
+		3595, 3596, 3597, 3598, 3599, 3600, 3601, 9462, 13465, 3602, 17962, 3603, 13455, 3604,
+		3605, 3606, 3607, 17963, 20237, 3608, 3609, 3610, 3611, 3612, 14206, 3613, 3614, 3615,
+		17965, 3616, 17964, 3617, 9438, 3618, 3619, 3620, 3621, 3622, 3623, 3624, 3625, 3626, 3627,
+		3628, 3629, 3630, 3631, 3632, 3633, 3634, 3635, 3636, 3637, 3638, 3639, 3640, 3641, 3642,
+		13057, 3643, 3644, 3645, 3646, 3647, 3648, 3649, 3650, 17966, 3651, 3652, 3653, 3654, 3655,
+		3656, 3657, 3658, 15925, 3659, 3660, 3661, 3662, 3663, 13618, 3664, 3665, 3666, 3667, 3668,
+		3669, 3670, 3671, 3672, 3673, 3674, 3675, 17967, 3676, 3677, 3678, 3679, 3680, 11765, 3681,
+		3682, 17968, 16252, 3683, 3684, 3685, 3686, 20068, 20257, 14018, 3687, 16717, 10581, 3688,
+		10740, 3689, 3690, 10392, 16149, 3691, 3692, 3693, 3694, 3695, 10777, 3696, 3697, 15112,
+		21990, 3698, 3699, 10023, 3700, 3701, 12719, 3702, 11725, 3703, 3704, 3705, 20258, 9054,
+		3706, 3707, 11539, 3708, 12273, 3709, 9269, 9603, 3710, 3711, 3712, 10595, 13777, 3713,
+		3714, 10221, 3715, 3716, 3717, 3718, 20259, 11705, 3719, 3720, 3721, 14058, 3722, 13430,
+		3723, 16146, 3724, 3725, 10438, 3726, 3727, 3728, 3729, 3730, 3731, 3732, 3733, 3734, 3735,
+		3736, 3737, 3738, 3739, 3740, 14761, 3741, 3742, 3743, 16066, 9298, 3744, 3745, 3746,
+		20444, 9088, 3747, 20446, 3748, 3749, 3750, 10188, 3751, 12302, 3752, 22225, 15122, 3753,
+		3754, 16124, 3755, 14776, 3756, 3757, 11574, 9661, 3758, 10556, 3759, 16077, 3760, 20644,
+		3761, 10212, 3762, 14180, 15139, 3763, 3764, 3765, 3766, 3767, 3768, 3769, 3770, 10384,
+		3771, 3772, 20447, 13974, 3773, 3774, 3775, 13067, 3776, 20450, 20449, 12480, 20448, 3777,
+		3778, 3779, 3780, 15198, 20451, 3781, 20452, 16504, 3782, 3783, 20453, 3784, 3785, 3786,
+		20454, 13281, 3787, 3788, 3789, 3790, 3791, 3792, 3793, 3794, 14787, 3795, 11345, 3796,
+		3797, 13638, 9984, 11726, 16273, 15936, 3798, 3799, 15309, 15186, 20069, 20070, 20071,
+		10946, 3800, 3801, 3802, 3803, 13982, 11960, 3804, 3805, 14625, 3806, 3807, 3808, 3809,
+		3810, 20077, 3811, 9051, 20075, 3812, 3813, 12070, 3814, 3815, 3816, 20072, 3817, 9498,
+		3818, 12722, 11187, 3819, 3820, 3821, 15540, 14809, 20076, 3822, 3823, 3824, 20073, 3825,
+		3826, 3827, 20080, 3828, 15144, 15694, 3829, 3830, 3831, 3832, 9811, 3833, 12668, 16517,
+		3834, 3835, 3836, 3837, 16104, 3838, 14004, 3839, 20082, 3840, 3841, 20081, 20084, 20083,
+		3842, 3843, 3844, 3845, 3846, 16316, 3847, 14967, 3848, 3849, 20088, 3850, 11161, 3851,
+		3852, 3853, 3854, 3855, 3856, 3857, 11585, 13821, 3858, 3859, 20089, 3860, 3861, 13800,
+		15009, 20087, 15911, 20090, 20092, 3862, 3863, 14612, 3864, 3865, 3866, 3867, 20086, 3868,
+		20091, 3869, 3870, 14803, 3871, 11177, 3872, 9626, 3873, 3874, 3875, 3876, 3877, 13258,
+		11701, 14815, 3878, 3879, 3880, 13466, 3881, 11692, 20093, 3882, 3883, 16284, 3884, 3885,
+		3886, 12287, 3887, 3888, 3889, 15926, 3890, 20094, 3891, 3892, 14945, 3893, 3894, 3895,
+		3896, 20095, 3897, 3898, 3899, 3900, 14043, 3901, 3902, 3903, 3904, 13030, 9046, 3905,
+		3906, 3907, 3908, 3909, 20097, 3910, 3911, 3912, 3913, 3914, 3915, 3916, 3917, 3918, 20096,
+		22008, 3919, 3920, 3921, 3922, 3923, 12851, 3924, 3925, 3926, 3927, 3928, 9224, 3929, 3930,
+		3931, 3932, 19158, 3933, 3934, 3935, 3936, 20098, 3937, 3938, 3939, 3940, 3941, 3942, 3943,
+		3944, 3945, 3946, 3947, 3948, 3949, 3950, 3951, 3952, 3953, 3954, 3955, 3956, 3957, 3958,
+		3959, 3960, 3961, 3962, 14044, 3963, 20099, 20100, 13261, 3964, 3965, 3966, 3967, 3968,
+		3969, 3970, 3971, 20101, 3972, 3973, 20102, 3974, 3975, 3976, 3977, 3978, 3979, 15892,
+		3980, 13485, 15508, 10637, 3981, 3982, 20079, 3983, 9465, 3984, 3985, 12537, 3986, 15953,
+		14415, 16513, 3987, 3988, 3989, 3990, 3991, 3992, 3993, 15899, 15723, 20270, 13098, 3994,
+		10449, 3995, 3996, 20287, 3997, 3998, 3999, 14178, 20297, 4000, 12107, 4001, 4002, 4003,
+		14626, 4004, 9610, 4005, 13263, 4006, 4007, 4008, 4009, 4010, 4011, 20327, 4012, 12855,
+		4013, 14745, 12834, 9245, 15958, 4014, 14050, 4015, 16321, 4016, 4017, 13256, 10233, 4018,
+		4019, 4020, 4021, 11205, 4022, 4023, 15169, 4024, 4025, 13792, 4026, 15916, 13593, 4027,
+		4028, 10588, 4029, 19695, 13803, 4030, 4031, 19692, 4032, 12143, 15156, 9441, 9854, 4033,
+		19693, 4034, 4035, 16095, 4036, 4037, 4038, 4039, 4040, 10202, 4041, 19694, 14053, 10605,
+		14430, 4042, 4043, 4044, 12085, 4045, 4046, 15373, 19696, 19699, 4047, 4048, 10953, 4049,
+		9228, 11555, 4050, 20074, 19700, 4051, 19703, 4052, 19708, 4053, 4054, 4055, 4056, 19709,
+		4057, 14196, 9094, 4058, 11316, 4059, 4060, 10760, 4061, 4062, 19698, 4063, 14622, 4064,
+		19707, 4065, 4066, 4067, 4068, 14811, 4069, 4070, 4071, 4072, 16132, 4073, 12309, 19701,
+		4074, 12657, 4075, 10826, 16251, 19705, 4076, 4077, 4078, 14029, 15935, 4079, 19697, 4080,
+		19702, 19704, 4081, 13420, 10422, 4082, 19706, 4083, 11936, 19715, 4084, 4085, 19720, 4086,
+		19718, 11365, 11352, 19724, 4087, 4088, 4089, 4090, 4091, 4092, 4093, 4094, 19727, 4095,
+		19723, 9305, 4096, 4097, 4098, 4099, 4100, 4101, 4102, 4103, 4104, 4105, 9081, 12844,
+		10589, 13271, 13615, 13650, 4106, 4107, 4108, 19712, 19717, 19719, 4109, 10816, 19721,
+		16520, 4110, 12916, 4111, 19725, 4112, 4113, 9478, 4114, 4115, 4116, 19714, 4117, 4118,
+		11382, 4119, 4120, 11909, 19710, 16334, 4121, 12343, 9486, 4122, 4123, 4124, 4125, 4126,
+		4127, 4128, 4129, 19728, 4130, 13998, 19722, 4131, 4132, 4133, 4134, 16059, 4135, 9289,
+		16082, 19711, 19713, 10181, 19716, 4136, 19726, 12089, 4137, 14052, 4138, 14163, 4139,
+		4140, 13265, 12150, 4141, 4142, 4143, 4144, 4145, 19738, 4146, 4147, 4148, 15108, 4149,
+		4150, 4151, 4152, 4153, 4154, 4155, 19747, 16319, 4156, 4157, 4158, 4159, 4160, 4161, 4162,
+		19729, 19730, 4163, 4164, 4165, 15384, 10968, 10634, 4166, 4167, 10626, 15917, 19744, 4168,
+		19743, 19741, 10815, 14395, 19735, 14731, 11957, 4169, 9049, 19746, 19745, 4170, 16467,
+		4171, 19733, 4172, 14544, 13778, 4173, 11139, 11554, 19739, 4174, 4175, 4176, 4177, 4178,
+		4179, 4180, 4181, 4182, 4183, 19731, 19732, 19734, 9996, 19736, 13432, 19740, 19742, 11511,
+		16449, 4184, 19753, 4185, 4186, 4187, 4188, 4189, 4190, 4191, 4192, 19750, 4193, 14551,
+		19751, 4194, 4195, 4196, 4197, 4198, 4199, 4200, 4201, 4202, 12281, 4203, 19737, 4204,
+		12658, 9105, 4205, 4206, 4207, 4208, 4209, 4210, 4211, 4212, 19749, 4213, 4214, 4215,
+		19752, 4216, 4217, 4218, 10738, 4219, 4220, 4221, 4222, 4223, 4224, 4225, 4226, 4227, 4228,
+		13825, 4229, 4230, 4231, 12681, 14789, 12135, 4232, 4233, 4234, 4235, 14238, 4236, 14406,
+		15126, 4237, 4238, 14030, 4239, 19748, 4240, 4241, 4242, 4243, 4244, 4245, 4246, 4247,
+		4248, 4249, 11381, 4250, 19754, 4251, 4252, 4253, 4254, 4255, 4256, 12697, 4257, 13274,
+		4258, 10822, 4259, 4260, 4261, 4262, 9109, 4263, 4264, 16491, 4265, 4266, 11317, 4267,
+		13094, 4268, 4269, 4270, 4271, 4272, 14381, 4273, 4274, 19861, 4275, 4276, 4277, 4278,
+		4279, 4280, 4281, 4282, 4283, 4284, 13788, 4285, 19857, 12131, 4286, 4287, 4288, 11910,
+		4289, 4290, 4291, 19759, 10790, 4292, 19756, 4293, 4294, 4295, 4296, 19859, 4297, 4298,
+		4299, 15531, 4300, 4301, 4302, 4303, 4304, 19858, 4305, 16263, 16456, 4306, 19862, 4307,
+		11518, 4308, 4309, 4310, 4311, 4312, 4313, 4314, 4315, 4316, 4317, 4318, 4319, 19757,
+		19758, 4320, 4321, 4322, 19856, 4323, 4324, 4325, 4326, 4327, 4328, 4329, 4330, 14589,
+		4331, 4332, 15497, 4333, 4334, 4335, 19873, 4336, 4337, 4338, 4339, 19864, 4340, 4341,
+		4342, 9795, 4343, 9812, 4344, 4345, 19866, 4346, 4347, 4348, 4349, 4350, 4351, 4352, 4353,
+		4354, 4355, 4356, 4357, 4358, 4359, 4360, 4361, 4362, 15113, 4363, 4364, 19860, 4365, 4366,
+		9689, 4367, 4368, 19867, 12132, 4369, 19865, 4370, 4371, 19880, 4372, 4373, 19879, 4374,
+		4375, 4376, 4377, 19869, 4378, 4379, 19755, 4380, 4381, 19863, 4382, 4383, 4384, 4385,
+		4386, 11887, 19872, 19881, 4387, 4388, 12450, 4389, 4390, 4391, 19870, 4392, 10582, 4393,
+		19868, 4394, 15735, 19875, 19876, 19878, 4395, 4396, 4397, 19894, 4398, 4399, 4400, 4401,
+		4402, 4403, 12103, 19892, 4404, 4405, 19871, 4406, 4407, 19882, 9106, 4408, 4409, 4410,
+		4411, 4412, 4413, 4414, 4415, 4416, 4417, 19883, 16060, 4418, 4419, 19885, 4420, 19886,
+		4421, 4422, 4423, 19888, 4424, 4425, 12336, 4426, 4427, 13607, 4428, 4429, 4430, 19884,
+		4431, 4432, 4433, 4434, 4435, 19889, 4436, 4437, 4438, 4439, 4440, 4441, 4442, 4443, 19890,
+		4444, 19874, 4445, 19877, 4446, 11132, 4447, 4448, 4449, 19887, 4450, 4451, 4452, 4453,
+		4454, 4455, 11392, 4456, 4457, 4458, 19891, 19893, 4459, 4460, 4461, 4462, 4463, 4464,
+		4465, 4466, 4467, 4468, 4469, 4470, 19897, 4471, 4472, 4473, 4474, 19901, 4475, 4476, 4477,
+		4478, 4479, 4480, 4481, 4482, 4483, 4484, 9464, 4485, 19895, 4486, 4487, 4488, 4489, 4490,
+		4491, 4492, 4493, 4494, 4495, 10369, 4496, 4497, 4498, 4499, 4500, 4501, 4502, 4503, 4504,
+		4505, 4506, 4507, 19898, 19899, 4508, 4509, 4510, 4511, 4512, 4513, 16088, 4514, 12828,
+		4515, 4516, 4517, 4518, 4519, 4520, 19911, 4521, 10990, 4522, 4523, 4524, 4525, 19896,
+		4526, 15678, 4527, 4528, 4529, 19907, 4530, 4531, 4532, 4533, 4534, 4535, 4536, 19910,
+		19903, 4537, 4538, 4539, 4540, 4541, 19902, 4542, 4543, 13428, 4544, 4545, 4546, 4547,
+		4548, 4549, 4550, 4551, 19905, 4552, 4553, 4554, 4555, 4556, 4557, 4558, 19912, 9635, 4559,
+		19906, 4560, 4561, 4562, 4563, 4564, 14995, 4565, 4566, 4567, 19900, 4568, 4569, 4570,
+		4571, 4572, 4573, 4574, 4575, 4576, 4577, 4578, 9682, 4579, 4580, 4581, 4582, 4583, 4584,
+		4585, 19909, 4586, 4587, 19913, 4588, 4589, 4590, 14365, 4591, 4592, 4593, 14928, 4594,
+		4595, 4596, 4597, 4598, 4599, 4600, 4601, 4602, 19908, 4603, 19915, 19914, 4604, 4605,
+		4606, 4607, 4608, 19917, 4609, 4610, 4611, 4612, 4613, 4614, 4615, 4616, 19904, 4617, 4618,
+		4619, 4620, 4621, 4622, 4623, 4624, 19916, 4625, 19918, 12677, 4626, 4627, 4628, 4629,
+		4630, 4631, 4632, 4633, 4634, 4635, 4636, 4637, 4638, 4639, 4640, 4641, 4642, 4643, 4644,
+		4645, 4646, 4647, 4648, 4649, 4650, 4651, 4652, 4653, 4654, 4655, 4656, 4657, 4658, 4659,
+		4660, 4661, 4662, 4663, 4664, 4665, 4666, 4667, 4668, 4669, 4670, 4671, 4672, 4673, 4674,
+		4675, 4676, 4677, 4678, 4679, 4680, 4681, 4682, 4683, 4684, 4685, 4686, 4687, 4688, 4689,
+		4690, 4691, 4692, 4693, 4694, 4695, 4696, 4697, 4698, 4699, 4700, 4701, 4702, 4703, 4704,
+		4705, 4706, 4707, 4708, 4709, 4710, 4711, 4712, 4713, 4714, 4715, 4716, 4717, 4718, 4719,
+		4720, 4721, 4722, 4723, 4724, 4725, 4726, 4727, 4728, 4729, 4730, 4731, 4732, 4733, 4734,
+		4735, 4736, 4737, 4738, 4739, 4740, 13418, 9831, 11137, 15137, 20428, 4741, 4742, 13038,
+		4743, 4744, 4745, 4746, 4747, 4748, 4749, 4750, 4751, 4752, 15766, 4753, 4754, 4755, 4756,
+		20429, 4757, 20430, 13264, 4758, 4759, 4760, 11953, 4761, 4762, 4763, 4764, 20431, 4765,
+		4766, 20432, 15115, 4767, 13419, 4768, 4769, 10617, 4770, 4771, 4772, 4773, 4774, 4775,
+		4776, 4777, 4778, 4779, 4780, 4781, 20433, 4782, 4783, 4784, 4785, 4786, 4787, 4788, 4789,
+		16270, 16148, 9828, 9434, 14793, 13276, 4790, 4791, 14600, 4792, 4793, 4794, 4795, 4796,
+		4797, 4798, 4799, 4800, 4801, 4802, 4803, 4804, 4805, 9870, 4806, 14187, 11368, 4807, 4808,
+		4809, 4810, 19921, 19922, 15369, 19924, 4811, 9874, 19923, 4812, 15314, 14031, 9453, 4813,
+		19927, 4814, 4815, 4816, 4817, 19925, 19926, 4818, 4819, 16264, 4820, 4821, 4822, 19928,
+		19929, 4823, 4824, 4825, 4826, 4827, 19930, 4828, 4829, 4830, 4831, 4832, 4833, 4834, 4835,
+		19931, 4836, 4837, 4838, 4839, 4840, 4841, 4842, 4843, 20440, 13040, 10211, 4844, 15568,
+		4845, 4846, 4847, 4848, 4849, 4850, 4851, 10053, 4852, 11172, 20442, 4853, 4854, 15552,
+		4855, 4856, 4857, 4858, 4859, 14792, 4860, 12849, 4861, 12666, 4862, 4863, 10195, 16650,
+		9255, 9262, 9264, 13109, 4864, 9263, 4865, 12646, 4866, 4867, 4868, 4869, 4870, 16072,
+		4871, 4872, 4873, 4874, 4875, 4876, 4877, 4878, 20239, 10958, 4879, 4880, 4881, 14371,
+		4882, 4883, 4884, 20240, 4885, 20242, 4886, 4887, 4888, 20243, 4889, 4890, 4891, 20241,
+		4892, 4893, 4894, 4895, 4896, 4897, 4898, 20244, 20246, 20245, 4899, 4900, 4901, 4902,
+		4903, 20247, 4904, 14012, 16646, 12716, 4905, 12640, 13293, 20248, 12867, 4906, 20249,
+		20250, 20251, 10408, 4907, 4908, 4909, 10445, 4910, 20252, 13461, 4911, 20254, 4912, 10929,
+		15380, 9042, 20253, 20255, 4913, 4914, 4915, 9985, 12485, 13467, 4916, 20256, 4917, 14169,
+		18778, 4918, 4919, 15707, 4920, 4921, 4922, 4923, 16874, 4924, 4925, 14536, 16258, 13478,
+		4926, 4927, 4928, 16879, 11182, 4929, 10951, 18781, 4930, 4931, 4932, 4933, 4934, 14926,
+		4935, 4936, 4937, 18779, 13814, 4938, 10950, 4939, 4940, 4941, 15315, 18780, 13659, 10750,
+		11508, 9651, 14784, 4942, 4943, 14377, 4944, 4945, 4946, 18787, 18788, 14619, 4947, 4948,
+		4949, 4950, 4951, 14358, 4952, 11324, 4953, 18789, 4954, 18790, 4955, 4956, 15163, 4957,
+		4958, 4959, 13296, 10413, 4960, 4961, 13458, 15527, 14779, 4962, 18783, 18791, 4963, 13857,
+		9628, 4964, 4965, 18786, 4966, 4967, 13272, 18784, 4968, 4969, 20634, 18785, 4970, 4971,
+		4972, 4973, 13794, 4974, 13085, 4975, 4976, 4977, 10756, 4978, 12662, 4979, 18782, 13044,
+		12259, 12502, 9460, 4980, 18792, 11121, 12838, 4981, 18795, 11743, 4982, 4983, 18806,
+		18800, 10976, 4984, 4985, 4986, 4987, 10403, 15720, 4988, 16291, 16106, 10768, 16075,
+		15351, 4989, 4990, 4991, 4992, 15130, 13481, 4993, 4994, 4995, 13595, 9425, 4996, 12693,
+		4997, 4998, 4999, 18793, 5000, 5001, 18807, 18794, 10363, 18802, 18799, 5002, 5003, 5004,
+		10381, 5005, 5006, 13017, 5007, 18801, 13076, 9413, 13297, 5008, 12889, 5009, 5010, 16437,
+		5011, 12130, 18804, 5012, 5013, 18805, 18808, 14354, 18798, 5014, 15705, 5015, 9250, 20635,
+		18796, 18797, 5016, 18803, 15131, 13240, 15948, 18809, 5017, 5018, 11559, 5019, 5020,
+		18912, 5021, 5022, 18911, 5023, 5024, 5025, 15378, 18908, 5026, 18914, 5027, 5028, 5029,
+		13672, 5030, 5031, 5032, 5033, 14935, 5034, 18913, 18919, 12514, 5035, 5036, 10185, 5037,
+		5038, 5039, 5040, 5041, 5042, 11577, 5043, 18907, 5044, 5045, 10998, 18915, 5046, 5047,
+		18917, 5048, 5049, 10258, 16307, 18923, 5050, 18918, 5051, 5052, 5053, 18906, 5054, 11193,
+		14596, 13397, 13056, 5055, 5056, 12342, 5057, 18909, 5058, 13414, 11507, 11524, 18910,
+		5059, 16474, 9471, 5060, 18916, 11340, 18920, 5061, 11190, 18921, 13023, 18922, 5062, 5063,
+		5064, 5065, 16122, 11878, 5066, 18931, 5067, 18928, 5068, 18932, 5069, 5070, 18934, 5071,
+		5072, 13259, 5073, 5074, 10963, 12108, 5075, 5076, 5077, 10546, 18925, 5078, 5079, 5080,
+		5081, 15866, 5082, 5083, 10928, 11589, 5084, 5085, 5086, 18933, 5087, 5088, 5089, 5090,
+		5091, 14566, 5092, 5093, 12914, 5094, 5095, 15006, 13845, 5096, 5097, 15706, 5098, 14964,
+		5099, 5100, 18924, 5101, 18929, 18930, 14418, 5102, 5103, 5104, 5105, 5106, 14391, 5107,
+		12117, 18926, 12271, 18927, 14773, 5108, 11148, 10026, 5109, 13668, 11502, 16094, 13787,
+		10547, 18947, 5110, 5111, 18949, 15331, 5112, 5113, 15511, 5114, 5115, 10938, 5116, 5117,
+		10977, 5118, 5119, 5120, 5121, 5122, 5123, 18940, 10052, 5124, 5125, 5126, 16480, 18937,
+		15008, 18936, 5127, 5128, 5129, 12315, 14387, 5130, 5131, 5132, 5133, 14036, 5134, 5135,
+		5136, 5137, 12880, 5138, 14397, 18945, 5139, 5140, 5141, 18944, 18938, 5142, 18941, 9988,
+		5143, 5144, 15732, 5145, 18943, 5146, 5147, 5148, 5149, 15574, 9852, 5150, 11135, 5151,
+		5152, 13853, 5153, 9815, 5154, 5155, 5156, 11191, 5157, 15340, 5158, 14422, 20636, 5159,
+		5160, 5161, 5162, 5163, 5164, 5165, 5166, 13464, 5167, 5168, 5169, 5170, 15873, 5171,
+		18948, 16488, 18939, 5172, 11500, 18942, 5173, 5174, 15745, 5175, 18946, 13863, 5176, 5177,
+		18935, 5178, 5179, 15744, 5180, 5181, 13489, 10206, 5182, 15957, 9424, 18960, 5183, 5184,
+		5185, 14759, 5186, 18950, 5187, 14753, 5188, 10604, 5189, 5190, 18959, 5191, 11917, 5192,
+		5193, 5194, 15721, 5195, 12711, 5196, 5197, 5198, 5199, 5200, 5201, 5202, 5203, 13055,
+		18961, 5204, 5205, 5206, 5207, 5208, 5209, 5210, 5211, 14571, 18952, 5212, 5213, 5214,
+		5215, 18957, 18958, 5216, 11019, 5217, 14985, 5218, 5219, 16086, 5220, 5221, 5222, 18955,
+		5223, 5224, 5225, 5226, 5227, 5228, 5229, 5230, 5231, 5232, 5233, 18953, 5234, 5235, 18951,
+		5236, 5237, 5238, 5239, 5240, 5241, 5242, 5243, 5244, 5245, 5246, 5247, 5248, 5249, 5250,
+		14604, 13975, 5251, 5252, 5253, 12068, 5254, 11501, 18956, 5255, 5256, 10585, 5257, 5258,
+		5259, 5260, 5261, 18976, 15885, 5262, 5263, 5264, 5265, 5266, 5267, 5268, 18964, 5269,
+		5270, 5271, 12334, 5272, 5273, 18978, 5274, 5275, 15556, 5276, 5277, 18968, 5278, 18969,
+		5279, 5280, 14925, 5281, 5282, 5283, 5284, 14216, 5285, 18963, 18954, 5286, 18974, 5287,
+		13645, 18972, 5288, 5289, 12896, 18971, 5290, 18970, 5291, 5292, 5293, 9687, 18977, 5294,
+		5295, 5296, 5297, 10040, 5298, 5299, 5300, 16479, 5301, 5302, 5303, 18975, 5304, 11127,
+		5305, 16485, 14392, 20323, 5306, 18973, 5307, 5308, 10821, 5309, 5310, 5311, 16290, 18962,
+		18965, 12535, 18967, 5312, 12488, 12101, 12493, 5313, 9300, 14363, 5314, 5315, 5316, 5317,
+		5318, 5319, 5320, 5321, 5322, 5323, 10021, 5324, 5325, 5326, 5327, 18984, 5328, 5329, 5330,
+		5331, 5332, 5333, 5334, 5335, 13220, 5336, 5337, 5338, 13270, 5339, 5340, 18990, 5341,
+		5342, 5343, 5344, 5345, 12454, 5346, 5347, 5348, 12141, 15356, 18983, 5349, 5350, 5351,
+		5352, 5353, 5354, 5355, 5356, 5357, 5358, 12839, 5359, 5360, 5361, 18982, 5362, 5363, 5364,
+		5365, 18991, 18989, 12539, 5366, 18966, 5367, 18985, 5368, 14059, 5369, 16091, 5370, 5371,
+		18986, 5372, 5373, 5374, 5375, 5376, 5377, 5378, 15385, 5379, 5380, 5381, 5382, 5383, 5384,
+		5385, 18980, 18981, 5386, 5387, 5388, 18987, 5389, 14736, 5390, 5391, 5392, 5393, 5394,
+		5395, 5396, 5397, 5398, 5399, 13058, 5400, 5401, 5402, 13412, 5403, 12471, 5404, 5405,
+		5406, 18979, 5407, 5408, 5409, 12297, 5410, 5411, 5412, 5413, 5414, 5415, 14367, 9612,
+		5416, 5417, 5418, 18996, 5419, 18988, 5420, 5421, 5422, 18995, 5423, 18998, 5424, 18997,
+		5425, 5426, 5427, 5428, 5429, 5430, 5431, 9641, 5432, 5433, 5434, 9621, 18992, 5435, 5436,
+		18994, 18993, 13091, 5437, 5438, 5439, 5440, 5441, 5442, 5443, 5444, 5445, 5446, 5447,
+		5448, 5449, 12094, 5450, 5451, 5452, 5453, 15937, 5454, 5455, 5456, 5457, 5458, 19097,
+		5459, 5460, 5461, 5462, 5463, 5464, 5465, 5466, 5467, 5468, 5469, 9061, 5470, 5471, 19099,
+		5472, 5473, 19098, 5474, 5475, 5476, 5477, 5478, 5479, 11309, 5480, 19100, 5481, 5482,
+		5483, 5484, 5485, 5486, 19096, 5487, 5488, 5489, 5490, 5491, 5492, 5493, 18999, 9299, 5494,
+		5495, 5496, 5497, 5498, 5499, 5500, 5501, 5502, 5503, 5504, 19103, 5505, 19104, 19101,
+		5506, 5507, 5508, 5509, 5510, 5511, 5512, 5513, 5514, 5515, 5516, 5517, 19102, 19105, 5518,
+		5519, 5520, 5521, 5522, 5523, 5524, 5525, 5526, 5527, 5528, 5529, 5530, 5531, 5532, 5533,
+		5534, 5535, 5536, 5537, 5538, 5539, 5540, 5541, 5542, 5543, 5544, 5545, 5546, 5547, 5548,
+		5549, 5550, 13262, 5551, 5552, 5553, 5554, 5555, 5556, 5557, 5558, 19106, 19108, 5559,
+		5560, 5561, 5562, 5563, 5564, 5565, 19107, 5566, 5567, 5568, 5569, 5570, 5571, 5572, 5573,
+		5574, 5575, 5576, 5577, 5578, 5579, 5580, 5581, 5582, 19110, 5583, 5584, 5585, 19109, 5586,
+		5587, 5588, 5589, 5590, 5591, 5592, 5593, 5594, 5595, 5596, 5597, 5598, 5599, 5600, 5601,
+		5602, 5603, 10799, 5604, 5605, 19111, 5606, 5607, 5608, 5609, 5610, 5611, 5612, 5613, 5614,
+		5615, 5616, 5617, 5618, 5619, 19112, 5620, 5621, 5622, 5623, 5624, 5625, 5626, 5627, 5628,
+		5629, 5630, 5631, 11195, 20490, 12715, 5632, 10013, 11165, 5633, 5634, 5635, 5636, 12328,
+		15943, 5637, 11721, 5638, 5639, 5640, 16473, 5641, 15919, 9456, 20455, 5642, 5643, 5644,
+		5645, 20078, 5646, 5647, 5648, 12460, 9807, 5649, 5650, 5651, 15350, 5652, 5653, 5654,
+		9615, 5655, 13603, 11902, 20457, 5656, 5657, 16288, 5658, 5659, 20456, 20458, 5660, 5661,
+		5662, 5663, 5664, 5665, 5666, 5667, 5668, 5669, 5670, 5671, 5672, 20462, 11755, 14376,
+		13073, 11713, 5673, 20463, 5674, 9309, 5675, 5676, 5677, 20461, 16063, 10042, 5678, 20459,
+		12277, 9662, 5679, 5680, 20460, 14179, 12100, 14439, 5681, 5682, 5683, 5684, 12305, 5685,
+		20465, 5686, 5687, 5688, 5689, 5690, 5691, 5692, 5693, 5694, 5695, 5696, 5697, 5698, 10995,
+		12116, 5699, 16327, 5700, 5701, 5702, 15339, 5703, 5704, 5705, 5706, 11905, 5707, 10374,
+		13828, 20464, 11181, 5708, 14389, 11588, 13625, 5709, 14924, 5710, 5711, 5712, 5713, 5714,
+		5715, 5716, 14608, 5717, 13090, 5718, 5719, 5720, 10428, 5721, 5722, 5723, 5724, 5725,
+		5726, 5727, 5728, 5729, 5730, 5731, 15336, 10949, 5732, 5733, 5734, 5735, 5736, 20466,
+		5737, 5738, 20467, 5739, 11147, 20468, 5740, 20491, 9241, 10412, 5741, 5742, 5743, 5744,
+		5745, 5746, 5747, 5748, 5749, 5750, 5751, 11520, 5752, 5753, 5754, 5755, 5756, 5757, 5758,
+		5759, 20469, 15365, 20470, 5760, 5761, 5762, 5763, 13612, 5764, 5765, 5766, 5767, 5768,
+		5769, 5770, 5771, 5772, 5773, 5774, 5775, 5776, 5777, 20474, 5778, 5779, 5780, 5781, 20476,
+		5782, 11160, 5783, 11374, 5784, 5785, 5786, 5787, 5788, 5789, 5790, 5791, 5792, 5793, 5794,
+		5795, 5796, 20472, 5797, 13798, 5798, 5799, 5800, 5801, 5802, 12661, 5803, 20492, 16108,
+		20473, 5804, 5805, 5806, 5807, 5808, 16328, 5809, 5810, 5811, 20475, 20471, 5812, 5813,
+		5814, 5815, 20477, 5816, 20478, 5817, 5818, 13806, 5819, 5820, 5821, 5822, 5823, 5824,
+		14923, 5825, 5826, 5827, 5828, 5829, 15165, 5830, 5831, 5832, 5833, 15307, 5834, 5835,
+		5836, 5837, 13644, 5838, 5839, 5840, 20479, 14810, 5841, 5842, 5843, 5844, 5845, 14041,
+		20483, 5846, 5847, 5848, 5849, 5850, 5851, 5852, 20482, 5853, 5854, 5855, 9055, 5856, 5857,
+		5858, 5859, 5860, 5861, 20480, 5862, 20481, 5863, 5864, 5865, 20493, 5866, 5867, 5868,
+		5869, 5870, 5871, 5872, 5873, 5874, 13613, 5875, 5876, 5877, 5878, 5879, 5880, 5881, 5882,
+		5883, 5884, 12294, 5885, 5886, 5887, 5888, 5889, 20485, 15359, 5890, 5891, 5892, 5893,
+		5894, 5895, 5896, 5897, 5898, 5899, 20484, 5900, 5901, 5902, 5903, 15944, 5904, 20486,
+		5905, 5906, 5907, 5908, 5909, 5910, 17233, 5911, 5912, 5913, 5914, 5915, 5916, 5917, 5918,
+		5919, 5920, 20487, 5921, 5922, 5923, 5924, 5925, 5926, 5927, 5928, 5929, 5930, 5931, 5932,
+		9227, 5933, 5934, 5935, 5936, 5937, 5938, 5939, 5940, 5941, 5942, 5943, 5944, 5945, 5946,
+		5947, 5948, 5949, 5950, 5951, 5952, 5953, 5954, 20488, 5955, 5956, 5957, 5958, 5959, 5960,
+		5961, 5962, 5963, 5964, 20489, 5965, 16441, 5966, 13047, 5967, 5968, 5969, 20263, 9039,
+		5970, 5971, 5972, 11769, 10569, 15500, 9079, 10064, 5973, 16644, 5974, 14167, 5975, 18776,
+		5976, 5977, 5978, 5979, 5980, 5981, 5982, 13217, 9095, 5983, 5984, 5985, 13053, 20260,
+		5986, 5987, 5988, 5989, 20261, 5990, 5991, 5992, 20262, 5993, 5994, 15327, 5995, 13018,
+		5996, 20127, 5997, 12843, 5998, 12846, 12111, 5999, 6000, 6001, 20128, 12858, 6002, 14804,
+		6003, 6004, 6005, 6006, 20125, 20129, 6007, 6008, 13867, 6009, 6010, 13398, 6011, 6012,
+		6013, 14401, 14819, 6014, 6015, 6016, 20130, 20131, 14927, 12136, 6017, 6018, 20132, 6019,
+		6020, 6021, 6022, 6023, 10196, 20133, 6024, 20134, 6025, 20135, 6026, 6027, 20136, 6028,
+		6029, 6030, 6031, 6032, 6033, 6034, 6035, 6036, 6037, 6038, 6039, 20126, 6040, 6041, 6042,
+		6043, 6044, 6045, 6046, 6047, 6048, 6049, 6050, 6051, 13599, 18410, 6052, 10379, 18411,
+		6053, 6054, 6055, 18412, 6056, 16455, 18413, 18414, 15719, 6057, 6058, 6059, 6060, 6061,
+		6062, 6063, 18416, 11956, 18415, 10025, 6064, 6065, 6066, 9238, 6067, 6068, 6069, 6070,
+		18418, 18417, 6071, 11017, 6072, 18419, 6073, 6074, 6075, 6076, 10758, 6077, 11734, 6078,
+		6079, 7790, 7791, 12917, 7792, 10986, 11533, 7793, 7794, 7795, 7796, 7797, 7798, 18420,
+		18422, 7799, 7800, 10197, 14948, 13973, 18421, 16143, 15767, 18423, 18427, 18424, 7801,
+		7802, 18425, 12139, 7803, 18429, 18526, 12104, 7804, 7805, 7806, 7807, 18426, 7808, 18428,
+		7809, 7810, 7811, 7812, 7813, 7814, 18530, 7815, 7816, 7817, 12307, 7818, 7819, 7820, 7821,
+		18528, 7822, 18533, 9499, 18527, 7823, 7824, 7825, 12680, 9439, 18532, 18531, 7826, 7827,
+		18529, 18534, 7828, 7829, 18536, 7830, 7831, 7832, 15146, 16322, 12643, 18537, 7833, 14970,
+		7834, 7835, 18539, 7836, 7837, 11004, 7838, 7839, 19919, 18538, 18535, 7840, 7841, 7842,
+		7843, 11126, 15884, 7844, 7845, 7846, 7847, 7848, 7849, 7850, 7851, 7852, 7853, 7854, 7855,
+		7856, 18541, 7857, 7858, 18540, 7859, 19920, 7860, 7861, 7862, 7863, 18542, 7864, 7865,
+		7866, 7867, 7868, 7869, 7870, 7871, 18543, 7872, 7873, 7874, 7875, 7876, 7877, 7878, 7879,
+		7880, 7881, 7882, 18544, 14346, 7883, 18545, 7884, 7885, 7980, 7981, 7982, 7983, 7984,
+		7985, 7986, 7987, 7988, 7989, 7990, 7991, 18546, 7992, 7993, 7994, 7995, 7996, 15199, 7997,
+		7998, 12487, 7999, 15756, 8000, 14620, 8001, 8002, 19539, 8003, 8004, 19540, 8005, 8006,
+		8007, 8008, 11718, 8009, 8010, 8011, 8012, 12519, 8013, 8014, 8015, 19543, 8016, 8017,
+		19542, 8018, 8019, 8020, 8021, 8022, 8023, 14605, 8024, 12656, 8025, 8026, 19541, 11138,
+		14969, 8027, 12320, 19548, 8028, 8029, 8030, 19547, 8031, 8032, 19559, 9408, 8033, 8034,
+		8035, 8036, 19549, 8037, 19545, 8038, 8039, 8040, 8041, 8042, 19551, 19550, 13801, 8043,
+		8044, 16123, 8045, 19544, 10364, 19546, 8046, 8047, 8048, 8049, 8050, 8051, 8052, 19553,
+		8053, 8054, 8055, 8056, 19558, 8057, 16318, 8058, 8059, 8060, 8061, 19552, 8062, 19557,
+		8063, 19556, 8064, 8065, 8066, 9089, 8067, 8068, 8069, 8070, 19560, 8071, 8072, 8073, 8074,
+		8075, 8170, 8171, 8172, 8173, 8174, 8175, 8176, 8177, 8178, 8179, 8180, 13477, 8181, 12102,
+		12142, 8182, 8183, 12335, 19555, 8184, 8185, 8186, 8187, 19561, 14241, 8188, 8189, 8190,
+		8191, 8192, 8193, 8194, 8195, 8196, 19667, 19666, 8197, 8198, 8199, 8200, 8201, 8202,
+		16468, 8203, 8204, 19565, 19564, 8205, 19566, 8206, 19562, 8207, 19569, 8208, 19568, 8209,
+		19567, 8210, 8211, 12308, 13452, 13108, 13050, 8212, 8213, 8214, 8215, 8216, 13472, 8217,
+		8218, 8219, 8220, 19668, 8221, 8222, 8223, 8224, 8225, 8226, 8227, 8228, 8229, 8230, 8231,
+		8232, 8233, 8234, 8235, 8236, 8237, 8238, 8239, 19671, 8240, 19670, 8241, 19672, 11012,
+		19563, 19669, 8242, 13665, 13785, 8243, 8244, 8245, 8246, 8247, 8248, 8249, 8250, 8251,
+		8252, 8253, 8254, 8255, 19674, 8256, 8257, 10804, 8258, 8259, 8260, 8261, 8262, 15389,
+		19673, 8263, 8264, 8265, 8360, 8361, 8362, 19675, 8363, 19678, 19679, 8364, 12262, 8365,
+		8366, 8367, 19680, 8368, 8369, 8370, 19681, 8371, 8372, 19677, 8373, 19685, 8374, 8375,
+		8376, 8377, 8378, 8379, 8380, 8381, 8382, 8383, 8384, 19676, 8385, 19682, 8386, 8387, 8388,
+		8389, 8390, 8391, 8392, 8393, 19686, 19683, 19684, 8394, 8395, 8396, 8397, 8398, 8399,
+		8400, 8401, 8402, 8403, 8404, 8405, 8406, 8407, 8408, 8409, 19688, 8410, 8411, 8412, 8413,
+		8414, 8415, 8416, 8417, 8418, 8419, 8420, 8421, 8422, 8423, 8424, 8425, 8426, 8427, 8428,
+		8429, 8430, 8431, 8432, 19687, 8433, 8434, 8435, 8436, 8437, 8438, 8439, 8440, 8441, 10782,
+		8442, 21197, 8443, 21198, 8444, 13221, 9099, 13616, 8445, 14598, 8446, 8447, 8448, 8449,
+		8450, 8451, 8452, 14770, 20062, 8453, 8454, 8455, 8550, 20063, 8551, 13236, 9826, 8552,
+		8553, 8554, 8555, 8556, 8557, 8558, 20064, 8559, 8560, 8561, 8562, 16126, 8563, 8564, 8565,
+		8566, 8567, 8568, 8569, 8570, 17664, 8571, 20065, 8572, 20066, 8573, 20067, 8574, 8575,
+		8576, 8577, 10587, 17971, 13860, 8578, 14425, 8579, 8580, 13865, 8581, 8582, 8583, 8584,
+		8585, 13866, 8586, 8587, 15710, 14161, 8588, 10551, 21199, 9249, 8589, 19119, 14424, 15716,
+		11360, 13849, 8590, 10046, 8591, 12873, 10048, 8592, 20819, 11128, 8593, 8594, 19537, 8595,
+		20820, 8596, 8597, 8598, 8599, 9604, 8600, 8601, 20823, 8602, 8603, 20822, 11566, 8604,
+		20821, 14748, 8605, 8606, 8607, 8608, 13062, 8609, 8610, 8611, 8612, 12339, 17241, 20824,
+		15188, 8613, 8614, 8615, 8616, 8617, 8618, 8619, 8620, 12497, 13277, 8621, 8622, 8623,
+		10367, 8624, 8625, 8626, 8627, 8628, 8629, 8630, 20825, 8631, 9670, 8632, 8633, 8634,
+		11206, 20826, 8635, 8636, 8637, 8638, 8639, 19535, 8640, 8641, 8642, 20827, 8643, 8644,
+		11509, 8645, 8740, 8741, 8742, 21441, 8743, 8744, 8745, 14037, 8746, 15526, 21243, 8747,
+		21244, 8748, 21245, 12293, 8749, 10622, 11731, 8750, 8751, 21247, 8752, 13032, 21246,
+		15376, 8753, 21249, 9069, 11569, 8754, 8755, 8756, 8757, 8758, 15548, 21248, 8759, 9800,
+		10427, 21255, 21254, 13208, 21250, 21251, 9820, 8760, 8761, 21252, 16133, 8762, 8763,
+		14404, 11735, 11323, 8764, 8765, 8766, 21257, 21256, 21253, 9406, 8767, 16247, 15699,
+		11708, 13597, 8768, 8769, 21259, 8770, 8771, 8772, 8773, 15382, 8774, 16289, 10984, 21258,
+		8775, 10192, 8776, 8777, 14555, 8778, 8779, 13211, 8780, 8781, 8782, 12256, 21260, 21263,
+		8783, 21262, 21265, 21261, 8784, 11145, 21264, 8785, 8786, 8787, 8788, 14366, 21267, 8789,
+		8790, 9648, 8791, 8792, 8793, 8794, 9267, 8795, 8796, 21268, 8797, 8798, 21269, 21271,
+		9850, 8799, 21266, 8800, 21272, 8801, 8802, 8803, 8804, 21275, 8805, 21273, 8806, 8807,
+		8808, 21270, 8809, 8810, 8811, 8812, 21278, 8813, 21274, 21277, 21279, 8814, 21376, 8815,
+		8816, 8817, 14758, 21379, 8818, 21378, 8819, 12341, 21276, 14026, 8820, 8821, 9866, 9296,
+		14362, 8822, 21381, 8823, 8824, 21382, 8825, 8826, 21387, 16101, 21384, 8827, 8828, 13604,
+		8829, 8830, 8831, 21377, 8832, 21386, 21383, 21380, 8833, 8834, 21385, 8835, 8930, 8931,
+		8932, 8933, 8934, 8935, 8936, 9034, 21388, 8937, 8938, 8939, 8940, 8941, 8942, 21390, 8943,
+		21392, 8944, 8945, 8946, 8947, 8948, 21391, 8949, 21389, 8950, 8951, 8952, 8953, 15297,
+		8954, 8955, 8956, 8957, 8958, 8959, 8960, 21393, 8961, 8962, 8963, 21394, 8964, 8965, 8966,
+		8967, 8968, 8969, 8970, 8971, 10814, 8972, 8973, 10014, 8974, 9080, 9082, 8975, 8976, 8977,
+		15942, 8978, 10011, 8979, 11547, 11157, 21192, 8980, 8981, 10607, 8982, 8983, 21193, 8984,
+		8985, 9033, 8986, 21194, 8987, 8988, 14613, 8989, 8990, 21195, 8991, 8992, 8993, 8994,
+		8995, 8996, 8997, 8998, 8999, 9000, 21196, 9001, 9002, 9003, 9004, 9005, 9006, 9007, 9008,
+		9009, 13209, 9010, 9011, 16314, 21443, 9012, 21444, 9013, 9014, 9015, 9016, 9017, 9018,
+		9019, 9020, 9021, 9022, 12718, 9023, 9024, 15734, 9025, 9120, 16294, 13087, 9121, 15690,
+		9122, 15555, 9123, 9124, 20838, 9052, 16076, 15341, 11369, 10973, 9125, 11963, 9126, 10584,
+		10008, 13059, 9127, 9128, 13967, 9129, 9130, 9131, 12678, 9132, 9133, 9134, 9135, 9136,
+		20839, 9137, 9138, 9139, 9140, 9141, 9142, 9143, 9144, 12856, 10167, 9145, 20691, 12639,
+		9146, 16262, 9147, 9148, 9149, 14979, 20694, 9150, 9151, 13061, 9152, 10225, 9153, 9154,
+		13966, 9155, 9156, 20692, 9157, 9158, 20695, 20696, 12663, 9159, 11895, 9160, 20693, 9161,
+		9162, 9163, 9164, 9165, 9166, 9167, 9168, 9169, 9170, 9171, 20699, 20697, 9172, 9173, 9174,
+		9175, 16125, 12698, 9176, 20698, 9177, 9178, 9179, 20701, 9180, 16058, 15298, 9181, 9182,
+		9183, 20700, 9184, 12683, 9185, 9186, 9187, 9188, 9189, 20702, 11959, 11761, 20703, 9190,
+		14432, 9191, 15354, 9192, 9193, 9194, 16472, 16141, 9195, 20707, 9196, 9197, 9198, 20706,
+		9199, 9200, 9201, 9202, 9203, 9204, 9205, 9206, 20704, 20705, 9207, 9208, 9209, 9210, 9211,
+		9212, 9213, 9214, 20708, 11691, 9215, 9310, 9311, 9312, 9313, 14170, 20806, 10194, 9314,
+		20807, 12857, 9315, 20709, 9316, 9317, 11557, 9444, 9318, 9319, 9320, 9321, 9322, 9323,
+		9324, 9325, 9326, 9327, 9328, 9329, 10200, 9330, 9331, 9332, 20810, 16651, 20808, 20811,
+		9333, 9334, 9335, 12708, 9677, 9336, 9337, 9338, 9339, 9340, 9341, 20812, 20809, 14940,
+		9342, 9343, 20813, 12532, 9344, 9345, 9346, 9347, 9348, 9349, 9350, 9351, 9352, 9353, 9354,
+		9355, 20814, 20815, 9356, 17776, 9357, 9358, 13224, 9359, 13433, 9360, 16330, 10016, 9361,
+		14173, 9362, 9363, 9364, 20816, 9365, 9366, 14546, 9367, 20817, 9368, 9369, 9370, 9371,
+		9372, 16071, 9373, 20818, 9374, 22361, 9375, 9376, 9377, 9378, 9379, 9380, 9381, 9382,
+		9383, 9384, 9385, 9386, 9387, 17234, 9388, 9389, 9390, 9391, 9392, 9393, 9394, 9395, 9396,
+		9788, 9397, 9398, 9399, 12647, 21445, 9400, 9401, 9402, 9403, 9404, 13989, 15536, 9405,
+		16255, 9500, 21077, 9501, 11741, 9502, 11530, 21078, 10209, 9036, 9503, 9504, 9505, 9506,
+		13980, 9507, 9508, 20637, 9509, 20638, 9510, 9511, 9512, 9513, 14814, 10370, 11958, 20639,
+		12520, 13791, 9514, 9515, 9516, 9517, 9518, 9519, 20640, 9520, 9521, 13291, 11894, 9522,
+		9523, 9524, 20643, 13103, 9525, 15343, 9526, 16444, 20641, 20642, 9527, 15361, 9528, 20646,
+		20647, 9529, 20651, 9530, 9531, 9532, 20655, 9533, 20653, 20661, 16127, 9534, 20656, 9535,
+		9536, 20654, 20645, 9537, 9538, 13088, 9539, 9540, 9541, 13243, 9542, 9543, 13848, 15915,
+		20648, 20649, 20650, 20652, 9544, 12154, 9545, 9786, 9546, 9547, 9548, 9549, 10807, 9550,
+		20663, 9551, 9552, 9553, 9554, 20664, 9555, 20657, 9556, 20662, 9557, 14813, 9558, 9559,
+		14177, 20659, 20660, 9560, 9561, 9562, 9563, 9564, 15000, 9565, 9566, 9567, 9568, 9569,
+		9570, 9571, 9572, 9573, 9574, 9575, 9576, 20665, 12337, 15693, 20658, 13608, 9577, 9578,
+		9579, 9580, 9581, 9582, 9583, 9584, 11384, 9585, 9586, 9587, 9588, 13095, 9589, 9590, 9591,
+		9592, 9593, 9594, 9595, 9690, 9691, 9692, 20669, 9693, 10054, 9694, 9695, 12466, 9038,
+		14228, 9696, 9697, 9229, 9698, 20667, 9699, 9700, 9701, 14610, 10041, 9702, 20668, 20666,
+		20670, 9703, 9704, 10065, 9705, 20671, 9706, 20672, 9707, 20675, 9708, 9259, 9709, 9710,
+		9711, 9712, 9713, 9714, 9715, 9716, 13101, 11383, 20673, 14373, 9479, 9717, 9718, 9719,
+		9720, 20674, 9721, 9722, 9723, 9724, 12900, 9725, 9726, 9822, 9727, 9728, 9729, 9110, 9730,
+		9731, 9732, 20678, 12123, 9857, 9733, 9734, 9735, 9736, 13060, 9737, 9738, 9739, 20676,
+		11911, 9740, 9741, 9742, 20677, 9743, 9744, 9745, 9746, 9747, 9748, 9749, 9750, 9751, 9752,
+		9753, 9754, 9755, 9756, 12830, 9757, 9758, 9759, 20679, 9760, 9761, 9762, 9763, 9764,
+		20680, 9765, 20682, 9766, 9767, 12310, 9768, 9769, 11154, 9770, 9771, 9772, 9773, 9774,
+		9775, 11519, 9776, 9777, 9778, 20681, 9779, 9780, 9781, 9782, 9783, 9784, 9785, 9880, 9881,
+		9882, 9883, 9884, 9885, 20683, 9886, 9887, 9888, 9889, 9890, 9891, 9892, 9893, 9894, 9895,
+		20685, 9896, 9897, 9898, 9899, 9900, 20684, 9901, 9902, 9903, 9904, 9905, 9906, 9907, 9908,
+		9909, 9910, 9911, 9912, 9913, 9914, 9915, 20686, 9916, 9917, 9918, 9919, 9920, 13995,
+		20499, 12146, 9921, 13846, 9922, 20500, 13283, 9923, 9924, 9925, 9926, 20501, 9927, 13282,
+		20502, 9928, 9929, 9930, 9931, 9932, 9933, 9934, 9935, 9936, 20505, 9937, 9938, 16505,
+		20508, 9939, 9940, 20506, 20503, 20504, 16438, 13856, 14233, 20509, 9941, 20507, 9942,
+		9943, 14988, 9944, 20511, 13222, 9945, 9946, 9947, 9948, 11337, 9949, 20510, 9950, 9951,
+		9952, 9953, 9954, 9955, 9956, 10002, 11201, 9957, 20512, 9958, 9959, 9960, 9961, 9962,
+		16902, 11586, 9963, 9964, 12472, 20513, 9965, 9966, 9967, 9968, 20514, 9969, 9970, 9971,
+		9972, 10548, 9973, 9974, 9975, 10070, 10071, 10072, 10073, 10074, 10075, 10076, 20515,
+		10077, 10078, 10079, 10080, 10081, 10082, 10083, 10084, 10085, 10086, 10087, 10088, 20516,
+		10089, 10090, 10091, 10092, 10093, 10094, 10095, 10096, 10097, 10098, 10099, 20517, 10100,
+		10101, 10102, 10103, 10104, 15752, 16639, 12140, 10105, 13456, 10969, 10106, 15172, 14184,
+		10107, 14561, 10108, 10109, 10594, 10110, 10111, 9307, 10112, 13474, 10113, 16299, 10114,
+		10115, 10116, 11913, 12710, 10117, 10118, 21080, 10119, 10120, 12691, 10121, 10122, 10123,
+		10124, 10125, 10126, 16501, 10127, 10128, 10129, 21082, 9646, 10130, 13451, 15372, 10131,
+		16285, 10132, 21083, 10133, 21081, 10134, 11208, 9633, 10135, 10136, 10137, 10138, 10139,
+		10140, 10141, 11548, 10142, 10143, 15523, 10144, 11179, 10145, 10146, 14916, 10147, 21087,
+		21086, 10148, 10149, 21084, 10150, 10151, 10152, 10153, 9639, 10154, 13827, 14171, 10155,
+		10156, 10157, 10158, 10159, 21089, 10160, 10161, 9087, 10162, 10163, 16286, 10164, 10165,
+		10260, 21088, 10261, 9672, 10262, 10263, 22378, 10264, 10265, 10266, 10267, 10268, 10269,
+		10270, 10271, 10272, 10273, 10274, 10275, 10276, 10277, 10278, 14765, 10279, 10280, 10281,
+		21187, 10282, 21186, 10283, 10005, 11363, 11207, 10284, 10614, 10285, 10286, 10287, 10288,
+		10289, 10290, 12859, 10291, 10292, 10293, 10294, 10295, 10296, 10297, 10298, 10299, 10300,
+		21188, 10301, 10302, 10303, 10304, 10305, 14230, 10306, 10307, 10308, 10309, 10310, 10311,
+		10312, 10313, 10314, 10315, 10316, 10317, 10318, 10319, 10320, 10321, 10322, 10323, 10324,
+		10325, 10326, 10327, 10328, 10329, 21191, 10330, 10331, 10332, 15303, 10333, 11716, 13473,
+		21397, 21398, 11928, 10334, 10335, 10336, 10337, 9794, 21399, 14562, 10338, 13447, 16068,
+		10339, 21400, 10340, 21401, 10341, 10342, 10343, 10344, 13442, 10345, 10346, 10347, 15489,
+		16292, 10348, 10349, 21402, 11544, 9801, 11714, 10350, 10351, 10352, 9845, 14774, 10353,
+		11938, 21404, 10354, 10355, 10450, 10451, 11965, 21403, 10452, 21406, 10453, 10454, 10455,
+		21405, 21407, 10456, 10457, 10458, 10459, 10460, 21408, 10461, 10462, 10463, 10464, 10465,
+		10466, 10467, 10468, 10469, 10470, 10471, 12349, 10472, 10473, 10474, 10475, 10476, 10477,
+		10478, 10479, 10480, 10481, 10482, 12257, 10483, 10484, 10485, 10486, 10487, 10488, 10489,
+		10490, 10491, 10492, 14055, 10493, 10494, 16085, 10495, 10496, 10497, 10498, 11711, 11710,
+		16089, 10499, 10500, 11877, 10501, 14550, 21396, 10502, 10503, 10504, 10505, 10506, 10507,
+		11558, 10508, 10208, 10509, 10510, 10511, 10512, 10513, 10514, 10515, 10516, 10517, 16326,
+		21790, 10518, 10519, 21791, 10520, 10590, 10521, 10522, 10523, 21793, 21794, 10524, 9067,
+		10525, 21792, 10526, 21796, 14236, 10527, 10528, 10529, 21798, 10530, 15111, 10531, 10532,
+		9257, 21795, 10533, 10534, 10535, 21802, 10536, 10024, 10537, 10538, 21810, 10539, 21805,
+		10540, 10541, 10542, 21807, 21806, 10446, 10543, 9246, 10544, 21801, 21797, 10033, 10545,
+		21803, 10640, 10641, 21804, 10642, 21808, 10643, 10644, 10645, 10646, 21800, 10647, 11372,
+		10648, 12348, 10649, 21809, 10650, 10651, 10652, 10653, 10654, 10655, 21813, 10656, 21799,
+		10657, 10015, 10658, 11573, 21815, 10659, 10660, 10359, 11955, 16339, 14553, 10661, 9865,
+		10662, 9468, 10663, 21811, 10664, 21812, 13799, 10665, 21816, 10666, 10667, 21817, 10668,
+		21820, 10669, 10670, 10671, 10672, 10673, 10674, 10675, 10676, 10677, 10678, 10679, 21818,
+		10680, 10681, 21822, 21821, 10682, 10683, 21814, 10684, 11949, 10685, 9674, 10686, 21819,
+		10687, 10688, 13404, 10689, 11387, 10690, 10691, 10692, 10693, 21830, 10694, 10695, 10696,
+		10697, 10698, 10699, 10700, 10766, 10701, 10702, 21823, 10703, 10704, 10705, 9418, 11209,
+		10706, 14222, 10707, 10708, 10709, 10710, 21832, 21828, 10711, 10712, 10713, 10795, 21833,
+		10714, 10715, 10716, 21824, 21825, 21829, 12510, 21831, 21834, 21827, 11398, 10717, 10718,
+		10719, 14983, 10720, 10721, 21835, 10722, 10723, 10724, 21826, 10725, 10726, 10727, 10728,
+		10729, 10730, 10731, 10732, 21837, 10733, 10734, 10735, 10830, 16448, 13215, 10831, 10832,
+		10833, 10834, 21838, 10835, 10836, 10837, 10838, 21836, 10839, 12453, 10840, 10841, 10842,
+		10843, 10844, 10606, 21840, 10845, 10846, 21839, 10847, 10848, 10849, 9844, 10850, 10851,
+		10852, 21841, 21842, 10853, 10854, 10855, 21843, 10856, 10857, 10858, 12091, 10859, 10860,
+		12138, 10861, 10862, 10863, 10864, 10865, 13096, 10866, 10867, 10868, 10869, 21846, 10870,
+		21845, 10871, 10872, 10873, 10874, 10875, 10876, 10877, 10878, 9841, 10879, 10880, 10881,
+		21849, 21844, 10882, 10883, 21847, 10884, 10885, 10886, 10887, 10888, 10889, 21848, 10890,
+		10891, 10892, 10893, 10894, 10895, 10896, 10897, 21946, 10898, 10899, 10900, 10901, 10902,
+		10903, 21948, 11156, 10904, 10905, 21947, 10906, 10907, 10908, 10909, 10910, 10911, 10912,
+		10913, 10914, 10915, 10916, 10917, 10918, 21949, 10919, 10920, 10921, 10922, 10923, 10924,
+		9435, 21951, 21950, 10925, 11020, 11021, 11022, 11023, 11024, 11025, 11026, 11027, 11028,
+		11029, 11319, 11030, 11031, 11032, 11033, 11034, 11035, 11036, 11037, 11038, 11039, 11040,
+		11041, 11042, 11043, 11044, 11045, 11046, 11047, 11048, 11049, 11050, 11051, 11052, 11053,
+		11054, 11055, 11056, 11057, 11058, 11059, 11060, 11061, 11062, 11063, 11064, 11065, 11066,
+		12690, 16880, 11067, 11068, 11069, 11070, 11071, 11072, 12129, 21989, 16484, 11073, 11074,
+		11075, 11076, 11077, 11078, 11079, 11080, 11081, 11082, 11083, 10414, 11084, 11085, 11086,
+		11087, 11088, 11089, 11090, 21991, 12258, 11091, 11092, 13246, 11093, 9839, 16074, 11094,
+		11095, 11096, 21993, 21992, 21994, 14213, 11097, 11098, 21995, 11099, 15898, 16309, 11100,
+		11101, 11102, 11103, 10419, 11104, 11105, 11106, 12279, 11107, 11108, 12282, 21996, 11697,
+		11109, 11110, 11111, 11112, 11113, 9851, 11114, 11115, 21997, 21998, 11696, 11210, 11211,
+		21999, 11212, 11213, 11214, 22004, 11215, 22000, 22003, 11216, 11018, 11217, 22001, 22002,
+		11218, 11219, 11220, 11221, 11222, 11223, 11224, 10611, 14384, 22005, 11225, 9463, 11226,
+		11227, 12686, 11228, 11229, 15932, 11898, 11230, 11231, 11232, 11233, 11234, 11235, 11236,
+		22006, 11237, 11238, 11239, 11240, 11241, 11242, 13035, 11243, 11244, 11245, 11246, 11247,
+		11248, 11249, 11250, 22018, 11251, 11252, 14936, 11253, 11254, 11255, 11256, 11257, 11258,
+		11259, 11260, 11261, 11262, 11263, 11264, 11265, 11266, 14766, 11267, 11268, 11269, 11270,
+		11271, 11272, 11273, 11274, 11275, 11276, 11277, 11278, 11279, 11280, 11281, 11282, 11283,
+		11284, 11285, 11286, 11287, 14211, 11288, 14242, 11289, 11290, 11291, 11292, 11579, 11293,
+		11294, 11295, 16482, 11296, 11297, 11298, 12124, 11299, 11300, 11301, 11302, 11303, 11304,
+		11305, 11400, 11401, 11402, 11403, 11404, 11405, 11406, 11407, 11408, 11409, 11410, 11411,
+		11412, 11413, 11414, 11415, 11416, 11417, 11418, 11419, 11420, 11421, 11422, 11423, 11424,
+		11425, 11426, 11427, 11428, 11429, 11430, 11431, 11432, 11433, 11434, 11435, 11436, 11437,
+		11438, 11439, 11440, 11441, 11442, 11443, 11444, 11445, 11446, 11447, 11448, 11449, 11450,
+		11451, 11452, 11453, 11454, 15190, 11455, 11456, 11457, 11458, 11459, 11460, 11461, 11462,
+		22019, 11463, 11464, 11465, 11466, 11467, 11468, 11469, 11470, 11471, 11472, 11473, 11474,
+		11475, 11476, 11477, 11478, 11479, 11480, 11481, 11482, 11483, 11484, 11485, 11486, 11487,
+		11488, 11489, 11490, 11491, 11492, 11493, 11494, 11495, 11590, 11591, 11592, 11593, 11594,
+		11595, 11596, 11597, 11598, 11599, 11600, 11601, 11602, 22020, 11603, 11604, 11605, 11606,
+		11607, 11608, 11609, 22021, 11610, 11611, 11612, 11613, 11614, 11615, 11616, 11617, 11618,
+		11619, 11620, 11621, 11622, 11623, 11624, 11625, 11626, 11627, 11628, 11629, 11630, 11631,
+		11632, 11633, 11634, 11635, 11636, 11637, 11638, 11639, 11640, 11641, 11642, 11643, 11644,
+		11645, 11646, 11647, 11648, 11649, 11650, 11651, 11652, 11653, 11654, 11655, 11656, 11657,
+		11658, 11659, 11660, 11661, 11662, 11663, 11664, 11665, 11666, 11667, 11668, 11669, 11670,
+		11671, 11672, 11673, 11674, 11675, 11676, 11677, 11678, 11679, 11680, 11681, 11682, 11683,
+		11684, 11685, 11780, 11781, 11782, 11783, 11784, 11785, 11786, 11787, 11788, 11789, 11790,
+		11791, 11792, 11793, 11794, 11795, 11796, 11797, 11798, 11799, 11800, 11801, 11802, 11803,
+		11804, 11805, 11806, 11807, 11808, 11809, 11810, 11811, 11812, 11813, 11814, 11815, 11816,
+		11817, 11818, 11819, 11820, 11821, 11822, 11823, 11824, 11825, 11826, 11827, 11828, 11829,
+		11830, 11831, 11832, 11833, 11834, 11835, 11836, 11837, 11838, 11839, 11840, 11841, 11842,
+		11843, 22582, 11844, 11845, 11846, 11847, 11848, 10372, 11849, 11850, 11851, 11852, 11853,
+		22022, 11854, 11855, 11856, 11857, 11858, 11859, 11860, 11861, 11862, 11863, 11864, 11865,
+		11866, 11867, 11868, 11869, 11870, 11871, 11872, 11873, 11874, 11875, 11970, 11971, 11972,
+		11973, 11974, 11975, 11976, 11977, 11978, 11979, 11980, 11981, 11982, 11983, 11984, 11985,
+		11986, 11987, 11988, 11989, 11990, 11991, 11992, 11993, 11994, 11995, 11996, 11997, 11998,
+		11999, 12000, 12001, 12002, 12003, 12004, 12005, 16510, 12006, 12007, 12008, 12009, 12010,
+		12011, 12012, 12013, 12014, 12015, 12016, 12017, 12018, 12019, 12020, 12021, 12022, 12023,
+		12024, 12025, 12026, 12027, 12028, 12029, 22023, 12030, 12031, 12032, 19374, 11717, 19375,
+		11001, 19376, 14958, 19377, 15893, 11327, 19378, 19379, 11350, 13635, 14744, 19476, 12033,
+		9816, 19477, 13795, 10602, 12866, 12034, 16496, 12503, 10410, 16274, 14763, 10391, 12035,
+		12036, 13021, 19478, 14978, 19479, 19480, 19481, 12278, 16508, 13855, 14939, 16260, 16298,
+		19482, 9102, 19483, 19484, 13835, 15564, 11698, 19485, 9108, 13647, 11560, 19486, 13623,
+		12037, 19487, 11185, 10633, 15299, 19488, 12516, 11773, 11537, 14554, 19489, 19490, 11763,
+		15174, 12038, 14226, 14393, 11349, 19491, 11313, 15192, 19492, 12039, 15193, 19493, 19494,
+		9819, 19495, 19496, 13869, 14737, 12699, 19498, 9248, 9676, 12040, 19499, 19500, 16494,
+		16087, 19501, 12489, 16461, 19502, 19503, 19504, 11379, 12704, 12099, 19505, 19506, 11314,
+		12041, 19507, 19508, 19497, 10213, 19509, 12042, 19510, 19511, 11141, 10037, 12483, 9279,
+		19512, 15886, 19513, 10576, 19515, 19514, 10431, 12043, 19516, 9493, 19517, 19518, 19519,
+		19520, 19521, 19522, 19523, 15682, 14240, 19524, 19525, 19526, 19527, 13816, 19528, 19529,
+		19530, 19531, 19532, 11536, 19533, 21785, 12044, 10600, 12045, 13602, 12046, 12047, 12048,
+		12049, 12050, 12051, 12052, 21786, 12053, 21787, 21788, 12054, 12055, 12056, 12057, 12058,
+		12059, 12060, 12061, 12062, 12063, 10797, 14623, 12064, 12065, 16697, 10942, 12160, 12507,
+		20828, 12161, 10358, 12162, 12163, 12164, 12165, 20830, 12166, 20829, 9078, 12167, 12168,
+		12169, 12170, 12171, 20832, 16109, 16514, 12172, 12173, 12174, 16280, 12175, 12176, 20834,
+		14045, 12177, 20833, 12178, 12179, 12180, 12181, 20835, 12182, 12183, 12184, 12185, 20837,
+		12186, 12187, 20836, 12188, 12189, 12190, 12191, 12192, 12193, 12194, 12195, 15377, 12196,
+		13423, 12197, 12667, 12198, 12199, 12200, 12201, 12202, 10610, 12203, 12204, 12205, 12206,
+		12207, 12326, 12208, 12209, 21983, 15168, 21984, 12210, 14974, 12211, 12212, 13611, 12213,
+		12214, 21985, 12215, 12216, 12217, 12218, 12219, 12220, 12221, 21986, 21987, 12222, 21988,
+		12223, 12224, 12225, 12226, 12227, 16905, 10639, 12228, 12229, 19176, 15755, 12230, 22009,
+		12231, 14769, 12232, 12233, 12234, 9660, 12235, 12236, 12237, 12238, 21395, 12239, 15563,
+		12240, 22010, 12241, 12242, 12243, 12244, 12245, 14987, 22011, 12246, 12247, 13439, 12248,
+		12249, 12250, 12251, 12252, 12253, 10027, 9853, 22013, 12254, 12255, 12350, 22012, 22014,
+		12351, 12352, 22015, 12353, 12354, 12355, 12356, 22016, 12357, 10943, 9056, 12358, 22017,
+		12359, 12360, 12361, 12362, 12363, 12364, 12365, 10368, 15562, 12366, 12367, 12368, 15496,
+		12112, 12369, 11903, 20238, 16118, 20085, 12370, 12371, 12372, 12373, 21458, 10253, 14158,
+		12374, 12375, 12870, 12376, 21446, 12377, 21447, 10636, 21448, 10961, 15902, 9075, 12378,
+		12379, 21449, 12380, 12381, 12382, 21450, 12383, 21451, 12384, 12385, 21452, 21453, 21454,
+		21456, 21455, 13068, 12386, 12387, 12388, 12389, 12390, 12391, 21457, 12392, 10255, 12393,
+		21459, 15499, 17804, 14197, 12394, 12395, 9655, 12396, 9977, 12397, 10737, 12398, 12399,
+		12909, 21460, 12400, 12401, 21461, 12402, 12403, 12404, 12291, 12346, 16261, 21462, 12405,
+		12406, 12407, 12408, 21463, 12409, 12265, 12410, 12411, 12412, 13229, 12413, 11744, 12414,
+		12415, 12416, 12417, 12418, 12419, 12420, 12421, 12422, 12423, 12424, 12425, 12426, 12427,
+		21464, 9832, 12428, 12429, 12430, 12431, 12432, 12433, 21465, 12434, 12435, 12436, 12437,
+		12438, 12439, 12440, 12441, 12442, 12443, 12444, 12445, 12540, 20633, 20632, 12541, 12542,
+		14219, 15547, 12543, 14188, 16111, 12544, 13651, 12545, 12128, 11306, 12546, 12547, 12548,
+		12549, 12550, 12551, 20268, 12552, 12553, 15109, 12554, 16311, 12555, 10204, 10601, 20267,
+		10591, 12556, 20266, 9601, 10776, 16256, 12557, 10439, 10397, 12558, 12559, 12560, 11376,
+		10385, 20273, 12561, 20274, 9050, 11922, 12562, 20272, 15768, 12563, 20275, 12564, 12565,
+		20276, 12566, 12567, 10401, 12568, 20269, 20271, 13861, 16300, 16100, 15123, 20282, 14749,
+		20283, 12569, 9983, 12570, 12571, 12572, 12573, 12574, 9234, 20285, 14350, 12575, 12576,
+		12577, 12578, 12579, 12580, 12581, 13069, 20286, 12582, 20284, 13078, 20281, 13969, 20288,
+		9116, 12583, 12584, 11015, 12585, 12586, 16648, 21442, 12587, 20277, 20278, 20279, 20280,
+		20289, 19317, 20292, 12588, 11947, 15525, 20290, 20295, 10621, 20291, 12589, 11521, 12590,
+		15161, 12591, 9048, 12592, 20296, 12885, 12593, 12594, 12595, 12596, 16257, 12597, 12598,
+		12599, 9849, 12600, 12601, 12531, 11330, 12602, 12603, 20293, 20294, 15929, 13279, 12877,
+		20298, 13022, 16900, 12604, 9422, 12605, 20303, 12606, 11532, 12607, 12608, 12609, 20301,
+		12610, 12611, 12612, 12613, 12614, 12615, 12616, 12617, 12618, 12619, 12620, 12621, 12622,
+		20302, 12623, 12624, 10557, 12625, 14584, 20304, 12626, 12627, 12628, 20300, 12629, 12274,
+		12630, 12631, 12632, 12633, 12634, 13207, 12635, 12730, 12731, 12732, 12733, 12734, 12735,
+		14428, 12736, 20305, 12737, 12081, 15509, 20306, 12738, 12739, 12740, 10560, 10558, 12741,
+		20307, 13422, 14618, 12742, 12743, 12744, 20309, 20310, 12745, 12746, 12747, 12748, 12749,
+		20312, 12750, 12751, 12752, 12753, 15145, 12754, 20317, 12755, 20313, 12756, 12757, 12758,
+		20316, 13674, 12759, 15387, 20311, 12760, 12761, 20308, 12762, 12763, 12764, 12765, 10570,
+		14972, 12894, 20314, 20315, 14403, 14575, 9107, 12766, 20321, 12767, 12768, 12769, 12770,
+		12771, 20320, 12772, 9423, 12773, 12774, 12775, 12776, 10609, 12777, 20322, 12778, 12779,
+		12780, 12781, 12782, 12783, 9291, 12784, 12785, 14382, 12829, 14920, 12786, 12787, 12788,
+		12789, 12790, 20324, 12791, 12792, 20427, 12793, 13097, 12794, 20325, 12795, 12796, 12797,
+		12798, 12799, 12800, 12801, 12802, 13812, 12803, 12804, 12805, 12806, 12807, 12808, 18588,
+		20329, 12809, 12810, 12811, 12812, 14581, 20426, 9274, 15698, 12813, 12814, 15545, 12815,
+		12816, 12817, 20328, 12818, 20326, 12819, 12820, 12821, 12822, 12823, 12824, 12825, 12920,
+		12921, 12922, 12923, 12924, 12925, 12926, 12927, 12928, 12929, 12930, 12931, 12932, 12933,
+		12934, 9623, 12935, 12936, 12937, 20061, 12938, 12939, 16487, 12940, 21956, 9679, 12941,
+		12942, 12943, 12944, 12945, 16278, 16279, 12946, 12947, 12948, 12949, 12950, 12951, 16128,
+		11727, 12952, 21952, 12953, 15493, 21953, 21954, 12954, 21955, 11728, 15738, 12955, 12956,
+		12957, 12958, 12959, 13839, 13840, 12960, 12961, 21789, 12962, 14035, 12963, 14427, 12964,
+		12965, 12966, 12967, 12968, 12969, 18547, 14175, 12970, 14797, 16304, 12971, 21958, 21959,
+		21960, 12972, 12973, 12974, 12975, 21963, 12976, 10954, 21964, 9092, 21961, 12977, 21962,
+		11497, 9458, 12978, 21967, 21968, 10235, 9421, 14962, 21965, 9797, 12979, 21966, 12980,
+		12981, 21969, 12982, 12983, 12984, 12985, 12986, 21970, 12987, 12988, 14542, 12989, 21971,
+		12990, 21972, 12991, 12992, 12993, 21973, 12994, 12995, 12996, 12997, 12998, 12999, 13000,
+		13001, 14205, 13002, 21974, 13003, 13004, 13005, 13006, 21975, 13007, 13008, 13009, 13010,
+		13011, 13012, 13013, 13014, 21976, 13015, 13110, 13111, 13112, 13113, 22007, 12283, 11377,
+		13114, 13786, 15357, 19180, 13115, 13116, 13117, 13118, 17404, 15538, 13119, 13120, 17405,
+		9037, 17406, 13121, 13122, 11553, 13123, 17410, 13124, 13125, 13126, 16659, 13127, 17408,
+		15758, 13128, 13829, 17411, 17407, 13129, 17412, 12637, 13130, 13131, 13132, 13133, 17413,
+		17422, 17414, 13134, 13135, 14788, 16250, 13136, 17432, 13137, 17430, 13138, 13139, 17435,
+		11565, 12456, 13140, 17409, 17428, 17431, 17415, 10405, 9062, 17424, 15135, 17418, 11123,
+		13141, 10383, 17429, 13142, 13143, 17423, 17416, 13454, 13144, 13145, 13146, 15326, 17417,
+		13147, 13148, 17427, 13149, 13150, 17433, 13151, 13152, 14738, 17419, 13153, 17420, 17425,
+		17426, 9457, 17434, 14208, 13154, 15888, 17444, 17448, 14351, 17455, 13155, 12706, 17445,
+		13156, 13157, 11908, 17442, 13158, 9115, 10757, 17454, 17436, 13159, 17421, 17439, 13669,
+		11939, 13160, 13161, 13162, 13163, 13802, 13164, 13165, 13166, 9244, 13167, 15677, 13168,
+		13169, 17443, 13170, 13171, 17438, 13172, 13232, 13173, 17447, 13174, 13175, 13176, 13177,
+		13178, 16469, 12650, 10377, 13444, 12644, 17451, 17441, 17460, 17437, 13179, 13180, 17446,
+		13181, 11690, 17440, 13182, 17449, 13183, 13184, 17452, 17453, 13185, 17473, 13186, 13187,
+		17450, 17577, 17456, 13188, 13189, 13190, 13191, 13192, 13193, 13194, 13195, 13196, 13197,
+		11380, 9821, 13198, 13199, 12638, 9476, 17475, 13200, 17467, 13201, 17464, 13202, 17477,
+		17463, 15565, 9477, 13203, 13640, 13652, 17476, 13204, 17462, 13205, 13300, 13301, 17472,
+		13302, 13303, 17470, 13304, 13305, 11688, 17469, 13306, 9466, 13307, 13308, 13309, 13310,
+		13311, 17468, 11391, 17457, 11151, 13312, 12148, 13313, 13314, 13315, 13316, 13317, 11357,
+		17458, 17459, 13318, 17466, 17471, 17474, 9995, 13319, 13642, 11186, 17479, 17478, 15686,
+		17576, 17578, 17580, 15566, 17579, 17581, 17582, 15494, 13320, 13321, 13322, 13323, 13324,
+		13325, 13326, 10966, 17584, 13327, 13328, 17597, 17592, 17595, 13329, 13330, 13331, 13332,
+		13333, 13334, 13335, 17591, 13251, 13336, 13337, 12147, 13338, 13339, 13340, 13341, 13790,
+		13342, 13343, 13344, 17461, 17589, 13345, 13346, 13347, 13348, 17598, 13349, 13350, 17465,
+		17590, 13351, 17599, 13352, 17587, 13353, 13354, 13355, 13356, 13357, 13358, 13359, 17600,
+		17594, 17588, 12835, 13360, 13361, 13362, 13363, 17583, 12084, 12266, 17585, 17586, 13364,
+		17593, 11196, 17596, 15683, 17601, 13365, 17602, 12642, 13366, 13367, 17625, 17603, 13368,
+		13369, 13370, 17624, 13371, 10764, 13372, 13373, 11738, 13374, 11775, 13375, 13376, 10967,
+		13377, 13378, 13379, 13380, 17617, 13381, 17612, 13382, 17606, 13383, 13384, 13385, 9448,
+		17610, 13386, 17618, 9409, 17628, 13387, 13388, 13389, 17605, 13390, 13391, 13392, 13253,
+		17623, 13393, 13394, 13395, 13490, 13491, 17627, 12321, 10393, 13492, 13493, 13494, 13495,
+		13496, 17621, 17622, 13497, 13498, 13499, 17611, 13500, 13501, 13502, 17604, 13503, 17620,
+		14394, 13504, 17616, 13505, 13506, 13507, 13508, 17609, 12675, 13233, 14739, 17619, 13509,
+		17615, 13510, 13511, 13512, 13513, 13514, 13515, 17608, 13516, 13517, 13518, 17613, 12505,
+		13519, 13520, 13521, 13522, 13523, 13524, 15684, 15685, 17626, 14999, 13673, 13525, 13526,
+		13527, 13528, 13529, 13530, 13531, 13532, 17645, 13533, 13534, 13535, 13536, 13537, 13538,
+		17614, 13539, 13540, 13541, 17639, 12513, 13542, 13543, 13544, 13545, 13546, 13547, 13548,
+		13549, 17640, 13550, 13551, 13552, 13553, 13554, 13555, 13556, 13557, 13558, 13559, 17630,
+		13560, 13561, 13562, 13563, 13564, 16333, 13565, 17632, 17631, 10625, 17629, 13566, 13567,
+		13568, 13569, 13252, 13570, 10178, 13571, 13572, 13573, 13574, 13575, 17641, 13576, 11014,
+		15930, 17646, 13577, 13578, 13579, 9833, 13580, 17633, 13581, 11966, 17642, 13582, 17638,
+		13583, 17636, 13584, 13585, 13680, 13681, 13682, 13683, 13684, 10032, 13685, 13686, 13687,
+		13688, 17634, 17635, 17637, 13689, 11510, 17643, 13690, 17644, 13691, 13692, 13693, 13694,
+		13695, 13696, 13697, 13698, 17660, 13699, 12676, 13700, 13701, 14221, 13702, 13703, 13704,
+		13705, 17657, 13706, 13707, 13708, 13709, 13710, 13711, 13712, 13713, 13714, 13715, 13716,
+		13717, 13718, 16690, 13719, 13720, 13254, 13721, 17659, 13722, 13723, 13724, 16139, 17658,
+		17655, 13725, 13726, 17651, 13727, 17654, 13728, 17647, 13729, 13730, 15183, 13731, 13732,
+		13733, 13734, 13641, 17653, 13735, 13736, 17648, 13737, 13738, 17649, 14237, 13739, 17652,
+		13740, 13741, 9260, 13742, 13743, 13744, 13745, 13746, 13747, 12087, 13748, 11332, 17656,
+		13749, 13750, 17662, 13751, 17661, 17650, 13752, 13753, 13754, 13755, 13756, 13093, 13757,
+		13758, 13759, 17666, 13760, 13761, 13762, 13763, 13764, 13765, 13766, 13767, 13768, 13769,
+		13770, 17769, 13771, 13772, 17768, 13773, 13774, 13775, 13870, 13871, 13872, 13873, 13874,
+		13875, 13876, 13877, 13878, 17663, 13879, 13880, 13881, 13882, 12714, 13883, 12536, 13884,
+		13885, 13886, 16120, 13887, 13888, 14746, 13889, 13890, 13891, 13892, 17668, 13893, 9449,
+		13894, 13895, 13896, 13897, 13898, 13899, 13900, 13901, 13902, 12897, 14028, 13903, 13904,
+		13905, 13906, 13907, 13908, 13909, 13910, 13911, 13912, 13425, 17665, 17667, 17669, 17767,
+		9035, 9261, 13913, 13914, 13915, 13916, 13917, 17777, 13918, 13919, 13920, 13921, 17771,
+		11517, 13664, 13922, 13923, 13924, 13925, 13926, 13927, 13928, 13929, 13930, 13931, 13932,
+		17766, 13933, 13934, 17770, 13935, 13936, 13937, 13938, 17774, 13939, 13940, 13941, 13942,
+		13943, 17773, 13944, 13945, 13946, 17772, 13947, 13948, 13949, 13950, 13951, 13952, 13953,
+		13954, 13955, 17778, 13956, 15909, 13957, 13958, 13959, 13960, 17784, 17775, 17779, 13961,
+		13962, 12122, 13963, 13964, 13965, 14060, 14061, 9216, 17787, 14062, 17782, 14063, 14064,
+		14065, 14066, 14067, 14068, 14069, 17783, 14070, 14071, 14072, 14073, 14074, 14075, 14076,
+		14077, 14078, 14079, 14080, 15301, 17786, 14081, 14082, 14083, 14084, 14085, 14086, 14087,
+		17780, 14088, 14089, 14090, 17781, 14091, 15134, 14092, 14093, 14094, 17785, 14042, 17790,
+		14095, 14096, 14097, 14098, 14099, 14100, 17789, 14101, 17788, 14102, 14103, 14104, 14105,
+		14106, 14107, 14108, 17792, 14109, 14110, 14111, 14112, 14113, 14114, 14115, 11564, 14116,
+		14117, 14118, 14119, 14120, 9461, 12709, 14121, 14122, 17791, 14123, 13041, 14124, 14125,
+		14126, 14127, 14128, 14129, 17793, 14130, 14131, 14132, 14133, 14134, 14135, 14136, 14402,
+		14137, 14138, 14139, 14140, 10365, 14141, 14142, 14143, 14144, 14145, 14146, 14147, 14148,
+		14149, 14150, 14151, 14152, 14153, 14154, 14155, 14250, 14251, 15934, 14252, 14253, 14254,
+		17794, 14255, 14256, 14257, 14258, 14259, 17796, 14260, 14261, 14262, 14263, 14264, 14265,
+		14266, 14267, 14268, 14269, 14270, 12827, 14271, 14272, 14273, 14274, 17798, 14275, 14276,
+		14277, 14278, 14279, 14280, 14281, 14282, 14283, 14284, 14285, 14286, 14287, 14288, 14289,
+		14290, 17795, 14291, 17797, 14292, 14293, 14294, 14295, 14296, 14297, 14298, 14299, 14300,
+		14301, 14302, 14303, 14304, 14305, 16081, 14306, 14307, 14308, 17799, 14309, 14310, 14311,
+		14312, 14313, 14314, 14315, 14316, 14317, 14318, 14319, 14320, 14321, 14322, 14323, 14324,
+		21579, 11117, 12463, 13031, 12484, 14325, 14326, 21580, 14327, 14328, 14329, 14330, 14331,
+		15178, 14332, 14333, 14334, 15736, 14335, 14336, 14337, 20264, 14338, 14339, 14340, 14341,
+		14342, 14343, 14344, 14345, 9665, 21581, 14440, 21582, 14441, 14442, 13978, 14443, 14444,
+		14445, 14446, 14447, 14448, 14449, 10996, 21584, 21586, 21585, 14223, 14941, 21583, 13985,
+		15532, 12521, 14450, 14451, 14452, 14453, 14454, 14455, 14456, 14760, 21589, 9111, 21588,
+		14457, 14458, 14459, 14460, 14461, 21595, 14462, 9452, 14463, 14464, 14465, 14466, 14467,
+		14468, 15328, 21591, 14469, 14470, 14471, 14472, 14473, 21593, 15938, 14474, 14475, 21592,
+		21587, 21596, 21594, 14476, 21590, 14477, 14478, 21604, 21601, 21603, 14479, 14480, 21607,
+		21599, 21597, 14481, 14482, 14483, 21602, 14484, 14485, 14486, 14487, 14488, 16336, 14489,
+		14490, 14491, 21598, 14492, 13484, 13838, 14493, 21605, 10773, 9991, 14494, 14495, 21600,
+		21606, 21613, 21619, 14496, 14497, 11170, 14498, 14499, 14500, 21618, 14595, 14501, 16320,
+		14502, 14503, 21615, 21617, 14504, 14505, 14506, 14507, 10627, 14508, 14509, 14510, 14511,
+		21608, 14512, 14513, 14514, 21611, 12534, 14515, 16116, 21609, 21610, 21612, 21616, 14516,
+		14517, 14518, 21622, 15703, 14519, 14520, 14521, 14522, 10239, 14523, 14046, 14524, 10423,
+		21620, 14525, 14526, 14527, 21621, 21623, 21626, 21624, 14528, 14529, 21625, 14530, 14531,
+		14532, 14533, 15344, 21614, 14534, 14576, 14535, 14772, 16254, 14630, 21632, 14631, 12694,
+		14632, 21629, 14633, 14634, 12080, 21641, 21627, 14635, 21630, 14636, 14637, 14638, 21637,
+		14639, 14640, 14641, 14642, 21631, 14643, 14644, 21636, 14645, 14646, 21635, 14647, 14648,
+		21638, 14649, 14650, 14651, 21628, 14652, 14653, 21633, 21639, 14654, 14655, 14656, 14657,
+		14658, 14659, 14660, 15687, 21634, 9490, 14661, 14662, 21647, 14663, 15116, 14664, 14665,
+		14666, 14667, 21650, 14668, 14669, 14670, 11155, 14671, 21654, 14672, 14673, 14674, 14675,
+		14676, 14677, 21645, 14678, 14679, 21651, 21653, 21655, 14680, 14681, 14682, 14683, 14684,
+		14685, 14686, 14687, 21648, 14688, 21646, 14689, 14690, 14691, 11016, 14692, 10066, 14693,
+		14694, 14695, 14696, 21644, 21652, 21642, 21643, 14697, 14698, 14699, 21640, 21761, 14700,
+		21758, 14701, 14702, 21757, 14703, 14704, 21649, 14705, 13643, 14706, 14707, 14708, 14709,
+		14710, 21656, 14711, 14712, 14713, 21760, 14714, 14715, 14716, 14717, 14718, 14719, 14720,
+		12723, 14721, 14722, 14723, 14724, 14725, 14820, 14821, 14822, 21658, 14823, 14824, 21762,
+		21764, 21759, 14825, 21657, 14826, 14827, 14828, 21766, 14829, 21765, 14830, 14831, 14832,
+		14833, 12506, 14834, 14835, 21769, 14836, 14837, 21771, 14838, 14839, 14840, 14841, 14842,
+		21756, 14843, 14844, 14845, 21772, 21767, 14846, 14847, 14848, 14849, 14850, 21770, 21659,
+		21768, 14851, 14852, 14853, 14854, 14855, 14856, 14857, 21773, 14858, 14859, 14860, 14861,
+		21775, 14862, 14863, 14864, 14865, 21763, 14866, 14867, 14868, 14869, 21774, 14870, 14871,
+		14872, 21776, 14873, 14874, 14875, 14876, 14877, 14878, 14879, 14880, 14881, 14882, 15128,
+		14883, 14884, 14885, 14886, 21779, 14887, 14888, 14889, 14890, 16904, 14891, 14892, 14893,
+		14894, 14895, 14896, 21780, 14897, 14898, 14899, 14900, 14901, 14902, 14903, 14904, 21778,
+		14905, 13653, 21777, 14906, 14907, 14908, 14909, 21781, 14910, 14911, 14912, 14913, 14914,
+		21782, 9817, 14915, 15010, 15011, 15012, 15013, 15014, 15015, 15016, 15017, 15018, 15019,
+		15020, 15021, 15022, 15023, 20840, 15024, 15025, 15026, 15027, 15028, 15029, 21783, 15030,
+		15031, 21784, 15032, 15033, 15034, 15305, 15035, 15036, 15037, 21957, 15143, 15038, 15039,
+		15040, 15041, 15042, 15043, 15153, 15355, 15044, 15045, 15046, 15047, 15048, 15049, 14961,
+		15050, 15051, 11549, 15052, 15330, 15053, 15054, 15055, 15056, 15057, 15058, 15059, 10991,
+		18408, 15519, 21409, 9430, 15060, 15061, 9292, 21410, 15062, 13807, 9631, 15063, 16897,
+		15064, 14160, 15065, 21411, 15066, 15067, 15068, 15069, 16297, 15070, 15071, 15072, 15073,
+		15074, 21412, 21977, 21413, 15075, 15877, 21414, 15076, 9057, 21978, 15077, 15078, 21979,
+		15079, 15080, 9877, 15081, 13074, 15082, 15083, 15084, 15085, 14372, 15086, 15087, 15088,
+		15173, 15089, 15090, 15091, 15092, 15093, 14599, 15094, 15095, 15096, 15097, 15098, 21415,
+		15099, 16898, 15100, 15101, 15102, 15103, 15104, 15105, 9242, 15200, 14929, 15201, 15202,
+		15203, 10549, 15204, 15205, 15206, 15207, 15208, 21417, 15209, 15210, 15211, 15212, 21418,
+		15213, 15214, 15215, 15216, 9440, 12304, 15217, 15218, 16451, 21416, 15219, 15220, 21419,
+		15221, 15222, 15223, 15224, 21421, 15225, 15226, 15227, 16901, 15228, 15550, 15868, 15229,
+		15230, 21980, 13610, 15231, 15232, 15233, 15234, 15235, 21981, 15236, 15237, 21420, 21422,
+		11942, 21423, 15238, 15239, 21427, 15240, 15241, 15242, 15243, 15244, 15245, 15246, 21429,
+		21424, 15247, 13824, 13080, 15248, 15249, 15250, 12512, 10827, 15251, 15252, 21426, 15253,
+		21428, 15254, 15255, 15256, 10786, 15257, 15258, 15259, 15260, 15261, 15262, 15263, 21434,
+		15264, 15265, 15266, 15267, 15268, 10979, 15269, 9118, 21432, 15270, 15271, 15272, 15273,
+		15274, 21431, 21425, 21433, 15275, 15276, 15277, 15278, 15279, 21430, 15280, 15281, 15282,
+		13661, 15283, 15284, 15285, 15286, 14577, 21436, 15287, 15288, 15289, 15290, 19124, 15291,
+		15292, 15293, 21435, 15294, 21437, 15295, 15390, 15391, 15392, 15393, 15394, 15395, 15396,
+		15397, 15398, 21438, 15399, 15400, 14984, 15401, 15402, 15403, 15404, 15405, 15406, 15407,
+		15408, 15409, 15410, 15411, 15412, 15413, 15414, 15415, 15416, 15417, 15418, 15419, 15420,
+		15421, 15422, 15423, 15424, 15425, 21982, 11578, 15426, 15427, 15428, 15429, 15430, 15431,
+		21439, 15432, 15433, 15434, 15435, 15436, 15437, 15438, 15439, 15440, 15441, 15442, 15443,
+		15444, 15445, 15446, 15447, 15448, 15449, 15450, 15451, 21440, 15452, 15453, 15454, 14812,
+		15455, 15495, 15456, 21466, 15457, 15458, 10563, 15459, 15460, 15461, 15462, 15463, 15464,
+		15465, 15466, 15467, 15468, 15469, 15470, 15471, 15472, 15473, 15474, 15475, 15476, 15477,
+		15478, 15479, 15480, 15481, 15482, 15483, 15484, 15485, 15580, 15581, 15582, 15583, 15584,
+		15585, 15586, 15587, 15588, 15589, 15590, 15591, 15592, 15593, 15594, 15595, 15596, 15597,
+		15598, 15599, 15600, 15601, 15602, 15603, 15604, 15605, 15606, 15607, 15608, 15609, 15610,
+		11396, 10794, 15611, 10805, 12692, 14016, 20117, 12097, 11770, 20118, 20119, 20120, 15612,
+		20121, 20122, 20123, 20124, 11534, 15613, 15614, 15615, 22226, 15616, 15617, 15618, 22228,
+		15619, 22229, 15620, 22227, 15621, 15622, 15623, 15624, 11561, 15625, 22326, 9790, 15626,
+		15627, 15628, 15629, 22327, 15630, 15631, 15632, 22328, 15633, 15634, 15635, 20443, 15636,
+		15637, 15638, 15639, 15640, 15641, 15642, 15643, 15644, 15645, 15646, 15647, 15347, 15648,
+		15649, 15650, 15651, 15652, 15653, 16889, 15654, 15655, 15656, 15657, 15658, 15659, 15660,
+		15661, 15662, 15663, 15664, 15665, 15666, 15667, 15668, 15669, 15670, 15671, 15672, 15673,
+		15674, 15675, 15770, 15771, 15772, 15773, 15774, 15775, 15776, 15777, 15778, 15779, 15780,
+		15781, 15782, 15783, 15784, 15785, 15786, 15787, 15788, 15789, 15790, 15791, 15792, 15793,
+		15794, 15795, 15796, 15797, 15798, 15799, 15800, 15801, 22329, 15802, 15803, 15804, 15805,
+		15806, 15807, 15808, 15809, 15810, 20831, 15811, 15812, 15813, 15814, 15815, 15816, 15817,
+		15818, 15819, 15820, 15821, 15822, 15823, 15824, 15825, 15826, 15827, 15828, 15829, 15830,
+		15831, 15832, 15833, 15834, 15835, 15836, 15837, 15838, 15839, 15840, 15841, 15842, 15843,
+		15844, 15845, 15846, 15847, 15848, 15849, 15850, 15851, 15852, 15853, 15854, 15855, 15856,
+		15857, 15858, 16073, 15859, 15860, 15861, 15862, 15863, 15864, 15865, 15960, 15961, 15962,
+		15963, 15964, 15965, 15966, 15967, 15769, 14405, 15968, 15969, 15970, 15971, 15972, 15973,
+		15974, 15975, 14001, 15976, 15977, 15978, 15979, 15980, 15981, 15982, 15983, 15984, 15985,
+		15986, 15987, 15988, 15989, 15990, 15991, 15992, 15993, 15994, 15995, 15996, 15997, 15998,
+		15999, 16000, 16001, 16002, 16003, 16004, 16005, 16006, 16007, 16008, 16009, 16010, 16011,
+		16012, 16013, 16014, 16015, 16016, 16017, 16018, 16019, 16020, 16021, 16022, 16023, 16024,
+		16025, 16026, 16027, 16028, 16029, 16030, 16031, 16032, 16033, 16034, 16035, 16036, 16037,
+		16038, 16039, 16040, 16041, 16042, 16043, 16044, 16045, 16046, 16047, 16048, 16049, 16050,
+		16051, 16052, 16053, 16054, 16055, 16150, 16151, 16152, 16153, 16154, 16155, 16156, 16157,
+		16158, 16159, 16160, 16161, 16162, 16163, 16164, 16165, 16166, 16167, 16168, 16169, 16170,
+		16171, 16172, 16173, 16174, 16175, 16176, 16177, 16178, 16179, 16180, 16181, 16182, 16183,
+		16184, 19127, 16185, 16186, 16187, 16188, 16189, 16190, 16191, 16192, 16193, 16194, 16195,
+		16196, 16197, 16198, 16199, 16200, 16201, 16202, 16203, 16204, 16205, 16206, 16207, 16208,
+		16209, 16210, 16211, 16212, 16213, 16214, 22330, 16215, 16216, 16217, 16218, 16219, 16220,
+		16221, 16222, 16223, 16224, 16225, 16226, 16227, 16228, 16229, 16230, 16231, 16232, 16233,
+		16234, 16235, 16236, 16237, 16238, 16239, 16240, 16241, 16242, 16243, 16244, 16245, 16340,
+		16341, 16342, 16343, 16344, 16345, 16346, 16347, 16348, 16349, 16350, 16351, 16352, 16353,
+		16354, 16355, 16356, 16357, 16358, 16359, 16360, 16361, 16362, 16363, 16364, 16365, 16366,
+		16367, 16368, 16369, 16370, 16371, 11700, 16372, 16373, 16374, 16375, 16376, 13214, 16377,
+		16378, 16379, 16380, 16381, 16382, 16383, 16384, 16385, 16386, 16387, 16388, 16389, 16390,
+		16391, 16392, 16393, 16394, 16395, 16396, 16397, 16398, 16399, 16400, 16401, 16402, 16403,
+		16404, 16405, 16406, 16407, 16408, 16409, 16410, 16411, 16412, 16413, 16414, 16415, 16416,
+		16417, 16418, 16419, 16420, 16421, 16422, 16423, 16424, 16425, 16426, 16427, 17010, 11343,
+		10174, 10573, 13632, 11310, 17011, 17012, 14399, 13620, 17013, 13298, 16428, 15316, 15558,
+		15317, 11344, 16429, 11513, 11183, 17014, 17015, 15335, 17016, 15182, 10244, 12504, 16430,
+		14202, 10432, 13847, 10390, 11772, 16249, 17017, 17018, 13237, 16506, 13987, 16431, 16064,
+		14218, 16134, 17019, 16308, 9827, 17021, 17020, 16432, 15560, 17022, 17023, 17024, 14017,
+		17025, 13976, 17026, 17027, 9642, 16324, 17028, 11131, 9989, 17029, 17030, 10813, 15310,
+		15557, 17031, 10580, 14989, 9484, 17032, 17033, 16433, 11570, 14785, 15754, 17034, 14808,
+		17035, 15728, 11184, 17036, 14176, 14203, 17037, 13470, 16323, 17038, 13036, 10198, 17039,
+		10399, 11921, 17040, 17041, 14168, 17042, 10062, 17043, 12289, 16462, 17044, 14369, 16434,
+		15559, 12842, 17045, 10068, 11164, 17046, 15124, 17047, 17048, 14754, 17049, 17050, 17051,
+		9492, 17054, 17052, 15367, 17053, 12688, 17055, 16435, 17056, 17057, 17058, 15132, 15490,
+		9114, 17059, 13406, 17060, 11582, 12636, 17061, 17062, 12728, 14368, 17063, 17064, 12095,
+		13260, 17065, 17066, 13415, 17067, 17068, 10775, 16530, 16531, 16532, 16533, 16534, 16535,
+		16536, 16537, 16538, 11192, 16539, 16540, 16541, 16542, 10190, 22033, 16543, 22034, 16544,
+		16545, 14602, 16546, 16547, 16548, 16549, 16550, 16551, 16552, 16553, 22160, 16554, 16555,
+		16556, 16557, 20299, 16558, 16559, 16560, 16561, 16562, 16563, 14998, 11146, 16564, 16565,
+		16566, 16567, 16568, 16569, 16570, 10957, 15870, 16571, 16572, 16573, 16574, 16575, 16576,
+		16577, 18392, 16578, 16579, 16580, 16581, 22219, 9225, 9487, 16582, 16583, 16584, 16585,
+		16586, 16587, 16588, 22220, 16589, 16590, 22222, 16591, 16592, 16593, 10974, 22221, 16594,
+		12653, 16595, 16596, 16597, 16598, 16599, 16600, 16601, 22224, 16602, 16603, 16604, 22223,
+		16605, 16606, 16607, 16608, 16609, 16610, 16611, 16612, 16613, 16614, 16615, 16616, 16617,
+		16618, 16619, 16620, 16621, 16622, 16623, 16624, 16625, 16720, 16721, 16722, 16723, 16724,
+		16725, 16726, 16727, 16728, 16729, 16730, 16731, 16732, 16733, 16734, 16735, 16736, 16737,
+		16738, 16739, 16740, 16741, 16742, 16743, 16744, 16745, 16746, 16747, 16748, 16749, 16750,
+		16751, 16752, 16753, 16754, 16755, 16756, 16757, 16758, 16759, 16760, 16761, 16762, 16763,
+		16764, 16765, 16766, 16767, 16768, 16769, 16770, 16771, 16772, 16773, 16774, 16775, 16776,
+		16777, 16778, 16779, 16780, 16781, 16782, 16783, 16784, 16785, 16786, 16787, 16788, 16789,
+		16790, 16791, 16792, 16793, 16794, 16795, 16796, 16797, 16798, 16799, 16800, 16801, 16802,
+		16803, 16804, 16805, 16806, 16807, 16808, 16809, 16810, 16811, 16812, 16813, 16814, 16815,
+		16910, 16911, 16912, 16913, 16914, 16915, 16916, 16917, 16918, 16919, 16920, 16921, 16922,
+		16923, 16924, 9235, 16129, 10571, 16925, 10752, 9443, 15945, 14960, 9085, 16098, 11200,
+		16287, 10378, 14361, 13227, 9280, 10761, 16337, 10800, 10356, 11395, 20103, 20104, 14434,
+		10818, 20105, 9876, 12654, 10404, 10981, 20106, 15949, 20107, 11359, 11178, 20108, 12317,
+		12469, 15928, 16477, 20109, 20110, 20113, 20111, 20112, 13837, 10564, 10201, 20114, 14039,
+		13820, 9830, 16926, 16927, 18582, 13081, 20115, 12086, 16928, 16459, 20116, 16447, 13677,
+		16676, 16670, 15927, 16929, 15954, 13811, 15689, 10596, 9659, 16930, 13841, 22031, 16931,
+		16932, 16933, 10978, 16934, 22032, 16935, 16936, 16498, 16937, 16938, 22026, 10561, 16107,
+		10592, 13285, 16939, 16940, 16941, 16942, 16943, 16944, 16945, 16946, 16947, 9630, 16948,
+		16949, 22027, 9607, 16950, 16951, 16952, 16953, 15894, 13482, 16954, 16955, 16956, 16957,
+		16958, 22029, 16959, 16960, 22028, 16961, 16962, 16963, 16964, 16965, 16966, 16967, 16968,
+		16969, 16970, 14388, 16971, 16972, 16973, 13589, 16974, 16975, 16976, 16977, 16978, 16979,
+		16980, 16981, 16982, 16983, 16984, 16985, 16986, 22030, 16987, 16502, 13046, 22167, 16988,
+		16989, 22162, 16990, 22170, 16991, 22169, 16992, 16271, 22168, 16993, 16994, 16995, 15895,
+		22171, 16996, 22179, 16997, 16998, 16999, 17000, 9072, 10063, 17001, 22176, 22177, 17002,
+		13075, 17003, 17004, 17005, 17100, 22172, 22173, 17101, 17102, 22174, 22178, 17103, 11749,
+		22175, 10635, 17104, 17105, 17106, 22183, 22186, 17107, 17108, 17109, 11946, 17110, 10817,
+		22163, 22180, 17111, 17112, 12468, 17113, 17114, 17115, 14433, 17116, 11394, 17117, 22181,
+		22182, 22184, 10234, 22185, 17118, 22188, 17119, 17120, 17121, 17122, 17123, 17124, 17125,
+		22164, 17126, 17127, 17128, 22187, 15702, 17129, 9671, 17130, 17131, 14349, 17132, 17133,
+		17134, 17135, 22189, 17136, 17137, 17138, 17139, 17140, 17141, 17142, 17143, 22190, 11750,
+		22191, 17144, 17145, 14408, 22194, 17146, 17147, 17148, 17149, 17150, 9445, 16492, 17151,
+		22192, 17152, 22193, 22195, 17153, 22201, 17154, 17155, 17156, 22199, 17157, 17158, 17159,
+		22198, 22196, 17160, 17161, 22200, 17162, 17163, 22197, 22203, 22204, 17164, 14412, 17165,
+		17166, 19126, 9999, 22202, 22207, 14348, 17167, 17168, 17169, 17170, 17171, 22205, 22206,
+		17172, 17173, 17174, 17175, 17176, 17177, 22165, 17178, 17179, 17180, 17181, 17182, 17183,
+		17184, 17185, 17186, 17187, 17188, 17189, 9251, 17190, 17191, 22166, 17192, 17193, 10012,
+		9473, 17194, 22211, 22208, 17195, 10220, 17290, 22212, 17291, 22209, 17292, 17293, 17294,
+		17295, 17296, 22210, 17297, 17298, 9843, 17299, 15939, 17300, 17301, 17302, 22213, 17303,
+		9684, 17304, 17305, 17306, 17307, 17308, 17309, 17310, 22214, 22216, 17311, 17312, 17313,
+		22215, 17314, 17315, 17316, 17317, 17318, 17319, 17320, 22217, 17321, 22218, 17322, 17323,
+		17324, 17325, 17326, 17327, 17328, 17329, 17330, 17331, 17332, 17333, 13852, 10745, 17334,
+		17335, 13486, 17336, 17337, 10232, 17338, 17339, 17340, 17341, 17342, 17343, 17344, 14386,
+		17345, 17346, 17347, 17348, 17349, 17350, 17351, 17352, 17353, 17354, 17355, 17356, 17357,
+		17358, 17359, 17360, 17361, 17362, 17363, 20051, 17364, 17365, 17366, 17367, 17368, 17369,
+		17370, 17371, 17372, 17373, 17374, 17375, 17376, 17377, 17378, 17379, 17380, 17381, 17382,
+		17383, 17384, 17385, 17480, 17481, 17482, 17483, 17484, 17485, 17486, 17487, 17488, 17489,
+		17490, 17491, 17492, 17493, 17494, 17495, 17496, 17497, 17498, 17499, 17500, 17501, 17502,
+		17503, 17504, 17505, 17506, 17507, 17508, 17509, 17510, 17511, 17512, 17513, 17514, 17515,
+		17516, 17517, 17518, 17519, 17520, 17521, 17522, 17523, 17524, 17525, 17526, 17527, 17528,
+		17529, 17530, 17531, 17532, 17533, 17534, 17535, 17536, 17537, 17538, 17539, 17540, 17541,
+		17542, 17543, 17544, 17545, 17546, 17547, 17548, 17549, 17550, 17551, 17552, 17553, 17554,
+		17555, 17556, 17557, 17558, 17559, 17560, 17561, 17562, 17563, 17564, 17565, 17566, 17567,
+		17568, 17569, 17570, 17571, 17572, 17573, 17574, 17575, 17670, 17671, 17672, 17673, 17674,
+		17675, 17676, 17677, 17678, 17679, 17680, 17681, 17682, 17683, 17684, 17685, 17686, 17687,
+		17688, 17689, 17690, 17691, 17692, 17693, 17694, 17695, 17696, 17697, 17698, 17699, 17700,
+		17701, 17702, 9616, 15959, 10811, 15194, 17703, 19932, 16445, 19933, 12499, 13662, 10993,
+		19934, 19935, 19936, 16310, 19937, 19938, 19940, 19939, 19941, 19942, 13460, 19943, 15921,
+		19944, 11541, 17704, 19945, 19946, 11542, 19947, 10553, 12285, 19948, 9233, 11167, 10820,
+		19949, 17705, 20046, 20047, 20048, 10443, 11318, 17706, 14033, 18191, 15880, 14944, 16078,
+		20049, 16117, 20050, 15138, 10763, 17707, 9824, 9272, 17708, 17709, 17710, 12082, 17711,
+		17712, 17713, 17714, 9285, 9286, 17715, 9287, 17716, 17717, 17718, 17719, 9624, 13657,
+		17720, 17721, 17722, 17723, 19128, 17724, 17725, 9278, 17726, 17727, 17728, 12296, 9864,
+		17729, 17730, 13403, 15731, 17731, 13294, 15319, 17732, 10828, 12530, 17733, 17734, 17735,
+		17736, 17737, 15688, 17738, 15908, 11587, 17739, 19129, 10376, 19130, 17740, 17741, 11140,
+		16121, 17742, 11583, 15887, 14730, 12267, 9652, 17743, 17744, 14431, 17745, 19133, 19131,
+		19135, 17746, 19137, 19134, 10022, 13245, 17747, 10067, 19132, 17748, 14051, 17749, 17750,
+		19136, 17751, 17752, 17753, 12687, 9252, 11308, 17754, 17755, 17756, 16458, 17757, 17758,
+		14578, 14200, 14007, 14396, 19139, 19138, 12895, 17759, 17760, 15296, 15318, 19140, 17761,
+		19143, 17762, 14559, 16325, 19142, 10036, 17763, 14565, 17764, 19144, 10191, 17765, 17860,
+		14543, 10803, 17861, 14002, 9644, 14212, 15941, 19145, 10429, 17862, 17863, 17864, 19141,
+		17865, 17866, 17867, 17868, 17869, 17870, 19148, 9879, 19149, 17871, 17872, 17873, 17874,
+		17875, 19146, 19147, 17876, 15546, 17877, 17878, 12508, 9253, 17879, 15741, 17880, 17881,
+		10226, 14231, 17882, 19150, 17883, 17884, 15761, 17885, 17886, 17887, 17888, 17889, 9288,
+		17890, 10249, 19153, 19151, 19152, 10007, 17891, 17892, 17893, 15522, 19155, 17894, 17895,
+		19157, 17896, 17897, 17898, 17899, 17900, 17901, 19156, 13413, 17902, 15488, 17903, 17904,
+		19154, 17905, 17906, 17907, 17908, 15931, 16113, 17909, 17910, 17911, 17912, 17913, 19159,
+		16516, 17914, 17915, 17916, 17917, 17918, 17919, 17920, 19160, 17921, 9275, 15386, 17922,
+		19161, 19163, 17923, 17924, 17925, 17926, 19162, 17927, 17928, 19164, 17929, 17930, 17931,
+		17932, 17933, 15541, 17934, 10018, 17935, 19538, 17936, 17090, 17937, 17093, 17938, 17091,
+		17939, 17092, 17940, 17941, 17942, 17095, 15152, 12864, 17943, 17944, 9103, 17945, 17946,
+		17947, 15121, 17948, 17094, 17949, 15717, 10935, 17197, 13476, 17950, 17097, 17096, 13834,
+		17098, 17951, 17196, 16497, 17099, 12313, 17952, 17953, 17200, 17954, 17955, 15759, 18050,
+		18051, 17202, 17199, 18052, 17203, 18053, 18054, 11523, 18055, 18056, 18057, 12106, 17198,
+		17201, 16248, 18058, 17204, 18059, 18060, 18061, 17208, 18062, 18063, 18064, 17209, 17207,
+		10959, 18065, 18066, 18067, 11879, 17206, 18068, 18069, 18070, 17205, 15904, 9436, 18071,
+		18072, 17210, 18073, 10824, 18074, 17211, 18075, 18076, 18077, 18078, 9622, 18079, 18080,
+		18081, 9981, 18082, 18083, 18084, 18085, 10193, 17212, 18086, 18087, 18088, 10250, 18089,
+		17213, 18090, 18091, 18092, 18093, 18094, 18095, 18096, 18097, 18098, 18099, 18100, 18101,
+		18102, 18103, 18104, 18105, 18106, 18107, 18108, 18109, 9256, 18110, 18111, 18112, 18113,
+		17215, 18114, 18115, 18116, 17214, 17216, 18117, 18118, 18119, 18120, 18121, 18122, 18123,
+		18124, 18125, 18126, 18127, 17218, 18128, 17217, 18129, 18130, 18131, 18132, 18133, 18134,
+		18135, 17219, 18136, 18137, 18138, 18139, 18140, 18141, 18142, 18143, 18144, 17220, 18145,
+		18240, 17221, 18241, 18242, 15722, 22035, 13480, 16470, 13083, 22037, 22038, 22036, 18243,
+		11723, 18244, 18245, 18246, 18247, 15184, 18248, 18249, 10406, 18250, 18251, 15910, 14355,
+		18252, 18253, 22137, 22136, 10933, 22039, 14209, 18254, 18255, 18256, 22139, 12115, 18257,
+		9669, 18258, 14545, 22140, 22138, 11515, 22143, 18259, 22144, 11540, 12659, 11940, 14220,
+		22145, 18260, 18261, 18262, 22141, 22142, 12905, 18263, 18264, 18265, 18266, 18267, 22147,
+		18268, 9813, 18269, 16512, 18270, 9840, 22146, 22149, 18271, 18272, 22148, 22150, 15154,
+		18273, 18274, 18275, 18276, 18277, 18278, 18279, 12684, 13594, 18280, 18281, 18282, 18283,
+		18284, 18285, 22151, 22152, 18286, 18287, 18288, 18289, 18290, 18291, 22153, 18292, 18293,
+		22154, 22155, 22156, 18294, 18295, 18296, 18297, 22158, 22157, 18298, 18299, 18300, 18301,
+		22159, 18302, 18303, 18304, 18305, 18306, 18307, 18308, 18309, 18310, 18311, 18312, 18313,
+		9446, 18314, 15727, 14010, 18315, 12144, 16301, 15501, 12286, 18316, 11575, 18317, 18318,
+		18319, 18320, 18321, 18322, 18323, 18324, 18325, 18326, 10555, 18327, 18328, 18329, 18330,
+		18331, 18332, 18333, 18334, 18335, 18430, 18431, 18432, 18433, 18434, 18435, 18436, 18437,
+		18438, 18439, 18440, 18441, 18442, 18443, 18444, 18445, 18446, 18447, 18448, 18449, 18450,
+		18451, 18452, 18453, 18454, 18455, 18456, 18457, 18458, 18459, 18460, 18461, 18462, 18463,
+		18464, 18465, 18466, 18467, 18468, 18469, 18470, 18471, 18472, 18473, 18474, 18475, 18476,
+		18477, 18478, 18479, 18480, 18481, 18482, 18483, 18484, 18485, 18486, 18487, 18488, 18489,
+		18490, 18491, 18492, 18493, 18494, 18495, 18496, 18497, 18498, 18499, 18500, 18501, 18502,
+		18503, 18504, 18505, 18506, 18507, 18508, 18509, 18510, 18511, 18512, 18513, 18514, 18515,
+		18516, 18517, 18518, 18519, 18520, 18521, 18522, 18523, 18524, 18525, 18620, 18621, 18622,
+		18623, 18624, 18625, 18626, 18627, 18628, 18629, 18630, 18631, 18632, 18633, 18634, 18635,
+		18636, 18637, 18638, 18639, 18640, 18641, 18642, 18643, 18644, 18645, 18646, 18647, 18648,
+		18649, 18650, 18651, 18652, 18653, 18654, 18655, 18656, 18657, 18658, 18659, 18660, 18661,
+		18662, 18663, 18664, 18665, 11393, 18666, 18667, 18668, 18669, 18670, 18671, 18672, 18673,
+		18674, 18675, 18676, 18677, 18678, 18679, 18680, 18681, 18682, 18683, 18684, 18685, 18686,
+		18687, 18688, 18689, 18690, 22363, 18691, 18692, 18693, 18694, 18695, 18696, 18697, 18698,
+		18699, 18700, 18701, 18702, 18703, 18704, 18705, 18706, 18707, 18708, 18709, 18710, 18711,
+		18712, 18713, 18714, 18715, 18810, 18811, 18812, 18813, 18814, 18815, 22364, 18816, 18817,
+		18818, 18819, 18820, 18821, 18822, 18823, 18824, 18825, 18826, 18827, 18828, 18829, 18830,
+		18831, 18832, 18833, 18834, 18835, 18836, 18837, 18838, 18839, 18840, 22365, 18841, 18842,
+		18843, 18844, 18845, 18846, 18847, 18848, 18849, 18850, 18851, 18852, 18853, 18854, 18855,
+		18856, 18857, 18858, 18859, 18860, 18861, 18862, 18863, 18864, 18865, 18866, 18867, 18868,
+		18869, 18870, 18871, 18872, 18873, 18874, 18875, 18876, 18877, 18878, 18879, 18880, 18881,
+		18882, 18883, 18884, 18885, 18886, 18887, 18888, 18889, 18890, 18891, 18892, 18893, 18894,
+		18895, 18896, 18897, 18898, 18899, 18900, 18901, 18902, 18903, 18904, 18905, 19000, 19001,
+		19002, 19003, 19004, 19005, 19006, 19007, 19008, 19009, 19010, 19011, 19012, 19013, 19014,
+		19015, 19016, 19017, 19018, 19019, 19020, 19021, 19022, 19023, 19024, 19025, 19026, 19027,
+		19028, 19029, 19030, 19031, 19032, 19033, 19034, 19035, 19036, 19037, 19038, 19039, 19040,
+		19041, 19042, 19043, 19044, 19045, 19046, 19047, 19048, 19049, 19050, 19051, 22366, 19052,
+		19053, 19054, 19055, 19056, 19057, 19058, 19059, 19060, 19061, 19062, 19063, 19064, 19065,
+		19066, 19067, 19068, 19069, 19070, 19071, 19072, 19073, 19074, 19075, 19076, 19077, 19078,
+		19079, 19080, 19081, 19082, 19083, 19084, 19085, 19086, 19087, 19088, 19089, 19090, 19091,
+		19092, 19093, 19094, 22367, 19095, 19190, 19191, 19192, 19193, 19194, 19195, 19196, 19197,
+		19198, 19199, 19200, 19201, 19202, 19203, 19204, 19205, 19206, 19207, 19208, 19209, 19210,
+		19211, 19212, 19213, 19214, 19215, 19216, 19217, 19218, 19219, 19220, 19221, 19222, 19223,
+		19224, 22369, 19225, 19226, 19227, 19228, 19229, 19230, 19231, 19232, 19233, 19234, 19235,
+		19236, 19237, 19238, 19239, 19240, 19241, 19242, 19243, 19244, 19245, 19246, 19247, 19248,
+		19249, 19250, 19251, 19252, 19253, 19254, 19255, 19256, 19257, 19258, 19259, 19260, 19261,
+		19262, 19263, 19264, 19265, 19266, 19267, 19268, 19269, 19270, 19271, 19272, 19273, 19274,
+		19275, 19276, 19277, 19278, 19279, 19280, 19281, 19282, 22368, 19283, 19284, 19285, 19380,
+		19381, 19382, 19383, 19384, 19385, 19386, 19387, 22588, 19388, 19389, 19390, 19391, 19392,
+		19393, 19394, 19395, 19396, 19397, 19398, 19399, 19400, 19401, 19402, 19403, 19404, 19405,
+		19406, 19407, 19408, 19409, 19410, 19411, 19412, 19413, 19414, 19415, 19416, 19417, 19418,
+		19419, 19420, 19421, 19422, 19423, 19424, 19425, 19426, 19427, 19428, 19429, 19430, 19431,
+		19432, 19433, 19434, 19435, 19436, 19437, 19438, 19439, 19440, 19441, 19442, 19443, 19444,
+		19445, 19446, 19447, 19448, 19449, 19450, 19451, 19452, 19453, 19454, 19455, 19456, 19457,
+		19458, 19459, 19460, 19461, 19462, 19463, 19464, 19465, 19466, 19467, 19468, 19469, 19470,
+		19471, 19472, 19473, 19474, 19475, 19570, 19571, 19572, 19573, 19574, 19575, 19576, 19577,
+		19578, 19579, 19580, 19581, 19582, 19583, 19584, 22370, 19585, 19586, 19587, 19588, 19589,
+		19590, 19591, 19592, 19593, 19594, 19595, 19596, 19597, 19598, 19599, 19600, 19601, 19602,
+		19603, 19604, 19605, 19606, 19607, 19608, 19609, 19610, 19611, 19612, 19613, 19614, 19615,
+		19616, 19617, 19618, 19619, 19620, 19621, 19622, 19623, 19624, 19625, 19626, 19627, 19628,
+		22371, 19629, 19630, 19631, 19632, 19633, 19634, 19635, 19636, 19637, 19638, 19639, 19640,
+		19641, 19642, 19643, 19644, 19645, 19646, 19647, 19648, 19649, 19650, 19651, 19652, 19653,
+		20841, 20842, 20843, 16130, 10169, 20845, 20844, 20846, 20847, 13400, 20848, 20849, 19654,
+		10371, 10061, 20850, 20852, 19655, 20851, 19656, 10583, 20853, 20854, 20855, 10224, 9609,
+		16296, 12863, 9236, 10599, 20856, 20857, 15896, 13448, 11776, 14782, 10754, 20859, 20858,
+		20861, 20860, 13020, 20862, 20863, 13409, 20864, 13410, 20865, 9412, 20866, 20867, 20868,
+		20869, 20870, 16509, 20871, 20872, 11361, 20873, 15718, 14435, 9417, 12324, 20874, 13401,
+		12648, 19657, 20875, 20876, 20877, 20878, 20879, 20880, 20881, 19658, 20882, 20883, 20884,
+		19659, 19660, 20885, 20886, 20887, 20889, 20888, 19661, 20890, 14548, 12478, 20891, 20892,
+		20893, 16056, 20894, 14934, 20895, 20896, 19662, 20897, 20898, 20996, 20899, 20997, 10630,
+		12725, 20998, 20999, 11529, 15516, 9494, 21000, 21001, 21002, 15573, 21003, 16338, 21004,
+		13249, 19663, 21005, 21006, 12275, 21007, 15005, 14243, 21009, 21008, 9685, 10823, 21010,
+		21011, 15171, 21012, 21013, 10425, 15136, 21014, 21015, 21016, 13666, 14409, 21017, 21018,
+		21019, 21020, 21021, 16119, 21022, 9862, 12645, 21023, 19664, 21024, 21025, 21026, 19665,
+		14818, 21027, 12509, 9809, 16457, 11580, 19760, 14954, 21030, 21028, 21029, 21031, 10172,
+		11397, 11751, 12679, 21032, 21033, 19761, 21034, 21042, 21035, 21036, 21037, 13429, 21076,
+		10210, 21038, 19762, 21039, 21040, 10203, 12665, 21041, 19763, 21043, 21044, 21045, 16137,
+		19764, 21046, 12912, 19765, 21047, 12913, 21048, 21049, 10613, 9112, 21050, 21051, 21052,
+		19766, 21053, 21054, 21055, 21056, 19767, 21057, 11706, 21060, 21058, 21059, 19768, 21061,
+		21062, 12300, 21063, 21064, 21065, 21066, 21067, 21068, 21069, 21070, 21071, 12121, 19769,
+		21072, 12268, 21073, 21074, 21075, 19770, 19771, 14981, 19772, 19773, 19774, 19775, 19776,
+		19777, 19778, 19779, 9599, 19780, 19781, 19782, 19783, 19784, 19785, 19786, 19787, 19788,
+		19789, 19790, 19791, 19792, 19793, 19794, 19795, 19796, 19797, 19798, 19799, 19800, 19801,
+		19802, 19803, 19804, 19805, 19806, 19807, 19808, 19809, 19810, 19811, 19812, 19813, 19814,
+		19815, 19816, 19817, 19818, 19819, 19820, 19821, 19822, 19823, 19824, 19825, 19826, 19827,
+		19828, 19829, 19830, 19831, 19832, 19833, 19834, 19835, 19836, 19837, 19838, 19839, 19840,
+		19841, 19842, 19843, 19844, 19845, 19846, 19847, 19848, 19849, 19850, 19851, 19852, 19853,
+		19854, 19855, 19950, 19951, 19952, 19953, 19954, 19955, 19956, 19957, 19958, 19959, 19960,
+		19961, 19962, 19963, 19964, 19965, 19966, 19967, 19968, 19969, 19970, 19971, 19972, 19973,
+		19974, 19975, 19976, 19977, 12672, 18752, 13808, 18753, 19978, 9268, 14767, 9804, 13667,
+		18754, 14963, 18755, 11373, 18756, 18757, 12673, 16057, 12879, 10810, 14762, 18758, 12721,
+		18759, 19979, 10362, 10628, 10975, 18760, 18761, 15901, 18762, 19980, 18763, 15338, 18764,
+		18765, 18766, 18767, 15349, 18768, 9496, 12092, 18769, 19981, 12076, 18770, 18771, 18772,
+		19982, 18773, 18774, 19983, 10568, 17071, 19984, 10216, 19985, 17073, 17072, 19986, 19987,
+		19988, 19989, 19990, 19991, 19992, 17075, 19993, 19994, 19995, 13663, 19996, 19997, 17074,
+		10387, 15379, 15570, 16138, 11550, 19998, 19999, 20000, 20001, 16507, 17077, 17076, 20002,
+		9027, 14586, 20003, 17078, 20004, 10574, 11347, 12474, 12449, 9629, 17079, 20005, 12455,
+		12841, 11516, 20006, 20007, 14977, 20008, 20009, 20010, 17080, 13809, 20011, 20012, 20013,
+		20014, 20015, 9276, 20016, 20017, 20018, 17081, 20019, 10189, 15891, 20020, 9688, 20021,
+		20022, 17082, 15906, 14968, 13082, 20023, 17083, 20024, 20025, 20026, 20027, 20028, 17084,
+		20029, 17085, 12329, 14398, 14976, 20030, 20031, 20032, 20033, 20034, 20035, 20036, 20037,
+		20038, 20039, 20040, 20041, 20042, 15746, 12446, 20043, 17086, 20044, 20045, 14224, 20140,
+		17087, 20141, 14225, 15579, 20142, 20143, 20144, 10629, 20145, 20146, 17088, 9040, 14937,
+		20147, 20148, 16102, 20149, 20150, 20151, 20152, 20153, 20154, 20155, 20156, 20157, 20158,
+		14232, 20159, 20160, 20161, 20162, 20163, 20164, 20165, 20166, 17089, 20167, 20168, 18751,
+		20169, 20170, 12260, 20171, 20172, 22356, 20173, 20174, 22357, 22358, 12874, 20175, 13609,
+		15362, 20176, 20177, 15164, 15332, 11320, 10780, 20178, 21079, 20179, 20180, 9823, 15701,
+		22359, 9686, 20181, 20182, 22360, 20183, 20184, 10056, 20185, 20186, 20187, 20188, 20189,
+		20190, 20191, 20192, 20193, 20194, 22362, 20195, 20196, 20197, 20198, 20199, 20200, 20201,
+		15749, 22332, 15304, 20202, 20203, 20204, 20205, 22334, 20206, 20207, 20208, 22333, 20209,
+		20210, 12322, 12120, 20211, 9217, 20212, 20213, 20214, 20215, 14802, 20216, 15177, 22336,
+		20217, 20218, 15001, 20219, 22335, 16135, 22337, 12660, 20220, 20221, 20222, 11199, 22339,
+		22338, 20223, 20224, 20225, 12887, 20226, 20227, 12311, 20228, 20229, 20230, 20231, 20232,
+		14165, 20233, 14943, 20234, 20235, 20330, 20331, 20332, 20333, 20334, 20335, 20336, 20337,
+		20338, 22340, 20339, 20340, 22341, 20341, 20342, 22342, 20343, 12467, 20344, 20345, 20346,
+		20347, 20348, 9077, 13104, 20349, 20350, 20351, 20352, 22343, 20353, 20354, 20355, 20356,
+		20357, 20358, 20359, 20360, 20361, 20362, 20363, 20364, 20365, 20366, 20367, 20368, 20369,
+		20370, 20371, 13459, 22331, 20372, 20373, 11709, 20374, 20375, 11703, 20376, 10044, 20377,
+		20378, 10394, 20379, 11906, 12685, 12705, 20380, 20381, 16669, 20382, 20383, 20384, 10624,
+		20385, 20386, 20387, 20388, 20389, 20390, 20391, 20392, 20393, 11584, 15300, 20394, 9073,
+		20395, 20396, 20397, 20398, 20399, 22531, 20400, 20401, 20402, 20403, 20404, 20405, 20406,
+		20407, 22532, 20408, 20409, 20410, 20411, 20412, 15117, 20413, 9041, 20414, 20415, 20416,
+		22533, 22534, 20417, 22535, 20418, 20419, 20420, 13437, 20421, 20422, 20423, 20424, 20425,
+		20520, 20521, 11732, 20522, 20523, 22538, 20524, 20525, 20526, 20527, 20528, 20529, 20530,
+		22537, 20531, 9277, 20532, 22536, 20533, 20534, 22539, 20535, 22540, 20536, 20537, 20538,
+		20539, 20540, 20541, 20542, 20543, 20544, 20545, 20546, 20547, 20548, 20549, 20550, 20551,
+		20552, 20553, 20554, 20555, 20556, 20557, 20558, 20559, 20560, 20561, 20562, 20563, 20564,
+		20565, 20566, 20567, 20568, 20569, 20570, 20571, 20572, 20573, 20574, 20575, 20576, 20577,
+		20578, 20579, 20580, 20581, 20582, 20583, 20584, 14729, 13630, 20585, 10936, 19689, 19690,
+		19691, 11719, 20586, 20587, 20588, 20589, 20590, 15569, 20591, 15912, 13831, 20592, 20593,
+		20594, 20595, 20596, 20597, 20598, 20599, 20600, 20601, 20602, 20603, 20604, 20605, 20606,
+		20607, 20608, 20609, 20610, 20611, 20612, 20613, 20614, 20615, 20710, 20711, 20712, 20713,
+		20714, 20715, 20716, 20717, 20718, 20719, 20720, 20721, 20722, 20723, 20724, 20725, 20726,
+		20727, 20728, 20729, 20730, 20731, 20732, 20733, 20734, 20735, 20736, 20737, 20738, 20739,
+		20740, 20741, 20742, 20743, 20744, 20745, 20746, 20747, 20748, 20749, 20750, 20751, 20752,
+		20753, 20754, 20755, 20756, 20757, 20758, 20759, 20760, 20761, 20762, 20763, 20764, 20765,
+		20766, 20767, 20768, 20769, 20770, 20771, 20772, 20773, 20774, 20775, 20776, 20777, 20778,
+		20779, 20780, 20781, 20782, 20783, 20784, 20785, 20786, 20787, 20788, 20789, 20790, 20791,
+		20792, 20793, 20794, 20795, 20796, 20797, 20798, 20799, 20800, 20801, 20802, 20803, 20804,
+		20805, 20900, 20901, 20902, 20903, 20904, 20905, 15504, 10170, 13469, 21467, 14993, 14174,
+		15180, 19554, 14606, 10778, 10222, 21468, 9093, 14199, 21469, 15869, 12458, 12331, 13241,
+		11702, 21566, 11358, 20906, 21567, 21568, 20907, 21569, 15520, 13226, 20908, 14574, 21570,
+		20909, 15692, 11912, 14411, 20910, 21571, 21572, 15348, 10243, 21573, 21574, 10038, 21575,
+		21576, 20911, 9497, 21577, 21578, 13592, 20912, 20913, 20914, 20915, 20916, 20917, 20918,
+		20919, 20920, 20921, 20922, 20923, 20924, 20925, 20926, 20927, 20928, 20929, 20930, 20931,
+		20932, 20933, 20934, 20935, 20936, 20937, 20938, 20939, 20940, 20941, 20942, 20943, 20944,
+		20945, 20946, 20947, 20948, 20949, 10426, 20950, 20951, 20434, 20435, 20436, 20952, 20437,
+		20953, 20954, 13219, 20438, 20439, 20955, 20956, 20957, 10396, 13984, 20958, 20959, 20960,
+		20961, 20962, 20963, 20964, 18549, 22562, 20965, 20966, 20967, 20968, 20969, 20970, 20971,
+		20972, 20973, 20974, 20975, 20976, 20977, 20978, 20979, 20980, 20981, 20982, 20983, 20984,
+		20985, 20986, 20987, 20988, 20989, 20990, 20991, 20992, 20993, 20994, 20995, 21090, 21091,
+		21092, 21093, 21094, 22563, 21095, 21096, 9450, 21097, 21098, 21099, 21100, 21101, 21102,
+		21103, 21104, 21105, 21106, 21107, 21108, 21109, 21110, 21111, 21112, 21113, 21114, 21115,
+		21116, 21117, 21118, 21119, 21120, 21121, 21122, 21123, 21124, 21125, 22564, 21126, 21127,
+		21128, 21129, 21130, 21131, 21132, 21133, 21134, 21135, 21136, 21137, 21138, 21139, 21140,
+		21141, 21142, 21143, 21144, 21145, 21146, 21147, 21148, 21149, 21150, 21151, 21152, 21153,
+		21154, 21155, 21156, 21157, 21158, 21159, 21160, 21161, 21162, 22566, 22565, 21163, 21164,
+		21165, 21166, 21167, 21168, 21169, 21170, 21171, 21172, 21173, 21174, 21175, 18552, 21176,
+		11307, 21177, 18553, 18554, 18555, 18556, 18557, 18558, 10380, 15576, 11499, 14011, 9220,
+		14194, 21178, 18559, 10257, 13621, 18560, 21179, 21180, 11535, 21181, 9308, 18561, 21182,
+		10251, 18562, 12882, 21183, 21184, 18563, 14973, 10796, 18564, 12066, 21185, 18565, 9491,
+		21280, 18566, 21281, 12338, 18567, 18568, 12533, 18569, 18570, 18571, 14020, 16649, 16884,
+		14982, 21282, 21283, 21284, 21285, 21286, 21287, 21288, 21289, 21290, 21291, 21292, 21190,
+		21293, 21294, 17401, 21295, 21296, 21297, 21298, 21299, 21300, 21301, 21302, 21303, 21304,
+		21305, 21306, 21307, 21308, 21309, 21310, 21311, 21312, 21313, 21314, 21315, 21316, 21317,
+		21318, 21319, 21320, 21321, 21322, 21323, 21324, 21325, 21326, 21327, 21328, 21329, 21330,
+		21331, 21332, 21333, 21334, 21335, 21336, 21337, 21338, 21339, 21340, 21341, 21342, 21343,
+		21344, 21345, 21346, 21347, 21348, 21349, 21350, 21351, 21352, 21353, 21354, 21355, 21356,
+		21357, 21358, 21359, 21360, 21361, 21362, 21363, 21364, 21365, 21366, 21367, 21368, 21369,
+		21370, 21371, 21372, 21373, 21374, 21375, 21470, 21471, 21472, 21473, 21474, 21475, 21476,
+		21477, 21478, 21479, 21480, 21481, 21482, 21483, 21484, 21485, 21486, 21487, 21488, 21489,
+		21490, 21491, 21492, 21493, 21494, 21495, 21496, 21497, 21498, 21499, 21500, 21501, 21502,
+		21503, 21504, 21505, 21506, 21507, 21508, 21509, 21510, 21511, 21512, 21513, 21514, 21515,
+		21516, 21517, 21518, 21519, 21520, 21521, 21522, 21523, 21524, 21525, 21526, 21527, 21528,
+		21529, 21530, 21531, 21532, 21533, 21534, 21535, 21536, 21537, 21538, 21539, 21540, 21541,
+		21542, 21543, 21544, 21545, 21546, 21547, 21548, 21549, 21550, 21551, 21552, 21553, 21554,
+		21555, 21556, 21557, 21558, 21559, 21560, 21561, 21562, 21563, 21564, 21565, 21660, 21661,
+		21662, 21663, 21664, 21665, 21666, 21667, 21668, 21669, 21670, 21671, 21672, 21673, 21674,
+		21675, 21676, 21677, 12522, 15871, 14587, 15312, 9654, 13488, 21678, 9426, 12476, 19349,
+		13992, 19350, 19351, 11737, 19352, 16439, 14588, 19354, 11366, 19353, 19355, 19356, 12523,
+		21679, 11525, 19357, 12515, 10932, 19358, 21680, 19359, 9645, 15368, 21681, 21682, 11880,
+		19360, 13284, 19361, 19362, 21683, 21684, 19363, 13218, 19364, 21685, 13782, 19365, 19366,
+		19367, 19120, 19368, 19369, 12511, 19370, 19371, 16317, 19372, 21686, 19373, 10774, 21687,
+		21688, 21689, 21690, 21691, 21692, 21693, 22542, 22541, 21694, 21695, 21696, 21697, 22545,
+		22543, 10926, 21698, 22546, 21699, 22547, 21700, 21701, 21702, 22549, 22548, 22551, 21703,
+		21704, 22550, 21705, 21706, 21707, 21708, 21709, 22552, 22553, 21710, 21711, 21712, 21713,
+		22554, 21714, 14227, 21715, 21716, 21717, 21718, 10608, 21719, 21720, 21721, 21722, 21723,
+		21724, 22567, 21725, 22568, 21726, 21727, 21728, 21729, 22569, 21730, 21731, 21732, 21733,
+		22571, 21734, 22573, 21735, 22570, 21736, 21737, 21738, 21739, 21740, 21741, 21742, 21743,
+		21744, 22574, 21745, 22572, 21746, 21747, 21748, 21749, 21750, 21751, 21752, 16490, 21753,
+		21754, 21755, 21850, 22575, 21851, 21852, 21853, 21854, 21855, 21856, 22576, 21857, 21858,
+		21859, 22577, 21860, 21861, 21862, 21863, 21864, 21865, 21866, 21867, 21868, 21869, 21870,
+		22578, 21871, 21872, 21873, 22579, 21874, 21875, 21876, 21877, 21878, 21879, 21880, 21881,
+		21882, 21883, 21884, 17238, 21885, 21886, 16635, 21887, 21888, 21889, 21890, 21891, 21892,
+		21893, 21894, 19182, 10812, 21895, 21896, 21897, 21898, 11968, 11189, 22556, 13244, 22555,
+		21899, 22557, 22559, 22558, 21900, 21901, 21902, 22560, 21903, 14751, 21904, 22561, 21905,
+		21906, 12832, 21907, 21908, 21909, 21910, 21911, 21912, 21913, 21914, 21915, 21916, 21917,
+		21918, 21919, 21920, 21921, 21922, 21923, 21924, 21925, 21926, 21927, 21928, 21929, 21930,
+		21931, 21932, 21933, 21934, 21935, 21936, 21937, 21938, 21939, 21940, 21941, 21942, 21943,
+		21944, 21945, 22040, 22041, 22042, 22043, 22044, 22045, 22046, 22047, 22048, 22049, 22050,
+		22051, 22052, 22053, 22054, 22055, 22056, 22057, 22058, 22059, 22060, 22061, 22062, 22063,
+		22064, 22065, 22066, 22067, 22068, 22069, 22070, 22071, 22072, 22073, 22074, 22075, 22076,
+		22077, 22078, 22079, 22080, 22081, 22082, 22083, 22084, 22085, 22086, 22087, 22088, 22089,
+		22090, 22091, 22092, 22093, 22094, 22095, 22096, 22097, 22098, 22099, 22100, 22101, 22102,
+		22103, 22104, 22105, 22106, 22107, 22108, 22109, 22110, 22111, 22112, 22113, 22114, 22115,
+		22116, 22117, 22118, 22119, 22120, 22121, 22122, 22123, 22124, 22125, 22126, 22127, 22128,
+		22129, 22130, 22131, 22132, 22133, 22134, 22135, 22230, 22231, 22232, 22233, 22234, 22235,
+		22236, 22237, 22238, 22239, 22240, 22241, 22242, 22243, 22244, 22245, 22246, 22247, 22248,
+		22249, 22250, 22251, 22252, 22253, 22254, 22255, 22256, 22257, 22258, 22259, 22260, 22261,
+		22262, 22263, 22264, 22265, 22266, 22267, 22268, 22269, 22270, 22271, 22272, 22273, 22274,
+		22275, 22276, 22277, 22278, 22279, 22280, 22281, 22282, 22283, 22284, 22285, 22286, 22287,
+		22288, 22289, 22290, 22291, 22292, 22293, 22294, 22295, 22296, 22297, 22298, 22299, 22300,
+		22301, 22302, 22303, 22304, 22305, 22306, 22307, 22308, 22309, 22310, 22311, 22312, 22313,
+		22314, 22315, 22316, 22317, 22318, 22319, 22320, 22321, 22322, 22323, 22324, 22325, 22420,
+		22421, 22422, 22423, 22424, 22425, 22426, 22427, 22428, 22429, 22430, 22431, 22432, 22433,
+		22434, 22435, 22436, 22437, 22438, 22439, 22440, 22441, 22442, 22443, 22444, 22445, 22446,
+		22447, 22448, 22449, 22450, 22451, 22452, 22453, 22454, 22455, 22456, 22457, 22458, 22459,
+		22460, 22461, 22462, 22463, 22464, 22465, 22466, 22467, 22468, 22469, 22470, 22471, 22472,
+		22473, 22474, 22475, 22476, 22477, 22478, 22479, 22480, 22481, 22482, 22483, 15742, 22484,
+		22485, 22372, 22486, 12464, 22373, 22487, 22488, 22374, 22375, 22376, 22377, 22489, 22490,
+		22379, 22491, 9226, 22380, 22492, 22381, 22382, 22383, 22493, 22384, 22385, 22494, 22495,
+		22496, 22497, 22386, 22387, 14957, 22498, 22388, 22389, 22390, 22391, 22392, 22393, 12145,
+		22394, 22395, 22396, 22397, 22398, 22499, 22399, 22500, 22400, 22401, 22501, 22402, 22403,
+		22404, 22405, 22406, 22407, 22408, 22409, 11693, 22502, 22410, 22411, 22412, 22413, 22503,
+		22504, 22505, 22506, 22507, 13675, 22414, 22415, 22416, 22417, 22508, 22509, 22418, 22419,
+		22516, 22517, 22518, 22519, 22520, 22510, 22511, 22521, 22522, 22523, 9293, 22524, 22525,
+		22526, 22512, 22513, 22527, 22528, 12314, 22529, 22514, 22515, 22530, 22610, 22611, 22612,
+		22613, 22614, 22615, 22616, 22617, 22618, 22619, 22620, 22621, 22622, 22623, 22624, 22625,
+		22626, 22627, 22628, 22629, 22630, 22631, 22632, 22633, 22634, 22635, 22636, 22637, 22638,
+		22639, 22640, 22641, 22642, 22643, 22644, 22645, 22646, 22647, 22648, 22649, 22650, 22651,
+		22652, 22653, 22654, 22655, 22656, 22657, 22658, 22659, 22660, 22661, 22662, 22663, 22664,
+		22665, 22666, 22667, 22668, 22669, 22670, 22671, 22672, 22673, 22674, 22675, 22676, 22677,
+		22678, 22679, 22680, 22681, 22682, 22683, 22684, 22685, 22686, 22687, 22688, 22689, 22690,
+		22691, 22692, 22693, 22694, 22695, 22696, 22697, 22698, 22699, 22700, 22701, 22702, 22703,
+		22704, 22705, 22800, 22801, 22802, 22803, 22804, 22805, 22806, 22807, 22808, 22809, 22810,
+		22811, 22812, 22813, 22814, 22815, 22816, 22817, 22818, 22819, 22820, 22821, 22822, 22823,
+		22824, 22825, 22826, 22827, 22828, 22829, 22830, 22831, 22832, 22833, 22834, 22835, 22836,
+		22837, 22838, 22839, 22840, 22841, 22842, 22843, 22844, 22845, 22846, 22847, 22848, 22849,
+		22850, 22851, 22852, 22853, 22854, 22855, 22856, 22857, 22858, 22859, 22860, 22861, 22862,
+		22863, 22864, 22865, 22866, 22867, 22868, 22869, 22870, 22871, 22872, 22873, 22874, 22875,
+		22876, 22877, 22878, 22879, 22880, 22881, 22882, 22883, 22884, 22885, 22886, 22887, 22888,
+		22889, 22890, 22891, 22892, 22893, 22894, 22895, 22990, 22991, 22992, 22993, 22994, 22995,
+		22996, 22997, 22998, 22999, 23000, 23001, 23002, 23003, 23004, 23005, 23006, 23007, 23008,
+		23009, 23010, 23011, 23012, 23013, 23014, 23015, 23016, 23017, 23018, 23019, 23020, 23021,
+		23022, 23023, 23024, 23025, 23026, 23027, 23028, 23029, 23030, 23031, 23032, 23033, 23034,
+		23035, 23036, 23037, 23038, 23039, 23040, 23041, 23042, 23043, 23044, 23045, 23046, 23047,
+		23048, 23049, 23050, 23051, 23052, 23053, 23054, 23055, 23056, 23057, 23058, 23059, 23060,
+		23061, 23062, 23063, 23064, 23065, 23066, 23067, 23068, 23069, 23070, 23071, 23072, 23073,
+		23074, 23075, 23076, 23077, 23078, 23079, 23080, 23081, 23082, 23083, 23084, 23085, 23180,
+		23181, 23182, 23183, 23184, 23185, 23186, 23187, 23188, 23189, 23190, 23191, 23192, 23193,
+		23194, 23195, 23196, 23197, 23198, 23199, 23200, 23201, 23202, 23203, 23204, 23205, 23206,
+		23207, 12906, 21200, 11311, 21201, 12724, 23208, 13039, 15322, 23209, 21202, 21203, 21204,
+		21205, 21206, 15323, 23210, 15371, 23211, 21208, 21207, 15872, 23212, 14585, 21209, 21211,
+		21210, 21212, 21213, 23213, 23214, 10620, 21214, 10997, 23215, 21215, 21216, 11758, 21217,
+		10241, 21218, 21219, 21220, 21221, 13606, 21222, 21223, 23216, 21224, 13099, 23217, 21225,
+		23218, 23219, 23220, 21226, 23221, 21227, 22544, 23222, 21228, 21229, 21230, 23223, 21231,
+		23224, 23225, 23226, 23227, 21232, 10980, 23228, 21233, 21234, 21235, 21236, 21237, 21238,
+		21239, 21241, 23229, 23230, 15680, 21240, 23231, 21242, 23232, 23233, 23234, 23235, 23236,
+		23237, 23238, 23239, 23240, 23241, 22161, 12470, 23242, 23243, 22583, 23244, 23245, 23246,
+		23247, 22584, 22585, 23248, 23249, 22586, 23250, 23251, 23252, 23253, 23254, 23255, 22587,
+		12465, 23256, 23257, 23258, 23259, 23260, 23261, 23262, 23263, 23264, 22589, 23265, 22590,
+		23266, 23267, 23268, 23269, 23270, 23271, 12528, 23272, 23273, 23274, 23275, 23370, 23371,
+		23372, 23373, 23374, 23375, 23376, 23377, 23378, 22025, 23379, 23380, 23381, 22024, 23382,
+		23383, 12518, 23384, 22580, 22581, 23385, 23386, 23387, 23388, 23389, 11153, 23390, 23391,
+		23392, 23393, 16883, 23394, 23395, 23396, 14047, 12137, 21189, 23397, 10983, 23398, 23399,
+		13408, 23400, 23401, 23402, 12837, 23403, 23404, 22591, 22592, 22593, 23405, 22595, 22594,
+		23406, 22596, 23407, 23408, 22599, 23409, 22598, 23410, 22597, 22600, 23411, 23412, 23413,
+		23414, 22601, 23415, 23416, 23417, 23418, 23419, 23420, 23421, 23422, 23423, 20688, 23424,
+		20689, 20690, 23425, 22353, 23426, 23427, 23428, 23429, 23430, 23431, 23432, 23433, 23434,
+		23435, 23436, 23437, 22354, 23438, 22355, 10171, 23439, 16655, 23440, 23441, 10771, 23442,
+		23443, 23444, 16652, 23445, 17402, 23446, 23447, 23448, 23449, 23450, 23451, 14048, 23452,
+		22602, 23453, 23454, 23455, 23456, 23457, 23458, 23459, 23460, 23461, 22603, 23462, 23463,
+		22604, 23464, 23465, 23560, 23561, 23562, 23563, 23564, 22606, 23565, 22605, 23566, 9254,
+		23567, 22607, 22608, 23568, 23569, 23570, 23571, 23572, 22609, 23573, 23574, 23575, 23576,
+		23577, 23578, 23579, 23580, 23581, 23582, 23583, 13280, 20445, 23584, 23585, 23586, 23587,
+		23588, 23589, 23590, 23591, 23592, 23593, 23594, 23595, 23596, 23597, 23598, 23599, 23600,
+		23601, 23602, 23603, 23604, 23605, 23606, 23607, 23608, 23609, 23610, 23611, 23612, 23613,
+		23614, 23615, 23616, 23617, 23618, 23619, 23620, 23621, 23622, 23623, 23624, 23625, 23626,
+		23627, 23628, 9656, 22344, 23629, 23630, 22345, 12323, 22346, 22347, 22348, 22349, 22350,
+		22351, 13588, 22352, 23631, 23632, 23633, 23634, 23635, 23636, 23637, 23638, 23639, 23640,
+		23641, 23642, 12345, 10743, 20687, 23643, 23644, 23645, 10809, 16878, 23646, 23647, 23648,
+		23649, 23650, 7886, 7887, 7888, 7889, 7890, 7891, 7892, 7893, 7894, 7895, 7896, 7897, 7898,
+		7899, 7900, 7901, 7902, 7903, 7904, 7905, 7906, 7907, 7908, 7909, 7910, 7911, 7912, 7913,
+		7914, 7915, 7916, 7917, 7918, 7919, 7920, 7921, 7922, 7923, 7924, 7925, 7926, 7927, 7928,
+		7929, 7930, 7931, 7932, 7933, 7934, 7935, 7936, 7937, 7938, 7939, 7940, 7941, 7942, 7943,
+		7944, 7945, 7946, 7947, 7948, 7949, 7950, 7951, 7952, 7953, 7954, 7955, 7956, 7957, 7958,
+		7959, 7960, 7961, 7962, 7963, 7964, 7965, 7966, 7967, 7968, 7969, 7970, 7971, 7972, 7973,
+		7974, 7975, 7976, 7977, 7978, 7979, 8076, 8077, 8078, 8079, 8080, 8081, 8082, 8083, 8084,
+		8085, 8086, 8087, 8088, 8089, 8090, 8091, 8092, 8093, 8094, 8095, 8096, 8097, 8098, 8099,
+		8100, 8101, 8102, 8103, 8104, 8105, 8106, 8107, 8108, 8109, 8110, 8111, 8112, 8113, 8114,
+		8115, 8116, 8117, 8118, 8119, 8120, 8121, 8122, 8123, 8124, 8125, 8126, 8127, 8128, 8129,
+		8130, 8131, 8132, 8133, 8134, 8135, 8136, 8137, 8138, 8139, 8140, 8141, 8142, 8143, 8144,
+		8145, 8146, 8147, 8148, 8149, 8150, 8151, 8152, 8153, 8154, 8155, 8156, 8157, 8158, 8159,
+		8160, 8161, 8162, 8163, 8164, 8165, 8166, 8167, 8168, 8169, 8266, 8267, 8268, 8269, 8270,
+		8271, 8272, 8273, 8274, 8275, 8276, 8277, 8278, 8279, 8280, 8281, 8282, 8283, 8284, 8285,
+		8286, 8287, 8288, 8289, 8290, 8291, 8292, 8293, 8294, 8295, 8296, 8297, 8298, 8299, 8300,
+		8301, 8302, 8303, 8304, 8305, 8306, 8307, 8308, 8309, 8310, 8311, 8312, 8313, 8314, 8315,
+		8316, 8317, 8318, 8319, 8320, 8321, 8322, 8323, 8324, 8325, 8326, 8327, 8328, 8329, 8330,
+		8331, 8332, 8333, 8334, 8335, 8336, 8337, 8338, 8339, 8340, 8341, 8342, 8343, 8344, 8345,
+		8346, 8347, 8348, 8349, 8350, 8351, 8352, 8353, 8354, 8355, 8356, 8357, 8358, 8359, 8456,
+		8457, 8458, 8459, 8460, 8461, 8462, 8463, 8464, 8465, 8466, 8467, 8468, 8469, 8470, 8471,
+		8472, 8473, 8474, 8475, 8476, 8477, 8478, 8479, 8480, 8481, 8482, 8483, 8484, 8485, 8486,
+		8487, 8488, 8489, 8490, 8491, 8492, 8493, 8494, 8495, 8496, 8497, 8498, 8499, 8500, 8501,
+		8502, 8503, 8504, 8505, 8506, 8507, 8508, 8509, 8510, 8511, 8512, 8513, 8514, 8515, 8516,
+		8517, 8518, 8519, 8520, 8521, 8522, 8523, 8524, 8525, 8526, 8527, 8528, 8529, 8530, 8531,
+		8532, 8533, 8534, 8535, 8536, 8537, 8538, 8539, 8540, 8541, 8542, 8543, 8544, 8545, 8546,
+		8547, 8548, 8549, 8646, 8647, 8648, 8649, 8650, 8651, 8652, 8653, 8654, 8655, 8656, 8657,
+		8658, 8659, 8660, 8661, 8662, 8663, 8664, 8665, 8666, 8667, 8668, 8669, 8670, 8671, 8672,
+		8673, 8674, 8675, 8676, 8677, 8678, 8679, 8680, 8681, 8682, 8683, 8684, 8685, 8686, 8687,
+		8688, 8689, 8690, 8691, 8692, 8693, 8694, 8695, 8696, 8697, 8698, 8699, 8700, 8701, 8702,
+		8703, 8704, 8705, 8706, 8707, 8708, 8709, 8710, 8711, 8712, 8713, 8714, 8715, 8716, 8717,
+		8718, 8719, 8720, 8721, 8722, 8723, 8724, 8725, 8726, 8727, 8728, 8729, 8730, 8731, 8732,
+		8733, 8734, 8735, 8736, 8737, 8738, 8739, 8836, 8837, 8838, 8839, 8840, 8841, 8842, 8843,
+		8844, 8845, 8846, 8847, 8848, 8849, 8850, 8851, 8852, 8853, 8854, 8855, 8856, 8857, 8858,
+		8859, 8860, 8861, 8862, 8863, 8864, 8865, 8866, 8867, 8868, 8869, 8870, 8871, 8872, 8873,
+		8874, 8875, 8876, 8877, 8878, 8879, 8880, 8881, 8882, 8883, 8884, 8885, 8886, 8887, 8888,
+		8889, 8890, 8891, 8892, 8893, 8894, 8895, 8896, 8897, 8898, 8899, 8900, 8901, 8902, 8903,
+		8904, 8905, 8906, 8907, 8908, 8909, 8910, 8911, 8912, 8913, 8914, 8915, 8916, 8917, 8918,
+		8919, 8920, 8921, 8922, 8923, 8924, 8925, 8926, 8927, 8928, 8929, 22706, 22707, 22708,
+		22709, 22710, 22711, 22712, 22713, 22714, 22715, 22716, 22717, 22718, 22719, 22720, 22721,
+		22722, 22723, 22724, 22725, 22726, 22727, 22728, 22729, 22730, 22731, 22732, 22733, 22734,
+		22735, 22736, 22737, 22738, 22739, 22740, 22741, 22742, 22743, 22744, 22745, 22746, 22747,
+		22748, 22749, 22750, 22751, 22752, 22753, 22754, 22755, 22756, 22757, 22758, 22759, 22760,
+		22761, 22762, 22763, 22764, 22765, 22766, 22767, 22768, 22769, 22770, 22771, 22772, 22773,
+		22774, 22775, 22776, 22777, 22778, 22779, 22780, 22781, 22782, 22783, 22784, 22785, 22786,
+		22787, 22788, 22789, 22790, 22791, 22792, 22793, 22794, 22795, 22796, 22797, 22798, 22799,
+		22896, 22897, 22898, 22899, 22900, 22901, 22902, 22903, 22904, 22905, 22906, 22907, 22908,
+		22909, 22910, 22911, 22912, 22913, 22914, 22915, 22916, 22917, 22918, 22919, 22920, 22921,
+		22922, 22923, 22924, 22925, 22926, 22927, 22928, 22929, 22930, 22931, 22932, 22933, 22934,
+		22935, 22936, 22937, 22938, 22939, 22940, 22941, 22942, 22943, 22944, 22945, 22946, 22947,
+		22948, 22949, 22950, 22951, 22952, 22953, 22954, 22955, 22956, 22957, 22958, 22959, 22960,
+		22961, 22962, 22963, 22964, 22965, 22966, 22967, 22968, 22969, 22970, 22971, 22972, 22973,
+		22974, 22975, 22976, 22977, 22978, 22979, 22980, 22981, 22982, 22983, 22984, 22985, 22986,
+		22987, 22988, 22989, 23086, 23087, 23088, 23089, 23090, 23091, 23092, 23093, 23094, 23095,
+		23096, 23097, 23098, 23099, 23100, 23101, 23102, 23103, 23104, 23105, 23106, 23107, 23108,
+		23109, 23110, 23111, 23112, 23113, 23114, 23115, 23116, 23117, 23118, 23119, 23120, 23121,
+		23122, 23123, 23124, 23125, 23126, 23127, 23128, 23129, 23130, 23131, 23132, 23133, 23134,
+		23135, 23136, 23137, 23138, 23139, 23140, 23141, 23142, 23143, 23144, 23145, 23146, 23147,
+		23148, 23149, 23150, 23151, 23152, 23153, 23154, 23155, 23156, 23157, 23158, 23159, 23160,
+		23161, 23162, 23163, 23164, 23165, 23166, 23167, 23168, 23169, 23170, 23171, 23172, 23173,
+		23174, 23175, 23176, 23177, 23178, 23179, 23276, 23277, 23278, 23279, 23280, 23281, 23282,
+		23283, 23284, 23285, 23286, 23287, 23288, 23289, 23290, 23291, 23292, 23293, 23294, 23295,
+		23296, 23297, 23298, 23299, 23300, 23301, 23302, 23303, 23304, 23305, 23306, 23307, 23308,
+		23309, 23310, 23311, 23312, 23313, 23314, 23315, 23316, 23317, 23318, 23319, 23320, 23321,
+		23322, 23323, 23324, 23325, 23326, 23327, 23328, 23329, 23330, 23331, 23332, 23333, 23334,
+		23335, 23336, 23337, 23338, 23339, 23340, 23341, 23342, 23343, 23344, 23345, 23346, 23347,
+		23348, 23349, 23350, 23351, 23352, 23353, 23354, 23355, 23356, 23357, 23358, 23359, 23360,
+		23361, 23362, 23363, 23364, 23365, 23366, 23367, 23368, 23369, 23466, 23467, 23468, 23469,
+		23470, 23471, 23472, 23473, 23474, 23475, 23476, 23477, 23478, 23479, 23480, 23481, 23482,
+		23483, 23484, 23485, 23486, 23487, 23488, 23489, 23490, 23491, 23492, 23493, 23494, 23495,
+		23496, 23497, 23498, 23499, 23500, 23501, 23502, 23503, 23504, 23505, 23506, 23507, 23508,
+		23509, 23510, 23511, 23512, 23513, 23514, 23515, 23516, 23517, 23518, 23519, 23520, 23521,
+		23522, 23523, 23524, 23525, 23526, 23527, 23528, 23529, 23530, 23531, 23532, 23533, 23534,
+		23535, 23536, 23537, 23538, 23539, 23540, 23541, 23542, 23543, 23544, 23545, 23546, 23547,
+		23548, 23549, 23550, 23551, 23552, 23553, 23554, 23555, 23556, 23557, 23558, 23559, 23656,
+		23657, 23658, 23659, 23660, 23661, 23662, 23663, 23664, 23665, 23666, 23667, 23668, 23669,
+		23670, 23671, 23672, 23673, 23674, 23675, 23676, 23677, 23678, 23679, 23680, 23681, 23682,
+		23683, 23684, 23685, 23686, 23687, 23688, 23689, 23690, 23691, 23692, 23693, 23694, 23695,
+		23696, 23697, 23698, 23699, 23700, 23701, 23702, 23703, 23704, 23705, 23706, 23707, 23708,
+		23709, 23710, 23711, 23712, 23713, 23714, 23715, 23716, 23717, 23718, 23719, 23720, 23721,
+		23722, 23723, 23724, 23725, 23726, 23727, 23728, 23729, 23730, 23731, 23732, 23733, 23734,
+		23735, 23736, 23737, 23738, 23739, 23740, 23741, 23742, 23743, 23744, 23745, 23746, 23747,
+		23748, 23749, 23846, 23847, 23848, 23849, 23850, 23851, 23852, 23853, 23854, 23855, 23856,
+		23857, 23858, 23859, 23860, 23861, 23862, 23863, 23864, 23865, 23866, 23867, 23868, 23869,
+		23870, 23871, 23872, 23873, 23874, 23875, 23876, 23877, 23878, 23879, 23880, 23881, 23882,
+		23883, 23884, 23885, 23886, 23887, 23888, 23889, 23890, 23891, 23892, 23893, 23894, 23895,
+		23896, 23897, 23898, 23899, 23900, 23901, 23902, 23903, 23904, 23905, 23906, 23907, 23908,
+		23909, 23910, 23911, 23912, 23913, 23914, 23915, 23916, 23917, 23918, 23919, 23920, 23921,
+		23922, 23923, 23924, 23925, 23926, 23927, 23928, 23929, 23930, 23931, 23932, 23933, 23934,
+		23935, 23936, 23937, 23938, 23939, 6080, 6081, 6082, 6083, 6084, 6085, 6086, 6087, 6088,
+		6089, 6090, 6091, 6092, 6093, 6094, 6095, 6096, 6097, 6098, 6099, 6100, 6101, 6102, 6103,
+		6104, 6105, 6106, 6107, 6108, 6109, 6110, 6111, 6112, 6113, 6114, 6115, 6116, 6117, 6118,
+		6119, 6120, 6121, 6122, 6123, 6124, 6125, 6126, 6127, 6128, 6129, 6130, 6131, 6132, 6133,
+		6134, 6135, 6136, 6137, 6138, 6139, 6140, 6141, 6142, 6143, 6144, 6145, 6146, 6147, 6148,
+		6149, 6150, 6151, 6152, 6153, 6154, 6155, 6156, 6157, 6158, 6159, 6160, 6161, 6162, 6163,
+		6164, 6165, 6166, 6167, 6168, 6169, 6170, 6171, 6172, 6173, 6174, 6175, 6270, 6271, 6272,
+		6273, 6274, 6275, 6276, 6277, 6278, 6279, 6280, 6281, 6282, 6283, 6284, 6285, 6286, 6287,
+		6288, 6289, 6290, 6291, 6292, 6293, 6294, 6295, 6296, 6297, 6298, 6299, 6300, 6301, 6302,
+		6303, 6304, 6305, 6306, 6307, 6308, 6309, 6310, 6311, 6312, 6313, 6314, 6315, 6316, 6317,
+		6318, 6319, 6320, 6321, 6322, 6323, 6324, 6325, 6326, 6327, 6328, 6329, 6330, 6331, 6332,
+		6333, 6334, 6335, 6336, 6337, 6338, 6339, 6340, 6341, 6342, 6343, 6344, 6345, 6346, 6347,
+		6348, 6349, 6350, 6351, 6352, 6353, 6354, 6355, 6356, 6357, 6358, 6359, 6360, 6361, 6362,
+		6363, 6364, 6365, 6460, 6461, 6462, 6463, 6464, 6465, 6466, 6467, 6468, 6469, 6470, 6471,
+		6472, 6473, 6474, 6475, 6476, 6477, 6478, 6479, 6480, 6481, 6482, 6483, 6484, 6485, 6486,
+		6487, 6488, 6489, 6490, 6491, 6492, 6493, 6494, 6495, 6496, 6497, 6498, 6499, 6500, 6501,
+		6502, 6503, 6504, 6505, 6506, 6507, 6508, 6509, 6510, 6511, 6512, 6513, 6514, 6515, 6516,
+		6517, 6518, 6519, 6520, 6521, 6522, 6523, 6524, 6525, 6526, 6527, 6528, 6529, 6530, 6531,
+		6532, 6533, 6534, 6535, 6536, 6537, 6538, 6539, 6540, 6541, 6542, 6543, 6544, 6545, 6546,
+		6547, 6548, 6549, 6550, 6551, 6552, 6553, 6554, 6555, 6650, 6651, 6652, 6653, 6654, 6655,
+		6656, 6657, 6658, 6659, 6660, 6661, 6662, 6663, 6664, 6665, 6666, 6667, 6668, 6669, 6670,
+		6671, 6672, 6673, 6674, 6675, 6676, 6677, 6678, 6679, 6680, 6681, 6682, 6683, 6684, 6685,
+		6686, 6687, 6688, 6689, 6690, 6691, 6692, 6693, 6694, 6695, 6696, 6697, 6698, 6699, 6700,
+		6701, 6702, 6703, 6704, 6705, 6706, 6707, 6708, 6709, 6710, 6711, 6712, 6713, 6714, 6715,
+		6716, 6717, 6718, 6719, 6720, 6721, 6722, 6723, 6724, 6725, 6726, 6727, 6728, 6729, 6730,
+		6731, 6732, 6733, 6734, 6735, 6736, 6737, 6738, 6739, 6740, 6741, 6742, 6743, 6744, 6745,
+		6840, 6841, 6842, 6843, 6844, 6845, 6846, 6847, 6848, 6849, 6850, 6851, 6852, 6853, 6854,
+		6855, 6856, 6857, 6858, 6859, 6860, 6861, 6862, 6863, 6864, 6865, 6866, 6867, 6868, 6869,
+		6870, 6871, 6872, 6873, 6874, 6875, 6876, 6877, 6878, 6879, 6880, 6881, 6882, 6883, 6884,
+		6885, 6886, 6887, 6888, 6889, 6890, 6891, 6892, 6893, 6894, 6895, 6896, 6897, 6898, 6899,
+		6900, 6901, 6902, 6903, 6904, 6905, 6906, 6907, 6908, 6909, 6910, 6911, 6912, 6913, 6914,
+		6915, 6916, 6917, 6918, 6919, 6920, 6921, 6922, 6923, 6924, 6925, 6926, 6927, 6928, 6929,
+		6930, 6931, 6932, 6933, 6934, 6935, 7030, 7031, 7032, 7033, 7034, 7035, 7036, 7037, 7038,
+		7039, 7040, 7041, 7042, 7043, 7044, 7045, 7046, 7047, 7048, 7049, 7050, 7051, 7052, 7053,
+		7054, 7055, 7056, 7057, 7058, 7059, 7060, 7061, 7062, 7063, 7064, 7065, 7066, 7067, 7068,
+		7069, 7070, 7071, 7072, 7073, 7074, 7075, 7076, 7077, 7078, 7079, 7080, 7081, 7082, 7083,
+		7084, 7085, 7086, 7087, 7088, 7089, 7090, 7091, 7092, 7093, 7094, 7095, 7096, 7097, 7098,
+		7099, 7100, 7101, 7102, 7103, 7104, 7105, 7106, 7107, 7108, 7109, 7110, 7111, 7112, 7113,
+		7114, 7115, 7116, 7117, 7118, 7119, 7120, 7121, 7122, 7123, 7124, 7125, 7220, 7221, 7222,
+		7223, 7224, 7225, 7226, 7227, 7228, 7229, 7230, 7231, 7232, 7233, 7234, 7235, 7236, 7237,
+		7238, 7239, 7240, 7241, 7242, 7243, 7244, 7245, 7246, 7247, 7248, 7249, 7250, 7251, 7252,
+		7253, 7254, 7255, 7256, 7257, 7258, 7259, 7260, 7261, 7262, 7263, 7264, 7265, 7266, 7267,
+		7268, 7269, 7270, 7271, 7272, 7273, 7274, 7275, 7276, 7277, 7278, 7279, 7280, 7281, 7282,
+		7283, 7284, 7285, 7286, 7287, 7288, 7289, 7290, 7291, 7292, 7293, 7294, 7295, 7296, 7297,
+		7298, 7299, 7300, 7301, 7302, 7303, 7304, 7305, 7306, 7307, 7308, 7309, 7310, 7311, 7312,
+		7313, 7314, 7315, 6376, 6377, 6378, 6379, 6380, 6381, 6433, 6444, 6445, 6458, 6459, 6829,
+		6830, 6831, 6832, 6833, 6834, 6835, 6836, 6837, 6838, 6839, 7022, 7023, 7024, 7025, 7026,
+		7027, 7028, 7029, 7150, 7151, 7152, 7153, 7154, 7155, 7156, 7157, 7182, 7183, 7184, 7185,
+		7186, 7187, 7188, 7201, 7202, 7208, 7211, 7212, 7213, 7214, 7215, 7216, 7217, 7218, 7219,
+		7349, 7350, 7351, 7352, 7353, 7354, 7355, 7356, 7357, 7358, 7359, 7360, 7361, 7362, 7363,
+		7397, 7398, 7399, 7400, 7401, 7402, 7403, 7404, 7405, 7406, 7407, 7408, 7409, 7495, 7496,
+		7497, 7498, 7499, 7500, 7501, 7502, 7503, 7504, 7505, 7538, 7539, 7540, 7541, 7579, 7580,
+		7581, 7582, 7583, 7584, 7585, 7586, 7587, 7588, 7589, 7590, 7591, 7592, 7593, 7594, 7595,
+		7596, 7597, 7598, 7599, 7624, 7627, 7629, 7630, 7631, 7686, 7687, 7688, 7689, 7690, 7691,
+		7692, 7693, 7694, 7695, 7696, 7697, 7698, 7775, 7776, 7777, 7778, 7779, 7780, 7781, 7782,
+		7783, 7784, 7785, 7786, 7787, 7788, 7789, 16525, 16526, 16527, 16528, 16529, 23767, 23768,
+		23769, 23775, 23783, 23788, 23789, 23794, 23795, 23804, 23812, 23829, 23830, 23845, 23651,
+		23652, 23653, 23654, 23655, 23750, 23751, 23752, 23753, 23754, 23755, 23756, 23757, 23758,
+		23759, 23760, 23761, 23762, 23763, 23764, 23765, 7621, 7207, 7209, 7210, 7189, 7190, 7205,
+		7206, 7191, 7192, 7203, 7204, 7195, 7196, 7193, 7194, 7197, 7198, 7199, 7200, 7640, 7641,
+		7642, 7643, 7644, 7645, 7646, 7647, 7648, 7649, 7650, 7651, 7652, 7653, 7654, 7655, 7656,
+		7657, 7658, 7659, 7660, 7661, 7662, 7663, 7664, 7665, 7666, 7667, 7668, 7669, 7670, 7671,
+		6556, 6557, 6558, 6246, 6560, 6561, 6562, 6563, 6564, 6565, 6566, 6567, 6568, 6569, 6570,
+		6571, 6572, 6573, 6574, 6575, 6576, 6577, 6578, 6579, 6580, 6581, 6582, 6583, 6584, 6585,
+		6586, 6587, 6588, 6589, 6590, 6591, 6592, 6593, 6594, 6595, 6596, 6597, 6598, 6599, 6600,
+		6601, 6602, 6603, 6604, 6605, 6606, 6607, 6608, 6609, 6610, 6611, 6612, 6613, 6614, 6615,
+		6616, 6617, 6618, 6619, 6620, 6621, 6622, 6623, 6624, 6625, 6626, 6627, 6628, 6629, 6630,
+		6631, 6632, 6633, 6634, 6635, 6636, 6637, 6638, 6639, 6640, 6641, 6642, 6643, 6644, 6645,
+		6646, 6647, 6648, 6186, 6248, 6249, 7622, 6649, 7623, 6559,
+	],
+};
+
+/// Four-byte GB18030 pointers below U+10000 that start a run of consecutive code points, paired
+/// with that code point. U+E7C7, at pointer 7457, is the one code point outside the runs.
+pub(crate) static GB18030_RANGES: [(u16, u16); 207] = [
+	(0, 0x0080),
+	(36, 0x00A5),
+	(38, 0x00A9),
+	(45, 0x00B2),
+	(50, 0x00B8),
+	(81, 0x00D8),
+	(89, 0x00E2),
+	(95, 0x00EB),
+	(96, 0x00EE),
+	(100, 0x00F4),
+	(103, 0x00F8),
+	(104, 0x00FB),
+	(105, 0x00FD),
+	(109, 0x0102),
+	(126, 0x0114),
+	(133, 0x011C),
+	(148, 0x012C),
+	(172, 0x0145),
+	(175, 0x0149),
+	(179, 0x014E),
+	(208, 0x016C),
+	(306, 0x01CF),
+	(307, 0x01D1),
+	(308, 0x01D3),
+	(309, 0x01D5),
+	(310, 0x01D7),
+	(311, 0x01D9),
+	(312, 0x01DB),
+	(313, 0x01DD),
+	(341, 0x01FA),
+	(428, 0x0252),
+	(443, 0x0262),
+	(544, 0x02C8),
+	(545, 0x02CC),
+	(558, 0x02DA),
+	(741, 0x03A2),
+	(742, 0x03AA),
+	(749, 0x03C2),
+	(750, 0x03CA),
+	(805, 0x0402),
+	(819, 0x0450),
+	(820, 0x0452),
+	(7458, 0x1E40),
+	(7922, 0x2011),
+	(7924, 0x2017),
+	(7925, 0x201A),
+	(7927, 0x201E),
+	(7934, 0x2027),
+	(7943, 0x2031),
+	(7944, 0x2034),
+	(7945, 0x2036),
+	(7950, 0x203C),
+	(8062, 0x20AD),
+	(8148, 0x2104),
+	(8149, 0x2106),
+	(8152, 0x210A),
+	(8164, 0x2117),
+	(8174, 0x2122),
+	(8236, 0x216C),
+	(8240, 0x217A),
+	(8262, 0x2194),
+	(8264, 0x219A),
+	(8374, 0x2209),
+	(8380, 0x2210),
+	(8381, 0x2212),
+	(8384, 0x2216),
+	(8388, 0x221B),
+	(8390, 0x2221),
+	(8392, 0x2224),
+	(8393, 0x2226),
+	(8394, 0x222C),
+	(8396, 0x222F),
+	(8401, 0x2238),
+	(8406, 0x223E),
+	(8416, 0x2249),
+	(8419, 0x224D),
+	(8424, 0x2253),
+	(8437, 0x2262),
+	(8439, 0x2268),
+	(8445, 0x2270),
+	(8482, 0x2296),
+	(8485, 0x229A),
+	(8496, 0x22A6),
+	(8521, 0x22C0),
+	(8603, 0x2313),
+	(8936, 0x246A),
+	(8946, 0x249C),
+	(9046, 0x254C),
+	(9050, 0x2574),
+	(9063, 0x2590),
+	(9066, 0x2596),
+	(9076, 0x25A2),
+	(9092, 0x25B4),
+	(9100, 0x25BE),
+	(9108, 0x25C8),
+	(9111, 0x25CC),
+	(9113, 0x25D0),
+	(9131, 0x25E6),
+	(9162, 0x2607),
+	(9164, 0x260A),
+	(9218, 0x2641),
+	(9219, 0x2643),
+	(11329, 0x2E82),
+	(11331, 0x2E85),
+	(11334, 0x2E89),
+	(11336, 0x2E8D),
+	(11346, 0x2E98),
+	(11361, 0x2EA8),
+	(11363, 0x2EAB),
+	(11366, 0x2EAF),
+	(11370, 0x2EB4),
+	(11372, 0x2EB8),
+	(11375, 0x2EBC),
+	(11389, 0x2ECB),
+	(11682, 0x2FFC),
+	(11686, 0x3004),
+	(11687, 0x3018),
+	(11692, 0x301F),
+	(11694, 0x302A),
+	(11714, 0x303F),
+	(11716, 0x3094),
+	(11723, 0x309F),
+	(11725, 0x30F7),
+	(11730, 0x30FF),
+	(11736, 0x312A),
+	(11982, 0x322A),
+	(11989, 0x3232),
+	(12102, 0x32A4),
+	(12336, 0x3390),
+	(12348, 0x339F),
+	(12350, 0x33A2),
+	(12384, 0x33C5),
+	(12393, 0x33CF),
+	(12395, 0x33D3),
+	(12397, 0x33D6),
+	(12510, 0x3448),
+	(12553, 0x3474),
+	(12851, 0x359F),
+	(12962, 0x360F),
+	(12973, 0x361B),
+	(13738, 0x3919),
+	(13823, 0x396F),
+	(13919, 0x39D1),
+	(13933, 0x39E0),
+	(14080, 0x3A74),
+	(14298, 0x3B4F),
+	(14585, 0x3C6F),
+	(14698, 0x3CE1),
+	(15583, 0x4057),
+	(15847, 0x4160),
+	(16318, 0x4338),
+	(16434, 0x43AD),
+	(16438, 0x43B2),
+	(16481, 0x43DE),
+	(16729, 0x44D7),
+	(17102, 0x464D),
+	(17122, 0x4662),
+	(17315, 0x4724),
+	(17320, 0x472A),
+	(17402, 0x477D),
+	(17418, 0x478E),
+	(17859, 0x4948),
+	(17909, 0x497B),
+	(17911, 0x497E),
+	(17915, 0x4984),
+	(17916, 0x4987),
+	(17936, 0x499C),
+	(17939, 0x49A0),
+	(17961, 0x49B8),
+	(18664, 0x4C78),
+	(18703, 0x4CA4),
+	(18814, 0x4D1A),
+	(18962, 0x4DAF),
+	(19043, 0x9FA6),
+	(33469, 0xE76C),
+	(33470, 0xE7C8),
+	(33471, 0xE7E7),
+	(33484, 0xE815),
+	(33485, 0xE819),
+	(33490, 0xE81F),
+	(33497, 0xE827),
+	(33501, 0xE82D),
+	(33505, 0xE833),
+	(33513, 0xE83C),
+	(33520, 0xE844),
+	(33536, 0xE856),
+	(33550, 0xE865),
+	(37845, 0xF92D),
+	(37921, 0xF97A),
+	(37948, 0xF996),
+	(38029, 0xF9E8),
+	(38038, 0xF9F2),
+	(38064, 0xFA10),
+	(38065, 0xFA12),
+	(38066, 0xFA15),
+	(38069, 0xFA19),
+	(38075, 0xFA22),
+	(38076, 0xFA25),
+	(38078, 0xFA2A),
+	(39108, 0xFE32),
+	(39109, 0xFE45),
+	(39113, 0xFE53),
+	(39114, 0xFE58),
+	(39115, 0xFE67),
+	(39116, 0xFE6C),
+	(39265, 0xFF5F),
+	(39394, 0xFFE6),
+];
+
+/// Big5-HKSCS following HKSCS-2008, indexed by `(lead - 0x87) * 157 + (trail - 0x40)`, with
+/// trail bytes from 0xA1 on counted from 0x7F. The four codes decoding to two code points are
+/// left out.
+pub(crate) static BIG5_HKSCS: Index<u32> = Index {
+	code_points: &[
+		0x43F0, 0x4C32, 0x4603, 0x45A6, 0x4578, 0x27267, 0x4D77, 0x45B3, 0x27CB1, 0x4CE2, 0x27CC5,
+		0x3B95, 0x4736, 0x4744, 0x4C47, 0x4C40, 0x242BF, 0x23617, 0x27352, 0x26E8B, 0x270D2,
+		0x4C57, 0x2A351, 0x474F, 0x45DA, 0x4C85, 0x27C6C, 0x4D07, 0x4AA4, 0x46A1, 0x26B23, 0x7225,
+		0x25A54, 0x21A63, 0x23E06, 0x23F61, 0x664D, 0x56FB, 0xFFFD, 0x7D95, 0x591D, 0x28BB9,
+		0x3DF4, 0x9734, 0x27BEF, 0x5BDB, 0x21D5E, 0x5AA4, 0x3625, 0x29EB0, 0x5AD1, 0x5BB7, 0x5CFC,
+		0x676E, 0x8593, 0x29945, 0x7461, 0x749D, 0x3875, 0x21D53, 0x2369E, 0x26021, 0x3EEC,
+		0x258DE, 0x3AF5, 0x7AFC, 0x9F97, 0x24161, 0x2890D, 0x231EA, 0x20A8A, 0x2325E, 0x430A,
+		0x8484, 0x9F96, 0x942F, 0x4930, 0x8613, 0x5896, 0x974A, 0x9218, 0x79D0, 0x7A32, 0x6660,
+		0x6A29, 0x889D, 0x744C, 0x7BC5, 0x6782, 0x7A2C, 0x524F, 0x9046, 0x34E6, 0x73C4, 0x25DB9,
+		0x74C6, 0x9FC7, 0x57B3, 0x492F, 0x544C, 0x4131, 0x2368E, 0x5818, 0x7A72, 0x27B65, 0x8B8F,
+		0x46AE, 0x26E88, 0x4181, 0x25D99, 0x7BAE, 0x224BC, 0x9FC8, 0x224C1, 0x224C9, 0x224CC,
+		0x9FC9, 0x8504, 0x235BB, 0x40B4, 0x9FCA, 0x44E1, 0x2ADFF, 0x62C1, 0x706E, 0x9FCB, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x31C0, 0x31C1, 0x31C2,
+		0x31C3, 0x31C4, 0x2010C, 0x31C5, 0x200D1, 0x200CD, 0x31C6, 0x31C7, 0x200CB, 0x21FE8,
+		0x31C8, 0x200CA, 0x31C9, 0x31CA, 0x31CB, 0x31CC, 0x2010E, 0x31CD, 0x31CE, 0x0100, 0x00C1,
+		0x01CD, 0x00C0, 0x0112, 0x00C9, 0x011A, 0x00C8, 0x014C, 0x00D3, 0x01D1, 0x00D2, 0xFFFD,
+		0x1EBE, 0xFFFD, 0x1EC0, 0x00CA, 0x0101, 0x00E1, 0x01CE, 0x00E0, 0x0251, 0x0113, 0x00E9,
+		0x011B, 0x00E8, 0x012B, 0x00ED, 0x01D0, 0x00EC, 0x014D, 0x00F3, 0x01D2, 0x00F2, 0x016B,
+		0x00FA, 0x01D4, 0x00F9, 0x01D6, 0x01D8, 0x01DA, 0x01DC, 0x00FC, 0xFFFD, 0x1EBF, 0xFFFD,
+		0x1EC1, 0x00EA, 0x0261, 0x23DA, 0x23DB, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0x2A3A9, 0x21145, 0xFFFD, 0x650A, 0xFFFD, 0xFFFD, 0x4E3D, 0x6EDD, 0x9D4E, 0x91DF,
+		0xFFFD, 0xFFFD, 0x27735, 0x6491, 0x4F1A, 0x4F28, 0x4FA8, 0x5156, 0x5174, 0x519C, 0x51E4,
+		0x52A1, 0x52A8, 0x533B, 0x534E, 0x53D1, 0x53D8, 0x56E2, 0x58F0, 0x5904, 0x5907, 0x5932,
+		0x5934, 0x5B66, 0x5B9E, 0x5B9F, 0x5C9A, 0x5E86, 0x603B, 0x6589, 0x67FE, 0x6804, 0x6865,
+		0x6D4E, 0x70BC, 0x7535, 0x7EA4, 0x7EAC, 0x7EBA, 0x7EC7, 0x7ECF, 0x7EDF, 0x7F06, 0x7F37,
+		0x827A, 0x82CF, 0x836F, 0x89C6, 0x8BBE, 0x8BE2, 0x8F66, 0x8F67, 0x8F6E, 0x7411, 0x7CFC,
+		0x7DCD, 0x6946, 0x7AC9, 0x5227, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x918C, 0x78B8, 0x915E,
+		0x80BC, 0xFFFD, 0x8D0B, 0x80F6, 0x209E7, 0xFFFD, 0xFFFD, 0x809F, 0x9EC7, 0x4CCD, 0x9DC9,
+		0x9E0C, 0x4C3E, 0x29DF6, 0x2700E, 0x9E0A, 0x2A133, 0x35C1, 0xFFFD, 0x6E9A, 0x823E, 0x7519,
+		0xFFFD, 0x4911, 0x9A6C, 0x9A8F, 0x9F99, 0x7987, 0x2846C, 0x21DCA, 0x205D0, 0x22AE6, 0x4E24,
+		0x4E81, 0x4E80, 0x4E87, 0x4EBF, 0x4EEB, 0x4F37, 0x344C, 0x4FBD, 0x3E48, 0x5003, 0x5088,
+		0x347D, 0x3493, 0x34A5, 0x5186, 0x5905, 0x51DB, 0x51FC, 0x5205, 0x4E89, 0x5279, 0x5290,
+		0x5327, 0x35C7, 0x53A9, 0x3551, 0x53B0, 0x3553, 0x53C2, 0x5423, 0x356D, 0x3572, 0x3681,
+		0x5493, 0x54A3, 0x54B4, 0x54B9, 0x54D0, 0x54EF, 0x5518, 0x5523, 0x5528, 0x3598, 0x553F,
+		0x35A5, 0x35BF, 0x55D7, 0x35C5, 0x27D84, 0x5525, 0xFFFD, 0x20C42, 0x20D15, 0x2512B, 0x5590,
+		0x22CC6, 0x39EC, 0x20341, 0x8E46, 0x24DB8, 0x294E5, 0x4053, 0x280BE, 0x777A, 0x22C38,
+		0x3A34, 0x47D5, 0x2815D, 0x269F2, 0x24DEA, 0x64DD, 0x20D7C, 0x20FB4, 0x20CD5, 0x210F4,
+		0x648D, 0x8E7E, 0x20E96, 0x20C0B, 0x20F64, 0x22CA9, 0x28256, 0x244D3, 0xFFFD, 0x20D46,
+		0x29A4D, 0x280E9, 0x47F4, 0x24EA7, 0x22CC2, 0x9AB2, 0x3A67, 0x295F4, 0x3FED, 0x3506,
+		0x252C7, 0x297D4, 0x278C8, 0x22D44, 0x9D6E, 0x9815, 0xFFFD, 0x43D9, 0x260A5, 0x64B4,
+		0x54E3, 0x22D4C, 0x22BCA, 0x21077, 0x39FB, 0x2106F, 0x266DA, 0x26716, 0x279A0, 0x64EA,
+		0x25052, 0x20C43, 0x8E68, 0x221A1, 0x28B4C, 0x20731, 0xFFFD, 0x480B, 0x201A9, 0x3FFA,
+		0x5873, 0x22D8D, 0xFFFD, 0x245C8, 0x204FC, 0x26097, 0x20F4C, 0x20D96, 0x5579, 0x40BB,
+		0x43BA, 0xFFFD, 0x4AB4, 0x22A66, 0x2109D, 0x81AA, 0x98F5, 0x20D9C, 0x6379, 0x39FE, 0x22775,
+		0x8DC0, 0x56A1, 0x647C, 0x3E43, 0xFFFD, 0x2A601, 0x20E09, 0x22ACF, 0x22CC9, 0xFFFD,
+		0x210C8, 0x239C2, 0x3992, 0x3A06, 0x2829B, 0x3578, 0x25E49, 0x220C7, 0x5652, 0x20F31,
+		0x22CB2, 0x29720, 0x34BC, 0x6C3D, 0x24E3B, 0xFFFD, 0xFFFD, 0x27574, 0x22E8B, 0x22208,
+		0x2A65B, 0x28CCD, 0x20E7A, 0x20C34, 0x2681C, 0x7F93, 0x210CF, 0x22803, 0x22939, 0x35FB,
+		0x251E3, 0x20E8C, 0x20F8D, 0x20EAA, 0x3F93, 0x20F30, 0x20D47, 0x2114F, 0x20E4C, 0xFFFD,
+		0x20EAB, 0x20BA9, 0x20D48, 0x210C0, 0x2113D, 0x3FF9, 0x22696, 0x6432, 0x20FAD, 0x233F4,
+		0x27639, 0x22BCE, 0x20D7E, 0x20D7F, 0x22C51, 0x22C55, 0x3A18, 0x20E98, 0x210C7, 0x20F2E,
+		0x2A632, 0x26B50, 0x28CD2, 0x28D99, 0x28CCA, 0x95AA, 0x54CC, 0x82C4, 0x55B9, 0xFFFD,
+		0x29EC3, 0x9C26, 0x9AB6, 0x2775E, 0x22DEE, 0x7140, 0x816D, 0x80EC, 0x5C1C, 0x26572, 0x8134,
+		0x3797, 0x535F, 0x280BD, 0x91B6, 0x20EFA, 0x20E0F, 0x20E77, 0x20EFB, 0x35DD, 0x24DEB,
+		0x3609, 0x20CD6, 0x56AF, 0x227B5, 0x210C9, 0x20E10, 0x20E78, 0x21078, 0x21148, 0x28207,
+		0x21455, 0x20E79, 0x24E50, 0x22DA4, 0x5A54, 0x2101D, 0x2101E, 0x210F5, 0x210F6, 0x579C,
+		0x20E11, 0x27694, 0x282CD, 0x20FB5, 0x20E7B, 0x2517E, 0x3703, 0x20FB6, 0x21180, 0x252D8,
+		0x2A2BD, 0x249DA, 0x2183A, 0x24177, 0x2827C, 0x5899, 0x5268, 0x361A, 0x2573D, 0x7BB2,
+		0x5B68, 0x4800, 0x4B2C, 0x9F27, 0x49E7, 0x9C1F, 0x9B8D, 0x25B74, 0x2313D, 0x55FB, 0x35F2,
+		0x5689, 0x4E28, 0x5902, 0x21BC1, 0x2F878, 0x9751, 0x20086, 0x4E5B, 0x4EBB, 0x353E, 0x5C23,
+		0x5F51, 0x5FC4, 0x38FA, 0x624C, 0x6535, 0x6B7A, 0x6C35, 0x6C3A, 0x706C, 0x722B, 0x4E2C,
+		0x72AD, 0x248E9, 0x7F52, 0x793B, 0x7CF9, 0x7F53, 0x2626A, 0x34C1, 0xFFFD, 0x2634B, 0x8002,
+		0x8080, 0x26612, 0x26951, 0x535D, 0x8864, 0x89C1, 0x278B2, 0x8BA0, 0x8D1D, 0x9485, 0x9578,
+		0x957F, 0x95E8, 0x28E0F, 0x97E6, 0x9875, 0x98CE, 0x98DE, 0x9963, 0x29810, 0x9C7C, 0x9E1F,
+		0x9EC4, 0x6B6F, 0xF907, 0x4E37, 0x20087, 0x961D, 0x6237, 0x94A2, 0xFFFD, 0x503B, 0x6DFE,
+		0x29C73, 0x9FA6, 0x3DC9, 0x888F, 0x2414E, 0x7077, 0x5CF5, 0x4B20, 0x251CD, 0x3559, 0x25D30,
+		0x6122, 0x28A32, 0x8FA7, 0x91F6, 0x7191, 0x6719, 0x73BA, 0x23281, 0x2A107, 0x3C8B, 0x21980,
+		0x4B10, 0x78E4, 0x7402, 0x51AE, 0x2870F, 0x4009, 0x6A63, 0x2A2BA, 0x4223, 0x860F, 0x20A6F,
+		0x7A2A, 0x29947, 0x28AEA, 0x9755, 0x704D, 0x5324, 0x2207E, 0x93F4, 0x76D9, 0x289E3, 0x9FA7,
+		0x77DD, 0x4EA3, 0x4FF0, 0x50BC, 0x4E2F, 0x4F17, 0x9FA8, 0x5434, 0x7D8B, 0x5892, 0x58D0,
+		0x21DB6, 0x5E92, 0x5E99, 0x5FC2, 0x22712, 0x658B, 0x233F9, 0x6919, 0x6A43, 0x23C63, 0x6CFF,
+		0xFFFD, 0x7200, 0x24505, 0x738C, 0x3EDB, 0x24A13, 0x5B15, 0x74B9, 0x8B83, 0x25CA4, 0x25695,
+		0x7A93, 0x7BEC, 0x7CC3, 0x7E6C, 0x82F8, 0x8597, 0x9FA9, 0x8890, 0x9FAA, 0x8EB9, 0x9FAB,
+		0x8FCF, 0x855F, 0x99E0, 0x9221, 0x9FAC, 0x28DB9, 0x2143F, 0x4071, 0x42A2, 0x5A1A, 0xFFFD,
+		0xFFFD, 0xFFFD, 0x9868, 0x676B, 0x4276, 0x573D, 0xFFFD, 0x85D6, 0x2497B, 0x82BF, 0x2710D,
+		0x4C81, 0x26D74, 0x5D7B, 0x26B15, 0x26FBE, 0x9FAD, 0x9FAE, 0x5B96, 0x9FAF, 0x66E7, 0x7E5B,
+		0x6E57, 0x79CA, 0x3D88, 0x44C3, 0x23256, 0x22796, 0x439A, 0x4536, 0xFFFD, 0x5CD5, 0x23B1A,
+		0x8AF9, 0x5C78, 0x3D12, 0x23551, 0x5D78, 0x9FB2, 0x7157, 0x4558, 0x240EC, 0x21E23, 0x4C77,
+		0x3978, 0x344A, 0x201A4, 0x26C41, 0x8ACC, 0x4FB4, 0x20239, 0x59BF, 0x816C, 0x9856, 0x298FA,
+		0x5F3B, 0x20B9F, 0xFFFD, 0x221C1, 0x2896D, 0x4102, 0x46BB, 0x29079, 0x3F07, 0x9FB3,
+		0x2A1B5, 0x40F8, 0x37D6, 0x46F7, 0x26C46, 0x417C, 0x286B2, 0x273FF, 0x456D, 0x38D4,
+		0x2549A, 0x4561, 0x451B, 0x4D89, 0x4C7B, 0x4D76, 0x45EA, 0x3FC8, 0x24B0F, 0x3661, 0x44DE,
+		0x44BD, 0x41ED, 0x5D3E, 0x5D48, 0x5D56, 0x3DFC, 0x380F, 0x5DA4, 0x5DB9, 0x3820, 0x3838,
+		0x5E42, 0x5EBD, 0x5F25, 0x5F83, 0x3908, 0x3914, 0x393F, 0x394D, 0x60D7, 0x613D, 0x5CE5,
+		0x3989, 0x61B7, 0x61B9, 0x61CF, 0x39B8, 0x622C, 0x6290, 0x62E5, 0x6318, 0x39F8, 0x56B1,
+		0x3A03, 0x63E2, 0x63FB, 0x6407, 0x645A, 0x3A4B, 0x64C0, 0x5D15, 0x5621, 0x9F9F, 0x3A97,
+		0x6586, 0x3ABD, 0x65FF, 0x6653, 0x3AF2, 0x6692, 0x3B22, 0x6716, 0x3B42, 0x67A4, 0x6800,
+		0x3B58, 0x684A, 0x6884, 0x3B72, 0x3B71, 0x3B7B, 0x6909, 0x6943, 0x725C, 0x6964, 0x699F,
+		0x6985, 0x3BBC, 0x69D6, 0x3BDD, 0x6A65, 0x6A74, 0x6A71, 0x6A82, 0x3BEC, 0x6A99, 0x3BF2,
+		0x6AAB, 0x6AB5, 0x6AD4, 0x6AF6, 0x6B81, 0x6BC1, 0x6BEA, 0x6C75, 0x6CAA, 0x3CCB, 0x6D02,
+		0x6D06, 0x6D26, 0x6D81, 0x3CEF, 0x6DA4, 0x6DB1, 0x6E15, 0x6E18, 0x6E29, 0x6E86, 0x289C0,
+		0x6EBB, 0x6EE2, 0x6EDA, 0x9F7F, 0x6EE8, 0x6EE9, 0x6F24, 0x6F34, 0x3D46, 0x23F41, 0x6F81,
+		0x6FBE, 0x3D6A, 0x3D75, 0x71B7, 0x5C99, 0x3D8A, 0x702C, 0x3D91, 0x7050, 0x7054, 0x706F,
+		0x707F, 0x7089, 0x20325, 0x43C1, 0x35F1, 0x20ED8, 0x23ED7, 0x57BE, 0x26ED3, 0x713E,
+		0x257E0, 0x364E, 0x69A2, 0x28BE9, 0x5B74, 0x7A49, 0x258E1, 0x294D9, 0x7A65, 0x7A7D,
+		0x259AC, 0x7ABB, 0x7AB0, 0x7AC2, 0x7AC3, 0x71D1, 0x2648D, 0x41CA, 0x7ADA, 0x7ADD, 0x7AEA,
+		0x41EF, 0x54B2, 0x25C01, 0x7B0B, 0x7B55, 0x7B29, 0x2530E, 0x25CFE, 0x7BA2, 0x7B6F, 0x839C,
+		0x25BB4, 0x26C7F, 0x7BD0, 0x8421, 0x7B92, 0xFFFD, 0x25D20, 0x3DAD, 0x25C65, 0x8492, 0x7BFA,
+		0xFFFD, 0x7C35, 0x25CC1, 0x7C44, 0x7C83, 0x24882, 0x7CA6, 0x667D, 0x24578, 0x7CC9, 0x7CC7,
+		0x7CE6, 0x7C74, 0x7CF3, 0x7CF5, 0xFFFD, 0x7E67, 0x451D, 0x26E44, 0x7D5D, 0x26ED6, 0x748D,
+		0x7D89, 0x7DAB, 0x7135, 0x7DB3, 0xFFFD, 0x24057, 0x26029, 0x7DE4, 0x3D13, 0x7DF5, 0x217F9,
+		0x7DE5, 0x2836D, 0xFFFD, 0x26121, 0x2615A, 0x7E6E, 0x7E92, 0x432B, 0x946C, 0x7E27, 0x7F40,
+		0x7F41, 0x7F47, 0x7936, 0x262D0, 0x99E1, 0x7F97, 0x26351, 0x7FA3, 0x21661, 0x20068, 0x455C,
+		0x23766, 0x4503, 0x2833A, 0x7FFA, 0x26489, 0xFFFD, 0x8008, 0x801D, 0xFFFD, 0x802F, 0x2A087,
+		0x26CC3, 0x803B, 0x803C, 0x8061, 0x22714, 0x4989, 0x26626, 0x23DE3, 0x266E8, 0x6725,
+		0x80A7, 0x28A48, 0x8107, 0x811A, 0x58B0, 0x226F6, 0x6C7F, 0x26498, 0x24FB8, 0x64E7,
+		0x2148A, 0x8218, 0x2185E, 0x6A53, 0x24A65, 0x24A95, 0x447A, 0x8229, 0x20B0D, 0x26A52,
+		0x23D7E, 0x4FF9, 0x214FD, 0x84E2, 0x8362, 0x26B0A, 0x249A7, 0x23530, 0x21773, 0x23DF8,
+		0x82AA, 0x691B, 0x2F994, 0x41DB, 0x854B, 0x82D0, 0x831A, 0x20E16, 0x217B4, 0x36C1, 0x2317D,
+		0x2355A, 0x827B, 0x82E2, 0x8318, 0x23E8B, 0x26DA3, 0x26B05, 0x26B97, 0x235CE, 0x3DBF,
+		0x831D, 0x55EC, 0x8385, 0x450B, 0x26DA5, 0x83AC, 0xFFFD, 0x83D3, 0x347E, 0x26ED4, 0x6A57,
+		0x855A, 0x3496, 0x26E42, 0x22EEF, 0x8458, 0x25BE4, 0x8471, 0x3DD3, 0x44E4, 0x6AA7, 0x844A,
+		0x23CB5, 0x7958, 0xFFFD, 0x26B96, 0x26E77, 0x26E43, 0x84DE, 0xFFFD, 0x8391, 0x44A0, 0x8493,
+		0x84E4, 0x25C91, 0x4240, 0x25CC0, 0x4543, 0x8534, 0x5AF2, 0x26E99, 0x4527, 0x8573, 0x4516,
+		0x67BF, 0x8616, 0x28625, 0x2863B, 0x85C1, 0x27088, 0x8602, 0x21582, 0x270CD, 0x2F9B2,
+		0x456A, 0x8628, 0x3648, 0x218A2, 0x53F7, 0x2739A, 0x867E, 0x8771, 0x2A0F8, 0x87EE, 0x22C27,
+		0x87B1, 0x87DA, 0x880F, 0x5661, 0x866C, 0x6856, 0x460F, 0x8845, 0x8846, 0x275E0, 0x23DB9,
+		0x275E4, 0x885E, 0x889C, 0x465B, 0x88B4, 0x88B5, 0x63C1, 0x88C5, 0x7777, 0x2770F, 0x8987,
+		0x898A, 0xFFFD, 0xFFFD, 0x89A7, 0x89BC, 0x28A25, 0x89E7, 0x27924, 0x27ABD, 0x8A9C, 0x7793,
+		0x91FE, 0x8A90, 0x27A59, 0x7AE9, 0x27B3A, 0x23F8F, 0x4713, 0x27B38, 0x717C, 0x8B0C, 0x8B1F,
+		0x25430, 0x25565, 0x8B3F, 0x8B4C, 0x8B4D, 0x8AA9, 0x24A7A, 0x8B90, 0x8B9B, 0x8AAF, 0x216DF,
+		0x4615, 0x884F, 0x8C9B, 0x27D54, 0x27D8F, 0x2F9D4, 0x3725, 0x27D53, 0x8CD6, 0x27D98,
+		0x27DBD, 0x8D12, 0x8D03, 0x21910, 0x8CDB, 0x705C, 0x8D11, 0x24CC9, 0x3ED0, 0xFFFD, 0x8DA9,
+		0x28002, 0x21014, 0x2498A, 0x3B7C, 0x281BC, 0x2710C, 0x7AE7, 0x8EAD, 0x8EB6, 0x8EC3,
+		0x92D4, 0x8F19, 0x8F2D, 0x28365, 0x28412, 0x8FA5, 0x9303, 0x2A29F, 0x20A50, 0x8FB3, 0x492A,
+		0x289DE, 0x2853D, 0x23DBB, 0x5EF8, 0x23262, 0x8FF9, 0x2A014, 0x286BC, 0x28501, 0x22325,
+		0x3980, 0x26ED7, 0x9037, 0x2853C, 0x27ABE, 0x9061, 0x2856C, 0x2860B, 0x90A8, 0x28713,
+		0x90C4, 0x286E6, 0x90AE, 0xFFFD, 0x9167, 0x3AF0, 0x91A9, 0x91C4, 0x7CAC, 0x28933, 0x21E89,
+		0x920E, 0x6C9F, 0x9241, 0x9262, 0x255B9, 0xFFFD, 0x28AC6, 0x23C9B, 0x28B0C, 0x255DB,
+		0x20D31, 0x932C, 0x936B, 0x28AE1, 0x28BEB, 0x708F, 0x5AC3, 0x28AE2, 0x28AE5, 0x4965,
+		0x9244, 0x28BEC, 0x28C39, 0x28BFF, 0x9373, 0x945B, 0x8EBC, 0x9585, 0x95A6, 0x9426, 0x95A0,
+		0x6FF6, 0x42B9, 0x2267A, 0x286D8, 0x2127C, 0x23E2E, 0x49DF, 0x6C1C, 0x967B, 0x9696, 0x416C,
+		0x96A3, 0x26ED5, 0x61DA, 0x96B6, 0x78F5, 0x28AE0, 0x96BD, 0x53CC, 0x49A1, 0x26CB8, 0x20274,
+		0x26410, 0x290AF, 0x290E5, 0x24AD1, 0x21915, 0x2330A, 0x9731, 0x8642, 0x9736, 0x4A0F,
+		0x453D, 0x4585, 0x24AE9, 0x7075, 0x5B41, 0x971B, 0xFFFD, 0x291D5, 0x9757, 0x5B4A, 0x291EB,
+		0x975F, 0x9425, 0x50D0, 0x230B7, 0x230BC, 0x9789, 0x979F, 0x97B1, 0x97BE, 0x97C0, 0x97D2,
+		0x97E0, 0x2546C, 0x97EE, 0x741C, 0x29433, 0xFFFD, 0x97F5, 0x2941D, 0x2797A, 0x4AD1, 0x9834,
+		0x9833, 0x984B, 0x9866, 0x3B0E, 0x27175, 0x3D51, 0x20630, 0x2415C, 0x25706, 0x98CA, 0x98B7,
+		0x98C8, 0x98C7, 0x4AFF, 0x26D27, 0x216D3, 0x55B0, 0x98E1, 0x98E6, 0x98EC, 0x9378, 0x9939,
+		0x24A29, 0x4B72, 0x29857, 0x29905, 0x99F5, 0x9A0C, 0x9A3B, 0x9A10, 0x9A58, 0x25725, 0x36C4,
+		0x290B1, 0x29BD5, 0x9AE0, 0x9AE2, 0x29B05, 0x9AF4, 0x4C0E, 0x9B14, 0x9B2D, 0x28600, 0x5034,
+		0x9B34, 0x269A8, 0x38C3, 0x2307D, 0x9B50, 0x9B40, 0x29D3E, 0x5A45, 0x21863, 0x9B8E,
+		0x2424B, 0x9C02, 0x9BFF, 0x9C0C, 0x29E68, 0x9DD4, 0x29FB7, 0x2A192, 0x2A1AB, 0x2A0E1,
+		0x2A123, 0x2A1DF, 0x9D7E, 0x9D83, 0x2A134, 0x9E0E, 0x6888, 0x9DC4, 0x2215B, 0x2A193,
+		0x2A220, 0x2193B, 0x2A233, 0x9D39, 0x2A0B9, 0x2A2B4, 0x9E90, 0x9E95, 0x9E9E, 0x9EA2,
+		0x4D34, 0x9EAA, 0x9EAF, 0x24364, 0x9EC1, 0x3B60, 0x39E5, 0x3D1D, 0x4F32, 0x37BE, 0x28C2B,
+		0x9F02, 0x9F08, 0x4B96, 0x9424, 0x26DA2, 0x9F17, 0xFFFD, 0x9F39, 0x569F, 0x568A, 0x9F45,
+		0x99B8, 0x2908B, 0x97F2, 0x847F, 0x9F62, 0x9F69, 0x7ADC, 0x9F8E, 0x7216, 0x4BBE, 0x24975,
+		0x249BB, 0x7177, 0x249F8, 0x24348, 0x24A51, 0x739E, 0x28BDA, 0x218FA, 0x799F, 0x2897E,
+		0x28E36, 0x9369, 0x93F3, 0x28A44, 0x92EC, 0x9381, 0x93CB, 0x2896C, 0x244B9, 0x7217, 0x3EEB,
+		0x7772, 0x7A43, 0x70D0, 0x24473, 0x243F8, 0x717E, 0x217EF, 0x70A3, 0x218BE, 0x23599,
+		0x3EC7, 0x21885, 0x2542F, 0x217F8, 0x3722, 0x216FB, 0x21839, 0x36E1, 0x21774, 0x218D1,
+		0x25F4B, 0x3723, 0x216C0, 0x575B, 0x24A25, 0x213FE, 0x212A8, 0x213C6, 0x214B6, 0x8503,
+		0x236A6, 0xFFFD, 0x8455, 0x24994, 0x27165, 0x23E31, 0x2555C, 0x23EFB, 0x27052, 0x44F4,
+		0x236EE, 0x2999D, 0x26F26, 0x67F9, 0x3733, 0x3C15, 0x3DE7, 0x586C, 0x21922, 0x6810, 0x4057,
+		0x2373F, 0x240E1, 0x2408B, 0x2410F, 0x26C21, 0x54CB, 0x569E, 0x266B1, 0x5692, 0x20FDF,
+		0x20BA8, 0x20E0D, 0x93C6, 0x28B13, 0x939C, 0x4EF8, 0x512B, 0x3819, 0x24436, 0x4EBC,
+		0x20465, 0x2037F, 0x4F4B, 0x4F8A, 0x25651, 0x5A68, 0x201AB, 0x203CB, 0x3999, 0x2030A,
+		0x20414, 0x3435, 0x4F29, 0x202C0, 0x28EB3, 0x20275, 0x8ADA, 0x2020C, 0x4E98, 0x50CD,
+		0x510D, 0x4FA2, 0x4F03, 0x24A0E, 0x23E8A, 0x4F42, 0x502E, 0x506C, 0x5081, 0x4FCC, 0x4FE5,
+		0x5058, 0x50FC, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0x6E76, 0x23595, 0x23E39, 0x23EBF, 0x6D72,
+		0x21884, 0x23E89, 0x51A8, 0x51C3, 0x205E0, 0x44DD, 0x204A3, 0x20492, 0x20491, 0x8D7A,
+		0x28A9C, 0x2070E, 0x5259, 0x52A4, 0x20873, 0x52E1, 0xFFFD, 0x467A, 0x718C, 0x2438C,
+		0x20C20, 0x249AC, 0x210E4, 0x69D1, 0x20E1D, 0xFFFD, 0x3EDE, 0x7499, 0x7414, 0x7456, 0x7398,
+		0x4B8E, 0x24ABC, 0x2408D, 0x53D0, 0x3584, 0x720F, 0x240C9, 0x55B4, 0x20345, 0x54CD,
+		0x20BC6, 0x571D, 0x925D, 0x96F4, 0x9366, 0x57DD, 0x578D, 0x577F, 0x363E, 0x58CB, 0x5A99,
+		0x28A46, 0x216FA, 0x2176F, 0x21710, 0x5A2C, 0x59B8, 0x928F, 0x5A7E, 0x5ACF, 0x5A12,
+		0x25946, 0x219F3, 0x21861, 0x24295, 0x36F5, 0x6D05, 0x7443, 0x5A21, 0x25E83, 0x5A81,
+		0x28BD7, 0x20413, 0x93E0, 0x748C, 0x21303, 0x7105, 0x4972, 0x9408, 0x289FB, 0x93BD, 0x37A0,
+		0x5C1E, 0x5C9E, 0x5E5E, 0x5E48, 0x21996, 0x2197C, 0x23AEE, 0x5ECD, 0x5B4F, 0x21903,
+		0x21904, 0x3701, 0x218A0, 0x36DD, 0x216FE, 0x36D3, 0x812A, 0x28A47, 0x21DBA, 0x23472,
+		0x289A8, 0x5F0C, 0x5F0E, 0x21927, 0x217AB, 0x5A6B, 0x2173B, 0x5B44, 0x8614, 0x275FD,
+		0x8860, 0x607E, 0x22860, 0x2262B, 0x5FDB, 0x3EB8, 0x225AF, 0x225BE, 0x29088, 0x26F73,
+		0x61C0, 0x2003E, 0x20046, 0x2261B, 0x6199, 0x6198, 0x6075, 0x22C9B, 0x22D07, 0x246D4,
+		0x2914D, 0x6471, 0x24665, 0x22B6A, 0x3A29, 0x22B22, 0x23450, 0x298EA, 0x22E78, 0x6337,
+		0x2A45B, 0x64B6, 0x6331, 0x63D1, 0x249E3, 0x22D67, 0x62A4, 0x22CA1, 0x643B, 0x656B, 0x6972,
+		0x3BF4, 0x2308E, 0x232AD, 0x24989, 0x232AB, 0x550D, 0x232E0, 0x218D9, 0x2943F, 0x66CE,
+		0x23289, 0x231B3, 0x3AE0, 0x4190, 0x25584, 0x28B22, 0x2558F, 0x216FC, 0x2555B, 0x25425,
+		0x78EE, 0x23103, 0x2182A, 0x23234, 0x3464, 0x2320F, 0x23182, 0x242C9, 0x668E, 0x26D24,
+		0x666B, 0x4B93, 0x6630, 0x27870, 0x21DEB, 0x6663, 0x232D2, 0x232E1, 0x661E, 0x25872,
+		0x38D1, 0x2383A, 0x237BC, 0x3B99, 0x237A2, 0x233FE, 0x74D0, 0x3B96, 0x678F, 0x2462A,
+		0x68B6, 0x681E, 0x3BC4, 0x6ABE, 0x3863, 0x237D5, 0x24487, 0x6A33, 0x6A52, 0x6AC9, 0x6B05,
+		0x21912, 0x6511, 0x6898, 0x6A4C, 0x3BD7, 0x6A7A, 0x6B57, 0x23FC0, 0x23C9A, 0x93A0, 0x92F2,
+		0x28BEA, 0x28ACB, 0x9289, 0x2801E, 0x289DC, 0x9467, 0x6DA5, 0x6F0B, 0x249EC, 0xFFFD,
+		0x23F7F, 0x3D8F, 0x6E04, 0x2403C, 0x5A3D, 0x6E0A, 0x5847, 0x6D24, 0x7842, 0x713B, 0x2431A,
+		0x24276, 0x70F1, 0x7250, 0x7287, 0x7294, 0x2478F, 0x24725, 0x5179, 0x24AA4, 0x205EB,
+		0x747A, 0x23EF8, 0x2365F, 0x24A4A, 0x24917, 0x25FE1, 0x3F06, 0x3EB1, 0x24ADF, 0x28C23,
+		0x23F35, 0x60A7, 0x3EF3, 0x74CC, 0x743C, 0x9387, 0x7437, 0x449F, 0x26DEA, 0x4551, 0x7583,
+		0x3F63, 0x24CD9, 0x24D06, 0x3F58, 0x7555, 0x7673, 0x2A5C6, 0x3B19, 0x7468, 0x28ACC,
+		0x249AB, 0x2498E, 0x3AFB, 0x3DCD, 0x24A4E, 0x3EFF, 0x249C5, 0x248F3, 0x91FA, 0x5732,
+		0x9342, 0x28AE3, 0x21864, 0x50DF, 0x25221, 0x251E7, 0x7778, 0x23232, 0x770E, 0x770F,
+		0x777B, 0x24697, 0x23781, 0x3A5E, 0x248F0, 0x7438, 0x749B, 0x3EBF, 0x24ABA, 0x24AC7,
+		0x40C8, 0x24A96, 0x261AE, 0x9307, 0x25581, 0x781E, 0x788D, 0x7888, 0x78D2, 0x73D0, 0x7959,
+		0x27741, 0x256E3, 0x410E, 0xFFFD, 0x8496, 0x79A5, 0x6A2D, 0x23EFA, 0x7A3A, 0x79F4, 0x416E,
+		0x216E6, 0x4132, 0x9235, 0x79F1, 0x20D4C, 0x2498C, 0x20299, 0x23DBA, 0x2176E, 0x3597,
+		0x556B, 0x3570, 0x36AA, 0x201D4, 0x20C0D, 0x7AE2, 0x5A59, 0x226F5, 0x25AAF, 0x25A9C,
+		0x5A0D, 0x2025B, 0x78F0, 0x5A2A, 0x25BC6, 0x7AFE, 0x41F9, 0x7C5D, 0x7C6D, 0x4211, 0x25BB3,
+		0x25EBC, 0x25EA6, 0x7CCD, 0x249F9, 0x217B0, 0x7C8E, 0x7C7C, 0x7CAE, 0x6AB2, 0x7DDC, 0x7E07,
+		0x7DD3, 0x7F4E, 0x26261, 0x2615C, 0x27B48, 0x7D97, 0x25E82, 0x426A, 0x26B75, 0x20916,
+		0x67D6, 0x2004E, 0x235CF, 0x57C4, 0x26412, 0x263F8, 0x24962, 0x7FDD, 0x7B27, 0x2082C,
+		0x25AE9, 0x25D43, 0x7B0C, 0x25E0E, 0x99E6, 0x8645, 0x9A63, 0x6A1C, 0x2343F, 0x39E2,
+		0x249F7, 0x265AD, 0x9A1F, 0x265A0, 0x8480, 0x27127, 0x26CD1, 0x44EA, 0x8137, 0x4402,
+		0x80C6, 0x8109, 0x8142, 0x267B4, 0x98C3, 0x26A42, 0x8262, 0x8265, 0x26A51, 0x8453, 0x26DA7,
+		0x8610, 0x2721B, 0x5A86, 0x417F, 0x21840, 0x5B2B, 0x218A1, 0x5AE4, 0x218D8, 0x86A0,
+		0x2F9BC, 0x23D8F, 0x882D, 0x27422, 0x5A02, 0x886E, 0x4F45, 0x8887, 0x88BF, 0x88E6, 0x8965,
+		0x894D, 0x25683, 0x8954, 0x27785, 0x27784, 0x28BF5, 0x28BD9, 0x28B9C, 0x289F9, 0x3EAD,
+		0x84A3, 0x46F5, 0x46CF, 0x37F2, 0x8A3D, 0x8A1C, 0x29448, 0x5F4D, 0x922B, 0x24284, 0x65D4,
+		0x7129, 0x70C4, 0x21845, 0x9D6D, 0x8C9F, 0x8CE9, 0x27DDC, 0x599A, 0x77C3, 0x59F0, 0x436E,
+		0x36D4, 0x8E2A, 0x8EA7, 0x24C09, 0x8F30, 0x8F4A, 0x42F4, 0x6C58, 0x6FBB, 0x22321, 0x489B,
+		0x6F79, 0x6E8B, 0x217DA, 0x9BE9, 0x36B5, 0x2492F, 0x90BB, 0xFFFD, 0x5571, 0x4906, 0x91BB,
+		0x9404, 0x28A4B, 0x4062, 0x28AFC, 0x9427, 0x28C1D, 0x28C3B, 0x84E5, 0x8A2B, 0x9599, 0x95A7,
+		0x9597, 0x9596, 0x28D34, 0x7445, 0x3EC2, 0x248FF, 0x24A42, 0x243EA, 0x3EE7, 0x23225,
+		0x968F, 0x28EE7, 0x28E66, 0x28E65, 0x3ECC, 0x249ED, 0x24A78, 0x23FEE, 0x7412, 0x746B,
+		0x3EFC, 0x9741, 0x290B0, 0x6847, 0x4A1D, 0x29093, 0x257DF, 0xFFFD, 0x9368, 0x28989,
+		0x28C26, 0x28B2F, 0x263BE, 0x92BA, 0x5B11, 0x8B69, 0x493C, 0x73F9, 0x2421B, 0x979B, 0x9771,
+		0x9938, 0x20F26, 0x5DC1, 0x28BC5, 0x24AB2, 0x981F, 0x294DA, 0x92F6, 0x295D7, 0x91E5,
+		0x44C0, 0x28B50, 0x24A67, 0x28B64, 0x98DC, 0x28A45, 0x3F00, 0x922A, 0x4925, 0x8414, 0x993B,
+		0x994D, 0x27B06, 0x3DFD, 0x999B, 0x4B6F, 0x99AA, 0x9A5C, 0x28B65, 0x258C8, 0x6A8F, 0x9A21,
+		0x5AFE, 0x9A2F, 0x298F1, 0x4B90, 0x29948, 0x99BC, 0x4BBD, 0x4B97, 0x937D, 0x5872, 0x21302,
+		0x5822, 0x249B8, 0x214E8, 0x7844, 0x2271F, 0x23DB8, 0x68C5, 0x3D7D, 0x9458, 0x3927, 0x6150,
+		0x22781, 0x2296B, 0x6107, 0x9C4F, 0x9C53, 0x9C7B, 0x9C35, 0x9C10, 0x9B7F, 0x9BCF, 0x29E2D,
+		0x9B9F, 0x2A1F5, 0x2A0FE, 0x9D21, 0x4CAE, 0x24104, 0x9E18, 0x4CB0, 0x9D0C, 0x2A1B4,
+		0x2A0ED, 0x2A0F3, 0x2992F, 0x9DA5, 0x84BD, 0x26E12, 0x26FDF, 0x26B82, 0x85FC, 0x4533,
+		0x26DA4, 0x26E84, 0x26DF0, 0x8420, 0x85EE, 0x26E00, 0x237D7, 0x26064, 0x79E2, 0x2359C,
+		0x23640, 0x492D, 0x249DE, 0x3D62, 0x93DB, 0x92BE, 0x9348, 0x202BF, 0x78B9, 0x9277, 0x944D,
+		0x4FE4, 0x3440, 0x9064, 0x2555D, 0x783D, 0x7854, 0x78B6, 0x784B, 0x21757, 0x231C9, 0x24941,
+		0x369A, 0x4F72, 0x6FDA, 0x6FD9, 0xFFFD, 0x701E, 0x5414, 0x241B5, 0x57BB, 0x58F3, 0x578A,
+		0x9D16, 0x57D7, 0x7134, 0x34AF, 0x241AC, 0x71EB, 0x26C40, 0x24F97, 0xFFFD, 0x217B5,
+		0x28A49, 0x610C, 0x5ACE, 0x5A0B, 0x42BC, 0x24488, 0x372C, 0x4B7B, 0x289FC, 0x93BB, 0x93B8,
+		0x218D6, 0x20F1D, 0x8472, 0x26CC0, 0x21413, 0x242FA, 0x22C26, 0x243C1, 0x5994, 0x23DB7,
+		0x26741, 0x7DA8, 0x2615B, 0x260A4, 0x249B9, 0x2498B, 0x289FA, 0x92E5, 0x73E2, 0x3EE9,
+		0x74B4, 0x28B63, 0x2189F, 0x3EE1, 0x24AB3, 0x6AD8, 0x73F3, 0x73FB, 0x3ED6, 0x24A3E,
+		0x24A94, 0x217D9, 0x24A66, 0x203A7, 0x21424, 0x249E5, 0x7448, 0x24916, 0x70A5, 0x24976,
+		0x9284, 0x73E6, 0x935F, 0x204FE, 0x9331, 0x28ACE, 0x28A16, 0x9386, 0x28BE7, 0x255D5,
+		0x4935, 0x28A82, 0x716B, 0x24943, 0x20CFF, 0x56A4, 0x2061A, 0x20BEB, 0x20CB8, 0x5502,
+		0x79C4, 0x217FA, 0x7DFE, 0x216C2, 0x24A50, 0x21852, 0x452E, 0x9401, 0x370A, 0x28AC0,
+		0x249AD, 0x59B0, 0x218BF, 0x21883, 0x27484, 0x5AA1, 0x36E2, 0x23D5B, 0x36B0, 0x925F,
+		0x5A79, 0x28A81, 0x21862, 0x9374, 0x3CCD, 0x20AB4, 0x4A96, 0x398A, 0x50F4, 0x3D69, 0x3D4C,
+		0x2139C, 0x7175, 0x42FB, 0x28218, 0x6E0F, 0x290E4, 0x44EB, 0x6D57, 0x27E4F, 0x7067, 0x6CAF,
+		0x3CD6, 0x23FED, 0x23E2D, 0x6E02, 0x6F0C, 0x3D6F, 0x203F5, 0x7551, 0x36BC, 0x34C8, 0x4680,
+		0x3EDA, 0x4871, 0x59C4, 0x926E, 0x493E, 0x8F41, 0x28C1C, 0x26BC0, 0x5812, 0x57C8, 0x36D6,
+		0x21452, 0x70FE, 0x24362, 0x24A71, 0x22FE3, 0x212B0, 0x223BD, 0x68B9, 0x6967, 0x21398,
+		0x234E5, 0x27BF4, 0x236DF, 0x28A83, 0x237D6, 0x233FA, 0x24C9F, 0x6A1A, 0x236AD, 0x26CB7,
+		0x843E, 0x44DF, 0x44CE, 0x26D26, 0x26D51, 0x26C82, 0x26FDE, 0x6F17, 0x27109, 0x833D,
+		0x2173A, 0x83ED, 0x26C80, 0x27053, 0x217DB, 0x5989, 0x5A82, 0x217B3, 0x5A61, 0x5A71,
+		0x21905, 0x241FC, 0x372D, 0x59EF, 0x2173C, 0x36C7, 0x718E, 0x9390, 0x669A, 0x242A5, 0x5A6E,
+		0x5A2B, 0x24293, 0x6A2B, 0x23EF9, 0x27736, 0x2445B, 0x242CA, 0x711D, 0x24259, 0x289E1,
+		0x4FB0, 0x26D28, 0x5CC2, 0x244CE, 0x27E4D, 0x243BD, 0x6A0C, 0x24256, 0x21304, 0x70A6,
+		0x7133, 0x243E9, 0x3DA5, 0x6CDF, 0x2F825, 0x24A4F, 0x7E65, 0x59EB, 0x5D2F, 0x3DF3, 0x5F5C,
+		0x24A5D, 0x217DF, 0x7DA4, 0x8426, 0x5485, 0x23AFA, 0x23300, 0x20214, 0x577E, 0x208D5,
+		0x20619, 0x3FE5, 0x21F9E, 0x2A2B6, 0x7003, 0x2915B, 0x5D70, 0x738F, 0x7CD3, 0x28A59,
+		0x29420, 0x4FC8, 0x7FE7, 0x72CD, 0x7310, 0x27AF4, 0x7338, 0x7339, 0x256F6, 0x7341, 0x7348,
+		0x3EA9, 0x27B18, 0x906C, 0x71F5, 0x248F2, 0x73E1, 0x81F6, 0x3ECA, 0x770C, 0x3ED1, 0x6CA2,
+		0x56FD, 0x7419, 0x741E, 0x741F, 0x3EE2, 0x3EF0, 0x3EF4, 0x3EFA, 0x74D3, 0x3F0E, 0x3F53,
+		0x7542, 0x756D, 0x7572, 0x758D, 0x3F7C, 0x75C8, 0x75DC, 0x3FC0, 0x764D, 0x3FD7, 0x7674,
+		0x3FDC, 0x767A, 0x24F5C, 0x7188, 0x5623, 0x8980, 0x5869, 0x401D, 0x7743, 0x4039, 0x6761,
+		0x4045, 0x35DB, 0x7798, 0x406A, 0x406F, 0x5C5E, 0x77BE, 0x77CB, 0x58F2, 0x7818, 0x70B9,
+		0x781C, 0x40A8, 0x7839, 0x7847, 0x7851, 0x7866, 0x8448, 0x25535, 0x7933, 0x6803, 0x7932,
+		0x4103, 0x4109, 0x7991, 0x7999, 0x8FBB, 0x7A06, 0x8FBC, 0x4167, 0x7A91, 0x41B2, 0x7ABC,
+		0x8279, 0x41C4, 0x7ACF, 0x7ADB, 0x41CF, 0x4E21, 0x7B62, 0x7B6C, 0x7B7B, 0x7C12, 0x7C1B,
+		0x4260, 0x427A, 0x7C7B, 0x7C9C, 0x428C, 0x7CB8, 0x4294, 0x7CED, 0x8F93, 0x70C0, 0x20CCF,
+		0x7DCF, 0x7DD4, 0x7DD0, 0x7DFD, 0x7FAE, 0x7FB4, 0x729F, 0x4397, 0x8020, 0x8025, 0x7B39,
+		0x802E, 0x8031, 0x8054, 0x3DCC, 0x57B4, 0x70A0, 0x80B7, 0x80E9, 0x43ED, 0x810C, 0x732A,
+		0x810E, 0x8112, 0x7560, 0x8114, 0x4401, 0x3B39, 0x8156, 0x8159, 0x815A, 0x4413, 0x583A,
+		0x817C, 0x8184, 0x4425, 0x8193, 0x442D, 0x81A5, 0x57EF, 0x81C1, 0x81E4, 0x8254, 0x448F,
+		0x82A6, 0x8276, 0x82CA, 0x82D8, 0x82FF, 0x44B0, 0x8357, 0x9669, 0x698A, 0x8405, 0x70F5,
+		0x8464, 0x60E3, 0x8488, 0x4504, 0x84BE, 0x84E1, 0x84F8, 0x8510, 0x8538, 0x8552, 0x453B,
+		0x856F, 0x8570, 0x85E0, 0x4577, 0x8672, 0x8692, 0x86B2, 0x86EF, 0x9645, 0x878B, 0x4606,
+		0x4617, 0x88AE, 0x88FF, 0x8924, 0x8947, 0x8991, 0x27967, 0x8A29, 0x8A38, 0x8A94, 0x8AB4,
+		0x8C51, 0x8CD4, 0x8CF2, 0x8D1C, 0x4798, 0x585F, 0x8DC3, 0x47ED, 0x4EEE, 0x8E3A, 0x55D8,
+		0x5754, 0x8E71, 0x55F5, 0x8EB0, 0x4837, 0x8ECE, 0x8EE2, 0x8EE4, 0x8EED, 0x8EF2, 0x8FB7,
+		0x8FC1, 0x8FCA, 0x8FCC, 0x9033, 0x99C4, 0x48AD, 0x98E0, 0x9213, 0x491E, 0x9228, 0x9258,
+		0x926B, 0x92B1, 0x92AE, 0x92BF, 0x92E3, 0x92EB, 0x92F3, 0x92F4, 0x92FD, 0x9343, 0x9384,
+		0x93AD, 0x4945, 0x4951, 0x9EBF, 0x9417, 0x5301, 0x941D, 0x942D, 0x943E, 0x496A, 0x9454,
+		0x9479, 0x952D, 0x95A2, 0x49A7, 0x95F4, 0x9633, 0x49E5, 0x67A0, 0x4A24, 0x9740, 0x4A35,
+		0x97B2, 0x97C2, 0x5654, 0x4AE4, 0x60E8, 0x98B9, 0x4B19, 0x98F1, 0x5844, 0x990E, 0x9919,
+		0x51B4, 0x991C, 0x9937, 0x9942, 0x995D, 0x9962, 0x4B70, 0x99C5, 0x4B9D, 0x9A3C, 0x9B0F,
+		0x7A83, 0x9B69, 0x9B81, 0x9BDD, 0x9BF1, 0x9BF4, 0x4C6D, 0x9C20, 0x376F, 0x21BC2, 0x9D49,
+		0x9C3A, 0x9EFE, 0x5650, 0x9D93, 0x9DBD, 0x9DC0, 0x9DFC, 0x94F6, 0x8FB6, 0x9E7B, 0x9EAC,
+		0x9EB1, 0x9EBD, 0x9EC6, 0x94DC, 0x9EE2, 0x9EF1, 0x9EF8, 0x7AC8, 0x9F44, 0x20094, 0x202B7,
+		0x203A0, 0x691A, 0x94C3, 0x59AC, 0x204D7, 0x5840, 0x94C1, 0x37B9, 0x205D5, 0x20615,
+		0x20676, 0x216BA, 0x5757, 0x7173, 0x20AC2, 0x20ACD, 0x20BBF, 0x546A, 0x2F83B, 0x20BCB,
+		0x549E, 0x20BFB, 0x20C3B, 0x20C53, 0x20C65, 0x20C7C, 0x60E7, 0x20C8D, 0x567A, 0x20CB5,
+		0x20CDD, 0x20CED, 0x20D6F, 0x20DB2, 0x20DC8, 0x6955, 0x9C2F, 0x87A5, 0x20E04, 0x20E0E,
+		0x20ED7, 0x20F90, 0x20F2D, 0x20E73, 0x5C20, 0x20FBC, 0x5E0B, 0x2105C, 0x2104F, 0x21076,
+		0x671E, 0x2107B, 0x21088, 0x21096, 0x3647, 0x210BF, 0x210D3, 0x2112F, 0x2113B, 0x5364,
+		0x84AD, 0x212E3, 0x21375, 0x21336, 0x8B81, 0x21577, 0x21619, 0x217C3, 0x217C7, 0x4E78,
+		0x70BB, 0x2182D, 0x2196A, 0x21A2D, 0x21A45, 0x21C2A, 0x21C70, 0x21CAC, 0x21EC8, 0x62C3,
+		0x21ED5, 0x21F15, 0x7198, 0x6855, 0x22045, 0x69E9, 0x36C8, 0x2227C, 0x223D7, 0x223FA,
+		0x2272A, 0x22871, 0x2294F, 0x82FD, 0x22967, 0x22993, 0x22AD5, 0x89A5, 0x22AE8, 0x8FA0,
+		0x22B0E, 0x97B8, 0x22B3F, 0x9847, 0x9ABD, 0x22C4C, 0xFFFD, 0x22C88, 0x22CB7, 0x25BE8,
+		0x22D08, 0x22D12, 0x22DB7, 0x22D95, 0x22E42, 0x22F74, 0x22FCC, 0x23033, 0x23066, 0x2331F,
+		0x233DE, 0x5FB1, 0x6648, 0x66BF, 0x27A79, 0x23567, 0x235F3, 0xFFFD, 0x249BA, 0xFFFD,
+		0x2361A, 0x23716, 0xFFFD, 0x20346, 0x58B5, 0x670E, 0x6918, 0x23AA7, 0x27657, 0x25FE2,
+		0x23E11, 0x23EB9, 0x275FE, 0x2209A, 0x48D0, 0x4AB8, 0x24119, 0x28A9A, 0x242EE, 0x2430D,
+		0x2403B, 0x24334, 0x24396, 0x24A45, 0x205CA, 0x51D2, 0x20611, 0x599F, 0x21EA8, 0x3BBE,
+		0x23CFF, 0x24404, 0x244D6, 0x5788, 0x24674, 0x399B, 0x2472F, 0x285E8, 0x299C9, 0x3762,
+		0x221C3, 0x8B5E, 0x28B4E, 0xFFFD, 0x24812, 0x248FB, 0x24A15, 0x7209, 0x24AC0, 0x20C78,
+		0x5965, 0x24EA5, 0x24F86, 0x20779, 0x8EDA, 0x2502C, 0x528F, 0x573F, 0x7171, 0x25299,
+		0x25419, 0x23F4A, 0x24AA7, 0x55BC, 0x25446, 0x2546E, 0x26B52, 0xFFFD, 0x3473, 0x2553F,
+		0x27632, 0x2555E, 0x4718, 0x25562, 0x25566, 0x257C7, 0x2493F, 0x2585D, 0x5066, 0x34FB,
+		0x233CC, 0xFFFD, 0x25903, 0x477C, 0x28948, 0x25AAE, 0x25B89, 0x25C06, 0x21D90, 0x57A1,
+		0x7151, 0xFFFD, 0x26102, 0x27C12, 0x9056, 0x261B2, 0x24F9A, 0x8B62, 0x26402, 0x2644A,
+		0x5D5B, 0x26BF7, 0xFFFD, 0x26484, 0x2191C, 0x8AEA, 0x249F6, 0x26488, 0x23FEF, 0x26512,
+		0x4BC0, 0x265BF, 0x266B5, 0x2271B, 0x9465, 0x257E1, 0x6195, 0x5A27, 0x2F8CD, 0xFFFD,
+		0x56B9, 0x24521, 0x266FC, 0x4E6A, 0x24934, 0x9656, 0x6D8F, 0x26CBD, 0x3618, 0x8977,
+		0x26799, 0x2686E, 0x26411, 0x2685E, 0xFFFD, 0x268C7, 0x7B42, 0x290C0, 0x20A11, 0x26926,
+		0xFFFD, 0x26939, 0x7A45, 0xFFFD, 0x269FA, 0x9A26, 0x26A2D, 0x365F, 0x26469, 0x20021,
+		0x7983, 0x26A34, 0x26B5B, 0x5D2C, 0x23519, 0xFFFD, 0x26B9D, 0x46D0, 0x26CA4, 0x753B,
+		0x8865, 0x26DAE, 0x58B6, 0x371C, 0x2258D, 0x2704B, 0x271CD, 0x3C54, 0x27280, 0x27285,
+		0x9281, 0x2217A, 0x2728B, 0x9330, 0x272E6, 0x249D0, 0x6C39, 0x949F, 0x27450, 0x20EF8,
+		0x8827, 0x88F5, 0x22926, 0x28473, 0x217B1, 0x6EB8, 0x24A2A, 0x21820, 0x39A4, 0x36B9,
+		0xFFFD, 0xFFFD, 0x453F, 0x66B6, 0x29CAD, 0x298A4, 0x8943, 0x277CC, 0x27858, 0x56D6, 0x40DF,
+		0x2160A, 0x39A1, 0x2372F, 0x280E8, 0x213C5, 0x71AD, 0x8366, 0x279DD, 0x291A8, 0xFFFD,
+		0x4CB7, 0x270AF, 0x289AB, 0x279FD, 0x27A0A, 0x27B0B, 0x27D66, 0x2417A, 0x7B43, 0x797E,
+		0x28009, 0x6FB5, 0x2A2DF, 0x6A03, 0x28318, 0x53A2, 0x26E07, 0x93BF, 0x6836, 0x975D,
+		0x2816F, 0x28023, 0x269B5, 0x213ED, 0x2322F, 0x28048, 0x5D85, 0x28C30, 0x28083, 0x5715,
+		0x9823, 0x28949, 0x5DAB, 0x24988, 0x65BE, 0x69D5, 0x53D2, 0x24AA5, 0x23F81, 0x3C11, 0x6736,
+		0x28090, 0x280F4, 0x2812E, 0x21FA1, 0x2814F, 0x28189, 0x281AF, 0x2821A, 0x28306, 0x2832F,
+		0x2838A, 0x35CA, 0x28468, 0x286AA, 0x48FA, 0x63E6, 0x28956, 0x7808, 0x9255, 0x289B8,
+		0x43F2, 0x289E7, 0x43DF, 0x289E8, 0x28B46, 0x28BD4, 0x59F8, 0x28C09, 0xFFFD, 0x28FC5,
+		0x290EC, 0xFFFD, 0x29110, 0x2913C, 0x3DF7, 0x2915E, 0x24ACA, 0x8FD0, 0x728F, 0x568B,
+		0x294E7, 0x295E9, 0x295B0, 0x295B8, 0x29732, 0x298D1, 0x29949, 0x2996A, 0x299C3, 0x29A28,
+		0x29B0E, 0x29D5A, 0x29D9B, 0x7E9F, 0x29EF8, 0x29F23, 0x4CA4, 0x9547, 0x2A293, 0x71A2,
+		0x2A2FF, 0x4D91, 0x9012, 0x2A5CB, 0x4D9C, 0x20C9C, 0x8FBE, 0x55C1, 0x8FBA, 0x224B0, 0x8FB9,
+		0x24A93, 0x4509, 0x7E7F, 0x6F56, 0x6AB1, 0x4EEA, 0x34E4, 0x28B2C, 0x2789D, 0x373A, 0x8E80,
+		0x217F5, 0x28024, 0x28B6C, 0x28B99, 0x27A3E, 0x266AF, 0x3DEB, 0x27655, 0x23CB7, 0x25635,
+		0x25956, 0x4E9A, 0x25E81, 0x26258, 0x56BF, 0x20E6D, 0x8E0E, 0x5B6D, 0x23E88, 0x24C9E,
+		0x63DE, 0xFFFD, 0x217F6, 0x2187B, 0x6530, 0x562D, 0x25C4A, 0x541A, 0x25311, 0x3DC6,
+		0x29D98, 0x4C7D, 0x5622, 0x561E, 0x7F49, 0x25ED8, 0x5975, 0x23D40, 0x8770, 0x4E1C, 0x20FEA,
+		0x20D49, 0x236BA, 0x8117, 0x9D5E, 0x8D18, 0x763B, 0x9C45, 0x764E, 0x77B9, 0x9345, 0x5432,
+		0x8148, 0x82F7, 0x5625, 0x8132, 0x8418, 0x80BD, 0x55EA, 0x7962, 0x5643, 0x5416, 0x20E9D,
+		0x35CE, 0x5605, 0x55F1, 0x66F1, 0x282E2, 0x362D, 0x7534, 0x55F0, 0x55BA, 0x5497, 0x5572,
+		0x20C41, 0x20C96, 0x5ED0, 0x25148, 0x20E76, 0x22C62, 0x20EA2, 0x9EAB, 0x7D5A, 0x55DE,
+		0x21075, 0x629D, 0x976D, 0x5494, 0x8CCD, 0x71F6, 0x9176, 0x63FC, 0x63B9, 0x63FE, 0x5569,
+		0x22B43, 0x9C72, 0x22EB3, 0x519A, 0x34DF, 0x20DA7, 0x51A7, 0x544D, 0x551E, 0x5513, 0x7666,
+		0x8E2D, 0x2688A, 0x75B1, 0x80B6, 0x8804, 0x8786, 0x88C7, 0x81B6, 0x841C, 0x210C1, 0x44EC,
+		0x7304, 0x24706, 0x5B90, 0x830B, 0x26893, 0x567B, 0x226F4, 0x27D2F, 0x241A3, 0x27D73,
+		0x26ED0, 0x272B6, 0x9170, 0x211D9, 0x9208, 0x23CFC, 0x2A6A9, 0x20EAC, 0x20EF9, 0x7266,
+		0x21CA2, 0x474E, 0x24FC2, 0x27FF9, 0x20FEB, 0x40FA, 0x9C5D, 0x651F, 0x22DA0, 0x48F3,
+		0x247E0, 0x29D7C, 0x20FEC, 0x20E0A, 0xFFFD, 0x275A3, 0x20FED, 0xFFFD, 0x26048, 0x21187,
+		0x71A3, 0x7E8E, 0x9D50, 0x4E1A, 0x4E04, 0x3577, 0x5B0D, 0x6CB2, 0x5367, 0x36AC, 0x39DC,
+		0x537D, 0x36A5, 0x24618, 0x589A, 0x24B6E, 0x822D, 0x544B, 0x57AA, 0x25A95, 0x20979, 0xFFFD,
+		0x3A52, 0x22465, 0x7374, 0x29EAC, 0x4D09, 0x9BED, 0x23CFE, 0x29F30, 0x4C5B, 0x24FA9,
+		0x2959E, 0x29FDE, 0x845C, 0x23DB6, 0x272B2, 0x267B3, 0x23720, 0x632E, 0x7D25, 0x23EF7,
+		0x23E2C, 0x3A2A, 0x9008, 0x52CC, 0x3E74, 0x367A, 0x45E9, 0x2048E, 0x7640, 0x5AF0, 0x20EB6,
+		0x787A, 0x27F2E, 0x58A7, 0x40BF, 0x567C, 0x9B8B, 0x5D74, 0x7654, 0x2A434, 0x9E85, 0x4CE1,
+		0xFFFD, 0x37FB, 0x6119, 0x230DA, 0x243F2, 0xFFFD, 0x565D, 0x212A9, 0x57A7, 0x24963,
+		0x29E06, 0x5234, 0x270AE, 0x35AD, 0xFFFD, 0x9D7C, 0x7C56, 0x9B39, 0x57DE, 0x2176C, 0x5C53,
+		0x64D3, 0x294D0, 0x26335, 0x27164, 0x86AD, 0x20D28, 0x26D22, 0x24AE2, 0x20D71, 0xFFFD,
+		0x51FE, 0x21F0F, 0x5D8E, 0x9703, 0x21DD1, 0x9E81, 0x904C, 0x7B1F, 0x9B02, 0x5CD1, 0x7BA3,
+		0x6268, 0x6335, 0x9AFF, 0x7BCF, 0x9B2A, 0x7C7E, 0xFFFD, 0x7C42, 0x7C86, 0x9C15, 0x7BFC,
+		0x9B09, 0xFFFD, 0x9C1B, 0x2493E, 0x9F5A, 0x5573, 0x5BC3, 0x4FFD, 0x9E98, 0x4FF2, 0x5260,
+		0x3E06, 0x52D1, 0x5767, 0x5056, 0x59B7, 0x5E12, 0x97C8, 0x9DAB, 0x8F5C, 0x5469, 0x97B4,
+		0x9940, 0x97BA, 0x532C, 0x6130, 0x692C, 0x53DA, 0x9C0A, 0x9D02, 0x4C3B, 0x9641, 0x6980,
+		0x50A6, 0x7546, 0x2176D, 0x99DA, 0x5273, 0xFFFD, 0x9159, 0x9681, 0x915C, 0xFFFD, 0x9151,
+		0x28E97, 0x637F, 0x26D23, 0x6ACA, 0x5611, 0x918E, 0x757A, 0x6285, 0x203FC, 0x734F, 0x7C70,
+		0x25C21, 0x23CFD, 0xFFFD, 0x24919, 0x76D6, 0x9B9D, 0x4E2A, 0x20CD4, 0x83BE, 0x8842, 0xFFFD,
+		0x5C4A, 0x69C0, 0xFFFD, 0x577A, 0x521F, 0x5DF5, 0x4ECE, 0x6C31, 0x201F2, 0x4F39, 0x549C,
+		0x54DA, 0x529A, 0x8D82, 0x35FE, 0xFFFD, 0x35F3, 0xFFFD, 0x6B52, 0x917C, 0x9FA5, 0x9B97,
+		0x982E, 0x98B4, 0x9ABA, 0x9EA8, 0x9E84, 0x717A, 0x7B14, 0xFFFD, 0x6BFA, 0x8818, 0x7F78,
+		0xFFFD, 0x5620, 0x2A64A, 0x8E77, 0x9F53, 0xFFFD, 0x8DD4, 0x8E4F, 0x9E1C, 0x8E01, 0x6282,
+		0x2837D, 0x8E28, 0x8E75, 0x7AD3, 0x24A77, 0x7A3E, 0x78D8, 0x6CEA, 0x8A67, 0x7607, 0x28A5A,
+		0x9F26, 0x6CCE, 0x87D6, 0x75C3, 0x2A2B2, 0x7853, 0x2F840, 0x8D0C, 0x72E2, 0x7371, 0x8B2D,
+		0x7302, 0x74F1, 0x8CEB, 0x24ABB, 0x862F, 0x5FBA, 0x88A0, 0x44B7, 0xFFFD, 0x2183B, 0x26E05,
+		0xFFFD, 0x8A7E, 0x2251B, 0xFFFD, 0x60FD, 0x7667, 0x9AD7, 0x9D44, 0x936E, 0x9B8F, 0x87F5,
+		0xFFFD, 0xFFFD, 0x8CF7, 0x732C, 0x9721, 0x9BB0, 0x35D6, 0x72B2, 0x4C07, 0x7C51, 0x994A,
+		0x26159, 0x6159, 0x4C04, 0x9E96, 0x617D, 0xFFFD, 0x575F, 0x616F, 0x62A6, 0x6239, 0xFFFD,
+		0x3A5C, 0x61E2, 0x53AA, 0x233F5, 0x6364, 0x6802, 0x35D2, 0x5D57, 0x28BC2, 0x8FDA, 0x28E39,
+		0xFFFD, 0x50D9, 0x21D46, 0x7906, 0x5332, 0x9638, 0x20F3B, 0x4065, 0xFFFD, 0x77FE, 0xFFFD,
+		0x7CC2, 0x25F1A, 0x7CDA, 0x7A2D, 0x8066, 0x8063, 0x7D4D, 0x7505, 0x74F2, 0x8994, 0x821A,
+		0x670C, 0x8062, 0x27486, 0x805B, 0x74F0, 0x8103, 0x7724, 0x8989, 0x267CC, 0x7553, 0x26ED1,
+		0x87A9, 0x87CE, 0x81C8, 0x878C, 0x8A49, 0x8CAD, 0x8B43, 0x772B, 0x74F8, 0x84DA, 0x3635,
+		0x69B2, 0x8DA6, 0xFFFD, 0x89A9, 0xFFFD, 0x6DB9, 0x87C1, 0x24011, 0x74E7, 0x3DDB, 0x7176,
+		0x60A4, 0x619C, 0x3CD1, 0xFFFD, 0x6077, 0xFFFD, 0x7F71, 0x28B2D, 0xFFFD, 0x60E9, 0x4B7E,
+		0x5220, 0x3C18, 0x23CC7, 0x25ED7, 0x27656, 0x25531, 0x21944, 0x212FE, 0x29903, 0x26DDC,
+		0x270AD, 0x5CC1, 0x261AD, 0x28A0F, 0x23677, 0x200EE, 0x26846, 0x24F0E, 0x4562, 0x5B1F,
+		0x2634C, 0x9F50, 0x9EA6, 0x2626B, 0x3000, 0xFF0C, 0x3001, 0x3002, 0xFF0E, 0x2022, 0xFF1B,
+		0xFF1A, 0xFF1F, 0xFF01, 0xFE30, 0x2026, 0x2025, 0xFE50, 0xFF64, 0xFE52, 0x00B7, 0xFE54,
+		0xFE55, 0xFE56, 0xFE57, 0xFF5C, 0x2013, 0xFE31, 0x2014, 0xFE33, 0x2574, 0xFE34, 0xFE4F,
+		0xFF08, 0xFF09, 0xFE35, 0xFE36, 0xFF5B, 0xFF5D, 0xFE37, 0xFE38, 0x3014, 0x3015, 0xFE39,
+		0xFE3A, 0x3010, 0x3011, 0xFE3B, 0xFE3C, 0x300A, 0x300B, 0xFE3D, 0xFE3E, 0x3008, 0x3009,
+		0xFE3F, 0xFE40, 0x300C, 0x300D, 0xFE41, 0xFE42, 0x300E, 0x300F, 0xFE43, 0xFE44, 0xFE59,
+		0xFE5A, 0xFE5B, 0xFE5C, 0xFE5D, 0xFE5E, 0x2018, 0x2019, 0x201C, 0x201D, 0x301D, 0x301E,
+		0x2035, 0x2032, 0xFF03, 0xFF06, 0xFF0A, 0x203B, 0x00A7, 0x3003, 0x25CB, 0x25CF, 0x25B3,
+		0x25B2, 0x25CE, 0x2606, 0x2605, 0x25C7, 0x25C6, 0x25A1, 0x25A0, 0x25BD, 0x25BC, 0x32A3,
+		0x2105, 0x203E, 0xFFE3, 0xFF3F, 0x02CD, 0xFE49, 0xFE4A, 0xFE4D, 0xFE4E, 0xFE4B, 0xFE4C,
+		0xFE5F, 0xFE60, 0xFE61, 0xFF0B, 0xFF0D, 0x00D7, 0x00F7, 0x00B1, 0x221A, 0xFF1C, 0xFF1E,
+		0xFF1D, 0x2266, 0x2267, 0x2260, 0x221E, 0x2252, 0x2261, 0xFE62, 0xFE63, 0xFE64, 0xFE65,
+		0xFE66, 0x223C, 0x2229, 0x222A, 0x22A5, 0x2220, 0x221F, 0x22BF, 0x33D2, 0x33D1, 0x222B,
+		0x222E, 0x2235, 0x2234, 0x2640, 0x2642, 0x2641, 0x2609, 0x2191, 0x2193, 0x2190, 0x2192,
+		0x2196, 0x2197, 0x2199, 0x2198, 0x2225, 0x2223, 0xFF0F, 0xFF3C, 0xFF0F, 0xFF3C, 0xFF04,
+		0x00A5, 0x3012, 0x00A2, 0x00A3, 0xFF05, 0xFF20, 0x2103, 0x2109, 0xFE69, 0xFE6A, 0xFE6B,
+		0x33D5, 0x339C, 0x339D, 0x339E, 0x33CE, 0x33A1, 0x338E, 0x338F, 0x33C4, 0x00B0, 0x5159,
+		0x515B, 0x515E, 0x515D, 0x5161, 0x5163, 0x55E7, 0x74E9, 0x7CCE, 0x2581, 0x2582, 0x2583,
+		0x2584, 0x2585, 0x2586, 0x2587, 0x2588, 0x258F, 0x258E, 0x258D, 0x258C, 0x258B, 0x258A,
+		0x2589, 0x253C, 0x2534, 0x252C, 0x2524, 0x251C, 0x2594, 0x2500, 0x2502, 0x2595, 0x250C,
+		0x2510, 0x2514, 0x2518, 0x256D, 0x256E, 0x2570, 0x256F, 0x2550, 0x255E, 0x256A, 0x2561,
+		0x25E2, 0x25E3, 0x25E5, 0x25E4, 0x2571, 0x2572, 0x2573, 0xFF10, 0xFF11, 0xFF12, 0xFF13,
+		0xFF14, 0xFF15, 0xFF16, 0xFF17, 0xFF18, 0xFF19, 0x2160, 0x2161, 0x2162, 0x2163, 0x2164,
+		0x2165, 0x2166, 0x2167, 0x2168, 0x2169, 0x3021, 0x3022, 0x3023, 0x3024, 0x3025, 0x3026,
+		0x3027, 0x3028, 0x3029, 0x5341, 0x5344, 0x5345, 0xFF21, 0xFF22, 0xFF23, 0xFF24, 0xFF25,
+		0xFF26, 0xFF27, 0xFF28, 0xFF29, 0xFF2A, 0xFF2B, 0xFF2C, 0xFF2D, 0xFF2E, 0xFF2F, 0xFF30,
+		0xFF31, 0xFF32, 0xFF33, 0xFF34, 0xFF35, 0xFF36, 0xFF37, 0xFF38, 0xFF39, 0xFF3A, 0xFF41,
+		0xFF42, 0xFF43, 0xFF44, 0xFF45, 0xFF46, 0xFF47, 0xFF48, 0xFF49, 0xFF4A, 0xFF4B, 0xFF4C,
+		0xFF4D, 0xFF4E, 0xFF4F, 0xFF50, 0xFF51, 0xFF52, 0xFF53, 0xFF54, 0xFF55, 0xFF56, 0xFF57,
+		0xFF58, 0xFF59, 0xFF5A, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398,
+		0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F, 0x03A0, 0x03A1, 0x03A3, 0x03A4,
+		0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6,
+		0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1,
+		0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0x3105, 0x3106, 0x3107, 0x3108,
+		0x3109, 0x310A, 0x310B, 0x310C, 0x310D, 0x310E, 0x310F, 0x3110, 0x3111, 0x3112, 0x3113,
+		0x3114, 0x3115, 0x3116, 0x3117, 0x3118, 0x3119, 0x311A, 0x311B, 0x311C, 0x311D, 0x311E,
+		0x311F, 0x3120, 0x3121, 0x3122, 0x3123, 0x3124, 0x3125, 0x3126, 0x3127, 0x3128, 0x3129,
+		0x02D9, 0x02C9, 0x02CA, 0x02C7, 0x02CB, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0x4E00, 0x4E59, 0x4E01, 0x4E03, 0x4E43, 0x4E5D, 0x4E86, 0x4E8C, 0x4EBA,
+		0x513F, 0x5165, 0x516B, 0x51E0, 0x5200, 0x5201, 0x529B, 0x5315, 0x5341, 0x535C, 0x53C8,
+		0x4E09, 0x4E0B, 0x4E08, 0x4E0A, 0x4E2B, 0x4E38, 0x51E1, 0x4E45, 0x4E48, 0x4E5F, 0x4E5E,
+		0x4E8E, 0x4EA1, 0x5140, 0x5203, 0x52FA, 0x5343, 0x53C9, 0x53E3, 0x571F, 0x58EB, 0x5915,
+		0x5927, 0x5973, 0x5B50, 0x5B51, 0x5B53, 0x5BF8, 0x5C0F, 0x5C22, 0x5C38, 0x5C71, 0x5DDD,
+		0x5DE5, 0x5DF1, 0x5DF2, 0x5DF3, 0x5DFE, 0x5E72, 0x5EFE, 0x5F0B, 0x5F13, 0x624D, 0x4E11,
+		0x4E10, 0x4E0D, 0x4E2D, 0x4E30, 0x4E39, 0x4E4B, 0x5C39, 0x4E88, 0x4E91, 0x4E95, 0x4E92,
+		0x4E94, 0x4EA2, 0x4EC1, 0x4EC0, 0x4EC3, 0x4EC6, 0x4EC7, 0x4ECD, 0x4ECA, 0x4ECB, 0x4EC4,
+		0x5143, 0x5141, 0x5167, 0x516D, 0x516E, 0x516C, 0x5197, 0x51F6, 0x5206, 0x5207, 0x5208,
+		0x52FB, 0x52FE, 0x52FF, 0x5316, 0x5339, 0x5348, 0x5347, 0x5345, 0x535E, 0x5384, 0x53CB,
+		0x53CA, 0x53CD, 0x58EC, 0x5929, 0x592B, 0x592A, 0x592D, 0x5B54, 0x5C11, 0x5C24, 0x5C3A,
+		0x5C6F, 0x5DF4, 0x5E7B, 0x5EFF, 0x5F14, 0x5F15, 0x5FC3, 0x6208, 0x6236, 0x624B, 0x624E,
+		0x652F, 0x6587, 0x6597, 0x65A4, 0x65B9, 0x65E5, 0x66F0, 0x6708, 0x6728, 0x6B20, 0x6B62,
+		0x6B79, 0x6BCB, 0x6BD4, 0x6BDB, 0x6C0F, 0x6C34, 0x706B, 0x722A, 0x7236, 0x723B, 0x7247,
+		0x7259, 0x725B, 0x72AC, 0x738B, 0x4E19, 0x4E16, 0x4E15, 0x4E14, 0x4E18, 0x4E3B, 0x4E4D,
+		0x4E4F, 0x4E4E, 0x4EE5, 0x4ED8, 0x4ED4, 0x4ED5, 0x4ED6, 0x4ED7, 0x4EE3, 0x4EE4, 0x4ED9,
+		0x4EDE, 0x5145, 0x5144, 0x5189, 0x518A, 0x51AC, 0x51F9, 0x51FA, 0x51F8, 0x520A, 0x52A0,
+		0x529F, 0x5305, 0x5306, 0x5317, 0x531D, 0x4EDF, 0x534A, 0x5349, 0x5361, 0x5360, 0x536F,
+		0x536E, 0x53BB, 0x53EF, 0x53E4, 0x53F3, 0x53EC, 0x53EE, 0x53E9, 0x53E8, 0x53FC, 0x53F8,
+		0x53F5, 0x53EB, 0x53E6, 0x53EA, 0x53F2, 0x53F1, 0x53F0, 0x53E5, 0x53ED, 0x53FB, 0x56DB,
+		0x56DA, 0x5916, 0x592E, 0x5931, 0x5974, 0x5976, 0x5B55, 0x5B83, 0x5C3C, 0x5DE8, 0x5DE7,
+		0x5DE6, 0x5E02, 0x5E03, 0x5E73, 0x5E7C, 0x5F01, 0x5F18, 0x5F17, 0x5FC5, 0x620A, 0x6253,
+		0x6254, 0x6252, 0x6251, 0x65A5, 0x65E6, 0x672E, 0x672C, 0x672A, 0x672B, 0x672D, 0x6B63,
+		0x6BCD, 0x6C11, 0x6C10, 0x6C38, 0x6C41, 0x6C40, 0x6C3E, 0x72AF, 0x7384, 0x7389, 0x74DC,
+		0x74E6, 0x7518, 0x751F, 0x7528, 0x7529, 0x7530, 0x7531, 0x7532, 0x7533, 0x758B, 0x767D,
+		0x76AE, 0x76BF, 0x76EE, 0x77DB, 0x77E2, 0x77F3, 0x793A, 0x79BE, 0x7A74, 0x7ACB, 0x4E1E,
+		0x4E1F, 0x4E52, 0x4E53, 0x4E69, 0x4E99, 0x4EA4, 0x4EA6, 0x4EA5, 0x4EFF, 0x4F09, 0x4F19,
+		0x4F0A, 0x4F15, 0x4F0D, 0x4F10, 0x4F11, 0x4F0F, 0x4EF2, 0x4EF6, 0x4EFB, 0x4EF0, 0x4EF3,
+		0x4EFD, 0x4F01, 0x4F0B, 0x5149, 0x5147, 0x5146, 0x5148, 0x5168, 0x5171, 0x518D, 0x51B0,
+		0x5217, 0x5211, 0x5212, 0x520E, 0x5216, 0x52A3, 0x5308, 0x5321, 0x5320, 0x5370, 0x5371,
+		0x5409, 0x540F, 0x540C, 0x540A, 0x5410, 0x5401, 0x540B, 0x5404, 0x5411, 0x540D, 0x5408,
+		0x5403, 0x540E, 0x5406, 0x5412, 0x56E0, 0x56DE, 0x56DD, 0x5733, 0x5730, 0x5728, 0x572D,
+		0x572C, 0x572F, 0x5729, 0x5919, 0x591A, 0x5937, 0x5938, 0x5984, 0x5978, 0x5983, 0x597D,
+		0x5979, 0x5982, 0x5981, 0x5B57, 0x5B58, 0x5B87, 0x5B88, 0x5B85, 0x5B89, 0x5BFA, 0x5C16,
+		0x5C79, 0x5DDE, 0x5E06, 0x5E76, 0x5E74, 0x5F0F, 0x5F1B, 0x5FD9, 0x5FD6, 0x620E, 0x620C,
+		0x620D, 0x6210, 0x6263, 0x625B, 0x6258, 0x6536, 0x65E9, 0x65E8, 0x65EC, 0x65ED, 0x66F2,
+		0x66F3, 0x6709, 0x673D, 0x6734, 0x6731, 0x6735, 0x6B21, 0x6B64, 0x6B7B, 0x6C16, 0x6C5D,
+		0x6C57, 0x6C59, 0x6C5F, 0x6C60, 0x6C50, 0x6C55, 0x6C61, 0x6C5B, 0x6C4D, 0x6C4E, 0x7070,
+		0x725F, 0x725D, 0x767E, 0x7AF9, 0x7C73, 0x7CF8, 0x7F36, 0x7F8A, 0x7FBD, 0x8001, 0x8003,
+		0x800C, 0x8012, 0x8033, 0x807F, 0x8089, 0x808B, 0x808C, 0x81E3, 0x81EA, 0x81F3, 0x81FC,
+		0x820C, 0x821B, 0x821F, 0x826E, 0x8272, 0x827E, 0x866B, 0x8840, 0x884C, 0x8863, 0x897F,
+		0x9621, 0x4E32, 0x4EA8, 0x4F4D, 0x4F4F, 0x4F47, 0x4F57, 0x4F5E, 0x4F34, 0x4F5B, 0x4F55,
+		0x4F30, 0x4F50, 0x4F51, 0x4F3D, 0x4F3A, 0x4F38, 0x4F43, 0x4F54, 0x4F3C, 0x4F46, 0x4F63,
+		0x4F5C, 0x4F60, 0x4F2F, 0x4F4E, 0x4F36, 0x4F59, 0x4F5D, 0x4F48, 0x4F5A, 0x514C, 0x514B,
+		0x514D, 0x5175, 0x51B6, 0x51B7, 0x5225, 0x5224, 0x5229, 0x522A, 0x5228, 0x52AB, 0x52A9,
+		0x52AA, 0x52AC, 0x5323, 0x5373, 0x5375, 0x541D, 0x542D, 0x541E, 0x543E, 0x5426, 0x544E,
+		0x5427, 0x5446, 0x5443, 0x5433, 0x5448, 0x5442, 0x541B, 0x5429, 0x544A, 0x5439, 0x543B,
+		0x5438, 0x542E, 0x5435, 0x5436, 0x5420, 0x543C, 0x5440, 0x5431, 0x542B, 0x541F, 0x542C,
+		0x56EA, 0x56F0, 0x56E4, 0x56EB, 0x574A, 0x5751, 0x5740, 0x574D, 0x5747, 0x574E, 0x573E,
+		0x5750, 0x574F, 0x573B, 0x58EF, 0x593E, 0x599D, 0x5992, 0x59A8, 0x599E, 0x59A3, 0x5999,
+		0x5996, 0x598D, 0x59A4, 0x5993, 0x598A, 0x59A5, 0x5B5D, 0x5B5C, 0x5B5A, 0x5B5B, 0x5B8C,
+		0x5B8B, 0x5B8F, 0x5C2C, 0x5C40, 0x5C41, 0x5C3F, 0x5C3E, 0x5C90, 0x5C91, 0x5C94, 0x5C8C,
+		0x5DEB, 0x5E0C, 0x5E8F, 0x5E87, 0x5E8A, 0x5EF7, 0x5F04, 0x5F1F, 0x5F64, 0x5F62, 0x5F77,
+		0x5F79, 0x5FD8, 0x5FCC, 0x5FD7, 0x5FCD, 0x5FF1, 0x5FEB, 0x5FF8, 0x5FEA, 0x6212, 0x6211,
+		0x6284, 0x6297, 0x6296, 0x6280, 0x6276, 0x6289, 0x626D, 0x628A, 0x627C, 0x627E, 0x6279,
+		0x6273, 0x6292, 0x626F, 0x6298, 0x626E, 0x6295, 0x6293, 0x6291, 0x6286, 0x6539, 0x653B,
+		0x6538, 0x65F1, 0x66F4, 0x675F, 0x674E, 0x674F, 0x6750, 0x6751, 0x675C, 0x6756, 0x675E,
+		0x6749, 0x6746, 0x6760, 0x6753, 0x6757, 0x6B65, 0x6BCF, 0x6C42, 0x6C5E, 0x6C99, 0x6C81,
+		0x6C88, 0x6C89, 0x6C85, 0x6C9B, 0x6C6A, 0x6C7A, 0x6C90, 0x6C70, 0x6C8C, 0x6C68, 0x6C96,
+		0x6C92, 0x6C7D, 0x6C83, 0x6C72, 0x6C7E, 0x6C74, 0x6C86, 0x6C76, 0x6C8D, 0x6C94, 0x6C98,
+		0x6C82, 0x7076, 0x707C, 0x707D, 0x7078, 0x7262, 0x7261, 0x7260, 0x72C4, 0x72C2, 0x7396,
+		0x752C, 0x752B, 0x7537, 0x7538, 0x7682, 0x76EF, 0x77E3, 0x79C1, 0x79C0, 0x79BF, 0x7A76,
+		0x7CFB, 0x7F55, 0x8096, 0x8093, 0x809D, 0x8098, 0x809B, 0x809A, 0x80B2, 0x826F, 0x8292,
+		0x828B, 0x828D, 0x898B, 0x89D2, 0x8A00, 0x8C37, 0x8C46, 0x8C55, 0x8C9D, 0x8D64, 0x8D70,
+		0x8DB3, 0x8EAB, 0x8ECA, 0x8F9B, 0x8FB0, 0x8FC2, 0x8FC6, 0x8FC5, 0x8FC4, 0x5DE1, 0x9091,
+		0x90A2, 0x90AA, 0x90A6, 0x90A3, 0x9149, 0x91C6, 0x91CC, 0x9632, 0x962E, 0x9631, 0x962A,
+		0x962C, 0x4E26, 0x4E56, 0x4E73, 0x4E8B, 0x4E9B, 0x4E9E, 0x4EAB, 0x4EAC, 0x4F6F, 0x4F9D,
+		0x4F8D, 0x4F73, 0x4F7F, 0x4F6C, 0x4F9B, 0x4F8B, 0x4F86, 0x4F83, 0x4F70, 0x4F75, 0x4F88,
+		0x4F69, 0x4F7B, 0x4F96, 0x4F7E, 0x4F8F, 0x4F91, 0x4F7A, 0x5154, 0x5152, 0x5155, 0x5169,
+		0x5177, 0x5176, 0x5178, 0x51BD, 0x51FD, 0x523B, 0x5238, 0x5237, 0x523A, 0x5230, 0x522E,
+		0x5236, 0x5241, 0x52BE, 0x52BB, 0x5352, 0x5354, 0x5353, 0x5351, 0x5366, 0x5377, 0x5378,
+		0x5379, 0x53D6, 0x53D4, 0x53D7, 0x5473, 0x5475, 0x5496, 0x5478, 0x5495, 0x5480, 0x547B,
+		0x5477, 0x5484, 0x5492, 0x5486, 0x547C, 0x5490, 0x5471, 0x5476, 0x548C, 0x549A, 0x5462,
+		0x5468, 0x548B, 0x547D, 0x548E, 0x56FA, 0x5783, 0x5777, 0x576A, 0x5769, 0x5761, 0x5766,
+		0x5764, 0x577C, 0x591C, 0x5949, 0x5947, 0x5948, 0x5944, 0x5954, 0x59BE, 0x59BB, 0x59D4,
+		0x59B9, 0x59AE, 0x59D1, 0x59C6, 0x59D0, 0x59CD, 0x59CB, 0x59D3, 0x59CA, 0x59AF, 0x59B3,
+		0x59D2, 0x59C5, 0x5B5F, 0x5B64, 0x5B63, 0x5B97, 0x5B9A, 0x5B98, 0x5B9C, 0x5B99, 0x5B9B,
+		0x5C1A, 0x5C48, 0x5C45, 0x5C46, 0x5CB7, 0x5CA1, 0x5CB8, 0x5CA9, 0x5CAB, 0x5CB1, 0x5CB3,
+		0x5E18, 0x5E1A, 0x5E16, 0x5E15, 0x5E1B, 0x5E11, 0x5E78, 0x5E9A, 0x5E97, 0x5E9C, 0x5E95,
+		0x5E96, 0x5EF6, 0x5F26, 0x5F27, 0x5F29, 0x5F80, 0x5F81, 0x5F7F, 0x5F7C, 0x5FDD, 0x5FE0,
+		0x5FFD, 0x5FF5, 0x5FFF, 0x600F, 0x6014, 0x602F, 0x6035, 0x6016, 0x602A, 0x6015, 0x6021,
+		0x6027, 0x6029, 0x602B, 0x601B, 0x6216, 0x6215, 0x623F, 0x623E, 0x6240, 0x627F, 0x62C9,
+		0x62CC, 0x62C4, 0x62BF, 0x62C2, 0x62B9, 0x62D2, 0x62DB, 0x62AB, 0x62D3, 0x62D4, 0x62CB,
+		0x62C8, 0x62A8, 0x62BD, 0x62BC, 0x62D0, 0x62D9, 0x62C7, 0x62CD, 0x62B5, 0x62DA, 0x62B1,
+		0x62D8, 0x62D6, 0x62D7, 0x62C6, 0x62AC, 0x62CE, 0x653E, 0x65A7, 0x65BC, 0x65FA, 0x6614,
+		0x6613, 0x660C, 0x6606, 0x6602, 0x660E, 0x6600, 0x660F, 0x6615, 0x660A, 0x6607, 0x670D,
+		0x670B, 0x676D, 0x678B, 0x6795, 0x6771, 0x679C, 0x6773, 0x6777, 0x6787, 0x679D, 0x6797,
+		0x676F, 0x6770, 0x677F, 0x6789, 0x677E, 0x6790, 0x6775, 0x679A, 0x6793, 0x677C, 0x676A,
+		0x6772, 0x6B23, 0x6B66, 0x6B67, 0x6B7F, 0x6C13, 0x6C1B, 0x6CE3, 0x6CE8, 0x6CF3, 0x6CB1,
+		0x6CCC, 0x6CE5, 0x6CB3, 0x6CBD, 0x6CBE, 0x6CBC, 0x6CE2, 0x6CAB, 0x6CD5, 0x6CD3, 0x6CB8,
+		0x6CC4, 0x6CB9, 0x6CC1, 0x6CAE, 0x6CD7, 0x6CC5, 0x6CF1, 0x6CBF, 0x6CBB, 0x6CE1, 0x6CDB,
+		0x6CCA, 0x6CAC, 0x6CEF, 0x6CDC, 0x6CD6, 0x6CE0, 0x7095, 0x708E, 0x7092, 0x708A, 0x7099,
+		0x722C, 0x722D, 0x7238, 0x7248, 0x7267, 0x7269, 0x72C0, 0x72CE, 0x72D9, 0x72D7, 0x72D0,
+		0x73A9, 0x73A8, 0x739F, 0x73AB, 0x73A5, 0x753D, 0x759D, 0x7599, 0x759A, 0x7684, 0x76C2,
+		0x76F2, 0x76F4, 0x77E5, 0x77FD, 0x793E, 0x7940, 0x7941, 0x79C9, 0x79C8, 0x7A7A, 0x7A79,
+		0x7AFA, 0x7CFE, 0x7F54, 0x7F8C, 0x7F8B, 0x8005, 0x80BA, 0x80A5, 0x80A2, 0x80B1, 0x80A1,
+		0x80AB, 0x80A9, 0x80B4, 0x80AA, 0x80AF, 0x81E5, 0x81FE, 0x820D, 0x82B3, 0x829D, 0x8299,
+		0x82AD, 0x82BD, 0x829F, 0x82B9, 0x82B1, 0x82AC, 0x82A5, 0x82AF, 0x82B8, 0x82A3, 0x82B0,
+		0x82BE, 0x82B7, 0x864E, 0x8671, 0x521D, 0x8868, 0x8ECB, 0x8FCE, 0x8FD4, 0x8FD1, 0x90B5,
+		0x90B8, 0x90B1, 0x90B6, 0x91C7, 0x91D1, 0x9577, 0x9580, 0x961C, 0x9640, 0x963F, 0x963B,
+		0x9644, 0x9642, 0x96B9, 0x96E8, 0x9752, 0x975E, 0x4E9F, 0x4EAD, 0x4EAE, 0x4FE1, 0x4FB5,
+		0x4FAF, 0x4FBF, 0x4FE0, 0x4FD1, 0x4FCF, 0x4FDD, 0x4FC3, 0x4FB6, 0x4FD8, 0x4FDF, 0x4FCA,
+		0x4FD7, 0x4FAE, 0x4FD0, 0x4FC4, 0x4FC2, 0x4FDA, 0x4FCE, 0x4FDE, 0x4FB7, 0x5157, 0x5192,
+		0x5191, 0x51A0, 0x524E, 0x5243, 0x524A, 0x524D, 0x524C, 0x524B, 0x5247, 0x52C7, 0x52C9,
+		0x52C3, 0x52C1, 0x530D, 0x5357, 0x537B, 0x539A, 0x53DB, 0x54AC, 0x54C0, 0x54A8, 0x54CE,
+		0x54C9, 0x54B8, 0x54A6, 0x54B3, 0x54C7, 0x54C2, 0x54BD, 0x54AA, 0x54C1, 0x54C4, 0x54C8,
+		0x54AF, 0x54AB, 0x54B1, 0x54BB, 0x54A9, 0x54A7, 0x54BF, 0x56FF, 0x5782, 0x578B, 0x57A0,
+		0x57A3, 0x57A2, 0x57CE, 0x57AE, 0x5793, 0x5955, 0x5951, 0x594F, 0x594E, 0x5950, 0x59DC,
+		0x59D8, 0x59FF, 0x59E3, 0x59E8, 0x5A03, 0x59E5, 0x59EA, 0x59DA, 0x59E6, 0x5A01, 0x59FB,
+		0x5B69, 0x5BA3, 0x5BA6, 0x5BA4, 0x5BA2, 0x5BA5, 0x5C01, 0x5C4E, 0x5C4F, 0x5C4D, 0x5C4B,
+		0x5CD9, 0x5CD2, 0x5DF7, 0x5E1D, 0x5E25, 0x5E1F, 0x5E7D, 0x5EA0, 0x5EA6, 0x5EFA, 0x5F08,
+		0x5F2D, 0x5F65, 0x5F88, 0x5F85, 0x5F8A, 0x5F8B, 0x5F87, 0x5F8C, 0x5F89, 0x6012, 0x601D,
+		0x6020, 0x6025, 0x600E, 0x6028, 0x604D, 0x6070, 0x6068, 0x6062, 0x6046, 0x6043, 0x606C,
+		0x606B, 0x606A, 0x6064, 0x6241, 0x62DC, 0x6316, 0x6309, 0x62FC, 0x62ED, 0x6301, 0x62EE,
+		0x62FD, 0x6307, 0x62F1, 0x62F7, 0x62EF, 0x62EC, 0x62FE, 0x62F4, 0x6311, 0x6302, 0x653F,
+		0x6545, 0x65AB, 0x65BD, 0x65E2, 0x6625, 0x662D, 0x6620, 0x6627, 0x662F, 0x661F, 0x6628,
+		0x6631, 0x6624, 0x66F7, 0x67FF, 0x67D3, 0x67F1, 0x67D4, 0x67D0, 0x67EC, 0x67B6, 0x67AF,
+		0x67F5, 0x67E9, 0x67EF, 0x67C4, 0x67D1, 0x67B4, 0x67DA, 0x67E5, 0x67B8, 0x67CF, 0x67DE,
+		0x67F3, 0x67B0, 0x67D9, 0x67E2, 0x67DD, 0x67D2, 0x6B6A, 0x6B83, 0x6B86, 0x6BB5, 0x6BD2,
+		0x6BD7, 0x6C1F, 0x6CC9, 0x6D0B, 0x6D32, 0x6D2A, 0x6D41, 0x6D25, 0x6D0C, 0x6D31, 0x6D1E,
+		0x6D17, 0x6D3B, 0x6D3D, 0x6D3E, 0x6D36, 0x6D1B, 0x6CF5, 0x6D39, 0x6D27, 0x6D38, 0x6D29,
+		0x6D2E, 0x6D35, 0x6D0E, 0x6D2B, 0x70AB, 0x70BA, 0x70B3, 0x70AC, 0x70AF, 0x70AD, 0x70B8,
+		0x70AE, 0x70A4, 0x7230, 0x7272, 0x726F, 0x7274, 0x72E9, 0x72E0, 0x72E1, 0x73B7, 0x73CA,
+		0x73BB, 0x73B2, 0x73CD, 0x73C0, 0x73B3, 0x751A, 0x752D, 0x754F, 0x754C, 0x754E, 0x754B,
+		0x75AB, 0x75A4, 0x75A5, 0x75A2, 0x75A3, 0x7678, 0x7686, 0x7687, 0x7688, 0x76C8, 0x76C6,
+		0x76C3, 0x76C5, 0x7701, 0x76F9, 0x76F8, 0x7709, 0x770B, 0x76FE, 0x76FC, 0x7707, 0x77DC,
+		0x7802, 0x7814, 0x780C, 0x780D, 0x7946, 0x7949, 0x7948, 0x7947, 0x79B9, 0x79BA, 0x79D1,
+		0x79D2, 0x79CB, 0x7A7F, 0x7A81, 0x7AFF, 0x7AFD, 0x7C7D, 0x7D02, 0x7D05, 0x7D00, 0x7D09,
+		0x7D07, 0x7D04, 0x7D06, 0x7F38, 0x7F8E, 0x7FBF, 0x8004, 0x8010, 0x800D, 0x8011, 0x8036,
+		0x80D6, 0x80E5, 0x80DA, 0x80C3, 0x80C4, 0x80CC, 0x80E1, 0x80DB, 0x80CE, 0x80DE, 0x80E4,
+		0x80DD, 0x81F4, 0x8222, 0x82E7, 0x8303, 0x8305, 0x82E3, 0x82DB, 0x82E6, 0x8304, 0x82E5,
+		0x8302, 0x8309, 0x82D2, 0x82D7, 0x82F1, 0x8301, 0x82DC, 0x82D4, 0x82D1, 0x82DE, 0x82D3,
+		0x82DF, 0x82EF, 0x8306, 0x8650, 0x8679, 0x867B, 0x867A, 0x884D, 0x886B, 0x8981, 0x89D4,
+		0x8A08, 0x8A02, 0x8A03, 0x8C9E, 0x8CA0, 0x8D74, 0x8D73, 0x8DB4, 0x8ECD, 0x8ECC, 0x8FF0,
+		0x8FE6, 0x8FE2, 0x8FEA, 0x8FE5, 0x8FED, 0x8FEB, 0x8FE4, 0x8FE8, 0x90CA, 0x90CE, 0x90C1,
+		0x90C3, 0x914B, 0x914A, 0x91CD, 0x9582, 0x9650, 0x964B, 0x964C, 0x964D, 0x9762, 0x9769,
+		0x97CB, 0x97ED, 0x97F3, 0x9801, 0x98A8, 0x98DB, 0x98DF, 0x9996, 0x9999, 0x4E58, 0x4EB3,
+		0x500C, 0x500D, 0x5023, 0x4FEF, 0x5026, 0x5025, 0x4FF8, 0x5029, 0x5016, 0x5006, 0x503C,
+		0x501F, 0x501A, 0x5012, 0x5011, 0x4FFA, 0x5000, 0x5014, 0x5028, 0x4FF1, 0x5021, 0x500B,
+		0x5019, 0x5018, 0x4FF3, 0x4FEE, 0x502D, 0x502A, 0x4FFE, 0x502B, 0x5009, 0x517C, 0x51A4,
+		0x51A5, 0x51A2, 0x51CD, 0x51CC, 0x51C6, 0x51CB, 0x5256, 0x525C, 0x5254, 0x525B, 0x525D,
+		0x532A, 0x537F, 0x539F, 0x539D, 0x53DF, 0x54E8, 0x5510, 0x5501, 0x5537, 0x54FC, 0x54E5,
+		0x54F2, 0x5506, 0x54FA, 0x5514, 0x54E9, 0x54ED, 0x54E1, 0x5509, 0x54EE, 0x54EA, 0x54E6,
+		0x5527, 0x5507, 0x54FD, 0x550F, 0x5703, 0x5704, 0x57C2, 0x57D4, 0x57CB, 0x57C3, 0x5809,
+		0x590F, 0x5957, 0x5958, 0x595A, 0x5A11, 0x5A18, 0x5A1C, 0x5A1F, 0x5A1B, 0x5A13, 0x59EC,
+		0x5A20, 0x5A23, 0x5A29, 0x5A25, 0x5A0C, 0x5A09, 0x5B6B, 0x5C58, 0x5BB0, 0x5BB3, 0x5BB6,
+		0x5BB4, 0x5BAE, 0x5BB5, 0x5BB9, 0x5BB8, 0x5C04, 0x5C51, 0x5C55, 0x5C50, 0x5CED, 0x5CFD,
+		0x5CFB, 0x5CEA, 0x5CE8, 0x5CF0, 0x5CF6, 0x5D01, 0x5CF4, 0x5DEE, 0x5E2D, 0x5E2B, 0x5EAB,
+		0x5EAD, 0x5EA7, 0x5F31, 0x5F92, 0x5F91, 0x5F90, 0x6059, 0x6063, 0x6065, 0x6050, 0x6055,
+		0x606D, 0x6069, 0x606F, 0x6084, 0x609F, 0x609A, 0x608D, 0x6094, 0x608C, 0x6085, 0x6096,
+		0x6247, 0x62F3, 0x6308, 0x62FF, 0x634E, 0x633E, 0x632F, 0x6355, 0x6342, 0x6346, 0x634F,
+		0x6349, 0x633A, 0x6350, 0x633D, 0x632A, 0x632B, 0x6328, 0x634D, 0x634C, 0x6548, 0x6549,
+		0x6599, 0x65C1, 0x65C5, 0x6642, 0x6649, 0x664F, 0x6643, 0x6652, 0x664C, 0x6645, 0x6641,
+		0x66F8, 0x6714, 0x6715, 0x6717, 0x6821, 0x6838, 0x6848, 0x6846, 0x6853, 0x6839, 0x6842,
+		0x6854, 0x6829, 0x68B3, 0x6817, 0x684C, 0x6851, 0x683D, 0x67F4, 0x6850, 0x6840, 0x683C,
+		0x6843, 0x682A, 0x6845, 0x6813, 0x6818, 0x6841, 0x6B8A, 0x6B89, 0x6BB7, 0x6C23, 0x6C27,
+		0x6C28, 0x6C26, 0x6C24, 0x6CF0, 0x6D6A, 0x6D95, 0x6D88, 0x6D87, 0x6D66, 0x6D78, 0x6D77,
+		0x6D59, 0x6D93, 0x6D6C, 0x6D89, 0x6D6E, 0x6D5A, 0x6D74, 0x6D69, 0x6D8C, 0x6D8A, 0x6D79,
+		0x6D85, 0x6D65, 0x6D94, 0x70CA, 0x70D8, 0x70E4, 0x70D9, 0x70C8, 0x70CF, 0x7239, 0x7279,
+		0x72FC, 0x72F9, 0x72FD, 0x72F8, 0x72F7, 0x7386, 0x73ED, 0x7409, 0x73EE, 0x73E0, 0x73EA,
+		0x73DE, 0x7554, 0x755D, 0x755C, 0x755A, 0x7559, 0x75BE, 0x75C5, 0x75C7, 0x75B2, 0x75B3,
+		0x75BD, 0x75BC, 0x75B9, 0x75C2, 0x75B8, 0x768B, 0x76B0, 0x76CA, 0x76CD, 0x76CE, 0x7729,
+		0x771F, 0x7720, 0x7728, 0x77E9, 0x7830, 0x7827, 0x7838, 0x781D, 0x7834, 0x7837, 0x7825,
+		0x782D, 0x7820, 0x781F, 0x7832, 0x7955, 0x7950, 0x7960, 0x795F, 0x7956, 0x795E, 0x795D,
+		0x7957, 0x795A, 0x79E4, 0x79E3, 0x79E7, 0x79DF, 0x79E6, 0x79E9, 0x79D8, 0x7A84, 0x7A88,
+		0x7AD9, 0x7B06, 0x7B11, 0x7C89, 0x7D21, 0x7D17, 0x7D0B, 0x7D0A, 0x7D20, 0x7D22, 0x7D14,
+		0x7D10, 0x7D15, 0x7D1A, 0x7D1C, 0x7D0D, 0x7D19, 0x7D1B, 0x7F3A, 0x7F5F, 0x7F94, 0x7FC5,
+		0x7FC1, 0x8006, 0x8018, 0x8015, 0x8019, 0x8017, 0x803D, 0x803F, 0x80F1, 0x8102, 0x80F0,
+		0x8105, 0x80ED, 0x80F4, 0x8106, 0x80F8, 0x80F3, 0x8108, 0x80FD, 0x810A, 0x80FC, 0x80EF,
+		0x81ED, 0x81EC, 0x8200, 0x8210, 0x822A, 0x822B, 0x8228, 0x822C, 0x82BB, 0x832B, 0x8352,
+		0x8354, 0x834A, 0x8338, 0x8350, 0x8349, 0x8335, 0x8334, 0x834F, 0x8332, 0x8339, 0x8336,
+		0x8317, 0x8340, 0x8331, 0x8328, 0x8343, 0x8654, 0x868A, 0x86AA, 0x8693, 0x86A4, 0x86A9,
+		0x868C, 0x86A3, 0x869C, 0x8870, 0x8877, 0x8881, 0x8882, 0x887D, 0x8879, 0x8A18, 0x8A10,
+		0x8A0E, 0x8A0C, 0x8A15, 0x8A0A, 0x8A17, 0x8A13, 0x8A16, 0x8A0F, 0x8A11, 0x8C48, 0x8C7A,
+		0x8C79, 0x8CA1, 0x8CA2, 0x8D77, 0x8EAC, 0x8ED2, 0x8ED4, 0x8ECF, 0x8FB1, 0x9001, 0x9006,
+		0x8FF7, 0x9000, 0x8FFA, 0x8FF4, 0x9003, 0x8FFD, 0x9005, 0x8FF8, 0x9095, 0x90E1, 0x90DD,
+		0x90E2, 0x9152, 0x914D, 0x914C, 0x91D8, 0x91DD, 0x91D7, 0x91DC, 0x91D9, 0x9583, 0x9662,
+		0x9663, 0x9661, 0x965B, 0x965D, 0x9664, 0x9658, 0x965E, 0x96BB, 0x98E2, 0x99AC, 0x9AA8,
+		0x9AD8, 0x9B25, 0x9B32, 0x9B3C, 0x4E7E, 0x507A, 0x507D, 0x505C, 0x5047, 0x5043, 0x504C,
+		0x505A, 0x5049, 0x5065, 0x5076, 0x504E, 0x5055, 0x5075, 0x5074, 0x5077, 0x504F, 0x500F,
+		0x506F, 0x506D, 0x515C, 0x5195, 0x51F0, 0x526A, 0x526F, 0x52D2, 0x52D9, 0x52D8, 0x52D5,
+		0x5310, 0x530F, 0x5319, 0x533F, 0x5340, 0x533E, 0x53C3, 0x66FC, 0x5546, 0x556A, 0x5566,
+		0x5544, 0x555E, 0x5561, 0x5543, 0x554A, 0x5531, 0x5556, 0x554F, 0x5555, 0x552F, 0x5564,
+		0x5538, 0x552E, 0x555C, 0x552C, 0x5563, 0x5533, 0x5541, 0x5557, 0x5708, 0x570B, 0x5709,
+		0x57DF, 0x5805, 0x580A, 0x5806, 0x57E0, 0x57E4, 0x57FA, 0x5802, 0x5835, 0x57F7, 0x57F9,
+		0x5920, 0x5962, 0x5A36, 0x5A41, 0x5A49, 0x5A66, 0x5A6A, 0x5A40, 0x5A3C, 0x5A62, 0x5A5A,
+		0x5A46, 0x5A4A, 0x5B70, 0x5BC7, 0x5BC5, 0x5BC4, 0x5BC2, 0x5BBF, 0x5BC6, 0x5C09, 0x5C08,
+		0x5C07, 0x5C60, 0x5C5C, 0x5C5D, 0x5D07, 0x5D06, 0x5D0E, 0x5D1B, 0x5D16, 0x5D22, 0x5D11,
+		0x5D29, 0x5D14, 0x5D19, 0x5D24, 0x5D27, 0x5D17, 0x5DE2, 0x5E38, 0x5E36, 0x5E33, 0x5E37,
+		0x5EB7, 0x5EB8, 0x5EB6, 0x5EB5, 0x5EBE, 0x5F35, 0x5F37, 0x5F57, 0x5F6C, 0x5F69, 0x5F6B,
+		0x5F97, 0x5F99, 0x5F9E, 0x5F98, 0x5FA1, 0x5FA0, 0x5F9C, 0x607F, 0x60A3, 0x6089, 0x60A0,
+		0x60A8, 0x60CB, 0x60B4, 0x60E6, 0x60BD, 0x60C5, 0x60BB, 0x60B5, 0x60DC, 0x60BC, 0x60D8,
+		0x60D5, 0x60C6, 0x60DF, 0x60B8, 0x60DA, 0x60C7, 0x621A, 0x621B, 0x6248, 0x63A0, 0x63A7,
+		0x6372, 0x6396, 0x63A2, 0x63A5, 0x6377, 0x6367, 0x6398, 0x63AA, 0x6371, 0x63A9, 0x6389,
+		0x6383, 0x639B, 0x636B, 0x63A8, 0x6384, 0x6388, 0x6399, 0x63A1, 0x63AC, 0x6392, 0x638F,
+		0x6380, 0x637B, 0x6369, 0x6368, 0x637A, 0x655D, 0x6556, 0x6551, 0x6559, 0x6557, 0x555F,
+		0x654F, 0x6558, 0x6555, 0x6554, 0x659C, 0x659B, 0x65AC, 0x65CF, 0x65CB, 0x65CC, 0x65CE,
+		0x665D, 0x665A, 0x6664, 0x6668, 0x6666, 0x665E, 0x66F9, 0x52D7, 0x671B, 0x6881, 0x68AF,
+		0x68A2, 0x6893, 0x68B5, 0x687F, 0x6876, 0x68B1, 0x68A7, 0x6897, 0x68B0, 0x6883, 0x68C4,
+		0x68AD, 0x6886, 0x6885, 0x6894, 0x689D, 0x68A8, 0x689F, 0x68A1, 0x6882, 0x6B32, 0x6BBA,
+		0x6BEB, 0x6BEC, 0x6C2B, 0x6D8E, 0x6DBC, 0x6DF3, 0x6DD9, 0x6DB2, 0x6DE1, 0x6DCC, 0x6DE4,
+		0x6DFB, 0x6DFA, 0x6E05, 0x6DC7, 0x6DCB, 0x6DAF, 0x6DD1, 0x6DAE, 0x6DDE, 0x6DF9, 0x6DB8,
+		0x6DF7, 0x6DF5, 0x6DC5, 0x6DD2, 0x6E1A, 0x6DB5, 0x6DDA, 0x6DEB, 0x6DD8, 0x6DEA, 0x6DF1,
+		0x6DEE, 0x6DE8, 0x6DC6, 0x6DC4, 0x6DAA, 0x6DEC, 0x6DBF, 0x6DE6, 0x70F9, 0x7109, 0x710A,
+		0x70FD, 0x70EF, 0x723D, 0x727D, 0x7281, 0x731C, 0x731B, 0x7316, 0x7313, 0x7319, 0x7387,
+		0x7405, 0x740A, 0x7403, 0x7406, 0x73FE, 0x740D, 0x74E0, 0x74F6, 0x74F7, 0x751C, 0x7522,
+		0x7565, 0x7566, 0x7562, 0x7570, 0x758F, 0x75D4, 0x75D5, 0x75B5, 0x75CA, 0x75CD, 0x768E,
+		0x76D4, 0x76D2, 0x76DB, 0x7737, 0x773E, 0x773C, 0x7736, 0x7738, 0x773A, 0x786B, 0x7843,
+		0x784E, 0x7965, 0x7968, 0x796D, 0x79FB, 0x7A92, 0x7A95, 0x7B20, 0x7B28, 0x7B1B, 0x7B2C,
+		0x7B26, 0x7B19, 0x7B1E, 0x7B2E, 0x7C92, 0x7C97, 0x7C95, 0x7D46, 0x7D43, 0x7D71, 0x7D2E,
+		0x7D39, 0x7D3C, 0x7D40, 0x7D30, 0x7D33, 0x7D44, 0x7D2F, 0x7D42, 0x7D32, 0x7D31, 0x7F3D,
+		0x7F9E, 0x7F9A, 0x7FCC, 0x7FCE, 0x7FD2, 0x801C, 0x804A, 0x8046, 0x812F, 0x8116, 0x8123,
+		0x812B, 0x8129, 0x8130, 0x8124, 0x8202, 0x8235, 0x8237, 0x8236, 0x8239, 0x838E, 0x839E,
+		0x8398, 0x8378, 0x83A2, 0x8396, 0x83BD, 0x83AB, 0x8392, 0x838A, 0x8393, 0x8389, 0x83A0,
+		0x8377, 0x837B, 0x837C, 0x8386, 0x83A7, 0x8655, 0x5F6A, 0x86C7, 0x86C0, 0x86B6, 0x86C4,
+		0x86B5, 0x86C6, 0x86CB, 0x86B1, 0x86AF, 0x86C9, 0x8853, 0x889E, 0x8888, 0x88AB, 0x8892,
+		0x8896, 0x888D, 0x888B, 0x8993, 0x898F, 0x8A2A, 0x8A1D, 0x8A23, 0x8A25, 0x8A31, 0x8A2D,
+		0x8A1F, 0x8A1B, 0x8A22, 0x8C49, 0x8C5A, 0x8CA9, 0x8CAC, 0x8CAB, 0x8CA8, 0x8CAA, 0x8CA7,
+		0x8D67, 0x8D66, 0x8DBE, 0x8DBA, 0x8EDB, 0x8EDF, 0x9019, 0x900D, 0x901A, 0x9017, 0x9023,
+		0x901F, 0x901D, 0x9010, 0x9015, 0x901E, 0x9020, 0x900F, 0x9022, 0x9016, 0x901B, 0x9014,
+		0x90E8, 0x90ED, 0x90FD, 0x9157, 0x91CE, 0x91F5, 0x91E6, 0x91E3, 0x91E7, 0x91ED, 0x91E9,
+		0x9589, 0x966A, 0x9675, 0x9673, 0x9678, 0x9670, 0x9674, 0x9676, 0x9677, 0x966C, 0x96C0,
+		0x96EA, 0x96E9, 0x7AE0, 0x7ADF, 0x9802, 0x9803, 0x9B5A, 0x9CE5, 0x9E75, 0x9E7F, 0x9EA5,
+		0x9EBB, 0x50A2, 0x508D, 0x5085, 0x5099, 0x5091, 0x5080, 0x5096, 0x5098, 0x509A, 0x6700,
+		0x51F1, 0x5272, 0x5274, 0x5275, 0x5269, 0x52DE, 0x52DD, 0x52DB, 0x535A, 0x53A5, 0x557B,
+		0x5580, 0x55A7, 0x557C, 0x558A, 0x559D, 0x5598, 0x5582, 0x559C, 0x55AA, 0x5594, 0x5587,
+		0x558B, 0x5583, 0x55B3, 0x55AE, 0x559F, 0x553E, 0x55B2, 0x559A, 0x55BB, 0x55AC, 0x55B1,
+		0x557E, 0x5589, 0x55AB, 0x5599, 0x570D, 0x582F, 0x582A, 0x5834, 0x5824, 0x5830, 0x5831,
+		0x5821, 0x581D, 0x5820, 0x58F9, 0x58FA, 0x5960, 0x5A77, 0x5A9A, 0x5A7F, 0x5A92, 0x5A9B,
+		0x5AA7, 0x5B73, 0x5B71, 0x5BD2, 0x5BCC, 0x5BD3, 0x5BD0, 0x5C0A, 0x5C0B, 0x5C31, 0x5D4C,
+		0x5D50, 0x5D34, 0x5D47, 0x5DFD, 0x5E45, 0x5E3D, 0x5E40, 0x5E43, 0x5E7E, 0x5ECA, 0x5EC1,
+		0x5EC2, 0x5EC4, 0x5F3C, 0x5F6D, 0x5FA9, 0x5FAA, 0x5FA8, 0x60D1, 0x60E1, 0x60B2, 0x60B6,
+		0x60E0, 0x611C, 0x6123, 0x60FA, 0x6115, 0x60F0, 0x60FB, 0x60F4, 0x6168, 0x60F1, 0x610E,
+		0x60F6, 0x6109, 0x6100, 0x6112, 0x621F, 0x6249, 0x63A3, 0x638C, 0x63CF, 0x63C0, 0x63E9,
+		0x63C9, 0x63C6, 0x63CD, 0x63D2, 0x63E3, 0x63D0, 0x63E1, 0x63D6, 0x63ED, 0x63EE, 0x6376,
+		0x63F4, 0x63EA, 0x63DB, 0x6452, 0x63DA, 0x63F9, 0x655E, 0x6566, 0x6562, 0x6563, 0x6591,
+		0x6590, 0x65AF, 0x666E, 0x6670, 0x6674, 0x6676, 0x666F, 0x6691, 0x667A, 0x667E, 0x6677,
+		0x66FE, 0x66FF, 0x671F, 0x671D, 0x68FA, 0x68D5, 0x68E0, 0x68D8, 0x68D7, 0x6905, 0x68DF,
+		0x68F5, 0x68EE, 0x68E7, 0x68F9, 0x68D2, 0x68F2, 0x68E3, 0x68CB, 0x68CD, 0x690D, 0x6912,
+		0x690E, 0x68C9, 0x68DA, 0x696E, 0x68FB, 0x6B3E, 0x6B3A, 0x6B3D, 0x6B98, 0x6B96, 0x6BBC,
+		0x6BEF, 0x6C2E, 0x6C2F, 0x6C2C, 0x6E2F, 0x6E38, 0x6E54, 0x6E21, 0x6E32, 0x6E67, 0x6E4A,
+		0x6E20, 0x6E25, 0x6E23, 0x6E1B, 0x6E5B, 0x6E58, 0x6E24, 0x6E56, 0x6E6E, 0x6E2D, 0x6E26,
+		0x6E6F, 0x6E34, 0x6E4D, 0x6E3A, 0x6E2C, 0x6E43, 0x6E1D, 0x6E3E, 0x6ECB, 0x6E89, 0x6E19,
+		0x6E4E, 0x6E63, 0x6E44, 0x6E72, 0x6E69, 0x6E5F, 0x7119, 0x711A, 0x7126, 0x7130, 0x7121,
+		0x7136, 0x716E, 0x711C, 0x724C, 0x7284, 0x7280, 0x7336, 0x7325, 0x7334, 0x7329, 0x743A,
+		0x742A, 0x7433, 0x7422, 0x7425, 0x7435, 0x7436, 0x7434, 0x742F, 0x741B, 0x7426, 0x7428,
+		0x7525, 0x7526, 0x756B, 0x756A, 0x75E2, 0x75DB, 0x75E3, 0x75D9, 0x75D8, 0x75DE, 0x75E0,
+		0x767B, 0x767C, 0x7696, 0x7693, 0x76B4, 0x76DC, 0x774F, 0x77ED, 0x785D, 0x786C, 0x786F,
+		0x7A0D, 0x7A08, 0x7A0B, 0x7A05, 0x7A00, 0x7A98, 0x7A97, 0x7A96, 0x7AE5, 0x7AE3, 0x7B49,
+		0x7B56, 0x7B46, 0x7B50, 0x7B52, 0x7B54, 0x7B4D, 0x7B4B, 0x7B4F, 0x7B51, 0x7C9F, 0x7CA5,
+		0x7D5E, 0x7D50, 0x7D68, 0x7D55, 0x7D2B, 0x7D6E, 0x7D72, 0x7D61, 0x7D66, 0x7D62, 0x7D70,
+		0x7D73, 0x5584, 0x7FD4, 0x7FD5, 0x800B, 0x8052, 0x8085, 0x8155, 0x8154, 0x814B, 0x8151,
+		0x814E, 0x8139, 0x8146, 0x813E, 0x814C, 0x8153, 0x8174, 0x8212, 0x821C, 0x83E9, 0x8403,
+		0x83F8, 0x840D, 0x83E0, 0x83C5, 0x840B, 0x83C1, 0x83EF, 0x83F1, 0x83F4, 0x8457, 0x840A,
+		0x83F0, 0x840C, 0x83CC, 0x83FD, 0x83F2, 0x83CA, 0x8438, 0x840E, 0x8404, 0x83DC, 0x8407,
+		0x83D4, 0x83DF, 0x865B, 0x86DF, 0x86D9, 0x86ED, 0x86D4, 0x86DB, 0x86E4, 0x86D0, 0x86DE,
+		0x8857, 0x88C1, 0x88C2, 0x88B1, 0x8983, 0x8996, 0x8A3B, 0x8A60, 0x8A55, 0x8A5E, 0x8A3C,
+		0x8A41, 0x8A54, 0x8A5B, 0x8A50, 0x8A46, 0x8A34, 0x8A3A, 0x8A36, 0x8A56, 0x8C61, 0x8C82,
+		0x8CAF, 0x8CBC, 0x8CB3, 0x8CBD, 0x8CC1, 0x8CBB, 0x8CC0, 0x8CB4, 0x8CB7, 0x8CB6, 0x8CBF,
+		0x8CB8, 0x8D8A, 0x8D85, 0x8D81, 0x8DCE, 0x8DDD, 0x8DCB, 0x8DDA, 0x8DD1, 0x8DCC, 0x8DDB,
+		0x8DC6, 0x8EFB, 0x8EF8, 0x8EFC, 0x8F9C, 0x902E, 0x9035, 0x9031, 0x9038, 0x9032, 0x9036,
+		0x9102, 0x90F5, 0x9109, 0x90FE, 0x9163, 0x9165, 0x91CF, 0x9214, 0x9215, 0x9223, 0x9209,
+		0x921E, 0x920D, 0x9210, 0x9207, 0x9211, 0x9594, 0x958F, 0x958B, 0x9591, 0x9593, 0x9592,
+		0x958E, 0x968A, 0x968E, 0x968B, 0x967D, 0x9685, 0x9686, 0x968D, 0x9672, 0x9684, 0x96C1,
+		0x96C5, 0x96C4, 0x96C6, 0x96C7, 0x96EF, 0x96F2, 0x97CC, 0x9805, 0x9806, 0x9808, 0x98E7,
+		0x98EA, 0x98EF, 0x98E9, 0x98F2, 0x98ED, 0x99AE, 0x99AD, 0x9EC3, 0x9ECD, 0x9ED1, 0x4E82,
+		0x50AD, 0x50B5, 0x50B2, 0x50B3, 0x50C5, 0x50BE, 0x50AC, 0x50B7, 0x50BB, 0x50AF, 0x50C7,
+		0x527F, 0x5277, 0x527D, 0x52DF, 0x52E6, 0x52E4, 0x52E2, 0x52E3, 0x532F, 0x55DF, 0x55E8,
+		0x55D3, 0x55E6, 0x55CE, 0x55DC, 0x55C7, 0x55D1, 0x55E3, 0x55E4, 0x55EF, 0x55DA, 0x55E1,
+		0x55C5, 0x55C6, 0x55E5, 0x55C9, 0x5712, 0x5713, 0x585E, 0x5851, 0x5858, 0x5857, 0x585A,
+		0x5854, 0x586B, 0x584C, 0x586D, 0x584A, 0x5862, 0x5852, 0x584B, 0x5967, 0x5AC1, 0x5AC9,
+		0x5ACC, 0x5ABE, 0x5ABD, 0x5ABC, 0x5AB3, 0x5AC2, 0x5AB2, 0x5D69, 0x5D6F, 0x5E4C, 0x5E79,
+		0x5EC9, 0x5EC8, 0x5F12, 0x5F59, 0x5FAC, 0x5FAE, 0x611A, 0x610F, 0x6148, 0x611F, 0x60F3,
+		0x611B, 0x60F9, 0x6101, 0x6108, 0x614E, 0x614C, 0x6144, 0x614D, 0x613E, 0x6134, 0x6127,
+		0x610D, 0x6106, 0x6137, 0x6221, 0x6222, 0x6413, 0x643E, 0x641E, 0x642A, 0x642D, 0x643D,
+		0x642C, 0x640F, 0x641C, 0x6414, 0x640D, 0x6436, 0x6416, 0x6417, 0x6406, 0x656C, 0x659F,
+		0x65B0, 0x6697, 0x6689, 0x6687, 0x6688, 0x6696, 0x6684, 0x6698, 0x668D, 0x6703, 0x6994,
+		0x696D, 0x695A, 0x6977, 0x6960, 0x6954, 0x6975, 0x6930, 0x6982, 0x694A, 0x6968, 0x696B,
+		0x695E, 0x6953, 0x6979, 0x6986, 0x695D, 0x6963, 0x695B, 0x6B47, 0x6B72, 0x6BC0, 0x6BBF,
+		0x6BD3, 0x6BFD, 0x6EA2, 0x6EAF, 0x6ED3, 0x6EB6, 0x6EC2, 0x6E90, 0x6E9D, 0x6EC7, 0x6EC5,
+		0x6EA5, 0x6E98, 0x6EBC, 0x6EBA, 0x6EAB, 0x6ED1, 0x6E96, 0x6E9C, 0x6EC4, 0x6ED4, 0x6EAA,
+		0x6EA7, 0x6EB4, 0x714E, 0x7159, 0x7169, 0x7164, 0x7149, 0x7167, 0x715C, 0x716C, 0x7166,
+		0x714C, 0x7165, 0x715E, 0x7146, 0x7168, 0x7156, 0x723A, 0x7252, 0x7337, 0x7345, 0x733F,
+		0x733E, 0x746F, 0x745A, 0x7455, 0x745F, 0x745E, 0x7441, 0x743F, 0x7459, 0x745B, 0x745C,
+		0x7576, 0x7578, 0x7600, 0x75F0, 0x7601, 0x75F2, 0x75F1, 0x75FA, 0x75FF, 0x75F4, 0x75F3,
+		0x76DE, 0x76DF, 0x775B, 0x776B, 0x7766, 0x775E, 0x7763, 0x7779, 0x776A, 0x776C, 0x775C,
+		0x7765, 0x7768, 0x7762, 0x77EE, 0x788E, 0x78B0, 0x7897, 0x7898, 0x788C, 0x7889, 0x787C,
+		0x7891, 0x7893, 0x787F, 0x797A, 0x797F, 0x7981, 0x842C, 0x79BD, 0x7A1C, 0x7A1A, 0x7A20,
+		0x7A14, 0x7A1F, 0x7A1E, 0x7A9F, 0x7AA0, 0x7B77, 0x7BC0, 0x7B60, 0x7B6E, 0x7B67, 0x7CB1,
+		0x7CB3, 0x7CB5, 0x7D93, 0x7D79, 0x7D91, 0x7D81, 0x7D8F, 0x7D5B, 0x7F6E, 0x7F69, 0x7F6A,
+		0x7F72, 0x7FA9, 0x7FA8, 0x7FA4, 0x8056, 0x8058, 0x8086, 0x8084, 0x8171, 0x8170, 0x8178,
+		0x8165, 0x816E, 0x8173, 0x816B, 0x8179, 0x817A, 0x8166, 0x8205, 0x8247, 0x8482, 0x8477,
+		0x843D, 0x8431, 0x8475, 0x8466, 0x846B, 0x8449, 0x846C, 0x845B, 0x843C, 0x8435, 0x8461,
+		0x8463, 0x8469, 0x846D, 0x8446, 0x865E, 0x865C, 0x865F, 0x86F9, 0x8713, 0x8708, 0x8707,
+		0x8700, 0x86FE, 0x86FB, 0x8702, 0x8703, 0x8706, 0x870A, 0x8859, 0x88DF, 0x88D4, 0x88D9,
+		0x88DC, 0x88D8, 0x88DD, 0x88E1, 0x88CA, 0x88D5, 0x88D2, 0x899C, 0x89E3, 0x8A6B, 0x8A72,
+		0x8A73, 0x8A66, 0x8A69, 0x8A70, 0x8A87, 0x8A7C, 0x8A63, 0x8AA0, 0x8A71, 0x8A85, 0x8A6D,
+		0x8A62, 0x8A6E, 0x8A6C, 0x8A79, 0x8A7B, 0x8A3E, 0x8A68, 0x8C62, 0x8C8A, 0x8C89, 0x8CCA,
+		0x8CC7, 0x8CC8, 0x8CC4, 0x8CB2, 0x8CC3, 0x8CC2, 0x8CC5, 0x8DE1, 0x8DDF, 0x8DE8, 0x8DEF,
+		0x8DF3, 0x8DFA, 0x8DEA, 0x8DE4, 0x8DE6, 0x8EB2, 0x8F03, 0x8F09, 0x8EFE, 0x8F0A, 0x8F9F,
+		0x8FB2, 0x904B, 0x904A, 0x9053, 0x9042, 0x9054, 0x903C, 0x9055, 0x9050, 0x9047, 0x904F,
+		0x904E, 0x904D, 0x9051, 0x903E, 0x9041, 0x9112, 0x9117, 0x916C, 0x916A, 0x9169, 0x91C9,
+		0x9237, 0x9257, 0x9238, 0x923D, 0x9240, 0x923E, 0x925B, 0x924B, 0x9264, 0x9251, 0x9234,
+		0x9249, 0x924D, 0x9245, 0x9239, 0x923F, 0x925A, 0x9598, 0x9698, 0x9694, 0x9695, 0x96CD,
+		0x96CB, 0x96C9, 0x96CA, 0x96F7, 0x96FB, 0x96F9, 0x96F6, 0x9756, 0x9774, 0x9776, 0x9810,
+		0x9811, 0x9813, 0x980A, 0x9812, 0x980C, 0x98FC, 0x98F4, 0x98FD, 0x98FE, 0x99B3, 0x99B1,
+		0x99B4, 0x9AE1, 0x9CE9, 0x9E82, 0x9F0E, 0x9F13, 0x9F20, 0x50E7, 0x50EE, 0x50E5, 0x50D6,
+		0x50ED, 0x50DA, 0x50D5, 0x50CF, 0x50D1, 0x50F1, 0x50CE, 0x50E9, 0x5162, 0x51F3, 0x5283,
+		0x5282, 0x5331, 0x53AD, 0x55FE, 0x5600, 0x561B, 0x5617, 0x55FD, 0x5614, 0x5606, 0x5609,
+		0x560D, 0x560E, 0x55F7, 0x5616, 0x561F, 0x5608, 0x5610, 0x55F6, 0x5718, 0x5716, 0x5875,
+		0x587E, 0x5883, 0x5893, 0x588A, 0x5879, 0x5885, 0x587D, 0x58FD, 0x5925, 0x5922, 0x5924,
+		0x596A, 0x5969, 0x5AE1, 0x5AE6, 0x5AE9, 0x5AD7, 0x5AD6, 0x5AD8, 0x5AE3, 0x5B75, 0x5BDE,
+		0x5BE7, 0x5BE1, 0x5BE5, 0x5BE6, 0x5BE8, 0x5BE2, 0x5BE4, 0x5BDF, 0x5C0D, 0x5C62, 0x5D84,
+		0x5D87, 0x5E5B, 0x5E63, 0x5E55, 0x5E57, 0x5E54, 0x5ED3, 0x5ED6, 0x5F0A, 0x5F46, 0x5F70,
+		0x5FB9, 0x6147, 0x613F, 0x614B, 0x6177, 0x6162, 0x6163, 0x615F, 0x615A, 0x6158, 0x6175,
+		0x622A, 0x6487, 0x6458, 0x6454, 0x64A4, 0x6478, 0x645F, 0x647A, 0x6451, 0x6467, 0x6434,
+		0x646D, 0x647B, 0x6572, 0x65A1, 0x65D7, 0x65D6, 0x66A2, 0x66A8, 0x669D, 0x699C, 0x69A8,
+		0x6995, 0x69C1, 0x69AE, 0x69D3, 0x69CB, 0x699B, 0x69B7, 0x69BB, 0x69AB, 0x69B4, 0x69D0,
+		0x69CD, 0x69AD, 0x69CC, 0x69A6, 0x69C3, 0x69A3, 0x6B49, 0x6B4C, 0x6C33, 0x6F33, 0x6F14,
+		0x6EFE, 0x6F13, 0x6EF4, 0x6F29, 0x6F3E, 0x6F20, 0x6F2C, 0x6F0F, 0x6F02, 0x6F22, 0x6EFF,
+		0x6EEF, 0x6F06, 0x6F31, 0x6F38, 0x6F32, 0x6F23, 0x6F15, 0x6F2B, 0x6F2F, 0x6F88, 0x6F2A,
+		0x6EEC, 0x6F01, 0x6EF2, 0x6ECC, 0x6EF7, 0x7194, 0x7199, 0x717D, 0x718A, 0x7184, 0x7192,
+		0x723E, 0x7292, 0x7296, 0x7344, 0x7350, 0x7464, 0x7463, 0x746A, 0x7470, 0x746D, 0x7504,
+		0x7591, 0x7627, 0x760D, 0x760B, 0x7609, 0x7613, 0x76E1, 0x76E3, 0x7784, 0x777D, 0x777F,
+		0x7761, 0x78C1, 0x789F, 0x78A7, 0x78B3, 0x78A9, 0x78A3, 0x798E, 0x798F, 0x798D, 0x7A2E,
+		0x7A31, 0x7AAA, 0x7AA9, 0x7AED, 0x7AEF, 0x7BA1, 0x7B95, 0x7B8B, 0x7B75, 0x7B97, 0x7B9D,
+		0x7B94, 0x7B8F, 0x7BB8, 0x7B87, 0x7B84, 0x7CB9, 0x7CBD, 0x7CBE, 0x7DBB, 0x7DB0, 0x7D9C,
+		0x7DBD, 0x7DBE, 0x7DA0, 0x7DCA, 0x7DB4, 0x7DB2, 0x7DB1, 0x7DBA, 0x7DA2, 0x7DBF, 0x7DB5,
+		0x7DB8, 0x7DAD, 0x7DD2, 0x7DC7, 0x7DAC, 0x7F70, 0x7FE0, 0x7FE1, 0x7FDF, 0x805E, 0x805A,
+		0x8087, 0x8150, 0x8180, 0x818F, 0x8188, 0x818A, 0x817F, 0x8182, 0x81E7, 0x81FA, 0x8207,
+		0x8214, 0x821E, 0x824B, 0x84C9, 0x84BF, 0x84C6, 0x84C4, 0x8499, 0x849E, 0x84B2, 0x849C,
+		0x84CB, 0x84B8, 0x84C0, 0x84D3, 0x8490, 0x84BC, 0x84D1, 0x84CA, 0x873F, 0x871C, 0x873B,
+		0x8722, 0x8725, 0x8734, 0x8718, 0x8755, 0x8737, 0x8729, 0x88F3, 0x8902, 0x88F4, 0x88F9,
+		0x88F8, 0x88FD, 0x88E8, 0x891A, 0x88EF, 0x8AA6, 0x8A8C, 0x8A9E, 0x8AA3, 0x8A8D, 0x8AA1,
+		0x8A93, 0x8AA4, 0x8AAA, 0x8AA5, 0x8AA8, 0x8A98, 0x8A91, 0x8A9A, 0x8AA7, 0x8C6A, 0x8C8D,
+		0x8C8C, 0x8CD3, 0x8CD1, 0x8CD2, 0x8D6B, 0x8D99, 0x8D95, 0x8DFC, 0x8F14, 0x8F12, 0x8F15,
+		0x8F13, 0x8FA3, 0x9060, 0x9058, 0x905C, 0x9063, 0x9059, 0x905E, 0x9062, 0x905D, 0x905B,
+		0x9119, 0x9118, 0x911E, 0x9175, 0x9178, 0x9177, 0x9174, 0x9278, 0x9280, 0x9285, 0x9298,
+		0x9296, 0x927B, 0x9293, 0x929C, 0x92A8, 0x927C, 0x9291, 0x95A1, 0x95A8, 0x95A9, 0x95A3,
+		0x95A5, 0x95A4, 0x9699, 0x969C, 0x969B, 0x96CC, 0x96D2, 0x9700, 0x977C, 0x9785, 0x97F6,
+		0x9817, 0x9818, 0x98AF, 0x98B1, 0x9903, 0x9905, 0x990C, 0x9909, 0x99C1, 0x9AAF, 0x9AB0,
+		0x9AE6, 0x9B41, 0x9B42, 0x9CF4, 0x9CF6, 0x9CF3, 0x9EBC, 0x9F3B, 0x9F4A, 0x5104, 0x5100,
+		0x50FB, 0x50F5, 0x50F9, 0x5102, 0x5108, 0x5109, 0x5105, 0x51DC, 0x5287, 0x5288, 0x5289,
+		0x528D, 0x528A, 0x52F0, 0x53B2, 0x562E, 0x563B, 0x5639, 0x5632, 0x563F, 0x5634, 0x5629,
+		0x5653, 0x564E, 0x5657, 0x5674, 0x5636, 0x562F, 0x5630, 0x5880, 0x589F, 0x589E, 0x58B3,
+		0x589C, 0x58AE, 0x58A9, 0x58A6, 0x596D, 0x5B09, 0x5AFB, 0x5B0B, 0x5AF5, 0x5B0C, 0x5B08,
+		0x5BEE, 0x5BEC, 0x5BE9, 0x5BEB, 0x5C64, 0x5C65, 0x5D9D, 0x5D94, 0x5E62, 0x5E5F, 0x5E61,
+		0x5EE2, 0x5EDA, 0x5EDF, 0x5EDD, 0x5EE3, 0x5EE0, 0x5F48, 0x5F71, 0x5FB7, 0x5FB5, 0x6176,
+		0x6167, 0x616E, 0x615D, 0x6155, 0x6182, 0x617C, 0x6170, 0x616B, 0x617E, 0x61A7, 0x6190,
+		0x61AB, 0x618E, 0x61AC, 0x619A, 0x61A4, 0x6194, 0x61AE, 0x622E, 0x6469, 0x646F, 0x6479,
+		0x649E, 0x64B2, 0x6488, 0x6490, 0x64B0, 0x64A5, 0x6493, 0x6495, 0x64A9, 0x6492, 0x64AE,
+		0x64AD, 0x64AB, 0x649A, 0x64AC, 0x6499, 0x64A2, 0x64B3, 0x6575, 0x6577, 0x6578, 0x66AE,
+		0x66AB, 0x66B4, 0x66B1, 0x6A23, 0x6A1F, 0x69E8, 0x6A01, 0x6A1E, 0x6A19, 0x69FD, 0x6A21,
+		0x6A13, 0x6A0A, 0x69F3, 0x6A02, 0x6A05, 0x69ED, 0x6A11, 0x6B50, 0x6B4E, 0x6BA4, 0x6BC5,
+		0x6BC6, 0x6F3F, 0x6F7C, 0x6F84, 0x6F51, 0x6F66, 0x6F54, 0x6F86, 0x6F6D, 0x6F5B, 0x6F78,
+		0x6F6E, 0x6F8E, 0x6F7A, 0x6F70, 0x6F64, 0x6F97, 0x6F58, 0x6ED5, 0x6F6F, 0x6F60, 0x6F5F,
+		0x719F, 0x71AC, 0x71B1, 0x71A8, 0x7256, 0x729B, 0x734E, 0x7357, 0x7469, 0x748B, 0x7483,
+		0x747E, 0x7480, 0x757F, 0x7620, 0x7629, 0x761F, 0x7624, 0x7626, 0x7621, 0x7622, 0x769A,
+		0x76BA, 0x76E4, 0x778E, 0x7787, 0x778C, 0x7791, 0x778B, 0x78CB, 0x78C5, 0x78BA, 0x78CA,
+		0x78BE, 0x78D5, 0x78BC, 0x78D0, 0x7A3F, 0x7A3C, 0x7A40, 0x7A3D, 0x7A37, 0x7A3B, 0x7AAF,
+		0x7AAE, 0x7BAD, 0x7BB1, 0x7BC4, 0x7BB4, 0x7BC6, 0x7BC7, 0x7BC1, 0x7BA0, 0x7BCC, 0x7CCA,
+		0x7DE0, 0x7DF4, 0x7DEF, 0x7DFB, 0x7DD8, 0x7DEC, 0x7DDD, 0x7DE8, 0x7DE3, 0x7DDA, 0x7DDE,
+		0x7DE9, 0x7D9E, 0x7DD9, 0x7DF2, 0x7DF9, 0x7F75, 0x7F77, 0x7FAF, 0x7FE9, 0x8026, 0x819B,
+		0x819C, 0x819D, 0x81A0, 0x819A, 0x8198, 0x8517, 0x853D, 0x851A, 0x84EE, 0x852C, 0x852D,
+		0x8513, 0x8511, 0x8523, 0x8521, 0x8514, 0x84EC, 0x8525, 0x84FF, 0x8506, 0x8782, 0x8774,
+		0x8776, 0x8760, 0x8766, 0x8778, 0x8768, 0x8759, 0x8757, 0x874C, 0x8753, 0x885B, 0x885D,
+		0x8910, 0x8907, 0x8912, 0x8913, 0x8915, 0x890A, 0x8ABC, 0x8AD2, 0x8AC7, 0x8AC4, 0x8A95,
+		0x8ACB, 0x8AF8, 0x8AB2, 0x8AC9, 0x8AC2, 0x8ABF, 0x8AB0, 0x8AD6, 0x8ACD, 0x8AB6, 0x8AB9,
+		0x8ADB, 0x8C4C, 0x8C4E, 0x8C6C, 0x8CE0, 0x8CDE, 0x8CE6, 0x8CE4, 0x8CEC, 0x8CED, 0x8CE2,
+		0x8CE3, 0x8CDC, 0x8CEA, 0x8CE1, 0x8D6D, 0x8D9F, 0x8DA3, 0x8E2B, 0x8E10, 0x8E1D, 0x8E22,
+		0x8E0F, 0x8E29, 0x8E1F, 0x8E21, 0x8E1E, 0x8EBA, 0x8F1D, 0x8F1B, 0x8F1F, 0x8F29, 0x8F26,
+		0x8F2A, 0x8F1C, 0x8F1E, 0x8F25, 0x9069, 0x906E, 0x9068, 0x906D, 0x9077, 0x9130, 0x912D,
+		0x9127, 0x9131, 0x9187, 0x9189, 0x918B, 0x9183, 0x92C5, 0x92BB, 0x92B7, 0x92EA, 0x92AC,
+		0x92E4, 0x92C1, 0x92B3, 0x92BC, 0x92D2, 0x92C7, 0x92F0, 0x92B2, 0x95AD, 0x95B1, 0x9704,
+		0x9706, 0x9707, 0x9709, 0x9760, 0x978D, 0x978B, 0x978F, 0x9821, 0x982B, 0x981C, 0x98B3,
+		0x990A, 0x9913, 0x9912, 0x9918, 0x99DD, 0x99D0, 0x99DF, 0x99DB, 0x99D1, 0x99D5, 0x99D2,
+		0x99D9, 0x9AB7, 0x9AEE, 0x9AEF, 0x9B27, 0x9B45, 0x9B44, 0x9B77, 0x9B6F, 0x9D06, 0x9D09,
+		0x9D03, 0x9EA9, 0x9EBE, 0x9ECE, 0x58A8, 0x9F52, 0x5112, 0x5118, 0x5114, 0x5110, 0x5115,
+		0x5180, 0x51AA, 0x51DD, 0x5291, 0x5293, 0x52F3, 0x5659, 0x566B, 0x5679, 0x5669, 0x5664,
+		0x5678, 0x566A, 0x5668, 0x5665, 0x5671, 0x566F, 0x566C, 0x5662, 0x5676, 0x58C1, 0x58BE,
+		0x58C7, 0x58C5, 0x596E, 0x5B1D, 0x5B34, 0x5B78, 0x5BF0, 0x5C0E, 0x5F4A, 0x61B2, 0x6191,
+		0x61A9, 0x618A, 0x61CD, 0x61B6, 0x61BE, 0x61CA, 0x61C8, 0x6230, 0x64C5, 0x64C1, 0x64CB,
+		0x64BB, 0x64BC, 0x64DA, 0x64C4, 0x64C7, 0x64C2, 0x64CD, 0x64BF, 0x64D2, 0x64D4, 0x64BE,
+		0x6574, 0x66C6, 0x66C9, 0x66B9, 0x66C4, 0x66C7, 0x66B8, 0x6A3D, 0x6A38, 0x6A3A, 0x6A59,
+		0x6A6B, 0x6A58, 0x6A39, 0x6A44, 0x6A62, 0x6A61, 0x6A4B, 0x6A47, 0x6A35, 0x6A5F, 0x6A48,
+		0x6B59, 0x6B77, 0x6C05, 0x6FC2, 0x6FB1, 0x6FA1, 0x6FC3, 0x6FA4, 0x6FC1, 0x6FA7, 0x6FB3,
+		0x6FC0, 0x6FB9, 0x6FB6, 0x6FA6, 0x6FA0, 0x6FB4, 0x71BE, 0x71C9, 0x71D0, 0x71D2, 0x71C8,
+		0x71D5, 0x71B9, 0x71CE, 0x71D9, 0x71DC, 0x71C3, 0x71C4, 0x7368, 0x749C, 0x74A3, 0x7498,
+		0x749F, 0x749E, 0x74E2, 0x750C, 0x750D, 0x7634, 0x7638, 0x763A, 0x76E7, 0x76E5, 0x77A0,
+		0x779E, 0x779F, 0x77A5, 0x78E8, 0x78DA, 0x78EC, 0x78E7, 0x79A6, 0x7A4D, 0x7A4E, 0x7A46,
+		0x7A4C, 0x7A4B, 0x7ABA, 0x7BD9, 0x7C11, 0x7BC9, 0x7BE4, 0x7BDB, 0x7BE1, 0x7BE9, 0x7BE6,
+		0x7CD5, 0x7CD6, 0x7E0A, 0x7E11, 0x7E08, 0x7E1B, 0x7E23, 0x7E1E, 0x7E1D, 0x7E09, 0x7E10,
+		0x7F79, 0x7FB2, 0x7FF0, 0x7FF1, 0x7FEE, 0x8028, 0x81B3, 0x81A9, 0x81A8, 0x81FB, 0x8208,
+		0x8258, 0x8259, 0x854A, 0x8559, 0x8548, 0x8568, 0x8569, 0x8543, 0x8549, 0x856D, 0x856A,
+		0x855E, 0x8783, 0x879F, 0x879E, 0x87A2, 0x878D, 0x8861, 0x892A, 0x8932, 0x8925, 0x892B,
+		0x8921, 0x89AA, 0x89A6, 0x8AE6, 0x8AFA, 0x8AEB, 0x8AF1, 0x8B00, 0x8ADC, 0x8AE7, 0x8AEE,
+		0x8AFE, 0x8B01, 0x8B02, 0x8AF7, 0x8AED, 0x8AF3, 0x8AF6, 0x8AFC, 0x8C6B, 0x8C6D, 0x8C93,
+		0x8CF4, 0x8E44, 0x8E31, 0x8E34, 0x8E42, 0x8E39, 0x8E35, 0x8F3B, 0x8F2F, 0x8F38, 0x8F33,
+		0x8FA8, 0x8FA6, 0x9075, 0x9074, 0x9078, 0x9072, 0x907C, 0x907A, 0x9134, 0x9192, 0x9320,
+		0x9336, 0x92F8, 0x9333, 0x932F, 0x9322, 0x92FC, 0x932B, 0x9304, 0x931A, 0x9310, 0x9326,
+		0x9321, 0x9315, 0x932E, 0x9319, 0x95BB, 0x96A7, 0x96A8, 0x96AA, 0x96D5, 0x970E, 0x9711,
+		0x9716, 0x970D, 0x9713, 0x970F, 0x975B, 0x975C, 0x9766, 0x9798, 0x9830, 0x9838, 0x983B,
+		0x9837, 0x982D, 0x9839, 0x9824, 0x9910, 0x9928, 0x991E, 0x991B, 0x9921, 0x991A, 0x99ED,
+		0x99E2, 0x99F1, 0x9AB8, 0x9ABC, 0x9AFB, 0x9AED, 0x9B28, 0x9B91, 0x9D15, 0x9D23, 0x9D26,
+		0x9D28, 0x9D12, 0x9D1B, 0x9ED8, 0x9ED4, 0x9F8D, 0x9F9C, 0x512A, 0x511F, 0x5121, 0x5132,
+		0x52F5, 0x568E, 0x5680, 0x5690, 0x5685, 0x5687, 0x568F, 0x58D5, 0x58D3, 0x58D1, 0x58CE,
+		0x5B30, 0x5B2A, 0x5B24, 0x5B7A, 0x5C37, 0x5C68, 0x5DBC, 0x5DBA, 0x5DBD, 0x5DB8, 0x5E6B,
+		0x5F4C, 0x5FBD, 0x61C9, 0x61C2, 0x61C7, 0x61E6, 0x61CB, 0x6232, 0x6234, 0x64CE, 0x64CA,
+		0x64D8, 0x64E0, 0x64F0, 0x64E6, 0x64EC, 0x64F1, 0x64E2, 0x64ED, 0x6582, 0x6583, 0x66D9,
+		0x66D6, 0x6A80, 0x6A94, 0x6A84, 0x6AA2, 0x6A9C, 0x6ADB, 0x6AA3, 0x6A7E, 0x6A97, 0x6A90,
+		0x6AA0, 0x6B5C, 0x6BAE, 0x6BDA, 0x6C08, 0x6FD8, 0x6FF1, 0x6FDF, 0x6FE0, 0x6FDB, 0x6FE4,
+		0x6FEB, 0x6FEF, 0x6F80, 0x6FEC, 0x6FE1, 0x6FE9, 0x6FD5, 0x6FEE, 0x6FF0, 0x71E7, 0x71DF,
+		0x71EE, 0x71E6, 0x71E5, 0x71ED, 0x71EC, 0x71F4, 0x71E0, 0x7235, 0x7246, 0x7370, 0x7372,
+		0x74A9, 0x74B0, 0x74A6, 0x74A8, 0x7646, 0x7642, 0x764C, 0x76EA, 0x77B3, 0x77AA, 0x77B0,
+		0x77AC, 0x77A7, 0x77AD, 0x77EF, 0x78F7, 0x78FA, 0x78F4, 0x78EF, 0x7901, 0x79A7, 0x79AA,
+		0x7A57, 0x7ABF, 0x7C07, 0x7C0D, 0x7BFE, 0x7BF7, 0x7C0C, 0x7BE0, 0x7CE0, 0x7CDC, 0x7CDE,
+		0x7CE2, 0x7CDF, 0x7CD9, 0x7CDD, 0x7E2E, 0x7E3E, 0x7E46, 0x7E37, 0x7E32, 0x7E43, 0x7E2B,
+		0x7E3D, 0x7E31, 0x7E45, 0x7E41, 0x7E34, 0x7E39, 0x7E48, 0x7E35, 0x7E3F, 0x7E2F, 0x7F44,
+		0x7FF3, 0x7FFC, 0x8071, 0x8072, 0x8070, 0x806F, 0x8073, 0x81C6, 0x81C3, 0x81BA, 0x81C2,
+		0x81C0, 0x81BF, 0x81BD, 0x81C9, 0x81BE, 0x81E8, 0x8209, 0x8271, 0x85AA, 0x8584, 0x857E,
+		0x859C, 0x8591, 0x8594, 0x85AF, 0x859B, 0x8587, 0x85A8, 0x858A, 0x8667, 0x87C0, 0x87D1,
+		0x87B3, 0x87D2, 0x87C6, 0x87AB, 0x87BB, 0x87BA, 0x87C8, 0x87CB, 0x893B, 0x8936, 0x8944,
+		0x8938, 0x893D, 0x89AC, 0x8B0E, 0x8B17, 0x8B19, 0x8B1B, 0x8B0A, 0x8B20, 0x8B1D, 0x8B04,
+		0x8B10, 0x8C41, 0x8C3F, 0x8C73, 0x8CFA, 0x8CFD, 0x8CFC, 0x8CF8, 0x8CFB, 0x8DA8, 0x8E49,
+		0x8E4B, 0x8E48, 0x8E4A, 0x8F44, 0x8F3E, 0x8F42, 0x8F45, 0x8F3F, 0x907F, 0x907D, 0x9084,
+		0x9081, 0x9082, 0x9080, 0x9139, 0x91A3, 0x919E, 0x919C, 0x934D, 0x9382, 0x9328, 0x9375,
+		0x934A, 0x9365, 0x934B, 0x9318, 0x937E, 0x936C, 0x935B, 0x9370, 0x935A, 0x9354, 0x95CA,
+		0x95CB, 0x95CC, 0x95C8, 0x95C6, 0x96B1, 0x96B8, 0x96D6, 0x971C, 0x971E, 0x97A0, 0x97D3,
+		0x9846, 0x98B6, 0x9935, 0x9A01, 0x99FF, 0x9BAE, 0x9BAB, 0x9BAA, 0x9BAD, 0x9D3B, 0x9D3F,
+		0x9E8B, 0x9ECF, 0x9EDE, 0x9EDC, 0x9EDD, 0x9EDB, 0x9F3E, 0x9F4B, 0x53E2, 0x5695, 0x56AE,
+		0x58D9, 0x58D8, 0x5B38, 0x5F5D, 0x61E3, 0x6233, 0x64F4, 0x64F2, 0x64FE, 0x6506, 0x64FA,
+		0x64FB, 0x64F7, 0x65B7, 0x66DC, 0x6726, 0x6AB3, 0x6AAC, 0x6AC3, 0x6ABB, 0x6AB8, 0x6AC2,
+		0x6AAE, 0x6AAF, 0x6B5F, 0x6B78, 0x6BAF, 0x7009, 0x700B, 0x6FFE, 0x7006, 0x6FFA, 0x7011,
+		0x700F, 0x71FB, 0x71FC, 0x71FE, 0x71F8, 0x7377, 0x7375, 0x74A7, 0x74BF, 0x7515, 0x7656,
+		0x7658, 0x7652, 0x77BD, 0x77BF, 0x77BB, 0x77BC, 0x790E, 0x79AE, 0x7A61, 0x7A62, 0x7A60,
+		0x7AC4, 0x7AC5, 0x7C2B, 0x7C27, 0x7C2A, 0x7C1E, 0x7C23, 0x7C21, 0x7CE7, 0x7E54, 0x7E55,
+		0x7E5E, 0x7E5A, 0x7E61, 0x7E52, 0x7E59, 0x7F48, 0x7FF9, 0x7FFB, 0x8077, 0x8076, 0x81CD,
+		0x81CF, 0x820A, 0x85CF, 0x85A9, 0x85CD, 0x85D0, 0x85C9, 0x85B0, 0x85BA, 0x85B9, 0x85A6,
+		0x87EF, 0x87EC, 0x87F2, 0x87E0, 0x8986, 0x89B2, 0x89F4, 0x8B28, 0x8B39, 0x8B2C, 0x8B2B,
+		0x8C50, 0x8D05, 0x8E59, 0x8E63, 0x8E66, 0x8E64, 0x8E5F, 0x8E55, 0x8EC0, 0x8F49, 0x8F4D,
+		0x9087, 0x9083, 0x9088, 0x91AB, 0x91AC, 0x91D0, 0x9394, 0x938A, 0x9396, 0x93A2, 0x93B3,
+		0x93AE, 0x93AC, 0x93B0, 0x9398, 0x939A, 0x9397, 0x95D4, 0x95D6, 0x95D0, 0x95D5, 0x96E2,
+		0x96DC, 0x96D9, 0x96DB, 0x96DE, 0x9724, 0x97A3, 0x97A6, 0x97AD, 0x97F9, 0x984D, 0x984F,
+		0x984C, 0x984E, 0x9853, 0x98BA, 0x993E, 0x993F, 0x993D, 0x992E, 0x99A5, 0x9A0E, 0x9AC1,
+		0x9B03, 0x9B06, 0x9B4F, 0x9B4E, 0x9B4D, 0x9BCA, 0x9BC9, 0x9BFD, 0x9BC8, 0x9BC0, 0x9D51,
+		0x9D5D, 0x9D60, 0x9EE0, 0x9F15, 0x9F2C, 0x5133, 0x56A5, 0x58DE, 0x58DF, 0x58E2, 0x5BF5,
+		0x9F90, 0x5EEC, 0x61F2, 0x61F7, 0x61F6, 0x61F5, 0x6500, 0x650F, 0x66E0, 0x66DD, 0x6AE5,
+		0x6ADD, 0x6ADA, 0x6AD3, 0x701B, 0x701F, 0x7028, 0x701A, 0x701D, 0x7015, 0x7018, 0x7206,
+		0x720D, 0x7258, 0x72A2, 0x7378, 0x737A, 0x74BD, 0x74CA, 0x74E3, 0x7587, 0x7586, 0x765F,
+		0x7661, 0x77C7, 0x7919, 0x79B1, 0x7A6B, 0x7A69, 0x7C3E, 0x7C3F, 0x7C38, 0x7C3D, 0x7C37,
+		0x7C40, 0x7E6B, 0x7E6D, 0x7E79, 0x7E69, 0x7E6A, 0x7F85, 0x7E73, 0x7FB6, 0x7FB9, 0x7FB8,
+		0x81D8, 0x85E9, 0x85DD, 0x85EA, 0x85D5, 0x85E4, 0x85E5, 0x85F7, 0x87FB, 0x8805, 0x880D,
+		0x87F9, 0x87FE, 0x8960, 0x895F, 0x8956, 0x895E, 0x8B41, 0x8B5C, 0x8B58, 0x8B49, 0x8B5A,
+		0x8B4E, 0x8B4F, 0x8B46, 0x8B59, 0x8D08, 0x8D0A, 0x8E7C, 0x8E72, 0x8E87, 0x8E76, 0x8E6C,
+		0x8E7A, 0x8E74, 0x8F54, 0x8F4E, 0x8FAD, 0x908A, 0x908B, 0x91B1, 0x91AE, 0x93E1, 0x93D1,
+		0x93DF, 0x93C3, 0x93C8, 0x93DC, 0x93DD, 0x93D6, 0x93E2, 0x93CD, 0x93D8, 0x93E4, 0x93D7,
+		0x93E8, 0x95DC, 0x96B4, 0x96E3, 0x972A, 0x9727, 0x9761, 0x97DC, 0x97FB, 0x985E, 0x9858,
+		0x985B, 0x98BC, 0x9945, 0x9949, 0x9A16, 0x9A19, 0x9B0D, 0x9BE8, 0x9BE7, 0x9BD6, 0x9BDB,
+		0x9D89, 0x9D61, 0x9D72, 0x9D6A, 0x9D6C, 0x9E92, 0x9E97, 0x9E93, 0x9EB4, 0x52F8, 0x56A8,
+		0x56B7, 0x56B6, 0x56B4, 0x56BC, 0x58E4, 0x5B40, 0x5B43, 0x5B7D, 0x5BF6, 0x5DC9, 0x61F8,
+		0x61FA, 0x6518, 0x6514, 0x6519, 0x66E6, 0x6727, 0x6AEC, 0x703E, 0x7030, 0x7032, 0x7210,
+		0x737B, 0x74CF, 0x7662, 0x7665, 0x7926, 0x792A, 0x792C, 0x792B, 0x7AC7, 0x7AF6, 0x7C4C,
+		0x7C43, 0x7C4D, 0x7CEF, 0x7CF0, 0x8FAE, 0x7E7D, 0x7E7C, 0x7E82, 0x7F4C, 0x8000, 0x81DA,
+		0x8266, 0x85FB, 0x85F9, 0x8611, 0x85FA, 0x8606, 0x860B, 0x8607, 0x860A, 0x8814, 0x8815,
+		0x8964, 0x89BA, 0x89F8, 0x8B70, 0x8B6C, 0x8B66, 0x8B6F, 0x8B5F, 0x8B6B, 0x8D0F, 0x8D0D,
+		0x8E89, 0x8E81, 0x8E85, 0x8E82, 0x91B4, 0x91CB, 0x9418, 0x9403, 0x93FD, 0x95E1, 0x9730,
+		0x98C4, 0x9952, 0x9951, 0x99A8, 0x9A2B, 0x9A30, 0x9A37, 0x9A35, 0x9C13, 0x9C0D, 0x9E79,
+		0x9EB5, 0x9EE8, 0x9F2F, 0x9F5F, 0x9F63, 0x9F61, 0x5137, 0x5138, 0x56C1, 0x56C0, 0x56C2,
+		0x5914, 0x5C6C, 0x5DCD, 0x61FC, 0x61FE, 0x651D, 0x651C, 0x6595, 0x66E9, 0x6AFB, 0x6B04,
+		0x6AFA, 0x6BB2, 0x704C, 0x721B, 0x72A7, 0x74D6, 0x74D4, 0x7669, 0x77D3, 0x7C50, 0x7E8F,
+		0x7E8C, 0x7FBC, 0x8617, 0x862D, 0x861A, 0x8823, 0x8822, 0x8821, 0x881F, 0x896A, 0x896C,
+		0x89BD, 0x8B74, 0x8B77, 0x8B7D, 0x8D13, 0x8E8A, 0x8E8D, 0x8E8B, 0x8F5F, 0x8FAF, 0x91BA,
+		0x942E, 0x9433, 0x9435, 0x943A, 0x9438, 0x9432, 0x942B, 0x95E2, 0x9738, 0x9739, 0x9732,
+		0x97FF, 0x9867, 0x9865, 0x9957, 0x9A45, 0x9A43, 0x9A40, 0x9A3E, 0x9ACF, 0x9B54, 0x9B51,
+		0x9C2D, 0x9C25, 0x9DAF, 0x9DB4, 0x9DC2, 0x9DB8, 0x9E9D, 0x9EEF, 0x9F19, 0x9F5C, 0x9F66,
+		0x9F67, 0x513C, 0x513B, 0x56C8, 0x56CA, 0x56C9, 0x5B7F, 0x5DD4, 0x5DD2, 0x5F4E, 0x61FF,
+		0x6524, 0x6B0A, 0x6B61, 0x7051, 0x7058, 0x7380, 0x74E4, 0x758A, 0x766E, 0x766C, 0x79B3,
+		0x7C60, 0x7C5F, 0x807E, 0x807D, 0x81DF, 0x8972, 0x896F, 0x89FC, 0x8B80, 0x8D16, 0x8D17,
+		0x8E91, 0x8E93, 0x8F61, 0x9148, 0x9444, 0x9451, 0x9452, 0x973D, 0x973E, 0x97C3, 0x97C1,
+		0x986B, 0x9955, 0x9A55, 0x9A4D, 0x9AD2, 0x9B1A, 0x9C49, 0x9C31, 0x9C3E, 0x9C3B, 0x9DD3,
+		0x9DD7, 0x9F34, 0x9F6C, 0x9F6A, 0x9F94, 0x56CC, 0x5DD6, 0x6200, 0x6523, 0x652B, 0x652A,
+		0x66EC, 0x6B10, 0x74DA, 0x7ACA, 0x7C64, 0x7C63, 0x7C65, 0x7E93, 0x7E96, 0x7E94, 0x81E2,
+		0x8638, 0x863F, 0x8831, 0x8B8A, 0x9090, 0x908F, 0x9463, 0x9460, 0x9464, 0x9768, 0x986F,
+		0x995C, 0x9A5A, 0x9A5B, 0x9A57, 0x9AD3, 0x9AD4, 0x9AD1, 0x9C54, 0x9C57, 0x9C56, 0x9DE5,
+		0x9E9F, 0x9EF4, 0x56D1, 0x58E9, 0x652C, 0x705E, 0x7671, 0x7672, 0x77D7, 0x7F50, 0x7F88,
+		0x8836, 0x8839, 0x8862, 0x8B93, 0x8B92, 0x8B96, 0x8277, 0x8D1B, 0x91C0, 0x946A, 0x9742,
+		0x9748, 0x9744, 0x97C6, 0x9870, 0x9A5F, 0x9B22, 0x9B58, 0x9C5F, 0x9DF9, 0x9DFA, 0x9E7C,
+		0x9E7D, 0x9F07, 0x9F77, 0x9F72, 0x5EF3, 0x6B16, 0x7063, 0x7C6C, 0x7C6E, 0x883B, 0x89C0,
+		0x8EA1, 0x91C1, 0x9472, 0x9470, 0x9871, 0x995E, 0x9AD6, 0x9B23, 0x9ECC, 0x7064, 0x77DA,
+		0x8B9A, 0x9477, 0x97C9, 0x9A62, 0x9A65, 0x7E9C, 0x8B9C, 0x8EAA, 0x91C5, 0x947D, 0x947E,
+		0x947C, 0x9C77, 0x9C78, 0x9EF7, 0x8C54, 0x947F, 0x9E1A, 0x7228, 0x9A6A, 0x9B31, 0x9E1B,
+		0x9E1E, 0x7C72, 0x2460, 0x2461, 0x2462, 0x2463, 0x2464, 0x2465, 0x2466, 0x2467, 0x2468,
+		0x2469, 0x2474, 0x2475, 0x2476, 0x2477, 0x2478, 0x2479, 0x247A, 0x247B, 0x247C, 0x247D,
+		0x2170, 0x2171, 0x2172, 0x2173, 0x2174, 0x2175, 0x2176, 0x2177, 0x2178, 0x2179, 0x4E36,
+		0x4E3F, 0x4E85, 0x4EA0, 0x5182, 0x5196, 0x51AB, 0x52F9, 0x5338, 0x5369, 0x53B6, 0x590A,
+		0x5B80, 0x5DDB, 0x2F33, 0x5E7F, 0xFFFD, 0x5F50, 0x5F61, 0x6534, 0xFFFD, 0x7592, 0xFFFD,
+		0x8FB5, 0xFFFD, 0x00A8, 0x02C6, 0x30FD, 0x30FE, 0x309D, 0x309E, 0xFFFD, 0xFFFD, 0x3005,
+		0x3006, 0x3007, 0x30FC, 0xFF3B, 0xFF3D, 0x273D, 0x3041, 0x3042, 0x3043, 0x3044, 0x3045,
+		0x3046, 0x3047, 0x3048, 0x3049, 0x304A, 0x304B, 0x304C, 0x304D, 0x304E, 0x304F, 0x3050,
+		0x3051, 0x3052, 0x3053, 0x3054, 0x3055, 0x3056, 0x3057, 0x3058, 0x3059, 0x305A, 0x305B,
+		0x305C, 0x305D, 0x305E, 0x305F, 0x3060, 0x3061, 0x3062, 0x3063, 0x3064, 0x3065, 0x3066,
+		0x3067, 0x3068, 0x3069, 0x306A, 0x306B, 0x306C, 0x306D, 0x306E, 0x306F, 0x3070, 0x3071,
+		0x3072, 0x3073, 0x3074, 0x3075, 0x3076, 0x3077, 0x3078, 0x3079, 0x307A, 0x307B, 0x307C,
+		0x307D, 0x307E, 0x307F, 0x3080, 0x3081, 0x3082, 0x3083, 0x3084, 0x3085, 0x3086, 0x3087,
+		0x3088, 0x3089, 0x308A, 0x308B, 0x308C, 0x308D, 0x308E, 0x308F, 0x3090, 0x3091, 0x3092,
+		0x3093, 0x30A1, 0x30A2, 0x30A3, 0x30A4, 0x30A5, 0x30A6, 0x30A7, 0x30A8, 0x30A9, 0x30AA,
+		0x30AB, 0x30AC, 0x30AD, 0x30AE, 0x30AF, 0x30B0, 0x30B1, 0x30B2, 0x30B3, 0x30B4, 0x30B5,
+		0x30B6, 0x30B7, 0x30B8, 0x30B9, 0x30BA, 0x30BB, 0x30BC, 0x30BD, 0x30BE, 0x30BF, 0x30C0,
+		0x30C1, 0x30C2, 0x30C3, 0x30C4, 0x30C5, 0x30C6, 0x30C7, 0x30C8, 0x30C9, 0x30CA, 0x30CB,
+		0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D0, 0x30D1, 0x30D2, 0x30D3, 0x30D4, 0x30D5, 0x30D6,
+		0x30D7, 0x30D8, 0x30D9, 0x30DA, 0x30DB, 0x30DC, 0x30DD, 0x30DE, 0x30DF, 0x30E0, 0x30E1,
+		0x30E2, 0x30E3, 0x30E4, 0x30E5, 0x30E6, 0x30E7, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC,
+		0x30ED, 0x30EE, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x30F3, 0x30F4, 0x30F5, 0x30F6, 0x0410,
+		0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0401, 0x0416, 0x0417, 0x0418, 0x0419, 0x041A,
+		0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425,
+		0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B, 0x042C, 0x042D, 0x042E, 0x042F, 0x0430,
+		0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0451, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A,
+		0x043B, 0x043C, 0x043D, 0x043E, 0x043F, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445,
+		0x0446, 0x0447, 0x0448, 0x0449, 0x044A, 0x044B, 0x044C, 0x044D, 0x044E, 0x044F, 0x21E7,
+		0x21B8, 0x21B9, 0x31CF, 0x200CC, 0x4E5A, 0x2008A, 0x5202, 0x4491, 0x9FB0, 0x5188, 0x9FB1,
+		0x27607, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD,
+		0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFFD, 0xFFE2, 0xFFE4, 0xFF07,
+		0xFF02, 0x3231, 0x2116, 0x2121, 0x309B, 0x309C, 0x2E80, 0x2E84, 0x2E86, 0x2E87, 0x2E88,
+		0x2E8A, 0x2E8C, 0x2E8D, 0x2E95, 0x2E9C, 0x2E9D, 0x2EA5, 0x2EA7, 0x2EAA, 0x2EAC, 0x2EAE,
+		0x2EB6, 0x2EBC, 0x2EBE, 0x2EC6, 0x2ECA, 0x2ECC, 0x2ECD, 0x2ECF, 0x2ED6, 0x2ED7, 0x2EDE,
+		0x2EE3, 0xFFFD, 0xFFFD, 0xFFFD, 0x0283, 0x0250, 0x025B, 0x0254, 0x0275, 0x0153, 0x00F8,
+		0x014B, 0x028A, 0x026A, 0x4E42, 0x4E5C, 0x51F5, 0x531A, 0x5382, 0x4E07, 0x4E0C, 0x4E47,
+		0x4E8D, 0x56D7, 0xFA0C, 0x5C6E, 0x5F73, 0x4E0F, 0x5187, 0x4E0E, 0x4E2E, 0x4E93, 0x4EC2,
+		0x4EC9, 0x4EC8, 0x5198, 0x52FC, 0x536C, 0x53B9, 0x5720, 0x5903, 0x592C, 0x5C10, 0x5DFF,
+		0x65E1, 0x6BB3, 0x6BCC, 0x6C14, 0x723F, 0x4E31, 0x4E3C, 0x4EE8, 0x4EDC, 0x4EE9, 0x4EE1,
+		0x4EDD, 0x4EDA, 0x520C, 0x531C, 0x534C, 0x5722, 0x5723, 0x5917, 0x592F, 0x5B81, 0x5B84,
+		0x5C12, 0x5C3B, 0x5C74, 0x5C73, 0x5E04, 0x5E80, 0x5E82, 0x5FC9, 0x6209, 0x6250, 0x6C15,
+		0x6C36, 0x6C43, 0x6C3F, 0x6C3B, 0x72AE, 0x72B0, 0x738A, 0x79B8, 0x808A, 0x961E, 0x4F0E,
+		0x4F18, 0x4F2C, 0x4EF5, 0x4F14, 0x4EF1, 0x4F00, 0x4EF7, 0x4F08, 0x4F1D, 0x4F02, 0x4F05,
+		0x4F22, 0x4F13, 0x4F04, 0x4EF4, 0x4F12, 0x51B1, 0x5213, 0x5209, 0x5210, 0x52A6, 0x5322,
+		0x531F, 0x534D, 0x538A, 0x5407, 0x56E1, 0x56DF, 0x572E, 0x572A, 0x5734, 0x593C, 0x5980,
+		0x597C, 0x5985, 0x597B, 0x597E, 0x5977, 0x597F, 0x5B56, 0x5C15, 0x5C25, 0x5C7C, 0x5C7A,
+		0x5C7B, 0x5C7E, 0x5DDF, 0x5E75, 0x5E84, 0x5F02, 0x5F1A, 0x5F74, 0x5FD5, 0x5FD4, 0x5FCF,
+		0x625C, 0x625E, 0x6264, 0x6261, 0x6266, 0x6262, 0x6259, 0x6260, 0x625A, 0x6265, 0x65EF,
+		0x65EE, 0x673E, 0x6739, 0x6738, 0x673B, 0x673A, 0x673F, 0x673C, 0x6733, 0x6C18, 0x6C46,
+		0x6C52, 0x6C5C, 0x6C4F, 0x6C4A, 0x6C54, 0x6C4B, 0x6C4C, 0x7071, 0x725E, 0x72B4, 0x72B5,
+		0x738E, 0x752A, 0x767F, 0x7A75, 0x7F51, 0x8278, 0x827C, 0x8280, 0x827D, 0x827F, 0x864D,
+		0x897E, 0x9099, 0x9097, 0x9098, 0x909B, 0x9094, 0x9622, 0x9624, 0x9620, 0x9623, 0x4F56,
+		0x4F3B, 0x4F62, 0x4F49, 0x4F53, 0x4F64, 0x4F3E, 0x4F67, 0x4F52, 0x4F5F, 0x4F41, 0x4F58,
+		0x4F2D, 0x4F33, 0x4F3F, 0x4F61, 0x518F, 0x51B9, 0x521C, 0x521E, 0x5221, 0x52AD, 0x52AE,
+		0x5309, 0x5363, 0x5372, 0x538E, 0x538F, 0x5430, 0x5437, 0x542A, 0x5454, 0x5445, 0x5419,
+		0x541C, 0x5425, 0x5418, 0x543D, 0x544F, 0x5441, 0x5428, 0x5424, 0x5447, 0x56EE, 0x56E7,
+		0x56E5, 0x5741, 0x5745, 0x574C, 0x5749, 0x574B, 0x5752, 0x5906, 0x5940, 0x59A6, 0x5998,
+		0x59A0, 0x5997, 0x598E, 0x59A2, 0x5990, 0x598F, 0x59A7, 0x59A1, 0x5B8E, 0x5B92, 0x5C28,
+		0x5C2A, 0x5C8D, 0x5C8F, 0x5C88, 0x5C8B, 0x5C89, 0x5C92, 0x5C8A, 0x5C86, 0x5C93, 0x5C95,
+		0x5DE0, 0x5E0A, 0x5E0E, 0x5E8B, 0x5E89, 0x5E8C, 0x5E88, 0x5E8D, 0x5F05, 0x5F1D, 0x5F78,
+		0x5F76, 0x5FD2, 0x5FD1, 0x5FD0, 0x5FED, 0x5FE8, 0x5FEE, 0x5FF3, 0x5FE1, 0x5FE4, 0x5FE3,
+		0x5FFA, 0x5FEF, 0x5FF7, 0x5FFB, 0x6000, 0x5FF4, 0x623A, 0x6283, 0x628C, 0x628E, 0x628F,
+		0x6294, 0x6287, 0x6271, 0x627B, 0x627A, 0x6270, 0x6281, 0x6288, 0x6277, 0x627D, 0x6272,
+		0x6274, 0x6537, 0x65F0, 0x65F4, 0x65F3, 0x65F2, 0x65F5, 0x6745, 0x6747, 0x6759, 0x6755,
+		0x674C, 0x6748, 0x675D, 0x674D, 0x675A, 0x674B, 0x6BD0, 0x6C19, 0x6C1A, 0x6C78, 0x6C67,
+		0x6C6B, 0x6C84, 0x6C8B, 0x6C8F, 0x6C71, 0x6C6F, 0x6C69, 0x6C9A, 0x6C6D, 0x6C87, 0x6C95,
+		0x6C9C, 0x6C66, 0x6C73, 0x6C65, 0x6C7B, 0x6C8E, 0x7074, 0x707A, 0x7263, 0x72BF, 0x72BD,
+		0x72C3, 0x72C6, 0x72C1, 0x72BA, 0x72C5, 0x7395, 0x7397, 0x7393, 0x7394, 0x7392, 0x753A,
+		0x7539, 0x7594, 0x7595, 0x7681, 0x793D, 0x8034, 0x8095, 0x8099, 0x8090, 0x8092, 0x809C,
+		0x8290, 0x828F, 0x8285, 0x828E, 0x8291, 0x8293, 0x828A, 0x8283, 0x8284, 0x8C78, 0x8FC9,
+		0x8FBF, 0x909F, 0x90A1, 0x90A5, 0x909E, 0x90A7, 0x90A0, 0x9630, 0x9628, 0x962F, 0x962D,
+		0x4E33, 0x4F98, 0x4F7C, 0x4F85, 0x4F7D, 0x4F80, 0x4F87, 0x4F76, 0x4F74, 0x4F89, 0x4F84,
+		0x4F77, 0x4F4C, 0x4F97, 0x4F6A, 0x4F9A, 0x4F79, 0x4F81, 0x4F78, 0x4F90, 0x4F9C, 0x4F94,
+		0x4F9E, 0x4F92, 0x4F82, 0x4F95, 0x4F6B, 0x4F6E, 0x519E, 0x51BC, 0x51BE, 0x5235, 0x5232,
+		0x5233, 0x5246, 0x5231, 0x52BC, 0x530A, 0x530B, 0x533C, 0x5392, 0x5394, 0x5487, 0x547F,
+		0x5481, 0x5491, 0x5482, 0x5488, 0x546B, 0x547A, 0x547E, 0x5465, 0x546C, 0x5474, 0x5466,
+		0x548D, 0x546F, 0x5461, 0x5460, 0x5498, 0x5463, 0x5467, 0x5464, 0x56F7, 0x56F9, 0x576F,
+		0x5772, 0x576D, 0x576B, 0x5771, 0x5770, 0x5776, 0x5780, 0x5775, 0x577B, 0x5773, 0x5774,
+		0x5762, 0x5768, 0x577D, 0x590C, 0x5945, 0x59B5, 0x59BA, 0x59CF, 0x59CE, 0x59B2, 0x59CC,
+		0x59C1, 0x59B6, 0x59BC, 0x59C3, 0x59D6, 0x59B1, 0x59BD, 0x59C0, 0x59C8, 0x59B4, 0x59C7,
+		0x5B62, 0x5B65, 0x5B93, 0x5B95, 0x5C44, 0x5C47, 0x5CAE, 0x5CA4, 0x5CA0, 0x5CB5, 0x5CAF,
+		0x5CA8, 0x5CAC, 0x5C9F, 0x5CA3, 0x5CAD, 0x5CA2, 0x5CAA, 0x5CA7, 0x5C9D, 0x5CA5, 0x5CB6,
+		0x5CB0, 0x5CA6, 0x5E17, 0x5E14, 0x5E19, 0x5F28, 0x5F22, 0x5F23, 0x5F24, 0x5F54, 0x5F82,
+		0x5F7E, 0x5F7D, 0x5FDE, 0x5FE5, 0x602D, 0x6026, 0x6019, 0x6032, 0x600B, 0x6034, 0x600A,
+		0x6017, 0x6033, 0x601A, 0x601E, 0x602C, 0x6022, 0x600D, 0x6010, 0x602E, 0x6013, 0x6011,
+		0x600C, 0x6009, 0x601C, 0x6214, 0x623D, 0x62AD, 0x62B4, 0x62D1, 0x62BE, 0x62AA, 0x62B6,
+		0x62CA, 0x62AE, 0x62B3, 0x62AF, 0x62BB, 0x62A9, 0x62B0, 0x62B8, 0x653D, 0x65A8, 0x65BB,
+		0x6609, 0x65FC, 0x6604, 0x6612, 0x6608, 0x65FB, 0x6603, 0x660B, 0x660D, 0x6605, 0x65FD,
+		0x6611, 0x6610, 0x66F6, 0x670A, 0x6785, 0x676C, 0x678E, 0x6792, 0x6776, 0x677B, 0x6798,
+		0x6786, 0x6784, 0x6774, 0x678D, 0x678C, 0x677A, 0x679F, 0x6791, 0x6799, 0x6783, 0x677D,
+		0x6781, 0x6778, 0x6779, 0x6794, 0x6B25, 0x6B80, 0x6B7E, 0x6BDE, 0x6C1D, 0x6C93, 0x6CEC,
+		0x6CEB, 0x6CEE, 0x6CD9, 0x6CB6, 0x6CD4, 0x6CAD, 0x6CE7, 0x6CB7, 0x6CD0, 0x6CC2, 0x6CBA,
+		0x6CC3, 0x6CC6, 0x6CED, 0x6CF2, 0x6CD2, 0x6CDD, 0x6CB4, 0x6C8A, 0x6C9D, 0x6C80, 0x6CDE,
+		0x6CC0, 0x6D30, 0x6CCD, 0x6CC7, 0x6CB0, 0x6CF9, 0x6CCF, 0x6CE9, 0x6CD1, 0x7094, 0x7098,
+		0x7085, 0x7093, 0x7086, 0x7084, 0x7091, 0x7096, 0x7082, 0x709A, 0x7083, 0x726A, 0x72D6,
+		0x72CB, 0x72D8, 0x72C9, 0x72DC, 0x72D2, 0x72D4, 0x72DA, 0x72CC, 0x72D1, 0x73A4, 0x73A1,
+		0x73AD, 0x73A6, 0x73A2, 0x73A0, 0x73AC, 0x739D, 0x74DD, 0x74E8, 0x753F, 0x7540, 0x753E,
+		0x758C, 0x7598, 0x76AF, 0x76F3, 0x76F1, 0x76F0, 0x76F5, 0x77F8, 0x77FC, 0x77F9, 0x77FB,
+		0x77FA, 0x77F7, 0x7942, 0x793F, 0x79C5, 0x7A78, 0x7A7B, 0x7AFB, 0x7C75, 0x7CFD, 0x8035,
+		0x808F, 0x80AE, 0x80A3, 0x80B8, 0x80B5, 0x80AD, 0x8220, 0x82A0, 0x82C0, 0x82AB, 0x829A,
+		0x8298, 0x829B, 0x82B5, 0x82A7, 0x82AE, 0x82BC, 0x829E, 0x82BA, 0x82B4, 0x82A8, 0x82A1,
+		0x82A9, 0x82C2, 0x82A4, 0x82C3, 0x82B6, 0x82A2, 0x8670, 0x866F, 0x866D, 0x866E, 0x8C56,
+		0x8FD2, 0x8FCB, 0x8FD3, 0x8FCD, 0x8FD6, 0x8FD5, 0x8FD7, 0x90B2, 0x90B4, 0x90AF, 0x90B3,
+		0x90B0, 0x9639, 0x963D, 0x963C, 0x963A, 0x9643, 0x4FCD, 0x4FC5, 0x4FD3, 0x4FB2, 0x4FC9,
+		0x4FCB, 0x4FC1, 0x4FD4, 0x4FDC, 0x4FD9, 0x4FBB, 0x4FB3, 0x4FDB, 0x4FC7, 0x4FD6, 0x4FBA,
+		0x4FC0, 0x4FB9, 0x4FEC, 0x5244, 0x5249, 0x52C0, 0x52C2, 0x533D, 0x537C, 0x5397, 0x5396,
+		0x5399, 0x5398, 0x54BA, 0x54A1, 0x54AD, 0x54A5, 0x54CF, 0x54C3, 0x830D, 0x54B7, 0x54AE,
+		0x54D6, 0x54B6, 0x54C5, 0x54C6, 0x54A0, 0x5470, 0x54BC, 0x54A2, 0x54BE, 0x5472, 0x54DE,
+		0x54B0, 0x57B5, 0x579E, 0x579F, 0x57A4, 0x578C, 0x5797, 0x579D, 0x579B, 0x5794, 0x5798,
+		0x578F, 0x5799, 0x57A5, 0x579A, 0x5795, 0x58F4, 0x590D, 0x5953, 0x59E1, 0x59DE, 0x59EE,
+		0x5A00, 0x59F1, 0x59DD, 0x59FA, 0x59FD, 0x59FC, 0x59F6, 0x59E4, 0x59F2, 0x59F7, 0x59DB,
+		0x59E9, 0x59F3, 0x59F5, 0x59E0, 0x59FE, 0x59F4, 0x59ED, 0x5BA8, 0x5C4C, 0x5CD0, 0x5CD8,
+		0x5CCC, 0x5CD7, 0x5CCB, 0x5CDB, 0x5CDE, 0x5CDA, 0x5CC9, 0x5CC7, 0x5CCA, 0x5CD6, 0x5CD3,
+		0x5CD4, 0x5CCF, 0x5CC8, 0x5CC6, 0x5CCE, 0x5CDF, 0x5CF8, 0x5DF9, 0x5E21, 0x5E22, 0x5E23,
+		0x5E20, 0x5E24, 0x5EB0, 0x5EA4, 0x5EA2, 0x5E9B, 0x5EA3, 0x5EA5, 0x5F07, 0x5F2E, 0x5F56,
+		0x5F86, 0x6037, 0x6039, 0x6054, 0x6072, 0x605E, 0x6045, 0x6053, 0x6047, 0x6049, 0x605B,
+		0x604C, 0x6040, 0x6042, 0x605F, 0x6024, 0x6044, 0x6058, 0x6066, 0x606E, 0x6242, 0x6243,
+		0x62CF, 0x630D, 0x630B, 0x62F5, 0x630E, 0x6303, 0x62EB, 0x62F9, 0x630F, 0x630C, 0x62F8,
+		0x62F6, 0x6300, 0x6313, 0x6314, 0x62FA, 0x6315, 0x62FB, 0x62F0, 0x6541, 0x6543, 0x65AA,
+		0x65BF, 0x6636, 0x6621, 0x6632, 0x6635, 0x661C, 0x6626, 0x6622, 0x6633, 0x662B, 0x663A,
+		0x661D, 0x6634, 0x6639, 0x662E, 0x670F, 0x6710, 0x67C1, 0x67F2, 0x67C8, 0x67BA, 0x67DC,
+		0x67BB, 0x67F8, 0x67D8, 0x67C0, 0x67B7, 0x67C5, 0x67EB, 0x67E4, 0x67DF, 0x67B5, 0x67CD,
+		0x67B3, 0x67F7, 0x67F6, 0x67EE, 0x67E3, 0x67C2, 0x67B9, 0x67CE, 0x67E7, 0x67F0, 0x67B2,
+		0x67FC, 0x67C6, 0x67ED, 0x67CC, 0x67AE, 0x67E6, 0x67DB, 0x67FA, 0x67C9, 0x67CA, 0x67C3,
+		0x67EA, 0x67CB, 0x6B28, 0x6B82, 0x6B84, 0x6BB6, 0x6BD6, 0x6BD8, 0x6BE0, 0x6C20, 0x6C21,
+		0x6D28, 0x6D34, 0x6D2D, 0x6D1F, 0x6D3C, 0x6D3F, 0x6D12, 0x6D0A, 0x6CDA, 0x6D33, 0x6D04,
+		0x6D19, 0x6D3A, 0x6D1A, 0x6D11, 0x6D00, 0x6D1D, 0x6D42, 0x6D01, 0x6D18, 0x6D37, 0x6D03,
+		0x6D0F, 0x6D40, 0x6D07, 0x6D20, 0x6D2C, 0x6D08, 0x6D22, 0x6D09, 0x6D10, 0x70B7, 0x709F,
+		0x70BE, 0x70B1, 0x70B0, 0x70A1, 0x70B4, 0x70B5, 0x70A9, 0x7241, 0x7249, 0x724A, 0x726C,
+		0x7270, 0x7273, 0x726E, 0x72CA, 0x72E4, 0x72E8, 0x72EB, 0x72DF, 0x72EA, 0x72E6, 0x72E3,
+		0x7385, 0x73CC, 0x73C2, 0x73C8, 0x73C5, 0x73B9, 0x73B6, 0x73B5, 0x73B4, 0x73EB, 0x73BF,
+		0x73C7, 0x73BE, 0x73C3, 0x73C6, 0x73B8, 0x73CB, 0x74EC, 0x74EE, 0x752E, 0x7547, 0x7548,
+		0x75A7, 0x75AA, 0x7679, 0x76C4, 0x7708, 0x7703, 0x7704, 0x7705, 0x770A, 0x76F7, 0x76FB,
+		0x76FA, 0x77E7, 0x77E8, 0x7806, 0x7811, 0x7812, 0x7805, 0x7810, 0x780F, 0x780E, 0x7809,
+		0x7803, 0x7813, 0x794A, 0x794C, 0x794B, 0x7945, 0x7944, 0x79D5, 0x79CD, 0x79CF, 0x79D6,
+		0x79CE, 0x7A80, 0x7A7E, 0x7AD1, 0x7B00, 0x7B01, 0x7C7A, 0x7C78, 0x7C79, 0x7C7F, 0x7C80,
+		0x7C81, 0x7D03, 0x7D08, 0x7D01, 0x7F58, 0x7F91, 0x7F8D, 0x7FBE, 0x8007, 0x800E, 0x800F,
+		0x8014, 0x8037, 0x80D8, 0x80C7, 0x80E0, 0x80D1, 0x80C8, 0x80C2, 0x80D0, 0x80C5, 0x80E3,
+		0x80D9, 0x80DC, 0x80CA, 0x80D5, 0x80C9, 0x80CF, 0x80D7, 0x80E6, 0x80CD, 0x81FF, 0x8221,
+		0x8294, 0x82D9, 0x82FE, 0x82F9, 0x8307, 0x82E8, 0x8300, 0x82D5, 0x833A, 0x82EB, 0x82D6,
+		0x82F4, 0x82EC, 0x82E1, 0x82F2, 0x82F5, 0x830C, 0x82FB, 0x82F6, 0x82F0, 0x82EA, 0x82E4,
+		0x82E0, 0x82FA, 0x82F3, 0x82ED, 0x8677, 0x8674, 0x867C, 0x8673, 0x8841, 0x884E, 0x8867,
+		0x886A, 0x8869, 0x89D3, 0x8A04, 0x8A07, 0x8D72, 0x8FE3, 0x8FE1, 0x8FEE, 0x8FE0, 0x90F1,
+		0x90BD, 0x90BF, 0x90D5, 0x90C5, 0x90BE, 0x90C7, 0x90CB, 0x90C8, 0x91D4, 0x91D3, 0x9654,
+		0x964F, 0x9651, 0x9653, 0x964A, 0x964E, 0x501E, 0x5005, 0x5007, 0x5013, 0x5022, 0x5030,
+		0x501B, 0x4FF5, 0x4FF4, 0x5033, 0x5037, 0x502C, 0x4FF6, 0x4FF7, 0x5017, 0x501C, 0x5020,
+		0x5027, 0x5035, 0x502F, 0x5031, 0x500E, 0x515A, 0x5194, 0x5193, 0x51CA, 0x51C4, 0x51C5,
+		0x51C8, 0x51CE, 0x5261, 0x525A, 0x5252, 0x525E, 0x525F, 0x5255, 0x5262, 0x52CD, 0x530E,
+		0x539E, 0x5526, 0x54E2, 0x5517, 0x5512, 0x54E7, 0x54F3, 0x54E4, 0x551A, 0x54FF, 0x5504,
+		0x5508, 0x54EB, 0x5511, 0x5505, 0x54F1, 0x550A, 0x54FB, 0x54F7, 0x54F8, 0x54E0, 0x550E,
+		0x5503, 0x550B, 0x5701, 0x5702, 0x57CC, 0x5832, 0x57D5, 0x57D2, 0x57BA, 0x57C6, 0x57BD,
+		0x57BC, 0x57B8, 0x57B6, 0x57BF, 0x57C7, 0x57D0, 0x57B9, 0x57C1, 0x590E, 0x594A, 0x5A19,
+		0x5A16, 0x5A2D, 0x5A2E, 0x5A15, 0x5A0F, 0x5A17, 0x5A0A, 0x5A1E, 0x5A33, 0x5B6C, 0x5BA7,
+		0x5BAD, 0x5BAC, 0x5C03, 0x5C56, 0x5C54, 0x5CEC, 0x5CFF, 0x5CEE, 0x5CF1, 0x5CF7, 0x5D00,
+		0x5CF9, 0x5E29, 0x5E28, 0x5EA8, 0x5EAE, 0x5EAA, 0x5EAC, 0x5F33, 0x5F30, 0x5F67, 0x605D,
+		0x605A, 0x6067, 0x6041, 0x60A2, 0x6088, 0x6080, 0x6092, 0x6081, 0x609D, 0x6083, 0x6095,
+		0x609B, 0x6097, 0x6087, 0x609C, 0x608E, 0x6219, 0x6246, 0x62F2, 0x6310, 0x6356, 0x632C,
+		0x6344, 0x6345, 0x6336, 0x6343, 0x63E4, 0x6339, 0x634B, 0x634A, 0x633C, 0x6329, 0x6341,
+		0x6334, 0x6358, 0x6354, 0x6359, 0x632D, 0x6347, 0x6333, 0x635A, 0x6351, 0x6338, 0x6357,
+		0x6340, 0x6348, 0x654A, 0x6546, 0x65C6, 0x65C3, 0x65C4, 0x65C2, 0x664A, 0x665F, 0x6647,
+		0x6651, 0x6712, 0x6713, 0x681F, 0x681A, 0x6849, 0x6832, 0x6833, 0x683B, 0x684B, 0x684F,
+		0x6816, 0x6831, 0x681C, 0x6835, 0x682B, 0x682D, 0x682F, 0x684E, 0x6844, 0x6834, 0x681D,
+		0x6812, 0x6814, 0x6826, 0x6828, 0x682E, 0x684D, 0x683A, 0x6825, 0x6820, 0x6B2C, 0x6B2F,
+		0x6B2D, 0x6B31, 0x6B34, 0x6B6D, 0x8082, 0x6B88, 0x6BE6, 0x6BE4, 0x6BE8, 0x6BE3, 0x6BE2,
+		0x6BE7, 0x6C25, 0x6D7A, 0x6D63, 0x6D64, 0x6D76, 0x6D0D, 0x6D61, 0x6D92, 0x6D58, 0x6D62,
+		0x6D6D, 0x6D6F, 0x6D91, 0x6D8D, 0x6DEF, 0x6D7F, 0x6D86, 0x6D5E, 0x6D67, 0x6D60, 0x6D97,
+		0x6D70, 0x6D7C, 0x6D5F, 0x6D82, 0x6D98, 0x6D2F, 0x6D68, 0x6D8B, 0x6D7E, 0x6D80, 0x6D84,
+		0x6D16, 0x6D83, 0x6D7B, 0x6D7D, 0x6D75, 0x6D90, 0x70DC, 0x70D3, 0x70D1, 0x70DD, 0x70CB,
+		0x7F39, 0x70E2, 0x70D7, 0x70D2, 0x70DE, 0x70E0, 0x70D4, 0x70CD, 0x70C5, 0x70C6, 0x70C7,
+		0x70DA, 0x70CE, 0x70E1, 0x7242, 0x7278, 0x7277, 0x7276, 0x7300, 0x72FA, 0x72F4, 0x72FE,
+		0x72F6, 0x72F3, 0x72FB, 0x7301, 0x73D3, 0x73D9, 0x73E5, 0x73D6, 0x73BC, 0x73E7, 0x73E3,
+		0x73E9, 0x73DC, 0x73D2, 0x73DB, 0x73D4, 0x73DD, 0x73DA, 0x73D7, 0x73D8, 0x73E8, 0x74DE,
+		0x74DF, 0x74F4, 0x74F5, 0x7521, 0x755B, 0x755F, 0x75B0, 0x75C1, 0x75BB, 0x75C4, 0x75C0,
+		0x75BF, 0x75B6, 0x75BA, 0x768A, 0x76C9, 0x771D, 0x771B, 0x7710, 0x7713, 0x7712, 0x7723,
+		0x7711, 0x7715, 0x7719, 0x771A, 0x7722, 0x7727, 0x7823, 0x782C, 0x7822, 0x7835, 0x782F,
+		0x7828, 0x782E, 0x782B, 0x7821, 0x7829, 0x7833, 0x782A, 0x7831, 0x7954, 0x795B, 0x794F,
+		0x795C, 0x7953, 0x7952, 0x7951, 0x79EB, 0x79EC, 0x79E0, 0x79EE, 0x79ED, 0x79EA, 0x79DC,
+		0x79DE, 0x79DD, 0x7A86, 0x7A89, 0x7A85, 0x7A8B, 0x7A8C, 0x7A8A, 0x7A87, 0x7AD8, 0x7B10,
+		0x7B04, 0x7B13, 0x7B05, 0x7B0F, 0x7B08, 0x7B0A, 0x7B0E, 0x7B09, 0x7B12, 0x7C84, 0x7C91,
+		0x7C8A, 0x7C8C, 0x7C88, 0x7C8D, 0x7C85, 0x7D1E, 0x7D1D, 0x7D11, 0x7D0E, 0x7D18, 0x7D16,
+		0x7D13, 0x7D1F, 0x7D12, 0x7D0F, 0x7D0C, 0x7F5C, 0x7F61, 0x7F5E, 0x7F60, 0x7F5D, 0x7F5B,
+		0x7F96, 0x7F92, 0x7FC3, 0x7FC2, 0x7FC0, 0x8016, 0x803E, 0x8039, 0x80FA, 0x80F2, 0x80F9,
+		0x80F5, 0x8101, 0x80FB, 0x8100, 0x8201, 0x822F, 0x8225, 0x8333, 0x832D, 0x8344, 0x8319,
+		0x8351, 0x8325, 0x8356, 0x833F, 0x8341, 0x8326, 0x831C, 0x8322, 0x8342, 0x834E, 0x831B,
+		0x832A, 0x8308, 0x833C, 0x834D, 0x8316, 0x8324, 0x8320, 0x8337, 0x832F, 0x8329, 0x8347,
+		0x8345, 0x834C, 0x8353, 0x831E, 0x832C, 0x834B, 0x8327, 0x8348, 0x8653, 0x8652, 0x86A2,
+		0x86A8, 0x8696, 0x868D, 0x8691, 0x869E, 0x8687, 0x8697, 0x8686, 0x868B, 0x869A, 0x8685,
+		0x86A5, 0x8699, 0x86A1, 0x86A7, 0x8695, 0x8698, 0x868E, 0x869D, 0x8690, 0x8694, 0x8843,
+		0x8844, 0x886D, 0x8875, 0x8876, 0x8872, 0x8880, 0x8871, 0x887F, 0x886F, 0x8883, 0x887E,
+		0x8874, 0x887C, 0x8A12, 0x8C47, 0x8C57, 0x8C7B, 0x8CA4, 0x8CA3, 0x8D76, 0x8D78, 0x8DB5,
+		0x8DB7, 0x8DB6, 0x8ED1, 0x8ED3, 0x8FFE, 0x8FF5, 0x9002, 0x8FFF, 0x8FFB, 0x9004, 0x8FFC,
+		0x8FF6, 0x90D6, 0x90E0, 0x90D9, 0x90DA, 0x90E3, 0x90DF, 0x90E5, 0x90D8, 0x90DB, 0x90D7,
+		0x90DC, 0x90E4, 0x9150, 0x914E, 0x914F, 0x91D5, 0x91E2, 0x91DA, 0x965C, 0x965F, 0x96BC,
+		0x98E3, 0x9ADF, 0x9B2F, 0x4E7F, 0x5070, 0x506A, 0x5061, 0x505E, 0x5060, 0x5053, 0x504B,
+		0x505D, 0x5072, 0x5048, 0x504D, 0x5041, 0x505B, 0x504A, 0x5062, 0x5015, 0x5045, 0x505F,
+		0x5069, 0x506B, 0x5063, 0x5064, 0x5046, 0x5040, 0x506E, 0x5073, 0x5057, 0x5051, 0x51D0,
+		0x526B, 0x526D, 0x526C, 0x526E, 0x52D6, 0x52D3, 0x532D, 0x539C, 0x5575, 0x5576, 0x553C,
+		0x554D, 0x5550, 0x5534, 0x552A, 0x5551, 0x5562, 0x5536, 0x5535, 0x5530, 0x5552, 0x5545,
+		0x550C, 0x5532, 0x5565, 0x554E, 0x5539, 0x5548, 0x552D, 0x553B, 0x5540, 0x554B, 0x570A,
+		0x5707, 0x57FB, 0x5814, 0x57E2, 0x57F6, 0x57DC, 0x57F4, 0x5800, 0x57ED, 0x57FD, 0x5808,
+		0x57F8, 0x580B, 0x57F3, 0x57CF, 0x5807, 0x57EE, 0x57E3, 0x57F2, 0x57E5, 0x57EC, 0x57E1,
+		0x580E, 0x57FC, 0x5810, 0x57E7, 0x5801, 0x580C, 0x57F1, 0x57E9, 0x57F0, 0x580D, 0x5804,
+		0x595C, 0x5A60, 0x5A58, 0x5A55, 0x5A67, 0x5A5E, 0x5A38, 0x5A35, 0x5A6D, 0x5A50, 0x5A5F,
+		0x5A65, 0x5A6C, 0x5A53, 0x5A64, 0x5A57, 0x5A43, 0x5A5D, 0x5A52, 0x5A44, 0x5A5B, 0x5A48,
+		0x5A8E, 0x5A3E, 0x5A4D, 0x5A39, 0x5A4C, 0x5A70, 0x5A69, 0x5A47, 0x5A51, 0x5A56, 0x5A42,
+		0x5A5C, 0x5B72, 0x5B6E, 0x5BC1, 0x5BC0, 0x5C59, 0x5D1E, 0x5D0B, 0x5D1D, 0x5D1A, 0x5D20,
+		0x5D0C, 0x5D28, 0x5D0D, 0x5D26, 0x5D25, 0x5D0F, 0x5D30, 0x5D12, 0x5D23, 0x5D1F, 0x5D2E,
+		0x5E3E, 0x5E34, 0x5EB1, 0x5EB4, 0x5EB9, 0x5EB2, 0x5EB3, 0x5F36, 0x5F38, 0x5F9B, 0x5F96,
+		0x5F9F, 0x608A, 0x6090, 0x6086, 0x60BE, 0x60B0, 0x60BA, 0x60D3, 0x60D4, 0x60CF, 0x60E4,
+		0x60D9, 0x60DD, 0x60C8, 0x60B1, 0x60DB, 0x60B7, 0x60CA, 0x60BF, 0x60C3, 0x60CD, 0x60C0,
+		0x6332, 0x6365, 0x638A, 0x6382, 0x637D, 0x63BD, 0x639E, 0x63AD, 0x639D, 0x6397, 0x63AB,
+		0x638E, 0x636F, 0x6387, 0x6390, 0x636E, 0x63AF, 0x6375, 0x639C, 0x636D, 0x63AE, 0x637C,
+		0x63A4, 0x633B, 0x639F, 0x6378, 0x6385, 0x6381, 0x6391, 0x638D, 0x6370, 0x6553, 0x65CD,
+		0x6665, 0x6661, 0x665B, 0x6659, 0x665C, 0x6662, 0x6718, 0x6879, 0x6887, 0x6890, 0x689C,
+		0x686D, 0x686E, 0x68AE, 0x68AB, 0x6956, 0x686F, 0x68A3, 0x68AC, 0x68A9, 0x6875, 0x6874,
+		0x68B2, 0x688F, 0x6877, 0x6892, 0x687C, 0x686B, 0x6872, 0x68AA, 0x6880, 0x6871, 0x687E,
+		0x689B, 0x6896, 0x688B, 0x68A0, 0x6889, 0x68A4, 0x6878, 0x687B, 0x6891, 0x688C, 0x688A,
+		0x687D, 0x6B36, 0x6B33, 0x6B37, 0x6B38, 0x6B91, 0x6B8F, 0x6B8D, 0x6B8E, 0x6B8C, 0x6C2A,
+		0x6DC0, 0x6DAB, 0x6DB4, 0x6DB3, 0x6E74, 0x6DAC, 0x6DE9, 0x6DE2, 0x6DB7, 0x6DF6, 0x6DD4,
+		0x6E00, 0x6DC8, 0x6DE0, 0x6DDF, 0x6DD6, 0x6DBE, 0x6DE5, 0x6DDC, 0x6DDD, 0x6DDB, 0x6DF4,
+		0x6DCA, 0x6DBD, 0x6DED, 0x6DF0, 0x6DBA, 0x6DD5, 0x6DC2, 0x6DCF, 0x6DC9, 0x6DD0, 0x6DF2,
+		0x6DD3, 0x6DFD, 0x6DD7, 0x6DCD, 0x6DE3, 0x6DBB, 0x70FA, 0x710D, 0x70F7, 0x7117, 0x70F4,
+		0x710C, 0x70F0, 0x7104, 0x70F3, 0x7110, 0x70FC, 0x70FF, 0x7106, 0x7113, 0x7100, 0x70F8,
+		0x70F6, 0x710B, 0x7102, 0x710E, 0x727E, 0x727B, 0x727C, 0x727F, 0x731D, 0x7317, 0x7307,
+		0x7311, 0x7318, 0x730A, 0x7308, 0x72FF, 0x730F, 0x731E, 0x7388, 0x73F6, 0x73F8, 0x73F5,
+		0x7404, 0x7401, 0x73FD, 0x7407, 0x7400, 0x73FA, 0x73FC, 0x73FF, 0x740C, 0x740B, 0x73F4,
+		0x7408, 0x7564, 0x7563, 0x75CE, 0x75D2, 0x75CF, 0x75CB, 0x75CC, 0x75D1, 0x75D0, 0x768F,
+		0x7689, 0x76D3, 0x7739, 0x772F, 0x772D, 0x7731, 0x7732, 0x7734, 0x7733, 0x773D, 0x7725,
+		0x773B, 0x7735, 0x7848, 0x7852, 0x7849, 0x784D, 0x784A, 0x784C, 0x7826, 0x7845, 0x7850,
+		0x7964, 0x7967, 0x7969, 0x796A, 0x7963, 0x796B, 0x7961, 0x79BB, 0x79FA, 0x79F8, 0x79F6,
+		0x79F7, 0x7A8F, 0x7A94, 0x7A90, 0x7B35, 0x7B47, 0x7B34, 0x7B25, 0x7B30, 0x7B22, 0x7B24,
+		0x7B33, 0x7B18, 0x7B2A, 0x7B1D, 0x7B31, 0x7B2B, 0x7B2D, 0x7B2F, 0x7B32, 0x7B38, 0x7B1A,
+		0x7B23, 0x7C94, 0x7C98, 0x7C96, 0x7CA3, 0x7D35, 0x7D3D, 0x7D38, 0x7D36, 0x7D3A, 0x7D45,
+		0x7D2C, 0x7D29, 0x7D41, 0x7D47, 0x7D3E, 0x7D3F, 0x7D4A, 0x7D3B, 0x7D28, 0x7F63, 0x7F95,
+		0x7F9C, 0x7F9D, 0x7F9B, 0x7FCA, 0x7FCB, 0x7FCD, 0x7FD0, 0x7FD1, 0x7FC7, 0x7FCF, 0x7FC9,
+		0x801F, 0x801E, 0x801B, 0x8047, 0x8043, 0x8048, 0x8118, 0x8125, 0x8119, 0x811B, 0x812D,
+		0x811F, 0x812C, 0x811E, 0x8121, 0x8115, 0x8127, 0x811D, 0x8122, 0x8211, 0x8238, 0x8233,
+		0x823A, 0x8234, 0x8232, 0x8274, 0x8390, 0x83A3, 0x83A8, 0x838D, 0x837A, 0x8373, 0x83A4,
+		0x8374, 0x838F, 0x8381, 0x8395, 0x8399, 0x8375, 0x8394, 0x83A9, 0x837D, 0x8383, 0x838C,
+		0x839D, 0x839B, 0x83AA, 0x838B, 0x837E, 0x83A5, 0x83AF, 0x8388, 0x8397, 0x83B0, 0x837F,
+		0x83A6, 0x8387, 0x83AE, 0x8376, 0x839A, 0x8659, 0x8656, 0x86BF, 0x86B7, 0x86C2, 0x86C1,
+		0x86C5, 0x86BA, 0x86B0, 0x86C8, 0x86B9, 0x86B3, 0x86B8, 0x86CC, 0x86B4, 0x86BB, 0x86BC,
+		0x86C3, 0x86BD, 0x86BE, 0x8852, 0x8889, 0x8895, 0x88A8, 0x88A2, 0x88AA, 0x889A, 0x8891,
+		0x88A1, 0x889F, 0x8898, 0x88A7, 0x8899, 0x889B, 0x8897, 0x88A4, 0x88AC, 0x888C, 0x8893,
+		0x888E, 0x8982, 0x89D6, 0x89D9, 0x89D5, 0x8A30, 0x8A27, 0x8A2C, 0x8A1E, 0x8C39, 0x8C3B,
+		0x8C5C, 0x8C5D, 0x8C7D, 0x8CA5, 0x8D7D, 0x8D7B, 0x8D79, 0x8DBC, 0x8DC2, 0x8DB9, 0x8DBF,
+		0x8DC1, 0x8ED8, 0x8EDE, 0x8EDD, 0x8EDC, 0x8ED7, 0x8EE0, 0x8EE1, 0x9024, 0x900B, 0x9011,
+		0x901C, 0x900C, 0x9021, 0x90EF, 0x90EA, 0x90F0, 0x90F4, 0x90F2, 0x90F3, 0x90D4, 0x90EB,
+		0x90EC, 0x90E9, 0x9156, 0x9158, 0x915A, 0x9153, 0x9155, 0x91EC, 0x91F4, 0x91F1, 0x91F3,
+		0x91F8, 0x91E4, 0x91F9, 0x91EA, 0x91EB, 0x91F7, 0x91E8, 0x91EE, 0x957A, 0x9586, 0x9588,
+		0x967C, 0x966D, 0x966B, 0x9671, 0x966F, 0x96BF, 0x976A, 0x9804, 0x98E5, 0x9997, 0x509B,
+		0x5095, 0x5094, 0x509E, 0x508B, 0x50A3, 0x5083, 0x508C, 0x508E, 0x509D, 0x5068, 0x509C,
+		0x5092, 0x5082, 0x5087, 0x515F, 0x51D4, 0x5312, 0x5311, 0x53A4, 0x53A7, 0x5591, 0x55A8,
+		0x55A5, 0x55AD, 0x5577, 0x5645, 0x55A2, 0x5593, 0x5588, 0x558F, 0x55B5, 0x5581, 0x55A3,
+		0x5592, 0x55A4, 0x557D, 0x558C, 0x55A6, 0x557F, 0x5595, 0x55A1, 0x558E, 0x570C, 0x5829,
+		0x5837, 0x5819, 0x581E, 0x5827, 0x5823, 0x5828, 0x57F5, 0x5848, 0x5825, 0x581C, 0x581B,
+		0x5833, 0x583F, 0x5836, 0x582E, 0x5839, 0x5838, 0x582D, 0x582C, 0x583B, 0x5961, 0x5AAF,
+		0x5A94, 0x5A9F, 0x5A7A, 0x5AA2, 0x5A9E, 0x5A78, 0x5AA6, 0x5A7C, 0x5AA5, 0x5AAC, 0x5A95,
+		0x5AAE, 0x5A37, 0x5A84, 0x5A8A, 0x5A97, 0x5A83, 0x5A8B, 0x5AA9, 0x5A7B, 0x5A7D, 0x5A8C,
+		0x5A9C, 0x5A8F, 0x5A93, 0x5A9D, 0x5BEA, 0x5BCD, 0x5BCB, 0x5BD4, 0x5BD1, 0x5BCA, 0x5BCE,
+		0x5C0C, 0x5C30, 0x5D37, 0x5D43, 0x5D6B, 0x5D41, 0x5D4B, 0x5D3F, 0x5D35, 0x5D51, 0x5D4E,
+		0x5D55, 0x5D33, 0x5D3A, 0x5D52, 0x5D3D, 0x5D31, 0x5D59, 0x5D42, 0x5D39, 0x5D49, 0x5D38,
+		0x5D3C, 0x5D32, 0x5D36, 0x5D40, 0x5D45, 0x5E44, 0x5E41, 0x5F58, 0x5FA6, 0x5FA5, 0x5FAB,
+		0x60C9, 0x60B9, 0x60CC, 0x60E2, 0x60CE, 0x60C4, 0x6114, 0x60F2, 0x610A, 0x6116, 0x6105,
+		0x60F5, 0x6113, 0x60F8, 0x60FC, 0x60FE, 0x60C1, 0x6103, 0x6118, 0x611D, 0x6110, 0x60FF,
+		0x6104, 0x610B, 0x624A, 0x6394, 0x63B1, 0x63B0, 0x63CE, 0x63E5, 0x63E8, 0x63EF, 0x63C3,
+		0x649D, 0x63F3, 0x63CA, 0x63E0, 0x63F6, 0x63D5, 0x63F2, 0x63F5, 0x6461, 0x63DF, 0x63BE,
+		0x63DD, 0x63DC, 0x63C4, 0x63D8, 0x63D3, 0x63C2, 0x63C7, 0x63CC, 0x63CB, 0x63C8, 0x63F0,
+		0x63D7, 0x63D9, 0x6532, 0x6567, 0x656A, 0x6564, 0x655C, 0x6568, 0x6565, 0x658C, 0x659D,
+		0x659E, 0x65AE, 0x65D0, 0x65D2, 0x667C, 0x666C, 0x667B, 0x6680, 0x6671, 0x6679, 0x666A,
+		0x6672, 0x6701, 0x690C, 0x68D3, 0x6904, 0x68DC, 0x692A, 0x68EC, 0x68EA, 0x68F1, 0x690F,
+		0x68D6, 0x68F7, 0x68EB, 0x68E4, 0x68F6, 0x6913, 0x6910, 0x68F3, 0x68E1, 0x6907, 0x68CC,
+		0x6908, 0x6970, 0x68B4, 0x6911, 0x68EF, 0x68C6, 0x6914, 0x68F8, 0x68D0, 0x68FD, 0x68FC,
+		0x68E8, 0x690B, 0x690A, 0x6917, 0x68CE, 0x68C8, 0x68DD, 0x68DE, 0x68E6, 0x68F4, 0x68D1,
+		0x6906, 0x68D4, 0x68E9, 0x6915, 0x6925, 0x68C7, 0x6B39, 0x6B3B, 0x6B3F, 0x6B3C, 0x6B94,
+		0x6B97, 0x6B99, 0x6B95, 0x6BBD, 0x6BF0, 0x6BF2, 0x6BF3, 0x6C30, 0x6DFC, 0x6E46, 0x6E47,
+		0x6E1F, 0x6E49, 0x6E88, 0x6E3C, 0x6E3D, 0x6E45, 0x6E62, 0x6E2B, 0x6E3F, 0x6E41, 0x6E5D,
+		0x6E73, 0x6E1C, 0x6E33, 0x6E4B, 0x6E40, 0x6E51, 0x6E3B, 0x6E03, 0x6E2E, 0x6E5E, 0x6E68,
+		0x6E5C, 0x6E61, 0x6E31, 0x6E28, 0x6E60, 0x6E71, 0x6E6B, 0x6E39, 0x6E22, 0x6E30, 0x6E53,
+		0x6E65, 0x6E27, 0x6E78, 0x6E64, 0x6E77, 0x6E55, 0x6E79, 0x6E52, 0x6E66, 0x6E35, 0x6E36,
+		0x6E5A, 0x7120, 0x711E, 0x712F, 0x70FB, 0x712E, 0x7131, 0x7123, 0x7125, 0x7122, 0x7132,
+		0x711F, 0x7128, 0x713A, 0x711B, 0x724B, 0x725A, 0x7288, 0x7289, 0x7286, 0x7285, 0x728B,
+		0x7312, 0x730B, 0x7330, 0x7322, 0x7331, 0x7333, 0x7327, 0x7332, 0x732D, 0x7326, 0x7323,
+		0x7335, 0x730C, 0x742E, 0x742C, 0x7430, 0x742B, 0x7416, 0x741A, 0x7421, 0x742D, 0x7431,
+		0x7424, 0x7423, 0x741D, 0x7429, 0x7420, 0x7432, 0x74FB, 0x752F, 0x756F, 0x756C, 0x75E7,
+		0x75DA, 0x75E1, 0x75E6, 0x75DD, 0x75DF, 0x75E4, 0x75D7, 0x7695, 0x7692, 0x76DA, 0x7746,
+		0x7747, 0x7744, 0x774D, 0x7745, 0x774A, 0x774E, 0x774B, 0x774C, 0x77DE, 0x77EC, 0x7860,
+		0x7864, 0x7865, 0x785C, 0x786D, 0x7871, 0x786A, 0x786E, 0x7870, 0x7869, 0x7868, 0x785E,
+		0x7862, 0x7974, 0x7973, 0x7972, 0x7970, 0x7A02, 0x7A0A, 0x7A03, 0x7A0C, 0x7A04, 0x7A99,
+		0x7AE6, 0x7AE4, 0x7B4A, 0x7B3B, 0x7B44, 0x7B48, 0x7B4C, 0x7B4E, 0x7B40, 0x7B58, 0x7B45,
+		0x7CA2, 0x7C9E, 0x7CA8, 0x7CA1, 0x7D58, 0x7D6F, 0x7D63, 0x7D53, 0x7D56, 0x7D67, 0x7D6A,
+		0x7D4F, 0x7D6D, 0x7D5C, 0x7D6B, 0x7D52, 0x7D54, 0x7D69, 0x7D51, 0x7D5F, 0x7D4E, 0x7F3E,
+		0x7F3F, 0x7F65, 0x7F66, 0x7FA2, 0x7FA0, 0x7FA1, 0x7FD7, 0x8051, 0x804F, 0x8050, 0x80FE,
+		0x80D4, 0x8143, 0x814A, 0x8152, 0x814F, 0x8147, 0x813D, 0x814D, 0x813A, 0x81E6, 0x81EE,
+		0x81F7, 0x81F8, 0x81F9, 0x8204, 0x823C, 0x823D, 0x823F, 0x8275, 0x833B, 0x83CF, 0x83F9,
+		0x8423, 0x83C0, 0x83E8, 0x8412, 0x83E7, 0x83E4, 0x83FC, 0x83F6, 0x8410, 0x83C6, 0x83C8,
+		0x83EB, 0x83E3, 0x83BF, 0x8401, 0x83DD, 0x83E5, 0x83D8, 0x83FF, 0x83E1, 0x83CB, 0x83CE,
+		0x83D6, 0x83F5, 0x83C9, 0x8409, 0x840F, 0x83DE, 0x8411, 0x8406, 0x83C2, 0x83F3, 0x83D5,
+		0x83FA, 0x83C7, 0x83D1, 0x83EA, 0x8413, 0x83C3, 0x83EC, 0x83EE, 0x83C4, 0x83FB, 0x83D7,
+		0x83E2, 0x841B, 0x83DB, 0x83FE, 0x86D8, 0x86E2, 0x86E6, 0x86D3, 0x86E3, 0x86DA, 0x86EA,
+		0x86DD, 0x86EB, 0x86DC, 0x86EC, 0x86E9, 0x86D7, 0x86E8, 0x86D1, 0x8848, 0x8856, 0x8855,
+		0x88BA, 0x88D7, 0x88B9, 0x88B8, 0x88C0, 0x88BE, 0x88B6, 0x88BC, 0x88B7, 0x88BD, 0x88B2,
+		0x8901, 0x88C9, 0x8995, 0x8998, 0x8997, 0x89DD, 0x89DA, 0x89DB, 0x8A4E, 0x8A4D, 0x8A39,
+		0x8A59, 0x8A40, 0x8A57, 0x8A58, 0x8A44, 0x8A45, 0x8A52, 0x8A48, 0x8A51, 0x8A4A, 0x8A4C,
+		0x8A4F, 0x8C5F, 0x8C81, 0x8C80, 0x8CBA, 0x8CBE, 0x8CB0, 0x8CB9, 0x8CB5, 0x8D84, 0x8D80,
+		0x8D89, 0x8DD8, 0x8DD3, 0x8DCD, 0x8DC7, 0x8DD6, 0x8DDC, 0x8DCF, 0x8DD5, 0x8DD9, 0x8DC8,
+		0x8DD7, 0x8DC5, 0x8EEF, 0x8EF7, 0x8EFA, 0x8EF9, 0x8EE6, 0x8EEE, 0x8EE5, 0x8EF5, 0x8EE7,
+		0x8EE8, 0x8EF6, 0x8EEB, 0x8EF1, 0x8EEC, 0x8EF4, 0x8EE9, 0x902D, 0x9034, 0x902F, 0x9106,
+		0x912C, 0x9104, 0x90FF, 0x90FC, 0x9108, 0x90F9, 0x90FB, 0x9101, 0x9100, 0x9107, 0x9105,
+		0x9103, 0x9161, 0x9164, 0x915F, 0x9162, 0x9160, 0x9201, 0x920A, 0x9225, 0x9203, 0x921A,
+		0x9226, 0x920F, 0x920C, 0x9200, 0x9212, 0x91FF, 0x91FD, 0x9206, 0x9204, 0x9227, 0x9202,
+		0x921C, 0x9224, 0x9219, 0x9217, 0x9205, 0x9216, 0x957B, 0x958D, 0x958C, 0x9590, 0x9687,
+		0x967E, 0x9688, 0x9689, 0x9683, 0x9680, 0x96C2, 0x96C8, 0x96C3, 0x96F1, 0x96F0, 0x976C,
+		0x9770, 0x976E, 0x9807, 0x98A9, 0x98EB, 0x9CE6, 0x9EF9, 0x4E83, 0x4E84, 0x4EB6, 0x50BD,
+		0x50BF, 0x50C6, 0x50AE, 0x50C4, 0x50CA, 0x50B4, 0x50C8, 0x50C2, 0x50B0, 0x50C1, 0x50BA,
+		0x50B1, 0x50CB, 0x50C9, 0x50B6, 0x50B8, 0x51D7, 0x527A, 0x5278, 0x527B, 0x527C, 0x55C3,
+		0x55DB, 0x55CC, 0x55D0, 0x55CB, 0x55CA, 0x55DD, 0x55C0, 0x55D4, 0x55C4, 0x55E9, 0x55BF,
+		0x55D2, 0x558D, 0x55CF, 0x55D5, 0x55E2, 0x55D6, 0x55C8, 0x55F2, 0x55CD, 0x55D9, 0x55C2,
+		0x5714, 0x5853, 0x5868, 0x5864, 0x584F, 0x584D, 0x5849, 0x586F, 0x5855, 0x584E, 0x585D,
+		0x5859, 0x5865, 0x585B, 0x583D, 0x5863, 0x5871, 0x58FC, 0x5AC7, 0x5AC4, 0x5ACB, 0x5ABA,
+		0x5AB8, 0x5AB1, 0x5AB5, 0x5AB0, 0x5ABF, 0x5AC8, 0x5ABB, 0x5AC6, 0x5AB7, 0x5AC0, 0x5ACA,
+		0x5AB4, 0x5AB6, 0x5ACD, 0x5AB9, 0x5A90, 0x5BD6, 0x5BD8, 0x5BD9, 0x5C1F, 0x5C33, 0x5D71,
+		0x5D63, 0x5D4A, 0x5D65, 0x5D72, 0x5D6C, 0x5D5E, 0x5D68, 0x5D67, 0x5D62, 0x5DF0, 0x5E4F,
+		0x5E4E, 0x5E4A, 0x5E4D, 0x5E4B, 0x5EC5, 0x5ECC, 0x5EC6, 0x5ECB, 0x5EC7, 0x5F40, 0x5FAF,
+		0x5FAD, 0x60F7, 0x6149, 0x614A, 0x612B, 0x6145, 0x6136, 0x6132, 0x612E, 0x6146, 0x612F,
+		0x614F, 0x6129, 0x6140, 0x6220, 0x9168, 0x6223, 0x6225, 0x6224, 0x63C5, 0x63F1, 0x63EB,
+		0x6410, 0x6412, 0x6409, 0x6420, 0x6424, 0x6433, 0x6443, 0x641F, 0x6415, 0x6418, 0x6439,
+		0x6437, 0x6422, 0x6423, 0x640C, 0x6426, 0x6430, 0x6428, 0x6441, 0x6435, 0x642F, 0x640A,
+		0x641A, 0x6440, 0x6425, 0x6427, 0x640B, 0x63E7, 0x641B, 0x642E, 0x6421, 0x640E, 0x656F,
+		0x6592, 0x65D3, 0x6686, 0x668C, 0x6695, 0x6690, 0x668B, 0x668A, 0x6699, 0x6694, 0x6678,
+		0x6720, 0x6966, 0x695F, 0x6938, 0x694E, 0x6962, 0x6971, 0x693F, 0x6945, 0x696A, 0x6939,
+		0x6942, 0x6957, 0x6959, 0x697A, 0x6948, 0x6949, 0x6935, 0x696C, 0x6933, 0x693D, 0x6965,
+		0x68F0, 0x6978, 0x6934, 0x6969, 0x6940, 0x696F, 0x6944, 0x6976, 0x6958, 0x6941, 0x6974,
+		0x694C, 0x693B, 0x694B, 0x6937, 0x695C, 0x694F, 0x6951, 0x6932, 0x6952, 0x692F, 0x697B,
+		0x693C, 0x6B46, 0x6B45, 0x6B43, 0x6B42, 0x6B48, 0x6B41, 0x6B9B, 0xFA0D, 0x6BFB, 0x6BFC,
+		0x6BF9, 0x6BF7, 0x6BF8, 0x6E9B, 0x6ED6, 0x6EC8, 0x6E8F, 0x6EC0, 0x6E9F, 0x6E93, 0x6E94,
+		0x6EA0, 0x6EB1, 0x6EB9, 0x6EC6, 0x6ED2, 0x6EBD, 0x6EC1, 0x6E9E, 0x6EC9, 0x6EB7, 0x6EB0,
+		0x6ECD, 0x6EA6, 0x6ECF, 0x6EB2, 0x6EBE, 0x6EC3, 0x6EDC, 0x6ED8, 0x6E99, 0x6E92, 0x6E8E,
+		0x6E8D, 0x6EA4, 0x6EA1, 0x6EBF, 0x6EB3, 0x6ED0, 0x6ECA, 0x6E97, 0x6EAE, 0x6EA3, 0x7147,
+		0x7154, 0x7152, 0x7163, 0x7160, 0x7141, 0x715D, 0x7162, 0x7172, 0x7178, 0x716A, 0x7161,
+		0x7142, 0x7158, 0x7143, 0x714B, 0x7170, 0x715F, 0x7150, 0x7153, 0x7144, 0x714D, 0x715A,
+		0x724F, 0x728D, 0x728C, 0x7291, 0x7290, 0x728E, 0x733C, 0x7342, 0x733B, 0x733A, 0x7340,
+		0x734A, 0x7349, 0x7444, 0x744A, 0x744B, 0x7452, 0x7451, 0x7457, 0x7440, 0x744F, 0x7450,
+		0x744E, 0x7442, 0x7446, 0x744D, 0x7454, 0x74E1, 0x74FF, 0x74FE, 0x74FD, 0x751D, 0x7579,
+		0x7577, 0x6983, 0x75EF, 0x760F, 0x7603, 0x75F7, 0x75FE, 0x75FC, 0x75F9, 0x75F8, 0x7610,
+		0x75FB, 0x75F6, 0x75ED, 0x75F5, 0x75FD, 0x7699, 0x76B5, 0x76DD, 0x7755, 0x775F, 0x7760,
+		0x7752, 0x7756, 0x775A, 0x7769, 0x7767, 0x7754, 0x7759, 0x776D, 0x77E0, 0x7887, 0x789A,
+		0x7894, 0x788F, 0x7884, 0x7895, 0x7885, 0x7886, 0x78A1, 0x7883, 0x7879, 0x7899, 0x7880,
+		0x7896, 0x787B, 0x797C, 0x7982, 0x797D, 0x7979, 0x7A11, 0x7A18, 0x7A19, 0x7A12, 0x7A17,
+		0x7A15, 0x7A22, 0x7A13, 0x7A1B, 0x7A10, 0x7AA3, 0x7AA2, 0x7A9E, 0x7AEB, 0x7B66, 0x7B64,
+		0x7B6D, 0x7B74, 0x7B69, 0x7B72, 0x7B65, 0x7B73, 0x7B71, 0x7B70, 0x7B61, 0x7B78, 0x7B76,
+		0x7B63, 0x7CB2, 0x7CB4, 0x7CAF, 0x7D88, 0x7D86, 0x7D80, 0x7D8D, 0x7D7F, 0x7D85, 0x7D7A,
+		0x7D8E, 0x7D7B, 0x7D83, 0x7D7C, 0x7D8C, 0x7D94, 0x7D84, 0x7D7D, 0x7D92, 0x7F6D, 0x7F6B,
+		0x7F67, 0x7F68, 0x7F6C, 0x7FA6, 0x7FA5, 0x7FA7, 0x7FDB, 0x7FDC, 0x8021, 0x8164, 0x8160,
+		0x8177, 0x815C, 0x8169, 0x815B, 0x8162, 0x8172, 0x6721, 0x815E, 0x8176, 0x8167, 0x816F,
+		0x8144, 0x8161, 0x821D, 0x8249, 0x8244, 0x8240, 0x8242, 0x8245, 0x84F1, 0x843F, 0x8456,
+		0x8476, 0x8479, 0x848F, 0x848D, 0x8465, 0x8451, 0x8440, 0x8486, 0x8467, 0x8430, 0x844D,
+		0x847D, 0x845A, 0x8459, 0x8474, 0x8473, 0x845D, 0x8507, 0x845E, 0x8437, 0x843A, 0x8434,
+		0x847A, 0x8443, 0x8478, 0x8432, 0x8445, 0x8429, 0x83D9, 0x844B, 0x842F, 0x8442, 0x842D,
+		0x845F, 0x8470, 0x8439, 0x844E, 0x844C, 0x8452, 0x846F, 0x84C5, 0x848E, 0x843B, 0x8447,
+		0x8436, 0x8433, 0x8468, 0x847E, 0x8444, 0x842B, 0x8460, 0x8454, 0x846E, 0x8450, 0x870B,
+		0x8704, 0x86F7, 0x870C, 0x86FA, 0x86D6, 0x86F5, 0x874D, 0x86F8, 0x870E, 0x8709, 0x8701,
+		0x86F6, 0x870D, 0x8705, 0x88D6, 0x88CB, 0x88CD, 0x88CE, 0x88DE, 0x88DB, 0x88DA, 0x88CC,
+		0x88D0, 0x8985, 0x899B, 0x89DF, 0x89E5, 0x89E4, 0x89E1, 0x89E0, 0x89E2, 0x89DC, 0x89E6,
+		0x8A76, 0x8A86, 0x8A7F, 0x8A61, 0x8A3F, 0x8A77, 0x8A82, 0x8A84, 0x8A75, 0x8A83, 0x8A81,
+		0x8A74, 0x8A7A, 0x8C3C, 0x8C4B, 0x8C4A, 0x8C65, 0x8C64, 0x8C66, 0x8C86, 0x8C84, 0x8C85,
+		0x8CCC, 0x8D68, 0x8D69, 0x8D91, 0x8D8C, 0x8D8E, 0x8D8F, 0x8D8D, 0x8D93, 0x8D94, 0x8D90,
+		0x8D92, 0x8DF0, 0x8DE0, 0x8DEC, 0x8DF1, 0x8DEE, 0x8DD0, 0x8DE9, 0x8DE3, 0x8DE2, 0x8DE7,
+		0x8DF2, 0x8DEB, 0x8DF4, 0x8F06, 0x8EFF, 0x8F01, 0x8F00, 0x8F05, 0x8F07, 0x8F08, 0x8F02,
+		0x8F0B, 0x9052, 0x903F, 0x9044, 0x9049, 0x903D, 0x9110, 0x910D, 0x910F, 0x9111, 0x9116,
+		0x9114, 0x910B, 0x910E, 0x916E, 0x916F, 0x9248, 0x9252, 0x9230, 0x923A, 0x9266, 0x9233,
+		0x9265, 0x925E, 0x9283, 0x922E, 0x924A, 0x9246, 0x926D, 0x926C, 0x924F, 0x9260, 0x9267,
+		0x926F, 0x9236, 0x9261, 0x9270, 0x9231, 0x9254, 0x9263, 0x9250, 0x9272, 0x924E, 0x9253,
+		0x924C, 0x9256, 0x9232, 0x959F, 0x959C, 0x959E, 0x959B, 0x9692, 0x9693, 0x9691, 0x9697,
+		0x96CE, 0x96FA, 0x96FD, 0x96F8, 0x96F5, 0x9773, 0x9777, 0x9778, 0x9772, 0x980F, 0x980D,
+		0x980E, 0x98AC, 0x98F6, 0x98F9, 0x99AF, 0x99B2, 0x99B0, 0x99B5, 0x9AAD, 0x9AAB, 0x9B5B,
+		0x9CEA, 0x9CED, 0x9CE7, 0x9E80, 0x9EFD, 0x50E6, 0x50D4, 0x50D7, 0x50E8, 0x50F3, 0x50DB,
+		0x50EA, 0x50DD, 0x50E4, 0x50D3, 0x50EC, 0x50F0, 0x50EF, 0x50E3, 0x50E0, 0x51D8, 0x5280,
+		0x5281, 0x52E9, 0x52EB, 0x5330, 0x53AC, 0x5627, 0x5615, 0x560C, 0x5612, 0x55FC, 0x560F,
+		0x561C, 0x5601, 0x5613, 0x5602, 0x55FA, 0x561D, 0x5604, 0x55FF, 0x55F9, 0x5889, 0x587C,
+		0x5890, 0x5898, 0x5886, 0x5881, 0x587F, 0x5874, 0x588B, 0x587A, 0x5887, 0x5891, 0x588E,
+		0x5876, 0x5882, 0x5888, 0x587B, 0x5894, 0x588F, 0x58FE, 0x596B, 0x5ADC, 0x5AEE, 0x5AE5,
+		0x5AD5, 0x5AEA, 0x5ADA, 0x5AED, 0x5AEB, 0x5AF3, 0x5AE2, 0x5AE0, 0x5ADB, 0x5AEC, 0x5ADE,
+		0x5ADD, 0x5AD9, 0x5AE8, 0x5ADF, 0x5B77, 0x5BE0, 0x5BE3, 0x5C63, 0x5D82, 0x5D80, 0x5D7D,
+		0x5D86, 0x5D7A, 0x5D81, 0x5D77, 0x5D8A, 0x5D89, 0x5D88, 0x5D7E, 0x5D7C, 0x5D8D, 0x5D79,
+		0x5D7F, 0x5E58, 0x5E59, 0x5E53, 0x5ED8, 0x5ED1, 0x5ED7, 0x5ECE, 0x5EDC, 0x5ED5, 0x5ED9,
+		0x5ED2, 0x5ED4, 0x5F44, 0x5F43, 0x5F6F, 0x5FB6, 0x612C, 0x6128, 0x6141, 0x615E, 0x6171,
+		0x6173, 0x6152, 0x6153, 0x6172, 0x616C, 0x6180, 0x6174, 0x6154, 0x617A, 0x615B, 0x6165,
+		0x613B, 0x616A, 0x6161, 0x6156, 0x6229, 0x6227, 0x622B, 0x642B, 0x644D, 0x645B, 0x645D,
+		0x6474, 0x6476, 0x6472, 0x6473, 0x647D, 0x6475, 0x6466, 0x64A6, 0x644E, 0x6482, 0x645E,
+		0x645C, 0x644B, 0x6453, 0x6460, 0x6450, 0x647F, 0x643F, 0x646C, 0x646B, 0x6459, 0x6465,
+		0x6477, 0x6573, 0x65A0, 0x66A1, 0x66A0, 0x669F, 0x6705, 0x6704, 0x6722, 0x69B1, 0x69B6,
+		0x69C9, 0x69A0, 0x69CE, 0x6996, 0x69B0, 0x69AC, 0x69BC, 0x6991, 0x6999, 0x698E, 0x69A7,
+		0x698D, 0x69A9, 0x69BE, 0x69AF, 0x69BF, 0x69C4, 0x69BD, 0x69A4, 0x69D4, 0x69B9, 0x69CA,
+		0x699A, 0x69CF, 0x69B3, 0x6993, 0x69AA, 0x69A1, 0x699E, 0x69D9, 0x6997, 0x6990, 0x69C2,
+		0x69B5, 0x69A5, 0x69C6, 0x6B4A, 0x6B4D, 0x6B4B, 0x6B9E, 0x6B9F, 0x6BA0, 0x6BC3, 0x6BC4,
+		0x6BFE, 0x6ECE, 0x6EF5, 0x6EF1, 0x6F03, 0x6F25, 0x6EF8, 0x6F37, 0x6EFB, 0x6F2E, 0x6F09,
+		0x6F4E, 0x6F19, 0x6F1A, 0x6F27, 0x6F18, 0x6F3B, 0x6F12, 0x6EED, 0x6F0A, 0x6F36, 0x6F73,
+		0x6EF9, 0x6EEE, 0x6F2D, 0x6F40, 0x6F30, 0x6F3C, 0x6F35, 0x6EEB, 0x6F07, 0x6F0E, 0x6F43,
+		0x6F05, 0x6EFD, 0x6EF6, 0x6F39, 0x6F1C, 0x6EFC, 0x6F3A, 0x6F1F, 0x6F0D, 0x6F1E, 0x6F08,
+		0x6F21, 0x7187, 0x7190, 0x7189, 0x7180, 0x7185, 0x7182, 0x718F, 0x717B, 0x7186, 0x7181,
+		0x7197, 0x7244, 0x7253, 0x7297, 0x7295, 0x7293, 0x7343, 0x734D, 0x7351, 0x734C, 0x7462,
+		0x7473, 0x7471, 0x7475, 0x7472, 0x7467, 0x746E, 0x7500, 0x7502, 0x7503, 0x757D, 0x7590,
+		0x7616, 0x7608, 0x760C, 0x7615, 0x7611, 0x760A, 0x7614, 0x76B8, 0x7781, 0x777C, 0x7785,
+		0x7782, 0x776E, 0x7780, 0x776F, 0x777E, 0x7783, 0x78B2, 0x78AA, 0x78B4, 0x78AD, 0x78A8,
+		0x787E, 0x78AB, 0x789E, 0x78A5, 0x78A0, 0x78AC, 0x78A2, 0x78A4, 0x7998, 0x798A, 0x798B,
+		0x7996, 0x7995, 0x7994, 0x7993, 0x7997, 0x7988, 0x7992, 0x7990, 0x7A2B, 0x7A4A, 0x7A30,
+		0x7A2F, 0x7A28, 0x7A26, 0x7AA8, 0x7AAB, 0x7AAC, 0x7AEE, 0x7B88, 0x7B9C, 0x7B8A, 0x7B91,
+		0x7B90, 0x7B96, 0x7B8D, 0x7B8C, 0x7B9B, 0x7B8E, 0x7B85, 0x7B98, 0x5284, 0x7B99, 0x7BA4,
+		0x7B82, 0x7CBB, 0x7CBF, 0x7CBC, 0x7CBA, 0x7DA7, 0x7DB7, 0x7DC2, 0x7DA3, 0x7DAA, 0x7DC1,
+		0x7DC0, 0x7DC5, 0x7D9D, 0x7DCE, 0x7DC4, 0x7DC6, 0x7DCB, 0x7DCC, 0x7DAF, 0x7DB9, 0x7D96,
+		0x7DBC, 0x7D9F, 0x7DA6, 0x7DAE, 0x7DA9, 0x7DA1, 0x7DC9, 0x7F73, 0x7FE2, 0x7FE3, 0x7FE5,
+		0x7FDE, 0x8024, 0x805D, 0x805C, 0x8189, 0x8186, 0x8183, 0x8187, 0x818D, 0x818C, 0x818B,
+		0x8215, 0x8497, 0x84A4, 0x84A1, 0x849F, 0x84BA, 0x84CE, 0x84C2, 0x84AC, 0x84AE, 0x84AB,
+		0x84B9, 0x84B4, 0x84C1, 0x84CD, 0x84AA, 0x849A, 0x84B1, 0x84D0, 0x849D, 0x84A7, 0x84BB,
+		0x84A2, 0x8494, 0x84C7, 0x84CC, 0x849B, 0x84A9, 0x84AF, 0x84A8, 0x84D6, 0x8498, 0x84B6,
+		0x84CF, 0x84A0, 0x84D7, 0x84D4, 0x84D2, 0x84DB, 0x84B0, 0x8491, 0x8661, 0x8733, 0x8723,
+		0x8728, 0x876B, 0x8740, 0x872E, 0x871E, 0x8721, 0x8719, 0x871B, 0x8743, 0x872C, 0x8741,
+		0x873E, 0x8746, 0x8720, 0x8732, 0x872A, 0x872D, 0x873C, 0x8712, 0x873A, 0x8731, 0x8735,
+		0x8742, 0x8726, 0x8727, 0x8738, 0x8724, 0x871A, 0x8730, 0x8711, 0x88F7, 0x88E7, 0x88F1,
+		0x88F2, 0x88FA, 0x88FE, 0x88EE, 0x88FC, 0x88F6, 0x88FB, 0x88F0, 0x88EC, 0x88EB, 0x899D,
+		0x89A1, 0x899F, 0x899E, 0x89E9, 0x89EB, 0x89E8, 0x8AAB, 0x8A99, 0x8A8B, 0x8A92, 0x8A8F,
+		0x8A96, 0x8C3D, 0x8C68, 0x8C69, 0x8CD5, 0x8CCF, 0x8CD7, 0x8D96, 0x8E09, 0x8E02, 0x8DFF,
+		0x8E0D, 0x8DFD, 0x8E0A, 0x8E03, 0x8E07, 0x8E06, 0x8E05, 0x8DFE, 0x8E00, 0x8E04, 0x8F10,
+		0x8F11, 0x8F0E, 0x8F0D, 0x9123, 0x911C, 0x9120, 0x9122, 0x911F, 0x911D, 0x911A, 0x9124,
+		0x9121, 0x911B, 0x917A, 0x9172, 0x9179, 0x9173, 0x92A5, 0x92A4, 0x9276, 0x929B, 0x927A,
+		0x92A0, 0x9294, 0x92AA, 0x928D, 0x92A6, 0x929A, 0x92AB, 0x9279, 0x9297, 0x927F, 0x92A3,
+		0x92EE, 0x928E, 0x9282, 0x9295, 0x92A2, 0x927D, 0x9288, 0x92A1, 0x928A, 0x9286, 0x928C,
+		0x9299, 0x92A7, 0x927E, 0x9287, 0x92A9, 0x929D, 0x928B, 0x922D, 0x969E, 0x96A1, 0x96FF,
+		0x9758, 0x977D, 0x977A, 0x977E, 0x9783, 0x9780, 0x9782, 0x977B, 0x9784, 0x9781, 0x977F,
+		0x97CE, 0x97CD, 0x9816, 0x98AD, 0x98AE, 0x9902, 0x9900, 0x9907, 0x999D, 0x999C, 0x99C3,
+		0x99B9, 0x99BB, 0x99BA, 0x99C2, 0x99BD, 0x99C7, 0x9AB1, 0x9AE3, 0x9AE7, 0x9B3E, 0x9B3F,
+		0x9B60, 0x9B61, 0x9B5F, 0x9CF1, 0x9CF2, 0x9CF5, 0x9EA7, 0x50FF, 0x5103, 0x5130, 0x50F8,
+		0x5106, 0x5107, 0x50F6, 0x50FE, 0x510B, 0x510C, 0x50FD, 0x510A, 0x528B, 0x528C, 0x52F1,
+		0x52EF, 0x5648, 0x5642, 0x564C, 0x5635, 0x5641, 0x564A, 0x5649, 0x5646, 0x5658, 0x565A,
+		0x5640, 0x5633, 0x563D, 0x562C, 0x563E, 0x5638, 0x562A, 0x563A, 0x571A, 0x58AB, 0x589D,
+		0x58B1, 0x58A0, 0x58A3, 0x58AF, 0x58AC, 0x58A5, 0x58A1, 0x58FF, 0x5AFF, 0x5AF4, 0x5AFD,
+		0x5AF7, 0x5AF6, 0x5B03, 0x5AF8, 0x5B02, 0x5AF9, 0x5B01, 0x5B07, 0x5B05, 0x5B0F, 0x5C67,
+		0x5D99, 0x5D97, 0x5D9F, 0x5D92, 0x5DA2, 0x5D93, 0x5D95, 0x5DA0, 0x5D9C, 0x5DA1, 0x5D9A,
+		0x5D9E, 0x5E69, 0x5E5D, 0x5E60, 0x5E5C, 0x7DF3, 0x5EDB, 0x5EDE, 0x5EE1, 0x5F49, 0x5FB2,
+		0x618B, 0x6183, 0x6179, 0x61B1, 0x61B0, 0x61A2, 0x6189, 0x619B, 0x6193, 0x61AF, 0x61AD,
+		0x619F, 0x6192, 0x61AA, 0x61A1, 0x618D, 0x6166, 0x61B3, 0x622D, 0x646E, 0x6470, 0x6496,
+		0x64A0, 0x6485, 0x6497, 0x649C, 0x648F, 0x648B, 0x648A, 0x648C, 0x64A3, 0x649F, 0x6468,
+		0x64B1, 0x6498, 0x6576, 0x657A, 0x6579, 0x657B, 0x65B2, 0x65B3, 0x66B5, 0x66B0, 0x66A9,
+		0x66B2, 0x66B7, 0x66AA, 0x66AF, 0x6A00, 0x6A06, 0x6A17, 0x69E5, 0x69F8, 0x6A15, 0x69F1,
+		0x69E4, 0x6A20, 0x69FF, 0x69EC, 0x69E2, 0x6A1B, 0x6A1D, 0x69FE, 0x6A27, 0x69F2, 0x69EE,
+		0x6A14, 0x69F7, 0x69E7, 0x6A40, 0x6A08, 0x69E6, 0x69FB, 0x6A0D, 0x69FC, 0x69EB, 0x6A09,
+		0x6A04, 0x6A18, 0x6A25, 0x6A0F, 0x69F6, 0x6A26, 0x6A07, 0x69F4, 0x6A16, 0x6B51, 0x6BA5,
+		0x6BA3, 0x6BA2, 0x6BA6, 0x6C01, 0x6C00, 0x6BFF, 0x6C02, 0x6F41, 0x6F26, 0x6F7E, 0x6F87,
+		0x6FC6, 0x6F92, 0x6F8D, 0x6F89, 0x6F8C, 0x6F62, 0x6F4F, 0x6F85, 0x6F5A, 0x6F96, 0x6F76,
+		0x6F6C, 0x6F82, 0x6F55, 0x6F72, 0x6F52, 0x6F50, 0x6F57, 0x6F94, 0x6F93, 0x6F5D, 0x6F00,
+		0x6F61, 0x6F6B, 0x6F7D, 0x6F67, 0x6F90, 0x6F53, 0x6F8B, 0x6F69, 0x6F7F, 0x6F95, 0x6F63,
+		0x6F77, 0x6F6A, 0x6F7B, 0x71B2, 0x71AF, 0x719B, 0x71B0, 0x71A0, 0x719A, 0x71A9, 0x71B5,
+		0x719D, 0x71A5, 0x719E, 0x71A4, 0x71A1, 0x71AA, 0x719C, 0x71A7, 0x71B3, 0x7298, 0x729A,
+		0x7358, 0x7352, 0x735E, 0x735F, 0x7360, 0x735D, 0x735B, 0x7361, 0x735A, 0x7359, 0x7362,
+		0x7487, 0x7489, 0x748A, 0x7486, 0x7481, 0x747D, 0x7485, 0x7488, 0x747C, 0x7479, 0x7508,
+		0x7507, 0x757E, 0x7625, 0x761E, 0x7619, 0x761D, 0x761C, 0x7623, 0x761A, 0x7628, 0x761B,
+		0x769C, 0x769D, 0x769E, 0x769B, 0x778D, 0x778F, 0x7789, 0x7788, 0x78CD, 0x78BB, 0x78CF,
+		0x78CC, 0x78D1, 0x78CE, 0x78D4, 0x78C8, 0x78C3, 0x78C4, 0x78C9, 0x799A, 0x79A1, 0x79A0,
+		0x799C, 0x79A2, 0x799B, 0x6B76, 0x7A39, 0x7AB2, 0x7AB4, 0x7AB3, 0x7BB7, 0x7BCB, 0x7BBE,
+		0x7BAC, 0x7BCE, 0x7BAF, 0x7BB9, 0x7BCA, 0x7BB5, 0x7CC5, 0x7CC8, 0x7CCC, 0x7CCB, 0x7DF7,
+		0x7DDB, 0x7DEA, 0x7DE7, 0x7DD7, 0x7DE1, 0x7E03, 0x7DFA, 0x7DE6, 0x7DF6, 0x7DF1, 0x7DF0,
+		0x7DEE, 0x7DDF, 0x7F76, 0x7FAC, 0x7FB0, 0x7FAD, 0x7FED, 0x7FEB, 0x7FEA, 0x7FEC, 0x7FE6,
+		0x7FE8, 0x8064, 0x8067, 0x81A3, 0x819F, 0x819E, 0x8195, 0x81A2, 0x8199, 0x8197, 0x8216,
+		0x824F, 0x8253, 0x8252, 0x8250, 0x824E, 0x8251, 0x8524, 0x853B, 0x850F, 0x8500, 0x8529,
+		0x850E, 0x8509, 0x850D, 0x851F, 0x850A, 0x8527, 0x851C, 0x84FB, 0x852B, 0x84FA, 0x8508,
+		0x850C, 0x84F4, 0x852A, 0x84F2, 0x8515, 0x84F7, 0x84EB, 0x84F3, 0x84FC, 0x8512, 0x84EA,
+		0x84E9, 0x8516, 0x84FE, 0x8528, 0x851D, 0x852E, 0x8502, 0x84FD, 0x851E, 0x84F6, 0x8531,
+		0x8526, 0x84E7, 0x84E8, 0x84F0, 0x84EF, 0x84F9, 0x8518, 0x8520, 0x8530, 0x850B, 0x8519,
+		0x852F, 0x8662, 0x8756, 0x8763, 0x8764, 0x8777, 0x87E1, 0x8773, 0x8758, 0x8754, 0x875B,
+		0x8752, 0x8761, 0x875A, 0x8751, 0x875E, 0x876D, 0x876A, 0x8750, 0x874E, 0x875F, 0x875D,
+		0x876F, 0x876C, 0x877A, 0x876E, 0x875C, 0x8765, 0x874F, 0x877B, 0x8775, 0x8762, 0x8767,
+		0x8769, 0x885A, 0x8905, 0x890C, 0x8914, 0x890B, 0x8917, 0x8918, 0x8919, 0x8906, 0x8916,
+		0x8911, 0x890E, 0x8909, 0x89A2, 0x89A4, 0x89A3, 0x89ED, 0x89F0, 0x89EC, 0x8ACF, 0x8AC6,
+		0x8AB8, 0x8AD3, 0x8AD1, 0x8AD4, 0x8AD5, 0x8ABB, 0x8AD7, 0x8ABE, 0x8AC0, 0x8AC5, 0x8AD8,
+		0x8AC3, 0x8ABA, 0x8ABD, 0x8AD9, 0x8C3E, 0x8C4D, 0x8C8F, 0x8CE5, 0x8CDF, 0x8CD9, 0x8CE8,
+		0x8CDA, 0x8CDD, 0x8CE7, 0x8DA0, 0x8D9C, 0x8DA1, 0x8D9B, 0x8E20, 0x8E23, 0x8E25, 0x8E24,
+		0x8E2E, 0x8E15, 0x8E1B, 0x8E16, 0x8E11, 0x8E19, 0x8E26, 0x8E27, 0x8E14, 0x8E12, 0x8E18,
+		0x8E13, 0x8E1C, 0x8E17, 0x8E1A, 0x8F2C, 0x8F24, 0x8F18, 0x8F1A, 0x8F20, 0x8F23, 0x8F16,
+		0x8F17, 0x9073, 0x9070, 0x906F, 0x9067, 0x906B, 0x912F, 0x912B, 0x9129, 0x912A, 0x9132,
+		0x9126, 0x912E, 0x9185, 0x9186, 0x918A, 0x9181, 0x9182, 0x9184, 0x9180, 0x92D0, 0x92C3,
+		0x92C4, 0x92C0, 0x92D9, 0x92B6, 0x92CF, 0x92F1, 0x92DF, 0x92D8, 0x92E9, 0x92D7, 0x92DD,
+		0x92CC, 0x92EF, 0x92C2, 0x92E8, 0x92CA, 0x92C8, 0x92CE, 0x92E6, 0x92CD, 0x92D5, 0x92C9,
+		0x92E0, 0x92DE, 0x92E7, 0x92D1, 0x92D3, 0x92B5, 0x92E1, 0x92C6, 0x92B4, 0x957C, 0x95AC,
+		0x95AB, 0x95AE, 0x95B0, 0x96A4, 0x96A2, 0x96D3, 0x9705, 0x9708, 0x9702, 0x975A, 0x978A,
+		0x978E, 0x9788, 0x97D0, 0x97CF, 0x981E, 0x981D, 0x9826, 0x9829, 0x9828, 0x9820, 0x981B,
+		0x9827, 0x98B2, 0x9908, 0x98FA, 0x9911, 0x9914, 0x9916, 0x9917, 0x9915, 0x99DC, 0x99CD,
+		0x99CF, 0x99D3, 0x99D4, 0x99CE, 0x99C9, 0x99D6, 0x99D8, 0x99CB, 0x99D7, 0x99CC, 0x9AB3,
+		0x9AEC, 0x9AEB, 0x9AF3, 0x9AF2, 0x9AF1, 0x9B46, 0x9B43, 0x9B67, 0x9B74, 0x9B71, 0x9B66,
+		0x9B76, 0x9B75, 0x9B70, 0x9B68, 0x9B64, 0x9B6C, 0x9CFC, 0x9CFA, 0x9CFD, 0x9CFF, 0x9CF7,
+		0x9D07, 0x9D00, 0x9CF9, 0x9CFB, 0x9D08, 0x9D05, 0x9D04, 0x9E83, 0x9ED3, 0x9F0F, 0x9F10,
+		0x511C, 0x5113, 0x5117, 0x511A, 0x5111, 0x51DE, 0x5334, 0x53E1, 0x5670, 0x5660, 0x566E,
+		0x5673, 0x5666, 0x5663, 0x566D, 0x5672, 0x565E, 0x5677, 0x571C, 0x571B, 0x58C8, 0x58BD,
+		0x58C9, 0x58BF, 0x58BA, 0x58C2, 0x58BC, 0x58C6, 0x5B17, 0x5B19, 0x5B1B, 0x5B21, 0x5B14,
+		0x5B13, 0x5B10, 0x5B16, 0x5B28, 0x5B1A, 0x5B20, 0x5B1E, 0x5BEF, 0x5DAC, 0x5DB1, 0x5DA9,
+		0x5DA7, 0x5DB5, 0x5DB0, 0x5DAE, 0x5DAA, 0x5DA8, 0x5DB2, 0x5DAD, 0x5DAF, 0x5DB4, 0x5E67,
+		0x5E68, 0x5E66, 0x5E6F, 0x5EE9, 0x5EE7, 0x5EE6, 0x5EE8, 0x5EE5, 0x5F4B, 0x5FBC, 0x619D,
+		0x61A8, 0x6196, 0x61C5, 0x61B4, 0x61C6, 0x61C1, 0x61CC, 0x61BA, 0x61BF, 0x61B8, 0x618C,
+		0x64D7, 0x64D6, 0x64D0, 0x64CF, 0x64C9, 0x64BD, 0x6489, 0x64C3, 0x64DB, 0x64F3, 0x64D9,
+		0x6533, 0x657F, 0x657C, 0x65A2, 0x66C8, 0x66BE, 0x66C0, 0x66CA, 0x66CB, 0x66CF, 0x66BD,
+		0x66BB, 0x66BA, 0x66CC, 0x6723, 0x6A34, 0x6A66, 0x6A49, 0x6A67, 0x6A32, 0x6A68, 0x6A3E,
+		0x6A5D, 0x6A6D, 0x6A76, 0x6A5B, 0x6A51, 0x6A28, 0x6A5A, 0x6A3B, 0x6A3F, 0x6A41, 0x6A6A,
+		0x6A64, 0x6A50, 0x6A4F, 0x6A54, 0x6A6F, 0x6A69, 0x6A60, 0x6A3C, 0x6A5E, 0x6A56, 0x6A55,
+		0x6A4D, 0x6A4E, 0x6A46, 0x6B55, 0x6B54, 0x6B56, 0x6BA7, 0x6BAA, 0x6BAB, 0x6BC8, 0x6BC7,
+		0x6C04, 0x6C03, 0x6C06, 0x6FAD, 0x6FCB, 0x6FA3, 0x6FC7, 0x6FBC, 0x6FCE, 0x6FC8, 0x6F5E,
+		0x6FC4, 0x6FBD, 0x6F9E, 0x6FCA, 0x6FA8, 0x7004, 0x6FA5, 0x6FAE, 0x6FBA, 0x6FAC, 0x6FAA,
+		0x6FCF, 0x6FBF, 0x6FB8, 0x6FA2, 0x6FC9, 0x6FAB, 0x6FCD, 0x6FAF, 0x6FB2, 0x6FB0, 0x71C5,
+		0x71C2, 0x71BF, 0x71B8, 0x71D6, 0x71C0, 0x71C1, 0x71CB, 0x71D4, 0x71CA, 0x71C7, 0x71CF,
+		0x71BD, 0x71D8, 0x71BC, 0x71C6, 0x71DA, 0x71DB, 0x729D, 0x729E, 0x7369, 0x7366, 0x7367,
+		0x736C, 0x7365, 0x736B, 0x736A, 0x747F, 0x749A, 0x74A0, 0x7494, 0x7492, 0x7495, 0x74A1,
+		0x750B, 0x7580, 0x762F, 0x762D, 0x7631, 0x763D, 0x7633, 0x763C, 0x7635, 0x7632, 0x7630,
+		0x76BB, 0x76E6, 0x779A, 0x779D, 0x77A1, 0x779C, 0x779B, 0x77A2, 0x77A3, 0x7795, 0x7799,
+		0x7797, 0x78DD, 0x78E9, 0x78E5, 0x78EA, 0x78DE, 0x78E3, 0x78DB, 0x78E1, 0x78E2, 0x78ED,
+		0x78DF, 0x78E0, 0x79A4, 0x7A44, 0x7A48, 0x7A47, 0x7AB6, 0x7AB8, 0x7AB5, 0x7AB1, 0x7AB7,
+		0x7BDE, 0x7BE3, 0x7BE7, 0x7BDD, 0x7BD5, 0x7BE5, 0x7BDA, 0x7BE8, 0x7BF9, 0x7BD4, 0x7BEA,
+		0x7BE2, 0x7BDC, 0x7BEB, 0x7BD8, 0x7BDF, 0x7CD2, 0x7CD4, 0x7CD7, 0x7CD0, 0x7CD1, 0x7E12,
+		0x7E21, 0x7E17, 0x7E0C, 0x7E1F, 0x7E20, 0x7E13, 0x7E0E, 0x7E1C, 0x7E15, 0x7E1A, 0x7E22,
+		0x7E0B, 0x7E0F, 0x7E16, 0x7E0D, 0x7E14, 0x7E25, 0x7E24, 0x7F43, 0x7F7B, 0x7F7C, 0x7F7A,
+		0x7FB1, 0x7FEF, 0x802A, 0x8029, 0x806C, 0x81B1, 0x81A6, 0x81AE, 0x81B9, 0x81B5, 0x81AB,
+		0x81B0, 0x81AC, 0x81B4, 0x81B2, 0x81B7, 0x81A7, 0x81F2, 0x8255, 0x8256, 0x8257, 0x8556,
+		0x8545, 0x856B, 0x854D, 0x8553, 0x8561, 0x8558, 0x8540, 0x8546, 0x8564, 0x8541, 0x8562,
+		0x8544, 0x8551, 0x8547, 0x8563, 0x853E, 0x855B, 0x8571, 0x854E, 0x856E, 0x8575, 0x8555,
+		0x8567, 0x8560, 0x858C, 0x8566, 0x855D, 0x8554, 0x8565, 0x856C, 0x8663, 0x8665, 0x8664,
+		0x879B, 0x878F, 0x8797, 0x8793, 0x8792, 0x8788, 0x8781, 0x8796, 0x8798, 0x8779, 0x8787,
+		0x87A3, 0x8785, 0x8790, 0x8791, 0x879D, 0x8784, 0x8794, 0x879C, 0x879A, 0x8789, 0x891E,
+		0x8926, 0x8930, 0x892D, 0x892E, 0x8927, 0x8931, 0x8922, 0x8929, 0x8923, 0x892F, 0x892C,
+		0x891F, 0x89F1, 0x8AE0, 0x8AE2, 0x8AF2, 0x8AF4, 0x8AF5, 0x8ADD, 0x8B14, 0x8AE4, 0x8ADF,
+		0x8AF0, 0x8AC8, 0x8ADE, 0x8AE1, 0x8AE8, 0x8AFF, 0x8AEF, 0x8AFB, 0x8C91, 0x8C92, 0x8C90,
+		0x8CF5, 0x8CEE, 0x8CF1, 0x8CF0, 0x8CF3, 0x8D6C, 0x8D6E, 0x8DA5, 0x8DA7, 0x8E33, 0x8E3E,
+		0x8E38, 0x8E40, 0x8E45, 0x8E36, 0x8E3C, 0x8E3D, 0x8E41, 0x8E30, 0x8E3F, 0x8EBD, 0x8F36,
+		0x8F2E, 0x8F35, 0x8F32, 0x8F39, 0x8F37, 0x8F34, 0x9076, 0x9079, 0x907B, 0x9086, 0x90FA,
+		0x9133, 0x9135, 0x9136, 0x9193, 0x9190, 0x9191, 0x918D, 0x918F, 0x9327, 0x931E, 0x9308,
+		0x931F, 0x9306, 0x930F, 0x937A, 0x9338, 0x933C, 0x931B, 0x9323, 0x9312, 0x9301, 0x9346,
+		0x932D, 0x930E, 0x930D, 0x92CB, 0x931D, 0x92FA, 0x9325, 0x9313, 0x92F9, 0x92F7, 0x9334,
+		0x9302, 0x9324, 0x92FF, 0x9329, 0x9339, 0x9335, 0x932A, 0x9314, 0x930C, 0x930B, 0x92FE,
+		0x9309, 0x9300, 0x92FB, 0x9316, 0x95BC, 0x95CD, 0x95BE, 0x95B9, 0x95BA, 0x95B6, 0x95BF,
+		0x95B5, 0x95BD, 0x96A9, 0x96D4, 0x970B, 0x9712, 0x9710, 0x9799, 0x9797, 0x9794, 0x97F0,
+		0x97F8, 0x9835, 0x982F, 0x9832, 0x9924, 0x991F, 0x9927, 0x9929, 0x999E, 0x99EE, 0x99EC,
+		0x99E5, 0x99E4, 0x99F0, 0x99E3, 0x99EA, 0x99E9, 0x99E7, 0x9AB9, 0x9ABF, 0x9AB4, 0x9ABB,
+		0x9AF6, 0x9AFA, 0x9AF9, 0x9AF7, 0x9B33, 0x9B80, 0x9B85, 0x9B87, 0x9B7C, 0x9B7E, 0x9B7B,
+		0x9B82, 0x9B93, 0x9B92, 0x9B90, 0x9B7A, 0x9B95, 0x9B7D, 0x9B88, 0x9D25, 0x9D17, 0x9D20,
+		0x9D1E, 0x9D14, 0x9D29, 0x9D1D, 0x9D18, 0x9D22, 0x9D10, 0x9D19, 0x9D1F, 0x9E88, 0x9E86,
+		0x9E87, 0x9EAE, 0x9EAD, 0x9ED5, 0x9ED6, 0x9EFA, 0x9F12, 0x9F3D, 0x5126, 0x5125, 0x5122,
+		0x5124, 0x5120, 0x5129, 0x52F4, 0x5693, 0x568C, 0x568D, 0x5686, 0x5684, 0x5683, 0x567E,
+		0x5682, 0x567F, 0x5681, 0x58D6, 0x58D4, 0x58CF, 0x58D2, 0x5B2D, 0x5B25, 0x5B32, 0x5B23,
+		0x5B2C, 0x5B27, 0x5B26, 0x5B2F, 0x5B2E, 0x5B7B, 0x5BF1, 0x5BF2, 0x5DB7, 0x5E6C, 0x5E6A,
+		0x5FBE, 0x5FBB, 0x61C3, 0x61B5, 0x61BC, 0x61E7, 0x61E0, 0x61E5, 0x61E4, 0x61E8, 0x61DE,
+		0x64EF, 0x64E9, 0x64E3, 0x64EB, 0x64E4, 0x64E8, 0x6581, 0x6580, 0x65B6, 0x65DA, 0x66D2,
+		0x6A8D, 0x6A96, 0x6A81, 0x6AA5, 0x6A89, 0x6A9F, 0x6A9B, 0x6AA1, 0x6A9E, 0x6A87, 0x6A93,
+		0x6A8E, 0x6A95, 0x6A83, 0x6AA8, 0x6AA4, 0x6A91, 0x6A7F, 0x6AA6, 0x6A9A, 0x6A85, 0x6A8C,
+		0x6A92, 0x6B5B, 0x6BAD, 0x6C09, 0x6FCC, 0x6FA9, 0x6FF4, 0x6FD4, 0x6FE3, 0x6FDC, 0x6FED,
+		0x6FE7, 0x6FE6, 0x6FDE, 0x6FF2, 0x6FDD, 0x6FE2, 0x6FE8, 0x71E1, 0x71F1, 0x71E8, 0x71F2,
+		0x71E4, 0x71F0, 0x71E2, 0x7373, 0x736E, 0x736F, 0x7497, 0x74B2, 0x74AB, 0x7490, 0x74AA,
+		0x74AD, 0x74B1, 0x74A5, 0x74AF, 0x7510, 0x7511, 0x7512, 0x750F, 0x7584, 0x7643, 0x7648,
+		0x7649, 0x7647, 0x76A4, 0x76E9, 0x77B5, 0x77AB, 0x77B2, 0x77B7, 0x77B6, 0x77B4, 0x77B1,
+		0x77A8, 0x77F0, 0x78F3, 0x78FD, 0x7902, 0x78FB, 0x78FC, 0x78F2, 0x7905, 0x78F9, 0x78FE,
+		0x7904, 0x79AB, 0x79A8, 0x7A5C, 0x7A5B, 0x7A56, 0x7A58, 0x7A54, 0x7A5A, 0x7ABE, 0x7AC0,
+		0x7AC1, 0x7C05, 0x7C0F, 0x7BF2, 0x7C00, 0x7BFF, 0x7BFB, 0x7C0E, 0x7BF4, 0x7C0B, 0x7BF3,
+		0x7C02, 0x7C09, 0x7C03, 0x7C01, 0x7BF8, 0x7BFD, 0x7C06, 0x7BF0, 0x7BF1, 0x7C10, 0x7C0A,
+		0x7CE8, 0x7E2D, 0x7E3C, 0x7E42, 0x7E33, 0x9848, 0x7E38, 0x7E2A, 0x7E49, 0x7E40, 0x7E47,
+		0x7E29, 0x7E4C, 0x7E30, 0x7E3B, 0x7E36, 0x7E44, 0x7E3A, 0x7F45, 0x7F7F, 0x7F7E, 0x7F7D,
+		0x7FF4, 0x7FF2, 0x802C, 0x81BB, 0x81C4, 0x81CC, 0x81CA, 0x81C5, 0x81C7, 0x81BC, 0x81E9,
+		0x825B, 0x825A, 0x825C, 0x8583, 0x8580, 0x858F, 0x85A7, 0x8595, 0x85A0, 0x858B, 0x85A3,
+		0x857B, 0x85A4, 0x859A, 0x859E, 0x8577, 0x857C, 0x8589, 0x85A1, 0x857A, 0x8578, 0x8557,
+		0x858E, 0x8596, 0x8586, 0x858D, 0x8599, 0x859D, 0x8581, 0x85A2, 0x8582, 0x8588, 0x8585,
+		0x8579, 0x8576, 0x8598, 0x8590, 0x859F, 0x8668, 0x87BE, 0x87AA, 0x87AD, 0x87C5, 0x87B0,
+		0x87AC, 0x87B9, 0x87B5, 0x87BC, 0x87AE, 0x87C9, 0x87C3, 0x87C2, 0x87CC, 0x87B7, 0x87AF,
+		0x87C4, 0x87CA, 0x87B4, 0x87B6, 0x87BF, 0x87B8, 0x87BD, 0x87DE, 0x87B2, 0x8935, 0x8933,
+		0x893C, 0x893E, 0x8941, 0x8952, 0x8937, 0x8942, 0x89AD, 0x89AF, 0x89AE, 0x89F2, 0x89F3,
+		0x8B1E, 0x8B18, 0x8B16, 0x8B11, 0x8B05, 0x8B0B, 0x8B22, 0x8B0F, 0x8B12, 0x8B15, 0x8B07,
+		0x8B0D, 0x8B08, 0x8B06, 0x8B1C, 0x8B13, 0x8B1A, 0x8C4F, 0x8C70, 0x8C72, 0x8C71, 0x8C6F,
+		0x8C95, 0x8C94, 0x8CF9, 0x8D6F, 0x8E4E, 0x8E4D, 0x8E53, 0x8E50, 0x8E4C, 0x8E47, 0x8F43,
+		0x8F40, 0x9085, 0x907E, 0x9138, 0x919A, 0x91A2, 0x919B, 0x9199, 0x919F, 0x91A1, 0x919D,
+		0x91A0, 0x93A1, 0x9383, 0x93AF, 0x9364, 0x9356, 0x9347, 0x937C, 0x9358, 0x935C, 0x9376,
+		0x9349, 0x9350, 0x9351, 0x9360, 0x936D, 0x938F, 0x934C, 0x936A, 0x9379, 0x9357, 0x9355,
+		0x9352, 0x934F, 0x9371, 0x9377, 0x937B, 0x9361, 0x935E, 0x9363, 0x9367, 0x9380, 0x934E,
+		0x9359, 0x95C7, 0x95C0, 0x95C9, 0x95C3, 0x95C5, 0x95B7, 0x96AE, 0x96B0, 0x96AC, 0x9720,
+		0x971F, 0x9718, 0x971D, 0x9719, 0x979A, 0x97A1, 0x979C, 0x979E, 0x979D, 0x97D5, 0x97D4,
+		0x97F1, 0x9841, 0x9844, 0x984A, 0x9849, 0x9845, 0x9843, 0x9925, 0x992B, 0x992C, 0x992A,
+		0x9933, 0x9932, 0x992F, 0x992D, 0x9931, 0x9930, 0x9998, 0x99A3, 0x99A1, 0x9A02, 0x99FA,
+		0x99F4, 0x99F7, 0x99F9, 0x99F8, 0x99F6, 0x99FB, 0x99FD, 0x99FE, 0x99FC, 0x9A03, 0x9ABE,
+		0x9AFE, 0x9AFD, 0x9B01, 0x9AFC, 0x9B48, 0x9B9A, 0x9BA8, 0x9B9E, 0x9B9B, 0x9BA6, 0x9BA1,
+		0x9BA5, 0x9BA4, 0x9B86, 0x9BA2, 0x9BA0, 0x9BAF, 0x9D33, 0x9D41, 0x9D67, 0x9D36, 0x9D2E,
+		0x9D2F, 0x9D31, 0x9D38, 0x9D30, 0x9D45, 0x9D42, 0x9D43, 0x9D3E, 0x9D37, 0x9D40, 0x9D3D,
+		0x7FF5, 0x9D2D, 0x9E8A, 0x9E89, 0x9E8D, 0x9EB0, 0x9EC8, 0x9EDA, 0x9EFB, 0x9EFF, 0x9F24,
+		0x9F23, 0x9F22, 0x9F54, 0x9FA0, 0x5131, 0x512D, 0x512E, 0x5698, 0x569C, 0x5697, 0x569A,
+		0x569D, 0x5699, 0x5970, 0x5B3C, 0x5C69, 0x5C6A, 0x5DC0, 0x5E6D, 0x5E6E, 0x61D8, 0x61DF,
+		0x61ED, 0x61EE, 0x61F1, 0x61EA, 0x61F0, 0x61EB, 0x61D6, 0x61E9, 0x64FF, 0x6504, 0x64FD,
+		0x64F8, 0x6501, 0x6503, 0x64FC, 0x6594, 0x65DB, 0x66DA, 0x66DB, 0x66D8, 0x6AC5, 0x6AB9,
+		0x6ABD, 0x6AE1, 0x6AC6, 0x6ABA, 0x6AB6, 0x6AB7, 0x6AC7, 0x6AB4, 0x6AAD, 0x6B5E, 0x6BC9,
+		0x6C0B, 0x7007, 0x700C, 0x700D, 0x7001, 0x7005, 0x7014, 0x700E, 0x6FFF, 0x7000, 0x6FFB,
+		0x7026, 0x6FFC, 0x6FF7, 0x700A, 0x7201, 0x71FF, 0x71F9, 0x7203, 0x71FD, 0x7376, 0x74B8,
+		0x74C0, 0x74B5, 0x74C1, 0x74BE, 0x74B6, 0x74BB, 0x74C2, 0x7514, 0x7513, 0x765C, 0x7664,
+		0x7659, 0x7650, 0x7653, 0x7657, 0x765A, 0x76A6, 0x76BD, 0x76EC, 0x77C2, 0x77BA, 0x78FF,
+		0x790C, 0x7913, 0x7914, 0x7909, 0x7910, 0x7912, 0x7911, 0x79AD, 0x79AC, 0x7A5F, 0x7C1C,
+		0x7C29, 0x7C19, 0x7C20, 0x7C1F, 0x7C2D, 0x7C1D, 0x7C26, 0x7C28, 0x7C22, 0x7C25, 0x7C30,
+		0x7E5C, 0x7E50, 0x7E56, 0x7E63, 0x7E58, 0x7E62, 0x7E5F, 0x7E51, 0x7E60, 0x7E57, 0x7E53,
+		0x7FB5, 0x7FB3, 0x7FF7, 0x7FF8, 0x8075, 0x81D1, 0x81D2, 0x81D0, 0x825F, 0x825E, 0x85B4,
+		0x85C6, 0x85C0, 0x85C3, 0x85C2, 0x85B3, 0x85B5, 0x85BD, 0x85C7, 0x85C4, 0x85BF, 0x85CB,
+		0x85CE, 0x85C8, 0x85C5, 0x85B1, 0x85B6, 0x85D2, 0x8624, 0x85B8, 0x85B7, 0x85BE, 0x8669,
+		0x87E7, 0x87E6, 0x87E2, 0x87DB, 0x87EB, 0x87EA, 0x87E5, 0x87DF, 0x87F3, 0x87E4, 0x87D4,
+		0x87DC, 0x87D3, 0x87ED, 0x87D8, 0x87E3, 0x87A4, 0x87D7, 0x87D9, 0x8801, 0x87F4, 0x87E8,
+		0x87DD, 0x8953, 0x894B, 0x894F, 0x894C, 0x8946, 0x8950, 0x8951, 0x8949, 0x8B2A, 0x8B27,
+		0x8B23, 0x8B33, 0x8B30, 0x8B35, 0x8B47, 0x8B2F, 0x8B3C, 0x8B3E, 0x8B31, 0x8B25, 0x8B37,
+		0x8B26, 0x8B36, 0x8B2E, 0x8B24, 0x8B3B, 0x8B3D, 0x8B3A, 0x8C42, 0x8C75, 0x8C99, 0x8C98,
+		0x8C97, 0x8CFE, 0x8D04, 0x8D02, 0x8D00, 0x8E5C, 0x8E62, 0x8E60, 0x8E57, 0x8E56, 0x8E5E,
+		0x8E65, 0x8E67, 0x8E5B, 0x8E5A, 0x8E61, 0x8E5D, 0x8E69, 0x8E54, 0x8F46, 0x8F47, 0x8F48,
+		0x8F4B, 0x9128, 0x913A, 0x913B, 0x913E, 0x91A8, 0x91A5, 0x91A7, 0x91AF, 0x91AA, 0x93B5,
+		0x938C, 0x9392, 0x93B7, 0x939B, 0x939D, 0x9389, 0x93A7, 0x938E, 0x93AA, 0x939E, 0x93A6,
+		0x9395, 0x9388, 0x9399, 0x939F, 0x938D, 0x93B1, 0x9391, 0x93B2, 0x93A4, 0x93A8, 0x93B4,
+		0x93A3, 0x93A5, 0x95D2, 0x95D3, 0x95D1, 0x96B3, 0x96D7, 0x96DA, 0x5DC2, 0x96DF, 0x96D8,
+		0x96DD, 0x9723, 0x9722, 0x9725, 0x97AC, 0x97AE, 0x97A8, 0x97AB, 0x97A4, 0x97AA, 0x97A2,
+		0x97A5, 0x97D7, 0x97D9, 0x97D6, 0x97D8, 0x97FA, 0x9850, 0x9851, 0x9852, 0x98B8, 0x9941,
+		0x993C, 0x993A, 0x9A0F, 0x9A0B, 0x9A09, 0x9A0D, 0x9A04, 0x9A11, 0x9A0A, 0x9A05, 0x9A07,
+		0x9A06, 0x9AC0, 0x9ADC, 0x9B08, 0x9B04, 0x9B05, 0x9B29, 0x9B35, 0x9B4A, 0x9B4C, 0x9B4B,
+		0x9BC7, 0x9BC6, 0x9BC3, 0x9BBF, 0x9BC1, 0x9BB5, 0x9BB8, 0x9BD3, 0x9BB6, 0x9BC4, 0x9BB9,
+		0x9BBD, 0x9D5C, 0x9D53, 0x9D4F, 0x9D4A, 0x9D5B, 0x9D4B, 0x9D59, 0x9D56, 0x9D4C, 0x9D57,
+		0x9D52, 0x9D54, 0x9D5F, 0x9D58, 0x9D5A, 0x9E8E, 0x9E8C, 0x9EDF, 0x9F01, 0x9F00, 0x9F16,
+		0x9F25, 0x9F2B, 0x9F2A, 0x9F29, 0x9F28, 0x9F4C, 0x9F55, 0x5134, 0x5135, 0x5296, 0x52F7,
+		0x53B4, 0x56AB, 0x56AD, 0x56A6, 0x56A7, 0x56AA, 0x56AC, 0x58DA, 0x58DD, 0x58DB, 0x5912,
+		0x5B3D, 0x5B3E, 0x5B3F, 0x5DC3, 0x5E70, 0x5FBF, 0x61FB, 0x6507, 0x6510, 0x650D, 0x6509,
+		0x650C, 0x650E, 0x6584, 0x65DE, 0x65DD, 0x66DE, 0x6AE7, 0x6AE0, 0x6ACC, 0x6AD1, 0x6AD9,
+		0x6ACB, 0x6ADF, 0x6ADC, 0x6AD0, 0x6AEB, 0x6ACF, 0x6ACD, 0x6ADE, 0x6B60, 0x6BB0, 0x6C0C,
+		0x7019, 0x7027, 0x7020, 0x7016, 0x702B, 0x7021, 0x7022, 0x7023, 0x7029, 0x7017, 0x7024,
+		0x701C, 0x702A, 0x720C, 0x720A, 0x7207, 0x7202, 0x7205, 0x72A5, 0x72A6, 0x72A4, 0x72A3,
+		0x72A1, 0x74CB, 0x74C5, 0x74B7, 0x74C3, 0x7516, 0x7660, 0x77C9, 0x77CA, 0x77C4, 0x77F1,
+		0x791D, 0x791B, 0x7921, 0x791C, 0x7917, 0x791E, 0x79B0, 0x7A67, 0x7A68, 0x7C33, 0x7C3C,
+		0x7C39, 0x7C2C, 0x7C3B, 0x7CEC, 0x7CEA, 0x7E76, 0x7E75, 0x7E78, 0x7E70, 0x7E77, 0x7E6F,
+		0x7E7A, 0x7E72, 0x7E74, 0x7E68, 0x7F4B, 0x7F4A, 0x7F83, 0x7F86, 0x7FB7, 0x7FFD, 0x7FFE,
+		0x8078, 0x81D7, 0x81D5, 0x8264, 0x8261, 0x8263, 0x85EB, 0x85F1, 0x85ED, 0x85D9, 0x85E1,
+		0x85E8, 0x85DA, 0x85D7, 0x85EC, 0x85F2, 0x85F8, 0x85D8, 0x85DF, 0x85E3, 0x85DC, 0x85D1,
+		0x85F0, 0x85E6, 0x85EF, 0x85DE, 0x85E2, 0x8800, 0x87FA, 0x8803, 0x87F6, 0x87F7, 0x8809,
+		0x880C, 0x880B, 0x8806, 0x87FC, 0x8808, 0x87FF, 0x880A, 0x8802, 0x8962, 0x895A, 0x895B,
+		0x8957, 0x8961, 0x895C, 0x8958, 0x895D, 0x8959, 0x8988, 0x89B7, 0x89B6, 0x89F6, 0x8B50,
+		0x8B48, 0x8B4A, 0x8B40, 0x8B53, 0x8B56, 0x8B54, 0x8B4B, 0x8B55, 0x8B51, 0x8B42, 0x8B52,
+		0x8B57, 0x8C43, 0x8C77, 0x8C76, 0x8C9A, 0x8D06, 0x8D07, 0x8D09, 0x8DAC, 0x8DAA, 0x8DAD,
+		0x8DAB, 0x8E6D, 0x8E78, 0x8E73, 0x8E6A, 0x8E6F, 0x8E7B, 0x8EC2, 0x8F52, 0x8F51, 0x8F4F,
+		0x8F50, 0x8F53, 0x8FB4, 0x9140, 0x913F, 0x91B0, 0x91AD, 0x93DE, 0x93C7, 0x93CF, 0x93C2,
+		0x93DA, 0x93D0, 0x93F9, 0x93EC, 0x93CC, 0x93D9, 0x93A9, 0x93E6, 0x93CA, 0x93D4, 0x93EE,
+		0x93E3, 0x93D5, 0x93C4, 0x93CE, 0x93C0, 0x93D2, 0x93E7, 0x957D, 0x95DA, 0x95DB, 0x96E1,
+		0x9729, 0x972B, 0x972C, 0x9728, 0x9726, 0x97B3, 0x97B7, 0x97B6, 0x97DD, 0x97DE, 0x97DF,
+		0x985C, 0x9859, 0x985D, 0x9857, 0x98BF, 0x98BD, 0x98BB, 0x98BE, 0x9948, 0x9947, 0x9943,
+		0x99A6, 0x99A7, 0x9A1A, 0x9A15, 0x9A25, 0x9A1D, 0x9A24, 0x9A1B, 0x9A22, 0x9A20, 0x9A27,
+		0x9A23, 0x9A1E, 0x9A1C, 0x9A14, 0x9AC2, 0x9B0B, 0x9B0A, 0x9B0E, 0x9B0C, 0x9B37, 0x9BEA,
+		0x9BEB, 0x9BE0, 0x9BDE, 0x9BE4, 0x9BE6, 0x9BE2, 0x9BF0, 0x9BD4, 0x9BD7, 0x9BEC, 0x9BDC,
+		0x9BD9, 0x9BE5, 0x9BD5, 0x9BE1, 0x9BDA, 0x9D77, 0x9D81, 0x9D8A, 0x9D84, 0x9D88, 0x9D71,
+		0x9D80, 0x9D78, 0x9D86, 0x9D8B, 0x9D8C, 0x9D7D, 0x9D6B, 0x9D74, 0x9D75, 0x9D70, 0x9D69,
+		0x9D85, 0x9D73, 0x9D7B, 0x9D82, 0x9D6F, 0x9D79, 0x9D7F, 0x9D87, 0x9D68, 0x9E94, 0x9E91,
+		0x9EC0, 0x9EFC, 0x9F2D, 0x9F40, 0x9F41, 0x9F4D, 0x9F56, 0x9F57, 0x9F58, 0x5337, 0x56B2,
+		0x56B5, 0x56B3, 0x58E3, 0x5B45, 0x5DC6, 0x5DC7, 0x5EEE, 0x5EEF, 0x5FC0, 0x5FC1, 0x61F9,
+		0x6517, 0x6516, 0x6515, 0x6513, 0x65DF, 0x66E8, 0x66E3, 0x66E4, 0x6AF3, 0x6AF0, 0x6AEA,
+		0x6AE8, 0x6AF9, 0x6AF1, 0x6AEE, 0x6AEF, 0x703C, 0x7035, 0x702F, 0x7037, 0x7034, 0x7031,
+		0x7042, 0x7038, 0x703F, 0x703A, 0x7039, 0x7040, 0x703B, 0x7033, 0x7041, 0x7213, 0x7214,
+		0x72A8, 0x737D, 0x737C, 0x74BA, 0x76AB, 0x76AA, 0x76BE, 0x76ED, 0x77CC, 0x77CE, 0x77CF,
+		0x77CD, 0x77F2, 0x7925, 0x7923, 0x7927, 0x7928, 0x7924, 0x7929, 0x79B2, 0x7A6E, 0x7A6C,
+		0x7A6D, 0x7AF7, 0x7C49, 0x7C48, 0x7C4A, 0x7C47, 0x7C45, 0x7CEE, 0x7E7B, 0x7E7E, 0x7E81,
+		0x7E80, 0x7FBA, 0x7FFF, 0x8079, 0x81DB, 0x81D9, 0x820B, 0x8268, 0x8269, 0x8622, 0x85FF,
+		0x8601, 0x85FE, 0x861B, 0x8600, 0x85F6, 0x8604, 0x8609, 0x8605, 0x860C, 0x85FD, 0x8819,
+		0x8810, 0x8811, 0x8817, 0x8813, 0x8816, 0x8963, 0x8966, 0x89B9, 0x89F7, 0x8B60, 0x8B6A,
+		0x8B5D, 0x8B68, 0x8B63, 0x8B65, 0x8B67, 0x8B6D, 0x8DAE, 0x8E86, 0x8E88, 0x8E84, 0x8F59,
+		0x8F56, 0x8F57, 0x8F55, 0x8F58, 0x8F5A, 0x908D, 0x9143, 0x9141, 0x91B7, 0x91B5, 0x91B2,
+		0x91B3, 0x940B, 0x9413, 0x93FB, 0x9420, 0x940F, 0x9414, 0x93FE, 0x9415, 0x9410, 0x9428,
+		0x9419, 0x940D, 0x93F5, 0x9400, 0x93F7, 0x9407, 0x940E, 0x9416, 0x9412, 0x93FA, 0x9409,
+		0x93F8, 0x940A, 0x93FF, 0x93FC, 0x940C, 0x93F6, 0x9411, 0x9406, 0x95DE, 0x95E0, 0x95DF,
+		0x972E, 0x972F, 0x97B9, 0x97BB, 0x97FD, 0x97FE, 0x9860, 0x9862, 0x9863, 0x985F, 0x98C1,
+		0x98C2, 0x9950, 0x994E, 0x9959, 0x994C, 0x994B, 0x9953, 0x9A32, 0x9A34, 0x9A31, 0x9A2C,
+		0x9A2A, 0x9A36, 0x9A29, 0x9A2E, 0x9A38, 0x9A2D, 0x9AC7, 0x9ACA, 0x9AC6, 0x9B10, 0x9B12,
+		0x9B11, 0x9C0B, 0x9C08, 0x9BF7, 0x9C05, 0x9C12, 0x9BF8, 0x9C40, 0x9C07, 0x9C0E, 0x9C06,
+		0x9C17, 0x9C14, 0x9C09, 0x9D9F, 0x9D99, 0x9DA4, 0x9D9D, 0x9D92, 0x9D98, 0x9D90, 0x9D9B,
+		0x9DA0, 0x9D94, 0x9D9C, 0x9DAA, 0x9D97, 0x9DA1, 0x9D9A, 0x9DA2, 0x9DA8, 0x9D9E, 0x9DA3,
+		0x9DBF, 0x9DA9, 0x9D96, 0x9DA6, 0x9DA7, 0x9E99, 0x9E9B, 0x9E9A, 0x9EE5, 0x9EE4, 0x9EE7,
+		0x9EE6, 0x9F30, 0x9F2E, 0x9F5B, 0x9F60, 0x9F5E, 0x9F5D, 0x9F59, 0x9F91, 0x513A, 0x5139,
+		0x5298, 0x5297, 0x56C3, 0x56BD, 0x56BE, 0x5B48, 0x5B47, 0x5DCB, 0x5DCF, 0x5EF1, 0x61FD,
+		0x651B, 0x6B02, 0x6AFC, 0x6B03, 0x6AF8, 0x6B00, 0x7043, 0x7044, 0x704A, 0x7048, 0x7049,
+		0x7045, 0x7046, 0x721D, 0x721A, 0x7219, 0x737E, 0x7517, 0x766A, 0x77D0, 0x792D, 0x7931,
+		0x792F, 0x7C54, 0x7C53, 0x7CF2, 0x7E8A, 0x7E87, 0x7E88, 0x7E8B, 0x7E86, 0x7E8D, 0x7F4D,
+		0x7FBB, 0x8030, 0x81DD, 0x8618, 0x862A, 0x8626, 0x861F, 0x8623, 0x861C, 0x8619, 0x8627,
+		0x862E, 0x8621, 0x8620, 0x8629, 0x861E, 0x8625, 0x8829, 0x881D, 0x881B, 0x8820, 0x8824,
+		0x881C, 0x882B, 0x884A, 0x896D, 0x8969, 0x896E, 0x896B, 0x89FA, 0x8B79, 0x8B78, 0x8B45,
+		0x8B7A, 0x8B7B, 0x8D10, 0x8D14, 0x8DAF, 0x8E8E, 0x8E8C, 0x8F5E, 0x8F5B, 0x8F5D, 0x9146,
+		0x9144, 0x9145, 0x91B9, 0x943F, 0x943B, 0x9436, 0x9429, 0x943D, 0x943C, 0x9430, 0x9439,
+		0x942A, 0x9437, 0x942C, 0x9440, 0x9431, 0x95E5, 0x95E4, 0x95E3, 0x9735, 0x973A, 0x97BF,
+		0x97E1, 0x9864, 0x98C9, 0x98C6, 0x98C0, 0x9958, 0x9956, 0x9A39, 0x9A3D, 0x9A46, 0x9A44,
+		0x9A42, 0x9A41, 0x9A3A, 0x9A3F, 0x9ACD, 0x9B15, 0x9B17, 0x9B18, 0x9B16, 0x9B3A, 0x9B52,
+		0x9C2B, 0x9C1D, 0x9C1C, 0x9C2C, 0x9C23, 0x9C28, 0x9C29, 0x9C24, 0x9C21, 0x9DB7, 0x9DB6,
+		0x9DBC, 0x9DC1, 0x9DC7, 0x9DCA, 0x9DCF, 0x9DBE, 0x9DC5, 0x9DC3, 0x9DBB, 0x9DB5, 0x9DCE,
+		0x9DB9, 0x9DBA, 0x9DAC, 0x9DC8, 0x9DB1, 0x9DAD, 0x9DCC, 0x9DB3, 0x9DCD, 0x9DB2, 0x9E7A,
+		0x9E9C, 0x9EEB, 0x9EEE, 0x9EED, 0x9F1B, 0x9F18, 0x9F1A, 0x9F31, 0x9F4E, 0x9F65, 0x9F64,
+		0x9F92, 0x4EB9, 0x56C6, 0x56C5, 0x56CB, 0x5971, 0x5B4B, 0x5B4C, 0x5DD5, 0x5DD1, 0x5EF2,
+		0x6521, 0x6520, 0x6526, 0x6522, 0x6B0B, 0x6B08, 0x6B09, 0x6C0D, 0x7055, 0x7056, 0x7057,
+		0x7052, 0x721E, 0x721F, 0x72A9, 0x737F, 0x74D8, 0x74D5, 0x74D9, 0x74D7, 0x766D, 0x76AD,
+		0x7935, 0x79B4, 0x7A70, 0x7A71, 0x7C57, 0x7C5C, 0x7C59, 0x7C5B, 0x7C5A, 0x7CF4, 0x7CF1,
+		0x7E91, 0x7F4F, 0x7F87, 0x81DE, 0x826B, 0x8634, 0x8635, 0x8633, 0x862C, 0x8632, 0x8636,
+		0x882C, 0x8828, 0x8826, 0x882A, 0x8825, 0x8971, 0x89BF, 0x89BE, 0x89FB, 0x8B7E, 0x8B84,
+		0x8B82, 0x8B86, 0x8B85, 0x8B7F, 0x8D15, 0x8E95, 0x8E94, 0x8E9A, 0x8E92, 0x8E90, 0x8E96,
+		0x8E97, 0x8F60, 0x8F62, 0x9147, 0x944C, 0x9450, 0x944A, 0x944B, 0x944F, 0x9447, 0x9445,
+		0x9448, 0x9449, 0x9446, 0x973F, 0x97E3, 0x986A, 0x9869, 0x98CB, 0x9954, 0x995B, 0x9A4E,
+		0x9A53, 0x9A54, 0x9A4C, 0x9A4F, 0x9A48, 0x9A4A, 0x9A49, 0x9A52, 0x9A50, 0x9AD0, 0x9B19,
+		0x9B2B, 0x9B3B, 0x9B56, 0x9B55, 0x9C46, 0x9C48, 0x9C3F, 0x9C44, 0x9C39, 0x9C33, 0x9C41,
+		0x9C3C, 0x9C37, 0x9C34, 0x9C32, 0x9C3D, 0x9C36, 0x9DDB, 0x9DD2, 0x9DDE, 0x9DDA, 0x9DCB,
+		0x9DD0, 0x9DDC, 0x9DD1, 0x9DDF, 0x9DE9, 0x9DD9, 0x9DD8, 0x9DD6, 0x9DF5, 0x9DD5, 0x9DDD,
+		0x9EB6, 0x9EF0, 0x9F35, 0x9F33, 0x9F32, 0x9F42, 0x9F6B, 0x9F95, 0x9FA2, 0x513D, 0x5299,
+		0x58E8, 0x58E7, 0x5972, 0x5B4D, 0x5DD8, 0x882F, 0x5F4F, 0x6201, 0x6203, 0x6204, 0x6529,
+		0x6525, 0x6596, 0x66EB, 0x6B11, 0x6B12, 0x6B0F, 0x6BCA, 0x705B, 0x705A, 0x7222, 0x7382,
+		0x7381, 0x7383, 0x7670, 0x77D4, 0x7C67, 0x7C66, 0x7E95, 0x826C, 0x863A, 0x8640, 0x8639,
+		0x863C, 0x8631, 0x863B, 0x863E, 0x8830, 0x8832, 0x882E, 0x8833, 0x8976, 0x8974, 0x8973,
+		0x89FE, 0x8B8C, 0x8B8E, 0x8B8B, 0x8B88, 0x8C45, 0x8D19, 0x8E98, 0x8F64, 0x8F63, 0x91BC,
+		0x9462, 0x9455, 0x945D, 0x9457, 0x945E, 0x97C4, 0x97C5, 0x9800, 0x9A56, 0x9A59, 0x9B1E,
+		0x9B1F, 0x9B20, 0x9C52, 0x9C58, 0x9C50, 0x9C4A, 0x9C4D, 0x9C4B, 0x9C55, 0x9C59, 0x9C4C,
+		0x9C4E, 0x9DFB, 0x9DF7, 0x9DEF, 0x9DE3, 0x9DEB, 0x9DF8, 0x9DE4, 0x9DF6, 0x9DE1, 0x9DEE,
+		0x9DE6, 0x9DF2, 0x9DF0, 0x9DE2, 0x9DEC, 0x9DF4, 0x9DF3, 0x9DE8, 0x9DED, 0x9EC2, 0x9ED0,
+		0x9EF2, 0x9EF3, 0x9F06, 0x9F1C, 0x9F38, 0x9F37, 0x9F36, 0x9F43, 0x9F4F, 0x9F71, 0x9F70,
+		0x9F6E, 0x9F6F, 0x56D3, 0x56CD, 0x5B4E, 0x5C6D, 0x652D, 0x66ED, 0x66EE, 0x6B13, 0x705F,
+		0x7061, 0x705D, 0x7060, 0x7223, 0x74DB, 0x74E5, 0x77D5, 0x7938, 0x79B7, 0x79B6, 0x7C6A,
+		0x7E97, 0x7F89, 0x826D, 0x8643, 0x8838, 0x8837, 0x8835, 0x884B, 0x8B94, 0x8B95, 0x8E9E,
+		0x8E9F, 0x8EA0, 0x8E9D, 0x91BE, 0x91BD, 0x91C2, 0x946B, 0x9468, 0x9469, 0x96E5, 0x9746,
+		0x9743, 0x9747, 0x97C7, 0x97E5, 0x9A5E, 0x9AD5, 0x9B59, 0x9C63, 0x9C67, 0x9C66, 0x9C62,
+		0x9C5E, 0x9C60, 0x9E02, 0x9DFE, 0x9E07, 0x9E03, 0x9E06, 0x9E05, 0x9E00, 0x9E01, 0x9E09,
+		0x9DFF, 0x9DFD, 0x9E04, 0x9EA0, 0x9F1E, 0x9F46, 0x9F74, 0x9F75, 0x9F76, 0x56D4, 0x652E,
+		0x65B8, 0x6B18, 0x6B19, 0x6B17, 0x6B1A, 0x7062, 0x7226, 0x72AA, 0x77D8, 0x77D9, 0x7939,
+		0x7C69, 0x7C6B, 0x7CF6, 0x7E9A, 0x7E98, 0x7E9B, 0x7E99, 0x81E0, 0x81E1, 0x8646, 0x8647,
+		0x8648, 0x8979, 0x897A, 0x897C, 0x897B, 0x89FF, 0x8B98, 0x8B99, 0x8EA5, 0x8EA4, 0x8EA3,
+		0x946E, 0x946D, 0x946F, 0x9471, 0x9473, 0x9749, 0x9872, 0x995F, 0x9C68, 0x9C6E, 0x9C6D,
+		0x9E0B, 0x9E0D, 0x9E10, 0x9E0F, 0x9E12, 0x9E11, 0x9EA1, 0x9EF5, 0x9F09, 0x9F47, 0x9F78,
+		0x9F7B, 0x9F7A, 0x9F79, 0x571E, 0x7066, 0x7C6F, 0x883C, 0x8DB2, 0x8EA6, 0x91C3, 0x9474,
+		0x9478, 0x9476, 0x9475, 0x9A60, 0x9C74, 0x9C73, 0x9C71, 0x9C75, 0x9E14, 0x9E13, 0x9EF6,
+		0x9F0A, 0x9FA4, 0x7068, 0x7065, 0x7CF7, 0x866A, 0x883E, 0x883D, 0x883F, 0x8B9E, 0x8C9C,
+		0x8EA9, 0x8EC9, 0x974B, 0x9873, 0x9874, 0x98CC, 0x9961, 0x99AB, 0x9A64, 0x9A66, 0x9A67,
+		0x9B24, 0x9E15, 0x9E17, 0x9F48, 0x6207, 0x6B1E, 0x7227, 0x864C, 0x8EA8, 0x9482, 0x9480,
+		0x9481, 0x9A69, 0x9A68, 0x9B2E, 0x9E19, 0x7229, 0x864B, 0x8B9F, 0x9483, 0x9C79, 0x9EB7,
+		0x7675, 0x9A6B, 0x9C7A, 0x9E1D, 0x7069, 0x706A, 0x9EA4, 0x9F7E, 0x9F49, 0x9F98, 0x7881,
+		0x92B9, 0x88CF, 0x58BB, 0x6052, 0x7CA7, 0x5AFA, 0x2554, 0x2566, 0x2557, 0x2560, 0x256C,
+		0x2563, 0x255A, 0x2569, 0x255D, 0x2552, 0x2564, 0x2555, 0x255E, 0x256A, 0x2561, 0x2558,
+		0x2567, 0x255B, 0x2553, 0x2565, 0x2556, 0x255F, 0x256B, 0x2562, 0x2559, 0x2568, 0x255C,
+		0x2551, 0x2550, 0x256D, 0x256E, 0x2570, 0x256F, 0xFFED, 0x20547, 0x92DB, 0x205DF, 0x23FC5,
+		0x854C, 0x42B5, 0x73EF, 0x51B5, 0x3649, 0x24942, 0x289E4, 0x9344, 0x219DB, 0x82EE, 0x23CC8,
+		0x783C, 0x6744, 0x62DF, 0x24933, 0x289AA, 0x202A0, 0x26BB3, 0x21305, 0x4FAB, 0x224ED,
+		0x5008, 0x26D29, 0x27A84, 0x23600, 0x24AB1, 0x22513, 0xFFFD, 0x2037E, 0x5FA4, 0x20380,
+		0x20347, 0x6EDB, 0x2041F, 0xFFFD, 0x5101, 0x347A, 0x510E, 0x986C, 0x3743, 0x8416, 0x249A4,
+		0x20487, 0x5160, 0x233B4, 0x516A, 0x20BFF, 0x220FC, 0x202E5, 0x22530, 0x2058E, 0x23233,
+		0x21983, 0x5B82, 0x877D, 0x205B3, 0x23C99, 0x51B2, 0x51B8, 0x9D34, 0x51C9, 0x51CF, 0x51D1,
+		0x3CDC, 0x51D3, 0x24AA6, 0x51B3, 0x51E2, 0x5342, 0x51ED, 0x83CD, 0x693E, 0x2372D, 0x5F7B,
+		0x520B, 0x5226, 0x523C, 0x52B5, 0x5257, 0x5294, 0x52B9, 0x52C5, 0x7C15, 0x8542, 0x52E0,
+		0x860D, 0x26B13, 0xFFFD, 0x28ADE, 0x5549, 0x6ED9, 0x23F80, 0x20954, 0x23FEC, 0x5333,
+		0xFFFD, 0x20BE2, 0x6CCB, 0x21726, 0x681B, 0x73D5, 0x604A, 0x3EAA, 0x38CC, 0x216E8, 0x71DD,
+		0x44A2, 0x536D, 0x5374, 0x286AB, 0x537E, 0xFFFD, 0x21596, 0x21613, 0x77E6, 0x5393, 0x28A9B,
+		0x53A0, 0x53AB, 0x53AE, 0x73A7, 0x25772, 0x3F59, 0x739C, 0x53C1, 0x53C5, 0x6C49, 0x4E49,
+		0x57FE, 0x53D9, 0x3AAB, 0x20B8F, 0x53E0, 0x23FEB, 0x22DA3, 0x53F6, 0x20C77, 0x5413, 0x7079,
+		0x552B, 0x6657, 0x6D5B, 0x546D, 0x26B53, 0x20D74, 0x555D, 0x548F, 0x54A4, 0x47A6, 0x2170D,
+		0x20EDD, 0x3DB4, 0x20D4D, 0x289BC, 0x22698, 0x5547, 0x4CED, 0x542F, 0x7417, 0x5586, 0x55A9,
+		0xFFFD, 0x218D7, 0x2403A, 0x4552, 0x24435, 0x66B3, 0x210B4, 0x5637, 0x66CD, 0x2328A,
+		0x66A4, 0x66AD, 0x564D, 0x564F, 0x78F1, 0x56F1, 0x9787, 0x53FE, 0x5700, 0x56EF, 0x56ED,
+		0x28B66, 0x3623, 0x2124F, 0x5746, 0x241A5, 0x6C6E, 0x708B, 0x5742, 0x36B1, 0x26C7E, 0x57E6,
+		0x21416, 0x5803, 0x21454, 0x24363, 0x5826, 0x24BF5, 0x585C, 0x58AA, 0x3561, 0x58E0, 0x58DC,
+		0x2123C, 0x58FB, 0x5BFF, 0x5743, 0x2A150, 0x24278, 0x93D3, 0x35A1, 0x591F, 0x68A6, 0x36C3,
+		0x6E59, 0x2163E, 0x5A24, 0x5553, 0x21692, 0x8505, 0x59C9, 0x20D4E, 0x26C81, 0x26D2A,
+		0x217DC, 0x59D9, 0x217FB, 0x217B2, 0x26DA6, 0x6D71, 0x21828, 0x216D5, 0x59F9, 0x26E45,
+		0x5AAB, 0x5A63, 0x36E6, 0x249A9, 0xFFFD, 0x3708, 0x5A96, 0x7465, 0x5AD3, 0x26FA1, 0x22554,
+		0x3D85, 0x21911, 0x3732, 0x216B8, 0x5E83, 0x52D0, 0x5B76, 0x6588, 0x5B7C, 0x27A0E, 0x4004,
+		0x485D, 0x20204, 0x5BD5, 0x6160, 0x21A34, 0x259CC, 0x205A5, 0x5BF3, 0x5B9D, 0x4D10, 0x5C05,
+		0x21B44, 0x5C13, 0x73CE, 0x5C14, 0x21CA5, 0x26B28, 0x5C49, 0x48DD, 0x5C85, 0x5CE9, 0x5CEF,
+		0x5D8B, 0x21DF9, 0x21E37, 0x5D10, 0x5D18, 0x5D46, 0x21EA4, 0x5CBA, 0x5DD7, 0x82FC, 0x382D,
+		0x24901, 0x22049, 0x22173, 0x8287, 0x3836, 0x3BC2, 0x5E2E, 0x6A8A, 0xFFFD, 0x5E7A, 0x244BC,
+		0x20CD3, 0x53A6, 0x4EB7, 0xFFFD, 0x53A8, 0x21771, 0x5E09, 0x5EF4, 0x28482, 0x5EF9, 0x5EFB,
+		0x38A0, 0x5EFC, 0x683E, 0x941B, 0x5F0D, 0x201C1, 0x2F894, 0x3ADE, 0x48AE, 0x2133A, 0x5F3A,
+		0x26888, 0x223D0, 0xFFFD, 0x22471, 0x5F63, 0x97BD, 0x26E6E, 0x5F72, 0x9340, 0x28A36,
+		0x5FA7, 0x5DB6, 0x3D5F, 0x25250, 0x21F6A, 0x270F8, 0x22668, 0x91D6, 0x2029E, 0x28A29,
+		0x6031, 0x6685, 0x21877, 0x3963, 0x3DC7, 0x3639, 0x5790, 0x227B4, 0x7971, 0x3E40, 0x609E,
+		0xFFFD, 0x60B3, 0x24982, 0x2498F, 0x27A53, 0x74A4, 0x50E1, 0x5AA0, 0x6164, 0x8424, 0x6142,
+		0x2F8A6, 0x26ED2, 0x6181, 0x51F4, 0x20656, 0x6187, 0x5BAA, 0x23FB7, 0x2285F, 0x61D3,
+		0x28B9D, 0x2995D, 0x61D0, 0x3932, 0x22980, 0x228C1, 0x6023, 0x615C, 0x651E, 0x638B,
+		0x20118, 0x62C5, 0x21770, 0x62D5, 0x22E0D, 0x636C, 0x249DF, 0x3A17, 0x6438, 0x63F8,
+		0x2138E, 0x217FC, 0xFFFD, 0x6F8A, 0x22E36, 0x9814, 0x2408C, 0x2571D, 0x64E1, 0x64E5,
+		0x947B, 0x3A66, 0x643A, 0x3A57, 0x654D, 0x6F16, 0x24A28, 0x24A23, 0x6585, 0x656D, 0x655F,
+		0x2307E, 0x65B5, 0x24940, 0x4B37, 0x65D1, 0x40D8, 0x21829, 0x65E0, 0x65E3, 0x5FDF, 0x23400,
+		0x6618, 0x231F7, 0x231F8, 0x6644, 0x231A4, 0x231A5, 0x664B, 0x20E75, 0x6667, 0x251E6,
+		0x6673, 0xFFFD, 0x21E3D, 0x23231, 0x285F4, 0x231C8, 0x25313, 0x77C5, 0x228F7, 0x99A4,
+		0x6702, 0x2439C, 0x24A21, 0x3B2B, 0x69FA, 0x237C2, 0xFFFD, 0x6767, 0x6762, 0x241CD,
+		0x290ED, 0x67D7, 0x44E9, 0x6822, 0x6E50, 0x923C, 0x6801, 0x233E6, 0x26DA0, 0x685D, 0x2346F,
+		0x69E1, 0x6A0B, 0x28ADF, 0x6973, 0x68C3, 0x235CD, 0x6901, 0x6900, 0x3D32, 0x3A01, 0x2363C,
+		0x3B80, 0x67AC, 0x6961, 0x28A4A, 0x42FC, 0x6936, 0x6998, 0x3BA1, 0x203C9, 0x8363, 0x5090,
+		0x69F9, 0x23659, 0x2212A, 0x6A45, 0x23703, 0x6A9D, 0x3BF3, 0x67B1, 0x6AC8, 0x2919C, 0x3C0D,
+		0x6B1D, 0x20923, 0x60DE, 0x6B35, 0x6B74, 0x227CD, 0x6EB5, 0x23ADB, 0x203B5, 0x21958,
+		0x3740, 0x5421, 0x23B5A, 0x6BE1, 0x23EFC, 0x6BDC, 0x6C37, 0x2248B, 0x248F1, 0x26B51,
+		0x6C5A, 0x8226, 0x6C79, 0x23DBC, 0x44C5, 0x23DBD, 0x241A4, 0x2490C, 0x24900, 0x23CC9,
+		0x36E5, 0x3CEB, 0x20D32, 0x9B83, 0x231F9, 0x22491, 0x7F8F, 0x6837, 0x26D25, 0x26DA1,
+		0x26DEB, 0x6D96, 0x6D5C, 0x6E7C, 0x6F04, 0x2497F, 0x24085, 0x26E72, 0x8533, 0x26F74,
+		0x51C7, 0xFFFD, 0xFFFD, 0x842E, 0x28B21, 0xFFFD, 0x23E2F, 0x7453, 0x23F82, 0x79CC, 0x6E4F,
+		0x5A91, 0x2304B, 0x6FF8, 0x370D, 0x6F9D, 0x23E30, 0x6EFA, 0x21497, 0x2403D, 0x4555, 0x93F0,
+		0x6F44, 0x6F5C, 0x3D4E, 0x6F74, 0x29170, 0x3D3B, 0x6F9F, 0x24144, 0x6FD3, 0x24091, 0x24155,
+		0x24039, 0x23FF0, 0x23FB4, 0x2413F, 0x51DF, 0x24156, 0x24157, 0x24140, 0x261DD, 0x704B,
+		0x707E, 0x70A7, 0x7081, 0x70CC, 0x70D5, 0x70D6, 0x70DF, 0x4104, 0x3DE8, 0x71B4, 0x7196,
+		0x24277, 0x712B, 0x7145, 0x5A88, 0x714A, 0xFFFD, 0x5C9C, 0x24365, 0x714F, 0x9362, 0x242C1,
+		0x712C, 0x2445A, 0x24A27, 0x24A22, 0x71BA, 0x28BE8, 0x70BD, 0x720E, 0x9442, 0x7215, 0x5911,
+		0x9443, 0x7224, 0x9341, 0x25605, 0x722E, 0x7240, 0x24974, 0x68BD, 0x7255, 0x7257, 0x3E55,
+		0x23044, 0x680D, 0x6F3D, 0x7282, 0xFFFD, 0x732B, 0x24823, 0x2882B, 0x48ED, 0x28804, 0x7328,
+		0x732E, 0x73CF, 0x73AA, 0x20C3A, 0x26A2E, 0x73C9, 0x7449, 0x241E2, 0x216E7, 0x24A24,
+		0x6623, 0x36C5, 0x249B7, 0x2498D, 0x249FB, 0x73F7, 0x7415, 0x6903, 0x24A26, 0x7439,
+		0x205C3, 0x3ED7, 0xFFFD, 0x228AD, 0x7460, 0x28EB2, 0x7447, 0x73E4, 0x7476, 0x83B9, 0x746C,
+		0x3730, 0x7474, 0x93F1, 0x6A2C, 0x7482, 0x4953, 0x24A8C, 0x2415F, 0x24A79, 0x28B8F, 0x5B46,
+		0x28C03, 0x2189E, 0x74C8, 0x21988, 0x750E, 0xFFFD, 0x751E, 0x28ED9, 0x21A4B, 0x5BD7,
+		0x28EAC, 0x9385, 0x754D, 0x754A, 0x7567, 0x756E, 0x24F82, 0x3F04, 0x24D13, 0x758E, 0x745D,
+		0x759E, 0x75B4, 0x7602, 0x762C, 0x7651, 0x764F, 0x766F, 0x7676, 0x263F5, 0x7690, 0x81EF,
+		0x37F8, 0x26911, 0x2690E, 0x76A1, 0x76A5, 0x76B7, 0x76CC, 0x26F9F, 0x8462, 0x2509D,
+		0x2517D, 0x21E1C, 0x771E, 0x7726, 0x7740, 0x64AF, 0x25220, 0x7758, 0x232AC, 0x77AF,
+		0x28964, 0x28968, 0x216C1, 0x77F4, 0xFFFD, 0x21376, 0x24A12, 0x68CA, 0x78AF, 0x78C7,
+		0x78D3, 0x96A5, 0x792E, 0x255E0, 0x78D7, 0x7934, 0x78B1, 0x2760C, 0x8FB8, 0x8884, 0x28B2B,
+		0x26083, 0x2261C, 0x7986, 0x8900, 0x6902, 0x7980, 0x25857, 0x799D, 0x27B39, 0x793C, 0x79A9,
+		0x6E2A, 0x27126, 0x3EA8, 0x79C6, 0x2910D, 0x79D4,
+	],
+	pointers: &[
+		4245, 4246, 4243, 4161, 10009, 4263, 4195, 4098, 182, 180, 186, 184, 195, 190, 188, 4193,
+		199, 197, 204, 202, 226, 208, 206, 212, 210, 4194, 10358, 216, 214, 221, 179, 196, 183,
+		201, 185, 203, 205, 10359, 187, 209, 10357, 213, 181, 198, 207, 189, 211, 215, 217, 218,
+		219, 220, 10353, 200, 10355, 10354, 227, 10361, 10356, 10352, 10360, 10010, 4488, 4486,
+		4487, 4489, 4181, 4485, 4400, 4401, 4402, 4403, 4404, 4405, 4406, 4407, 4408, 4409, 4410,
+		4411, 4412, 4413, 4414, 4415, 4416, 4417, 4418, 4419, 4420, 4421, 4422, 4423, 4424, 4425,
+		4426, 4427, 4428, 4429, 4430, 4431, 4432, 4433, 4434, 4435, 4436, 4437, 4438, 4439, 4440,
+		4441, 4442, 4443, 4444, 4445, 4446, 4447, 10199, 10193, 10194, 10195, 10196, 10197, 10198,
+		10200, 10201, 10202, 10203, 10204, 10205, 10206, 10207, 10208, 10209, 10210, 10211, 10212,
+		10213, 10214, 10215, 10216, 10217, 10218, 10219, 10220, 10221, 10222, 10223, 10224, 10225,
+		10226, 10227, 10228, 10229, 10230, 10231, 10233, 10234, 10235, 10236, 10237, 10238, 10239,
+		10240, 10241, 10242, 10243, 10244, 10245, 10246, 10247, 10248, 10249, 10250, 10251, 10252,
+		10253, 10254, 10255, 10256, 10257, 10258, 10232, 192, 223, 194, 225, 4104, 4106, 4149,
+		4150, 4151, 4152, 4087, 4094, 4093, 4156, 4155, 4160, 4178, 4249, 4177, 4250, 10317, 10318,
+		4326, 4327, 4328, 4329, 4330, 4331, 4332, 4333, 4334, 4335, 9974, 9975, 9976, 9977, 9978,
+		9979, 9980, 9981, 9982, 9983, 4230, 4228, 4231, 4229, 4232, 4233, 4235, 4234, 10260, 10261,
+		10259, 4196, 4203, 4216, 4215, 4237, 4236, 4212, 4213, 4220, 4221, 4223, 4222, 4211, 4204,
+		4202, 4205, 4200, 4201, 4214, 4217, 228, 229, 9954, 9955, 9956, 9957, 9958, 9959, 9960,
+		9961, 9962, 9963, 9964, 9965, 9966, 9967, 9968, 9969, 9970, 9971, 9972, 9973, 4294, 4295,
+		4297, 4298, 4299, 4300, 4292, 4291, 4290, 4289, 4288, 18049, 18048, 18030, 18039, 18021,
+		18032, 18041, 18023, 18036, 18045, 18027, 18038, 18047, 18029, 18033, 18042, 18024, 18035,
+		18044, 18026, 18031, 18040, 18022, 18037, 18046, 18028, 18034, 18043, 18025, 18050, 18051,
+		18053, 18052, 4313, 4314, 4315, 4108, 4273, 4274, 4275, 4276, 4277, 4278, 4279, 4280, 4287,
+		4286, 4285, 4284, 4283, 4282, 4281, 4293, 4296, 4173, 4172, 4166, 4165, 4175, 4174, 4171,
+		4170, 4163, 4167, 4164, 4309, 4310, 4312, 4311, 4169, 4168, 4227, 4224, 4226, 4225, 10023,
+		10321, 10322, 10323, 10324, 10325, 10326, 10327, 10328, 10329, 10330, 10331, 10332, 10333,
+		10334, 10335, 10336, 10337, 10338, 10339, 10340, 10341, 10342, 10343, 10344, 10345, 10346,
+		10347, 10348, 9998, 4082, 4084, 4085, 4162, 10017, 10018, 10019, 4131, 4132, 4127, 4128,
+		4135, 4136, 4139, 4140, 4123, 4124, 4244, 4119, 4120, 4153, 4154, 4336, 4337, 4338, 4339,
+		4340, 4341, 4342, 4343, 4344, 10024, 10025, 10026, 10027, 10028, 10029, 10030, 10031,
+		10032, 10033, 10034, 10035, 10036, 10037, 10038, 10039, 10040, 10041, 10042, 10043, 10044,
+		10045, 10046, 10047, 10048, 10049, 10050, 10051, 10052, 10053, 10054, 10055, 10056, 10057,
+		10058, 10059, 10060, 10061, 10062, 10063, 10064, 10065, 10066, 10067, 10068, 10069, 10070,
+		10071, 10072, 10073, 10074, 10075, 10076, 10077, 10078, 10079, 10080, 10081, 10082, 10083,
+		10084, 10085, 10086, 10087, 10088, 10089, 10090, 10091, 10092, 10093, 10094, 10095, 10096,
+		10097, 10098, 10099, 10100, 10101, 10102, 10103, 10104, 10105, 10106, 10319, 10320, 10013,
+		10014, 10107, 10108, 10109, 10110, 10111, 10112, 10113, 10114, 10115, 10116, 10117, 10118,
+		10119, 10120, 10121, 10122, 10123, 10124, 10125, 10126, 10127, 10128, 10129, 10130, 10131,
+		10132, 10133, 10134, 10135, 10136, 10137, 10138, 10139, 10140, 10141, 10142, 10143, 10144,
+		10145, 10146, 10147, 10148, 10149, 10150, 10151, 10152, 10153, 10154, 10155, 10156, 10157,
+		10158, 10159, 10160, 10161, 10162, 10163, 10164, 10165, 10166, 10167, 10168, 10169, 10170,
+		10171, 10172, 10173, 10174, 10175, 10176, 10177, 10178, 10179, 10180, 10181, 10182, 10183,
+		10184, 10185, 10186, 10187, 10188, 10189, 10190, 10191, 10192, 10020, 10011, 10012, 4448,
+		4449, 4450, 4451, 4452, 4453, 4454, 4455, 4456, 4457, 4458, 4459, 4460, 4461, 4462, 4463,
+		4464, 4465, 4466, 4467, 4468, 4469, 4470, 4471, 4472, 4473, 4474, 4475, 4476, 4477, 4478,
+		4479, 4480, 4481, 4482, 4483, 4484, 157, 158, 159, 160, 161, 163, 166, 167, 170, 172, 173,
+		174, 175, 177, 178, 10262, 10316, 4176, 4260, 4261, 4255, 4256, 4257, 4259, 4262, 4258,
+		4219, 4218, 4254, 1782, 2480, 931, 429, 1991, 3265, 18095, 434, 1281, 435, 1285, 436, 2504,
+		591, 750, 2633, 3630, 3526, 92, 3276, 517, 730, 448, 450, 796, 18260, 453, 2164, 454, 3693,
+		584, 1848, 2162, 465, 18270, 467, 3765, 468, 407, 470, 446, 3460, 3594, 3987, 3965, 2804,
+		668, 1097, 720, 3887, 608, 3885, 670, 3325, 707, 18242, 48, 3599, 4035, 18407, 1862, 3121,
+		1329, 18063, 1104, 3344, 970, 3735, 455, 2490, 3700, 2165, 3697, 2600, 18249, 2314, 3386,
+		2632, 1261, 18273, 1594, 18719, 2691, 3153, 1911, 2299, 2645, 1909, 1717, 2598, 18590,
+		18296, 1879, 1907, 696, 18299, 2590, 18624, 3360, 1714, 1721, 1399, 2517, 2688, 18739,
+		18307, 1744, 3529, 18570, 18098, 3236, 3042, 660, 1895, 3074, 1655, 953, 2280, 18782, 3753,
+		978, 1768, 981, 18348, 18353, 982, 2021, 58, 18371, 1608, 18162, 2007, 960, 734, 987, 988,
+		2425, 18437, 989, 990, 18405, 930, 1445, 994, 2609, 581, 1779, 3232, 3399, 3385, 998, 3698,
+		2224, 1652, 479, 1003, 532, 567, 18536, 1005, 582, 18451, 635, 1950, 3731, 488, 1010, 3710,
+		18467, 3981, 2124, 18465, 514, 1015, 18189, 1017, 18378, 1979, 1460, 1020, 64, 2103, 1565,
+		2098, 1022, 18509, 2885, 1024, 1027, 1651, 1031, 1030, 1032, 1417, 18538, 11, 2014, 2010,
+		18545, 1039, 3226, 18354, 2019, 2032, 1041, 1046, 1048, 18555, 1967, 18559, 3447, 1745,
+		4059, 3364, 807, 1058, 2606, 4049, 2624, 18122, 18591, 1063, 921, 1176, 1653, 18535, 18637,
+		1079, 2612, 18634, 1567, 18394, 2471, 2611, 1083, 2629, 1084, 2423, 18305, 910, 1087, 2050,
+		1089, 2719, 1142, 18210, 1272, 3560, 18406, 789, 2872, 2104, 1291, 4045, 1746, 18661, 3537,
+		2726, 42, 3483, 977, 2396, 3816, 18411, 572, 431, 18696, 3734, 18836, 2759, 18161, 2276,
+		2077, 1931, 2128, 2336, 1710, 2766, 2346, 1411, 2768, 2550, 18729, 2635, 857, 1839, 2545,
+		2774, 2340, 2541, 1699, 62, 2775, 2082, 2776, 2777, 2352, 2106, 2389, 18767, 2076, 949,
+		2779, 2780, 2094, 18181, 2091, 2785, 613, 2788, 968, 2790, 2792, 2739, 516, 624, 547,
+		18315, 814, 2799, 2801, 2803, 484, 1750, 2323, 3999, 2806, 2807, 882, 2815, 119, 557, 3744,
+		2131, 18480, 3397, 952, 3673, 946, 2825, 18660, 2826, 2144, 100, 2154, 2832, 1507, 2152,
+		956, 2249, 108, 1980, 2834, 2837, 1120, 2840, 1255, 973, 1124, 2179, 2182, 817, 1308, 2847,
+		2202, 890, 2848, 2851, 2853, 883, 18060, 1498, 2515, 2305, 2615, 18542, 72, 1186, 2298,
+		2865, 914, 558, 1096, 525, 3471, 2877, 0, 3469, 2884, 2234, 2889, 2893, 2895, 1238, 2901,
+		10267, 2087, 1304, 18165, 2907, 3944, 972, 2383, 911, 18584, 2668, 1818, 971, 2667, 121,
+		1292, 18518, 2232, 2619, 3647, 1739, 1202, 2916, 3521, 1276, 1316, 963, 1163, 1314, 2588,
+		2457, 915, 2923, 1529, 3389, 1310, 2089, 18223, 18630, 926, 1200, 962, 4076, 1327, 959,
+		2927, 4, 1530, 3, 7, 24, 3736, 967, 2, 2934, 1344, 1393, 2935, 1352, 1830, 2634, 29, 106,
+		947, 2279, 3354, 2278, 954, 1377, 3269, 12, 13, 3669, 23, 3280, 2950, 18207, 489, 2953,
+		510, 711, 545, 2961, 18316, 2636, 2309, 2973, 18379, 3211, 18334, 18705, 3677, 3463, 2319,
+		413, 2976, 2391, 1434, 2469, 98, 76, 2572, 2368, 2639, 2991, 2992, 18744, 1485, 2999, 1891,
+		1217, 1516, 3004, 1503, 3007, 714, 1528, 2356, 3009, 3011, 2608, 28, 560, 3212, 1560, 3015,
+		1575, 809, 3018, 794, 712, 18478, 2398, 3029, 1585, 2518, 4057, 1844, 2408, 1998, 1659,
+		2412, 3031, 2411, 1677, 3307, 3972, 3967, 1601, 1, 3835, 402, 15, 14, 21, 3718, 3040, 929,
+		965, 3562, 897, 25, 3505, 2442, 2445, 3408, 399, 3751, 9, 18215, 27, 3714, 18325, 1646,
+		966, 6, 964, 3510, 3513, 4553, 4555, 4556, 3692, 10367, 4575, 4573, 4576, 4574, 10368,
+		4618, 10377, 10375, 4617, 4616, 4712, 4711, 4710, 4713, 4709, 3691, 3570, 4836, 4837, 2841,
+		422, 5278, 722, 3866, 4577, 742, 4619, 10378, 835, 4620, 10397, 5003, 10755, 9984, 779,
+		4578, 4621, 4714, 10398, 320, 9985, 10362, 4557, 4580, 10369, 4581, 18186, 4622, 4715,
+		4717, 4716, 4838, 4839, 5279, 6056, 4554, 10264, 728, 10363, 4558, 4583, 4582, 4840, 3320,
+		5280, 3136, 6513, 12100, 424, 423, 7476, 13424, 13425, 9986, 4559, 425, 4624, 442, 5281,
+		4560, 10370, 4584, 4625, 4627, 10379, 4628, 4626, 1789, 4841, 3542, 5282, 5283, 5657, 9987,
+		4585, 4629, 832, 4842, 4844, 4843, 5004, 5284, 5285, 5658, 5659, 6057, 13426, 18362, 17543,
+		4561, 729, 1770, 426, 4631, 4630, 10380, 4632, 4638, 4633, 4634, 10382, 10381, 4636, 4637,
+		4635, 3877, 4720, 4721, 4722, 4723, 4719, 4726, 10404, 10400, 10403, 4727, 4743, 10402,
+		4724, 4725, 4718, 10399, 10401, 3525, 427, 2954, 4857, 10440, 4854, 4858, 10450, 10438,
+		4855, 10442, 1766, 4856, 4859, 4845, 10441, 4860, 10445, 1793, 10449, 10446, 10443, 4846,
+		4848, 4861, 4850, 10435, 4853, 4851, 4852, 10451, 10448, 10439, 4849, 836, 10436, 4847,
+		328, 10444, 10447, 329, 1783, 10437, 10557, 5026, 5013, 1654, 10558, 5010, 5028, 428, 5018,
+		3880, 5017, 10546, 5021, 5016, 10551, 10559, 10555, 1796, 5019, 2262, 5022, 5007, 5031,
+		10548, 1773, 10767, 5005, 5027, 5006, 5014, 5015, 10553, 10549, 5020, 5012, 10545, 5008,
+		10556, 5029, 5032, 5011, 5024, 5030, 5009, 10554, 5025, 10560, 10547, 5023, 10550, 10552,
+		5299, 10769, 10781, 5291, 10782, 5286, 5296, 2491, 5289, 10763, 5297, 10762, 10766, 10773,
+		10771, 5305, 5300, 10757, 10759, 5302, 5290, 10760, 10772, 10779, 5295, 10765, 10758, 5294,
+		10761, 5298, 10764, 1774, 5293, 5288, 5303, 10774, 5304, 10778, 10776, 10780, 5301, 10768,
+		10756, 10770, 5292, 10775, 5287, 10777, 1792, 330, 18078, 5674, 5662, 2707, 11116, 11124,
+		935, 5661, 5669, 5681, 11130, 11128, 11123, 430, 5663, 11129, 11119, 5677, 5668, 5676,
+		11114, 11126, 2749, 11117, 5672, 11118, 1800, 11113, 5679, 5666, 5675, 5665, 11115, 11120,
+		11127, 5673, 5670, 11122, 5678, 11125, 11121, 5667, 5680, 5671, 5664, 5660, 2479, 1801,
+		11131, 6083, 6061, 833, 6077, 3814, 6082, 11571, 11570, 11575, 11576, 6064, 1243, 6073,
+		3812, 6086, 6074, 432, 11564, 6067, 11565, 18080, 6088, 6079, 6058, 6059, 11584, 6530,
+		6072, 6071, 11566, 6075, 12116, 6066, 11577, 6081, 6080, 6070, 11569, 11578, 11563, 6069,
+		11579, 6078, 11567, 6060, 6063, 6062, 11580, 6076, 6065, 6085, 6087, 11574, 6084, 1797,
+		11582, 11568, 11583, 11572, 1605, 11581, 11573, 785, 6068, 12124, 12112, 6518, 12117,
+		12123, 6517, 12110, 6521, 12114, 12107, 6519, 12111, 6524, 6529, 12128, 12106, 6525, 3819,
+		12127, 1802, 6520, 12113, 6516, 12108, 12104, 12118, 12105, 12103, 12115, 12121, 12122,
+		6522, 3275, 12744, 12119, 12102, 12120, 1798, 6532, 12125, 6531, 12101, 12109, 12126, 6527,
+		6526, 6523, 6528, 6514, 6515, 7010, 1799, 12747, 12740, 7007, 12748, 433, 12738, 12741,
+		7006, 12742, 18548, 7009, 12746, 12736, 12735, 7011, 7012, 7008, 7013, 12734, 12745, 12743,
+		12737, 7005, 12739, 3838, 7483, 7477, 13430, 7486, 13436, 13439, 7479, 7480, 13433, 7478,
+		13442, 7484, 13443, 13438, 7485, 834, 13427, 7482, 13428, 13437, 13435, 13431, 7481, 13429,
+		7487, 13434, 13441, 13432, 13440, 1790, 7934, 7931, 1542, 7932, 14124, 14116, 7930, 7927,
+		14117, 3993, 7929, 14120, 14122, 2114, 14129, 18419, 14128, 14123, 7926, 14115, 7924,
+		14118, 7935, 14121, 14125, 7928, 7925, 14127, 14126, 7933, 14119, 2610, 8314, 14739, 14736,
+		8315, 8313, 1803, 14743, 14740, 14733, 8312, 18094, 8316, 14734, 8311, 8319, 14737, 14738,
+		8317, 8318, 14744, 14741, 14742, 1791, 18096, 8707, 15379, 8704, 15376, 8706, 8708, 15377,
+		8705, 15378, 15375, 9003, 15948, 9004, 15946, 15947, 15945, 15944, 15949, 9002, 1767,
+		16414, 16415, 14735, 16413, 9005, 9451, 16779, 16780, 9694, 9695, 17365, 17364, 9778, 9777,
+		17694, 4562, 4586, 4640, 4639, 4729, 4728, 4864, 4863, 4865, 4862, 5034, 5033, 5035, 5307,
+		5306, 5308, 331, 5682, 4264, 11585, 4265, 6533, 4267, 4266, 12749, 18102, 4268, 7936, 4269,
+		4563, 4641, 4866, 5309, 18104, 4564, 4644, 4642, 4643, 4867, 332, 5036, 5311, 5310, 5312,
+		2067, 6089, 8709, 9988, 437, 10376, 10269, 4730, 4731, 4868, 10561, 5684, 5683, 11587,
+		11586, 6534, 9989, 4645, 10383, 3629, 333, 10783, 5685, 6092, 6090, 6091, 3632, 1815, 8710,
+		9990, 4732, 812, 4869, 10452, 18116, 18125, 3023, 18062, 5037, 5038, 18117, 10562, 10784,
+		5313, 10785, 1816, 11589, 11590, 6095, 18610, 11591, 18119, 11588, 6096, 6094, 6093, 11592,
+		18120, 12129, 18121, 3222, 18123, 12750, 13444, 14130, 439, 8320, 8711, 15380, 18647, 4565,
+		4579, 18126, 334, 18128, 6535, 7015, 7937, 18427, 10364, 4646, 4735, 4733, 4734, 440, 5314,
+		3783, 4566, 4567, 10266, 4587, 441, 4647, 4648, 4649, 10454, 4736, 18133, 10405, 4873,
+		10455, 4871, 4872, 10453, 4874, 4870, 10563, 5633, 10564, 3875, 4058, 10565, 5040, 5039,
+		18134, 382, 5043, 5041, 5042, 5320, 5319, 10790, 10787, 10788, 3763, 10786, 5321, 5317,
+		5316, 5318, 5315, 18135, 5322, 5687, 11132, 10789, 5692, 11133, 5688, 5691, 5690, 5689,
+		5686, 90, 11595, 6099, 11598, 6097, 18137, 1825, 11594, 6100, 6098, 6101, 11596, 11597,
+		3815, 11593, 11599, 706, 7019, 6536, 12130, 12132, 12131, 12133, 6537, 7016, 3842, 7017,
+		7018, 7489, 13446, 443, 13445, 13447, 13448, 7490, 7488, 14131, 14132, 7939, 7938, 14470,
+		8321, 8322, 8323, 8325, 14745, 14746, 8324, 3253, 444, 8712, 8713, 18138, 16781, 17367,
+		17366, 17695, 3883, 4568, 4738, 4737, 335, 4875, 1826, 10456, 336, 5045, 5046, 5044, 5047,
+		10566, 10567, 18136, 18139, 5324, 10791, 5323, 11134, 5696, 11135, 5695, 18140, 5693, 5694,
+		3733, 11600, 18310, 3817, 6538, 12135, 6541, 12134, 6725, 6540, 6539, 7022, 7021, 7020,
+		7491, 18143, 1828, 7494, 7495, 7493, 7492, 14133, 14134, 14748, 8326, 14747, 8714, 15950,
+		9006, 16782, 9598, 9991, 4588, 4650, 10384, 4651, 4652, 2995, 4739, 4740, 4876, 10568,
+		10792, 10793, 5697, 11601, 6543, 6542, 12752, 12751, 4569, 4653, 4741, 6544, 10365, 10406,
+		4742, 10458, 4878, 4877, 10457, 5048, 825, 445, 6102, 3829, 12136, 7496, 14135, 7940, 3996,
+		18153, 15381, 17111, 9992, 4654, 337, 10794, 11136, 6547, 6545, 6546, 4570, 18127, 4589,
+		4346, 4657, 4656, 4655, 4745, 4744, 10407, 10459, 338, 5328, 5325, 5327, 5326, 5698, 7023,
+		4571, 757, 4658, 661, 4747, 4746, 10569, 3126, 5329, 3696, 9993, 10385, 18166, 4749, 4748,
+		4879, 4880, 10570, 5049, 18167, 5050, 5330, 5331, 5332, 5699, 11137, 3699, 18169, 6103,
+		10366, 4659, 10460, 10571, 10572, 10795, 18174, 10796, 11139, 11138, 11141, 11140, 5700,
+		12137, 6105, 11602, 6104, 18176, 3423, 12753, 7024, 18361, 12754, 18364, 447, 3983, 18177,
+		14136, 7941, 18178, 449, 8327, 16783, 9994, 10386, 4750, 18183, 451, 6548, 18184, 4572,
+		4590, 4661, 4660, 1515, 4662, 1847, 339, 3444, 5334, 5333, 5335, 340, 18188, 3832, 5701,
+		6106, 18191, 15382, 9278, 4591, 4752, 4767, 4762, 4757, 4756, 4763, 4761, 4754, 4768, 4755,
+		4751, 4766, 4765, 4764, 4753, 4760, 18194, 1331, 4759, 4769, 4758, 18237, 4886, 4892, 4888,
+		4894, 10461, 4891, 4881, 4884, 4887, 4883, 4890, 4893, 4882, 4885, 4889, 4895, 18196, 2496,
+		3592, 10581, 10578, 3558, 5063, 10579, 5051, 5053, 5077, 5072, 18571, 452, 10586, 10580,
+		5055, 5057, 10585, 5064, 10575, 5076, 5078, 5052, 5069, 18216, 10573, 5075, 3582, 5060,
+		838, 5070, 5071, 10574, 5068, 5066, 5067, 5073, 10582, 5054, 5074, 10584, 5062, 5059,
+		10577, 5058, 10587, 5061, 5065, 3705, 99, 3633, 5056, 10583, 10576, 10813, 10812, 5353,
+		10815, 10817, 10806, 10809, 10816, 5354, 3825, 3084, 10803, 10807, 18201, 10811, 11156,
+		5349, 11160, 5336, 10808, 5337, 5350, 5343, 5339, 10804, 5342, 5347, 5356, 10805, 10798,
+		5341, 10799, 10801, 5344, 2732, 5346, 10797, 10802, 5355, 5351, 10810, 5357, 18205, 5348,
+		10800, 5345, 456, 3618, 5340, 5338, 3603, 10814, 5352, 3881, 3087, 11155, 11143, 11158,
+		457, 18206, 11145, 5708, 5722, 5704, 5721, 5713, 5718, 5702, 11144, 11150, 5717, 11162,
+		5719, 1125, 5709, 458, 11152, 11149, 5707, 459, 11142, 5720, 11157, 5712, 11159, 5723,
+		5703, 5714, 5711, 11147, 5715, 11153, 11154, 5710, 5716, 5706, 1756, 645, 1853, 5705,
+		11146, 460, 11151, 3882, 11161, 11622, 6119, 11604, 528, 11609, 6112, 6123, 11607, 6107,
+		6117, 6122, 11614, 6118, 6121, 461, 11617, 6113, 11608, 11620, 11621, 6115, 11619, 6111,
+		6126, 11611, 6109, 2581, 11624, 11612, 11616, 6114, 6125, 11613, 6120, 11618, 11625, 12152,
+		1972, 11623, 6127, 6108, 11615, 11606, 3635, 6116, 11605, 462, 11610, 3634, 463, 472,
+		11603, 6124, 464, 12144, 18198, 6567, 12158, 6565, 6562, 12149, 6558, 12153, 6569, 12143,
+		12148, 12147, 6110, 6564, 12156, 12159, 12140, 7042, 466, 12160, 6570, 6556, 6553, 12151,
+		6550, 18214, 12157, 18148, 6557, 12161, 12141, 12155, 6560, 12142, 12145, 12150, 18277,
+		6561, 6559, 6571, 6566, 18204, 6554, 6706, 6555, 12146, 6568, 6563, 12154, 6552, 3625,
+		6551, 2163, 2318, 3604, 3810, 12138, 12139, 12759, 556, 7025, 7028, 12770, 7048, 12773,
+		7026, 12766, 7032, 7038, 7313, 18218, 7036, 12763, 7049, 7029, 7037, 12771, 13462, 12776,
+		12764, 477, 12755, 12768, 12762, 7035, 12774, 7031, 7051, 7044, 7033, 7030, 7041, 12775,
+		12761, 12767, 12769, 12757, 12772, 7027, 12756, 18219, 7034, 7050, 7046, 12758, 7040, 1578,
+		7047, 7043, 7039, 1851, 12765, 647, 3602, 7045, 3260, 13460, 13456, 3516, 13471, 13449,
+		13458, 7510, 7511, 7503, 13467, 7513, 13454, 13453, 13451, 13469, 7501, 13463, 13452, 7504,
+		13461, 7499, 13457, 13464, 13466, 469, 2956, 13470, 7508, 13450, 7502, 13455, 3614, 7497,
+		7509, 13465, 7505, 7506, 7512, 7500, 4270, 7498, 13459, 3589, 1274, 7507, 3601, 3596,
+		13468, 2959, 7957, 7952, 14151, 14147, 719, 14141, 7946, 7942, 14150, 7943, 14144, 14146,
+		14149, 3595, 7948, 7955, 7949, 14139, 7950, 7951, 14142, 7956, 3853, 14140, 14145, 7947,
+		14138, 7953, 7945, 7944, 14143, 14148, 3564, 7954, 3905, 1013, 3563, 2796, 3585, 14137,
+		8334, 14765, 14762, 3556, 8328, 8340, 8341, 8331, 14760, 8333, 14752, 8339, 18227, 14764,
+		8330, 14766, 8329, 14761, 14763, 8332, 14759, 14753, 14750, 3591, 12760, 14756, 14749,
+		14755, 14754, 14751, 18232, 8336, 18233, 3047, 587, 8335, 3014, 8337, 14757, 8715, 14758,
+		3758, 15391, 15384, 1341, 8727, 15388, 8719, 8723, 15387, 8722, 8718, 8721, 8716, 8726,
+		15389, 15385, 8725, 15383, 8724, 15390, 15386, 8338, 8728, 15392, 8720, 8717, 3095, 3653,
+		3745, 15957, 15959, 9008, 15960, 15958, 15956, 15955, 9010, 15954, 9011, 721, 1666, 3488,
+		15952, 15953, 9007, 9012, 9009, 1759, 15951, 9279, 16418, 16416, 16421, 16419, 16417,
+		16420, 1757, 1665, 570, 2577, 9452, 16786, 16787, 9599, 16788, 16784, 16789, 16785, 9280,
+		672, 1004, 17112, 17114, 9602, 17113, 9601, 9600, 3317, 9603, 17369, 17370, 3545, 9697,
+		9696, 9698, 17368, 17545, 17544, 9779, 9781, 9780, 17546, 9836, 17809, 9877, 17808, 17881,
+		3396, 10371, 4771, 4770, 4898, 4897, 10463, 4896, 10462, 341, 5081, 10590, 10589, 5079,
+		5082, 18240, 10588, 18239, 5080, 18235, 10818, 10819, 5358, 37, 2770, 5724, 18238, 11626,
+		11627, 6128, 6129, 12163, 6572, 6574, 12162, 6573, 12777, 7052, 7514, 7515, 13472, 3437,
+		7959, 7958, 14767, 15394, 15393, 1855, 17941, 4592, 10387, 10408, 10409, 4901, 4905, 10465,
+		4903, 4902, 10464, 4904, 4900, 2110, 4899, 10466, 5092, 891, 5089, 3254, 5085, 10591,
+		18248, 18266, 10592, 18244, 5087, 10594, 5083, 10595, 10593, 5086, 5088, 5091, 5090, 5084,
+		10596, 2957, 3079, 1723, 3976, 5363, 10832, 5365, 5364, 3818, 10833, 5362, 5361, 10823,
+		10822, 10820, 10825, 10824, 10821, 10830, 10831, 10828, 10826, 5360, 3874, 10829, 5366,
+		10834, 2736, 1861, 10827, 5725, 5359, 3230, 2500, 5726, 11167, 1860, 11173, 18408, 5732,
+		11171, 11177, 11168, 11172, 11174, 11176, 11170, 689, 11169, 11164, 11165, 5727, 3286,
+		5729, 5728, 11166, 11175, 3760, 3706, 5731, 97, 2873, 11163, 11637, 11636, 11641, 11632,
+		2498, 11635, 11634, 1100, 11638, 11642, 6130, 6133, 2208, 11633, 11639, 2644, 6132, 11628,
+		5730, 12177, 11640, 11631, 6131, 11630, 2502, 12168, 1859, 3770, 6575, 6579, 12184, 12166,
+		12180, 6580, 12182, 18251, 12188, 12192, 12183, 12171, 12179, 2897, 12193, 12191, 12181,
+		12176, 12169, 12785, 12167, 6584, 12174, 6585, 6581, 12164, 12186, 12172, 18187, 12170,
+		12189, 6582, 18253, 12195, 6576, 6578, 12178, 12173, 6134, 6577, 12175, 12190, 12194,
+		12185, 12187, 2643, 12165, 102, 12780, 12789, 12788, 7060, 12781, 7061, 7059, 2416, 12783,
+		7056, 12787, 18256, 12782, 12784, 12778, 7054, 12797, 12796, 12793, 7053, 7057, 7058,
+		11629, 12790, 7055, 6583, 12792, 12779, 12795, 12794, 2890, 12798, 13486, 12791, 3072,
+		3020, 2055, 12786, 13478, 7525, 7528, 7523, 13477, 13481, 13476, 7517, 7527, 13473, 7521,
+		13480, 7519, 7518, 13483, 7520, 13485, 18258, 13482, 7516, 2951, 7526, 13487, 13475, 13484,
+		13474, 2798, 7522, 1747, 7524, 13479, 13488, 2414, 548, 14159, 7960, 14165, 7965, 14161,
+		14168, 14153, 7967, 7961, 14158, 8342, 14157, 14166, 7962, 7966, 14156, 14162, 14167,
+		14152, 7964, 14160, 14164, 14170, 14154, 14163, 840, 7963, 14169, 78, 14155, 705, 3702,
+		8346, 14769, 8344, 8343, 14771, 14776, 14772, 14775, 8349, 3743, 8702, 8348, 18259, 14768,
+		14774, 8347, 14773, 1226, 14770, 8345, 3201, 3359, 15399, 18017, 15401, 15396, 8730, 15398,
+		8729, 15400, 8732, 15402, 8731, 15395, 15397, 1863, 9016, 15963, 841, 9015, 15964, 9014,
+		15962, 9013, 15961, 9282, 9281, 16790, 16792, 18262, 16791, 9453, 9454, 18261, 9455, 17115,
+		9604, 17697, 17696, 9878, 4593, 4663, 5093, 342, 2811, 2499, 11178, 7062, 7063, 18264,
+		13489, 7968, 14171, 14777, 723, 10388, 343, 438, 10597, 344, 9995, 10835, 11179, 11643,
+		6135, 18685, 16793, 9699, 4594, 4772, 10410, 4906, 4907, 5367, 40, 18271, 6586, 7970, 7971,
+		7969, 4595, 4664, 4666, 4665, 10389, 4667, 4773, 10411, 4774, 345, 346, 4908, 4909, 10467,
+		5094, 10598, 5371, 10836, 5369, 5370, 5368, 11644, 5736, 5735, 5737, 5734, 11180, 5372,
+		5733, 6136, 6137, 6138, 12196, 7064, 12799, 6587, 3247, 7529, 7973, 7972, 14172, 8350,
+		8733, 16422, 17547, 17698, 4596, 4775, 3567, 4776, 10473, 4911, 4914, 10471, 10469, 4913,
+		10472, 10474, 10468, 4916, 4915, 4912, 4910, 10470, 2681, 5105, 5102, 10603, 10606, 10605,
+		5096, 5104, 2530, 5101, 10602, 10600, 5100, 2295, 5095, 5098, 3224, 10601, 10608, 10604,
+		5099, 5103, 5106, 10599, 10607, 5097, 3070, 5377, 5385, 2593, 10848, 10841, 5386, 10852,
+		10837, 10844, 3820, 1870, 5376, 10838, 5374, 10845, 10849, 5373, 937, 10850, 10843, 10846,
+		2637, 5388, 5379, 10853, 10851, 18280, 5384, 5382, 10842, 5381, 10840, 10839, 5380, 5378,
+		5387, 5383, 5375, 10847, 5739, 18285, 5746, 11194, 5738, 11186, 11182, 11198, 11181, 5741,
+		11191, 5744, 5747, 5742, 11195, 5745, 2724, 6145, 11201, 11183, 2689, 2297, 11185, 11192,
+		11196, 11200, 11197, 11190, 11193, 3475, 18292, 11187, 5749, 11189, 11188, 11199, 5740,
+		11184, 5748, 2260, 5743, 6151, 11652, 2514, 6150, 2173, 11650, 6139, 1874, 6144, 11649,
+		11646, 11651, 6140, 11645, 884, 6143, 6141, 11653, 6142, 6146, 1882, 6147, 18276, 6149,
+		3314, 6148, 2176, 2697, 1869, 11647, 11648, 11654, 12203, 6588, 12813, 12202, 12221, 6594,
+		2053, 12219, 6593, 6589, 12228, 12212, 12215, 1613, 6597, 12225, 12217, 6590, 6598, 12222,
+		12220, 12205, 12226, 12214, 12209, 684, 12199, 12227, 12211, 12198, 2169, 6596, 12216,
+		12229, 12213, 12201, 12206, 12197, 2684, 6595, 18295, 12210, 12207, 6591, 12200, 1776,
+		12224, 6592, 1921, 12208, 12204, 2696, 12223, 2685, 7065, 12806, 2602, 12803, 12820, 12808,
+		12821, 1872, 7067, 1884, 2682, 12817, 12814, 2248, 18667, 12815, 12818, 12822, 12218,
+		12824, 13509, 18621, 7068, 12825, 12801, 12811, 18300, 12816, 1864, 7066, 7069, 12823,
+		12826, 12805, 12802, 18420, 2597, 12804, 47, 12809, 12807, 7070, 12819, 18294, 12810,
+		12812, 12800, 13497, 13495, 7538, 7536, 13505, 13496, 13506, 13502, 13494, 13508, 13493,
+		13500, 7535, 7534, 7533, 13498, 13503, 7530, 7537, 1482, 13491, 13501, 13490, 13499, 7531,
+		13504, 13492, 7532, 13507, 2513, 1873, 50, 18302, 14176, 7978, 7977, 7979, 14188, 14178,
+		14184, 14173, 14187, 14186, 14190, 14183, 7974, 14182, 7980, 2253, 14175, 7975, 14189,
+		7976, 14177, 14180, 14185, 14179, 14174, 3739, 1312, 14181, 14779, 8354, 14782, 14781,
+		14784, 14786, 18020, 8352, 14780, 2405, 14778, 14787, 14785, 14783, 14789, 14788, 8356,
+		8351, 8353, 8355, 3694, 14790, 15409, 2366, 15408, 15407, 859, 15410, 15403, 15404, 15412,
+		15405, 8734, 15414, 4077, 15413, 15406, 15968, 9019, 15966, 15971, 15970, 15411, 9018,
+		2251, 15969, 15965, 15973, 15972, 9017, 15967, 8735, 9283, 16423, 16794, 16795, 16796,
+		9605, 1533, 9606, 1923, 17116, 18749, 17372, 17371, 1538, 17548, 17549, 17699, 17810, 1904,
+		4597, 4598, 4599, 4668, 4777, 10475, 4917, 4918, 5109, 5110, 5108, 5107, 5389, 10854, 5391,
+		5390, 10855, 347, 710, 5750, 6152, 11655, 3548, 12231, 6599, 7072, 12230, 7071, 1107, 7981,
+		18311, 14191, 8736, 9020, 15974, 18313, 9607, 9782, 9996, 10412, 18112, 4778, 10413, 4921,
+		4919, 4920, 4922, 5112, 5111, 10609, 5113, 3650, 10610, 10856, 10857, 904, 5392, 5394,
+		5396, 5393, 5397, 5395, 18324, 348, 349, 5754, 5751, 5753, 5755, 5752, 11656, 11202, 18430,
+		11658, 11657, 6158, 6154, 6155, 6157, 6159, 6156, 51, 6161, 6160, 6604, 12233, 12232, 6603,
+		3811, 6602, 6601, 6605, 6600, 12832, 12829, 7074, 12828, 12833, 7076, 12831, 7073, 7075,
+		12830, 18318, 13510, 18759, 13511, 13512, 45, 7982, 7990, 14192, 7984, 7988, 14193, 7989,
+		7985, 7986, 7983, 7987, 8359, 12827, 8360, 8358, 8357, 15415, 8737, 15975, 15976, 18323,
+		9456, 9608, 4600, 4923, 18265, 5756, 11659, 6162, 18326, 6608, 6607, 6606, 7077, 7078,
+		12834, 7991, 8738, 4601, 10390, 4669, 10414, 18328, 18330, 10476, 4924, 5398, 657, 1896,
+		13513, 3111, 4602, 731, 4670, 10477, 10611, 10612, 5114, 12835, 7079, 13514, 9021, 4603,
+		4623, 4671, 10415, 4779, 5118, 5117, 5115, 5116, 10858, 5400, 5401, 10859, 5399, 18333,
+		3871, 5760, 11203, 5759, 5757, 5758, 6165, 6163, 3772, 11661, 6164, 11660, 6153, 12234,
+		6610, 6611, 2808, 6609, 7992, 14194, 8361, 8362, 14791, 9022, 16424, 16425, 9700, 17811,
+		10373, 4672, 4604, 10417, 10416, 920, 4925, 10479, 10480, 10478, 10481, 18335, 10620,
+		10615, 10617, 10619, 10616, 5122, 10613, 10614, 5119, 5120, 10618, 10621, 5121, 10622,
+		1086, 350, 18670, 10873, 1897, 10867, 10862, 5403, 10870, 10868, 10861, 10874, 10877,
+		10872, 10865, 5405, 10871, 5406, 10866, 10869, 10860, 10864, 10876, 5407, 5408, 10863,
+		10875, 5402, 5404, 18345, 4069, 2709, 11220, 11213, 11219, 11212, 11214, 11208, 11206,
+		11221, 11218, 11204, 3792, 5762, 11216, 11217, 917, 11215, 11207, 11205, 5761, 11211,
+		11209, 11210, 11222, 993, 6170, 18336, 6169, 11662, 6166, 11664, 18337, 6171, 11665, 6174,
+		793, 6172, 11666, 11223, 11668, 6168, 52, 6167, 11663, 11667, 6173, 6613, 6612, 12236,
+		12240, 12242, 6614, 12245, 18341, 6618, 12247, 6620, 1012, 6616, 6624, 18342, 6621, 12238,
+		6615, 12237, 12235, 12249, 12239, 6617, 12248, 6622, 12244, 12243, 6623, 12241, 6619, 3350,
+		12250, 2725, 12246, 12850, 12857, 12846, 7082, 12842, 12858, 12836, 12855, 12853, 12847,
+		12856, 12849, 974, 12841, 12859, 12839, 12852, 12837, 12860, 18343, 7083, 975, 12854,
+		13517, 12840, 7080, 12844, 7081, 12843, 12848, 12845, 976, 3988, 12851, 3297, 13521, 13524,
+		13516, 13518, 13523, 13522, 7539, 12838, 13520, 7540, 2744, 13515, 13519, 3747, 14201, 923,
+		14208, 14199, 899, 14206, 14197, 14205, 14209, 14196, 14200, 14195, 7993, 3434, 14198,
+		7994, 14204, 14203, 14202, 18338, 14207, 3785, 14795, 14797, 8364, 14798, 14793, 14792,
+		14802, 14800, 8363, 14803, 14794, 14799, 14801, 14796, 979, 15419, 15424, 15418, 15423,
+		3440, 15416, 15426, 15422, 15427, 15421, 15417, 15425, 15428, 15420, 18393, 15977, 9026,
+		980, 9024, 9023, 9025, 16426, 2375, 16692, 16797, 17117, 17118, 9609, 17373, 9701, 17374,
+		17551, 9784, 9783, 17550, 9837, 18346, 17700, 9997, 4605, 4926, 10482, 10623, 5264, 6625,
+		4606, 4782, 4781, 4780, 5123, 6175, 13525, 4607, 4608, 4609, 4673, 3876, 5763, 11224, 7084,
+		4610, 10391, 4783, 4784, 10418, 4927, 18366, 10624, 3113, 5124, 10625, 5414, 3821, 10879,
+		5412, 5411, 10878, 5409, 10880, 5410, 5413, 5764, 5766, 11228, 11225, 11226, 11227, 11229,
+		5765, 11670, 11669, 6177, 6176, 18355, 6628, 12252, 6627, 6629, 6626, 7086, 12251, 7087,
+		12862, 983, 7088, 12861, 7085, 1899, 13528, 13530, 7541, 13529, 13527, 13526, 14212, 7999,
+		7997, 7998, 14210, 14211, 7995, 14807, 14805, 1898, 8366, 14806, 8367, 8365, 7996, 15431,
+		15429, 15430, 14804, 15979, 9027, 15978, 16427, 16428, 15432, 16798, 4611, 4785, 4929,
+		10483, 4928, 5415, 7542, 18358, 4674, 4786, 5767, 7089, 9999, 10419, 10420, 18309, 10484,
+		351, 5126, 10629, 10627, 5127, 10626, 10628, 10630, 5125, 843, 5419, 5420, 5417, 844, 5416,
+		11233, 5418, 5768, 11232, 11234, 11231, 11235, 5769, 6180, 11671, 11673, 6178, 11674, 6179,
+		11672, 11230, 12253, 12256, 12257, 12254, 6633, 6632, 6630, 6631, 12255, 984, 6634, 7091,
+		7092, 7093, 13531, 13533, 13535, 7544, 7543, 7090, 13534, 13532, 1903, 14216, 3607, 14214,
+		14220, 8000, 14221, 14218, 8001, 14215, 14213, 14219, 8369, 14809, 14217, 8371, 14810,
+		8370, 8373, 14811, 8368, 8372, 15437, 15435, 15434, 15436, 15433, 9458, 17119, 17120,
+		17375, 17552, 9912, 18367, 5421, 5128, 1438, 18369, 5770, 18370, 18372, 4612, 4675, 4787,
+		10485, 5129, 10631, 11236, 5771, 8002, 4613, 1917, 18375, 1918, 4930, 7545, 4614, 4676,
+		4677, 4789, 4788, 10486, 4931, 10632, 5130, 10882, 10883, 10884, 985, 5422, 5423, 10881,
+		5424, 5772, 11237, 11676, 6181, 11675, 6635, 12258, 6636, 12259, 18381, 941, 7094, 13536,
+		14223, 14222, 8003, 8374, 14812, 8739, 15438, 9028, 2284, 9785, 17702, 10001, 732, 10885,
+		11238, 6637, 12863, 7546, 2727, 9284, 10002, 5132, 18386, 5131, 5773, 11677, 6639, 6911,
+		6640, 6638, 7095, 14224, 8004, 8375, 18389, 10374, 10487, 10634, 5133, 10633, 5134, 18132,
+		5428, 10888, 10887, 5427, 5425, 5426, 10886, 986, 5775, 11239, 5778, 5774, 5780, 5776,
+		5777, 5779, 6184, 6183, 6182, 12261, 6641, 6644, 6642, 12260, 6647, 6643, 12262, 6646,
+		6645, 18088, 12865, 12864, 18392, 7098, 7096, 7097, 12866, 7547, 13538, 7548, 13537, 3188,
+		14813, 8377, 14225, 8376, 8005, 3942, 15981, 15439, 9029, 15980, 16799, 17121, 17122, 845,
+		4678, 733, 4790, 10421, 5136, 5138, 10490, 10637, 10636, 10635, 10489, 10488, 4933, 5137,
+		5135, 4932, 1930, 5429, 10889, 18484, 5430, 10642, 10644, 10643, 10890, 10639, 5142, 5140,
+		10638, 10640, 10646, 5139, 10641, 10650, 5432, 10647, 5141, 10645, 10648, 5431, 5433,
+		10649, 10910, 10897, 10895, 10909, 10904, 5785, 5434, 10905, 10908, 5781, 10907, 5435,
+		5440, 5438, 10898, 10893, 10900, 5445, 10911, 5782, 10901, 5783, 5441, 10903, 18440, 11254,
+		5784, 10892, 5442, 5786, 5443, 5439, 5444, 10902, 10891, 10906, 5436, 18402, 10894, 10899,
+		10896, 5437, 11240, 11241, 352, 11251, 11681, 11252, 5792, 11255, 11245, 5791, 11247,
+		11248, 18160, 11250, 5787, 6188, 18018, 11246, 11242, 6189, 11256, 6185, 11679, 11249,
+		11678, 11244, 11253, 5790, 6186, 5796, 6187, 11257, 11680, 5789, 6191, 5795, 5794, 5793,
+		6190, 11258, 6192, 5788, 11243, 1942, 4051, 1927, 6648, 11684, 11686, 11688, 6193, 6199,
+		12265, 11692, 11683, 6650, 12263, 6198, 6196, 11694, 12264, 11685, 6197, 11689, 6200,
+		11691, 6195, 11690, 11693, 11687, 18412, 6194, 6651, 11682, 6649, 4047, 2081, 6652, 12267,
+		12276, 7101, 18414, 6654, 6659, 7102, 12278, 6666, 12868, 12268, 6658, 6661, 6656, 12266,
+		12280, 12283, 12883, 12281, 12872, 6657, 6664, 6668, 12275, 12867, 12279, 6653, 12869,
+		12282, 12871, 12271, 7099, 12269, 12270, 6663, 991, 6662, 12273, 6667, 12277, 6660, 12274,
+		18562, 6665, 7103, 7100, 12870, 2914, 12272, 6655, 3093, 3016, 4056, 7108, 7112, 12874,
+		7553, 7110, 12878, 7114, 13539, 12880, 7555, 7106, 7109, 12881, 3952, 12882, 12888, 7116,
+		7556, 12884, 12889, 12877, 7566, 2429, 7557, 7115, 12875, 12890, 2512, 7565, 7113, 7550,
+		12887, 7117, 12879, 12873, 7107, 12876, 12885, 3754, 7549, 7554, 7104, 12886, 7552, 798,
+		7105, 7564, 14227, 13550, 13542, 14226, 13546, 13548, 3830, 13545, 7563, 13544, 7567,
+		14242, 992, 7562, 8007, 13551, 14228, 18423, 7560, 13543, 13547, 8006, 7551, 13540, 13541,
+		8008, 7559, 7561, 7558, 13549, 2426, 14232, 14233, 14238, 8382, 14245, 8014, 3971, 8013,
+		14240, 18441, 8381, 14229, 8012, 18319, 14244, 8010, 8011, 18421, 14241, 14830, 8379, 7111,
+		14243, 8386, 14235, 8380, 3977, 8385, 14230, 14234, 14231, 14237, 8015, 8378, 8009, 14816,
+		14239, 8384, 3974, 8387, 14236, 18426, 8383, 14815, 18429, 14820, 8743, 14814, 15451,
+		14829, 8391, 8389, 8741, 14826, 14822, 8395, 3313, 15442, 1941, 1940, 8393, 14821, 4048,
+		15440, 14825, 14828, 14819, 8394, 8388, 15441, 8742, 14827, 8390, 8392, 14824, 8396, 14823,
+		14818, 14817, 8740, 14831, 15444, 15983, 8745, 995, 15450, 996, 15448, 15984, 8746, 15449,
+		1936, 15446, 9031, 15982, 15443, 15445, 9032, 8748, 9030, 8747, 9034, 15447, 8744, 997,
+		18436, 18433, 16437, 16429, 1510, 15990, 16430, 15986, 3982, 9285, 15988, 15987, 9033,
+		15985, 15989, 16438, 16434, 16436, 16431, 16432, 16435, 16433, 9459, 9462, 9461, 9460,
+		9610, 17123, 9611, 16800, 9702, 17376, 9703, 9786, 9838, 17703, 17704, 17705, 17986, 4679,
+		10422, 4791, 4935, 4936, 4934, 4937, 5144, 5143, 10912, 5447, 5446, 11695, 6669, 6670,
+		7118, 13552, 7568, 7569, 13554, 13556, 13555, 14247, 14246, 8016, 14248, 999, 14832, 8397,
+		8749, 9035, 9286, 9036, 4680, 782, 3979, 10651, 10913, 5449, 5448, 5450, 5797, 11259,
+		11260, 11696, 6201, 6671, 7119, 12891, 4681, 735, 4615, 4682, 10423, 4795, 4794, 4792,
+		4793, 4940, 10497, 10499, 4939, 10491, 10492, 10498, 10494, 10496, 4938, 10493, 10500,
+		10495, 3794, 5151, 5160, 5158, 10661, 10658, 10666, 5156, 10667, 5149, 10664, 5155, 10660,
+		10659, 5153, 10665, 5154, 5451, 5148, 10662, 3914, 10652, 5145, 3856, 5164, 10657, 10663,
+		5150, 5152, 10653, 10654, 10655, 1000, 5163, 5157, 5162, 10656, 5161, 5147, 5146, 5159,
+		3616, 1962, 3978, 5465, 10925, 10918, 5460, 5479, 10914, 10921, 10923, 10926, 5474, 10922,
+		10915, 5472, 10919, 10927, 5457, 10924, 5467, 5466, 10917, 5455, 123, 5456, 3146, 5454,
+		18445, 5478, 5470, 5464, 5452, 10920, 5463, 5453, 5471, 5480, 11261, 5468, 10916, 5458,
+		5461, 5462, 18447, 5476, 5477, 5475, 5469, 5473, 5459, 5798, 18072, 1001, 11267, 5810,
+		5802, 5804, 5809, 11279, 5807, 11697, 6202, 5812, 11264, 11272, 5808, 11271, 11268, 11276,
+		11278, 5801, 5805, 5811, 6204, 11273, 5803, 5814, 11266, 5806, 6203, 5800, 11263, 11270,
+		11262, 11265, 11269, 11698, 5813, 11274, 11275, 11277, 5799, 1002, 6218, 11710, 6216, 6217,
+		11700, 11716, 3727, 6207, 1958, 12284, 11718, 11712, 3795, 11703, 1955, 11721, 11706, 6213,
+		12307, 11709, 6215, 6206, 11723, 11711, 6209, 11704, 11701, 11702, 6210, 11717, 11724,
+		6212, 11708, 11707, 6220, 6219, 6205, 6211, 6214, 11720, 11714, 6208, 11699, 11722, 11713,
+		11715, 11719, 3985, 12285, 6679, 6699, 6698, 6687, 18449, 12303, 12299, 12296, 12314, 6682,
+		6674, 12301, 7135, 6678, 12309, 566, 6700, 6697, 12305, 12288, 3850, 6696, 12311, 12287,
+		6685, 6689, 12310, 12297, 6690, 6684, 12286, 18443, 7121, 12313, 12295, 6695, 12298, 12312,
+		6694, 12892, 6675, 12293, 6680, 6691, 6686, 12302, 12292, 12290, 12308, 6672, 6692, 6676,
+		7120, 12306, 6677, 6673, 6688, 6683, 6681, 12294, 6693, 12291, 12304, 12300, 12894, 12893,
+		3623, 12289, 12910, 7123, 1355, 12916, 12899, 12913, 13557, 7126, 12917, 12920, 7125,
+		12902, 12919, 12918, 7127, 12895, 7122, 7130, 1959, 7128, 12915, 12905, 7132, 12922, 12914,
+		12923, 7140, 7138, 12912, 12911, 3551, 12909, 12903, 7131, 1006, 7129, 11705, 12896, 3464,
+		13587, 12897, 7124, 7137, 13559, 7133, 7134, 12898, 12921, 13558, 12906, 12901, 7136,
+		12907, 12904, 18453, 7141, 1007, 3622, 3624, 7584, 1008, 13562, 13581, 13586, 13574, 7580,
+		13591, 7577, 13560, 13561, 7570, 7579, 13568, 7582, 7583, 13569, 13582, 13588, 7578, 7572,
+		13567, 13563, 13590, 13572, 13573, 13564, 13584, 13575, 13585, 13577, 7573, 14249, 7576,
+		7574, 13589, 13580, 13576, 626, 13565, 8026, 13579, 7581, 13571, 18452, 13570, 18466, 1964,
+		7575, 7571, 14270, 13583, 13578, 13566, 14265, 14250, 14261, 14268, 8024, 7139, 14266,
+		8019, 8018, 14273, 1009, 14251, 14264, 14252, 14263, 8022, 14267, 12908, 14274, 14259,
+		8025, 14846, 8398, 14272, 14271, 8027, 14833, 8399, 14834, 1947, 14255, 14256, 14253,
+		14258, 14254, 14275, 8021, 8400, 8023, 8028, 571, 14257, 14269, 14262, 14837, 8017, 8403,
+		15458, 14842, 14841, 14843, 498, 14840, 8404, 327, 8410, 8407, 8408, 14835, 14838, 14848,
+		8416, 8414, 14839, 12900, 8401, 14845, 14836, 8417, 14844, 8020, 8406, 14260, 8409, 8413,
+		8415, 8412, 8411, 18797, 8405, 14847, 8402, 8418, 527, 1957, 8753, 8754, 15457, 8763, 8760,
+		1011, 8751, 8758, 15459, 8756, 8750, 8757, 15456, 9038, 8752, 8759, 9037, 15455, 15454,
+		8761, 3773, 8762, 15453, 15452, 9039, 15462, 8755, 15460, 493, 9040, 18462, 9045, 15993,
+		15995, 18463, 9042, 1231, 15996, 15992, 537, 15994, 9043, 9046, 15991, 9041, 9044, 9288,
+		15461, 9287, 9293, 16442, 9291, 9292, 16445, 16441, 9289, 16439, 9463, 16443, 16444, 16440,
+		9290, 16801, 16804, 317, 16805, 16803, 16806, 9464, 16802, 2029, 17127, 9613, 17126, 17125,
+		17124, 9612, 9614, 17377, 9705, 9704, 18442, 3675, 17554, 17553, 17556, 9839, 9787, 17707,
+		17555, 17706, 9841, 9840, 9879, 17812, 17882, 4683, 3555, 12924, 15463, 10003, 736, 4941,
+		10668, 5167, 5165, 5166, 10928, 5481, 5815, 11280, 11281, 5816, 11726, 6221, 6222, 11725,
+		18468, 6707, 6703, 12315, 6710, 6709, 6702, 6705, 6708, 6704, 12928, 6701, 7142, 18474,
+		7144, 7145, 12927, 12930, 7143, 12925, 12929, 12926, 1965, 7585, 18473, 13592, 8029, 14276,
+		8764, 8419, 14849, 8420, 8421, 14851, 14850, 14852, 15465, 15464, 15998, 15997, 9047, 9048,
+		16807, 18472, 1016, 4684, 18312, 353, 847, 12931, 7147, 7146, 13593, 16446, 9706, 17708,
+		4685, 6223, 6712, 6711, 12932, 12933, 7586, 14277, 8030, 15466, 4686, 4796, 5482, 10929,
+		11282, 5817, 6713, 12934, 7148, 7587, 14853, 14854, 18476, 15999, 9294, 17883, 4687, 10930,
+		5483, 5818, 3442, 11283, 6224, 11730, 11728, 11729, 6225, 11727, 6715, 6716, 12316, 6717,
+		6714, 12935, 18479, 12936, 13594, 2287, 8032, 8031, 16000, 16447, 16809, 16808, 17128,
+		18482, 10392, 5819, 18483, 4688, 4797, 4943, 4942, 4944, 4945, 10502, 10501, 10669, 5168,
+		10672, 10671, 10670, 10673, 5484, 10936, 10932, 10941, 1018, 5491, 5489, 10937, 10933,
+		10940, 5488, 5495, 10935, 10931, 5494, 10938, 5487, 10939, 5490, 5492, 10943, 10942, 10934,
+		5486, 5485, 5493, 18486, 11288, 11294, 2005, 5825, 5822, 11285, 11290, 18718, 5828, 5820,
+		11289, 5823, 5826, 11292, 5821, 11297, 5824, 1999, 5827, 11286, 11291, 11295, 11287, 11284,
+		11296, 11293, 6233, 6226, 6229, 18489, 6232, 11733, 3189, 6227, 11731, 18492, 6231, 36,
+		6228, 11734, 6230, 1019, 18199, 12320, 6719, 12319, 12321, 6718, 6723, 11732, 83, 12318,
+		12322, 2002, 6720, 12317, 6722, 18494, 6721, 12943, 1997, 12938, 7149, 7153, 7150, 12941,
+		12944, 18496, 7151, 7152, 7157, 13603, 12942, 7155, 12939, 12937, 1153, 7156, 12940, 7593,
+		18403, 13595, 7590, 7591, 7589, 13600, 13599, 13596, 7595, 1995, 13598, 7154, 1021, 13602,
+		13597, 7592, 7588, 7594, 13601, 2694, 8035, 14280, 14279, 14278, 8033, 18230, 8034, 14857,
+		14860, 8423, 18231, 8422, 14861, 14856, 8425, 14858, 18225, 8424, 14855, 3390, 14859, 8770,
+		8767, 15475, 15474, 15473, 15468, 3190, 15469, 8768, 8765, 8769, 15467, 8766, 15470, 15471,
+		15476, 18228, 1976, 15472, 16001, 9050, 16450, 9049, 16448, 16449, 9295, 9466, 16810, 9465,
+		17130, 17131, 9615, 906, 17129, 9707, 17709, 9842, 17813, 17814, 4689, 3597, 4946, 4947,
+		5169, 10944, 5829, 6234, 6724, 6549, 7158, 7159, 7014, 12945, 18506, 7596, 14282, 14281,
+		4690, 4948, 10945, 5497, 4014, 5496, 3202, 11298, 11299, 11735, 11736, 6235, 6236, 1023,
+		6237, 12323, 803, 6726, 7161, 3117, 7160, 13604, 13874, 14283, 15477, 1221, 9296, 9616,
+		4691, 4800, 4801, 4799, 4802, 4798, 4951, 10510, 4950, 4952, 3448, 10505, 10504, 10507,
+		10506, 10509, 4949, 10503, 10508, 18071, 10674, 5179, 10675, 10679, 5178, 10683, 10678,
+		10681, 5171, 5172, 5173, 5174, 5181, 10677, 5176, 5182, 10676, 10682, 5175, 10680, 5177,
+		5170, 5180, 2802, 18514, 18513, 5518, 889, 10947, 5498, 53, 5508, 5509, 5501, 5519, 5503,
+		10955, 5514, 10950, 5504, 10965, 10966, 10958, 10951, 5517, 10963, 5512, 5510, 10964, 88,
+		10962, 10954, 10946, 10953, 5505, 5511, 5499, 10957, 10956, 10948, 2015, 5513, 10960,
+		10949, 5516, 10967, 5500, 5507, 10952, 10961, 5515, 5502, 5506, 10959, 3008, 1025, 18539,
+		11331, 5837, 5850, 18556, 11326, 11316, 5843, 11314, 5836, 11309, 5846, 11322, 11303,
+		11305, 1317, 11308, 11300, 11321, 11337, 5841, 11310, 11328, 11302, 11335, 11336, 11339,
+		11330, 11315, 11323, 5847, 5834, 5842, 5854, 5831, 5833, 2205, 18517, 11307, 5851, 5844,
+		11333, 11304, 5853, 5848, 11313, 5852, 11320, 11312, 5845, 11332, 11324, 5839, 11338,
+		11311, 5835, 11329, 11319, 5840, 11325, 5832, 11301, 5849, 6252, 5838, 11318, 11317, 11306,
+		1743, 11334, 11327, 354, 5830, 1026, 18522, 3986, 2823, 355, 18698, 1749, 11756, 6259,
+		11757, 11745, 6248, 6260, 11738, 18158, 11747, 11755, 2018, 11737, 11764, 6238, 18519,
+		11763, 11758, 11759, 6246, 6257, 11749, 11750, 11760, 11751, 11746, 11740, 11741, 11754,
+		11748, 3426, 18597, 6239, 6243, 11762, 11742, 6255, 6251, 18373, 6254, 6261, 6244, 6256,
+		11753, 6258, 6241, 2355, 6240, 11739, 1028, 11743, 6249, 11761, 11752, 11744, 6253, 6250,
+		6242, 6245, 3150, 1343, 18525, 356, 12344, 12328, 12329, 12333, 12348, 12345, 12338, 12337,
+		6733, 12341, 12356, 12324, 12357, 12343, 12361, 12349, 6732, 12347, 6727, 6748, 6738, 1029,
+		6742, 6741, 12325, 1632, 12354, 12360, 12352, 12359, 12340, 12326, 12358, 12342, 6730,
+		6743, 12351, 6736, 2030, 12350, 12327, 6744, 6746, 12353, 6747, 6729, 12334, 12355, 18272,
+		6735, 6745, 12336, 12346, 12331, 12335, 6740, 12330, 6728, 6737, 6734, 12339, 6247, 12968,
+		6731, 2017, 2653, 18693, 18531, 6739, 2422, 12971, 12993, 12982, 7181, 18809, 7176, 12965,
+		7177, 12981, 12974, 12987, 7173, 12947, 12989, 7163, 12955, 7166, 7165, 7182, 12949, 12983,
+		12984, 7168, 7164, 12963, 7175, 12958, 12985, 7171, 12977, 12990, 12952, 12957, 12951,
+		7170, 12970, 13626, 12953, 7174, 12962, 12986, 7169, 12959, 12956, 12973, 7172, 7162, 7184,
+		12976, 12975, 18534, 18533, 18827, 18725, 12948, 7167, 12988, 12964, 12966, 1033, 12979,
+		12978, 12946, 7178, 7180, 12954, 12961, 12969, 7179, 12960, 12972, 12991, 12980, 3203, 849,
+		3068, 1253, 12992, 12950, 3831, 13646, 7604, 13644, 13623, 13628, 13621, 18543, 13640,
+		13607, 13614, 13638, 13648, 13624, 18130, 13611, 13630, 13635, 13615, 1034, 13632, 13612,
+		380, 13619, 13620, 7606, 13639, 13637, 13608, 13642, 13643, 13645, 7610, 7602, 3102, 12332,
+		13616, 13634, 13617, 7599, 7615, 13641, 7613, 7609, 13606, 7601, 18540, 13609, 7614, 1036,
+		13625, 13605, 2654, 7607, 13629, 13613, 7608, 13622, 7598, 7183, 13631, 12967, 13610, 1966,
+		18530, 13636, 7603, 13633, 7600, 13627, 7611, 13618, 13647, 3837, 7605, 13759, 1038, 7612,
+		2910, 14297, 14295, 14317, 14293, 14311, 7597, 8038, 14289, 14316, 18544, 14294, 14308,
+		8043, 8036, 14314, 1037, 14287, 14313, 1105, 8054, 14304, 14320, 8052, 14296, 8037, 14298,
+		14312, 8046, 14291, 8050, 8040, 14300, 14290, 14284, 4036, 14310, 8047, 14319, 14285, 8044,
+		14306, 8045, 14292, 14303, 14299, 14301, 3872, 8039, 14318, 8053, 14302, 14321, 14286,
+		14307, 8042, 8051, 8049, 14288, 14309, 8048, 1836, 8041, 14305, 3443, 1040, 14315, 18527,
+		14873, 14869, 14865, 14885, 14882, 8428, 3152, 14889, 14872, 8439, 14879, 14868, 14878,
+		8436, 14898, 14895, 14881, 14866, 18549, 18510, 14886, 14888, 8432, 14876, 14871, 14862,
+		8429, 8437, 3421, 14891, 8438, 14863, 14897, 14884, 14890, 8435, 18528, 2713, 14887, 14894,
+		8440, 8434, 14880, 14867, 14899, 14864, 14892, 8431, 2663, 14874, 2222, 14875, 8430, 8427,
+		14870, 8433, 8426, 14893, 14896, 14877, 15490, 84, 2699, 18742, 2148, 15482, 2024, 15478,
+		8783, 8772, 8777, 8773, 15492, 15503, 8771, 15484, 15493, 14883, 15494, 850, 8778, 18552,
+		15509, 8782, 8785, 15480, 8781, 2031, 15507, 15508, 15498, 15497, 15489, 2025, 1235, 15499,
+		15506, 15505, 1283, 8776, 8774, 15491, 15488, 15485, 15504, 8784, 15502, 8780, 8779, 815,
+		15496, 1042, 15479, 15481, 15483, 15501, 15495, 8775, 15486, 15500, 1044, 1043, 15487,
+		2033, 9058, 16019, 9051, 16004, 1045, 16015, 9053, 16022, 16011, 16006, 18356, 16023,
+		16002, 16013, 2403, 9060, 16018, 16024, 16012, 9052, 16014, 16003, 9059, 1047, 16021,
+		16008, 9055, 18554, 16010, 16007, 9061, 16009, 9054, 9057, 16017, 16005, 16020, 1293,
+		16016, 1049, 9298, 16461, 9303, 9304, 3524, 2192, 9297, 16460, 1050, 16457, 16458, 9301,
+		16452, 16456, 9300, 16453, 2020, 9302, 9299, 16451, 16455, 16459, 18557, 2026, 3852, 16816,
+		16813, 16822, 16821, 16819, 16814, 9470, 1051, 2547, 16815, 9469, 9056, 16818, 9468, 16823,
+		16817, 16812, 16454, 9467, 16811, 17135, 17134, 16820, 9617, 17138, 17139, 17133, 17137,
+		17132, 1052, 17381, 17136, 9710, 9708, 17379, 17382, 17378, 17380, 9709, 2027, 17558,
+		17559, 9788, 17557, 17712, 9843, 17710, 17711, 17815, 9913, 17886, 17884, 17885, 17887,
+		18560, 17987, 4692, 4953, 5520, 10968, 11340, 11765, 11767, 11766, 11768, 6749, 12363,
+		11769, 18563, 12362, 12364, 12365, 12994, 7186, 12995, 12997, 7187, 7185, 12996, 13654,
+		13652, 13651, 13650, 13649, 7616, 13653, 8055, 14322, 14324, 8056, 14323, 8442, 8441,
+		14900, 3889, 15511, 15510, 15512, 2034, 8786, 16025, 9062, 16462, 9305, 16824, 9789, 4693,
+		4803, 4954, 5183, 5521, 5522, 5855, 11770, 777, 7617, 18564, 15026, 8787, 9306, 4694, 737,
+		4955, 10970, 5523, 10969, 1053, 11341, 5856, 11342, 5857, 11772, 6263, 6262, 12370, 12368,
+		12369, 12367, 12366, 12998, 13001, 7189, 12999, 7188, 13000, 13655, 14325, 14326, 14327,
+		14903, 14902, 8443, 14901, 14904, 15513, 15514, 15515, 16026, 9063, 9307, 16825, 9711,
+		10393, 5858, 11343, 6264, 6750, 7190, 13002, 7619, 7618, 1054, 14328, 14329, 8444, 8445,
+		15517, 15516, 16463, 17713, 4695, 10394, 4804, 5184, 10684, 5859, 7620, 4696, 11344, 5860,
+		11345, 9064, 4697, 18575, 10971, 11346, 18573, 11777, 11776, 11774, 11773, 11778, 11775,
+		1055, 6751, 6752, 7191, 13003, 13004, 13005, 13660, 13661, 13659, 3901, 13657, 13658, 7621,
+		14330, 14907, 14906, 14905, 14908, 15519, 15518, 8788, 15520, 9065, 16027, 16464, 16826,
+		17560, 4698, 4806, 4805, 5524, 10395, 10424, 4956, 10511, 10685, 10686, 5525, 1504, 10972,
+		5861, 11347, 11348, 6265, 6269, 11779, 6268, 6266, 6267, 12371, 6753, 7194, 7192, 7193,
+		13006, 3878, 8057, 4699, 738, 10425, 18576, 4807, 3373, 739, 10428, 592, 4810, 10427, 4809,
+		4808, 5185, 10426, 10512, 18185, 10516, 10518, 10519, 4966, 4967, 10515, 4962, 10513,
+		10517, 4963, 4958, 2306, 4959, 18580, 4965, 10514, 4957, 5186, 4960, 4961, 4964, 10703,
+		10701, 10688, 5198, 10695, 5193, 10689, 10697, 18246, 10694, 5196, 10693, 5203, 10702,
+		5205, 1056, 5207, 10687, 18582, 5194, 10704, 5201, 5204, 1228, 10995, 5188, 5211, 5202,
+		10690, 5191, 5206, 10698, 5189, 5190, 10993, 10691, 5197, 5208, 10705, 10692, 5195, 5200,
+		10973, 5209, 10699, 5199, 5210, 5187, 10696, 5192, 10700, 10994, 1467, 2769, 1057, 5537,
+		5553, 10980, 5544, 2623, 11001, 5529, 3695, 5532, 10992, 10978, 10982, 5540, 5542, 10985,
+		5549, 5535, 5533, 5534, 5548, 10997, 5543, 10984, 10986, 5541, 5546, 10987, 11000, 5862,
+		5552, 18156, 5530, 10999, 3927, 11003, 10983, 11005, 10990, 5539, 10979, 5538, 5556, 5545,
+		10977, 11357, 5551, 5555, 10991, 10996, 2720, 5557, 5550, 5536, 5526, 5531, 10981, 5527,
+		11004, 3922, 10975, 10974, 10988, 10976, 5554, 6270, 5547, 10989, 5528, 5877, 11002, 852,
+		11364, 11367, 1059, 11370, 11359, 1880, 1060, 11373, 11376, 11378, 11356, 5863, 5868,
+		11784, 5884, 11371, 11379, 11363, 11355, 11811, 5871, 11368, 11360, 11362, 5876, 11365,
+		5870, 11352, 11374, 11377, 2056, 5867, 1061, 5879, 11349, 5881, 5865, 5885, 11375, 11351,
+		5882, 11805, 10998, 5869, 5864, 11358, 11350, 5883, 5875, 11369, 5880, 5878, 11361, 5872,
+		11353, 5873, 5874, 11354, 11372, 5866, 11366, 357, 2620, 11787, 6278, 6283, 18200, 18602,
+		11796, 11802, 11798, 11785, 11788, 11781, 11782, 6290, 6275, 11797, 11806, 6285, 6271,
+		6280, 11789, 6282, 11790, 11800, 18289, 1812, 6284, 11815, 11783, 6277, 6276, 6288, 11780,
+		11813, 11801, 11814, 11808, 11794, 11809, 1062, 11803, 11812, 11810, 6289, 11795, 6274,
+		6273, 6281, 6287, 11807, 6286, 11792, 6754, 3323, 11816, 11791, 11786, 6279, 6291, 6272,
+		18601, 11799, 11804, 1064, 2045, 6788, 12373, 12377, 6769, 6767, 1065, 6758, 12375, 12374,
+		6778, 12380, 6772, 4041, 12398, 12410, 6755, 12395, 12388, 6790, 12372, 12400, 6787, 6775,
+		6786, 6765, 12384, 12402, 12394, 6766, 6760, 12408, 12401, 12403, 6768, 6776, 12405, 12382,
+		12399, 12387, 12407, 6781, 6757, 6779, 12392, 12390, 12391, 6770, 12386, 12385, 6759,
+		12379, 12409, 6761, 12389, 6791, 6785, 12378, 6782, 6780, 6789, 12396, 6784, 11793, 12397,
+		6783, 12404, 6756, 12393, 6774, 12381, 6773, 6771, 6763, 6762, 13007, 12406, 786, 12383,
+		2627, 13028, 2051, 6764, 2054, 2617, 1066, 1067, 7223, 6777, 7205, 13022, 7219, 13010,
+		7202, 7198, 13040, 7204, 7208, 7203, 7212, 13044, 13035, 1068, 18834, 13017, 7217, 7211,
+		13029, 7195, 13041, 13034, 7199, 13023, 7214, 13052, 13053, 7196, 13039, 7216, 13027,
+		13013, 13014, 7220, 13018, 13025, 13019, 7218, 7226, 13015, 13008, 13009, 13011, 7201,
+		13024, 7215, 7224, 18620, 18520, 13026, 13050, 13042, 7197, 13048, 7209, 908, 7207, 18274,
+		13054, 7206, 13032, 13020, 13030, 7229, 13036, 13033, 13016, 7225, 13046, 13043, 13051,
+		7200, 13031, 7228, 13038, 7210, 7213, 13037, 7227, 13021, 12376, 1808, 13047, 13045, 13049,
+		18603, 1069, 13012, 7222, 2311, 13692, 13691, 13665, 7627, 13690, 13668, 13669, 7637,
+		13699, 7632, 13689, 409, 13662, 7638, 7628, 13677, 13667, 13670, 13694, 7622, 13701, 13693,
+		7631, 13682, 7642, 7641, 7635, 13700, 7623, 13680, 13671, 13684, 13696, 7643, 18566, 7625,
+		13679, 3382, 13672, 7634, 1071, 7633, 13675, 13685, 13695, 13666, 13676, 7626, 13686, 7639,
+		7630, 13673, 7629, 13664, 13678, 13698, 7221, 8085, 13681, 14331, 13683, 13697, 7636,
+		13674, 7624, 7640, 8463, 13663, 13688, 18149, 1073, 18091, 13687, 321, 1072, 1075, 1076,
+		14359, 8082, 14348, 14353, 8071, 14333, 8084, 8062, 14332, 14365, 8086, 14336, 14352,
+		18627, 14338, 14368, 14364, 8060, 8070, 14934, 8083, 8068, 14334, 18604, 14363, 8072,
+		14360, 14373, 14340, 14349, 2046, 2628, 14371, 14361, 8067, 14347, 8061, 8059, 8077, 18469,
+		2673, 14345, 14342, 14343, 14367, 14372, 14370, 8065, 14374, 8069, 8076, 1077, 14335,
+		14910, 14344, 8063, 8081, 8078, 8066, 14354, 14339, 8079, 14356, 8073, 8075, 8058, 1078,
+		14358, 14350, 14337, 8074, 14366, 14369, 14346, 14357, 18699, 8064, 8446, 14355, 14909,
+		14362, 18632, 14341, 14919, 14929, 8449, 14928, 14940, 8451, 14926, 3523, 14930, 8462,
+		14921, 8454, 18633, 14933, 15528, 8466, 8465, 14935, 14918, 14945, 8460, 8450, 14938,
+		14942, 14947, 14936, 14924, 8453, 8456, 8464, 8459, 14927, 14351, 18635, 14923, 14946,
+		8455, 2310, 8458, 14948, 8447, 14937, 14911, 14943, 9074, 1081, 14925, 8448, 14920, 8452,
+		14912, 8080, 14916, 18457, 14941, 14917, 14915, 8457, 14939, 14914, 14932, 14931, 14944,
+		14922, 8461, 18625, 15531, 18638, 8801, 8791, 15543, 15523, 8793, 15535, 8800, 8795, 15533,
+		16029, 15539, 15545, 15538, 15521, 15536, 15547, 15549, 8790, 15548, 8796, 8802, 3419,
+		8799, 15542, 8798, 15537, 2307, 15525, 15530, 1082, 15541, 8797, 8794, 8789, 8792, 15529,
+		14913, 15524, 15527, 15544, 15532, 15522, 16028, 15546, 15526, 15540, 18640, 16031, 9078,
+		9066, 2493, 2492, 9070, 16033, 16039, 16037, 9068, 9069, 9076, 16040, 16032, 9071, 16036,
+		16035, 16041, 9077, 9072, 9075, 16034, 9079, 9073, 9080, 9067, 16038, 16030, 1497, 16477,
+		18623, 9312, 16474, 16476, 9310, 16472, 16473, 16468, 2742, 15534, 16469, 9311, 16465,
+		9308, 16478, 9309, 16466, 16467, 16471, 9314, 9313, 16470, 9476, 16830, 16836, 9477, 16827,
+		9474, 9471, 16838, 9475, 2495, 9472, 16829, 16832, 16833, 16834, 16837, 16475, 16828, 9473,
+		16835, 16839, 16831, 1088, 17142, 9619, 17145, 9620, 17153, 17144, 17141, 17143, 17147,
+		17150, 17149, 17152, 17140, 9618, 17148, 17151, 17154, 17146, 17383, 17384, 17388, 17389,
+		17386, 17387, 17385, 18652, 9712, 824, 1090, 9790, 17564, 1091, 17561, 17562, 17563, 9791,
+		17715, 17714, 1408, 17818, 9880, 17816, 17819, 17817, 17888, 9914, 9928, 17963, 17942,
+		2622, 17962, 18008, 18009, 4700, 740, 124, 1092, 4968, 10520, 10706, 1532, 5212, 792, 5215,
+		18197, 10707, 5213, 5214, 18653, 1093, 18655, 11014, 11016, 11011, 11008, 11010, 1094,
+		5561, 18247, 5559, 1481, 11012, 5560, 11009, 11006, 5558, 11013, 11007, 5562, 11015, 11381,
+		2874, 11385, 1707, 5894, 2560, 2716, 18654, 11388, 5886, 5889, 5891, 5893, 5890, 11384,
+		11383, 5888, 11386, 11387, 11380, 5892, 2813, 5887, 3137, 358, 18681, 11382, 2856, 2289,
+		11830, 11831, 11832, 6296, 6292, 11821, 18656, 11829, 11834, 6297, 1702, 11819, 11825,
+		11818, 11828, 18657, 18658, 11824, 6293, 6295, 11833, 11817, 11820, 11826, 18659, 11827,
+		11835, 11823, 6294, 6796, 12417, 2061, 12419, 12415, 2912, 12427, 12413, 12426, 6792,
+		12411, 13058, 12421, 6795, 2647, 12422, 12425, 12429, 12418, 1890, 12423, 6793, 6794,
+		12428, 12416, 12412, 12430, 12420, 12424, 12414, 7230, 7231, 13068, 7237, 2704, 13056,
+		13065, 13055, 7234, 13063, 13061, 13062, 7232, 13066, 2288, 18665, 18675, 13059, 13057,
+		7233, 13060, 13064, 2717, 2503, 1170, 7235, 13067, 2058, 1102, 654, 13707, 13714, 13716,
+		13722, 18666, 7656, 13702, 7648, 18668, 13717, 7653, 13723, 7644, 18672, 13720, 3287,
+		13704, 13721, 13703, 7658, 925, 13715, 7645, 13724, 7650, 13708, 7655, 13719, 13706, 13713,
+		13709, 13705, 7647, 7654, 7652, 7649, 7657, 7646, 13712, 2574, 7651, 7236, 13718, 3255,
+		13710, 3080, 2614, 4046, 1680, 13711, 3898, 14382, 1379, 8089, 1705, 14378, 14384, 14380,
+		8091, 14379, 14383, 14375, 2795, 14377, 8090, 1831, 2692, 14381, 14376, 802, 8092, 8087,
+		18663, 14385, 3149, 8088, 14954, 14951, 14963, 14957, 14959, 8467, 14953, 14961, 3508,
+		3688, 14960, 14958, 14964, 8470, 14955, 14962, 8468, 3403, 14950, 14952, 8469, 14949,
+		14965, 18662, 14956, 1085, 15553, 8809, 18679, 15564, 15562, 8803, 15552, 15555, 15556,
+		15551, 8813, 8814, 15550, 15565, 15560, 8807, 8804, 15559, 15557, 8810, 15561, 8805, 1118,
+		8806, 15558, 8808, 15554, 15563, 8811, 15566, 15567, 8812, 18164, 9082, 9089, 16042, 16048,
+		16046, 9085, 9084, 9081, 16044, 2506, 9087, 9086, 9083, 16047, 16043, 16045, 9088, 2762,
+		3620, 9318, 16481, 9315, 9316, 16483, 9317, 16480, 854, 16479, 16843, 16482, 16844, 9478,
+		16842, 3244, 16841, 16840, 9479, 18682, 1849, 9621, 17155, 17156, 18684, 1676, 1698, 17392,
+		17391, 9713, 17390, 17565, 17566, 17716, 17820, 18687, 31, 17889, 17988, 9948, 17998, 4701,
+		741, 5563, 5564, 18690, 5895, 9090, 4702, 5565, 6298, 7659, 4703, 6797, 8093, 10396, 18691,
+		11389, 11836, 14386, 9091, 4704, 5566, 11390, 11391, 13069, 7238, 13725, 2062, 7660, 14387,
+		18694, 8471, 18695, 9480, 4705, 13070, 4706, 1035, 4970, 10521, 4969, 5218, 5217, 5216,
+		10708, 3667, 5567, 5568, 11017, 11392, 11395, 5897, 11393, 5896, 11394, 5898, 11839, 11838,
+		11837, 6299, 12432, 12433, 6798, 12431, 12434, 7240, 6799, 18700, 7239, 13074, 13073, 2063,
+		13071, 13072, 13075, 13727, 13726, 13730, 3487, 13729, 13728, 8094, 14390, 2064, 14389,
+		8095, 14388, 14966, 14967, 8472, 15568, 15569, 2864, 16849, 9481, 16848, 16847, 16845,
+		16846, 9714, 17157, 17567, 17890, 4707, 743, 10429, 4811, 10430, 3966, 10522, 10523, 10714,
+		10710, 10709, 5569, 10713, 5220, 10711, 5219, 10715, 10712, 11021, 11396, 11019, 11026,
+		2751, 5570, 5573, 11027, 11023, 11024, 11018, 5572, 11020, 5571, 11025, 11022, 11400, 5900,
+		5901, 3934, 11403, 11397, 11402, 11398, 5899, 11401, 11399, 11845, 11842, 11844, 6304,
+		6303, 6301, 11841, 11846, 6300, 6302, 11843, 12442, 11840, 11847, 3937, 3648, 12437, 12441,
+		12440, 13077, 13088, 12443, 2752, 12438, 13076, 6803, 6802, 12436, 12439, 6804, 6801, 6800,
+		12435, 12444, 13079, 13086, 7242, 13085, 13082, 18707, 7244, 2879, 18702, 3962, 13084,
+		18708, 13078, 13080, 13083, 13081, 7243, 13087, 7241, 7661, 2754, 2755, 13734, 13733,
+		13731, 7664, 7663, 13735, 2757, 13732, 14391, 8096, 7662, 2758, 13737, 13736, 14394, 14392,
+		8473, 3858, 8097, 14393, 14969, 8474, 14968, 14977, 14976, 14974, 14973, 14970, 14971,
+		14972, 14975, 14978, 15574, 15571, 15572, 8815, 15570, 15576, 15575, 15573, 16050, 16051,
+		9092, 3935, 9093, 16049, 3712, 9320, 16484, 9319, 9482, 9483, 9622, 17159, 17158, 17393,
+		17568, 9792, 17718, 17717, 17719, 4812, 11404, 6305, 6805, 12445, 4813, 10431, 4708, 856,
+		10524, 2745, 10720, 10718, 10719, 10716, 5221, 10717, 1843, 18182, 11035, 1684, 5576,
+		11033, 11029, 11032, 11028, 5578, 11031, 18179, 5575, 5574, 18710, 5577, 11034, 11030,
+		5905, 5908, 11412, 11411, 11410, 5902, 11419, 11409, 804, 5904, 11852, 11416, 11414, 5907,
+		11406, 11417, 93, 11408, 11418, 11415, 11407, 18713, 5903, 11420, 11405, 5906, 18329,
+		18709, 2140, 11857, 11848, 11859, 18159, 11851, 11862, 11863, 11849, 11861, 11858, 11856,
+		11860, 6311, 6309, 2764, 2540, 11854, 18735, 11850, 2563, 11853, 11864, 11855, 6310, 11413,
+		6306, 6308, 18061, 2548, 12459, 12448, 12446, 18723, 12447, 2369, 12454, 2549, 12455,
+		12451, 6810, 12456, 12453, 12450, 811, 6808, 12449, 6806, 6809, 12452, 12460, 6307, 6807,
+		12458, 12457, 6811, 377, 2350, 1841, 18724, 13093, 18217, 2771, 13094, 7254, 1554, 13100,
+		2772, 2773, 13102, 13095, 7248, 13099, 13098, 7249, 7255, 7256, 13101, 7246, 13092, 13090,
+		13096, 13089, 7253, 13091, 13097, 13103, 7247, 7252, 7250, 7251, 2086, 2126, 18727, 7245,
+		2084, 7671, 13744, 7670, 13748, 1881, 13738, 2335, 13749, 18734, 2558, 18714, 13739, 13740,
+		86, 13750, 13747, 13745, 13746, 13742, 13741, 18617, 13751, 7667, 1842, 13743, 7672, 7666,
+		7673, 7674, 18770, 7669, 7668, 18732, 56, 14395, 8099, 8098, 18301, 14400, 2099, 8475,
+		8100, 2351, 18738, 8102, 14401, 7665, 8101, 14397, 14399, 14396, 18740, 14398, 18736,
+		14988, 2070, 14987, 14984, 8478, 15577, 8479, 14983, 18743, 8477, 14985, 14982, 14979,
+		14986, 14980, 14981, 8476, 1888, 1167, 16055, 15581, 15580, 15582, 16052, 8818, 1840,
+		15578, 2127, 8816, 57, 8820, 8819, 15579, 15583, 8817, 18418, 16059, 9096, 9321, 9097,
+		9094, 16056, 16054, 16057, 16060, 9095, 16058, 16053, 2542, 16487, 16490, 16852, 16485,
+		860, 17160, 16491, 9484, 16489, 9322, 16486, 16488, 16492, 16853, 16851, 95, 18752, 9485,
+		16850, 2083, 9623, 2013, 2778, 9716, 17570, 9715, 17572, 17569, 17571, 9844, 17821, 4814,
+		11036, 11865, 11866, 6812, 13752, 8821, 9486, 9793, 17822, 4815, 4044, 11037, 4271, 11421,
+		11422, 4018, 3938, 4011, 11867, 11868, 6813, 6814, 4033, 13104, 13755, 13754, 13753, 14402,
+		14403, 14404, 8103, 4010, 14990, 14989, 15584, 8822, 8823, 18754, 16064, 16061, 16062,
+		16063, 16494, 16493, 9323, 16854, 17394, 4816, 411, 5909, 6815, 13756, 18756, 4817, 11869,
+		6816, 7257, 7258, 4818, 4819, 10525, 5223, 5222, 5910, 11423, 13105, 4820, 4821, 4822,
+		4823, 3600, 359, 5224, 5225, 10722, 10721, 3356, 5579, 11040, 11038, 11039, 2781, 3839,
+		11424, 11425, 18763, 5914, 5912, 18762, 5913, 5911, 2631, 4023, 6312, 2095, 6316, 6315,
+		11870, 6314, 6313, 11871, 2882, 6819, 12462, 12461, 6817, 6818, 18764, 7260, 7259, 13107,
+		2782, 18765, 13106, 6820, 2783, 7675, 13758, 7676, 13757, 3855, 14405, 14991, 8480, 15585,
+		2090, 16065, 9488, 9487, 9794, 4824, 11041, 2784, 18769, 6821, 14406, 8104, 10005, 10723,
+		10724, 11042, 5581, 5582, 5580, 18771, 5918, 5919, 5916, 5917, 11426, 11427, 5915, 11872,
+		3639, 6320, 6321, 18772, 6824, 11878, 6326, 6324, 11879, 11874, 6323, 6322, 6317, 11877,
+		11876, 11873, 6325, 3929, 11875, 6318, 6319, 2786, 6825, 12466, 12467, 6826, 12463, 12465,
+		12469, 12468, 12464, 6822, 6823, 13115, 7265, 7264, 13109, 7262, 2787, 13112, 7266, 13113,
+		7267, 13110, 7261, 7263, 13114, 13111, 13108, 13771, 13760, 7678, 7681, 7680, 7685, 7684,
+		13772, 13770, 13763, 13767, 13766, 7682, 13769, 13765, 13773, 13764, 7683, 7677, 7679,
+		18773, 13762, 3924, 14408, 8108, 14412, 8107, 14409, 8106, 13761, 13768, 14411, 8109,
+		14413, 14410, 14407, 14994, 14998, 15000, 14996, 14995, 14993, 8483, 8481, 8486, 8487,
+		14997, 8484, 14992, 8485, 8105, 14999, 8482, 18774, 15587, 15586, 15594, 15588, 15593,
+		15590, 8824, 15592, 8825, 8826, 3577, 15591, 15589, 3738, 9099, 16066, 9098, 16069, 16067,
+		16068, 9100, 2789, 3579, 18776, 16498, 18775, 9326, 16499, 3748, 9324, 16500, 9325, 16497,
+		16501, 16495, 9489, 16855, 9490, 9624, 16496, 9625, 3636, 3953, 9717, 17395, 9796, 17573,
+		9795, 18777, 17720, 9881, 9882, 2096, 2791, 18004, 18778, 5920, 11428, 2793, 7268, 7269,
+		4825, 4971, 10526, 10725, 5226, 5583, 5921, 5922, 5923, 12471, 11880, 6327, 6827, 12470,
+		18780, 13117, 7271, 13116, 7270, 13774, 8488, 15004, 15001, 15002, 15003, 18785, 16070,
+		18786, 16502, 17162, 17161, 17574, 4826, 11043, 6328, 7272, 13775, 18787, 14414, 8489,
+		15595, 16503, 17163, 4827, 5584, 5926, 11429, 5927, 5925, 5924, 11881, 6329, 18788, 6330,
+		6331, 6829, 12472, 6828, 3864, 828, 13118, 6830, 7273, 13776, 7686, 7687, 8110, 8111, 8490,
+		8828, 15596, 8827, 16071, 9101, 16504, 17164, 4828, 5227, 11046, 11045, 5585, 11044, 5586,
+		11047, 11435, 5930, 5929, 11437, 11436, 5934, 5933, 5928, 11431, 11432, 11433, 5935, 11430,
+		5931, 11434, 5932, 2767, 2119, 2120, 11884, 11888, 11886, 11885, 11889, 11890, 11891,
+		11883, 11882, 18794, 6333, 6334, 11892, 11887, 4020, 12481, 18795, 11893, 6335, 6332, 4032,
+		12475, 12474, 12476, 12477, 12479, 12478, 12483, 6834, 6831, 6835, 12473, 6836, 12482,
+		6833, 12480, 6832, 18796, 2800, 13121, 13123, 13119, 13120, 13124, 13126, 13127, 13122,
+		13125, 7274, 13780, 13785, 13777, 13781, 18799, 13786, 13782, 7688, 7696, 7691, 13778,
+		13779, 8115, 7699, 7692, 7697, 7690, 13784, 7698, 13783, 7694, 7689, 7695, 13787, 14419,
+		14421, 1700, 1357, 2117, 7693, 486, 2121, 14416, 8113, 14422, 8114, 14420, 14415, 14418,
+		14423, 8112, 14417, 8492, 15008, 15007, 8495, 8493, 15005, 8491, 15006, 8494, 1370, 15604,
+		15606, 2805, 15605, 15597, 15601, 15600, 15598, 8830, 8831, 8829, 15599, 15602, 15603,
+		8832, 9106, 16079, 9103, 16073, 9105, 9107, 18801, 9104, 16078, 16074, 9102, 16077, 16072,
+		16076, 16075, 3580, 16506, 9329, 9330, 9327, 2809, 9328, 16505, 2296, 16858, 18503, 9491,
+		16856, 16857, 2810, 17165, 17168, 17166, 17167, 17396, 9718, 17721, 17823, 9883, 17891,
+		17892, 9929, 4829, 5936, 831, 13128, 13788, 4830, 5228, 5587, 18173, 11438, 11439, 6336,
+		13129, 7275, 7700, 9108, 16080, 16859, 17169, 4831, 18805, 11053, 11048, 11050, 11052,
+		11051, 11049, 5588, 4001, 5937, 11448, 11443, 11440, 3466, 11447, 5939, 5940, 11446, 11445,
+		11444, 11441, 11442, 11449, 5938, 2812, 2814, 6340, 2136, 6346, 6345, 11902, 11896, 11894,
+		6343, 12490, 6338, 11899, 11903, 11905, 11901, 11895, 6344, 11900, 11898, 6337, 11906,
+		6347, 11904, 6341, 11897, 6342, 6339, 2816, 18070, 2483, 2057, 6838, 2419, 12491, 2817,
+		12484, 12486, 12488, 2486, 12489, 12487, 6839, 12492, 2818, 12485, 3931, 2484, 13133, 7276,
+		13141, 13130, 13142, 13131, 13132, 2819, 13140, 13139, 13136, 6837, 7277, 13134, 13137,
+		7278, 13138, 13135, 13799, 3741, 13803, 7707, 14429, 7710, 13801, 18014, 13798, 13793,
+		13795, 13796, 13789, 2138, 7706, 7705, 2137, 7701, 13792, 7708, 7709, 13791, 13794, 13802,
+		7703, 7704, 13800, 13790, 14431, 8117, 14433, 13797, 14435, 8121, 14436, 14432, 8118,
+		14428, 8120, 14425, 14430, 14434, 14427, 18810, 7702, 18818, 14424, 8119, 14426, 2485, 388,
+		2476, 8498, 15010, 8502, 8500, 8116, 15017, 15018, 8497, 18811, 15016, 15019, 8499, 8496,
+		15012, 15009, 15014, 15011, 8503, 15013, 2139, 18812, 15015, 8501, 18816, 3921, 8834,
+		15613, 15607, 15611, 15617, 15618, 15614, 15615, 15612, 810, 15609, 8836, 8833, 15608,
+		15610, 8835, 15616, 1987, 9112, 2175, 18234, 16086, 16081, 9111, 1512, 9109, 16088, 9110,
+		16084, 16085, 16082, 16089, 16507, 9113, 16083, 16090, 16087, 3995, 16511, 16508, 9331,
+		16512, 16514, 16513, 16509, 16510, 16864, 9492, 16861, 16863, 16860, 16865, 16862, 17171,
+		17174, 17170, 9626, 17172, 17173, 17175, 9627, 9629, 9628, 17397, 18814, 17399, 17398,
+		2824, 2822, 18817, 17575, 1192, 17824, 17893, 4832, 746, 18832, 10726, 5589, 11055, 5590,
+		5591, 11054, 11454, 11453, 5941, 5944, 5943, 5942, 11450, 11452, 11451, 11909, 6349, 11913,
+		11912, 11911, 11907, 6348, 6352, 6355, 1296, 2141, 6356, 11908, 11910, 6354, 6353, 6351,
+		6350, 12499, 3590, 12497, 12493, 6840, 12494, 6841, 12495, 12496, 12498, 6842, 13146,
+		18410, 13145, 13144, 13143, 13807, 7711, 13804, 13806, 3417, 7712, 18828, 7713, 13805,
+		3347, 18825, 417, 14445, 14438, 14439, 8124, 8122, 8123, 14447, 2827, 14446, 14443, 14442,
+		14441, 14440, 14444, 14437, 2828, 15020, 15025, 15023, 18830, 1687, 15022, 15021, 15024,
+		15619, 2147, 8837, 9114, 16092, 18833, 9115, 16091, 16516, 16515, 9332, 16866, 9493, 17176,
+		9797, 17576, 17826, 17825, 10432, 5945, 5946, 12500, 7715, 4833, 5231, 5230, 5229, 2582,
+		11056, 18837, 5593, 5592, 909, 5949, 18619, 11456, 11459, 11457, 81, 5947, 5948, 18839,
+		11455, 11458, 6363, 11920, 11922, 11921, 6360, 11916, 2466, 6358, 6357, 6361, 6359, 6362,
+		11919, 11914, 11915, 11918, 11917, 2156, 2151, 12503, 12504, 12502, 12501, 6843, 7283,
+		13147, 13149, 13151, 7282, 2830, 7280, 13148, 7281, 13150, 7279, 13817, 13808, 13811,
+		13815, 7719, 13813, 13812, 13809, 13810, 7717, 13816, 7716, 7721, 7720, 7718, 13814, 14453,
+		14452, 820, 14448, 89, 4006, 8125, 14451, 14450, 8126, 82, 8508, 15027, 2150, 8509, 8505,
+		8507, 3920, 8504, 8506, 1701, 15620, 3339, 8840, 15622, 15621, 1108, 14449, 8842, 8841,
+		8838, 8839, 16097, 16095, 9116, 16096, 16098, 16094, 16093, 16517, 9335, 9333, 9334, 1111,
+		16867, 16868, 9495, 9494, 17178, 17179, 17177, 17577, 17578, 103, 4834, 10527, 5232, 11057,
+		5595, 5594, 11058, 1112, 11461, 5950, 11460, 5951, 3034, 6364, 11925, 11923, 11929, 6365,
+		11924, 11928, 11926, 11927, 12505, 12507, 2833, 6844, 864, 12506, 6845, 7286, 7285, 7284,
+		13152, 13820, 7722, 7723, 13819, 13818, 14454, 8128, 8127, 14455, 14456, 8511, 8510, 1115,
+		15626, 15028, 15030, 15029, 15625, 15623, 15627, 15624, 8843, 1114, 2835, 16099, 9117,
+		16100, 16101, 1116, 1117, 9336, 9337, 9630, 3063, 381, 9845, 4835, 2838, 11462, 3918,
+		11930, 6366, 1121, 2839, 1674, 1122, 6996, 6995, 2168, 7288, 13154, 7287, 13153, 1420,
+		1374, 1123, 13821, 8129, 14457, 8130, 9631, 17180, 4972, 5596, 11059, 65, 5953, 2178, 5952,
+		11463, 11464, 11932, 11934, 6367, 11936, 11939, 11937, 1127, 2217, 11938, 11935, 11931,
+		6368, 11940, 11933, 3899, 12516, 6851, 12525, 6848, 12518, 6852, 3790, 6846, 12513, 12526,
+		12514, 12511, 6850, 2213, 6847, 1129, 12517, 12520, 6849, 12521, 6853, 12522, 12512, 12519,
+		12523, 12515, 12510, 12508, 12524, 2868, 13156, 13161, 3333, 3416, 13157, 13163, 7291,
+		12509, 13158, 7289, 13155, 7296, 13159, 7295, 13160, 7297, 7292, 7298, 7293, 7294, 1128,
+		7290, 13162, 7726, 13832, 2842, 13835, 13823, 13828, 13822, 7728, 13826, 2843, 13824, 7727,
+		1133, 13831, 13830, 13827, 13829, 13825, 8134, 13834, 7724, 13833, 2844, 14473, 8141,
+		14468, 8140, 14458, 14460, 8133, 14465, 14464, 14467, 8138, 14462, 14461, 1139, 8137, 8132,
+		14463, 8135, 14469, 14471, 14466, 14459, 8136, 8519, 8131, 1132, 3793, 14472, 15034, 8512,
+		110, 15036, 8513, 709, 8515, 15039, 15031, 8139, 15037, 15033, 7725, 8518, 8514, 87, 8516,
+		8517, 8846, 15038, 15032, 8520, 15035, 3797, 1137, 15637, 15632, 15642, 8844, 15634, 8848,
+		15640, 15631, 15628, 15643, 9123, 8849, 15639, 15629, 8847, 15633, 8851, 15630, 15635,
+		8850, 15638, 15641, 865, 16119, 16120, 16104, 16111, 16109, 9121, 16116, 15636, 1145,
+		16107, 3804, 16117, 9120, 16106, 16105, 16115, 16112, 16114, 16102, 16118, 9118, 16113,
+		16122, 16110, 9122, 9119, 16108, 16103, 16121, 8845, 2845, 18141, 16520, 2846, 16518,
+		16524, 9341, 16522, 16521, 9343, 16527, 9342, 16528, 16525, 9339, 16526, 16519, 9340, 9338,
+		16872, 16523, 16529, 16869, 1147, 9500, 9498, 16871, 16873, 16870, 9499, 9496, 9497, 9501,
+		3801, 9633, 1149, 17185, 17184, 17182, 17181, 17183, 9632, 9634, 9719, 3968, 17401, 17400,
+		3768, 17579, 17581, 17583, 17582, 17580, 2180, 9799, 9798, 9847, 9846, 9848, 17723, 17722,
+		17894, 17827, 17895, 9915, 2181, 9916, 17943, 3859, 9953, 4973, 1158, 11060, 11466, 11467,
+		11465, 2849, 2190, 5954, 3799, 11468, 11469, 11470, 1150, 11941, 11947, 3802, 11945, 6369,
+		11943, 11944, 11946, 2189, 11942, 6854, 12527, 6856, 12529, 6855, 12528, 2850, 13165, 7299,
+		13167, 13164, 12530, 7300, 1152, 18019, 13166, 1463, 2191, 13838, 7729, 13836, 7730, 13837,
+		7731, 2852, 8142, 14477, 14474, 14476, 8143, 8144, 14475, 4003, 866, 15040, 1156, 15041,
+		1155, 8521, 15043, 15042, 2186, 4272, 15647, 15648, 15644, 2746, 15645, 8852, 8853, 15646,
+		9129, 4005, 9125, 9130, 9126, 9128, 9124, 9127, 1157, 9344, 16123, 16875, 16874, 2854,
+		17186, 9635, 9636, 17585, 17402, 1159, 17584, 1160, 17896, 17964, 4974, 747, 5233, 378,
+		11061, 5597, 5957, 11473, 5955, 11471, 5960, 5956, 5961, 5959, 11472, 5958, 6373, 6372,
+		11958, 6381, 11951, 11957, 6377, 11950, 11956, 11954, 6376, 6378, 11953, 6371, 11952, 6382,
+		6379, 6383, 6380, 11949, 11948, 11955, 6374, 6370, 6375, 3728, 12545, 12538, 7305, 12537,
+		6860, 6867, 6864, 6870, 6869, 6865, 12531, 12534, 12533, 6861, 12535, 12544, 6862, 12532,
+		12541, 12542, 6863, 12539, 6868, 6858, 6866, 12536, 6857, 12540, 12543, 4009, 13184, 13175,
+		7302, 13182, 13179, 13171, 13180, 7304, 13172, 13168, 3613, 7737, 13177, 1165, 7301, 13183,
+		7308, 7310, 13170, 7309, 13173, 7303, 13181, 13174, 13178, 13176, 7306, 13169, 7311, 6859,
+		7307, 7312, 7733, 13845, 13847, 13849, 13853, 13843, 13841, 7735, 13848, 13852, 13844,
+		13840, 13839, 1168, 839, 13850, 13842, 13846, 7736, 7734, 13854, 7732, 13851, 39, 14494,
+		2200, 8147, 14486, 8534, 14496, 8150, 14500, 8156, 14481, 2730, 14497, 14478, 2533, 14499,
+		14482, 1169, 8163, 8160, 14498, 14492, 8146, 8154, 8153, 1171, 8152, 8158, 14479, 8159,
+		14493, 8155, 8145, 14495, 8148, 8149, 8157, 14484, 14483, 14480, 14488, 14485, 14489, 8162,
+		14501, 8151, 14490, 14491, 379, 14487, 2858, 2860, 8161, 2195, 2859, 15048, 8526, 8535,
+		8531, 15045, 2193, 8528, 8532, 15057, 8522, 15049, 8530, 1175, 1179, 15052, 15047, 8529,
+		8533, 15046, 8527, 15056, 8524, 15055, 15054, 8536, 14808, 8523, 1177, 15053, 15044, 8537,
+		15051, 8525, 2861, 2584, 15050, 2194, 8856, 8861, 8854, 15661, 15652, 15664, 15656, 15662,
+		8862, 8855, 15649, 15655, 15665, 15658, 15663, 15651, 15659, 8857, 15657, 8860, 8859,
+		15653, 15654, 15650, 15660, 8858, 15667, 15666, 1188, 16134, 16130, 9137, 16124, 9131,
+		9147, 16136, 9139, 9135, 16127, 9142, 9145, 16138, 9134, 16129, 9143, 16140, 16137, 16125,
+		9138, 9132, 9146, 16132, 9141, 16126, 9136, 16139, 9140, 9133, 16133, 9144, 16131, 16135,
+		16531, 16537, 9350, 16540, 9345, 9346, 16532, 16539, 16534, 9351, 9348, 907, 16530, 9347,
+		16536, 16538, 9349, 16535, 16533, 2723, 1162, 16885, 9505, 9506, 9502, 867, 9503, 1184,
+		16881, 16879, 16883, 9508, 16884, 16877, 16876, 16880, 16878, 9504, 16882, 17187, 9639,
+		9638, 17188, 3522, 17190, 17189, 9640, 17407, 17404, 17405, 17403, 17406, 9721, 17408,
+		3689, 9720, 17586, 1185, 9849, 9851, 17724, 9850, 17828, 17898, 17900, 17897, 17899, 9935,
+		3502, 360, 361, 362, 363, 364, 365, 366, 4975, 367, 5962, 11822, 6384, 6871, 13185, 13186,
+		1189, 1190, 15668, 9148, 16141, 1191, 9352, 3565, 16887, 16886, 9641, 17409, 2196, 17587,
+		9884, 10528, 745, 748, 5598, 5234, 11474, 11964, 11959, 11963, 11961, 6385, 11962, 11960,
+		12546, 13187, 13188, 13857, 13858, 7739, 7740, 13856, 13859, 13855, 7738, 8164, 4053, 7741,
+		14502, 8538, 15058, 8539, 3903, 8863, 15671, 15669, 15670, 16144, 16143, 16142, 16888,
+		9507, 16889, 17588, 9885, 17829, 4976, 5600, 5599, 11476, 5963, 18596, 11475, 11966, 604,
+		6386, 12547, 11965, 1195, 6873, 12550, 12548, 12549, 6872, 13190, 13191, 13189, 1197, 7744,
+		13861, 13860, 13862, 7743, 7742, 15059, 15061, 2862, 8540, 15060, 15672, 8864, 16542, 2863,
+		16541, 9509, 16890, 9511, 9510, 17191, 17410, 9722, 4977, 11477, 5964, 11969, 6388, 11968,
+		11967, 6387, 12556, 12558, 12551, 12552, 6874, 12553, 6875, 12557, 12554, 12555, 6876,
+		7314, 7315, 13192, 13863, 13864, 2212, 14506, 8167, 8165, 8166, 14503, 14504, 14505, 15066,
+		2750, 15067, 8541, 15064, 15063, 15065, 15062, 8867, 15673, 8865, 8866, 16146, 9149, 16145,
+		16398, 16543, 16544, 9353, 1204, 9354, 9150, 16891, 16892, 17192, 9642, 4978, 753, 4979,
+		5965, 5601, 6389, 11478, 1207, 7316, 4980, 5967, 11479, 11480, 5966, 5968, 4981, 11481,
+		6391, 11970, 6393, 6390, 6392, 12561, 6877, 1208, 12560, 12559, 2866, 13865, 14507, 2867,
+		8542, 8868, 15675, 15674, 16147, 2869, 1210, 17411, 2870, 4982, 10727, 11062, 5969, 11482,
+		11972, 1213, 1214, 6394, 11971, 6395, 12563, 6879, 12562, 12564, 6878, 13194, 13195, 13193,
+		7317, 2871, 7745, 7746, 8169, 4017, 14509, 14508, 8168, 1215, 4015, 4008, 15068, 4007,
+		15069, 15676, 9154, 9153, 9151, 9152, 9155, 16545, 9356, 9355, 16893, 17193, 9801, 9800,
+		4983, 754, 11771, 7748, 7318, 7747, 8170, 4984, 10433, 4985, 4986, 11063, 10730, 10731,
+		5236, 10728, 5235, 5238, 10729, 5240, 5239, 10732, 5237, 397, 5606, 5604, 11065, 5603,
+		1222, 5608, 5610, 5607, 11068, 11064, 5611, 5605, 5241, 5609, 11067, 3640, 2875, 11066,
+		5602, 390, 3588, 11488, 5973, 5974, 11490, 2235, 11484, 11487, 11496, 11494, 5975, 11500,
+		5978, 11497, 11489, 11486, 13197, 11495, 5970, 11498, 11483, 11492, 5972, 5977, 11493,
+		5981, 5979, 11485, 5976, 11491, 5980, 5971, 11499, 2876, 656, 6400, 6409, 6398, 6396,
+		11974, 6404, 6401, 11976, 393, 6403, 11975, 11973, 11978, 6408, 6406, 13196, 11979, 11977,
+		6397, 4019, 6399, 6402, 1224, 6405, 2236, 6407, 2878, 2880, 2881, 2883, 12574, 6881, 3574,
+		12565, 12567, 1225, 12568, 12576, 12572, 12570, 12573, 12577, 6882, 6886, 12566, 12575,
+		6884, 1912, 6883, 12571, 12569, 6880, 6885, 3586, 659, 2233, 7324, 13205, 13203, 7326,
+		2237, 13198, 13879, 7325, 13202, 3583, 13199, 7321, 7327, 13204, 7323, 13201, 8171, 7322,
+		13200, 7328, 7320, 7319, 2886, 2887, 2888, 13871, 13869, 13875, 13867, 13880, 13872, 13866,
+		7752, 7758, 13877, 13870, 7755, 938, 655, 7753, 13878, 7750, 7749, 13873, 7754, 7329,
+		13876, 13868, 7751, 7756, 7757, 2891, 8176, 8172, 8177, 14512, 2892, 14511, 14513, 8174,
+		14510, 8175, 14516, 14515, 14514, 8173, 2894, 15073, 15076, 8548, 15075, 8547, 8543, 8544,
+		8545, 15072, 15071, 8546, 15074, 15070, 2896, 15678, 15688, 8871, 8870, 563, 15682, 15684,
+		15679, 15683, 15677, 15686, 8869, 15685, 15681, 3644, 15687, 15680, 9158, 16148, 16154,
+		9162, 9164, 9161, 9160, 2898, 9159, 9157, 16149, 16152, 9156, 16153, 4027, 9163, 16151,
+		16150, 9357, 9358, 16548, 16546, 16547, 16895, 16894, 9512, 17195, 9643, 17194, 17412,
+		17589, 9802, 17901, 17902, 9852, 4987, 2899, 5612, 13206, 8178, 9165, 16155, 4988, 6411,
+		6410, 13207, 18781, 15689, 4989, 5982, 2765, 13208, 13209, 13210, 8179, 8872, 4990, 5613,
+		11501, 6412, 11980, 6887, 13211, 7759, 8180, 8873, 9166, 9359, 17196, 4991, 5614, 6413,
+		12578, 7330, 8181, 14517, 15077, 1233, 4013, 4992, 7331, 13881, 8182, 4993, 11069, 11502,
+		5983, 11982, 18581, 6416, 1239, 6414, 6415, 6417, 3704, 11981, 12583, 12580, 12582, 6888,
+		6890, 6889, 12579, 6891, 12581, 13212, 13213, 410, 13214, 13884, 13885, 13883, 13886, 7760,
+		13882, 8183, 15082, 15078, 15081, 15083, 15080, 15079, 2900, 15690, 15691, 15692, 8874,
+		8875, 16157, 16156, 16158, 16550, 16549, 16897, 2241, 16898, 16896, 2242, 9644, 17197,
+		17198, 17590, 17725, 17830, 4994, 5242, 9167, 4995, 12584, 13215, 2903, 9892, 10529, 2836,
+		368, 1264, 10530, 10532, 4996, 10533, 10531, 10740, 10741, 10735, 18352, 10739, 5244, 5245,
+		10736, 10734, 10733, 10737, 5243, 10738, 11503, 11074, 5617, 11073, 11075, 5616, 11080,
+		5620, 11070, 11084, 11090, 5627, 11087, 5624, 2902, 11077, 11083, 11085, 1252, 11072, 5623,
+		5618, 11078, 5625, 5628, 5622, 5615, 11082, 11076, 11089, 5630, 5626, 5621, 11081, 6418,
+		11079, 5619, 5629, 895, 11071, 11086, 11088, 646, 2904, 369, 1257, 6000, 5994, 6002, 5999,
+		11510, 11513, 5995, 2905, 11504, 5988, 5998, 6001, 6003, 11525, 11516, 1265, 5987, 11524,
+		5991, 5989, 5984, 11508, 11523, 11512, 11515, 11528, 18068, 6004, 11522, 5996, 11517,
+		11527, 11514, 11518, 11521, 3584, 868, 11506, 11526, 11520, 18347, 3160, 11505, 2906,
+		11509, 5997, 5992, 5985, 5990, 5986, 6005, 11507, 11999, 5993, 3651, 11519, 11148, 12002,
+		6432, 1266, 11986, 1258, 11997, 11993, 1273, 12012, 12004, 11994, 12003, 11988, 11992,
+		12015, 6435, 12007, 11998, 6419, 12013, 11984, 12006, 6434, 6429, 11983, 6427, 6426, 6431,
+		12005, 6423, 6430, 11511, 13216, 12000, 2675, 11990, 6433, 11991, 11995, 6436, 11985,
+		12009, 12008, 12016, 6425, 6422, 12014, 12010, 12001, 11996, 6428, 6424, 11987, 6420,
+		12011, 6421, 11989, 2908, 1246, 18547, 3404, 370, 12590, 12592, 12597, 12617, 6905, 6895,
+		12589, 6906, 6907, 12600, 12607, 12613, 12594, 12601, 1275, 6908, 12615, 12610, 6903, 6901,
+		12606, 12602, 12588, 6892, 12593, 12585, 1303, 6900, 6902, 12598, 12595, 6897, 12611, 6894,
+		12596, 12618, 12604, 1134, 12603, 6893, 6904, 6896, 12586, 12591, 12608, 12614, 6909,
+		12587, 12599, 12605, 6899, 1278, 12616, 12609, 12612, 18737, 6898, 3868, 13232, 13220,
+		7339, 13249, 13257, 13260, 7337, 13228, 13253, 13229, 13243, 7350, 13239, 7347, 18129,
+		13240, 13217, 13254, 1280, 7356, 13251, 13241, 13262, 13236, 13918, 13265, 7354, 13234,
+		13246, 7357, 7336, 13238, 13263, 13231, 13224, 13235, 13223, 13221, 7332, 13255, 13230,
+		13258, 2677, 13259, 7340, 7345, 7341, 7349, 13250, 7342, 13242, 13226, 7334, 13218, 13252,
+		13261, 13225, 7348, 13266, 13237, 13233, 7333, 7353, 2911, 13248, 7355, 13244, 7344, 7338,
+		7346, 7335, 7352, 13245, 13227, 13247, 13222, 13256, 2392, 18099, 3587, 13264, 3645, 2461,
+		1138, 13219, 18422, 2731, 13917, 13939, 7714, 13922, 18613, 13920, 13899, 7764, 13915,
+		13935, 13911, 7772, 13934, 13909, 7351, 13925, 13910, 13932, 7771, 7763, 2666, 13888,
+		13896, 13921, 13913, 13938, 13916, 7777, 13933, 2820, 7768, 1294, 13919, 13927, 13900,
+		13926, 13943, 13895, 13928, 2244, 13941, 1732, 13889, 7343, 1288, 13903, 13902, 7770, 3722,
+		13906, 13908, 13923, 13940, 7773, 18790, 7774, 2913, 13894, 7766, 13898, 13936, 7775, 7767,
+		7769, 7776, 13942, 13929, 13924, 1290, 2524, 13905, 13904, 7765, 13890, 7762, 13914, 13891,
+		13912, 13901, 13937, 1671, 2229, 7761, 73, 13897, 2915, 13893, 13931, 13892, 8196, 14557,
+		1144, 1305, 14540, 2146, 14518, 14548, 8188, 14533, 14543, 8191, 14536, 8189, 14521, 14551,
+		14520, 14539, 2277, 14519, 14537, 14546, 14544, 14532, 14527, 14525, 3127, 14526, 14545,
+		14556, 14534, 8190, 14529, 14549, 8193, 14528, 14522, 14538, 8197, 2452, 2917, 8185, 8194,
+		14530, 14524, 8187, 13930, 8186, 14541, 8184, 8199, 8192, 14542, 14531, 14523, 14550,
+		14535, 8198, 14554, 8195, 14553, 14547, 14552, 4034, 14555, 1301, 2918, 1245, 1306, 2328,
+		15123, 15124, 15111, 15110, 15106, 8560, 8552, 15126, 15125, 13887, 15103, 15107, 15101,
+		15120, 15105, 2919, 15127, 15098, 15096, 15108, 15118, 15113, 8562, 15087, 15117, 1729,
+		117, 18279, 8563, 13907, 15099, 15090, 15093, 15131, 15100, 15091, 15089, 15086, 2920,
+		8556, 15109, 8555, 8559, 15104, 15112, 8549, 15128, 15132, 8551, 15095, 15115, 15119,
+		15092, 15129, 8558, 8557, 15084, 8561, 15122, 15094, 15114, 15088, 15102, 15097, 8553,
+		8554, 15116, 15133, 15130, 15121, 18608, 1311, 2921, 15085, 8550, 15709, 15700, 15703,
+		18142, 8881, 15705, 15694, 15701, 15707, 8878, 8882, 8876, 1256, 18059, 15696, 15712,
+		15706, 2922, 15697, 15721, 15715, 15693, 16177, 15699, 8877, 1284, 15710, 15720, 8885, 876,
+		15717, 15698, 15704, 15708, 15702, 15722, 15719, 15716, 8879, 8880, 8884, 15695, 15723,
+		8883, 15713, 2924, 2925, 15711, 1315, 15714, 16190, 16171, 16176, 16189, 16175, 16167,
+		16172, 9170, 16160, 16184, 16186, 16159, 9169, 16188, 16180, 9176, 16187, 16173, 9178,
+		16165, 15718, 16181, 16178, 16161, 16192, 9172, 54, 9173, 16163, 16179, 869, 16191, 16182,
+		16169, 9175, 9171, 16183, 16170, 16193, 16164, 16174, 16185, 16166, 16168, 9368, 16162,
+		9177, 9361, 9168, 9174, 9365, 16566, 16556, 16551, 16557, 16567, 16571, 16570, 9367, 9366,
+		16558, 16572, 16561, 16553, 1321, 16555, 16554, 16560, 16565, 16552, 16559, 16564, 9364,
+		16562, 9362, 16563, 9360, 9363, 16914, 16568, 9516, 893, 16906, 16910, 16902, 16905, 16913,
+		9514, 16918, 16911, 2926, 16903, 16919, 16912, 9517, 9518, 16916, 16904, 9513, 9515, 16899,
+		16907, 16901, 2462, 16917, 16915, 16900, 16908, 17205, 9519, 16909, 9646, 9648, 9645, 2456,
+		17210, 17202, 17200, 17204, 17201, 1323, 17206, 17208, 9649, 9651, 17207, 9652, 9650,
+		17209, 18144, 818, 2246, 9647, 77, 1924, 1318, 9723, 17413, 17419, 9725, 17203, 17418,
+		17425, 17416, 17423, 17422, 17199, 17417, 16569, 17426, 17415, 17420, 1328, 17424, 17414,
+		17594, 9724, 17421, 3941, 17730, 17595, 17593, 17591, 17592, 17596, 9853, 17728, 17726,
+		17731, 17729, 17732, 9854, 17727, 1526, 17831, 2220, 17903, 17904, 17905, 17999, 17989,
+		10534, 5631, 6006, 12018, 12017, 6437, 6910, 12620, 12619, 7358, 7779, 7778, 7780, 14558,
+		15134, 15724, 15726, 15725, 9179, 16194, 16573, 17965, 4997, 1342, 11093, 11094, 11092,
+		11091, 5632, 2928, 11532, 11530, 11529, 6007, 6009, 6008, 11531, 1333, 12030, 12027, 12025,
+		6438, 12028, 6443, 12022, 12037, 12039, 12023, 2929, 6440, 12040, 12035, 12021, 12026,
+		12036, 12032, 12029, 6445, 12038, 12024, 2255, 12033, 12019, 6444, 6441, 12031, 12034,
+		12020, 6442, 6439, 3777, 6920, 12627, 6919, 2930, 12630, 12633, 6916, 6914, 12622, 12631,
+		12629, 12626, 12634, 12635, 12637, 12638, 12621, 6913, 12624, 12623, 12636, 6915, 12625,
+		6917, 6912, 12628, 6921, 6918, 12632, 7365, 13281, 13270, 7362, 13949, 13279, 13267, 7360,
+		13272, 7363, 13276, 13274, 7366, 7359, 13268, 13271, 7364, 13269, 13280, 13278, 13273,
+		13275, 13277, 7361, 2931, 13950, 13956, 13946, 13952, 7781, 13948, 7787, 7786, 7785, 13955,
+		7788, 7789, 13945, 13958, 7790, 7784, 7783, 13954, 7791, 13944, 13947, 13957, 13953, 14590,
+		14579, 7782, 8206, 14567, 14588, 14568, 8201, 14565, 14574, 14566, 8203, 14560, 14587,
+		8204, 14584, 14585, 14561, 8209, 14576, 14570, 14577, 14564, 14589, 14581, 14575, 14559,
+		8205, 14582, 8208, 14586, 14580, 8202, 14578, 14572, 8200, 14563, 14571, 14583, 14569,
+		14573, 8573, 13951, 15152, 15161, 15151, 15147, 15144, 8574, 15142, 8207, 15135, 8572,
+		15141, 8571, 15146, 15143, 15159, 15154, 15148, 15153, 8567, 15145, 15164, 15136, 15137,
+		15160, 8568, 15165, 8570, 15166, 15150, 14562, 15156, 15149, 15158, 15155, 3569, 1334,
+		15140, 8565, 15163, 8566, 15138, 8569, 15736, 15157, 15162, 18113, 15733, 8564, 8886,
+		15743, 15739, 3642, 15737, 15732, 15747, 2933, 4028, 8890, 15728, 15740, 15741, 15731,
+		15730, 15744, 15734, 15729, 15735, 15746, 15727, 15745, 15742, 8888, 8887, 8889, 15738,
+		16590, 3104, 4025, 16196, 9185, 16200, 16197, 16204, 16210, 16199, 1338, 16219, 9182,
+		16213, 16202, 16214, 16209, 16216, 16201, 9187, 9186, 16203, 16217, 16195, 16215, 9180,
+		4042, 16207, 16206, 16211, 16198, 9184, 9188, 16205, 16212, 9189, 16208, 4026, 9181, 9183,
+		16586, 16584, 3928, 16591, 16588, 16592, 1339, 16577, 16585, 16596, 16218, 16581, 9372,
+		15139, 16576, 16589, 16583, 16580, 16575, 16574, 16595, 16579, 16578, 9370, 16587, 1336,
+		9369, 9371, 16582, 16594, 3958, 16923, 16924, 9523, 16921, 9520, 16929, 9524, 16931, 16920,
+		16593, 16933, 16922, 3641, 9521, 16928, 16930, 16925, 16932, 16927, 16926, 9522, 1340,
+		17212, 17213, 17215, 9653, 9654, 17216, 17214, 3902, 17211, 17429, 17432, 17428, 9729,
+		17430, 9728, 9727, 9726, 17431, 17601, 17599, 3377, 17598, 17427, 17600, 17433, 17597,
+		2258, 17735, 17701, 17733, 9855, 17734, 17736, 17834, 9886, 17833, 17832, 9887, 9917,
+		17944, 17967, 17966, 17968, 4998, 11533, 3869, 12041, 12042, 1345, 1346, 13282, 17434,
+		17835, 4999, 6010, 11534, 1394, 12639, 6922, 13284, 13283, 7367, 7792, 15167, 8575, 8576,
+		1350, 1926, 8891, 9888, 5000, 758, 3357, 11535, 5634, 11537, 11536, 6011, 12043, 2261,
+		12050, 6446, 12048, 12046, 12053, 12044, 12045, 6447, 6451, 12054, 6450, 12052, 12049,
+		12047, 6448, 6449, 12051, 18821, 2263, 6924, 12640, 6929, 12656, 6928, 12658, 790, 871,
+		12646, 6926, 12657, 12641, 6927, 12653, 12649, 12651, 12645, 12652, 1351, 85, 6923, 12648,
+		3943, 12647, 12643, 12654, 12650, 12642, 12644, 6925, 12655, 2936, 7370, 13295, 1353, 1354,
+		13291, 13293, 13288, 13287, 13285, 13292, 13294, 13290, 2264, 13289, 7368, 7369, 1356,
+		3643, 13297, 7800, 13960, 13966, 13961, 13962, 18016, 13967, 7802, 7794, 7801, 13959,
+		13286, 7797, 7795, 13965, 13964, 7796, 7798, 13963, 7793, 7799, 2265, 14592, 8216, 14603,
+		14602, 14597, 8218, 14601, 14593, 14594, 8210, 8212, 3378, 14599, 14591, 8214, 8213, 14595,
+		14600, 14598, 8215, 14596, 2937, 18826, 13296, 8211, 15168, 15175, 8578, 15179, 8582,
+		15171, 15169, 15178, 8577, 15177, 8579, 8580, 15170, 8581, 15176, 15172, 15173, 15174,
+		8217, 15748, 15760, 8896, 15755, 15757, 2938, 8894, 15749, 15753, 15756, 8892, 8895, 15759,
+		15751, 15752, 15758, 15750, 15754, 8893, 16221, 16220, 9191, 16226, 9193, 9190, 16222,
+		9194, 16223, 16224, 16227, 3393, 9192, 16601, 2939, 16604, 16598, 16600, 2267, 16599,
+		16602, 16603, 16225, 16597, 2269, 9527, 16937, 16940, 16942, 16935, 16936, 16939, 16941,
+		9528, 9526, 9525, 16938, 16934, 17217, 9655, 2266, 17218, 17436, 9730, 17438, 9731, 17435,
+		17437, 9804, 17602, 9803, 17739, 17738, 17737, 3326, 17906, 17907, 17909, 17908, 10535,
+		5001, 2797, 6012, 12659, 7371, 13968, 9373, 1359, 16943, 4021, 1360, 5246, 6931, 2940,
+		6930, 4012, 13298, 7372, 13300, 13299, 13969, 7803, 14604, 14607, 14606, 14605, 15180,
+		15182, 15181, 3164, 8898, 1363, 4039, 8897, 9195, 16228, 16230, 16229, 9374, 16945, 16944,
+		17219, 9656, 1364, 9732, 17604, 17603, 9918, 759, 371, 5247, 11538, 6013, 12662, 12660,
+		12661, 13302, 13303, 13976, 13301, 13970, 13974, 13973, 13975, 7804, 13972, 13971, 13977,
+		1366, 14610, 14608, 14609, 15185, 15183, 15184, 15761, 16231, 16232, 9375, 16946, 17220,
+		9657, 17439, 17605, 9805, 17740, 17910, 5248, 6015, 6016, 11539, 11540, 6014, 6457, 6455,
+		6454, 6461, 6453, 6462, 12055, 6459, 6456, 6460, 6458, 6452, 6939, 2282, 6933, 12666, 6938,
+		6940, 6934, 6935, 12664, 2942, 6932, 2329, 12665, 6937, 12663, 6936, 7383, 7385, 2943,
+		13306, 7384, 7373, 7377, 2281, 7823, 13982, 13308, 7378, 13311, 13312, 7382, 13314, 4029,
+		13316, 13317, 13305, 13304, 13318, 7381, 13315, 13313, 7379, 7375, 7386, 13309, 13310,
+		13307, 7380, 7376, 7374, 13981, 7818, 7813, 7808, 3923, 7824, 7809, 7805, 7820, 7817, 7819,
+		7810, 7815, 7806, 7807, 13989, 13986, 13978, 13983, 7821, 13990, 7822, 7812, 3949, 13980,
+		13988, 13984, 13987, 13985, 7816, 13979, 7811, 14613, 8220, 8223, 14615, 1372, 8231, 14614,
+		8225, 2944, 8587, 14616, 8230, 14612, 8232, 1369, 8221, 7814, 8224, 8222, 8226, 8228, 8219,
+		8233, 8229, 1387, 8227, 14611, 1391, 8594, 8590, 2945, 8597, 15188, 8598, 15200, 15193,
+		8583, 15201, 15195, 8593, 15196, 8592, 15199, 8586, 15197, 15187, 8585, 15772, 8591, 8588,
+		934, 8596, 15186, 15190, 8584, 15189, 15191, 15192, 8595, 15194, 15198, 15202, 1787, 8599,
+		8904, 15767, 15773, 15770, 15762, 15774, 15763, 15769, 8899, 8905, 15775, 3302, 8901, 8911,
+		8906, 15777, 15771, 8902, 15764, 8912, 15765, 15766, 8913, 8910, 8589, 919, 8900, 15778,
+		8914, 8907, 15776, 8903, 8908, 8909, 9203, 16237, 16246, 16243, 16245, 9200, 16238, 1380,
+		16244, 9196, 16240, 9204, 16236, 16241, 16248, 15768, 16242, 16235, 9197, 16234, 9198,
+		16249, 9199, 16247, 9202, 16233, 1381, 9201, 16239, 16607, 16621, 16616, 16618, 16606,
+		9376, 16605, 9379, 9378, 3936, 16620, 16612, 16609, 16615, 16608, 16610, 16619, 16617,
+		9377, 16624, 16622, 16613, 16623, 16614, 1384, 16950, 9529, 16957, 4031, 17442, 9536,
+		16611, 16948, 9532, 16949, 16954, 1385, 1386, 9534, 9535, 16947, 16956, 16958, 16951,
+		16953, 16955, 16952, 16959, 9531, 9537, 9533, 9530, 17223, 3238, 9662, 17221, 3294, 17225,
+		17226, 9660, 17227, 17224, 2367, 17222, 9663, 9659, 17228, 9661, 9658, 9733, 9734, 17441,
+		17440, 17443, 17444, 9735, 17606, 17611, 9806, 3131, 17608, 861, 17607, 17610, 17609,
+		17744, 9856, 17743, 17741, 17742, 105, 1389, 9890, 9889, 17836, 17837, 9891, 17911, 17912,
+		9930, 1390, 9936, 17969, 18000, 761, 372, 373, 5249, 12667, 12668, 13991, 14617, 15203,
+		9206, 9205, 16625, 16960, 17745, 5250, 12056, 6463, 6941, 13993, 13992, 8600, 15204, 8601,
+		16250, 9380, 2946, 9945, 5251, 11095, 12057, 6942, 12669, 12670, 13319, 7387, 7825, 13995,
+		13994, 13996, 14618, 14619, 8234, 8915, 8602, 8916, 16254, 16251, 16253, 16252, 9207,
+		16626, 16962, 16961, 10742, 6465, 6464, 12058, 12671, 13321, 13320, 7388, 13998, 13999,
+		13997, 7827, 7826, 8236, 8235, 15205, 15781, 15779, 15780, 8917, 16256, 16255, 16629,
+		16628, 16627, 16963, 1395, 17970, 5252, 6017, 2292, 6018, 6466, 6467, 12060, 12059, 12672,
+		6948, 6946, 6943, 6947, 6945, 6944, 4030, 7389, 13324, 7832, 7391, 7396, 13326, 7398, 7397,
+		7400, 13325, 13322, 7394, 7390, 7392, 13323, 7399, 7395, 7393, 7834, 7833, 7831, 7835,
+		7829, 7830, 7828, 14000, 3619, 14621, 8238, 8239, 8237, 2947, 14620, 1401, 14622, 15208,
+		15210, 1407, 8611, 15211, 8604, 15207, 8603, 8613, 8609, 8610, 8606, 15206, 8605, 15212,
+		15209, 2293, 8612, 3939, 8607, 8608, 15783, 15785, 15784, 2948, 15786, 8918, 15782, 3961,
+		9211, 16257, 9208, 9212, 9210, 9209, 16630, 16633, 16632, 1405, 16631, 9381, 16964, 16965,
+		9538, 16966, 9539, 392, 3933, 9665, 9664, 17445, 1409, 1404, 9736, 17446, 17612, 9807,
+		9808, 3576, 17746, 9893, 2949, 762, 5253, 6950, 6949, 14001, 14002, 8240, 15787, 8614,
+		15788, 16258, 5254, 11541, 6020, 6019, 12061, 6468, 12062, 12675, 1822, 12674, 12673,
+		13328, 7403, 3884, 13327, 7402, 13329, 7401, 14004, 14007, 14005, 14006, 14010, 14003,
+		14011, 14008, 14009, 8242, 14623, 8241, 15216, 15214, 8615, 15213, 15215, 8616, 15789,
+		4037, 15790, 9213, 1413, 16968, 16970, 16967, 16969, 17229, 17447, 17945, 5255, 6021,
+		12063, 12065, 12064, 12678, 6952, 12676, 6951, 12679, 569, 12680, 12677, 2952, 13341, 7411,
+		13333, 13339, 7406, 7409, 13332, 7404, 13336, 14017, 7408, 13331, 3910, 13337, 13334,
+		13340, 13330, 13338, 7407, 7410, 13335, 7405, 7837, 14013, 7836, 14020, 14019, 7843, 7844,
+		14021, 7838, 14018, 7842, 14023, 14014, 14016, 7839, 14012, 14015, 14022, 7840, 14024,
+		7841, 8243, 14628, 14634, 14626, 14635, 3913, 14625, 14630, 14636, 14633, 14632, 14631,
+		14624, 14629, 14627, 3547, 8621, 8618, 15225, 15230, 15232, 15229, 15222, 15224, 15234,
+		15231, 15226, 15235, 15223, 15233, 8619, 8625, 8623, 15217, 8624, 8620, 15218, 15220,
+		15219, 15227, 15228, 3916, 8622, 2300, 8617, 3637, 15221, 15800, 8920, 15791, 8921, 8924,
+		15796, 15793, 8923, 2955, 15797, 15798, 15792, 15801, 15794, 15799, 8922, 8919, 15795, 481,
+		16264, 9216, 9214, 9217, 9215, 16263, 16260, 16259, 3911, 16262, 16261, 16647, 9387, 16638,
+		16637, 9382, 16643, 16642, 16634, 16645, 16639, 9386, 16636, 16644, 16635, 9383, 9385,
+		16640, 9384, 16641, 540, 16646, 16974, 9544, 16971, 16975, 2958, 9541, 16973, 9546, 3917,
+		9543, 3907, 16972, 9545, 16976, 9540, 499, 3530, 9667, 9669, 17232, 9668, 17230, 9542,
+		17231, 9666, 9737, 9739, 17449, 9738, 17448, 17617, 9809, 17616, 9810, 17614, 17613, 17618,
+		17619, 17747, 17615, 17841, 17838, 17839, 17840, 9919, 17915, 17914, 17913, 17946, 2301,
+		17990, 17971, 9937, 5256, 6469, 1421, 2960, 7845, 1422, 873, 8626, 1492, 15802, 9388,
+		16977, 1423, 17972, 5257, 5635, 6023, 6022, 2962, 6472, 12066, 6470, 12067, 6471, 12685,
+		12681, 3251, 6953, 12684, 12683, 12682, 6954, 12686, 12687, 2963, 2964, 13348, 13346,
+		13350, 13351, 13357, 13353, 13355, 2965, 13347, 13342, 13354, 2966, 13356, 13349, 13352,
+		13343, 7413, 13345, 13344, 7412, 7414, 7848, 14026, 14028, 14027, 14032, 7846, 14029,
+		14025, 14030, 14031, 7847, 7849, 14033, 14640, 14639, 14637, 14638, 8245, 8247, 8244, 8246,
+		15242, 15243, 15238, 1425, 15239, 8628, 8633, 8627, 8634, 8629, 15240, 15241, 15237, 8635,
+		8631, 8630, 8632, 15236, 1426, 15804, 8926, 2303, 15806, 8928, 15809, 15805, 15803, 15808,
+		8927, 15807, 8925, 9219, 9222, 16266, 2640, 9220, 16265, 9218, 9221, 16648, 16649, 16650,
+		9389, 2304, 16651, 9390, 9548, 16980, 16981, 16979, 16978, 16982, 9547, 17236, 17234,
+		17235, 17237, 17233, 17238, 17451, 3824, 17452, 17450, 9740, 17620, 9811, 17621, 17749,
+		17748, 374, 375, 376, 2855, 5258, 7415, 7850, 3166, 8248, 1429, 8930, 800, 8929, 9549,
+		9637, 9741, 5259, 6473, 7851, 1433, 16983, 10007, 3053, 2967, 18820, 3519, 3517, 2829,
+		2831, 3515, 10744, 2968, 5260, 5263, 5262, 5261, 10743, 2969, 11097, 2970, 11099, 5636,
+		875, 3486, 5638, 11096, 11098, 5637, 11101, 11100, 11102, 3990, 11545, 11543, 6026, 11542,
+		6031, 6028, 6025, 6032, 6027, 6030, 6029, 11544, 6024, 6479, 12069, 12075, 6476, 6483,
+		1440, 6478, 12072, 12074, 6481, 12068, 12071, 6477, 6474, 12070, 6480, 12073, 6482, 6475,
+		3732, 12689, 12692, 6956, 6966, 6962, 12690, 3511, 6970, 6963, 6968, 6958, 6955, 6957,
+		6969, 12691, 6961, 6964, 6960, 6965, 12693, 6967, 6959, 12688, 13358, 7416, 13360, 7418,
+		7420, 2971, 13359, 7417, 7421, 1447, 7419, 7857, 14038, 7865, 14035, 7866, 7855, 14036, 91,
+		7860, 14037, 7853, 7852, 3789, 7863, 7862, 7861, 7859, 7864, 14034, 7854, 7856, 7858, 3291,
+		8250, 8253, 8257, 8251, 8256, 8254, 8249, 1450, 8255, 8252, 2481, 15247, 8638, 8636, 15248,
+		2761, 8639, 8637, 15246, 15245, 8934, 15244, 8932, 8931, 15810, 8640, 8933, 15811, 8936,
+		15812, 8935, 9224, 16268, 9223, 9228, 9226, 9227, 9392, 9225, 16267, 15813, 9391, 9393,
+		9550, 9551, 17239, 9858, 9857, 5265, 10540, 6484, 10537, 10538, 10536, 10539, 10748, 10745,
+		10750, 10746, 5266, 5269, 10747, 5268, 10749, 1453, 5267, 1457, 11105, 11107, 5641, 11103,
+		11106, 11104, 5639, 5642, 5640, 2316, 11547, 11551, 11548, 6035, 6036, 1455, 11550, 11552,
+		11554, 6033, 11553, 6034, 12700, 11549, 12076, 12085, 12083, 12078, 12079, 12084, 12086,
+		6486, 12081, 12077, 6485, 6487, 12080, 12087, 12082, 6971, 12703, 12695, 12701, 12702,
+		6972, 12694, 12696, 11546, 12698, 12699, 12697, 7423, 13367, 15814, 13368, 13365, 6973,
+		7425, 13364, 13370, 13369, 7422, 13373, 13363, 13372, 13361, 13371, 13366, 7424, 14045,
+		14040, 14046, 14041, 14039, 14042, 7867, 14044, 14043, 7868, 8259, 8258, 14647, 14650,
+		14642, 14646, 8260, 14645, 14643, 14649, 14644, 14641, 14648, 15254, 8643, 16652, 15251,
+		15252, 15250, 13362, 8642, 15255, 15249, 8641, 8644, 15253, 15815, 8937, 15816, 15817,
+		16269, 9229, 16653, 16654, 16655, 16985, 16984, 17241, 17240, 17454, 17455, 17453, 17622,
+		9812, 5270, 6038, 6037, 6490, 6489, 12089, 12090, 12088, 3848, 6488, 12707, 12708, 12704,
+		6974, 12705, 3844, 12706, 3846, 389, 13376, 13378, 13374, 13377, 7426, 13375, 7427, 1459,
+		13553, 7871, 7870, 7869, 14047, 14048, 3660, 14652, 14654, 8264, 8261, 3621, 8263, 8262,
+		14653, 14651, 3890, 15262, 15259, 15260, 8648, 15261, 15256, 15257, 8645, 8646, 15258,
+		8647, 387, 15821, 3854, 15822, 15819, 15820, 8938, 15818, 16273, 16270, 16272, 9232, 16276,
+		9231, 16274, 16277, 16275, 16271, 9230, 16657, 16658, 16656, 1461, 16660, 9394, 9395,
+		16987, 9553, 16659, 16986, 9552, 17244, 17245, 9670, 17243, 663, 17242, 17456, 9742, 2320,
+		17750, 17843, 17842, 9894, 9920, 17844, 17947, 1462, 9938, 5271, 5643, 7872, 9671, 5272,
+		6039, 6975, 7428, 9396, 5644, 11556, 11555, 12091, 18399, 6493, 6491, 6495, 12093, 6494,
+		6492, 323, 12092, 6978, 12714, 2382, 6977, 6979, 12719, 6981, 12716, 12717, 12709, 6980,
+		12720, 12711, 12712, 12710, 6976, 801, 12718, 12713, 12715, 2109, 13390, 1371, 13389,
+		13387, 13379, 13394, 13382, 13392, 13399, 13391, 7436, 3662, 7432, 13380, 13386, 7434,
+		1466, 13385, 7435, 7437, 13388, 2975, 7429, 7430, 13400, 13398, 80, 13397, 13383, 13395,
+		7433, 878, 7431, 13396, 13381, 13384, 13393, 2977, 2390, 2285, 14689, 14058, 14051, 14070,
+		14079, 14054, 7883, 2155, 14067, 7873, 7875, 7887, 14052, 18521, 7876, 7878, 7888, 7877,
+		1468, 1486, 7886, 14060, 14049, 7884, 14059, 7880, 14077, 7885, 14075, 14063, 14073, 7882,
+		14050, 14076, 14071, 3467, 14078, 7874, 2978, 7889, 7879, 1856, 14056, 2601, 14064, 14068,
+		1469, 14072, 7881, 14055, 14053, 14065, 2979, 14062, 14061, 2638, 14066, 14069, 14074,
+		14657, 2477, 8265, 14667, 14659, 8270, 8274, 14676, 14684, 14669, 8266, 3367, 14673, 14057,
+		2562, 8267, 14680, 14685, 14677, 2041, 14679, 14688, 14681, 14663, 14672, 1871, 8275, 8271,
+		14661, 14674, 8269, 14668, 8268, 14682, 14665, 14658, 8272, 14687, 14660, 14678, 14675,
+		14670, 14656, 14655, 14664, 14683, 8273, 14686, 14662, 14666, 8653, 2981, 2980, 8661, 8656,
+		15295, 15292, 15268, 8651, 18015, 2365, 8650, 8657, 2473, 2982, 15266, 8655, 15278, 15264,
+		15265, 8649, 15294, 8659, 15281, 15286, 15280, 15840, 15276, 15284, 15282, 15269, 15263,
+		15290, 8658, 15291, 1424, 15285, 15274, 15272, 15267, 18056, 15275, 15288, 15271, 15287,
+		15293, 2983, 8654, 2539, 15283, 15289, 15279, 15273, 8652, 2984, 1693, 14671, 15277, 8660,
+		15270, 2038, 2985, 2986, 2380, 15846, 8941, 15845, 15842, 15861, 8945, 2987, 15858, 15850,
+		15860, 15835, 15848, 1430, 8947, 15827, 2134, 15825, 15859, 15857, 15856, 15839, 15838,
+		15828, 8949, 15834, 15844, 15855, 8952, 15862, 9240, 8954, 8948, 15832, 15841, 15824,
+		15826, 8939, 8951, 8944, 15833, 15849, 15843, 8950, 15823, 9235, 15851, 15854, 8946, 1477,
+		15837, 8953, 8943, 3370, 2566, 8942, 15847, 15853, 8940, 15830, 15852, 15831, 18390, 18688,
+		2111, 2988, 18066, 3581, 15836, 16283, 2474, 16288, 9237, 9239, 16294, 9233, 16309, 16300,
+		16289, 16290, 16299, 9246, 16298, 16282, 16297, 16285, 16310, 9245, 9243, 16286, 16305,
+		2564, 16291, 16304, 18673, 16306, 16281, 9238, 1858, 16307, 2360, 1690, 16295, 1478, 9242,
+		16292, 3956, 9244, 16301, 1490, 2605, 9236, 16287, 16302, 1582, 16296, 15829, 16303, 16284,
+		2413, 9241, 16308, 1694, 9234, 16279, 2989, 18761, 2569, 2085, 16674, 16667, 9398, 16662,
+		16677, 16669, 16293, 2693, 16679, 16663, 9397, 16673, 9399, 9407, 9405, 16675, 9406, 16665,
+		1765, 16666, 16671, 16676, 2037, 16278, 9400, 16684, 16681, 16685, 16672, 16668, 16682,
+		16998, 16670, 9403, 2990, 9402, 16280, 9404, 16678, 16680, 9401, 16683, 16661, 16664, 2521,
+		2520, 1894, 3425, 17007, 16991, 9557, 17005, 1763, 16989, 9558, 17000, 1695, 16996, 9563,
+		17006, 16990, 16993, 9555, 17008, 18269, 17001, 17004, 9561, 9566, 9564, 16997, 16992,
+		2472, 9559, 9560, 16988, 9556, 1887, 9554, 9562, 17003, 9565, 16999, 17009, 9567, 16995,
+		17002, 18631, 18741, 1691, 827, 17258, 17272, 17260, 17267, 16994, 17265, 17248, 17270,
+		9674, 17252, 17269, 17259, 2589, 9673, 2321, 17274, 17261, 1892, 17266, 17268, 17246,
+		17271, 17257, 17262, 17250, 17254, 17273, 17264, 17247, 17251, 17253, 17263, 2994, 9672,
+		17256, 18374, 2996, 17249, 1660, 1541, 1495, 2325, 17255, 17460, 17465, 9749, 17467, 2997,
+		9743, 75, 17463, 17469, 9748, 9744, 9745, 17459, 17466, 9747, 17464, 9746, 17458, 17462,
+		17461, 2998, 17457, 17468, 18683, 18686, 9813, 17629, 17632, 17628, 17630, 17631, 17625,
+		17626, 17623, 2478, 17627, 17624, 9814, 9815, 3000, 17752, 17754, 2424, 1491, 17753, 17755,
+		9860, 17751, 9859, 9861, 3311, 2044, 17846, 17847, 9895, 17845, 1187, 17917, 17916, 17918,
+		9922, 17919, 9921, 17920, 17948, 17951, 17950, 9931, 17949, 3001, 18464, 9941, 9939, 9940,
+		9946, 17992, 17993, 17991, 18001, 763, 3374, 783, 3073, 3069, 3059, 3052, 3002, 3506, 5645,
+		764, 12721, 13401, 15296, 17010, 765, 5646, 6040, 6496, 1493, 12722, 12723, 6982, 7440,
+		13403, 13402, 7444, 7439, 13404, 7441, 7443, 7442, 7438, 2333, 2332, 7890, 2330, 14083,
+		14081, 14082, 14080, 1496, 8276, 3003, 8279, 8281, 8280, 1494, 2331, 8277, 8278, 644,
+		15298, 15297, 8662, 15299, 15300, 8663, 15870, 15868, 16316, 15866, 15867, 8955, 15863,
+		15871, 15865, 15869, 16312, 16314, 16315, 9251, 16311, 9250, 16313, 9247, 9248, 9249,
+		15864, 9410, 16688, 16686, 16687, 9408, 9411, 9409, 17011, 17012, 9568, 17275, 17277,
+		17276, 9675, 9750, 17472, 17471, 17470, 766, 3005, 5647, 781, 10434, 10543, 5002, 10541,
+		10544, 10542, 10752, 5276, 5277, 10754, 5274, 10753, 10751, 5275, 5273, 3006, 3997, 11108,
+		11111, 5650, 11110, 11109, 5649, 5648, 3836, 5652, 11112, 5651, 2932, 11561, 6042, 6043,
+		6044, 11562, 11558, 6041, 11559, 11560, 11557, 3322, 6503, 6500, 12094, 6501, 6504, 12095,
+		6499, 6497, 6498, 6502, 2909, 6983, 12726, 6991, 12725, 12728, 6987, 12727, 7452, 6985,
+		6988, 6984, 6989, 6990, 6986, 1505, 12724, 7448, 13406, 13410, 3845, 13409, 7453, 7449,
+		7450, 13405, 13407, 13408, 7445, 7447, 7451, 7446, 2342, 14086, 14084, 14085, 7892, 7893,
+		1506, 14087, 7891, 8282, 8284, 8283, 14690, 14691, 15302, 1508, 15301, 18813, 8956, 8957,
+		15872, 8958, 16319, 16317, 16318, 9252, 16689, 9569, 1511, 9253, 5653, 6505, 12096, 1514,
+		12729, 6992, 7454, 13411, 13413, 7456, 7455, 7457, 7458, 13412, 7896, 7897, 7895, 8285,
+		7894, 14088, 8286, 15303, 15873, 8959, 9254, 16690, 16694, 9414, 16691, 9415, 9413, 16695,
+		9416, 16693, 17013, 9412, 9570, 17848, 5654, 6994, 6993, 7459, 13415, 13414, 7460, 1857,
+		14092, 7901, 7898, 14091, 7900, 14089, 7899, 14090, 14692, 8287, 15306, 3786, 8664, 15304,
+		8665, 8666, 15305, 8667, 15874, 8963, 8960, 8965, 15876, 8961, 15875, 8964, 8962, 16322,
+		16324, 1534, 9255, 16323, 9256, 16321, 16320, 3963, 16697, 16696, 9417, 16698, 17018, 9572,
+		17017, 17014, 9571, 17015, 17016, 17278, 17279, 9676, 1525, 9753, 43, 17473, 1527, 9751,
+		9752, 17474, 9816, 9817, 17633, 3010, 2353, 9896, 17850, 9898, 17849, 17851, 9897, 17921,
+		79, 17973, 726, 5655, 823, 7902, 1537, 14693, 15307, 8966, 8967, 3427, 5656, 1540, 8668,
+		9573, 6045, 8968, 9862, 6046, 12730, 13416, 3617, 13418, 13417, 2372, 14096, 14093, 7903,
+		7904, 14094, 14095, 14695, 14700, 8288, 14694, 14696, 14703, 14698, 14702, 14699, 14697,
+		14701, 8289, 18236, 15310, 1545, 15308, 8670, 8669, 15309, 8671, 15879, 15878, 8969, 15877,
+		16325, 2371, 16327, 16329, 16328, 1546, 9257, 16326, 16705, 9418, 16703, 16706, 9419,
+		16701, 16704, 16702, 16699, 9420, 16700, 1547, 3012, 17019, 3826, 17021, 17020, 3168,
+		17280, 3828, 17281, 18387, 1548, 17475, 1549, 9819, 3013, 9818, 17756, 17757, 9899, 17852,
+		3822, 9932, 6047, 7461, 14705, 14704, 15312, 15311, 1550, 9258, 16331, 16330, 16709, 16707,
+		16710, 16708, 9574, 17022, 17023, 17024, 1551, 17476, 17634, 17853, 768, 6048, 1553, 15880,
+		16332, 1670, 6049, 1557, 8290, 15881, 9421, 16711, 9575, 17282, 17283, 9754, 17758, 6050,
+		6997, 6998, 12731, 7462, 7463, 13419, 7464, 7908, 7910, 14098, 14099, 14097, 7905, 7906,
+		7909, 7907, 18459, 523, 14706, 8291, 8292, 15319, 8674, 15314, 15313, 2378, 15318, 8672,
+		3438, 8976, 15315, 15320, 15317, 15316, 8673, 8974, 3893, 15883, 8970, 15884, 1562, 1561,
+		15882, 8973, 8971, 8975, 8972, 16333, 16338, 16334, 16337, 9259, 3170, 16128, 16336, 16335,
+		1563, 9424, 9422, 9425, 9423, 16712, 16713, 16714, 9426, 939, 17028, 9577, 17026, 9578,
+		17025, 17027, 9576, 17287, 17284, 17285, 17286, 17477, 9756, 1564, 9755, 888, 17636, 17635,
+		9820, 18097, 9863, 9900, 9923, 17922, 17974, 17975, 769, 6051, 13420, 14100, 14707, 14708,
+		8293, 8294, 15321, 8675, 3894, 9260, 1572, 16715, 3017, 9427, 17031, 9579, 17030, 17032,
+		17029, 17480, 17288, 17289, 2239, 9677, 17479, 1574, 1573, 17478, 1571, 17637, 17976, 770,
+		6052, 2387, 771, 6053, 2974, 1579, 6506, 12097, 12732, 1580, 7465, 7468, 7466, 13421, 1581,
+		7470, 7467, 3019, 7469, 7912, 564, 14101, 14102, 15323, 7911, 7913, 7914, 14710, 14709,
+		8295, 8296, 14711, 15322, 8298, 8676, 8297, 3021, 8977, 15324, 8678, 8677, 15325, 15328,
+		15326, 15327, 8679, 3022, 8982, 8980, 3024, 8979, 15886, 8981, 15885, 16339, 15887, 8978,
+		15888, 16342, 16340, 16341, 16346, 9431, 16345, 16348, 16347, 16344, 16343, 9261, 3025,
+		2373, 1583, 16718, 2393, 16717, 9430, 9428, 9429, 3827, 16716, 3026, 17035, 9580, 17034,
+		17033, 9581, 3969, 17294, 17293, 2394, 17291, 17290, 9679, 9678, 17295, 17638, 9821, 17482,
+		9757, 17481, 17292, 17639, 9864, 3027, 9924, 17923, 17977, 3028, 772, 6054, 12733, 16349,
+		6055, 2397, 14713, 14712, 15889, 16351, 16350, 18505, 9432, 17036, 17037, 9680, 2399,
+		17978, 6507, 7472, 7471, 14103, 14105, 7916, 14104, 7915, 7917, 14106, 1668, 14715, 14717,
+		14716, 2410, 14719, 8299, 14718, 14714, 2972, 3030, 14720, 15335, 15338, 15340, 15330,
+		15334, 15331, 8681, 8684, 8686, 15332, 15333, 8685, 15336, 15339, 15337, 8687, 3841, 8683,
+		15329, 8680, 8682, 877, 1194, 8984, 15895, 15893, 15892, 2219, 15898, 15897, 15896, 15891,
+		8983, 15890, 15894, 8985, 16354, 1588, 16358, 16355, 16357, 16356, 16353, 16359, 16362,
+		16360, 16361, 9263, 9262, 16352, 16363, 16723, 16726, 16728, 16727, 16721, 16725, 16720,
+		1589, 16722, 9433, 16719, 1591, 16724, 17050, 17039, 9582, 9583, 17038, 17043, 17049,
+		17041, 17048, 2227, 17045, 2404, 17044, 17047, 17042, 17040, 3342, 17046, 17302, 17300,
+		9681, 17299, 17305, 17303, 2406, 9682, 17298, 17296, 17297, 9684, 17301, 9683, 17304,
+		17483, 17489, 1590, 3032, 17484, 9761, 17490, 9760, 17488, 17487, 9759, 17486, 9758, 17485,
+		17645, 17647, 17646, 17643, 9823, 17640, 17644, 17649, 17648, 17641, 17642, 9822, 17759,
+		9867, 1592, 17760, 9865, 9866, 2400, 17854, 9901, 17952, 9933, 2221, 17979, 9934, 17980,
+		17981, 17995, 17994, 9949, 18005, 414, 415, 6508, 14108, 14107, 8300, 8301, 14721, 513,
+		15341, 15901, 651, 8688, 8986, 15899, 3895, 15902, 8987, 3171, 16364, 15900, 16729, 9434,
+		17051, 17308, 17306, 17307, 17491, 9762, 17650, 9870, 9824, 9868, 9869, 17855, 9925, 3954,
+		6509, 16730, 12098, 1597, 7918, 1598, 14722, 8302, 14723, 15343, 15342, 8989, 8689, 8690,
+		15346, 15345, 15344, 1600, 15903, 15906, 15905, 15904, 8988, 16368, 16366, 16365, 3796,
+		16367, 3791, 9435, 16732, 16733, 9436, 16731, 3805, 17053, 17052, 17055, 9584, 17054, 3033,
+		17309, 17311, 17310, 1602, 17492, 17495, 17493, 17494, 17651, 9825, 17761, 17762, 17763,
+		9902, 9926, 17982, 6510, 8691, 8990, 16734, 3798, 17652, 1603, 17996, 12099, 9950, 6511,
+		15907, 1606, 16735, 17056, 3769, 17496, 17653, 6512, 14724, 14725, 1611, 8303, 8304, 15348,
+		8693, 8692, 15347, 16369, 16736, 16738, 16737, 9439, 9438, 9437, 1610, 9764, 17497, 9763,
+		17655, 17654, 9903, 17856, 6999, 14109, 14728, 14726, 14727, 15357, 15352, 15349, 15356,
+		3035, 15358, 8695, 15355, 15351, 15350, 15354, 15353, 8694, 15918, 15913, 15911, 15920,
+		15912, 2435, 15908, 3036, 15914, 18593, 15909, 16378, 15910, 15921, 3746, 716, 1615, 3957,
+		15917, 8991, 15916, 15915, 15919, 3892, 16370, 16373, 3865, 16372, 2438, 16380, 16375,
+		16379, 16377, 16376, 16374, 16371, 9266, 9265, 9267, 9264, 16381, 3964, 16744, 16747,
+		16745, 16749, 16750, 16742, 9444, 16743, 16741, 16748, 16740, 16739, 9443, 9441, 9440,
+		2436, 16746, 17065, 17071, 9587, 17066, 17069, 17073, 9588, 17068, 3037, 17060, 17059,
+		17072, 17063, 17061, 17070, 17062, 9586, 9585, 2313, 17057, 17058, 17067, 3715, 17064,
+		3038, 3039, 17314, 17317, 9442, 1618, 1617, 17315, 17321, 17319, 17313, 17324, 3833, 17312,
+		1619, 9686, 17320, 2434, 17316, 9685, 17323, 3803, 17322, 3807, 17500, 17499, 715, 3041,
+		17506, 17502, 17505, 9766, 650, 17503, 17504, 17498, 17501, 9765, 3103, 9827, 17666, 17661,
+		17665, 2433, 17668, 17664, 17660, 3045, 9829, 17663, 17667, 9828, 17658, 17318, 17662,
+		17659, 3578, 17656, 17657, 9826, 17767, 17769, 17772, 17768, 17773, 2430, 17766, 17764,
+		2431, 9871, 17770, 9873, 9872, 17765, 17771, 3674, 17861, 9904, 17862, 17860, 17857, 17859,
+		17858, 17924, 17926, 17925, 17955, 3627, 17954, 17953, 17956, 9942, 9943, 18002, 18006,
+		2432, 774, 7000, 13422, 14112, 7919, 14110, 14111, 14729, 14730, 8307, 8305, 14731, 8306,
+		15363, 15366, 15360, 15367, 15359, 15361, 15362, 15365, 3834, 8698, 15370, 15369, 8696,
+		15364, 15368, 8697, 2446, 15931, 8996, 15926, 8992, 2501, 15923, 15929, 15932, 8997, 15928,
+		15925, 15933, 15924, 2441, 15930, 8993, 15922, 8994, 8995, 15927, 16399, 16386, 16387,
+		16390, 16388, 16382, 18118, 16385, 16395, 16389, 1639, 9268, 16397, 16394, 9269, 16396,
+		16383, 16392, 16393, 3955, 16391, 3044, 16754, 16756, 16759, 322, 16753, 3690, 9445, 16761,
+		16752, 16762, 16758, 16760, 16764, 16757, 16765, 16755, 16751, 9446, 3575, 16763, 9447,
+		9590, 16384, 17099, 17090, 9592, 17086, 9593, 2291, 522, 17095, 17089, 17079, 9591, 17092,
+		17087, 17088, 17074, 17081, 17096, 17093, 3767, 17085, 1628, 17097, 17080, 17075, 17094,
+		1629, 17077, 17091, 17082, 17098, 17078, 9589, 17076, 17083, 17084, 17331, 17329, 3048,
+		17334, 17346, 17337, 17330, 17326, 17339, 17332, 17335, 17328, 17342, 17325, 17333, 17338,
+		17340, 17343, 17327, 2451, 17347, 17348, 17341, 17345, 17336, 3823, 17522, 17525, 9767,
+		17524, 17529, 17527, 9768, 17518, 17508, 17507, 9770, 17520, 17521, 17517, 17509, 3049,
+		17514, 17344, 3050, 17510, 9769, 17516, 1633, 17515, 17511, 17523, 400, 17512, 17673,
+		17526, 17528, 17519, 17513, 17674, 17676, 17670, 9830, 1621, 17683, 17681, 9831, 17680,
+		17679, 17672, 17669, 17675, 17684, 17671, 17677, 17782, 17787, 17777, 17780, 9874, 17784,
+		17791, 17678, 17778, 17788, 17792, 17783, 17776, 17786, 17785, 17790, 17789, 17682, 17781,
+		17775, 17779, 9905, 9906, 17774, 3051, 17873, 17864, 17872, 17869, 17870, 17863, 17866,
+		17874, 17868, 17867, 17865, 17871, 405, 17927, 401, 17928, 1631, 17930, 17929, 17932,
+		17931, 17958, 17957, 17983, 17984, 2444, 17997, 9947, 9951, 3912, 18007, 9952, 775, 7001,
+		9687, 17530, 3054, 9907, 9908, 7002, 14113, 3788, 7920, 15371, 3897, 3750, 15935, 15936,
+		15934, 16401, 16400, 9270, 16767, 16402, 16766, 1642, 17101, 9594, 9596, 17100, 1643, 3973,
+		9595, 3813, 17349, 17351, 17350, 17531, 9771, 1644, 9875, 17875, 17933, 1645, 18010, 7003,
+		4080, 14732, 3896, 8699, 1647, 3612, 3055, 15938, 15937, 1648, 16403, 3056, 9597, 9688,
+		17685, 18003, 7004, 8308, 3057, 8700, 2993, 17102, 1650, 17793, 7473, 776, 3058, 398,
+		16404, 9927, 7474, 8701, 9271, 17794, 7475, 15372, 8999, 15939, 15940, 8998, 16405, 9275,
+		9273, 9274, 9272, 16768, 9448, 3060, 17353, 17352, 17355, 17354, 9689, 17532, 17534, 17533,
+		9772, 17686, 3061, 17795, 17796, 9876, 17934, 17959, 9944, 3062, 13423, 15941, 16406,
+		17103, 14114, 3046, 16407, 16770, 16769, 1657, 17797, 9909, 1658, 17935, 17960, 7921,
+		15373, 15374, 15942, 7922, 9449, 16771, 1662, 17536, 9773, 17537, 17535, 17798, 17876,
+		7923, 16410, 16409, 16408, 16772, 3926, 713, 16776, 16775, 16774, 16773, 9450, 17104,
+		17357, 9690, 17356, 17538, 17689, 17688, 9832, 17687, 17801, 17800, 17799, 1664, 8309,
+		15943, 9276, 17105, 17106, 17690, 17802, 3064, 1667, 17877, 17936, 17985, 18012, 8310,
+		9277, 16777, 17107, 17539, 17803, 4079, 8703, 3908, 16411, 16778, 17108, 17109, 17110,
+		17362, 3809, 17358, 9774, 17361, 17360, 9691, 17359, 9693, 1672, 9692, 17541, 17540, 9775,
+		9776, 1673, 9834, 17691, 9833, 17806, 17807, 17805, 17804, 9911, 17878, 17879, 17880, 9910,
+		17937, 17940, 17939, 17938, 18011, 1074, 9000, 1675, 9457, 17363, 17542, 9835, 17692, 74,
+		66, 18013, 416, 9001, 1014, 16412, 17693, 17961, 3891, 788, 830, 837, 870, 872, 874, 879,
+		902, 903, 905, 10268, 10270, 924, 950, 96, 112, 116, 120, 125, 778, 10372, 13656, 4092,
+		4105, 4107, 4109, 4113, 4114, 4117, 4118, 4121, 4122, 4125, 4126, 4129, 4130, 4133, 4134,
+		4137, 4138, 4141, 4142, 4182, 4183, 4186, 4187, 4184, 4185, 4110, 4095, 4097, 4099, 4100,
+		4101, 4102, 4143, 4144, 4145, 4146, 4147, 4148, 4188, 4189, 4190, 4206, 4207, 4208, 4209,
+		4210, 4251, 4252, 4253, 4091, 10315, 4157, 4242, 4247, 4158, 10314, 4111, 4112, 4159, 4191,
+		4083, 4192, 4086, 4240, 4316, 4317, 4318, 4319, 4320, 4321, 4322, 4323, 4324, 4325, 4089,
+		4088, 4197, 4199, 4198, 4090, 4248, 4348, 4349, 4350, 4351, 4352, 4353, 4354, 4355, 4356,
+		4357, 4358, 4359, 4360, 4361, 4362, 4363, 4364, 4365, 4366, 4367, 4368, 4369, 4370, 4371,
+		4372, 4373, 10021, 4241, 10022, 4180, 4374, 4375, 4376, 4377, 4378, 4379, 4380, 4381, 4382,
+		4383, 4384, 4385, 4386, 4387, 4388, 4389, 4390, 4391, 4392, 4393, 4394, 4395, 4396, 4397,
+		4398, 4399, 4115, 4103, 4116, 4096, 10312, 4179, 10313, 18054, 3346, 1937, 1938, 2206,
+		1199, 727, 780, 10265, 3065, 171, 168, 10263, 165, 164, 4073, 162, 176, 18444, 932, 546,
+		1777, 18376, 2166, 3879, 18317, 1788, 2735, 936, 2174, 1518, 1786, 2159, 18400, 18075,
+		3066, 2475, 1784, 18107, 1780, 1095, 480, 1852, 3200, 18090, 18087, 1772, 18089, 3067,
+		2555, 18568, 18546, 1778, 2630, 3857, 1886, 1781, 18092, 1771, 18101, 3737, 1821, 1820,
+		1819, 3071, 552, 2565, 18055, 18109, 18322, 18114, 18728, 3221, 420, 3075, 18057, 1817,
+		2069, 3223, 3076, 2738, 2578, 1568, 18428, 3077, 1824, 543, 3250, 2214, 1827, 2737, 2204,
+		18561, 18151, 3708, 394, 3335, 1432, 819, 70, 2607, 3081, 3082, 1240, 18190, 942, 1761,
+		620, 3083, 1854, 3086, 18155, 2579, 3088, 18105, 501, 2167, 1833, 602, 18711, 3089, 3605,
+		474, 539, 3090, 3091, 18195, 3246, 3092, 3094, 3606, 3514, 3096, 2580, 2857, 18360, 3867,
+		496, 671, 3097, 3098, 2576, 475, 3778, 1476, 18592, 507, 615, 621, 3572, 2157, 18211,
+		18281, 3099, 3781, 18203, 494, 631, 632, 555, 565, 3631, 3100, 3101, 3105, 575, 3681, 1762,
+		3106, 665, 675, 690, 1259, 1837, 617, 3546, 3110, 18493, 3609, 666, 676, 681, 601, 694,
+		610, 500, 636, 3593, 3611, 612, 619, 3665, 3740, 3107, 1098, 18209, 3376, 3666, 664, 667,
+		2523, 2374, 3109, 638, 614, 588, 3998, 554, 502, 611, 3108, 627, 495, 693, 697, 3112, 1760,
+		3571, 3672, 3680, 3684, 1415, 685, 686, 3115, 3114, 533, 3615, 3116, 531, 677, 3118, 3119,
+		3120, 562, 18226, 3122, 622, 3646, 637, 579, 674, 605, 3123, 1835, 497, 687, 688, 3124,
+		3125, 623, 315, 678, 616, 698, 3687, 3661, 18263, 18243, 1501, 1726, 3759, 2651, 3128,
+		4065, 2415, 1889, 2715, 18077, 3130, 18380, 3129, 18807, 18454, 2655, 2613, 3402, 1727,
+		3431, 1725, 2526, 18252, 2556, 881, 2646, 18254, 680, 1232, 18628, 1728, 2418, 1244, 3132,
+		1324, 18171, 3398, 18172, 3133, 18275, 1198, 18278, 18308, 3078, 1722, 18804, 2585, 1577,
+		18291, 1392, 2153, 18716, 18163, 1866, 1715, 1984, 1910, 18208, 1868, 18157, 2676, 1922,
+		2690, 2487, 3771, 3840, 2161, 1867, 18446, 18365, 1250, 1718, 1920, 2188, 3381, 18287,
+		2683, 1260, 2510, 3134, 3135, 2553, 2312, 2680, 18284, 2729, 1706, 3531, 3553, 1713, 1178,
+		2583, 18286, 18455, 3384, 18290, 18481, 1989, 3138, 1716, 702, 3946, 2250, 2290, 2587,
+		1234, 1877, 2604, 1614, 2113, 18404, 3554, 2595, 1813, 1711, 18751, 2544, 1908, 2252, 1330,
+		1708, 2594, 1719, 2522, 18221, 2254, 1974, 1686, 1905, 1906, 2686, 1406, 18306, 2028, 1523,
+		3301, 1748, 1919, 1637, 4064, 18569, 3139, 1901, 808, 18111, 18753, 1900, 18067, 1876,
+		3140, 18320, 3141, 18758, 33, 18327, 724, 3043, 3142, 3143, 3668, 18331, 3144, 3994, 59,
+		46, 3285, 842, 1914, 419, 3787, 2001, 18339, 18793, 928, 18340, 18498, 1465, 18344, 3225,
+		3145, 3147, 3784, 3148, 18396, 2740, 3452, 169, 3151, 18350, 826, 3210, 586, 18106, 18551,
+		1634, 18351, 3368, 541, 944, 3237, 598, 3154, 2308, 1444, 2652, 18383, 3155, 3156, 3711,
+		18385, 18577, 18595, 3518, 111, 113, 114, 115, 18079, 18085, 3950, 18108, 18304, 3361,
+		1932, 1933, 1939, 18824, 1929, 18398, 1499, 625, 18213, 3654, 2170, 1227, 846, 1216, 3310,
+		2420, 3157, 568, 2427, 913, 18409, 673, 18565, 606, 18432, 1928, 3158, 18731, 18439, 18504,
+		3379, 607, 3159, 3161, 2428, 18438, 3162, 561, 576, 3163, 421, 3165, 3167, 1951, 3169,
+		3626, 1949, 530, 630, 2528, 1337, 487, 3172, 633, 634, 3610, 3174, 1943, 1963, 503, 589,
+		3175, 512, 478, 577, 1944, 3177, 3178, 521, 529, 1961, 549, 3180, 3676, 18193, 683, 3179,
+		653, 18448, 18458, 3181, 1954, 597, 3628, 1287, 3182, 3183, 2650, 3184, 18697, 18622, 3185,
+		1609, 18475, 1968, 1543, 1544, 3755, 1988, 718, 1262, 1993, 18490, 18491, 1978, 18501,
+		2488, 69, 18487, 18488, 18594, 1992, 2341, 3432, 18499, 2118, 18110, 1990, 912, 71, 1439,
+		805, 1977, 18229, 1971, 18800, 1969, 2003, 1973, 2004, 2734, 1524, 3186, 18103, 3277, 3187,
+		18523, 628, 3984, 848, 2661, 2012, 18485, 2223, 1952, 18526, 1915, 2656, 3351, 1249, 922,
+		1263, 3192, 1809, 1709, 2467, 118, 18532, 1271, 2207, 3193, 18083, 17, 3197, 18537, 2468,
+		18550, 2072, 4072, 101, 60, 1730, 2664, 3573, 2658, 1740, 18553, 3198, 3726, 18131, 3400,
+		1751, 1201, 2123, 2011, 2009, 18511, 2022, 2660, 2464, 2008, 580, 3204, 18567, 1902, 2733,
+		918, 18572, 851, 18115, 2036, 1473, 1295, 3539, 4060, 18069, 18589, 3663, 3861, 3716, 3227,
+		3568, 2599, 1242, 2257, 3723, 2531, 2421, 1348, 2160, 1437, 18583, 18585, 1219, 1251, 34,
+		3207, 3730, 2626, 1502, 18616, 18626, 1735, 1810, 3549, 1814, 1795, 1267, 3208, 1811, 1099,
+		3729, 2071, 2700, 2149, 1737, 18574, 2080, 1080, 3258, 35, 2049, 18150, 3446, 18618, 1376,
+		18645, 18431, 2035, 18058, 18192, 18152, 2625, 2349, 3305, 18644, 4043, 18643, 18222, 3217,
+		2052, 18629, 1173, 18606, 1753, 18460, 1846, 18641, 1850, 1752, 927, 2443, 1754, 3213,
+		18646, 18650, 18639, 791, 18642, 18648, 18649, 1569, 18746, 67, 703, 3415, 3656, 18586,
+		18245, 2505, 2497, 18515, 18715, 2687, 2370, 1616, 2714, 2705, 2060, 18664, 18268, 2286,
+		2698, 1878, 2695, 16, 18674, 1994, 2703, 3215, 2527, 3216, 2059, 3218, 1682, 2648, 18255,
+		1649, 18671, 1832, 3219, 18507, 2712, 2529, 2718, 2339, 3756, 1704, 3228, 18224, 1769,
+		18676, 2702, 1703, 2023, 2516, 1697, 18359, 2710, 505, 3229, 855, 3318, 1154, 551, 3701,
+		2016, 1948, 3231, 2122, 1945, 3649, 2066, 3233, 2065, 3678, 3241, 18703, 1151, 744, 2125,
+		18578, 2763, 2108, 3242, 2337, 18588, 18349, 18587, 2559, 2074, 3863, 2315, 18073, 3321,
+		3808, 3273, 18477, 2489, 18064, 2575, 2211, 3761, 18692, 1678, 2561, 894, 18605, 18415,
+		3441, 1970, 1416, 2537, 2158, 18721, 2102, 18416, 1733, 18100, 1248, 18297, 2101, 1834,
+		2592, 18720, 2417, 2536, 3195, 1679, 2107, 3372, 701, 2470, 18450, 1960, 2557, 2047, 2347,
+		3303, 2225, 1681, 2187, 18722, 1794, 18808, 858, 3243, 18508, 18678, 18471, 18717, 1724,
+		18726, 18677, 18470, 1584, 3383, 2551, 2338, 3220, 2073, 2105, 2722, 2586, 1683, 2728,
+		1236, 2554, 2385, 2649, 3919, 2348, 18747, 1388, 18745, 3520, 2552, 1237, 2132, 2068, 3445,
+		18124, 3259, 18084, 2377, 2546, 2129, 3940, 1845, 3245, 2130, 3485, 1522, 2078, 3780, 1531,
+		969, 3703, 18257, 2302, 3550, 2662, 1410, 2092, 2093, 18768, 482, 492, 669, 593, 682, 3248,
+		511, 4075, 2794, 18766, 3249, 2508, 3293, 3719, 1230, 3670, 3252, 538, 18791, 476, 3608,
+		18792, 695, 795, 609, 18495, 2116, 18798, 2115, 18395, 3256, 518, 699, 1130, 3559, 18502,
+		3257, 1986, 1712, 1382, 3261, 1552, 3262, 961, 4063, 2821, 3266, 1985, 1736, 2482, 3268,
+		3270, 1383, 3271, 2135, 1981, 1983, 1470, 2571, 1475, 18815, 18689, 3540, 1775, 2268, 863,
+		2143, 2756, 1570, 18461, 1593, 708, 18180, 3272, 2358, 1103, 3312, 18829, 3274, 2006, 2402,
+		63, 1109, 3279, 1875, 3541, 1113, 18321, 32, 3707, 2172, 3282, 2171, 2215, 717, 3283, 2183,
+		1135, 2177, 1289, 3176, 1126, 3284, 3860, 3557, 1143, 1307, 862, 1309, 1148, 1131, 1141,
+		797, 2216, 109, 94, 2218, 585, 3543, 2201, 1883, 2185, 2184, 4061, 3566, 4004, 1720, 2075,
+		3206, 61, 1174, 3686, 2465, 18823, 553, 2535, 526, 3289, 1182, 3970, 1183, 2534, 2198,
+		4070, 2133, 3292, 18651, 3544, 2197, 749, 4081, 1193, 3775, 752, 4078, 1196, 2364, 18779,
+		2210, 3295, 1519, 3329, 2209, 3296, 3345, 3300, 3304, 1205, 1119, 1229, 3306, 658, 2228,
+		2226, 3308, 755, 1218, 3536, 1758, 3309, 534, 1220, 3319, 535, 2532, 3327, 3725, 2238,
+		4022, 603, 4074, 3330, 3328, 18382, 3638, 3652, 3332, 18784, 18783, 3336, 3338, 756, 1607,
+		3430, 491, 3341, 3343, 18712, 3348, 2240, 2243, 1241, 1269, 1247, 18145, 900, 30, 18332,
+		640, 18579, 3263, 18202, 3349, 2203, 2455, 1298, 1270, 3353, 18076, 2642, 3298, 1755, 2507,
+		933, 955, 18250, 1136, 2678, 18282, 2671, 3355, 2665, 1517, 3324, 2525, 1212, 2231, 3779,
+		3851, 1996, 18598, 2669, 1576, 2708, 18081, 18283, 2670, 898, 18524, 18599, 1661, 1268,
+		2458, 1277, 18288, 2245, 3358, 4067, 2088, 18600, 2460, 2463, 3947, 3424, 2453, 1286, 1300,
+		1164, 18293, 18388, 18607, 1299, 2459, 107, 19, 1313, 3658, 4024, 18425, 1101, 1282, 1509,
+		1166, 1446, 1742, 1935, 18609, 18789, 18303, 901, 2672, 2454, 404, 3362, 1738, 2679, 1322,
+		4068, 3764, 3409, 1325, 20, 18397, 2674, 1419, 896, 18835, 2230, 3776, 1734, 1566, 3363,
+		2247, 5, 3365, 3366, 3369, 3724, 3659, 3371, 18, 1332, 958, 2259, 3375, 2596, 4016, 596,
+		3683, 1347, 1349, 1925, 3209, 10271, 18819, 3267, 629, 3538, 4062, 3205, 691, 1358, 326,
+		2701, 2142, 652, 2271, 2270, 3394, 3395, 2000, 3528, 760, 520, 1367, 2941, 1559, 536, 3405,
+		3411, 3412, 18314, 3535, 18417, 1373, 3191, 18082, 1368, 1449, 2753, 2395, 3413, 2760,
+		1378, 18831, 1375, 2199, 104, 44, 2657, 3290, 26, 8, 10, 3655, 1400, 1396, 3414, 3657, 471,
+		1397, 1402, 1403, 2294, 2711, 2621, 3742, 3671, 1414, 3418, 2042, 3429, 3532, 3433, 3436,
+		3449, 662, 485, 3401, 509, 3450, 3451, 3453, 490, 3428, 3454, 3455, 1418, 679, 2616, 3456,
+		504, 704, 583, 692, 3598, 3457, 3422, 3458, 1203, 1427, 1180, 3915, 3459, 1428, 3461, 418,
+		3380, 18368, 1443, 1448, 1436, 1451, 3234, 18500, 1604, 1452, 1319, 1320, 3462, 18168, 957,
+		1442, 1500, 1456, 813, 1454, 18706, 18704, 68, 1464, 3281, 3439, 3465, 18802, 18803, 1696,
+		945, 1688, 2361, 1916, 18074, 3410, 3468, 18212, 1070, 2043, 1435, 2706, 829, 18065, 3470,
+		3472, 2275, 2538, 1893, 2519, 4071, 2568, 1365, 18401, 799, 18391, 1692, 2388, 1865, 1913,
+		1223, 2511, 18541, 2322, 2747, 3925, 2603, 2573, 2659, 3214, 18175, 1823, 2591, 1472, 2040,
+		2100, 2567, 18147, 18529, 1513, 1479, 1483, 2112, 1484, 822, 2324, 1474, 1764, 18614, 1982,
+		18822, 3527, 4054, 2363, 3473, 542, 3239, 2384, 2543, 2386, 2401, 18241, 3533, 18748, 3534,
+		2274, 18434, 41, 3989, 2376, 3474, 1885, 2273, 1685, 2570, 18680, 1106, 2039, 1480, 1487,
+		2272, 1489, 18750, 3476, 2641, 2326, 2079, 2362, 1656, 3435, 1488, 2327, 643, 600, 641,
+		2334, 642, 880, 767, 1689, 3991, 2345, 2344, 3849, 18760, 18733, 1785, 18757, 2343, 3478,
+		948, 1934, 1669, 2357, 1520, 2354, 1595, 3334, 2618, 1521, 3479, 18516, 18838, 3481, 3482,
+		1946, 2743, 3484, 18636, 18558, 3406, 1536, 1539, 1558, 2748, 1555, 1975, 2283, 3774, 1110,
+		2379, 483, 3489, 3720, 3491, 3492, 2381, 3490, 515, 590, 3493, 519, 773, 1586, 3392, 3494,
+		1953, 2407, 940, 4066, 1587, 2450, 55, 821, 2409, 3495, 18435, 3496, 1741, 3497, 3235,
+		3498, 508, 1599, 3499, 1596, 787, 3391, 1612, 3500, 3679, 3561, 3501, 403, 3762, 2437,
+		1620, 3713, 49, 649, 3503, 3504, 3717, 1622, 3721, 1441, 1211, 1640, 1625, 2448, 2449,
+		1335, 2440, 806, 1626, 406, 1630, 18267, 1623, 1635, 1624, 2447, 951, 1627, 2439, 1636,
+		1638, 3507, 1431, 3930, 1641, 2741, 816, 700, 3420, 3509, 22, 314, 3749, 1956, 2097, 3512,
+		574, 639, 3906, 599, 3664, 122, 2721, 3085, 3932, 725, 18377, 18424, 3315, 1254, 1326,
+		2256, 1398,
+	],
+};
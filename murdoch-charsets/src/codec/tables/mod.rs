@@ -0,0 +1,94 @@
+//! Mapping tables, one module per family of character sets.
+pub(crate) mod chinese;
+pub(crate) mod ebcdic;
+pub(crate) mod japanese;
+pub(crate) mod korean;
+pub(crate) mod single_byte;
+
+/// A table of a single-byte character set.
+///
+/// `code_points` maps bytes, from the first one the table covers, to code points, holding U+FFFD
+/// where nothing is assigned. `bytes` lists the indices into `code_points` sorted by code point,
+/// and is computed at compile time. Where two have the same code point, the one that is not a
+/// fallback comes first, then the lower one.
+pub(crate) struct Table<const N: usize>
+{
+	pub(crate) code_points: [u16; N],
+	pub(crate) bytes: [u8; N],
+}
+
+impl<const N: usize> Table<N>
+{
+	pub(crate) const fn new(code_points: [u16; N]) -> Self
+	{
+		Self::with_fallbacks(code_points, &[])
+	}
+
+	/// A table in which the indices in `fallbacks` decode like the others but are only encoded to
+	/// if no other index has their code point.
+	pub(crate) const fn with_fallbacks(code_points: [u16; N], fallbacks: &[u8]) -> Self
+	{
+		assert!(N <= 256);
+		// Sorted by code point, then by whether it is a fallback.
+		let mut keys = [0; N];
+		let mut i = 0;
+		while i < N
+		{
+			keys[i] = (code_points[i] as u32) << 1;
+			i += 1;
+		}
+		let mut i = 0;
+		while i < fallbacks.len()
+		{
+			keys[fallbacks[i] as usize] |= 1;
+			i += 1;
+		}
+		// An insertion sort, which is stable and can run in a constant.
+		let mut bytes = [0; N];
+		let mut i = 0;
+		while i < N
+		{
+			let mut j = i;
+			while j > 0 && keys[bytes[j - 1] as usize] > keys[i]
+			{
+				bytes[j] = bytes[j - 1];
+				j -= 1;
+			}
+			bytes[j] = i as u8;
+			i += 1;
+		}
+		Self { code_points, bytes }
+	}
+}
+
+/// A table of a multi-byte coded character set.
+///
+/// `code_points` maps pointers to code points, holding U+FFFD where nothing is assigned.
+/// `pointers` lists the pointer each code point encodes to, sorted by code point. Tables reaching
+/// beyond the Basic Multilingual Plane hold `u32` code points.
+pub(crate) struct Index<T: 'static = u16>
+{
+	pub(crate) code_points: &'static [T],
+	pub(crate) pointers: &'static [u16],
+}
+
+impl<T: Copy + Into<u32>> Index<T>
+{
+	pub(crate) fn code_point(&self, pointer: usize) -> Option<char>
+	{
+		match self.code_points.get(pointer).map(|&c| c.into())
+		{
+			Some(0xFFFD) | None => None,
+			Some(c) => char::from_u32(c),
+		}
+	}
+
+	pub(crate) fn pointer(&self, c: char) -> Option<usize>
+	{
+		let c = u32::from(c);
+		self.pointers
+			.binary_search_by_key(&c, |&p| self.code_points[usize::from(p)].into())
+			.ok()
+			.map(|i| usize::from(self.pointers[i]))
+	}
+}
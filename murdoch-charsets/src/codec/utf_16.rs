@@ -0,0 +1,245 @@
+//! UTF-16, UTF-16BE and UTF-16LE as specified by RFC 2781.
+//!
+//! Only `UTF-16` treats a leading U+FEFF as a byte-order mark; `UTF-16BE` and `UTF-16LE` decode
+//! it as ZERO WIDTH NO-BREAK SPACE. Encoding to `UTF-16` writes big-endian with a byte-order mark.
+//!
+//! ISO-10646-UCS-2 and UNICODE-1-1 are big-endian UTF-16 without surrogate pairs, so only the
+//! Basic Multilingual Plane. ISO-10646-UCS-Basic and ISO-10646-Unicode-Latin1 are the same form
+//! restricted further, to the repertoires of US-ASCII and ISO-8859-1.
+use super::{
+	encode_chars,
+	Form,
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+pub(crate) struct Utf16
+{
+	/// `None` until the byte-order mark of an unmarked stream has been looked for.
+	big_endian: Option<bool>,
+	/// Whether the encoder still has to write a byte-order mark.
+	mark: bool,
+	/// For UCS-2, the highest code point of the repertoire; surrogates are then malformed.
+	max: Option<u16>,
+}
+
+impl Utf16
+{
+	pub(crate) fn new(form: Form) -> Self
+	{
+		let (big_endian, mark) = match form
+		{
+			Form::Unmarked => (None, true),
+			Form::BigEndian => (Some(true), false),
+			Form::LittleEndian => (Some(false), false),
+		};
+		Self {
+			big_endian,
+			mark,
+			max: None,
+		}
+	}
+
+	/// Big-endian UCS-2 limited to the code points up to `max`.
+	pub(crate) fn ucs2(max: u16) -> Self
+	{
+		Self {
+			big_endian: Some(true),
+			mark: false,
+			max: Some(max),
+		}
+	}
+}
+
+impl Stepper for Utf16
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		if bytes.len() < 2
+		{
+			return Step::Incomplete;
+		}
+		let big_endian = match self.big_endian
+		{
+			Some(big_endian) => big_endian,
+			None =>
+			{
+				let (big_endian, len) = match [bytes[0], bytes[1]]
+				{
+					[0xFE, 0xFF] => (true, 2),
+					[0xFF, 0xFE] => (false, 2),
+					_ => (true, 0),
+				};
+				self.big_endian = Some(big_endian);
+				if len != 0
+				{
+					return Step::Decoded(len);
+				}
+				big_endian
+			}
+		};
+		let unit = |i: usize| {
+			let pair = [bytes[i], bytes[i + 1]];
+			if big_endian
+			{
+				u16::from_be_bytes(pair)
+			}
+			else
+			{
+				u16::from_le_bytes(pair)
+			}
+		};
+
+		let high = unit(0);
+		if let Some(max) = self.max
+		{
+			return match char::from_u32(u32::from(high))
+			{
+				Some(c) if high <= max =>
+				{
+					output.push(c);
+					Step::Decoded(2)
+				}
+				_ => Step::Malformed(2),
+			};
+		}
+		match high
+		{
+			0xD800..=0xDBFF =>
+			{
+				if bytes.len() < 4
+				{
+					return Step::Incomplete;
+				}
+				match unit(2)
+				{
+					low @ 0xDC00..=0xDFFF =>
+					{
+						let c = 0x10000
+							+ ((u32::from(high) - 0xD800) << 10)
+							+ (u32::from(low) - 0xDC00);
+						output.push(char::from_u32(c).unwrap_or_default());
+						Step::Decoded(4)
+					}
+					_ => Step::Malformed(2),
+				}
+			}
+			0xDC00..=0xDFFF => Step::Malformed(2),
+			c =>
+			{
+				output.push(char::from_u32(u32::from(c)).unwrap_or_default());
+				Step::Decoded(2)
+			}
+		}
+	}
+}
+
+impl RawEncoder for Utf16
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		if self.mark && !input.is_empty()
+		{
+			output.extend_from_slice(&[0xFE, 0xFF]);
+			self.mark = false;
+		}
+		let big_endian = self.big_endian.unwrap_or(true);
+		if let Some(max) = self.max
+		{
+			return encode_chars(input, output, |c, output| {
+				let c = u32::from(c);
+				if c > u32::from(max)
+				{
+					return false;
+				}
+				output.extend_from_slice(&(c as u16).to_be_bytes());
+				true
+			});
+		}
+		for unit in input.encode_utf16()
+		{
+			if big_endian
+			{
+				output.extend_from_slice(&unit.to_be_bytes());
+			}
+			else
+			{
+				output.extend_from_slice(&unit.to_le_bytes());
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_utf_16()
+	{
+		let codec = CharacterSetEnum::UTF_16.codec().unwrap();
+		round_trip(codec, "a€😀", b"\xFE\xFF\x00a\x20\xAC\xD8\x3D\xDE\x00");
+		assert_eq!(codec.decode(b"\xFF\xFEa\x00\xAC\x20"), "a€");
+		assert_eq!(codec.decode(b"\x00a"), "a");
+
+		let codec = CharacterSetEnum::UTF_16BE.codec().unwrap();
+		round_trip(
+			codec,
+			"\u{FEFF}a€😀",
+			b"\xFE\xFF\x00a\x20\xAC\xD8\x3D\xDE\x00",
+		);
+		let codec = CharacterSetEnum::UTF_16LE.codec().unwrap();
+		round_trip(codec, "a€😀", b"a\x00\xAC\x20\x3D\xD8\x00\xDE");
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::UTF_16BE.codec().unwrap();
+		malformed(codec, b"\x00a\xDC\x00\x00b", 2, b"\xDC\x00");
+		malformed(codec, b"\x00a\xD8\x3D\x00b", 2, b"\xD8\x3D");
+		malformed(codec, b"\x00a\xD8\x3D", 2, b"\xD8\x3D");
+		let codec = CharacterSetEnum::UTF_16LE.codec().unwrap();
+		malformed(codec, b"a\x00b", 2, b"b");
+	}
+
+	#[test]
+	fn converts_ucs_2_and_its_subsets()
+	{
+		let codec = CharacterSetEnum::ISO_10646_UCS_2.codec().unwrap();
+		round_trip(codec, "\u{FEFF}\u{20AC}", b"\xFE\xFF\x20\xAC");
+		malformed(codec, b"\x00A\xD8\x3D\xDE\x00", 2, b"\xD8\x3D");
+		assert_eq!(codec.encode("A\u{1F600}").unwrap_err().offset(), 1);
+
+		let codec = CharacterSetEnum::ISO_10646_UCS_BASIC.codec().unwrap();
+		assert_eq!(codec.decode(b"\x00A\x00\xE9"), "A\u{FFFD}");
+		assert!(codec.encode("\u{E9}").is_err());
+
+		let codec = CharacterSetEnum::ISO_10646_UNICODE_LATIN1.codec().unwrap();
+		assert_eq!(codec.decode(b"\x00\xE9\x01\x00"), "\u{E9}\u{FFFD}");
+		assert_eq!(codec.encode("\u{E9}").unwrap(), &b"\x00\xE9"[..]);
+	}
+
+	#[test]
+	fn converts_ucs_4_as_utf_32be()
+	{
+		let codec = CharacterSetEnum::ISO_10646_UCS_4.codec().unwrap();
+		round_trip(codec, "\u{1F600}", b"\x00\x01\xF6\x00");
+	}
+}
@@ -0,0 +1,163 @@
+//! UTF-32, UTF-32BE and UTF-32LE, with the same byte-order mark rules as UTF-16.
+use super::{
+	Form,
+	RawEncodeError,
+	RawEncoder,
+	Step,
+	Stepper,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+pub(crate) struct Utf32
+{
+	/// `None` until the byte-order mark of an unmarked stream has been looked for.
+	big_endian: Option<bool>,
+	/// Whether the encoder still has to write a byte-order mark.
+	mark: bool,
+}
+
+impl Utf32
+{
+	pub(crate) fn new(form: Form) -> Self
+	{
+		let (big_endian, mark) = match form
+		{
+			Form::Unmarked => (None, true),
+			Form::BigEndian => (Some(true), false),
+			Form::LittleEndian => (Some(false), false),
+		};
+		Self { big_endian, mark }
+	}
+}
+
+impl Stepper for Utf32
+{
+	fn step(&mut self, bytes: &[u8], output: &mut String) -> Step
+	{
+		if bytes.len() < 4
+		{
+			return Step::Incomplete;
+		}
+		let unit = [bytes[0], bytes[1], bytes[2], bytes[3]];
+		let big_endian = match self.big_endian
+		{
+			Some(big_endian) => big_endian,
+			None =>
+			{
+				let (big_endian, len) = match unit
+				{
+					[0x00, 0x00, 0xFE, 0xFF] => (true, 4),
+					[0xFF, 0xFE, 0x00, 0x00] => (false, 4),
+					_ => (true, 0),
+				};
+				self.big_endian = Some(big_endian);
+				if len != 0
+				{
+					return Step::Decoded(len);
+				}
+				big_endian
+			}
+		};
+		let c = if big_endian
+		{
+			u32::from_be_bytes(unit)
+		}
+		else
+		{
+			u32::from_le_bytes(unit)
+		};
+		match char::from_u32(c)
+		{
+			Some(c) =>
+			{
+				output.push(c);
+				Step::Decoded(4)
+			}
+			None => Step::Malformed(4),
+		}
+	}
+}
+
+impl RawEncoder for Utf32
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		if self.mark && !input.is_empty()
+		{
+			output.extend_from_slice(&[0x00, 0x00, 0xFE, 0xFF]);
+			self.mark = false;
+		}
+		let big_endian = self.big_endian.unwrap_or(true);
+		for c in input.chars()
+		{
+			if big_endian
+			{
+				output.extend_from_slice(&u32::from(c).to_be_bytes());
+			}
+			else
+			{
+				output.extend_from_slice(&u32::from(c).to_le_bytes());
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_utf_32()
+	{
+		let codec = CharacterSetEnum::UTF_32.codec().unwrap();
+		round_trip(
+			codec,
+			"a😀",
+			b"\x00\x00\xFE\xFF\x00\x00\x00a\x00\x01\xF6\x00",
+		);
+		assert_eq!(codec.decode(b"\xFF\xFE\x00\x00a\x00\x00\x00"), "a");
+		assert_eq!(codec.decode(b"\x00\x00\x00a"), "a");
+
+		let codec = CharacterSetEnum::UTF_32BE.codec().unwrap();
+		round_trip(
+			codec,
+			"\u{FEFF}a😀",
+			b"\x00\x00\xFE\xFF\x00\x00\x00a\x00\x01\xF6\x00",
+		);
+		let codec = CharacterSetEnum::UTF_32LE.codec().unwrap();
+		round_trip(codec, "a😀", b"a\x00\x00\x00\x00\xF6\x01\x00");
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::UTF_32BE.codec().unwrap();
+		malformed(
+			codec,
+			b"\x00\x00\x00a\x00\x11\x00\x00",
+			4,
+			b"\x00\x11\x00\x00",
+		);
+		malformed(codec, b"\x00\x00\x00a\x00\x00", 4, b"\x00\x00");
+		let codec = CharacterSetEnum::UTF_32LE.codec().unwrap();
+		malformed(
+			codec,
+			b"a\x00\x00\x00\x00\xD8\x00\x00",
+			4,
+			b"\x00\xD8\x00\x00",
+		);
+	}
+}
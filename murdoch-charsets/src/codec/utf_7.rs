@@ -0,0 +1,359 @@
+//! UTF-7 (RFC 2152) and the modified UTF-7 of IMAP mailbox names (RFC 3501).
+//!
+//! The encoder writes the direct and optional direct characters as themselves and everything
+//! else as base64 shift sequences, ending them with `-` only where the next character requires
+//! it. The decoder accepts any ASCII character outside shift sequences. In IMAP mode `&` starts
+//! a shift sequence, `,` replaces `/`, only printable ASCII is direct and every shift sequence
+//! ends with `-`.
+use super::{
+	RawDecodeError,
+	RawDecoder,
+	RawEncodeError,
+	RawEncoder,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+/// Optional direct characters of RFC 2152, besides the direct ones.
+const OPTIONAL_DIRECT: &[u8] = b"!\"#$%&*;<=>@[]^_`{|}";
+
+pub(crate) struct Utf7
+{
+	imap: bool,
+	/// Whether a shift sequence is open.
+	shifted: bool,
+	/// Whether the open shift sequence has no base64 characters yet.
+	empty: bool,
+	/// Bits not yet making up a code unit when decoding, or a base64 character when encoding.
+	bits: u32,
+	len: u32,
+	/// A high surrogate waiting for its low surrogate.
+	high: Option<u16>,
+	/// A character decoded along with a lone high surrogate, written after the error.
+	carry: Option<char>,
+}
+
+impl Utf7
+{
+	pub(crate) fn new(imap: bool) -> Self
+	{
+		Self {
+			imap,
+			shifted: false,
+			empty: false,
+			bits: 0,
+			len: 0,
+			high: None,
+			carry: None,
+		}
+	}
+
+	fn shift(&self) -> u8
+	{
+		if self.imap
+		{
+			b'&'
+		}
+		else
+		{
+			b'+'
+		}
+	}
+
+	fn sextet(&self, b: u8) -> Option<u32>
+	{
+		let v = match b
+		{
+			b'A'..=b'Z' => b - b'A',
+			b'a'..=b'z' => b - b'a' + 26,
+			b'0'..=b'9' => b - b'0' + 52,
+			b'+' => 62,
+			b',' if self.imap => 63,
+			b'/' if !self.imap => 63,
+			_ => return None,
+		};
+		Some(u32::from(v))
+	}
+
+	fn digit(&self, v: u32) -> u8
+	{
+		match v
+		{
+			0..=25 => b'A' + v as u8,
+			26..=51 => b'a' + (v - 26) as u8,
+			52..=61 => b'0' + (v - 52) as u8,
+			62 => b'+',
+			_ if self.imap => b',',
+			_ => b'/',
+		}
+	}
+
+	fn is_direct(&self, c: char) -> bool
+	{
+		if self.imap
+		{
+			return (' '..='~').contains(&c) && c != '&';
+		}
+		c.is_ascii_alphanumeric()
+			|| "'(),-./:? \t\r\n".contains(c)
+			|| (c.is_ascii() && OPTIONAL_DIRECT.contains(&(c as u8)))
+	}
+
+	/// Closes the shift sequence at its end, which is well-formed if only zero padding is left.
+	fn unshift(&mut self) -> bool
+	{
+		let well_formed = self.len < 6 && self.bits == 0 && self.high.is_none();
+		self.shifted = false;
+		self.bits = 0;
+		self.len = 0;
+		self.high = None;
+		well_formed
+	}
+
+	fn push_unit(&mut self, unit: u16, output: &mut String) -> bool
+	{
+		match (self.high.take(), unit)
+		{
+			(None, 0xD800..=0xDBFF) =>
+			{
+				self.high = Some(unit);
+				true
+			}
+			(None, 0xDC00..=0xDFFF) => false,
+			(Some(high), 0xDC00..=0xDFFF) =>
+			{
+				let c = 0x10000 + ((u32::from(high) - 0xD800) << 10) + u32::from(unit) - 0xDC00;
+				output.extend(char::from_u32(c));
+				true
+			}
+			(Some(_), 0xD800..=0xDBFF) =>
+			{
+				self.high = Some(unit);
+				false
+			}
+			(Some(_), unit) =>
+			{
+				self.carry = char::from_u32(u32::from(unit));
+				false
+			}
+			(None, unit) =>
+			{
+				output.extend(char::from_u32(u32::from(unit)));
+				true
+			}
+		}
+	}
+
+	fn write_base64(&mut self, c: char, output: &mut Vec<u8>)
+	{
+		if !self.shifted
+		{
+			output.push(self.shift());
+			self.shifted = true;
+		}
+		let mut units = [0; 2];
+		for &mut unit in c.encode_utf16(&mut units)
+		{
+			self.bits = self.bits << 16 | u32::from(unit);
+			self.len += 16;
+			while self.len >= 6
+			{
+				self.len -= 6;
+				output.push(self.digit(self.bits >> self.len & 0x3F));
+			}
+			self.bits &= (1 << self.len) - 1;
+		}
+	}
+
+	/// Ends the shift sequence before `next`, or before the end of the text.
+	fn close(&mut self, next: Option<char>, output: &mut Vec<u8>)
+	{
+		if self.len > 0
+		{
+			output.push(self.digit(self.bits << (6 - self.len) & 0x3F));
+		}
+		let delimit = match next
+		{
+			Some(c) => !c.is_ascii() || c == '-' || self.sextet(c as u8).is_some(),
+			None => true,
+		};
+		if self.imap || delimit
+		{
+			output.push(b'-');
+		}
+		self.shifted = false;
+		self.bits = 0;
+		self.len = 0;
+	}
+
+	fn encode_char(&mut self, c: char, output: &mut Vec<u8>)
+	{
+		if self.is_direct(c)
+		{
+			if self.shifted
+			{
+				self.close(Some(c), output);
+			}
+			output.push(c as u8);
+		}
+		else if c == char::from(self.shift()) && (self.imap || !self.shifted)
+		{
+			if self.shifted
+			{
+				self.close(Some(c), output);
+			}
+			output.extend_from_slice(&[self.shift(), b'-']);
+		}
+		else
+		{
+			self.write_base64(c, output);
+		}
+	}
+}
+
+impl RawDecoder for Utf7
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>)
+	{
+		output.extend(self.carry.take());
+		for (i, &b) in input.iter().enumerate()
+		{
+			if self.shifted
+			{
+				if let Some(v) = self.sextet(b)
+				{
+					self.empty = false;
+					self.bits = self.bits << 6 | v;
+					self.len += 6;
+					if self.len >= 16
+					{
+						self.len -= 16;
+						let unit = (self.bits >> self.len) as u16;
+						self.bits &= (1 << self.len) - 1;
+						if !self.push_unit(unit, output)
+						{
+							return (i, Some(RawDecodeError::new(i + 1)));
+						}
+					}
+					continue;
+				}
+				let empty = self.empty;
+				let well_formed = self.unshift();
+				if b == b'-'
+				{
+					if empty
+					{
+						output.push(char::from(self.shift()));
+					}
+					else if !well_formed
+					{
+						return (i, Some(RawDecodeError::new(i + 1)));
+					}
+					continue;
+				}
+				// Any other byte ends the sequence and is decoded again on its own.
+				if empty || !well_formed || self.imap
+				{
+					return (i, Some(RawDecodeError::new(i)));
+				}
+			}
+			if b == self.shift()
+			{
+				self.shifted = true;
+				self.empty = true;
+			}
+			else if b.is_ascii() && (!self.imap || (b' '..=b'~').contains(&b))
+			{
+				output.push(char::from(b));
+			}
+			else
+			{
+				return (i, Some(RawDecodeError::new(i + 1)));
+			}
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, output: &mut String) -> Option<RawDecodeError>
+	{
+		output.extend(self.carry.take());
+		if !self.shifted
+		{
+			return None;
+		}
+		if !self.unshift() || self.imap
+		{
+			return Some(RawDecodeError::new(0));
+		}
+		None
+	}
+}
+
+impl RawEncoder for Utf7
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		for c in input.chars()
+		{
+			self.encode_char(c, output);
+		}
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, output: &mut Vec<u8>)
+	{
+		if self.shifted
+		{
+			self.close(None, output);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_utf_7()
+	{
+		let codec = CharacterSetEnum::UTF_7.codec().unwrap();
+		round_trip(codec, "Hi Mom -☺-!", b"Hi Mom -+Jjo--!");
+		round_trip(codec, "日本語", b"+ZeVnLIqe-");
+		round_trip(codec, "A≢Α.", b"A+ImIDkQ.");
+		assert_eq!(codec.decode(b"+AGEAYQBh-"), "aaa");
+	}
+
+	#[test]
+	fn converts_imap_mailbox_names()
+	{
+		let codec = CharacterSetEnum::UTF_7
+			.codec()
+			.unwrap()
+			.with_imap_utf7(true);
+		round_trip(
+			codec,
+			"~peter/mail/台北/日本語",
+			b"~peter/mail/&U,BTFw-/&ZeVnLIqe-",
+		);
+		round_trip(codec, "& ü", b"&- &APw-");
+		assert_eq!(codec.decode(b"a+b"), "a+b");
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::UTF_7.codec().unwrap();
+		malformed(codec, b"a\xE9", 1, b"\xE9");
+		malformed(codec, b"a+ZeVnLIqe\x80", 10, b"\x80");
+		malformed(codec, b"a+2D3-", 5, b"-");
+	}
+}
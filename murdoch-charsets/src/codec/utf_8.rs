@@ -0,0 +1,132 @@
+//! UTF-8, decoded incrementally so that sequences may be split across calls.
+use super::{
+	Pending,
+	RawDecodeError,
+	RawDecoder,
+	RawEncodeError,
+	RawEncoder,
+};
+use alloc::{
+	string::String,
+	vec::Vec,
+};
+
+#[derive(Default)]
+pub(crate) struct Utf8
+{
+	pending: Pending,
+}
+
+impl RawDecoder for Utf8
+{
+	fn raw_feed(&mut self, input: &[u8], output: &mut String) -> (usize, Option<RawDecodeError>)
+	{
+		let mut i = 0;
+		let earlier = self.pending.len();
+		// Complete the sequence left over by the previous call one byte at a time.
+		while !self.pending.is_empty()
+		{
+			let b = match input.get(i)
+			{
+				Some(&b) => b,
+				None => return (input.len(), None),
+			};
+			let mut candidate = self.pending;
+			candidate.push(b);
+			match core::str::from_utf8(candidate.as_slice())
+			{
+				Ok(s) =>
+				{
+					output.push_str(s);
+					self.pending = Pending::default();
+				}
+				Err(e) if e.error_len().is_none() => self.pending = candidate,
+				Err(_) =>
+				{
+					self.pending = Pending::default();
+					return (0, Some(RawDecodeError::new(i).held(earlier)));
+				}
+			}
+			i += 1;
+		}
+
+		match core::str::from_utf8(&input[i..])
+		{
+			Ok(s) =>
+			{
+				output.push_str(s);
+				(input.len(), None)
+			}
+			Err(e) =>
+			{
+				let valid = i + e.valid_up_to();
+				output.push_str(core::str::from_utf8(&input[i..valid]).unwrap_or_default());
+				match e.error_len()
+				{
+					Some(len) => (valid, Some(RawDecodeError::new(valid + len))),
+					None =>
+					{
+						for &b in &input[valid..]
+						{
+							self.pending.push(b);
+						}
+						(input.len(), None)
+					}
+				}
+			}
+		}
+	}
+
+	fn raw_finish(&mut self, _output: &mut String) -> Option<RawDecodeError>
+	{
+		if self.pending.is_empty()
+		{
+			None
+		}
+		else
+		{
+			let held = self.pending.len();
+			self.pending = Pending::default();
+			Some(RawDecodeError::new(0).held(held))
+		}
+	}
+}
+
+impl RawEncoder for Utf8
+{
+	fn raw_feed(&mut self, input: &str, output: &mut Vec<u8>) -> (usize, Option<RawEncodeError>)
+	{
+		output.extend_from_slice(input.as_bytes());
+		(input.len(), None)
+	}
+
+	fn raw_finish(&mut self, _output: &mut Vec<u8>) {}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use crate::{
+		codec::tests::{
+			malformed,
+			round_trip,
+		},
+		CharacterSetEnum,
+	};
+
+	#[test]
+	fn converts_utf_8()
+	{
+		let codec = CharacterSetEnum::UTF_8.codec().unwrap();
+		round_trip(codec, "aé€😀", b"a\xC3\xA9\xE2\x82\xAC\xF0\x9F\x98\x80");
+	}
+
+	#[test]
+	fn reports_malformed_input()
+	{
+		let codec = CharacterSetEnum::UTF_8.codec().unwrap();
+		malformed(codec, b"a\xE2\x82\xC3\xA9", 1, b"\xE2\x82");
+		malformed(codec, b"ab\xF0\x9F", 2, b"\xF0\x9F");
+		malformed(codec, b"\xED\xA0\x80", 0, b"\xED");
+	}
+}
@@ -0,0 +1,421 @@
+//! Parsing of `Content-Type` header values, enabled by the `alloc` feature.
+//!
+//! A media type is parsed as RFC 7231 section 3.1.1.1 and RFC 2045 section 5.1 define it: a type
+//! and subtype followed by `;`-separated parameters whose values are tokens or quoted strings with
+//! backslash escapes. Types, subtypes and parameter names are matched case-insensitively and kept
+//! in lowercase. Whitespace is allowed around every delimiter, and empty parameters are ignored.
+//!
+//! ```
+//! use murdoch_charsets::{
+//! 	content_type::ContentType,
+//! 	CharacterSetEnum,
+//! };
+//!
+//! let content_type: ContentType = "Text/HTML; Charset=\"latin1\"".parse().unwrap();
+//! assert_eq!(content_type.essence(), "text/html");
+//! assert_eq!(
+//! 	content_type.charset(),
+//! 	Some(CharacterSetEnum::ISO_8859_1_1987)
+//! );
+//! assert_eq!(content_type.to_string(), "text/html; charset=ISO-8859-1");
+//! ```
+use crate::{
+	CharacterSet,
+	CharacterSetEnum,
+};
+use alloc::{
+	borrow::ToOwned,
+	string::String,
+	vec::Vec,
+};
+
+/// A parsed `Content-Type` header value.
+///
+/// `Display` writes it canonically: in lowercase except for parameter values, with `; ` between
+/// parameters, values quoted only where needed and the `charset` parameter spelled as the
+/// preferred MIME name of the character set it names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContentType
+{
+	/// The type and subtype, such as `text/html`.
+	essence: String,
+	slash: usize,
+	parameters: Vec<(String, String)>,
+}
+
+impl ContentType
+{
+	/// Returns the type and subtype without parameters, such as `text/html`.
+	pub fn essence(&self) -> &str
+	{
+		&self.essence
+	}
+
+	/// Returns the type, such as `text` in `text/html`.
+	pub fn top_level_type(&self) -> &str
+	{
+		&self.essence[..self.slash]
+	}
+
+	/// Returns the subtype, such as `html` in `text/html`.
+	pub fn subtype(&self) -> &str
+	{
+		&self.essence[self.slash + 1..]
+	}
+
+	/// Returns the names, in lowercase, and values of the parameters in the order they appear.
+	pub fn parameters(&self) -> impl Iterator<Item = (&str, &str)>
+	{
+		self.parameters
+			.iter()
+			.map(|(name, value)| (name.as_str(), value.as_str()))
+	}
+
+	/// Returns the value of the first parameter called `name`, ignoring case.
+	pub fn parameter(&self, name: &str) -> Option<&str>
+	{
+		self.parameters()
+			.find(|(n, _)| n.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value)
+	}
+
+	/// Returns the character set the `charset` parameter names, or `None` if there is no such
+	/// parameter or it names no registered character set.
+	pub fn charset(&self) -> Option<CharacterSetEnum>
+	{
+		CharacterSetEnum::lookup(self.parameter("charset")?.as_bytes())
+	}
+
+	/// Sets the `charset` parameter, replacing any there are.
+	pub fn with_charset(mut self, charset: CharacterSetEnum) -> Self
+	{
+		let value = charset
+			.preferred_mime_name()
+			.unwrap_or_else(|| charset.name())
+			.to_owned();
+		// Kept in the place of the first one.
+		let i = self
+			.parameters
+			.iter()
+			.position(|(name, _)| name == "charset")
+			.unwrap_or(self.parameters.len());
+		self.parameters.retain(|(name, _)| name != "charset");
+		self.parameters.insert(i, ("charset".to_owned(), value));
+		self
+	}
+}
+
+impl core::str::FromStr for ContentType
+{
+	type Err = InvalidContentTypeError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err>
+	{
+		let mut parser = Parser {
+			input: s,
+			position: 0,
+		};
+		parser.skip_whitespace();
+		let top_level_type = parser.token()?;
+		parser.skip_whitespace();
+		parser.expect(b'/')?;
+		parser.skip_whitespace();
+		let subtype = parser.token()?;
+		let mut essence = String::with_capacity(top_level_type.len() + 1 + subtype.len());
+		essence.push_str(top_level_type);
+		essence.push('/');
+		essence.push_str(subtype);
+		essence.make_ascii_lowercase();
+
+		let mut parameters = Vec::new();
+		loop
+		{
+			parser.skip_whitespace();
+			if parser.peek().is_none()
+			{
+				break;
+			}
+			parser.expect(b';')?;
+			parser.skip_whitespace();
+			if parser.peek().is_none() || parser.peek() == Some(b';')
+			{
+				continue;
+			}
+			let name = parser.token()?.to_ascii_lowercase();
+			parser.skip_whitespace();
+			parser.expect(b'=')?;
+			parser.skip_whitespace();
+			let value = if parser.peek() == Some(b'"')
+			{
+				parser.quoted_string()?
+			}
+			else
+			{
+				parser.token()?.to_owned()
+			};
+			parameters.push((name, value));
+		}
+		Ok(Self {
+			essence,
+			slash: top_level_type.len(),
+			parameters,
+		})
+	}
+}
+
+impl core::fmt::Display for ContentType
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		f.write_str(&self.essence)?;
+		for (name, value) in &self.parameters
+		{
+			let charset = if name == "charset"
+			{
+				CharacterSetEnum::lookup(value.as_bytes())
+			}
+			else
+			{
+				None
+			};
+			let value = match charset
+			{
+				Some(charset) =>
+				{
+					charset
+						.preferred_mime_name()
+						.unwrap_or_else(|| charset.name())
+				}
+				None => value,
+			};
+			write!(f, "; {}=", name)?;
+			if !value.is_empty() && value.bytes().all(is_token_byte)
+			{
+				f.write_str(value)?;
+			}
+			else
+			{
+				f.write_str("\"")?;
+				for c in value.chars()
+				{
+					if c == '"' || c == '\\'
+					{
+						f.write_str("\\")?;
+					}
+					write!(f, "{}", c)?;
+				}
+				f.write_str("\"")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Error returned when a `Content-Type` header value is not a valid media type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidContentTypeError
+{
+	offset: usize,
+}
+
+impl InvalidContentTypeError
+{
+	/// Returns the byte offset in the input where the media type stops being valid.
+	pub fn offset(&self) -> usize
+	{
+		self.offset
+	}
+}
+
+impl core::fmt::Display for InvalidContentTypeError
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		write!(f, "the content type is not valid at byte {}.", self.offset)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidContentTypeError {}
+
+/// `tchar` of RFC 7230 section 3.2.6.
+fn is_token_byte(b: u8) -> bool
+{
+	b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+}
+
+struct Parser<'a>
+{
+	input: &'a str,
+	position: usize,
+}
+
+impl<'a> Parser<'a>
+{
+	fn peek(&self) -> Option<u8>
+	{
+		self.input.as_bytes().get(self.position).copied()
+	}
+
+	fn error(&self) -> InvalidContentTypeError
+	{
+		InvalidContentTypeError {
+			offset: self.position,
+		}
+	}
+
+	fn skip_whitespace(&mut self)
+	{
+		while let Some(b' ') | Some(b'\t') = self.peek()
+		{
+			self.position += 1;
+		}
+	}
+
+	fn expect(&mut self, b: u8) -> Result<(), InvalidContentTypeError>
+	{
+		if self.peek() == Some(b)
+		{
+			self.position += 1;
+			Ok(())
+		}
+		else
+		{
+			Err(self.error())
+		}
+	}
+
+	fn token(&mut self) -> Result<&'a str, InvalidContentTypeError>
+	{
+		let start = self.position;
+		while matches!(self.peek(), Some(b) if is_token_byte(b))
+		{
+			self.position += 1;
+		}
+		if self.position == start
+		{
+			return Err(self.error());
+		}
+		Ok(&self.input[start..self.position])
+	}
+
+	/// Reads a quoted string, starting at its opening quote, and returns it unescaped.
+	fn quoted_string(&mut self) -> Result<String, InvalidContentTypeError>
+	{
+		self.expect(b'"')?;
+		let mut value = String::new();
+		let mut escaped = false;
+		loop
+		{
+			let c = match self.input[self.position..].chars().next()
+			{
+				Some(c) => c,
+				None => return Err(self.error()),
+			};
+			// Text, including obs-text, is anything but the controls other than HTAB.
+			if c.is_ascii_control() && c != '\t'
+			{
+				return Err(self.error());
+			}
+			self.position += c.len_utf8();
+			match c
+			{
+				_ if escaped =>
+				{
+					escaped = false;
+					value.push(c);
+				}
+				'\\' => escaped = true,
+				'"' => return Ok(value),
+				_ => value.push(c),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+	use alloc::string::ToString;
+
+	#[test]
+	fn keeps_a_charset_it_does_not_know()
+	{
+		let content_type: ContentType = "text/plain; charset=x-unknown".parse().unwrap();
+		assert_eq!(content_type.charset(), None);
+		assert_eq!(content_type.to_string(), "text/plain; charset=x-unknown");
+
+		let content_type: ContentType = "text/plain; charset=\"csutf8\"".parse().unwrap();
+		assert_eq!(content_type.charset(), Some(CharacterSetEnum::UTF_8));
+		assert_eq!(content_type.to_string(), "text/plain; charset=UTF-8");
+	}
+
+	#[test]
+	fn unescapes_quoted_strings_and_quotes_them_again()
+	{
+		let content_type: ContentType = r#"text/plain; title="a \"b\" \\ c"; x="\y"; empty="""#
+			.parse()
+			.unwrap();
+		assert_eq!(content_type.parameter("title"), Some(r#"a "b" \ c"#));
+		assert_eq!(content_type.parameter("x"), Some("y"));
+		assert_eq!(content_type.parameter("empty"), Some(""));
+		assert_eq!(
+			content_type.to_string(),
+			r#"text/plain; title="a \"b\" \\ c"; x=y; empty="""#
+		);
+		let reparsed: ContentType = content_type.to_string().parse().unwrap();
+		assert_eq!(reparsed, content_type);
+	}
+
+	#[test]
+	fn reports_where_the_input_stops_being_valid()
+	{
+		for &(input, offset) in &[
+			("text/plain; a=\"bc", 17),
+			("text/plain; charset utf-8", 20),
+			("text", 4),
+			("text plain", 5),
+			("text/plain; a=\"b\x01c\"", 16),
+			("text/pl\x01ain", 7),
+			("text/plain; a=b\x7F", 15),
+		]
+		{
+			let error = input.parse::<ContentType>().unwrap_err();
+			assert_eq!(error.offset(), offset, "{:?}", input);
+		}
+	}
+
+	#[test]
+	fn ignores_empty_parameters()
+	{
+		let content_type: ContentType = "text/plain;; charset=utf-8 ; ;".parse().unwrap();
+		assert_eq!(
+			content_type.parameters().collect::<Vec<_>>(),
+			[("charset", "utf-8")]
+		);
+		assert_eq!(content_type.to_string(), "text/plain; charset=UTF-8");
+	}
+
+	#[test]
+	fn takes_the_first_of_several_charsets()
+	{
+		let content_type: ContentType = "text/plain; Charset=utf-8; format=flowed; charset=latin1"
+			.parse()
+			.unwrap();
+		assert_eq!(content_type.charset(), Some(CharacterSetEnum::UTF_8));
+
+		let content_type = content_type.with_charset(CharacterSetEnum::SHIFT_JIS);
+		assert_eq!(
+			content_type.to_string(),
+			"text/plain; charset=Shift_JIS; format=flowed"
+		);
+		let content_type: ContentType = "text/plain; format=flowed".parse().unwrap();
+		assert_eq!(
+			content_type
+				.with_charset(CharacterSetEnum::UTF_8)
+				.to_string(),
+			"text/plain; format=flowed; charset=UTF-8"
+		);
+	}
+}
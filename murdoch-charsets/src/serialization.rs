@@ -0,0 +1,277 @@
+//! Support for [serde], enabled by the `serde` feature.
+//!
+//! Character sets serialize as their `name()` and deserialize through the same alias-aware,
+//! case-insensitive matching as `FromStr`. The modules below can be used with
+//! `#[serde(with = "...")]` to pick another representation:
+//!
+//! ```
+//! # #[cfg(not(feature = "no_charset"))]
+//! # {
+//! use murdoch_charsets::CharacterSetEnum;
+//! use serde::{
+//! 	Deserialize,
+//! 	Serialize,
+//! };
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config
+//! {
+//! 	#[serde(with = "murdoch_charsets::serialization::preferred_mime_name")]
+//! 	charset: CharacterSetEnum,
+//! 	#[serde(with = "murdoch_charsets::serialization::mib_enum")]
+//! 	legacy_charset: CharacterSetEnum,
+//! }
+//!
+//! let config: Config =
+//! 	serde_json::from_str(r#"{ "charset": "latin1", "legacy_charset": 2026 }"#).unwrap();
+//! assert_eq!(config.charset, CharacterSetEnum::ISO_8859_1_1987);
+//! assert_eq!(config.legacy_charset, CharacterSetEnum::BIG5);
+//! assert_eq!(
+//! 	serde_json::to_string(&config).unwrap(),
+//! 	r#"{"charset":"ISO-8859-1","legacy_charset":2026}"#
+//! );
+//! # }
+//! ```
+//!
+//! [serde]: https://serde.rs
+use crate::{
+	CharacterSet,
+	InvalidCharacterSetNameError,
+	InvalidMibEnumError,
+};
+use ::serde::{
+	de,
+	Deserialize,
+	Deserializer,
+	Serializer,
+};
+
+/// Serializes as `name()`, which is also what the `Serialize` impls do.
+pub mod name
+{
+	use super::*;
+
+	pub fn serialize<T: CharacterSet, S: Serializer>(
+		value: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(value.name())
+	}
+
+	pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<T, D::Error>
+	{
+		T::deserialize(deserializer)
+	}
+}
+
+/// Serializes as `preferred_mime_name()`, falling back to `name()` for character sets that
+/// have no preferred MIME name.
+pub mod preferred_mime_name
+{
+	use super::*;
+
+	pub fn serialize<T: CharacterSet, S: Serializer>(
+		value: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_str(value.preferred_mime_name().unwrap_or_else(|| value.name()))
+	}
+
+	pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<T, D::Error>
+	{
+		T::deserialize(deserializer)
+	}
+}
+
+/// Serializes as the MIBenum, and deserializes from it.
+pub mod mib_enum
+{
+	use super::*;
+	use core::convert::TryFrom;
+
+	pub fn serialize<T: CharacterSet, S: Serializer>(
+		value: &T,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	{
+		serializer.serialize_u16(value.mib_enum())
+	}
+
+	pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+	where
+		T: TryFrom<u16, Error = InvalidMibEnumError>,
+		D: Deserializer<'de>,
+	{
+		T::try_from(u16::deserialize(deserializer)?).map_err(de::Error::custom)
+	}
+}
+
+/// Visitor used by the `Deserialize` impls `character_sets!` generates.
+#[doc(hidden)]
+pub struct Visitor<T>(pub core::marker::PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for Visitor<T>
+where
+	T: core::str::FromStr<Err = InvalidCharacterSetNameError>,
+{
+	type Value = T;
+
+	fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		write!(f, "a registered character set name")
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E>
+	{
+		v.parse().map_err(E::custom)
+	}
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_serde {
+	($type:ident) => {
+		impl murdoch_charsets::__private::serde::Serialize for $type
+		{
+			fn serialize<S: murdoch_charsets::__private::serde::Serializer>(
+				&self,
+				serializer: S,
+			) -> core::result::Result<S::Ok, S::Error>
+			{
+				murdoch_charsets::serialization::name::serialize(self, serializer)
+			}
+		}
+
+		impl<'de> murdoch_charsets::__private::serde::Deserialize<'de> for $type
+		{
+			fn deserialize<D: murdoch_charsets::__private::serde::Deserializer<'de>>(
+				deserializer: D,
+			) -> core::result::Result<Self, D::Error>
+			{
+				deserializer.deserialize_str(murdoch_charsets::serialization::Visitor(
+					core::marker::PhantomData,
+				))
+			}
+		}
+	};
+}
+
+#[cfg(all(test, not(feature = "no_charset")))]
+mod tests
+{
+	extern crate std;
+
+	use crate::{
+		CharacterSet,
+		CharacterSetEnum,
+		UTF_8,
+	};
+	use serde::{
+		Deserialize,
+		Serialize,
+	};
+	use std::string::ToString;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Mime
+	{
+		#[serde(with = "super::preferred_mime_name")]
+		charset: CharacterSetEnum,
+	}
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Mib
+	{
+		#[serde(with = "super::mib_enum")]
+		charset: CharacterSetEnum,
+	}
+
+	#[test]
+	fn round_trips_through_the_name()
+	{
+		for charset in CharacterSetEnum::iter()
+		{
+			let json = serde_json::to_string(&charset).unwrap();
+			assert_eq!(json, std::format!("{:?}", charset.name()));
+			assert_eq!(
+				serde_json::from_str::<CharacterSetEnum>(&json).unwrap(),
+				charset
+			);
+		}
+		assert_eq!(serde_json::to_string(&UTF_8).unwrap(), r#""UTF-8""#);
+		assert_eq!(serde_json::from_str::<UTF_8>(r#""csutf8""#).unwrap(), UTF_8);
+		assert!(serde_json::from_str::<UTF_8>(r#""UTF-16""#).is_err());
+	}
+
+	#[test]
+	fn serializes_the_preferred_mime_name()
+	{
+		let latin1 = Mime {
+			charset: CharacterSetEnum::ISO_8859_1_1987,
+		};
+		assert_eq!(
+			serde_json::to_string(&latin1).unwrap(),
+			r#"{"charset":"ISO-8859-1"}"#
+		);
+		assert_eq!(
+			serde_json::from_str::<Mime>(r#"{"charset":"ISO-8859-1"}"#).unwrap(),
+			latin1
+		);
+		assert_eq!(CharacterSetEnum::IBM037.preferred_mime_name(), None);
+		assert_eq!(
+			serde_json::to_string(&Mime {
+				charset: CharacterSetEnum::IBM037
+			})
+			.unwrap(),
+			r#"{"charset":"IBM037"}"#
+		);
+	}
+
+	#[test]
+	fn serializes_the_mib_enum()
+	{
+		let utf_8 = Mib {
+			charset: CharacterSetEnum::UTF_8,
+		};
+		assert_eq!(serde_json::to_string(&utf_8).unwrap(), r#"{"charset":106}"#);
+		assert_eq!(
+			serde_json::from_str::<Mib>(r#"{"charset":106}"#).unwrap(),
+			utf_8
+		);
+	}
+
+	#[test]
+	fn rejects_unknown_names_and_mib_enums()
+	{
+		let error = serde_json::from_str::<CharacterSetEnum>(r#""utf-9""#).unwrap_err();
+		assert!(
+			error
+				.to_string()
+				.starts_with(r#""utf-9" is not a registered character set name; did you mean"#),
+			"{}",
+			error
+		);
+		let error = serde_json::from_str::<CharacterSetEnum>("106").unwrap_err();
+		assert!(
+			error
+				.to_string()
+				.contains("a registered character set name"),
+			"{}",
+			error
+		);
+		let error = serde_json::from_str::<Mib>(r#"{"charset":9999}"#).unwrap_err();
+		assert!(
+			error
+				.to_string()
+				.starts_with("no character set is registered with MIBenum 9999."),
+			"{}",
+			error
+		);
+	}
+}
@@ -0,0 +1,214 @@
+//! "Did you mean" candidates for `InvalidCharacterSetNameError`.
+//!
+//! Names are compared after normalization: ASCII letters are uppercased and everything but
+//! letters and digits is dropped, so `win-1252`, `WIN_1252` and `win1252` all look the same.
+//! Normalized names are then ranked by Levenshtein distance, ties going to the candidate that
+//! shares the longer prefix and suffix with the input.
+
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Normalized names longer than this are truncated; no registered name comes close.
+const MAX_LEN: usize = 64;
+
+/// Up to `MAX_SUGGESTIONS` registered names closest to a name that failed to parse, best match
+/// first. Dereferences to a slice of the names.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Suggestions
+{
+	names: [&'static str; MAX_SUGGESTIONS],
+	len: usize,
+}
+
+impl Suggestions
+{
+	pub(crate) fn new(candidates: &'static [&'static str], found: &str) -> Self
+	{
+		let mut found_buf = [0; MAX_LEN];
+		let found = normalize(found, &mut found_buf);
+
+		let mut best = [((usize::MAX, 0), ""); MAX_SUGGESTIONS];
+		for &candidate in candidates
+		{
+			let mut buf = [0; MAX_LEN];
+			let normalized = normalize(candidate, &mut buf);
+			let distance = distance(found, normalized);
+			if distance > found.len().max(normalized.len()) / 2 || distance >= found.len().max(1)
+			{
+				continue;
+			}
+			let rank = (distance, core::cmp::Reverse(affix(found, normalized)));
+			let duplicate = best.iter().any(|&(_, name)| {
+				let mut buf = [0; MAX_LEN];
+				!name.is_empty() && normalize(name, &mut buf) == normalized
+			});
+			if duplicate
+			{
+				continue;
+			}
+			if let Some(i) = best
+				.iter()
+				.position(|&((d, a), name)| name.is_empty() || rank < (d, core::cmp::Reverse(a)))
+			{
+				best[i..].rotate_right(1);
+				best[i] = ((distance, affix(found, normalized)), candidate);
+			}
+		}
+
+		let mut names = [""; MAX_SUGGESTIONS];
+		let mut len = 0;
+		for &(_, name) in &best
+		{
+			if !name.is_empty()
+			{
+				names[len] = name;
+				len += 1;
+			}
+		}
+		Self { names, len }
+	}
+
+	pub fn as_slice(&self) -> &[&'static str]
+	{
+		&self.names[..self.len]
+	}
+}
+
+impl core::ops::Deref for Suggestions
+{
+	type Target = [&'static str];
+
+	fn deref(&self) -> &[&'static str]
+	{
+		self.as_slice()
+	}
+}
+
+fn normalize<'a>(s: &str, buf: &'a mut [u8; MAX_LEN]) -> &'a [u8]
+{
+	let mut len = 0;
+	for b in s.bytes().filter(u8::is_ascii_alphanumeric).take(MAX_LEN)
+	{
+		buf[len] = b.to_ascii_uppercase();
+		len += 1;
+	}
+	&buf[..len]
+}
+
+/// Length of the common prefix plus the common suffix.
+fn affix(a: &[u8], b: &[u8]) -> usize
+{
+	let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+	let suffix = a
+		.iter()
+		.rev()
+		.zip(b.iter().rev())
+		.take_while(|(x, y)| x == y)
+		.count();
+	prefix + suffix
+}
+
+fn distance(a: &[u8], b: &[u8]) -> usize
+{
+	let mut previous = [0; MAX_LEN + 1];
+	let mut current = [0; MAX_LEN + 1];
+	for (j, d) in previous.iter_mut().enumerate().take(b.len() + 1)
+	{
+		*d = j;
+	}
+	for (i, &x) in a.iter().enumerate()
+	{
+		current[0] = i + 1;
+		for (j, &y) in b.iter().enumerate()
+		{
+			let substitution = previous[j] + usize::from(x != y);
+			current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+		}
+		core::mem::swap(&mut previous, &mut current);
+	}
+	previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests
+{
+	extern crate std;
+
+	use crate::InvalidCharacterSetNameError;
+
+	const NAMES: &[&str] = &["UTF-8", "UTF8", "UTF-16", "UTF16", "US-ASCII", "ASCII"];
+
+	#[test]
+	fn failed_parse_keeps_only_the_input()
+	{
+		// Only the candidates and the input are kept, so a failed parse costs no more than a copy
+		// of the input.
+		assert_eq!(
+			core::mem::size_of::<InvalidCharacterSetNameError>(),
+			core::mem::size_of::<(&[&str], crate::Found)>()
+		);
+		let error = InvalidCharacterSetNameError::new(NAMES, "utf-9");
+		assert_eq!(error.found(), "utf-9");
+		assert_eq!(error.expected(), NAMES);
+		assert_eq!(&*error.suggestions(), &["UTF-8", "UTF-16"]);
+		assert_eq!(error.suggestions(), error.suggestions());
+		#[cfg(not(feature = "no_charset"))]
+		{
+			let error = "utf-9".parse::<crate::CharacterSetEnum>().unwrap_err();
+			assert_eq!(error.found(), "utf-9");
+			assert_eq!(&*error.suggestions(), &["UTF-8", "UTF-7", "UTF-16"]);
+		}
+	}
+
+	#[test]
+	fn ranks_by_distance_then_shared_prefix_and_suffix()
+	{
+		const ISO_8859: &[&str] = &[
+			"ISO-8859-1",
+			"ISO_8859-1",
+			"ISO-8859-2",
+			"ISO-8859-3",
+			"ISO-8859-15",
+			"latin1",
+		];
+		let error = InvalidCharacterSetNameError::new(ISO_8859, "iso8859-5");
+		assert_eq!(
+			&*error.suggestions(),
+			&["ISO-8859-15", "ISO-8859-1", "ISO-8859-2"]
+		);
+		let error = InvalidCharacterSetNameError::new(ISO_8859, "iso-8859-1x");
+		assert_eq!(
+			&*error.suggestions(),
+			&["ISO-8859-1", "ISO-8859-15", "ISO-8859-2"]
+		);
+		let error = InvalidCharacterSetNameError::new(ISO_8859, "ebcdic");
+		assert!(error.suggestions().is_empty());
+	}
+
+	#[test]
+	fn display_lists_suggestions()
+	{
+		let error = InvalidCharacterSetNameError::new(NAMES, "utf_8x");
+		assert_eq!(
+			std::format!("{}", error),
+			"\"utf_8x\" is not a registered character set name; did you mean \"UTF-8\" or \
+			 \"UTF-16\"?"
+		);
+		let error = InvalidCharacterSetNameError::new(NAMES, "latin1");
+		assert_eq!(error.suggestions().as_slice(), &[] as &[&str]);
+		assert_eq!(
+			std::format!("{}", error),
+			"\"latin1\" is not a registered character set name."
+		);
+	}
+
+	#[test]
+	fn debug_leaves_out_the_expected_names()
+	{
+		let error = InvalidCharacterSetNameError::new(NAMES, "utf-9");
+		assert_eq!(
+			std::format!("{:?}", error),
+			"InvalidCharacterSetNameError { found: \"utf-9\", suggestions: [\"UTF-8\", \
+			 \"UTF-16\"] }"
+		);
+	}
+}
@@ -0,0 +1,557 @@
+//! Label resolution as specified by the [WHATWG Encoding Standard].
+//!
+//! Browsers do not look charset labels up in the IANA registry: they resolve them with the
+//! Encoding Standard, which maps many labels differently. For example `latin1`, `ascii` and
+//! `iso-8859-1` all resolve to windows-1252, and `iso-2022-kr` resolves to the "replacement"
+//! encoding, which decodes any input to a single U+FFFD.
+//!
+//! [WHATWG Encoding Standard]: https://encoding.spec.whatwg.org/
+use crate::{
+	CharacterSet,
+	CharacterSetEnum,
+};
+
+/// An encoding defined by the WHATWG Encoding Standard.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WhatwgEncoding
+{
+	UTF_8,
+	IBM866,
+	ISO_8859_2,
+	ISO_8859_3,
+	ISO_8859_4,
+	ISO_8859_5,
+	ISO_8859_6,
+	ISO_8859_7,
+	ISO_8859_8,
+	ISO_8859_8_I,
+	ISO_8859_10,
+	ISO_8859_13,
+	ISO_8859_14,
+	ISO_8859_15,
+	ISO_8859_16,
+	KOI8_R,
+	KOI8_U,
+	MACINTOSH,
+	WINDOWS_874,
+	WINDOWS_1250,
+	WINDOWS_1251,
+	WINDOWS_1252,
+	WINDOWS_1253,
+	WINDOWS_1254,
+	WINDOWS_1255,
+	WINDOWS_1256,
+	WINDOWS_1257,
+	WINDOWS_1258,
+	X_MAC_CYRILLIC,
+	GBK,
+	GB18030,
+	BIG5,
+	EUC_JP,
+	ISO_2022_JP,
+	SHIFT_JIS,
+	EUC_KR,
+	REPLACEMENT,
+	UTF_16BE,
+	UTF_16LE,
+	X_USER_DEFINED,
+}
+
+impl WhatwgEncoding
+{
+	/// Returns the canonical name of the encoding as written in the Encoding Standard.
+	pub fn name(&self) -> &'static str
+	{
+		match self
+		{
+			WhatwgEncoding::UTF_8 => "UTF-8",
+			WhatwgEncoding::IBM866 => "IBM866",
+			WhatwgEncoding::ISO_8859_2 => "ISO-8859-2",
+			WhatwgEncoding::ISO_8859_3 => "ISO-8859-3",
+			WhatwgEncoding::ISO_8859_4 => "ISO-8859-4",
+			WhatwgEncoding::ISO_8859_5 => "ISO-8859-5",
+			WhatwgEncoding::ISO_8859_6 => "ISO-8859-6",
+			WhatwgEncoding::ISO_8859_7 => "ISO-8859-7",
+			WhatwgEncoding::ISO_8859_8 => "ISO-8859-8",
+			WhatwgEncoding::ISO_8859_8_I => "ISO-8859-8-I",
+			WhatwgEncoding::ISO_8859_10 => "ISO-8859-10",
+			WhatwgEncoding::ISO_8859_13 => "ISO-8859-13",
+			WhatwgEncoding::ISO_8859_14 => "ISO-8859-14",
+			WhatwgEncoding::ISO_8859_15 => "ISO-8859-15",
+			WhatwgEncoding::ISO_8859_16 => "ISO-8859-16",
+			WhatwgEncoding::KOI8_R => "KOI8-R",
+			WhatwgEncoding::KOI8_U => "KOI8-U",
+			WhatwgEncoding::MACINTOSH => "macintosh",
+			WhatwgEncoding::WINDOWS_874 => "windows-874",
+			WhatwgEncoding::WINDOWS_1250 => "windows-1250",
+			WhatwgEncoding::WINDOWS_1251 => "windows-1251",
+			WhatwgEncoding::WINDOWS_1252 => "windows-1252",
+			WhatwgEncoding::WINDOWS_1253 => "windows-1253",
+			WhatwgEncoding::WINDOWS_1254 => "windows-1254",
+			WhatwgEncoding::WINDOWS_1255 => "windows-1255",
+			WhatwgEncoding::WINDOWS_1256 => "windows-1256",
+			WhatwgEncoding::WINDOWS_1257 => "windows-1257",
+			WhatwgEncoding::WINDOWS_1258 => "windows-1258",
+			WhatwgEncoding::X_MAC_CYRILLIC => "x-mac-cyrillic",
+			WhatwgEncoding::GBK => "GBK",
+			WhatwgEncoding::GB18030 => "gb18030",
+			WhatwgEncoding::BIG5 => "Big5",
+			WhatwgEncoding::EUC_JP => "EUC-JP",
+			WhatwgEncoding::ISO_2022_JP => "ISO-2022-JP",
+			WhatwgEncoding::SHIFT_JIS => "Shift_JIS",
+			WhatwgEncoding::EUC_KR => "EUC-KR",
+			WhatwgEncoding::REPLACEMENT => "replacement",
+			WhatwgEncoding::UTF_16BE => "UTF-16BE",
+			WhatwgEncoding::UTF_16LE => "UTF-16LE",
+			WhatwgEncoding::X_USER_DEFINED => "x-user-defined",
+		}
+	}
+
+	/// Returns the registered character set the encoding corresponds to.
+	///
+	/// `REPLACEMENT`, `X_USER_DEFINED` and `X_MAC_CYRILLIC` have no counterpart in the IANA
+	/// registry and return `None`.
+	pub fn to_character_set(&self) -> Option<CharacterSetEnum>
+	{
+		match self
+		{
+			WhatwgEncoding::UTF_8 => Some(CharacterSetEnum::UTF_8),
+			WhatwgEncoding::IBM866 => Some(CharacterSetEnum::IBM866),
+			WhatwgEncoding::ISO_8859_2 => Some(CharacterSetEnum::ISO_8859_2_1987),
+			WhatwgEncoding::ISO_8859_3 => Some(CharacterSetEnum::ISO_8859_3_1988),
+			WhatwgEncoding::ISO_8859_4 => Some(CharacterSetEnum::ISO_8859_4_1988),
+			WhatwgEncoding::ISO_8859_5 => Some(CharacterSetEnum::ISO_8859_5_1988),
+			WhatwgEncoding::ISO_8859_6 => Some(CharacterSetEnum::ISO_8859_6_1987),
+			WhatwgEncoding::ISO_8859_7 => Some(CharacterSetEnum::ISO_8859_7_1987),
+			WhatwgEncoding::ISO_8859_8 => Some(CharacterSetEnum::ISO_8859_8_1988),
+			WhatwgEncoding::ISO_8859_8_I => Some(CharacterSetEnum::ISO_8859_8_I),
+			WhatwgEncoding::ISO_8859_10 => Some(CharacterSetEnum::ISO_8859_10),
+			WhatwgEncoding::ISO_8859_13 => Some(CharacterSetEnum::ISO_8859_13),
+			WhatwgEncoding::ISO_8859_14 => Some(CharacterSetEnum::ISO_8859_14),
+			WhatwgEncoding::ISO_8859_15 => Some(CharacterSetEnum::ISO_8859_15),
+			WhatwgEncoding::ISO_8859_16 => Some(CharacterSetEnum::ISO_8859_16),
+			WhatwgEncoding::KOI8_R => Some(CharacterSetEnum::KOI8_R),
+			WhatwgEncoding::KOI8_U => Some(CharacterSetEnum::KOI8_U),
+			WhatwgEncoding::MACINTOSH => Some(CharacterSetEnum::MACINTOSH),
+			WhatwgEncoding::WINDOWS_874 => Some(CharacterSetEnum::WINDOWS_874),
+			WhatwgEncoding::WINDOWS_1250 => Some(CharacterSetEnum::WINDOWS_1250),
+			WhatwgEncoding::WINDOWS_1251 => Some(CharacterSetEnum::WINDOWS_1251),
+			WhatwgEncoding::WINDOWS_1252 => Some(CharacterSetEnum::WINDOWS_1252),
+			WhatwgEncoding::WINDOWS_1253 => Some(CharacterSetEnum::WINDOWS_1253),
+			WhatwgEncoding::WINDOWS_1254 => Some(CharacterSetEnum::WINDOWS_1254),
+			WhatwgEncoding::WINDOWS_1255 => Some(CharacterSetEnum::WINDOWS_1255),
+			WhatwgEncoding::WINDOWS_1256 => Some(CharacterSetEnum::WINDOWS_1256),
+			WhatwgEncoding::WINDOWS_1257 => Some(CharacterSetEnum::WINDOWS_1257),
+			WhatwgEncoding::WINDOWS_1258 => Some(CharacterSetEnum::WINDOWS_1258),
+			WhatwgEncoding::X_MAC_CYRILLIC => None,
+			WhatwgEncoding::GBK => Some(CharacterSetEnum::GBK),
+			WhatwgEncoding::GB18030 => Some(CharacterSetEnum::GB18030),
+			WhatwgEncoding::BIG5 => Some(CharacterSetEnum::BIG5),
+			WhatwgEncoding::EUC_JP =>
+			{
+				Some(CharacterSetEnum::EXTENDED_UNIX_CODE_PACKED_FORMAT_FOR_JAPANESE)
+			}
+			WhatwgEncoding::ISO_2022_JP => Some(CharacterSetEnum::ISO_2022_JP),
+			WhatwgEncoding::SHIFT_JIS => Some(CharacterSetEnum::SHIFT_JIS),
+			WhatwgEncoding::EUC_KR => Some(CharacterSetEnum::EUC_KR),
+			WhatwgEncoding::REPLACEMENT => None,
+			WhatwgEncoding::UTF_16BE => Some(CharacterSetEnum::UTF_16BE),
+			WhatwgEncoding::UTF_16LE => Some(CharacterSetEnum::UTF_16LE),
+			WhatwgEncoding::X_USER_DEFINED => None,
+		}
+	}
+
+	/// Resolves a registered character set the way a browser would resolve its name.
+	///
+	/// The name of the character set is tried first, then its aliases. Returns `None` when none
+	/// of them is a WHATWG label, and `Some(WhatwgEncoding::REPLACEMENT)` for the character sets
+	/// the Encoding Standard deliberately refuses to decode, such as `ISO-2022-KR` and
+	/// `HZ-GB-2312`.
+	pub fn from_character_set(charset: CharacterSetEnum) -> Option<Self>
+	{
+		core::iter::once(charset.name())
+			.chain(charset.aliases().iter().copied())
+			.find_map(resolve_label)
+	}
+
+	pub fn is_replacement(&self) -> bool
+	{
+		*self == WhatwgEncoding::REPLACEMENT
+	}
+}
+
+impl core::fmt::Display for WhatwgEncoding
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result
+	{
+		write!(f, "{}", self.name())
+	}
+}
+
+/// Resolves a label per the "get an encoding" algorithm of the Encoding Standard.
+///
+/// Leading and trailing ASCII whitespace is removed and the label is matched ASCII
+/// case-insensitively without allocating.
+pub fn resolve_label(label: &str) -> Option<WhatwgEncoding>
+{
+	let label = label.trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '));
+	crate::__private::binary_search(LABELS, label.as_bytes())
+}
+
+const _: () = assert!(crate::__private::is_sorted(LABELS));
+
+/// Every label of the Encoding Standard, uppercased and sorted for binary search.
+const LABELS: &[(&str, WhatwgEncoding)] = &[
+	("866", WhatwgEncoding::IBM866),
+	("ANSI_X3.4-1968", WhatwgEncoding::WINDOWS_1252),
+	("ARABIC", WhatwgEncoding::ISO_8859_6),
+	("ASCII", WhatwgEncoding::WINDOWS_1252),
+	("ASMO-708", WhatwgEncoding::ISO_8859_6),
+	("BIG5", WhatwgEncoding::BIG5),
+	("BIG5-HKSCS", WhatwgEncoding::BIG5),
+	("CHINESE", WhatwgEncoding::GBK),
+	("CN-BIG5", WhatwgEncoding::BIG5),
+	("CP1250", WhatwgEncoding::WINDOWS_1250),
+	("CP1251", WhatwgEncoding::WINDOWS_1251),
+	("CP1252", WhatwgEncoding::WINDOWS_1252),
+	("CP1253", WhatwgEncoding::WINDOWS_1253),
+	("CP1254", WhatwgEncoding::WINDOWS_1254),
+	("CP1255", WhatwgEncoding::WINDOWS_1255),
+	("CP1256", WhatwgEncoding::WINDOWS_1256),
+	("CP1257", WhatwgEncoding::WINDOWS_1257),
+	("CP1258", WhatwgEncoding::WINDOWS_1258),
+	("CP819", WhatwgEncoding::WINDOWS_1252),
+	("CP866", WhatwgEncoding::IBM866),
+	("CSBIG5", WhatwgEncoding::BIG5),
+	("CSEUCKR", WhatwgEncoding::EUC_KR),
+	("CSEUCPKDFMTJAPANESE", WhatwgEncoding::EUC_JP),
+	("CSGB2312", WhatwgEncoding::GBK),
+	("CSIBM866", WhatwgEncoding::IBM866),
+	("CSISO2022JP", WhatwgEncoding::ISO_2022_JP),
+	("CSISO2022KR", WhatwgEncoding::REPLACEMENT),
+	("CSISO58GB231280", WhatwgEncoding::GBK),
+	("CSISO88596E", WhatwgEncoding::ISO_8859_6),
+	("CSISO88596I", WhatwgEncoding::ISO_8859_6),
+	("CSISO88598E", WhatwgEncoding::ISO_8859_8),
+	("CSISO88598I", WhatwgEncoding::ISO_8859_8_I),
+	("CSISOLATIN1", WhatwgEncoding::WINDOWS_1252),
+	("CSISOLATIN2", WhatwgEncoding::ISO_8859_2),
+	("CSISOLATIN3", WhatwgEncoding::ISO_8859_3),
+	("CSISOLATIN4", WhatwgEncoding::ISO_8859_4),
+	("CSISOLATIN5", WhatwgEncoding::WINDOWS_1254),
+	("CSISOLATIN6", WhatwgEncoding::ISO_8859_10),
+	("CSISOLATIN9", WhatwgEncoding::ISO_8859_15),
+	("CSISOLATINARABIC", WhatwgEncoding::ISO_8859_6),
+	("CSISOLATINCYRILLIC", WhatwgEncoding::ISO_8859_5),
+	("CSISOLATINGREEK", WhatwgEncoding::ISO_8859_7),
+	("CSISOLATINHEBREW", WhatwgEncoding::ISO_8859_8),
+	("CSKOI8R", WhatwgEncoding::KOI8_R),
+	("CSKSC56011987", WhatwgEncoding::EUC_KR),
+	("CSMACINTOSH", WhatwgEncoding::MACINTOSH),
+	("CSSHIFTJIS", WhatwgEncoding::SHIFT_JIS),
+	("CSUNICODE", WhatwgEncoding::UTF_16LE),
+	("CYRILLIC", WhatwgEncoding::ISO_8859_5),
+	("DOS-874", WhatwgEncoding::WINDOWS_874),
+	("ECMA-114", WhatwgEncoding::ISO_8859_6),
+	("ECMA-118", WhatwgEncoding::ISO_8859_7),
+	("ELOT_928", WhatwgEncoding::ISO_8859_7),
+	("EUC-JP", WhatwgEncoding::EUC_JP),
+	("EUC-KR", WhatwgEncoding::EUC_KR),
+	("GB18030", WhatwgEncoding::GB18030),
+	("GB2312", WhatwgEncoding::GBK),
+	("GBK", WhatwgEncoding::GBK),
+	("GB_2312", WhatwgEncoding::GBK),
+	("GB_2312-80", WhatwgEncoding::GBK),
+	("GREEK", WhatwgEncoding::ISO_8859_7),
+	("GREEK8", WhatwgEncoding::ISO_8859_7),
+	("HEBREW", WhatwgEncoding::ISO_8859_8),
+	("HZ-GB-2312", WhatwgEncoding::REPLACEMENT),
+	("IBM819", WhatwgEncoding::WINDOWS_1252),
+	("IBM866", WhatwgEncoding::IBM866),
+	("ISO-10646-UCS-2", WhatwgEncoding::UTF_16LE),
+	("ISO-2022-CN", WhatwgEncoding::REPLACEMENT),
+	("ISO-2022-CN-EXT", WhatwgEncoding::REPLACEMENT),
+	("ISO-2022-JP", WhatwgEncoding::ISO_2022_JP),
+	("ISO-2022-KR", WhatwgEncoding::REPLACEMENT),
+	("ISO-8859-1", WhatwgEncoding::WINDOWS_1252),
+	("ISO-8859-10", WhatwgEncoding::ISO_8859_10),
+	("ISO-8859-11", WhatwgEncoding::WINDOWS_874),
+	("ISO-8859-13", WhatwgEncoding::ISO_8859_13),
+	("ISO-8859-14", WhatwgEncoding::ISO_8859_14),
+	("ISO-8859-15", WhatwgEncoding::ISO_8859_15),
+	("ISO-8859-16", WhatwgEncoding::ISO_8859_16),
+	("ISO-8859-2", WhatwgEncoding::ISO_8859_2),
+	("ISO-8859-3", WhatwgEncoding::ISO_8859_3),
+	("ISO-8859-4", WhatwgEncoding::ISO_8859_4),
+	("ISO-8859-5", WhatwgEncoding::ISO_8859_5),
+	("ISO-8859-6", WhatwgEncoding::ISO_8859_6),
+	("ISO-8859-6-E", WhatwgEncoding::ISO_8859_6),
+	("ISO-8859-6-I", WhatwgEncoding::ISO_8859_6),
+	("ISO-8859-7", WhatwgEncoding::ISO_8859_7),
+	("ISO-8859-8", WhatwgEncoding::ISO_8859_8),
+	("ISO-8859-8-E", WhatwgEncoding::ISO_8859_8),
+	("ISO-8859-8-I", WhatwgEncoding::ISO_8859_8_I),
+	("ISO-8859-9", WhatwgEncoding::WINDOWS_1254),
+	("ISO-IR-100", WhatwgEncoding::WINDOWS_1252),
+	("ISO-IR-101", WhatwgEncoding::ISO_8859_2),
+	("ISO-IR-109", WhatwgEncoding::ISO_8859_3),
+	("ISO-IR-110", WhatwgEncoding::ISO_8859_4),
+	("ISO-IR-126", WhatwgEncoding::ISO_8859_7),
+	("ISO-IR-127", WhatwgEncoding::ISO_8859_6),
+	("ISO-IR-138", WhatwgEncoding::ISO_8859_8),
+	("ISO-IR-144", WhatwgEncoding::ISO_8859_5),
+	("ISO-IR-148", WhatwgEncoding::WINDOWS_1254),
+	("ISO-IR-149", WhatwgEncoding::EUC_KR),
+	("ISO-IR-157", WhatwgEncoding::ISO_8859_10),
+	("ISO-IR-58", WhatwgEncoding::GBK),
+	("ISO8859-1", WhatwgEncoding::WINDOWS_1252),
+	("ISO8859-10", WhatwgEncoding::ISO_8859_10),
+	("ISO8859-11", WhatwgEncoding::WINDOWS_874),
+	("ISO8859-13", WhatwgEncoding::ISO_8859_13),
+	("ISO8859-14", WhatwgEncoding::ISO_8859_14),
+	("ISO8859-15", WhatwgEncoding::ISO_8859_15),
+	("ISO8859-2", WhatwgEncoding::ISO_8859_2),
+	("ISO8859-3", WhatwgEncoding::ISO_8859_3),
+	("ISO8859-4", WhatwgEncoding::ISO_8859_4),
+	("ISO8859-5", WhatwgEncoding::ISO_8859_5),
+	("ISO8859-6", WhatwgEncoding::ISO_8859_6),
+	("ISO8859-7", WhatwgEncoding::ISO_8859_7),
+	("ISO8859-8", WhatwgEncoding::ISO_8859_8),
+	("ISO8859-9", WhatwgEncoding::WINDOWS_1254),
+	("ISO88591", WhatwgEncoding::WINDOWS_1252),
+	("ISO885910", WhatwgEncoding::ISO_8859_10),
+	("ISO885911", WhatwgEncoding::WINDOWS_874),
+	("ISO885913", WhatwgEncoding::ISO_8859_13),
+	("ISO885914", WhatwgEncoding::ISO_8859_14),
+	("ISO885915", WhatwgEncoding::ISO_8859_15),
+	("ISO88592", WhatwgEncoding::ISO_8859_2),
+	("ISO88593", WhatwgEncoding::ISO_8859_3),
+	("ISO88594", WhatwgEncoding::ISO_8859_4),
+	("ISO88595", WhatwgEncoding::ISO_8859_5),
+	("ISO88596", WhatwgEncoding::ISO_8859_6),
+	("ISO88597", WhatwgEncoding::ISO_8859_7),
+	("ISO88598", WhatwgEncoding::ISO_8859_8),
+	("ISO88599", WhatwgEncoding::WINDOWS_1254),
+	("ISO_8859-1", WhatwgEncoding::WINDOWS_1252),
+	("ISO_8859-15", WhatwgEncoding::ISO_8859_15),
+	("ISO_8859-1:1987", WhatwgEncoding::WINDOWS_1252),
+	("ISO_8859-2", WhatwgEncoding::ISO_8859_2),
+	("ISO_8859-2:1987", WhatwgEncoding::ISO_8859_2),
+	("ISO_8859-3", WhatwgEncoding::ISO_8859_3),
+	("ISO_8859-3:1988", WhatwgEncoding::ISO_8859_3),
+	("ISO_8859-4", WhatwgEncoding::ISO_8859_4),
+	("ISO_8859-4:1988", WhatwgEncoding::ISO_8859_4),
+	("ISO_8859-5", WhatwgEncoding::ISO_8859_5),
+	("ISO_8859-5:1988", WhatwgEncoding::ISO_8859_5),
+	("ISO_8859-6", WhatwgEncoding::ISO_8859_6),
+	("ISO_8859-6:1987", WhatwgEncoding::ISO_8859_6),
+	("ISO_8859-7", WhatwgEncoding::ISO_8859_7),
+	("ISO_8859-7:1987", WhatwgEncoding::ISO_8859_7),
+	("ISO_8859-8", WhatwgEncoding::ISO_8859_8),
+	("ISO_8859-8:1988", WhatwgEncoding::ISO_8859_8),
+	("ISO_8859-9", WhatwgEncoding::WINDOWS_1254),
+	("ISO_8859-9:1989", WhatwgEncoding::WINDOWS_1254),
+	("KOI", WhatwgEncoding::KOI8_R),
+	("KOI8", WhatwgEncoding::KOI8_R),
+	("KOI8-R", WhatwgEncoding::KOI8_R),
+	("KOI8-RU", WhatwgEncoding::KOI8_U),
+	("KOI8-U", WhatwgEncoding::KOI8_U),
+	("KOI8_R", WhatwgEncoding::KOI8_R),
+	("KOREAN", WhatwgEncoding::EUC_KR),
+	("KSC5601", WhatwgEncoding::EUC_KR),
+	("KSC_5601", WhatwgEncoding::EUC_KR),
+	("KS_C_5601-1987", WhatwgEncoding::EUC_KR),
+	("KS_C_5601-1989", WhatwgEncoding::EUC_KR),
+	("L1", WhatwgEncoding::WINDOWS_1252),
+	("L2", WhatwgEncoding::ISO_8859_2),
+	("L3", WhatwgEncoding::ISO_8859_3),
+	("L4", WhatwgEncoding::ISO_8859_4),
+	("L5", WhatwgEncoding::WINDOWS_1254),
+	("L6", WhatwgEncoding::ISO_8859_10),
+	("L9", WhatwgEncoding::ISO_8859_15),
+	("LATIN1", WhatwgEncoding::WINDOWS_1252),
+	("LATIN2", WhatwgEncoding::ISO_8859_2),
+	("LATIN3", WhatwgEncoding::ISO_8859_3),
+	("LATIN4", WhatwgEncoding::ISO_8859_4),
+	("LATIN5", WhatwgEncoding::WINDOWS_1254),
+	("LATIN6", WhatwgEncoding::ISO_8859_10),
+	("LOGICAL", WhatwgEncoding::ISO_8859_8_I),
+	("MAC", WhatwgEncoding::MACINTOSH),
+	("MACINTOSH", WhatwgEncoding::MACINTOSH),
+	("MS932", WhatwgEncoding::SHIFT_JIS),
+	("MS_KANJI", WhatwgEncoding::SHIFT_JIS),
+	("REPLACEMENT", WhatwgEncoding::REPLACEMENT),
+	("SHIFT-JIS", WhatwgEncoding::SHIFT_JIS),
+	("SHIFT_JIS", WhatwgEncoding::SHIFT_JIS),
+	("SJIS", WhatwgEncoding::SHIFT_JIS),
+	("SUN_EU_GREEK", WhatwgEncoding::ISO_8859_7),
+	("TIS-620", WhatwgEncoding::WINDOWS_874),
+	("UCS-2", WhatwgEncoding::UTF_16LE),
+	("UNICODE", WhatwgEncoding::UTF_16LE),
+	("UNICODE-1-1-UTF-8", WhatwgEncoding::UTF_8),
+	("UNICODE11UTF8", WhatwgEncoding::UTF_8),
+	("UNICODE20UTF8", WhatwgEncoding::UTF_8),
+	("UNICODEFEFF", WhatwgEncoding::UTF_16LE),
+	("UNICODEFFFE", WhatwgEncoding::UTF_16BE),
+	("US-ASCII", WhatwgEncoding::WINDOWS_1252),
+	("UTF-16", WhatwgEncoding::UTF_16LE),
+	("UTF-16BE", WhatwgEncoding::UTF_16BE),
+	("UTF-16LE", WhatwgEncoding::UTF_16LE),
+	("UTF-8", WhatwgEncoding::UTF_8),
+	("UTF8", WhatwgEncoding::UTF_8),
+	("VISUAL", WhatwgEncoding::ISO_8859_8),
+	("WINDOWS-1250", WhatwgEncoding::WINDOWS_1250),
+	("WINDOWS-1251", WhatwgEncoding::WINDOWS_1251),
+	("WINDOWS-1252", WhatwgEncoding::WINDOWS_1252),
+	("WINDOWS-1253", WhatwgEncoding::WINDOWS_1253),
+	("WINDOWS-1254", WhatwgEncoding::WINDOWS_1254),
+	("WINDOWS-1255", WhatwgEncoding::WINDOWS_1255),
+	("WINDOWS-1256", WhatwgEncoding::WINDOWS_1256),
+	("WINDOWS-1257", WhatwgEncoding::WINDOWS_1257),
+	("WINDOWS-1258", WhatwgEncoding::WINDOWS_1258),
+	("WINDOWS-31J", WhatwgEncoding::SHIFT_JIS),
+	("WINDOWS-874", WhatwgEncoding::WINDOWS_874),
+	("WINDOWS-949", WhatwgEncoding::EUC_KR),
+	("X-CP1250", WhatwgEncoding::WINDOWS_1250),
+	("X-CP1251", WhatwgEncoding::WINDOWS_1251),
+	("X-CP1252", WhatwgEncoding::WINDOWS_1252),
+	("X-CP1253", WhatwgEncoding::WINDOWS_1253),
+	("X-CP1254", WhatwgEncoding::WINDOWS_1254),
+	("X-CP1255", WhatwgEncoding::WINDOWS_1255),
+	("X-CP1256", WhatwgEncoding::WINDOWS_1256),
+	("X-CP1257", WhatwgEncoding::WINDOWS_1257),
+	("X-CP1258", WhatwgEncoding::WINDOWS_1258),
+	("X-EUC-JP", WhatwgEncoding::EUC_JP),
+	("X-GBK", WhatwgEncoding::GBK),
+	("X-MAC-CYRILLIC", WhatwgEncoding::X_MAC_CYRILLIC),
+	("X-MAC-ROMAN", WhatwgEncoding::MACINTOSH),
+	("X-MAC-UKRAINIAN", WhatwgEncoding::X_MAC_CYRILLIC),
+	("X-SJIS", WhatwgEncoding::SHIFT_JIS),
+	("X-UNICODE20UTF8", WhatwgEncoding::UTF_8),
+	("X-USER-DEFINED", WhatwgEncoding::X_USER_DEFINED),
+	("X-X-BIG5", WhatwgEncoding::BIG5),
+];
+
+#[cfg(test)]
+mod tests
+{
+	use super::*;
+
+	#[test]
+	fn resolves_labels_ignoring_case_and_surrounding_whitespace()
+	{
+		assert_eq!(resolve_label("utf-8"), Some(WhatwgEncoding::UTF_8));
+		assert_eq!(resolve_label("Utf8"), Some(WhatwgEncoding::UTF_8));
+		assert_eq!(
+			resolve_label(" \t\n\x0C\rUNICODE-1-1-utf-8 \r\n"),
+			Some(WhatwgEncoding::UTF_8)
+		);
+		assert_eq!(resolve_label("x-sjis"), Some(WhatwgEncoding::SHIFT_JIS));
+		assert_eq!(resolve_label("X-SJIS"), Some(WhatwgEncoding::SHIFT_JIS));
+		assert_eq!(resolve_label("866"), Some(WhatwgEncoding::IBM866));
+	}
+
+	#[test]
+	fn resolves_latin1_labels_to_windows_1252()
+	{
+		for label in &[
+			"latin1",
+			"ascii",
+			"iso-8859-1",
+			"US-ASCII",
+			"ISO_8859-1:1987",
+		]
+		{
+			assert_eq!(
+				resolve_label(label),
+				Some(WhatwgEncoding::WINDOWS_1252),
+				"{}",
+				label
+			);
+		}
+	}
+
+	#[test]
+	fn resolves_replacement_labels()
+	{
+		for label in &[
+			"replacement",
+			"iso-2022-kr",
+			"csISO2022KR",
+			"hz-gb-2312",
+			"iso-2022-cn",
+		]
+		{
+			let encoding = resolve_label(label).unwrap();
+			assert!(encoding.is_replacement(), "{}", label);
+		}
+		assert!(!WhatwgEncoding::UTF_8.is_replacement());
+	}
+
+	#[test]
+	fn rejects_unknown_labels()
+	{
+		for label in &[
+			"",
+			" ",
+			"utf-",
+			"utf-88",
+			"utf 8",
+			"\u{A0}utf-8",
+			"x-unknown",
+		]
+		{
+			assert_eq!(resolve_label(label), None, "{:?}", label);
+		}
+	}
+
+	#[test]
+	fn maps_encodings_to_character_sets()
+	{
+		assert_eq!(
+			WhatwgEncoding::WINDOWS_1252.to_character_set(),
+			Some(CharacterSetEnum::WINDOWS_1252)
+		);
+		assert_eq!(
+			WhatwgEncoding::EUC_JP.to_character_set(),
+			Some(CharacterSetEnum::EXTENDED_UNIX_CODE_PACKED_FORMAT_FOR_JAPANESE)
+		);
+		assert_eq!(WhatwgEncoding::REPLACEMENT.to_character_set(), None);
+		assert_eq!(WhatwgEncoding::X_USER_DEFINED.to_character_set(), None);
+		assert_eq!(WhatwgEncoding::X_MAC_CYRILLIC.to_character_set(), None);
+	}
+
+	#[test]
+	fn maps_character_sets_to_encodings()
+	{
+		assert_eq!(
+			WhatwgEncoding::from_character_set(CharacterSetEnum::ISO_8859_1_1987),
+			Some(WhatwgEncoding::WINDOWS_1252)
+		);
+		assert_eq!(
+			WhatwgEncoding::from_character_set(CharacterSetEnum::SHIFT_JIS),
+			Some(WhatwgEncoding::SHIFT_JIS)
+		);
+		assert_eq!(
+			WhatwgEncoding::from_character_set(CharacterSetEnum::ISO_2022_KR),
+			Some(WhatwgEncoding::REPLACEMENT)
+		);
+		assert_eq!(
+			WhatwgEncoding::from_character_set(CharacterSetEnum::IBM037),
+			None
+		);
+		for &(_, encoding) in LABELS
+		{
+			if let Some(charset) = encoding.to_character_set()
+			{
+				assert_eq!(
+					WhatwgEncoding::from_character_set(charset),
+					Some(encoding),
+					"{}",
+					encoding
+				);
+			}
+		}
+	}
+}